@@ -31,8 +31,9 @@ fn main() {
         return;
     }
 
-    // `-Wl,-rpath,...` is Linux-specific. (macOS uses @loader_path; Windows doesn't use rpath.)
-    if !cfg!(target_os = "linux") {
+    // Windows doesn't use rpath; macOS uses @loader_path where Linux uses $ORIGIN.
+    let macos = cfg!(target_os = "macos");
+    if !cfg!(target_os = "linux") && !macos {
         return;
     }
 
@@ -47,13 +48,12 @@ fn main() {
         .join("vcpkg_installed");
 
     let target = env::var("TARGET").unwrap_or_default();
+    let os = if macos { "osx" } else { "linux" };
     let triplet = if target.contains("aarch64") {
-        "arm64-linux"
-    } else if target.contains("x86_64") {
-        // depthai-core's internal vcpkg commonly uses x64-linux.
-        "x64-linux"
+        format!("arm64-{os}")
     } else {
-        "x64-linux"
+        // depthai-core's internal vcpkg commonly uses x64-linux / x64-osx.
+        format!("x64-{os}")
     };
 
     let libdir = vcpkg_root.join(triplet).join("lib");
@@ -69,8 +69,13 @@ fn main() {
 
     // Always include $ORIGIN so staged .so files next to executables work out-of-the-box.
     // Then include the internal build outputs if present.
-    let mut runpaths: Vec<String> = vec!["$ORIGIN".to_string()];
-    if dcl_dir.join("libdynamic_calibration.so").exists() {
+    let (origin, dcl_lib) = if macos {
+        ("@loader_path", "libdynamic_calibration.dylib")
+    } else {
+        ("$ORIGIN", "libdynamic_calibration.so")
+    };
+    let mut runpaths: Vec<String> = vec![origin.to_string()];
+    if dcl_dir.join(dcl_lib).exists() {
         runpaths.push(dcl_dir.to_string_lossy().to_string());
     }
     if libdir.exists() {
@@ -81,5 +86,12 @@ fn main() {
     // When depthai is used as a library dependency in downstream crates, those crates need
     // to set their own rpath (e.g., `cargo:rustc-link-arg=-Wl,-rpath,$ORIGIN` in their
     // build.rs) to find the staged .so files.
-    println!("cargo:rustc-link-arg=-Wl,-rpath,{}", runpaths.join(":"));
+    // ld64 takes one directory per -rpath; rpaths set at link time keep code signatures valid.
+    if macos {
+        for path in &runpaths {
+            println!("cargo:rustc-link-arg=-Wl,-rpath,{path}");
+        }
+    } else {
+        println!("cargo:rustc-link-arg=-Wl,-rpath,{}", runpaths.join(":"));
+    }
 }
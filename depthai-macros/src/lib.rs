@@ -21,6 +21,17 @@ use syn::{parse::Parse, parse::ParseStream, parse_macro_input, Ident, ItemStruct
 /// - `as_node = true|false`: optional, defaults to `true`.
 /// - `inputs(...)`: optional, list of input port names.
 /// - `outputs(...)`: optional, list of output port names.
+///
+/// - `props(...)`: optional, list of typed properties (`props(fps: f32, enable_undistort: bool)`).
+///
+/// Ports may be annotated with their message type (`outputs(depth: ImageFrame)`); the generated
+/// accessor then returns `TypedOutput<ImageFrame>` / `TypedInput<ImageFrame>` instead of the
+/// untyped port, so linking incompatible ports fails at compile time.
+///
+/// Each property generates a `set_<name>(value)` method calling `Node::set_property`, which
+/// updates the node's properties by their JSON path. The path defaults to the name in camelCase
+/// (`enable_undistort` sets `enableUndistort`); nested or differently named properties take it
+/// explicitly: `props(fps = "initialConfig.fps": f32)`. The type must be `serde::Serialize`.
 #[proc_macro_attribute]
 pub fn native_node_wrapper(attr: TokenStream, item: TokenStream) -> TokenStream {
     let args = parse_macro_input!(attr as NativeNodeArgs);
@@ -36,8 +47,67 @@ struct NativeNodeArgs {
     native: syn::LitStr,
     field: Ident,
     gen_as_node: bool,
-    inputs: Vec<Ident>,
-    outputs: Vec<Ident>,
+    inputs: Vec<PortSpec>,
+    outputs: Vec<PortSpec>,
+    props: Vec<PropSpec>,
+}
+
+/// A property setter (`name: Type` or `name = "json.path": Type`).
+struct PropSpec {
+    name: Ident,
+    path: String,
+    ty: syn::Type,
+}
+
+impl Parse for PropSpec {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let name: Ident = input.parse()?;
+        let path = if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            input.parse::<syn::LitStr>()?.value()
+        } else {
+            camel_case(&name.to_string())
+        };
+        input.parse::<Token![:]>()?;
+        let ty = input.parse()?;
+        Ok(Self { name, path, ty })
+    }
+}
+
+/// `enable_undistort` -> `enableUndistort`, the naming of DepthAI-Core's serialized properties.
+fn camel_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    let mut upper = false;
+    for c in name.chars() {
+        if c == '_' {
+            upper = !out.is_empty();
+        } else if upper {
+            out.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// A port name with an optional message type (`name` or `name: Type`).
+struct PortSpec {
+    name: Ident,
+    ty: Option<syn::Type>,
+}
+
+impl Parse for PortSpec {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let name: Ident = input.parse()?;
+        let ty = if input.peek(Token![:]) {
+            input.parse::<Token![:]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        Ok(Self { name, ty })
+    }
 }
 
 impl Parse for NativeNodeArgs {
@@ -45,8 +115,9 @@ impl Parse for NativeNodeArgs {
         let mut native: Option<syn::LitStr> = None;
         let mut field: Option<Ident> = None;
         let mut gen_as_node: Option<bool> = None;
-        let mut inputs: Vec<Ident> = Vec::new();
-        let mut outputs: Vec<Ident> = Vec::new();
+        let mut inputs: Vec<PortSpec> = Vec::new();
+        let mut outputs: Vec<PortSpec> = Vec::new();
+        let mut props: Vec<PropSpec> = Vec::new();
 
         while !input.is_empty() {
             let key: Ident = input.parse()?;
@@ -63,17 +134,22 @@ impl Parse for NativeNodeArgs {
                 } else {
                     return Err(syn::Error::new_spanned(key, "unknown argument; expected `native`, `field`, or `as_node`"));
                 }
+            } else if input.peek(syn::token::Paren) && key == "props" {
+                let content;
+                syn::parenthesized!(content in input);
+                let specs = content.parse_terminated(PropSpec::parse, Token![,])?;
+                props.extend(specs);
             } else if input.peek(syn::token::Paren) {
                 let content;
                 syn::parenthesized!(content in input);
                 while !content.is_empty() {
-                    let id: Ident = content.parse()?;
+                    let port: PortSpec = content.parse()?;
                     if key == "inputs" {
-                        inputs.push(id);
+                        inputs.push(port);
                     } else if key == "outputs" {
-                        outputs.push(id);
+                        outputs.push(port);
                     } else {
-                        return Err(syn::Error::new_spanned(key, "unknown argument; expected `inputs` or `outputs`"));
+                        return Err(syn::Error::new_spanned(key, "unknown argument; expected `inputs`, `outputs` or `props`"));
                     }
                     if content.peek(Token![,]) {
                         content.parse::<Token![,]>()?;
@@ -96,6 +172,7 @@ impl Parse for NativeNodeArgs {
             gen_as_node: gen_as_node.unwrap_or(true),
             inputs,
             outputs,
+            props,
         })
     }
 }
@@ -146,22 +223,53 @@ fn expand_native_node(args: NativeNodeArgs, item_struct: ItemStruct) -> Result<T
     let inputs = args.inputs;
     let outputs = args.outputs;
 
-    let input_methods = inputs.iter().map(|id| {
+    let input_methods = inputs.iter().map(|port| {
+        let id = &port.name;
         let name = id.to_string();
-        quote! {
-            #[allow(non_snake_case)]
-            pub fn #id(&self) -> ::depthai::Result<::depthai::output::Input> {
-                self.as_node().input(#name)
-            }
+        match &port.ty {
+            Some(ty) => quote! {
+                #[allow(non_snake_case)]
+                pub fn #id(&self) -> ::depthai::Result<::depthai::output::TypedInput<#ty>> {
+                    self.as_node().input(#name).map(::depthai::output::TypedInput::from_untyped)
+                }
+            },
+            None => quote! {
+                #[allow(non_snake_case)]
+                pub fn #id(&self) -> ::depthai::Result<::depthai::output::Input> {
+                    self.as_node().input(#name)
+                }
+            },
         }
     });
 
-    let output_methods = outputs.iter().map(|id| {
+    let output_methods = outputs.iter().map(|port| {
+        let id = &port.name;
         let name = id.to_string();
+        match &port.ty {
+            Some(ty) => quote! {
+                #[allow(non_snake_case)]
+                pub fn #id(&self) -> ::depthai::Result<::depthai::output::TypedOutput<#ty>> {
+                    self.as_node().output(#name).map(::depthai::output::TypedOutput::from_untyped)
+                }
+            },
+            None => quote! {
+                #[allow(non_snake_case)]
+                pub fn #id(&self) -> ::depthai::Result<::depthai::output::Output> {
+                    self.as_node().output(#name)
+                }
+            },
+        }
+    });
+
+    let prop_methods = args.props.iter().map(|prop| {
+        let setter = syn::Ident::new(&format!("set_{}", prop.name), prop.name.span());
+        let path = &prop.path;
+        let ty = &prop.ty;
+        let doc = format!("Set the `{path}` property of the node.");
         quote! {
-            #[allow(non_snake_case)]
-            pub fn #id(&self) -> ::depthai::Result<::depthai::output::Output> {
-                self.as_node().output(#name)
+            #[doc = #doc]
+            pub fn #setter(&self, value: #ty) -> ::depthai::Result<()> {
+                self.as_node().set_property(#path, value)
             }
         }
     });
@@ -175,6 +283,7 @@ fn expand_native_node(args: NativeNodeArgs, item_struct: ItemStruct) -> Result<T
         impl #ty_ident {
             #(#input_methods)*
             #(#output_methods)*
+            #(#prop_methods)*
         }
 
         unsafe impl ::depthai::pipeline::DeviceNode for #ty_ident {
@@ -194,23 +303,258 @@ fn expand_native_node(args: NativeNodeArgs, item_struct: ItemStruct) -> Result<T
 /// and provides a higher-level API.
 /// 
 /// This macro implements `crate::pipeline::device_node::CreateInPipeline`
-/// by calling `Self::new(pipeline)`.
+/// by calling `Self::new(pipeline)`, or with `#[depthai_composite(default)]` by creating every
+/// field with `pipeline.create()`.
+///
+/// Helper attributes, placed below `#[depthai_composite]`:
+/// - `#[forward(output = "depth", from = "stereo")]` on the struct, or `#[forward(output = "depth")]`
+///   on the `stereo` field, generates `fn depth(&self)` returning that port of the member. Use
+///   `input = "..."` for inputs, `name = "..."` to rename the method and `ty = ImageFrame` to
+///   return a `TypedOutput` / `TypedInput`.
+/// - `#[link(from = "left.raw", to = "stereo.left")]` on the struct links two member ports once
+///   the members are created.
+///
+/// The links are made by `pipeline.create()`, after `new` returns. With `#[link]`s, keep `new`
+/// private so the composite cannot be built unlinked.
+///
+/// Members referenced by `forward` and `link` need an `as_node()` method, like the nodes
+/// generated by `native_node_wrapper`.
 #[proc_macro_attribute]
-pub fn depthai_composite(_args: TokenStream, item: TokenStream) -> TokenStream {
+pub fn depthai_composite(args: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args as CompositeArgs);
     let item_struct = parse_macro_input!(item as ItemStruct);
+
+    match expand_composite(args, item_struct) {
+        Ok(ts) => ts,
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+struct CompositeArgs {
+    default: bool,
+}
+
+impl Parse for CompositeArgs {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let mut default = false;
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+            if key == "default" {
+                default = true;
+            } else {
+                return Err(syn::Error::new_spanned(key, "unknown argument; expected `default`"));
+            }
+            if input.peek(Token![,]) {
+                input.parse::<Token![,]>()?;
+            }
+        }
+        Ok(Self { default })
+    }
+}
+
+/// `key = "value"` / `ty = Type` pairs of a helper attribute.
+struct HelperArgs {
+    strings: Vec<(Ident, syn::LitStr)>,
+    ty: Option<syn::Type>,
+}
+
+impl Parse for HelperArgs {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let mut strings = Vec::new();
+        let mut ty = None;
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            if key == "ty" {
+                ty = Some(input.parse()?);
+            } else {
+                strings.push((key, input.parse()?));
+            }
+            if input.peek(Token![,]) {
+                input.parse::<Token![,]>()?;
+            }
+        }
+        Ok(Self { strings, ty })
+    }
+}
+
+impl HelperArgs {
+    fn take(&mut self, key: &str) -> Option<syn::LitStr> {
+        let pos = self.strings.iter().position(|(k, _)| k == key)?;
+        Some(self.strings.remove(pos).1)
+    }
+
+    fn finish(self) -> Result<()> {
+        match self.strings.into_iter().next() {
+            Some((key, _)) => Err(syn::Error::new_spanned(key, "unknown argument")),
+            None => Ok(()),
+        }
+    }
+}
+
+/// A member port re-exported as a method of the composite.
+struct Forward {
+    method: Ident,
+    field: Ident,
+    port: String,
+    is_output: bool,
+    ty: Option<syn::Type>,
+}
+
+impl Forward {
+    fn parse(attr: &syn::Attribute, field: Option<&Ident>) -> Result<Self> {
+        let mut args: HelperArgs = attr.parse_args()?;
+        let (port, is_output) = match (args.take("output"), args.take("input")) {
+            (Some(port), None) => (port, true),
+            (None, Some(port)) => (port, false),
+            _ => return Err(syn::Error::new_spanned(attr, "expected exactly one of `output = \"...\"` or `input = \"...\"`")),
+        };
+        let field = match (args.take("from"), field) {
+            (Some(from), None) => from.parse()?,
+            (None, Some(field)) => field.clone(),
+            (Some(from), Some(_)) => return Err(syn::Error::new_spanned(from, "`from` is implied on fields")),
+            (None, None) => return Err(syn::Error::new_spanned(attr, "missing `from = \"<field>\"`")),
+        };
+        let method = match args.take("name") {
+            Some(name) => name.parse()?,
+            None => port.parse().map_err(|_| {
+                syn::Error::new_spanned(&port, "port name is not a valid method name; pass `name = \"...\"`")
+            })?,
+        };
+        let ty = args.ty.take();
+        args.finish()?;
+        Ok(Self {
+            method,
+            field,
+            port: port.value(),
+            is_output,
+            ty,
+        })
+    }
+}
+
+/// `field.port`, as used by `#[link]`.
+fn member_port(lit: &syn::LitStr) -> Result<(Ident, String)> {
+    let value = lit.value();
+    match value.split_once('.') {
+        Some((field, port)) if !port.is_empty() => {
+            let field: Ident = syn::parse_str(field).map_err(|_| syn::Error::new_spanned(lit, "invalid field name"))?;
+            Ok((Ident::new(&field.to_string(), lit.span()), port.to_string()))
+        }
+        _ => Err(syn::Error::new_spanned(lit, "expected `\"<field>.<port>\"`")),
+    }
+}
+
+fn is_helper(attr: &syn::Attribute, name: &str) -> bool {
+    attr.path().is_ident(name)
+}
+
+fn expand_composite(args: CompositeArgs, mut item_struct: ItemStruct) -> Result<TokenStream> {
     let ty_ident = item_struct.ident.clone();
+    let mut forwards = Vec::new();
+    let mut links = Vec::new();
+
+    for attr in &item_struct.attrs {
+        if is_helper(attr, "forward") {
+            forwards.push(Forward::parse(attr, None)?);
+        } else if is_helper(attr, "link") {
+            let mut link: HelperArgs = attr.parse_args()?;
+            let missing = |key| syn::Error::new_spanned(attr, format!("missing `{key} = \"<field>.<port>\"`"));
+            let from = member_port(&link.take("from").ok_or_else(|| missing("from"))?)?;
+            let to = member_port(&link.take("to").ok_or_else(|| missing("to"))?)?;
+            link.finish()?;
+            links.push((from, to));
+        }
+    }
+    item_struct.attrs.retain(|a| !is_helper(a, "forward") && !is_helper(a, "link"));
+
+    let syn::Fields::Named(named) = &mut item_struct.fields else {
+        return Err(syn::Error::new_spanned(&item_struct.ident, "composite nodes need named fields"));
+    };
+    let mut field_names = Vec::new();
+    for field in named.named.iter_mut() {
+        let ident = field.ident.clone().expect("named field");
+        for attr in field.attrs.iter().filter(|a| is_helper(a, "forward")) {
+            forwards.push(Forward::parse(attr, Some(&ident))?);
+        }
+        field.attrs.retain(|a| !is_helper(a, "forward"));
+        field_names.push(ident);
+    }
+
+    let known = |field: &Ident| -> Result<()> {
+        if field_names.contains(field) {
+            Ok(())
+        } else {
+            Err(syn::Error::new_spanned(field, format!("`{ty_ident}` has no field `{field}`")))
+        }
+    };
+    for forward in &forwards {
+        known(&forward.field)?;
+    }
+    for ((from, _), (to, _)) in &links {
+        known(from)?;
+        known(to)?;
+    }
+
+    let forward_methods = forwards.iter().map(|f| {
+        let (method, field, port) = (&f.method, &f.field, &f.port);
+        let doc = format!("The `{port}` {} of `{field}`.", if f.is_output { "output" } else { "input" });
+        let (getter, untyped, typed) = if f.is_output {
+            (quote! { output }, quote! { Output }, quote! { TypedOutput })
+        } else {
+            (quote! { input }, quote! { Input }, quote! { TypedInput })
+        };
+        let (ret, convert) = match &f.ty {
+            Some(ty) => (
+                quote! { ::depthai::output::#typed<#ty> },
+                quote! { .map(::depthai::output::#typed::from_untyped) },
+            ),
+            None => (quote! { ::depthai::output::#untyped }, quote! {}),
+        };
+        quote! {
+            #[doc = #doc]
+            #[allow(non_snake_case)]
+            pub fn #method(&self) -> ::depthai::Result<#ret> {
+                self.#field.as_node().#getter(#port)#convert
+            }
+        }
+    });
+
+    let link_stmts = links.iter().map(|((from, out), (to, input))| {
+        quote! {
+            self.#from.as_node().output(#out)?.link(&self.#to.as_node().input(#input)?)?;
+        }
+    });
+
+    let construct = if args.default {
+        quote! { Self { #(#field_names: pipeline.create()?),* } }
+    } else {
+        quote! { Self::new(pipeline)? }
+    };
 
     let expanded = quote! {
         #item_struct
 
+        impl #ty_ident {
+            #(#forward_methods)*
+
+            /// Make the `#[link]`s between the members. Called once by `pipeline.create()`.
+            fn link_members(&self) -> ::depthai::Result<()> {
+                #(#link_stmts)*
+                Ok(())
+            }
+        }
+
         impl ::depthai::pipeline::device_node::CreateInPipeline for #ty_ident {
             fn create(pipeline: &::depthai::pipeline::Pipeline) -> ::depthai::Result<Self> {
-                Self::new(pipeline)
+                let this = #construct;
+                this.link_members()?;
+                Ok(this)
             }
         }
     };
 
-    expanded.into()
+    Ok(expanded.into())
 }
 
 /// Attribute macro for defining Rust host nodes.
@@ -44,6 +44,9 @@ static BUILD_FOLDER_PATH: Lazy<PathBuf> = Lazy::new(|| {
     BASE_BUILD_FOLDER_PATH.join(tag)
 });
 
+// Root of the prebuilt SDK in use, if any (see `resolve_prebuilt_sdk`).
+static PREBUILT_SDK_ROOT: Lazy<RwLock<Option<PathBuf>>> = Lazy::new(|| RwLock::new(None));
+
 static GEN_FOLDER_PATH: Lazy<PathBuf> =
     Lazy::new(|| PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap()).join("generated"));
 
@@ -146,6 +149,57 @@ fn depthai_core_winprebuilt_url(tag: &str) -> String {
     )
 }
 
+fn depthai_core_linux_prebuilt_url(tag: &str) -> String {
+    // Same release layout as the Windows package:
+    //   https://github.com/luxonis/depthai-core/releases/download/<tag>/depthai-core-<tag>-linux-<arch>.zip
+    let tag = if tag.starts_with('v') {
+        tag.to_string()
+    } else {
+        format!("v{}", tag)
+    };
+    let arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_else(|_| "x86_64".to_string());
+    format!(
+        "https://github.com/luxonis/depthai-core/releases/download/{tag}/depthai-core-{tag}-linux-{arch}.zip"
+    )
+}
+
+/// File name of shared library `name` on the host platform (`libfoo.so` / `libfoo.dylib`).
+fn shared_lib_file(name: &str) -> String {
+    if cfg!(target_os = "macos") {
+        format!("lib{}.dylib", name)
+    } else {
+        format!("lib{}.so", name)
+    }
+}
+
+fn is_shared_lib_file(file_name: &str) -> bool {
+    if cfg!(target_os = "macos") {
+        file_name.ends_with(".dylib")
+    } else {
+        file_name.ends_with(".so") || file_name.contains(".so.")
+    }
+}
+
+/// rpath entry meaning "the directory of the binary being loaded".
+fn rpath_origin() -> &'static str {
+    if cfg!(target_os = "macos") { "@loader_path" } else { "$ORIGIN" }
+}
+
+/// Embed `dirs` as runtime search paths.
+///
+/// GNU ld takes a single `:`-separated RUNPATH (some toolchains keep only the last `-rpath`),
+/// while ld64 wants one `-rpath` per directory. Setting rpaths at link time, rather than
+/// patching binaries afterwards with `install_name_tool`, keeps macOS code signatures valid.
+fn emit_rpaths(dirs: &[String]) {
+    if cfg!(target_os = "macos") {
+        for dir in dirs {
+            println!("cargo:rustc-link-arg=-Wl,-rpath,{}", dir);
+        }
+    } else {
+        println!("cargo:rustc-link-arg=-Wl,-rpath,{}", dirs.join(":"));
+    }
+}
+
 fn no_native_build_enabled() -> bool {
     // docs.rs sets DOCS_RS=1 when building documentation.
     // We also expose an explicit `no-native` Cargo feature for local builds.
@@ -163,6 +217,8 @@ fn main() {
     println!("cargo:rerun-if-env-changed=DEPTHAI_DYNAMIC_CALIBRATION_SUPPORT");
     println!("cargo:rerun-if-env-changed=DEPTHAI_ENABLE_EVENTS_MANAGER");
     println!("cargo:rerun-if-env-changed=DEPTHAI_RPATH_DISABLE");
+    println!("cargo:rerun-if-env-changed=DEPTHAI_SYS_PREBUILT_DIR");
+    println!("cargo:rerun-if-env-changed=DEPTHAI_SYS_PREBUILT_URL");
     println_build!("Checking for depthai-core...");
 
     let no_native = no_native_build_enabled();
@@ -339,16 +395,17 @@ fn main() {
         // Ensure downstream binaries can resolve staged .so files when this crate is used as a
         // dependency. Linux does NOT search the executable directory by default.
         if env::var("DEPTHAI_RPATH_DISABLE").ok().as_deref() != Some("1") {
-            // Use $ORIGIN so binaries in target/<profile>/{deps,examples} can find the .so files
-            // we copy next to them.
-            println!("cargo:rustc-link-arg=-Wl,-rpath,$ORIGIN");
+            // Use $ORIGIN (@loader_path on macOS) so binaries in target/<profile>/{deps,examples}
+            // can find the shared libraries we copy next to them.
+            emit_rpaths(&[rpath_origin().to_string()]);
         }
 
         let depthai_core_lib = depthai_core_lib.expect("depthai-core path should be available when not in no-native mode");
 
         match depthai_core_lib.extension().and_then(|e| e.to_str()) {
-            Some("so") => {
-                let lib_name = "libdepthai-core.so";
+            Some("so") | Some("dylib") => {
+                let lib_name = shared_lib_file("depthai-core");
+                let lib_name = lib_name.as_str();
                 let dest_main = target_dir.join(lib_name);
                 if depthai_core_lib != dest_main {
                     fs::copy(&depthai_core_lib, &dest_main)
@@ -383,15 +440,18 @@ fn main() {
         // Even when DepthAI-Core itself is linked statically, some features (notably
         // Dynamic Calibration) and some vcpkg-provided deps (FFmpeg, libusb) are still
         // dynamically linked on Linux. Stage those .so files next to executables.
-        if cfg!(target_os = "linux") {
+        if cfg!(any(target_os = "linux", target_os = "macos")) {
             if stage_runtime_deps {
-                stage_linux_runtime_deps(target_dir, &deps_dir, &examples_dir);
+                if let Some(sdk) = prebuilt_sdk_root() {
+                    stage_prebuilt_shared_libs(&sdk, target_dir, &deps_dir, &examples_dir);
+                }
+                stage_unix_runtime_deps(target_dir, &deps_dir, &examples_dir);
             } else {
                 println_build!("DEPTHAI_STAGE_RUNTIME_DEPS=0: skipping runtime .so staging");
             }
         }
 
-        println_build!("Linux build configuration complete.");
+        println_build!("Unix build configuration complete.");
     }
 }
 
@@ -420,8 +480,16 @@ fn copy_matching_shared_libs_with_prefixes(
 
         // On Linux, the DT_NEEDED entry usually references the SONAME (e.g. libavcodec.so.60),
         // so we must copy versioned variants too. Matching by prefix handles both `libfoo.so`
-        // and `libfoo.so.<major>`.
-        if !prefixes.iter().any(|p| file_name.starts_with(p)) {
+        // and `libfoo.so.<major>`; on macOS the version comes first (`libfoo.60.dylib`).
+        let matches = if cfg!(target_os = "macos") {
+            file_name.ends_with(".dylib")
+                && prefixes
+                    .iter()
+                    .any(|p| file_name.starts_with(&format!("{}.", p.trim_end_matches(".so"))))
+        } else {
+            prefixes.iter().any(|p| file_name.starts_with(p))
+        };
+        if !matches {
             continue;
         }
 
@@ -439,13 +507,14 @@ fn copy_matching_shared_libs_with_prefixes(
     }
 }
 
-fn stage_linux_runtime_deps(target_dir: &Path, deps_dir: &Path, examples_dir: &Path) {
+fn stage_unix_runtime_deps(target_dir: &Path, deps_dir: &Path, examples_dir: &Path) {
     // 1) Dynamic calibration plugin (DepthAI-Core loads it dynamically).
     if let Some(dcl) = find_dynamic_calibration_so() {
         copy_so_to_run_dirs(&dcl, target_dir, deps_dir, examples_dir);
     } else {
         println_build!(
-            "Note: libdynamic_calibration.so not found in build tree; if your depthai-core build requires it, runtime loading may fail"
+            "Note: {} not found in build tree; if your depthai-core build requires it, runtime loading may fail",
+            shared_lib_file("dynamic_calibration")
         );
     }
 
@@ -496,7 +565,8 @@ fn copy_so_to_run_dirs(src: &Path, target_dir: &Path, deps_dir: &Path, examples_
 
 #[cfg(feature = "native")]
 fn find_dynamic_calibration_so() -> Option<PathBuf> {
-    let needle = "libdynamic_calibration.so";
+    let needle = shared_lib_file("dynamic_calibration");
+    let needle = needle.as_str();
 
     let candidates = [
         BUILD_FOLDER_PATH
@@ -761,7 +831,8 @@ fn build_with_autocxx(no_native: bool) -> Vec<PathBuf> {
     // Create builder
     // NOTE: `extra_clang_args` are used both for parsing (bindgen) and compiling the generated C++.
     // In `no-native` mode we define a macro that prevents pulling in DepthAI headers.
-    let builder = if cfg!(target_arch = "aarch64") {
+    // The GCC include dir is for aarch64 Linux; Apple Silicon uses the SDK's clang headers.
+    let builder = if cfg!(all(target_arch = "aarch64", target_os = "linux")) {
         if no_native {
             autocxx_build::Builder::new("src/lib.rs", &include_refs)
                 .extra_clang_args(&[
@@ -855,6 +926,27 @@ fn build_cpp_wrapper(include_paths: &[PathBuf], opencv_enabled: bool) {
         cc_build.include(include);
     }
 
+    if opencv_enabled {
+        // Expose the OpenCV parts of DepthAI's API (e.g. `ImgFrame::getCvFrame`) to the wrapper.
+        cc_build.define("DEPTHAI_HAVE_OPENCV_SUPPORT", None);
+        for include in include_paths {
+            let opencv_include = include.join("opencv4");
+            if opencv_include.exists() {
+                cc_build.include(opencv_include);
+            }
+        }
+        #[cfg(feature = "native")]
+        if cfg!(any(target_os = "linux", target_os = "macos")) {
+            if let Ok(lib) = PkgConfig::new().cargo_metadata(false).probe("opencv4") {
+                for include in lib.include_paths {
+                    cc_build.include(include);
+                }
+            } else if let Some(prefix) = homebrew_opencv_prefix() {
+                cc_build.include(prefix.join("include").join("opencv4"));
+            }
+        }
+    }
+
     cc_build.compile("depthai_wrapper");
     println_build!("C++ wrapper build completed.");
 }
@@ -898,6 +990,19 @@ fn get_depthai_includes() -> Vec<PathBuf> {
         println_build!("No depthai-core deps directory found, using core include.");
     }
 
+    // Installed SDKs ship the headers of depthai-core's public dependencies (nlohmann_json,
+    // libnop, xtensor, ...) next to the CMake package instead of in a `_deps` tree.
+    if let Some(sdk) = prebuilt_sdk_root() {
+        for dir in [
+            sdk.join("lib").join("cmake").join("depthai").join("dependencies").join("include"),
+            sdk.join("include").join("depthai-shared").join("3rdparty"),
+        ] {
+            if dir.exists() {
+                includes.push(dir);
+            }
+        }
+    }
+
     // Linux-only additional include
     if cfg!(target_os = "linux") {
         let bootloader = get_depthai_core_root()
@@ -1234,6 +1339,10 @@ fn resolve_depthai_core_lib() -> Result<PathBuf, &'static str> {
     let target_dir = Path::new(&out_dir).ancestors().nth(3).unwrap();
     let deps_dir = Path::new(&target_dir).join("deps");
 
+    if let Some(lib) = resolve_prebuilt_sdk()? {
+        return Ok(lib);
+    }
+
     if cfg!(target_os = "windows") {
         // On Windows (MSVC), linking must be done via the import library (.lib), not the DLL.
         // Prefer the import library next to the configured DEPTHAI_CORE_ROOT first.
@@ -1285,9 +1394,9 @@ fn resolve_depthai_core_lib() -> Result<PathBuf, &'static str> {
         }
     } else {
         // Shared explicitly requested.
-        let builds_lib = BUILD_FOLDER_PATH.join("libdepthai-core.so");
+        let builds_lib = BUILD_FOLDER_PATH.join(shared_lib_file("depthai-core"));
         if builds_lib.exists() {
-            println_build!("Found libdepthai-core.so in builds directory.");
+            println_build!("Found {} in builds directory.", builds_lib.display());
             emit_link_directives(&builds_lib);
             return Ok(builds_lib);
         }
@@ -1315,11 +1424,11 @@ fn resolve_depthai_core_lib() -> Result<PathBuf, &'static str> {
         println!("cargo:rustc-link-lib=depthai-core");
         return Ok(lib);
     } else if !prefer_static
-        && target_dir.join("libdepthai-core.so").exists()
+        && target_dir.join(shared_lib_file("depthai-core")).exists()
         && depthai_core_headers_present()
     {
         // Shared path only when explicitly requested.
-        let candidate = target_dir.join("libdepthai-core.so");
+        let candidate = target_dir.join(shared_lib_file("depthai-core"));
         println_build!("Found {} in OUT_DIR: {}", candidate.display(), target_dir.display());
         emit_link_directives(&candidate);
         return Ok(candidate);
@@ -1432,7 +1541,7 @@ Please point DEPTHAI_CORE_ROOT to a full depthai-core distribution (with include
                 panic!("Failed to find depthai-core after downloading prebuilt binary.");
             }
         }
-    } else if cfg!(target_os = "linux") {
+    } else if cfg!(any(target_os = "linux", target_os = "macos")) {
         if !get_depthai_core_root().exists() {
             let clone_path = BUILD_FOLDER_PATH.join("depthai-core");
 
@@ -1495,7 +1604,7 @@ fn probe_depthai_core_lib(out: PathBuf, prefer_static: bool) -> Option<PathBuf>
     } else if prefer_static {
         deps_dir.join("libdepthai-core.a")
     } else {
-        deps_dir.join("libdepthai-core.so")
+        deps_dir.join(shared_lib_file("depthai-core"))
     };
 
     println_build!(
@@ -1527,7 +1636,7 @@ fn probe_depthai_core_lib(out: PathBuf, prefer_static: bool) -> Option<PathBuf>
         match prob_res {
             Some(_) => {
                 println_build!("Found depthai-core via pkg-config.");
-                return Some(out.join("libdepthai-core.so"));
+                return Some(out.join(shared_lib_file("depthai-core")));
             }
             None => {
                 println_build!("depthai-core not found via pkg-config.");
@@ -1541,15 +1650,16 @@ fn probe_depthai_core_lib(out: PathBuf, prefer_static: bool) -> Option<PathBuf>
     }
 
     // Deterministic probing: prefer the requested artifact type first.
-    let preferred_names: &[&str] = if cfg!(target_os = "windows") {
-        &["depthai-core.dll", "depthai-core.lib"]
+    let shared_name = shared_lib_file("depthai-core");
+    let preferred_names: Vec<&str> = if cfg!(target_os = "windows") {
+        vec!["depthai-core.dll", "depthai-core.lib"]
     } else if prefer_static {
-        &["libdepthai-core.a", "libdepthai-core.so"]
+        vec!["libdepthai-core.a", &shared_name]
     } else {
-        &["libdepthai-core.so", "libdepthai-core.a"]
+        vec![&shared_name, "libdepthai-core.a"]
     };
 
-    for name in preferred_names {
+    for name in &preferred_names {
         if let Some(found) = WalkDir::new(&out)
             .into_iter()
             .filter_entry(|entry| {
@@ -1643,8 +1753,7 @@ fn cmake_build_depthai_core(path: PathBuf) -> Option<PathBuf> {
         .arg(&path)
         .arg("-DCMAKE_BUILD_TYPE=Release")
         .arg(format!("-DBUILD_SHARED_LIBS={}", if prefer_static { "OFF" } else { "ON" }))
-        .arg("-DCMAKE_C_COMPILER=/usr/bin/gcc")
-        .arg("-DCMAKE_CXX_COMPILER=/usr/bin/g++")
+        .args(cmake_compiler_args())
         // Ensure vcpkg manifest features are enabled (notably `opencv-support`).
         .arg("-DDEPTHAI_VCPKG_INTERNAL_ONLY:BOOL=OFF")
         .arg(format!(
@@ -1689,6 +1798,29 @@ fn cmake_build_depthai_core(path: PathBuf) -> Option<PathBuf> {
     probe_depthai_core_lib(path, prefer_static)
 }
 
+/// Compiler selection for the depthai-core CMake build.
+///
+/// Linux pins GCC. On macOS, AppleClang is the only supported toolchain and the build targets
+/// the Cargo target's architecture, so Intel and Apple Silicon hosts can cross-build each other.
+fn cmake_compiler_args() -> Vec<String> {
+    if cfg!(target_os = "macos") {
+        let arch = match env::var("CARGO_CFG_TARGET_ARCH").as_deref() {
+            Ok("aarch64") => "arm64",
+            _ => "x86_64",
+        };
+        vec![
+            "-DCMAKE_C_COMPILER=/usr/bin/clang".to_string(),
+            "-DCMAKE_CXX_COMPILER=/usr/bin/clang++".to_string(),
+            format!("-DCMAKE_OSX_ARCHITECTURES={}", arch),
+        ]
+    } else {
+        vec![
+            "-DCMAKE_C_COMPILER=/usr/bin/gcc".to_string(),
+            "-DCMAKE_CXX_COMPILER=/usr/bin/g++".to_string(),
+        ]
+    }
+}
+
 fn env_bool(key: &str) -> Option<bool> {
     match env::var(key) {
         Ok(value) => {
@@ -1874,10 +2006,161 @@ fn is_wsl() -> bool {
     false
 }
 
+fn prebuilt_sdk_root() -> Option<PathBuf> {
+    PREBUILT_SDK_ROOT.read().unwrap().clone()
+}
+
+/// Use a prebuilt depthai-core SDK instead of cloning and building it with CMake.
+///
+/// Sources, in order: `DEPTHAI_SYS_PREBUILT_DIR` (an unpacked SDK), `DEPTHAI_SYS_PREBUILT_URL`
+/// (a `.zip` of one), then the Linux release package for the selected tag. Returns `Ok(None)`
+/// when the source build should run instead: with the `vendored` feature, on Windows (which
+/// already uses the release package), or when the release package can't be downloaded.
+#[cfg(feature = "native")]
+fn resolve_prebuilt_sdk() -> Result<Option<PathBuf>, &'static str> {
+    if env::var_os("CARGO_FEATURE_VENDORED").is_some() {
+        println_build!("`vendored` feature enabled: building depthai-core from source");
+        return Ok(None);
+    }
+
+    let sdk = if let Some(dir) = env::var_os("DEPTHAI_SYS_PREBUILT_DIR") {
+        let dir = PathBuf::from(dir);
+        println_build!("Using prebuilt depthai-core SDK from DEPTHAI_SYS_PREBUILT_DIR: {}", dir.display());
+        dir
+    } else if let Ok(url) = env::var("DEPTHAI_SYS_PREBUILT_URL") {
+        match download_prebuilt_sdk(&url) {
+            Ok(dir) => dir,
+            Err(e) => panic!("Failed to fetch DEPTHAI_SYS_PREBUILT_URL ({}): {}", url, e),
+        }
+    } else if cfg!(target_os = "linux") {
+        let url = depthai_core_linux_prebuilt_url(&selected_depthai_core_tag());
+        match download_prebuilt_sdk(&url) {
+            Ok(dir) => dir,
+            Err(e) => {
+                println_build!(
+                    "No usable prebuilt depthai-core at {} ({}); falling back to a source build",
+                    url,
+                    e
+                );
+                return Ok(None);
+            }
+        }
+    } else {
+        return Ok(None);
+    };
+
+    let header = sdk.join("include").join("depthai").join("depthai.hpp");
+    if !header.exists() {
+        panic!(
+            "Prebuilt depthai-core SDK at '{}' is missing '{}'. Point DEPTHAI_SYS_PREBUILT_DIR to an installed SDK (with include/ and lib/) or enable the `vendored` feature to build from source.",
+            sdk.display(),
+            header.display()
+        );
+    }
+
+    let lib_dir = sdk.join("lib");
+    let candidates = if cfg!(target_os = "windows") {
+        vec![lib_dir.join("depthai-core.lib")]
+    } else {
+        // Shared first: a static archive would also need every transitive dependency, which an
+        // installed SDK doesn't carry the way depthai-core's own build tree does.
+        vec![lib_dir.join(shared_lib_file("depthai-core")), lib_dir.join("libdepthai-core.a")]
+    };
+    let lib = match candidates.into_iter().find(|p| p.exists()) {
+        Some(lib) => lib,
+        None => panic!(
+            "Prebuilt depthai-core SDK at '{}' has no depthai-core library in {}.",
+            sdk.display(),
+            lib_dir.display()
+        ),
+    };
+
+    *DEPTHAI_CORE_ROOT.write().unwrap() = sdk.clone();
+    *PREBUILT_SDK_ROOT.write().unwrap() = Some(sdk);
+
+    println_build!("Linking prebuilt depthai-core: {}", lib.display());
+    if cfg!(target_os = "windows") {
+        println!("cargo:rustc-link-search=native={}", lib_dir.display());
+        println!("cargo:rustc-link-lib=depthai-core");
+    } else {
+        emit_link_directives(&lib);
+    }
+    Ok(Some(lib))
+}
+
+/// Download and unpack a zipped SDK into the build folder, reusing a previous extraction.
+#[cfg(feature = "native")]
+fn download_prebuilt_sdk(url: &str) -> Result<PathBuf, String> {
+    let extracted_path = BUILD_FOLDER_PATH.join("depthai-core-prebuilt");
+    if let Some(sdk) = find_sdk_root(&extracted_path) {
+        println_build!("Reusing prebuilt depthai-core SDK at {}", sdk.display());
+        return Ok(sdk);
+    }
+
+    let zip_path = download_file(url, BUILD_FOLDER_PATH.as_path())?;
+    if extracted_path.exists() {
+        fs::remove_dir_all(&extracted_path)
+            .map_err(|e| format!("Failed to remove incomplete prebuilt dir: {}", e))?;
+    }
+    zip::zip_extract::zip_extract(&zip_path, &extracted_path)
+        .map_err(|e| format!("Failed to extract {}: {}", zip_path.display(), e))?;
+    let _ = fs::remove_file(&zip_path);
+
+    find_sdk_root(&extracted_path)
+        .ok_or_else(|| format!("{} does not contain an SDK", extracted_path.display()))
+}
+
+/// `dir` itself, or its single top-level folder, as release archives wrap the SDK in one.
+#[cfg(feature = "native")]
+fn find_sdk_root(dir: &Path) -> Option<PathBuf> {
+    if dir.join("include").exists() {
+        return Some(dir.to_path_buf());
+    }
+    let mut subdirs = fs::read_dir(dir).ok()?.flatten().map(|e| e.path()).filter(|p| p.is_dir());
+    match (subdirs.next(), subdirs.next()) {
+        (Some(inner), None) if inner.join("include").exists() => Some(inner),
+        _ => None,
+    }
+}
+
+/// Stage the shared libraries of a prebuilt SDK (depthai-core and its dependencies) next to
+/// executables, like the source build does for its own artifacts.
+fn stage_prebuilt_shared_libs(sdk: &Path, target_dir: &Path, deps_dir: &Path, examples_dir: &Path) {
+    let entries = match fs::read_dir(sdk.join("lib")) {
+        Ok(e) => e,
+        Err(_) => return,
+    };
+    for entry in entries.flatten() {
+        let src = entry.path();
+        let is_shared = src.file_name().and_then(|n| n.to_str()).is_some_and(is_shared_lib_file);
+        if src.is_file() && is_shared {
+            copy_so_to_run_dirs(&src, target_dir, deps_dir, examples_dir);
+        }
+    }
+}
+
 fn get_depthai_core_root() -> PathBuf {
     DEPTHAI_CORE_ROOT.read().unwrap().to_path_buf()
 }
 
+/// Install prefix of Homebrew's `opencv` formula, if present.
+fn homebrew_opencv_prefix() -> Option<PathBuf> {
+    if !cfg!(target_os = "macos") {
+        return None;
+    }
+    let from_brew = Command::new("brew")
+        .args(["--prefix", "opencv"])
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| PathBuf::from(String::from_utf8_lossy(&o.stdout).trim()));
+    // Default prefixes on Apple Silicon and Intel, for when `brew` isn't on PATH.
+    from_brew
+        .into_iter()
+        .chain([PathBuf::from("/opt/homebrew/opt/opencv"), PathBuf::from("/usr/local/opt/opencv")])
+        .find(|prefix| prefix.join("include").join("opencv4").exists())
+}
+
 fn vcpkg_lib_dir() -> Option<PathBuf> {
     let root = BUILD_FOLDER_PATH.join("vcpkg_installed");
     if !root.exists() {
@@ -1897,18 +2180,21 @@ fn vcpkg_lib_dir() -> Option<PathBuf> {
     let chosen = if let Some(target) = target {
         // Best-effort mapping: depthai-core's internal vcpkg uses triplet-like folder names.
         // Prefer the one that matches the current Rust target.
+        let os = if target.contains("apple") { "osx" } else { "linux" };
         if target.contains("aarch64") {
+            let triplet = format!("arm64-{}", os);
             candidates
                 .iter()
-                .find(|p| p.file_name().and_then(|n| n.to_str()) == Some("arm64-linux"))
+                .find(|p| p.file_name().and_then(|n| n.to_str()) == Some(triplet.as_str()))
                 .cloned()
         } else if target.contains("x86_64") {
+            let triplets = [format!("x64-{}", os), format!("x86_64-{}", os)];
             candidates
                 .iter()
                 .find(|p| {
                     p.file_name()
                         .and_then(|n| n.to_str())
-                        .is_some_and(|n| n == "x64-linux" || n == "x86_64-linux")
+                        .is_some_and(|n| triplets.iter().any(|t| t == n))
                 })
                 .cloned()
         } else {
@@ -1989,27 +2275,31 @@ fn emit_link_directives(path: &Path) {
             });
 
             // Only prefer system OpenCV if we *don't* have a vcpkg OpenCV build to match.
+            // On macOS, Homebrew's OpenCV is used even without pkg-config installed.
+            let homebrew_opencv = if vcpkg_opencv_available { None } else { homebrew_opencv_prefix() };
             let system_opencv_available = !vcpkg_opencv_available
                 && (cfg!(target_os = "linux") || cfg!(target_os = "macos"))
-                && PkgConfig::new()
-                    .cargo_metadata(false)
-                    .probe("opencv4")
-                    .is_ok();
+                && (homebrew_opencv.is_some()
+                    || PkgConfig::new()
+                        .cargo_metadata(false)
+                        .probe("opencv4")
+                        .is_ok());
+            if let Some(ref prefix) = homebrew_opencv {
+                println!("cargo:rustc-link-search=native={}", prefix.join("lib").display());
+            }
 
             if let Some(ref libdir) = vcpkg_lib {
                 println!("cargo:rustc-link-search=native={}", libdir.display());
 
                 // If we end up linking any shared libs from vcpkg (e.g. ffmpeg, libusb),
                 // set an rpath so binaries can run without manual LD_LIBRARY_PATH.
-                if cfg!(target_os = "linux") {
-                    // NOTE: On some toolchains, passing multiple `-Wl,-rpath,...` only keeps
-                    // the last value. Prefer a single RUNPATH containing both directories.
-                    let mut parts: Vec<String> = vec!["$ORIGIN".to_string()];
-                    if dcl_dir.join("libdynamic_calibration.so").exists() {
+                if cfg!(any(target_os = "linux", target_os = "macos")) {
+                    let mut parts: Vec<String> = vec![rpath_origin().to_string()];
+                    if dcl_dir.join(shared_lib_file("dynamic_calibration")).exists() {
                         parts.push(dcl_dir.display().to_string());
                     }
                     parts.push(libdir.display().to_string());
-                    println!("cargo:rustc-link-arg=-Wl,-rpath,{}", parts.join(":"));
+                    emit_rpaths(&parts);
                 }
             }
 
@@ -2068,7 +2358,7 @@ fn emit_link_directives(path: &Path) {
             }
 
             // Dynamic calibration.
-            if dcl_dir.join("libdynamic_calibration.so").exists() {
+            if dcl_dir.join(shared_lib_file("dynamic_calibration")).exists() {
                 println!("cargo:rustc-link-search=native={}", dcl_dir.display());
                 println!("cargo:rustc-link-lib=dynamic_calibration");
             }
@@ -2101,8 +2391,8 @@ fn emit_link_directives(path: &Path) {
                     }
                 };
 
-                let dylib_if_exists = |fname: &str, name: &str| {
-                    if libdir.join(fname).exists() {
+                let dylib_if_exists = |name: &str| {
+                    if libdir.join(shared_lib_file(name)).exists() {
                         println!("cargo:rustc-link-lib={}", name);
                     }
                 };
@@ -2217,17 +2507,17 @@ fn emit_link_directives(path: &Path) {
 
                 // OpenCV videoio can be built with FFmpeg; vcpkg provides these as shared libs.
                 if !system_opencv_available {
-                    dylib_if_exists("libavcodec.so", "avcodec");
-                    dylib_if_exists("libavformat.so", "avformat");
-                    dylib_if_exists("libavutil.so", "avutil");
-                    dylib_if_exists("libavfilter.so", "avfilter");
-                    dylib_if_exists("libavdevice.so", "avdevice");
-                    dylib_if_exists("libswscale.so", "swscale");
-                    dylib_if_exists("libswresample.so", "swresample");
+                    dylib_if_exists("avcodec");
+                    dylib_if_exists("avformat");
+                    dylib_if_exists("avutil");
+                    dylib_if_exists("avfilter");
+                    dylib_if_exists("avdevice");
+                    dylib_if_exists("swscale");
+                    dylib_if_exists("swresample");
                 }
 
                 // libusb is typically shared; link dynamically if present.
-                if libdir.join("libusb-1.0.so").exists() {
+                if libdir.join(shared_lib_file("usb-1.0")).exists() {
                     println!("cargo:rustc-link-lib=usb-1.0");
                 }
             }
@@ -2244,6 +2534,16 @@ fn emit_link_directives(path: &Path) {
                 println!("cargo:rustc-link-lib=dl");
                 println!("cargo:rustc-link-lib=m");
             }
+
+            // macOS system frameworks used by the static deps: libusb (IOKit), curl/OpenSSL
+            // (Security, SystemConfiguration) and CoreFoundation for both. ld64 has no
+            // --start-group/--whole-archive, so archives above are linked plainly; it resolves
+            // symbols across archives regardless of order.
+            if cfg!(target_os = "macos") {
+                for framework in ["CoreFoundation", "IOKit", "Security", "SystemConfiguration"] {
+                    println!("cargo:rustc-link-lib=framework={}", framework);
+                }
+            }
         }
         _ => {
             println!("cargo:rustc-link-lib=dylib=depthai-core");
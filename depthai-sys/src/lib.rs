@@ -18,6 +18,9 @@ include_cpp! {
     generate!("dai::dai_build_bootloader_version")
     generate!("dai::dai_build_device_rvc3_version")
     generate!("dai::dai_build_device_rvc4_version")
+    generate!("dai::dai_build_has_node")
+    generate!("dai::dai_build_has_opencv_support")
+    generate!("dai::dai_build_has_dynamic_calibration_support")
 
     // Device functions
     generate!("dai::dai_device_new")
@@ -25,7 +28,19 @@ include_cpp! {
     generate!("dai::dai_device_delete")
     generate!("dai::dai_device_is_closed")
     generate!("dai::dai_device_close")
+    generate!("dai::dai_device_get_native_id")
+    generate!("dai::dai_device_use_count")
+    generate!("dai::dai_device_new_with_config")
+    generate!("dai::dai_device_get_usb_speed")
+    generate!("dai::dai_device_reset")
+    generate!("dai::dai_device_get_all_available_json")
+    generate!("dai::dai_device_get_device_id")
+    generate!("dai::dai_device_get_bootloader_version")
+    generate!("dai::dai_device_get_chip_temperature")
+    generate!("dai::dai_device_flash_bootloader")
     generate!("dai::dai_device_get_connected_camera_sockets")
+    generate!("dai::dai_device_get_connected_camera_features_json")
+    generate!("dai::dai_device_get_stereo_pairs_json")
     generate!("dai::dai_pipeline_new_with_device")
 
     // Pipeline functions
@@ -58,6 +73,10 @@ include_cpp! {
     generate!("dai::dai_pipeline_is_calibration_data_available")
     generate!("dai::dai_pipeline_get_calibration_data_json")
     generate!("dai::dai_pipeline_set_calibration_data_json")
+    generate!("dai::dai_device_read_calibration_json")
+    generate!("dai::dai_device_read_factory_calibration_json")
+    generate!("dai::dai_device_flash_calibration_json")
+    generate!("dai::dai_calibration_get_camera_intrinsics")
     generate!("dai::dai_pipeline_get_global_properties_json")
     generate!("dai::dai_pipeline_set_global_properties_json")
     generate!("dai::dai_pipeline_get_board_config_json")
@@ -78,10 +97,18 @@ include_cpp! {
     generate!("dai::dai_pipeline_create_node_by_name")
     generate!("dai::dai_node_get_output")
     generate!("dai::dai_node_get_input")
+    generate!("dai::dai_node_get_input_in_map")
+    generate!("dai::dai_node_get_output_in_map")
     generate!("dai::dai_node_get_id")
     generate!("dai::dai_node_get_alias")
     generate!("dai::dai_node_set_alias")
+    generate!("dai::dai_node_get_log_level")
+    generate!("dai::dai_node_set_log_level")
     generate!("dai::dai_node_get_name")
+    generate!("dai::dai_node_get_inputs_json")
+    generate!("dai::dai_node_get_io_json")
+    generate!("dai::dai_node_get_properties_json")
+    generate!("dai::dai_node_set_property_json")
     generate!("dai::dai_output_link")
     generate!("dai::dai_output_link_input")
     generate!("dai::dai_node_link")
@@ -92,6 +119,7 @@ include_cpp! {
     generate!("dai::dai_hostnode_run_sync_on_host")
     generate!("dai::dai_hostnode_run_sync_on_device")
     generate!("dai::dai_hostnode_send_processing_to_pipeline")
+    generate!("dai::dai_hostnode_process_on_arrival")
     generate!("dai::dai_threaded_hostnode_create_input")
     generate!("dai::dai_threaded_hostnode_create_output")
     generate!("dai::dai_threaded_node_is_running")
@@ -99,6 +127,8 @@ include_cpp! {
     // Device helpers
     generate!("dai::dai_device_get_platform")
     generate!("dai::dai_device_set_ir_laser_dot_projector_intensity")
+    generate!("dai::dai_device_set_ir_flood_light_intensity")
+    generate!("dai::dai_device_get_ir_driver_count")
 
     // StereoDepth configuration helpers
     generate!("dai::dai_stereo_set_subpixel")
@@ -109,16 +139,57 @@ include_cpp! {
     generate!("dai::dai_stereo_enable_distortion_correction")
     generate!("dai::dai_stereo_set_output_size")
     generate!("dai::dai_stereo_set_output_keep_aspect_ratio")
+    generate!("dai::dai_stereo_set_depth_align")
+    generate!("dai::dai_stereo_set_depth_align_socket")
+    generate!("dai::dai_stereo_set_rectification")
+    generate!("dai::dai_stereo_set_alpha_scaling")
+    generate!("dai::dai_stereo_set_focal_length_from_calibration")
+    generate!("dai::dai_stereo_set_input_resolution")
+    generate!("dai::dai_stereo_set_baseline")
+    generate!("dai::dai_stereo_set_focal_length")
     generate!("dai::dai_stereo_initial_set_left_right_check_threshold")
     generate!("dai::dai_stereo_initial_set_threshold_filter_max_range")
+    generate!("dai::dai_stereo_set_post_processing_hardware_resources")
 
     // RGBD configuration helpers
     generate!("dai::dai_rgbd_set_depth_unit")
+    generate!("dai::dai_rgbd_get_depth_unit")
+    generate!("dai::dai_rgbd_set_output_fps")
+    generate!("dai::dai_rgbd_get_output_fps")
+    generate!("dai::dai_rgbd_set_sparse")
+    generate!("dai::dai_rgbd_get_sparse")
 
     // ImageAlign helpers
     generate!("dai::dai_image_align_set_run_on_host")
     generate!("dai::dai_image_align_set_output_size")
     generate!("dai::dai_image_align_set_out_keep_aspect_ratio")
+    generate!("dai::dai_image_align_set_interpolation")
+    generate!("dai::dai_image_align_set_num_shaves")
+    generate!("dai::dai_image_align_set_num_frames_pool")
+    generate!("dai::dai_image_align_run_on_host")
+    generate!("dai::dai_image_align_config_new")
+    generate!("dai::dai_image_align_get_initial_config")
+    generate!("dai::dai_image_align_config_set_static_depth_plane")
+    generate!("dai::dai_image_align_config_get_static_depth_plane")
+    generate!("dai::dai_neural_depth_build")
+    generate!("dai::dai_neural_depth_config_new")
+    generate!("dai::dai_neural_depth_get_initial_config")
+    generate!("dai::dai_neural_depth_config_set_confidence_threshold")
+    generate!("dai::dai_neural_depth_config_get_confidence_threshold")
+    generate!("dai::dai_neural_depth_config_set_edge_threshold")
+    generate!("dai::dai_neural_depth_config_get_edge_threshold")
+    generate!("dai::dai_neural_depth_config_set_depth_unit")
+    generate!("dai::dai_neural_depth_config_get_depth_unit")
+    generate!("dai::dai_neural_depth_config_set_custom_depth_unit_multiplier")
+    generate!("dai::dai_neural_depth_config_get_custom_depth_unit_multiplier")
+    generate!("dai::dai_image_filters_set_run_on_host")
+    generate!("dai::dai_image_filters_run_on_host")
+    generate!("dai::dai_image_filters_set_default_profile_preset")
+    generate!("dai::dai_image_filters_config_new")
+    generate!("dai::dai_image_filters_get_initial_config")
+    generate!("dai::dai_image_filters_config_set_filter_json")
+    generate!("dai::dai_image_filters_config_set_profile_preset")
+    generate!("dai::dai_image_filters_config_get_filters_json")
 
     // ImageManip helpers
     generate!("dai::dai_image_manip_set_num_frames_pool")
@@ -130,6 +201,8 @@ include_cpp! {
     generate!("dai::dai_image_manip_run")
     generate!("dai::dai_image_manip_config_new")
     generate!("dai::dai_image_manip_get_initial_config")
+    generate!("dai::dai_image_manip_config_copy")
+    generate!("dai::dai_image_manip_config_reset")
     generate!("dai::dai_image_manip_config_clear_ops")
     generate!("dai::dai_image_manip_config_add_crop_xywh")
     generate!("dai::dai_image_manip_config_add_crop_rect")
@@ -180,6 +253,122 @@ include_cpp! {
     generate!("dai::dai_video_encoder_set_max_output_frame_size")
     generate!("dai::dai_video_encoder_get_max_output_frame_size")
 
+    // Cast node helpers
+    generate!("dai::dai_record_video_set_record_video_file")
+    generate!("dai::dai_record_video_set_record_metadata_file")
+    generate!("dai::dai_record_metadata_only_set_record_file")
+    generate!("dai::dai_replay_video_set_replay_video_file")
+    generate!("dai::dai_replay_video_set_replay_metadata_file")
+    generate!("dai::dai_replay_metadata_only_set_replay_file")
+    generate!("dai::dai_replay_video_set_loop")
+    generate!("dai::dai_replay_metadata_only_set_loop")
+    generate!("dai::dai_script_set_script")
+    generate!("dai::dai_sync_set_sync_threshold_ns")
+    generate!("dai::dai_sync_set_sync_attempts")
+    generate!("dai::dai_cast_set_num_frames_pool")
+    generate!("dai::dai_cast_set_output_frame_type")
+    generate!("dai::dai_cast_set_scale")
+    generate!("dai::dai_cast_set_offset")
+    generate!("dai::dai_cast_set_num_shaves")
+
+    // UVC node helpers
+    generate!("dai::dai_uvc_set_gpios_on_init")
+    generate!("dai::dai_uvc_set_gpios_on_stream_on")
+    generate!("dai::dai_uvc_set_gpios_on_stream_off")
+
+    // SPIOut / SPIIn node helpers
+    generate!("dai::dai_spi_out_set_stream_name")
+    generate!("dai::dai_spi_out_set_bus_id")
+    generate!("dai::dai_spi_in_set_stream_name")
+    generate!("dai::dai_spi_in_set_bus_id")
+    generate!("dai::dai_spi_in_set_max_data_size")
+    generate!("dai::dai_spi_in_set_num_frames")
+    generate!("dai::dai_spi_in_get_stream_name")
+    generate!("dai::dai_spi_in_get_bus_id")
+    generate!("dai::dai_spi_in_get_max_data_size")
+    generate!("dai::dai_spi_in_get_num_frames")
+
+    // SpatialLocationCalculator node, config and data
+    generate!("dai::dai_spatial_location_config_new")
+    generate!("dai::dai_spatial_location_calculator_get_initial_config")
+    generate!("dai::dai_spatial_location_calculator_set_wait_for_config_input")
+    generate!("dai::dai_spatial_location_config_clear_rois")
+    generate!("dai::dai_spatial_location_config_add_roi")
+    generate!("dai::dai_spatial_location_config_get_num_rois")
+    generate!("dai::dai_spatial_location_config_get_roi")
+    generate!("dai::dai_datatype_as_spatial_location_data")
+    generate!("dai::dai_spatial_location_data_get_count")
+    generate!("dai::dai_spatial_location_data_get_roi")
+    generate!("dai::dai_spatial_location_data_get_location")
+    generate!("dai::dai_spatial_location_data_release")
+
+    // Generic input reads and ImgDetections
+    generate!("dai::dai_input_get_datatype")
+    generate!("dai::dai_input_try_get_datatype")
+    generate!("dai::dai_datatype_as_img_detections")
+    generate!("dai::dai_img_detections_get_count")
+    generate!("dai::dai_img_detections_get")
+    generate!("dai::dai_img_detections_get_timestamp_ns")
+    generate!("dai::dai_img_detections_set_timestamp_ns")
+    generate!("dai::dai_img_detections_get_sequence_num")
+    generate!("dai::dai_img_detections_set_sequence_num")
+    generate!("dai::dai_img_detections_release")
+
+    // Model zoo
+    generate!("dai::dai_model_zoo_get_model")
+    generate!("dai::dai_model_zoo_download_models")
+
+    // NNArchive / NeuralNetwork / DetectionNetwork
+    generate!("dai::dai_nn_archive_new")
+    generate!("dai::dai_nn_archive_release")
+    generate!("dai::dai_nn_archive_get_info_json")
+    generate!("dai::dai_neural_network_set_nn_archive")
+    generate!("dai::dai_neural_network_set_num_inference_threads")
+    generate!("dai::dai_detection_network_set_nn_archive")
+    generate!("dai::dai_detection_network_set_confidence_threshold")
+    generate!("dai::dai_neural_network_set_num_shaves_per_inference_thread")
+    generate!("dai::dai_neural_network_set_num_nce_per_inference_thread")
+    generate!("dai::dai_neural_network_set_num_pool_frames")
+    generate!("dai::dai_detection_network_set_num_inference_threads")
+    generate!("dai::dai_detection_network_set_num_shaves_per_inference_thread")
+    generate!("dai::dai_detection_network_set_num_nce_per_inference_thread")
+    generate!("dai::dai_datatype_as_nn_data")
+    generate!("dai::dai_message_group_get_nn_data")
+    generate!("dai::dai_nn_data_get_layers_json")
+    generate!("dai::dai_nn_data_get_tensor_f32")
+    generate!("dai::dai_nn_data_get_timestamp_ns")
+    generate!("dai::dai_nn_data_get_sequence_num")
+    generate!("dai::dai_nn_data_release")
+    generate!("dai::dai_img_detections_new")
+    generate!("dai::dai_img_detections_push")
+    generate!("dai::dai_img_detections_copy_metadata")
+    generate!("dai::dai_img_detections_to_buffer")
+    generate!("dai::dai_output_send_img_detections")
+    generate!("dai::dai_img_annotations_from_json")
+    generate!("dai::dai_detection_parser_set_nn_archive")
+    generate!("dai::dai_detection_parser_set_confidence_threshold")
+    generate!("dai::dai_detection_parser_set_num_classes")
+    generate!("dai::dai_detection_parser_set_iou_threshold")
+
+    // Input receive with timeout
+    generate!("dai::dai_input_get_datatype_timeout")
+
+    // Device log callbacks
+    generate!("dai::dai_device_add_log_callback")
+    generate!("dai::dai_device_remove_log_callback")
+
+    // CameraControl helpers
+    generate!("dai::dai_camera_control_new")
+    generate!("dai::dai_camera_control_set_auto_exposure_region")
+    generate!("dai::dai_camera_control_set_auto_exposure_limit")
+    generate!("dai::dai_camera_control_set_auto_exposure_lock")
+    generate!("dai::dai_camera_control_set_auto_focus_mode")
+    generate!("dai::dai_camera_control_set_auto_focus_trigger")
+    generate!("dai::dai_camera_control_set_auto_white_balance_lock")
+    generate!("dai::dai_camera_control_set_streaming")
+    generate!("dai::dai_camera_control_set_frame_sync_mode")
+    generate!("dai::dai_camera_control_set_external_trigger")
+
     // Camera functions
     generate!("dai::dai_camera_request_output")
     generate!("dai::dai_camera_request_full_resolution_output")
@@ -190,6 +379,7 @@ include_cpp! {
     generate!("dai::dai_camera_get_max_height")
     generate!("dai::dai_camera_set_sensor_type")
     generate!("dai::dai_camera_get_sensor_type")
+    generate!("dai::dai_camera_set_frame_sync_mode")
     generate!("dai::dai_camera_set_raw_num_frames_pool")
     generate!("dai::dai_camera_set_max_size_pool_raw")
     generate!("dai::dai_camera_set_isp_num_frames_pool")
@@ -209,6 +399,24 @@ include_cpp! {
     generate!("dai::dai_output_create_queue")
     generate!("dai::dai_queue_delete")
 
+    // Port introspection / configuration
+    generate!("dai::dai_output_get_name")
+    generate!("dai::dai_output_get_group")
+    generate!("dai::dai_output_get_possible_datatypes_json")
+    generate!("dai::dai_output_can_connect")
+    generate!("dai::dai_output_get_parent")
+    generate!("dai::dai_input_get_parent")
+    generate!("dai::dai_output_get_connections_json")
+    generate!("dai::dai_input_get_name")
+    generate!("dai::dai_input_get_group")
+    generate!("dai::dai_input_get_possible_datatypes_json")
+    generate!("dai::dai_input_set_blocking")
+    generate!("dai::dai_input_get_blocking")
+    generate!("dai::dai_input_set_max_size")
+    generate!("dai::dai_input_get_max_size")
+    generate!("dai::dai_input_set_reuse_previous_message")
+    generate!("dai::dai_input_get_reuse_previous_message")
+
     // Generic queue controls / status
     generate!("dai::dai_queue_get_name")
     generate!("dai::dai_queue_set_name")
@@ -250,6 +458,7 @@ include_cpp! {
     // Generic datatype helpers
     generate!("dai::dai_datatype_release")
     generate!("dai::dai_datatype_clone")
+    generate!("dai::dai_datatype_get_native_id")
     generate!("dai::dai_datatype_get_datatype_enum")
     generate!("dai::dai_datatype_as_img_frame")
     generate!("dai::dai_datatype_as_encoded_frame")
@@ -257,6 +466,9 @@ include_cpp! {
     generate!("dai::dai_datatype_as_rgbd")
     generate!("dai::dai_datatype_as_buffer")
     generate!("dai::dai_datatype_as_message_group")
+    generate!("dai::dai_datatype_get_timestamp_ns")
+    generate!("dai::dai_datatype_get_timestamp_device_ns")
+    generate!("dai::dai_datatype_get_sequence_num")
     generate!("dai::dai_datatype_array_len")
     generate!("dai::dai_datatype_array_take")
     generate!("dai::dai_datatype_array_free")
@@ -267,6 +479,26 @@ include_cpp! {
     generate!("dai::dai_frame_get_size")
     generate!("dai::dai_frame_release")
 
+    // OpenCV interop
+    generate!("dai::dai_frame_get_stride")
+    generate!("dai::dai_frame_get_cv_frame")
+    generate!("dai::dai_cv_mat_get_data")
+    generate!("dai::dai_cv_mat_get_rows")
+    generate!("dai::dai_cv_mat_get_cols")
+    generate!("dai::dai_cv_mat_get_type")
+    generate!("dai::dai_cv_mat_get_step")
+    generate!("dai::dai_cv_mat_release")
+
+    // IMU node and IMUData
+    generate!("dai::dai_imu_enable_sensor")
+    generate!("dai::dai_imu_set_batch_report_threshold")
+    generate!("dai::dai_imu_set_max_batch_reports")
+    generate!("dai::dai_datatype_as_imu_data")
+    generate!("dai::dai_imu_data_get_num_packets")
+    generate!("dai::dai_imu_data_get_report")
+    generate!("dai::dai_imu_data_release")
+    generate!("dai::dai_pointcloud_get_timestamp_ns")
+
     // EncodedFrame accessors
     generate!("dai::dai_encoded_frame_get_data")
     generate!("dai::dai_encoded_frame_get_data_size")
@@ -280,6 +512,8 @@ include_cpp! {
     generate!("dai::dai_encoded_frame_get_bitrate")
     generate!("dai::dai_encoded_frame_get_lossless")
     generate!("dai::dai_encoded_frame_get_instance_num")
+    generate!("dai::dai_encoded_frame_get_timestamp_ns")
+    generate!("dai::dai_encoded_frame_get_sequence_num")
     generate!("dai::dai_encoded_frame_release")
 
     // PointCloudData accessors
@@ -304,6 +538,8 @@ include_cpp! {
     generate!("dai::dai_input_create_input_queue")
     generate!("dai::dai_input_queue_delete")
     generate!("dai::dai_input_queue_send")
+    generate!("dai::dai_input_queue_send_img_frame")
+    generate!("dai::dai_input_queue_send_buffer")
 
     // Output send helpers
     generate!("dai::dai_output_send_buffer")
@@ -319,6 +555,30 @@ include_cpp! {
     generate!("dai::dai_buffer_new")
     generate!("dai::dai_buffer_release")
     generate!("dai::dai_buffer_set_data")
+    generate!("dai::dai_buffer_get_data")
+    generate!("dai::dai_buffer_get_size")
+
+    // Host-created message helpers
+    generate!("dai::dai_frame_new")
+    generate!("dai::dai_frame_set_data")
+    generate!("dai::dai_buffer_use_count")
+    generate!("dai::dai_frame_use_count")
+    generate!("dai::dai_buffer_write_data")
+    generate!("dai::dai_frame_write_data")
+    generate!("dai::dai_buffer_set_timestamp_ns")
+    generate!("dai::dai_buffer_get_timestamp_ns")
+    generate!("dai::dai_buffer_set_timestamp_now")
+    generate!("dai::dai_buffer_set_sequence_num")
+    generate!("dai::dai_buffer_get_sequence_num")
+    generate!("dai::dai_frame_set_timestamp_ns")
+    generate!("dai::dai_frame_get_timestamp_ns")
+    generate!("dai::dai_frame_set_timestamp_now")
+    generate!("dai::dai_frame_set_sequence_num")
+    generate!("dai::dai_frame_get_sequence_num")
+    generate!("dai::dai_clock_now_ns")
+    generate!("dai::dai_frame_get_timestamp_device_ns")
+    generate!("dai::dai_frame_get_instance_num")
+    generate!("dai::dai_frame_get_intrinsic_matrix")
 
     // Utilities
     generate!("dai::dai_camera_socket_name")
@@ -347,6 +607,11 @@ pub type DaiRGBDData = *mut autocxx::c_void;
 pub type DaiMessageGroup = *mut autocxx::c_void;
 pub type DaiBuffer = *mut autocxx::c_void;
 pub type DaiInputQueue = *mut autocxx::c_void;
+pub type DaiImuData = *mut autocxx::c_void;
+pub type DaiSpatialLocationData = *mut autocxx::c_void;
+pub type DaiImgDetections = *mut autocxx::c_void;
+pub type DaiNNArchive = *mut autocxx::c_void;
+pub type DaiNNData = *mut autocxx::c_void;
 
 pub mod string_utils;
 
@@ -7,6 +7,8 @@
 #include "depthai/pipeline/datatype/PointCloudData.hpp"
 #include "depthai/pipeline/datatype/RGBDData.hpp"
 #include "depthai/pipeline/datatype/EncodedFrame.hpp"
+#include "depthai/modelzoo/Zoo.hpp"
+#include "depthai/pipeline/node/ImageFilters.hpp"
 #include "XLink/XLink.h"
 #include "XLink/XLinkPublicDefines.h"
 
@@ -26,20 +28,36 @@
     #else
         #define DAI_HAS_NODE_NEURAL_DEPTH 0
     #endif
+
+    #if __has_include(<depthai/pipeline/node/host/Record.hpp>) && __has_include(<depthai/pipeline/node/host/Replay.hpp>)
+        #include <depthai/pipeline/node/host/Record.hpp>
+        #include <depthai/pipeline/node/host/Replay.hpp>
+        #define DAI_HAS_NODE_RECORD_REPLAY 1
+    #else
+        #define DAI_HAS_NODE_RECORD_REPLAY 0
+    #endif
 #else
     #define DAI_HAS_NODE_RECTIFICATION 0
     #define DAI_HAS_NODE_NEURAL_DEPTH 0
+    #define DAI_HAS_NODE_RECORD_REPLAY 0
 #endif
+
+#include <algorithm>
 #include <chrono>
+#include <cmath>
 #include <cstring>
 #include <cstdlib>
 #include <limits>
 #include <memory>
 #include <mutex>
 #include <optional>
+#include <stdexcept>
 #include <string>
+#include <thread>
 #include <type_traits>
+#include <typeindex>
 #include <unordered_map>
+#include <variant>
 #include <functional>
 
 // Global error storage
@@ -114,6 +132,32 @@ class RustHostNode : public dai::NodeCRTP<dai::node::HostNode, RustHostNode> {
         return out;
     }
 
+    // Hand each synced group to `process` as it reaches the internal `in` input, instead of
+    // from a node thread waiting on it. `run` then returns right away, so the node keeps no
+    // thread of its own (input syncing still runs as usual).
+    void processOnArrival() {
+        for(auto* input : getInputRefs()) {
+            if(!input || input->getName() != "in") continue;
+            // Nothing reads the queue itself; keep it from ever blocking the sender.
+            input->setBlocking(false);
+            input->setMaxSize(1);
+            input->addCallback([this](std::string, std::shared_ptr<dai::ADatatype> msg) {
+                if(auto group = std::dynamic_pointer_cast<dai::MessageGroup>(msg)) {
+                    processGroup(std::move(group));
+                }
+            });
+            onArrival = true;
+            return;
+        }
+        throw std::runtime_error("host node has no internal 'in' input");
+    }
+
+    void run() override {
+        if(!onArrival) {
+            dai::node::HostNode::run();
+        }
+    }
+
     void onStart() override {
         if(callbacks.on_start) {
             callbacks.on_start(ctx);
@@ -129,6 +173,7 @@ class RustHostNode : public dai::NodeCRTP<dai::node::HostNode, RustHostNode> {
    private:
     HostNodeCallbacks callbacks;
     void* ctx = nullptr;
+    bool onArrival = false;
 };
 
 class RustThreadedHostNode : public dai::NodeCRTP<dai::node::ThreadedHostNode, RustThreadedHostNode> {
@@ -207,6 +252,36 @@ static bool select_first_device_info(dai::DeviceInfo& out) {
     return false;
 }
 
+// Devices XLink can see, queried per state for the same reason as above.
+static std::vector<dai::DeviceInfo> all_device_infos() {
+    const XLinkDeviceState_t states[] = {
+        X_LINK_UNBOOTED,
+        X_LINK_BOOTLOADER,
+        X_LINK_FLASH_BOOTED,
+        X_LINK_GATE,
+        X_LINK_GATE_SETUP,
+        X_LINK_BOOTED,
+    };
+    std::vector<dai::DeviceInfo> out;
+    for(const auto state : states) {
+        try {
+            auto devices = dai::XLinkConnection::getAllConnectedDevices(state, /*skipInvalidDevices=*/true);
+            out.insert(out.end(), devices.begin(), devices.end());
+        } catch(...) {
+            // Ignore and continue to next state.
+        }
+    }
+    return out;
+}
+
+// Find the device whose ID (MXID) or name (USB path / IP address) is `id`.
+static dai::DeviceInfo find_device_info(const std::string& id) {
+    for(const auto& info : all_device_infos()) {
+        if(info.getDeviceId() == id || info.name == id) return info;
+    }
+    throw std::runtime_error("device " + id + " not found");
+}
+
 namespace dai {
 
 const char* dai_build_version() {
@@ -248,6 +323,20 @@ const char* dai_build_device_rvc3_version() {
 const char* dai_build_device_rvc4_version() {
     return dai::build::DEVICE_RVC4_VERSION;
 }
+bool dai_build_has_opencv_support() {
+#ifdef DEPTHAI_HAVE_OPENCV_SUPPORT
+    return true;
+#else
+    return false;
+#endif
+}
+bool dai_build_has_dynamic_calibration_support() {
+#ifdef DEPTHAI_HAVE_DYNAMIC_CALIBRATION_SUPPORT
+    return true;
+#else
+    return false;
+#endif
+}
 
 // Basic string utilities
 char* dai_string_to_cstring(const char* str) {
@@ -307,6 +396,40 @@ DaiDevice dai_device_new() {
     }
 }
 
+DaiDevice dai_device_new_with_config(int max_usb_speed,
+                                     int watchdog_timeout_ms,
+                                     int watchdog_initial_delay_ms,
+                                     bool non_exclusive,
+                                     const char* firmware_path,
+                                     const char* device_id) {
+    try {
+        dai_clear_last_error();
+        dai::DeviceBase::Config config;
+        config.nonExclusiveMode = non_exclusive;
+        config.board.usb.maxSpeed =
+            max_usb_speed >= 0 ? static_cast<dai::UsbSpeed>(max_usb_speed) : dai::DeviceBase::DEFAULT_USB_SPEED;
+        if(watchdog_timeout_ms >= 0) {
+            config.board.watchdogTimeoutMs = static_cast<uint32_t>(watchdog_timeout_ms);
+        }
+        if(watchdog_initial_delay_ms >= 0) {
+            config.board.watchdogInitialDelayMs = static_cast<uint32_t>(watchdog_initial_delay_ms);
+        }
+
+        dai::DeviceInfo info;
+        if(device_id) {
+            info = find_device_info(device_id);
+        } else if(!select_first_device_info(info)) {
+            throw std::runtime_error("No available devices");
+        }
+        auto created = firmware_path ? std::make_shared<dai::Device>(config, info, std::filesystem::u8path(firmware_path))
+                                     : std::make_shared<dai::Device>(config, info);
+        return static_cast<DaiDevice>(new std::shared_ptr<dai::Device>(created));
+    } catch (const std::exception& e) {
+        last_error = std::string("dai_device_new_with_config failed: ") + e.what();
+        return nullptr;
+    }
+}
+
 DaiDevice dai_device_clone(DaiDevice device) {
     if(!device) {
         last_error = "dai_device_clone: null device";
@@ -353,6 +476,18 @@ bool dai_device_is_closed(DaiDevice device) {
     }
 }
 
+uintptr_t dai_device_get_native_id(DaiDevice device) {
+    if(!device) return 0;
+    auto dev = static_cast<std::shared_ptr<dai::Device>*>(device);
+    return reinterpret_cast<uintptr_t>(dev->get());
+}
+
+int dai_device_use_count(DaiDevice device) {
+    if(!device) return 0;
+    auto dev = static_cast<std::shared_ptr<dai::Device>*>(device);
+    return static_cast<int>(dev->use_count());
+}
+
 void dai_device_close(DaiDevice device) {
     if (!device) {
         last_error = "dai_device_close: null device";
@@ -370,6 +505,138 @@ void dai_device_close(DaiDevice device) {
     }
 }
 
+bool dai_device_reset(DaiDevice device, int timeout_ms) {
+    if(!device) {
+        last_error = "dai_device_reset: null device";
+        return false;
+    }
+    try {
+        auto dev = static_cast<std::shared_ptr<dai::Device>*>(device);
+        if(!dev->get() || !(*dev)) {
+            last_error = "dai_device_reset: invalid device";
+            return false;
+        }
+        // Closing the connection resets the device; it then re-enumerates waiting to be booted.
+        const auto device_id = (*dev)->getDeviceId();
+        (*dev)->close();
+        const auto deadline = std::chrono::steady_clock::now() + std::chrono::milliseconds(std::max(timeout_ms, 0));
+        do {
+            for(const auto state : {X_LINK_UNBOOTED, X_LINK_BOOTLOADER, X_LINK_GATE}) {
+                for(const auto& info : dai::XLinkConnection::getAllConnectedDevices(state, /*skipInvalidDevices=*/true)) {
+                    if(info.getDeviceId() == device_id) return true;
+                }
+            }
+            std::this_thread::sleep_for(std::chrono::milliseconds(100));
+        } while(std::chrono::steady_clock::now() < deadline);
+        last_error = "dai_device_reset: device " + device_id + " did not come back after reset";
+        return false;
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_device_reset failed: ") + e.what();
+        return false;
+    }
+}
+
+char* dai_device_get_all_available_json() {
+    try {
+        dai_clear_last_error();
+        nlohmann::json j = nlohmann::json::array();
+        for(const auto& info : all_device_infos()) {
+            nlohmann::json item;
+            item["deviceId"] = info.getDeviceId();
+            item["name"] = info.name;
+            item["state"] = XLinkDeviceStateToStr(info.state);
+            item["protocol"] = XLinkProtocolToStr(info.protocol);
+            item["platform"] = XLinkPlatformToStr(info.platform);
+            j.push_back(std::move(item));
+        }
+        auto dumped = j.dump();
+        return dai_string_to_cstring(dumped.c_str());
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_device_get_all_available_json failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+char* dai_device_get_device_id(DaiDevice device) {
+    if(!device) {
+        last_error = "dai_device_get_device_id: null device";
+        return nullptr;
+    }
+    try {
+        auto dev = static_cast<std::shared_ptr<dai::Device>*>(device);
+        if(!dev->get() || !(*dev)) {
+            last_error = "dai_device_get_device_id: invalid device";
+            return nullptr;
+        }
+        return dai_string_to_cstring((*dev)->getDeviceId().c_str());
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_device_get_device_id failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+char* dai_device_get_bootloader_version(DaiDevice device) {
+    if(!device) {
+        last_error = "dai_device_get_bootloader_version: null device";
+        return nullptr;
+    }
+    try {
+        auto dev = static_cast<std::shared_ptr<dai::Device>*>(device);
+        if(!dev->get() || !(*dev)) {
+            last_error = "dai_device_get_bootloader_version: invalid device";
+            return nullptr;
+        }
+        const auto version = (*dev)->getBootloaderVersion();
+        return dai_string_to_cstring(version ? version->toString().c_str() : "");
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_device_get_bootloader_version failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+bool dai_device_get_chip_temperature(DaiDevice device, float* out_temperatures) {
+    if(!device || !out_temperatures) {
+        last_error = "dai_device_get_chip_temperature: null argument";
+        return false;
+    }
+    try {
+        auto dev = static_cast<std::shared_ptr<dai::Device>*>(device);
+        if(!dev->get() || !(*dev)) {
+            last_error = "dai_device_get_chip_temperature: invalid device";
+            return false;
+        }
+        const auto t = (*dev)->getChipTemperature();
+        out_temperatures[0] = t.css;
+        out_temperatures[1] = t.mss;
+        out_temperatures[2] = t.upa;
+        out_temperatures[3] = t.dss;
+        out_temperatures[4] = t.average;
+        return true;
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_device_get_chip_temperature failed: ") + e.what();
+        return false;
+    }
+}
+
+bool dai_device_flash_bootloader(const char* device_id) {
+    if(!device_id) {
+        last_error = "dai_device_flash_bootloader: null device id";
+        return false;
+    }
+    try {
+        dai_clear_last_error();
+        dai::DeviceBootloader bootloader(find_device_info(device_id), /*allowFlashingBootloader=*/true);
+        const auto [ok, message] = bootloader.flashBootloader([](float) {});
+        if(!ok) {
+            last_error = "dai_device_flash_bootloader: " + message;
+        }
+        return ok;
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_device_flash_bootloader failed: ") + e.what();
+        return false;
+    }
+}
+
 // Low-level pipeline operations
 DaiPipeline dai_pipeline_new() {
     try {
@@ -453,9 +720,15 @@ DaiNode dai_rgbd_build_ex(DaiNode rgbd, bool autocreate, int preset_mode, int wi
     }
 }
 
+static void _dai_rgbd_forget_nodes(dai::Pipeline& pipeline);
+
 void dai_pipeline_delete(DaiPipeline pipeline) {
     if (pipeline) {
         auto pipe = static_cast<dai::Pipeline*>(pipeline);
+        try {
+            _dai_rgbd_forget_nodes(*pipe);
+        } catch(const std::exception&) {
+        }
         delete pipe;
     }
 }
@@ -710,6 +983,7 @@ char* dai_pipeline_get_all_nodes_json(DaiPipeline pipeline) {
             item["id"] = n->id;
             item["alias"] = n->getAlias();
             item["name"] = std::string(n->getName());
+            item["runOnHost"] = n->runOnHost();
             j.push_back(std::move(item));
         }
         auto dumped = j.dump();
@@ -736,6 +1010,7 @@ char* dai_pipeline_get_source_nodes_json(DaiPipeline pipeline) {
             item["id"] = n->id;
             item["alias"] = n->getAlias();
             item["name"] = std::string(n->getName());
+            item["runOnHost"] = n->runOnHost();
             j.push_back(std::move(item));
         }
         auto dumped = j.dump();
@@ -926,6 +1201,85 @@ bool dai_pipeline_set_calibration_data_json(DaiPipeline pipeline, const char* ee
     }
 }
 
+char* dai_device_read_calibration_json(DaiDevice device) {
+    if(!device) {
+        last_error = "dai_device_read_calibration_json: null device";
+        return nullptr;
+    }
+    try {
+        auto dev = static_cast<std::shared_ptr<dai::Device>*>(device);
+        if(!dev->get() || !(*dev)) {
+            last_error = "dai_device_read_calibration_json: invalid device";
+            return nullptr;
+        }
+        auto dumped = (*dev)->readCalibration().eepromToJson().dump();
+        return dai_string_to_cstring(dumped.c_str());
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_device_read_calibration_json failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+char* dai_device_read_factory_calibration_json(DaiDevice device) {
+    if(!device) {
+        last_error = "dai_device_read_factory_calibration_json: null device";
+        return nullptr;
+    }
+    try {
+        auto dev = static_cast<std::shared_ptr<dai::Device>*>(device);
+        if(!dev->get() || !(*dev)) {
+            last_error = "dai_device_read_factory_calibration_json: invalid device";
+            return nullptr;
+        }
+        auto dumped = (*dev)->readFactoryCalibration().eepromToJson().dump();
+        return dai_string_to_cstring(dumped.c_str());
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_device_read_factory_calibration_json failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+bool dai_device_flash_calibration_json(DaiDevice device, const char* eeprom_data_json) {
+    if(!device || !eeprom_data_json) {
+        last_error = "dai_device_flash_calibration_json: null argument";
+        return false;
+    }
+    try {
+        auto dev = static_cast<std::shared_ptr<dai::Device>*>(device);
+        if(!dev->get() || !(*dev)) {
+            last_error = "dai_device_flash_calibration_json: invalid device";
+            return false;
+        }
+        auto calib = dai::CalibrationHandler::fromJson(nlohmann::json::parse(eeprom_data_json));
+        // Throws with the device's reason when the write is rejected.
+        (*dev)->flashCalibration(calib);
+        return true;
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_device_flash_calibration_json failed: ") + e.what();
+        return false;
+    }
+}
+
+bool dai_calibration_get_camera_intrinsics(const char* eeprom_data_json, int socket, int width, int height, bool keep_aspect_ratio, float* out_matrix) {
+    if(!eeprom_data_json || !out_matrix) {
+        last_error = "dai_calibration_get_camera_intrinsics: null argument";
+        return false;
+    }
+    try {
+        auto calib = dai::CalibrationHandler::fromJson(nlohmann::json::parse(eeprom_data_json));
+        auto m = calib.getCameraIntrinsics(static_cast<dai::CameraBoardSocket>(socket), width, height, dai::Point2f(), dai::Point2f(), keep_aspect_ratio);
+        for(size_t r = 0; r < 3; ++r) {
+            for(size_t c = 0; c < 3; ++c) {
+                out_matrix[r * 3 + c] = m.at(r).at(c);
+            }
+        }
+        return true;
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_calibration_get_camera_intrinsics failed: ") + e.what();
+        return false;
+    }
+}
+
 char* dai_pipeline_get_global_properties_json(DaiPipeline pipeline) {
     if(!pipeline) {
         last_error = "dai_pipeline_get_global_properties_json: null pipeline";
@@ -1250,6 +1604,7 @@ static std::unordered_map<std::string, NodeCreator>& get_node_registry() {
         REGISTER_NODE(dai::node::SpatialDetectionNetwork);
         REGISTER_NODE(dai::node::BenchmarkIn);
         REGISTER_NODE(dai::node::BenchmarkOut);
+        REGISTER_NODE(dai::node::Cast);
 
     #if DAI_HAS_NODE_RECTIFICATION
         REGISTER_NODE(dai::node::Rectification);
@@ -1265,6 +1620,18 @@ static std::unordered_map<std::string, NodeCreator>& get_node_registry() {
         REGISTER_NODE(dai::node::SPIOut);
         REGISTER_NODE(dai::node::Thermal);
 
+    #ifdef DEPTHAI_HAVE_OPENCV_SUPPORT
+        // Without OpenCV, DepthAI-Core only has stubs for it (see image_filters_stub.cpp).
+        REGISTER_NODE(dai::node::ImageFilters);
+    #endif
+
+    #if DAI_HAS_NODE_RECORD_REPLAY
+        REGISTER_NODE(dai::node::RecordVideo);
+        REGISTER_NODE(dai::node::RecordMetadataOnly);
+        REGISTER_NODE(dai::node::ReplayVideo);
+        REGISTER_NODE(dai::node::ReplayMetadataOnly);
+    #endif
+
         // XLink nodes are in internal namespace but we expose them as dai::node::XLinkIn/Out
         registry["dai::node::XLinkIn"] = [](dai::Pipeline* p) { return p->create<dai::node::internal::XLinkIn>().get(); };
         registry["dai::node::XLinkOut"] = [](dai::Pipeline* p) { return p->create<dai::node::internal::XLinkOut>().get(); };
@@ -1272,6 +1639,114 @@ static std::unordered_map<std::string, NodeCreator>& get_node_registry() {
     return registry;
 }
 
+// Properties of device nodes, as JSON, by the node's dynamic type.
+struct PropertiesAccess {
+    std::function<nlohmann::json(dai::Node*)> get;
+    std::function<void(dai::Node*, const nlohmann::json&)> set;
+};
+
+template <typename N>
+static PropertiesAccess _dai_properties_access() {
+    return {
+        [](dai::Node* n) { return nlohmann::json(static_cast<N*>(n)->properties); },
+        [](dai::Node* n, const nlohmann::json& j) {
+            static_cast<N*>(n)->properties = j.get<typename N::Properties>();
+        },
+    };
+}
+
+#define REGISTER_PROPERTIES(name) registry[std::type_index(typeid(name))] = _dai_properties_access<name>()
+
+static std::unordered_map<std::type_index, PropertiesAccess>& get_properties_registry() {
+    static std::unordered_map<std::type_index, PropertiesAccess> registry;
+    if(registry.empty()) {
+        REGISTER_PROPERTIES(dai::node::Camera);
+        REGISTER_PROPERTIES(dai::node::ColorCamera);
+        REGISTER_PROPERTIES(dai::node::MonoCamera);
+        REGISTER_PROPERTIES(dai::node::StereoDepth);
+        REGISTER_PROPERTIES(dai::node::ImageAlign);
+        REGISTER_PROPERTIES(dai::node::VideoEncoder);
+        REGISTER_PROPERTIES(dai::node::NeuralNetwork);
+        REGISTER_PROPERTIES(dai::node::ImageManip);
+        REGISTER_PROPERTIES(dai::node::SystemLogger);
+        REGISTER_PROPERTIES(dai::node::SpatialLocationCalculator);
+        REGISTER_PROPERTIES(dai::node::FeatureTracker);
+        REGISTER_PROPERTIES(dai::node::ObjectTracker);
+        REGISTER_PROPERTIES(dai::node::IMU);
+        REGISTER_PROPERTIES(dai::node::EdgeDetector);
+        REGISTER_PROPERTIES(dai::node::Warp);
+        REGISTER_PROPERTIES(dai::node::AprilTag);
+        REGISTER_PROPERTIES(dai::node::DetectionParser);
+        REGISTER_PROPERTIES(dai::node::PointCloud);
+        REGISTER_PROPERTIES(dai::node::Sync);
+        REGISTER_PROPERTIES(dai::node::MessageDemux);
+        REGISTER_PROPERTIES(dai::node::Cast);
+        REGISTER_PROPERTIES(dai::node::SpatialDetectionNetwork);
+        REGISTER_PROPERTIES(dai::node::BenchmarkIn);
+        REGISTER_PROPERTIES(dai::node::BenchmarkOut);
+        REGISTER_PROPERTIES(dai::node::UVC);
+        REGISTER_PROPERTIES(dai::node::SPIIn);
+        REGISTER_PROPERTIES(dai::node::SPIOut);
+        REGISTER_PROPERTIES(dai::node::Thermal);
+    }
+    return registry;
+}
+
+static const PropertiesAccess& _dai_properties_of(dai::Node* node) {
+    auto& registry = get_properties_registry();
+    auto it = registry.find(std::type_index(typeid(*node)));
+    if(it == registry.end()) {
+        throw std::invalid_argument(std::string("node '") + node->getName() + "' has no JSON-accessible properties");
+    }
+    return it->second;
+}
+
+char* dai_node_get_properties_json(DaiNode node) {
+    if(!node) {
+        last_error = "dai_node_get_properties_json: null node";
+        return nullptr;
+    }
+    try {
+        auto n = static_cast<dai::Node*>(node);
+        auto dumped = _dai_properties_of(n).get(n).dump();
+        return dai_string_to_cstring(dumped.c_str());
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_node_get_properties_json failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+bool dai_node_set_property_json(DaiNode node, const char* path, const char* value_json) {
+    if(!node || !path || !value_json) {
+        last_error = "dai_node_set_property_json: null node, path or value";
+        return false;
+    }
+    try {
+        auto n = static_cast<dai::Node*>(node);
+        const auto& access = _dai_properties_of(n);
+        auto properties = access.get(n);
+        nlohmann::json::json_pointer pointer(path);
+        if(!properties.contains(pointer)) {
+            last_error = std::string("dai_node_set_property_json: node '") + n->getName() + "' has no property '" + path + "'";
+            return false;
+        }
+        properties[pointer] = nlohmann::json::parse(value_json);
+        access.set(n, properties);
+        return true;
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_node_set_property_json failed: ") + e.what();
+        return false;
+    }
+}
+
+bool dai_build_has_node(const char* name) {
+    if(!name) {
+        return false;
+    }
+    auto& registry = get_node_registry();
+    return registry.find(name) != registry.end();
+}
+
 DaiNode dai_pipeline_create_node_by_name(DaiPipeline pipeline, const char* name) {
     if (!pipeline || !name) {
         last_error = "dai_pipeline_create_node_by_name: null pipeline or name";
@@ -1343,27 +1818,75 @@ DaiInput dai_node_get_input(DaiNode node, const char* group, const char* name) {
     }
 }
 
-int dai_node_get_id(DaiNode node) {
+DaiInput dai_node_get_input_in_map(DaiNode node, const char* map_name, const char* key) {
     if(!node) {
-        last_error = "dai_node_get_id: null node";
-        return -1;
+        last_error = "dai_node_get_input_in_map: null node";
+        return nullptr;
+    }
+    if(_dai_cstr_empty(map_name) || _dai_cstr_empty(key)) {
+        last_error = "dai_node_get_input_in_map: empty map name or key";
+        return nullptr;
     }
     try {
-        dai_clear_last_error();
         auto n = static_cast<dai::Node*>(node);
-        return n->id;
+        for(auto* map : n->getInputMapRefs()) {
+            if(map && map->name == map_name) {
+                return static_cast<DaiInput>(&(*map)[std::string(key)]);
+            }
+        }
+        last_error = std::string("dai_node_get_input_in_map: node has no input map '") + map_name + "'";
+        return nullptr;
     } catch(const std::exception& e) {
-        last_error = std::string("dai_node_get_id failed: ") + e.what();
-        return -1;
+        last_error = std::string("dai_node_get_input_in_map failed: ") + e.what();
+        return nullptr;
     }
 }
 
-char* dai_node_get_alias(DaiNode node) {
+DaiOutput dai_node_get_output_in_map(DaiNode node, const char* map_name, const char* key) {
     if(!node) {
-        last_error = "dai_node_get_alias: null node";
+        last_error = "dai_node_get_output_in_map: null node";
         return nullptr;
     }
-    try {
+    if(_dai_cstr_empty(map_name) || _dai_cstr_empty(key)) {
+        last_error = "dai_node_get_output_in_map: empty map name or key";
+        return nullptr;
+    }
+    try {
+        auto n = static_cast<dai::Node*>(node);
+        for(auto* map : n->getOutputMapRefs()) {
+            if(map && map->name == map_name) {
+                return static_cast<DaiOutput>(&(*map)[std::string(key)]);
+            }
+        }
+        last_error = std::string("dai_node_get_output_in_map: node has no output map '") + map_name + "'";
+        return nullptr;
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_node_get_output_in_map failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+int dai_node_get_id(DaiNode node) {
+    if(!node) {
+        last_error = "dai_node_get_id: null node";
+        return -1;
+    }
+    try {
+        dai_clear_last_error();
+        auto n = static_cast<dai::Node*>(node);
+        return n->id;
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_node_get_id failed: ") + e.what();
+        return -1;
+    }
+}
+
+char* dai_node_get_alias(DaiNode node) {
+    if(!node) {
+        last_error = "dai_node_get_alias: null node";
+        return nullptr;
+    }
+    try {
         dai_clear_last_error();
         auto n = static_cast<dai::Node*>(node);
         auto s = n->getAlias();
@@ -1394,6 +1917,45 @@ bool dai_node_set_alias(DaiNode node, const char* alias) {
     }
 }
 
+int dai_node_get_log_level(DaiNode node) {
+    if(!node) {
+        last_error = "dai_node_get_log_level: null node";
+        return -1;
+    }
+    try {
+        dai_clear_last_error();
+        auto threaded = dynamic_cast<dai::ThreadedNode*>(static_cast<dai::Node*>(node));
+        if(!threaded) {
+            last_error = "dai_node_get_log_level: node is not a ThreadedNode";
+            return -1;
+        }
+        return static_cast<int>(threaded->getLogLevel());
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_node_get_log_level failed: ") + e.what();
+        return -1;
+    }
+}
+
+bool dai_node_set_log_level(DaiNode node, int level) {
+    if(!node) {
+        last_error = "dai_node_set_log_level: null node";
+        return false;
+    }
+    try {
+        dai_clear_last_error();
+        auto threaded = dynamic_cast<dai::ThreadedNode*>(static_cast<dai::Node*>(node));
+        if(!threaded) {
+            last_error = "dai_node_set_log_level: node is not a ThreadedNode";
+            return false;
+        }
+        threaded->setLogLevel(static_cast<dai::LogLevel>(level));
+        return true;
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_node_set_log_level failed: ") + e.what();
+        return false;
+    }
+}
+
 char* dai_node_get_name(DaiNode node) {
     if(!node) {
         last_error = "dai_node_get_name: null node";
@@ -1512,6 +2074,24 @@ int dai_device_get_platform(DaiDevice device) {
     }
 }
 
+int dai_device_get_usb_speed(DaiDevice device) {
+    if(!device) {
+        last_error = "dai_device_get_usb_speed: null device";
+        return -1;
+    }
+    try {
+        auto dev = static_cast<std::shared_ptr<dai::Device>*>(device);
+        if(!dev->get() || !(*dev)) {
+            last_error = "dai_device_get_usb_speed: invalid device";
+            return -1;
+        }
+        return static_cast<int>((*dev)->getUsbSpeed());
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_device_get_usb_speed failed: ") + e.what();
+        return -1;
+    }
+}
+
 void dai_device_set_ir_laser_dot_projector_intensity(DaiDevice device, float intensity) {
     if(!device) {
         last_error = "dai_device_set_ir_laser_dot_projector_intensity: null device";
@@ -1529,6 +2109,41 @@ void dai_device_set_ir_laser_dot_projector_intensity(DaiDevice device, float int
     }
 }
 
+void dai_device_set_ir_flood_light_intensity(DaiDevice device, float intensity) {
+    if(!device) {
+        last_error = "dai_device_set_ir_flood_light_intensity: null device";
+        return;
+    }
+    try {
+        auto dev = static_cast<std::shared_ptr<dai::Device>*>(device);
+        if(!dev->get() || !(*dev)) {
+            last_error = "dai_device_set_ir_flood_light_intensity: invalid device";
+            return;
+        }
+        (*dev)->setIrFloodLightIntensity(intensity);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_device_set_ir_flood_light_intensity failed: ") + e.what();
+    }
+}
+
+int dai_device_get_ir_driver_count(DaiDevice device) {
+    if(!device) {
+        last_error = "dai_device_get_ir_driver_count: null device";
+        return -1;
+    }
+    try {
+        auto dev = static_cast<std::shared_ptr<dai::Device>*>(device);
+        if(!dev->get() || !(*dev)) {
+            last_error = "dai_device_get_ir_driver_count: invalid device";
+            return -1;
+        }
+        return static_cast<int>((*dev)->getIrDrivers().size());
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_device_get_ir_driver_count failed: ") + e.what();
+        return -1;
+    }
+}
+
 static inline dai::node::StereoDepth* _dai_as_stereo(DaiNode stereo) {
     return static_cast<dai::node::StereoDepth*>(stereo);
 }
@@ -1629,6 +2244,102 @@ void dai_stereo_set_output_keep_aspect_ratio(DaiNode stereo, bool keep) {
     }
 }
 
+void dai_stereo_set_depth_align(DaiNode stereo, int align) {
+    if(!stereo) {
+        last_error = "dai_stereo_set_depth_align: null stereo";
+        return;
+    }
+    try {
+        _dai_as_stereo(stereo)->setDepthAlign(static_cast<dai::StereoDepthProperties::DepthAlign>(align));
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_stereo_set_depth_align failed: ") + e.what();
+    }
+}
+
+void dai_stereo_set_depth_align_socket(DaiNode stereo, int socket) {
+    if(!stereo) {
+        last_error = "dai_stereo_set_depth_align_socket: null stereo";
+        return;
+    }
+    try {
+        _dai_as_stereo(stereo)->setDepthAlign(static_cast<dai::CameraBoardSocket>(socket));
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_stereo_set_depth_align_socket failed: ") + e.what();
+    }
+}
+
+void dai_stereo_set_rectification(DaiNode stereo, bool enable) {
+    if(!stereo) {
+        last_error = "dai_stereo_set_rectification: null stereo";
+        return;
+    }
+    try {
+        _dai_as_stereo(stereo)->setRectification(enable);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_stereo_set_rectification failed: ") + e.what();
+    }
+}
+
+void dai_stereo_set_alpha_scaling(DaiNode stereo, float alpha) {
+    if(!stereo) {
+        last_error = "dai_stereo_set_alpha_scaling: null stereo";
+        return;
+    }
+    try {
+        _dai_as_stereo(stereo)->setAlphaScaling(alpha);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_stereo_set_alpha_scaling failed: ") + e.what();
+    }
+}
+
+void dai_stereo_set_focal_length_from_calibration(DaiNode stereo, bool enable) {
+    if(!stereo) {
+        last_error = "dai_stereo_set_focal_length_from_calibration: null stereo";
+        return;
+    }
+    try {
+        _dai_as_stereo(stereo)->setFocalLengthFromCalibration(enable);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_stereo_set_focal_length_from_calibration failed: ") + e.what();
+    }
+}
+
+void dai_stereo_set_input_resolution(DaiNode stereo, int width, int height) {
+    if(!stereo) {
+        last_error = "dai_stereo_set_input_resolution: null stereo";
+        return;
+    }
+    try {
+        _dai_as_stereo(stereo)->setInputResolution(width, height);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_stereo_set_input_resolution failed: ") + e.what();
+    }
+}
+
+void dai_stereo_set_baseline(DaiNode stereo, float baseline_cm) {
+    if(!stereo) {
+        last_error = "dai_stereo_set_baseline: null stereo";
+        return;
+    }
+    try {
+        _dai_as_stereo(stereo)->setBaseline(baseline_cm);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_stereo_set_baseline failed: ") + e.what();
+    }
+}
+
+void dai_stereo_set_focal_length(DaiNode stereo, float focal_length_px) {
+    if(!stereo) {
+        last_error = "dai_stereo_set_focal_length: null stereo";
+        return;
+    }
+    try {
+        _dai_as_stereo(stereo)->setFocalLength(focal_length_px);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_stereo_set_focal_length failed: ") + e.what();
+    }
+}
+
 void dai_stereo_initial_set_left_right_check_threshold(DaiNode stereo, int threshold) {
     if(!stereo) {
         last_error = "dai_stereo_initial_set_left_right_check_threshold: null stereo";
@@ -1663,6 +2374,29 @@ void dai_stereo_initial_set_threshold_filter_max_range(DaiNode stereo, int max_r
     }
 }
 
+void dai_stereo_set_post_processing_hardware_resources(DaiNode stereo, int num_shaves, int num_memory_slices) {
+    if(!stereo) {
+        last_error = "dai_stereo_set_post_processing_hardware_resources: null stereo";
+        return;
+    }
+    try {
+        _dai_as_stereo(stereo)->setPostProcessingHardwareResources(num_shaves, num_memory_slices);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_stereo_set_post_processing_hardware_resources failed: ") + e.what();
+    }
+}
+
+// dai::node::RGBD has no depth unit getter nor output rate / sparse options, so remember what
+// was set per node. Queues created on its outputs go through an RgbdOutputFilter applying them.
+struct RgbdSettings {
+    int depth_unit = static_cast<int>(dai::StereoDepthConfig::AlgorithmControl::DepthUnit::MILLIMETER);
+    float output_fps = 0.0f;
+    bool sparse = false;
+};
+
+static std::mutex g_rgbd_settings_mutex;
+static std::unordered_map<const void*, RgbdSettings> g_rgbd_settings;
+
 void dai_rgbd_set_depth_unit(DaiNode rgbd, int depth_unit) {
     if(!rgbd) {
         last_error = "dai_rgbd_set_depth_unit: null rgbd";
@@ -1671,11 +2405,135 @@ void dai_rgbd_set_depth_unit(DaiNode rgbd, int depth_unit) {
     try {
         auto r = static_cast<dai::node::RGBD*>(rgbd);
         r->setDepthUnit(static_cast<dai::StereoDepthConfig::AlgorithmControl::DepthUnit>(depth_unit));
+        std::lock_guard<std::mutex> lock(g_rgbd_settings_mutex);
+        g_rgbd_settings[rgbd].depth_unit = depth_unit;
     } catch(const std::exception& e) {
         last_error = std::string("dai_rgbd_set_depth_unit failed: ") + e.what();
     }
 }
 
+int dai_rgbd_get_depth_unit(DaiNode rgbd) {
+    if(!rgbd) {
+        last_error = "dai_rgbd_get_depth_unit: null rgbd";
+        return -1;
+    }
+    std::lock_guard<std::mutex> lock(g_rgbd_settings_mutex);
+    auto it = g_rgbd_settings.find(rgbd);
+    // RGBD outputs millimeters until setDepthUnit is called.
+    return it == g_rgbd_settings.end() ? RgbdSettings{}.depth_unit : it->second.depth_unit;
+}
+
+void dai_rgbd_set_output_fps(DaiNode rgbd, float fps) {
+    if(!rgbd) {
+        last_error = "dai_rgbd_set_output_fps: null rgbd";
+        return;
+    }
+    std::lock_guard<std::mutex> lock(g_rgbd_settings_mutex);
+    g_rgbd_settings[rgbd].output_fps = fps > 0.0f ? fps : 0.0f;
+}
+
+float dai_rgbd_get_output_fps(DaiNode rgbd) {
+    if(!rgbd) {
+        last_error = "dai_rgbd_get_output_fps: null rgbd";
+        return 0.0f;
+    }
+    std::lock_guard<std::mutex> lock(g_rgbd_settings_mutex);
+    auto it = g_rgbd_settings.find(rgbd);
+    return it == g_rgbd_settings.end() ? 0.0f : it->second.output_fps;
+}
+
+void dai_rgbd_set_sparse(DaiNode rgbd, bool sparse) {
+    if(!rgbd) {
+        last_error = "dai_rgbd_set_sparse: null rgbd";
+        return;
+    }
+    std::lock_guard<std::mutex> lock(g_rgbd_settings_mutex);
+    g_rgbd_settings[rgbd].sparse = sparse;
+}
+
+bool dai_rgbd_get_sparse(DaiNode rgbd) {
+    if(!rgbd) {
+        last_error = "dai_rgbd_get_sparse: null rgbd";
+        return false;
+    }
+    std::lock_guard<std::mutex> lock(g_rgbd_settings_mutex);
+    auto it = g_rgbd_settings.find(rgbd);
+    return it != g_rgbd_settings.end() && it->second.sparse;
+}
+
+// Drop the settings of the pipeline's RGBD nodes, so a node later allocated at the same address
+// does not inherit them.
+static void _dai_rgbd_forget_nodes(dai::Pipeline& pipeline) {
+    std::lock_guard<std::mutex> lock(g_rgbd_settings_mutex);
+    if(g_rgbd_settings.empty()) return;
+    for(const auto& node : pipeline.getAllNodes()) {
+        if(node) g_rgbd_settings.erase(static_cast<const void*>(node.get()));
+    }
+}
+
+namespace {
+// Host-side stage between an RGBD output and its queues: drops messages closer than
+// 1 / output_fps to the previous one and, if sparse, keeps only points with a measurement.
+class RgbdOutputFilter : public dai::NodeCRTP<dai::node::ThreadedHostNode, RgbdOutputFilter> {
+   public:
+    constexpr static const char* NAME = "RgbdOutputFilter";
+
+    Input input{*this, {"in", DEFAULT_GROUP, false, 4, {{{dai::DatatypeEnum::Buffer, true}}}, true}};
+    Output out{*this, {"out", DEFAULT_GROUP, {{{dai::DatatypeEnum::Buffer, true}}}}};
+
+    RgbdSettings settings;
+
+    void run() override {
+        std::optional<std::chrono::steady_clock::time_point> last;
+        const auto period = settings.output_fps > 0.0f
+                                ? std::chrono::nanoseconds(static_cast<int64_t>(1e9 / settings.output_fps))
+                                : std::chrono::nanoseconds(0);
+        while(isRunning()) {
+            auto msg = input.get<dai::Buffer>();
+            if(!msg) continue;
+            auto ts = msg->getTimestamp();
+            if(last && ts - *last < period) continue;
+            last = ts;
+            if(auto pcl = std::dynamic_pointer_cast<dai::PointCloudData>(msg); pcl && settings.sparse) {
+                auto points = pcl->getPointsRGB();
+                points.erase(std::remove_if(points.begin(),
+                                            points.end(),
+                                            [](const dai::Point3fRGBA& p) {
+                                                return p.z == 0.0f || !std::isfinite(p.x) || !std::isfinite(p.y)
+                                                       || !std::isfinite(p.z);
+                                            }),
+                             points.end());
+                auto sparse = std::make_shared<dai::PointCloudData>(*pcl);
+                sparse->setPointsRGB(points);
+                sparse->setWidth(static_cast<unsigned int>(points.size()));
+                sparse->setHeight(1);
+                msg = sparse;
+            }
+            out.send(msg);
+        }
+    }
+};
+}  // namespace
+
+// Output to create queues from: `out` itself, or a filter behind it when `out` belongs to an RGBD
+// node with an output rate or sparse point clouds set.
+static dai::Node::Output* _dai_rgbd_filtered_output(dai::Node::Output* out) {
+    auto& parent = out->getParent();
+    if(!dynamic_cast<dai::node::RGBD*>(&parent)) return out;
+    RgbdSettings settings;
+    {
+        std::lock_guard<std::mutex> lock(g_rgbd_settings_mutex);
+        auto it = g_rgbd_settings.find(static_cast<const void*>(&parent));
+        if(it == g_rgbd_settings.end()) return out;
+        settings = it->second;
+    }
+    if(settings.output_fps <= 0.0f && !settings.sparse) return out;
+    auto filter = parent.getParentPipeline().create<RgbdOutputFilter>();
+    filter->settings = settings;
+    out->link(filter->input);
+    return &filter->out;
+}
+
 static inline dai::node::ImageAlign* _dai_as_image_align(DaiNode align) {
     return static_cast<dai::node::ImageAlign*>(align);
 }
@@ -1716,51 +2574,524 @@ void dai_image_align_set_out_keep_aspect_ratio(DaiNode align, bool keep) {
     }
 }
 
-static inline dai::node::ImageManip* _dai_as_image_manip(DaiNode manip) {
-    return static_cast<dai::node::ImageManip*>(manip);
-}
-
-static inline dai::node::VideoEncoder* _dai_as_video_encoder(DaiNode encoder) {
-    return static_cast<dai::node::VideoEncoder*>(encoder);
-}
-
-// Helper to validate and cast a DaiBuffer to ImageManipConfig.
-// 
-// Error handling contract:
-// - Returns nullptr on failure (null cfg or wrong type)
-// - Sets global last_error with context-specific message
-// - Callers MUST check return value and return early on nullptr
-// - Error is propagated to Rust via dai_get_last_error()
-//
-// This pattern ensures all validation failures are consistently reported
-// to the Rust layer without requiring per-function error handling.
-static inline std::shared_ptr<dai::ImageManipConfig> _dai_as_image_manip_config(DaiBuffer cfg, const char* ctx) {
-    if(!cfg) {
-        last_error = std::string(ctx) + ": null cfg";
-        return nullptr;
+void dai_image_align_set_interpolation(DaiNode align, int interpolation) {
+    if(!align) {
+        last_error = "dai_image_align_set_interpolation: null align";
+        return;
     }
-    auto base_ptr = static_cast<std::shared_ptr<dai::Buffer>*>(cfg);
-    auto typed = std::dynamic_pointer_cast<dai::ImageManipConfig>(*base_ptr);
-    if(!typed) {
-        last_error = std::string(ctx) + ": cfg is not ImageManipConfig";
-        return nullptr;
+    try {
+        _dai_as_image_align(align)->setInterpolation(static_cast<dai::Interpolation>(interpolation));
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_image_align_set_interpolation failed: ") + e.what();
     }
-    return typed;
 }
 
-void dai_image_manip_set_num_frames_pool(DaiNode manip, int num_frames_pool) {
-    if(!manip) {
-        last_error = "dai_image_manip_set_num_frames_pool: null manip";
+void dai_image_align_set_num_shaves(DaiNode align, int num_shaves) {
+    if(!align) {
+        last_error = "dai_image_align_set_num_shaves: null align";
         return;
     }
     try {
-        _dai_as_image_manip(manip)->setNumFramesPool(num_frames_pool);
+        _dai_as_image_align(align)->setNumShaves(num_shaves);
     } catch(const std::exception& e) {
-        last_error = std::string("dai_image_manip_set_num_frames_pool failed: ") + e.what();
+        last_error = std::string("dai_image_align_set_num_shaves failed: ") + e.what();
     }
 }
 
-void dai_image_manip_set_max_output_frame_size(DaiNode manip, int max_frame_size) {
+void dai_image_align_set_num_frames_pool(DaiNode align, int num_frames_pool) {
+    if(!align) {
+        last_error = "dai_image_align_set_num_frames_pool: null align";
+        return;
+    }
+    try {
+        _dai_as_image_align(align)->setNumFramesPool(num_frames_pool);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_image_align_set_num_frames_pool failed: ") + e.what();
+    }
+}
+
+bool dai_image_align_run_on_host(DaiNode align) {
+    if(!align) {
+        last_error = "dai_image_align_run_on_host: null align";
+        return false;
+    }
+    try {
+        return _dai_as_image_align(align)->runOnHost();
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_image_align_run_on_host failed: ") + e.what();
+        return false;
+    }
+}
+
+// Helper to validate and cast a DaiBuffer to ImageAlignConfig.
+// Same contract as `_dai_as_image_manip_config`.
+static inline std::shared_ptr<dai::ImageAlignConfig> _dai_as_image_align_config(DaiBuffer cfg, const char* ctx) {
+    if(!cfg) {
+        last_error = std::string(ctx) + ": null cfg";
+        return nullptr;
+    }
+    auto base_ptr = static_cast<std::shared_ptr<dai::Buffer>*>(cfg);
+    auto typed = std::dynamic_pointer_cast<dai::ImageAlignConfig>(*base_ptr);
+    if(!typed) {
+        last_error = std::string(ctx) + ": cfg is not ImageAlignConfig";
+        return nullptr;
+    }
+    return typed;
+}
+
+DaiBuffer dai_image_align_config_new() {
+    try {
+        auto cfg = std::make_shared<dai::ImageAlignConfig>();
+        return new std::shared_ptr<dai::Buffer>(std::static_pointer_cast<dai::Buffer>(std::move(cfg)));
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_image_align_config_new failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+DaiBuffer dai_image_align_get_initial_config(DaiNode align) {
+    if(!align) {
+        last_error = "dai_image_align_get_initial_config: null align";
+        return nullptr;
+    }
+    try {
+        auto a = _dai_as_image_align(align);
+        if(!a->initialConfig) {
+            last_error = "dai_image_align_get_initial_config: initialConfig is null";
+            return nullptr;
+        }
+        return new std::shared_ptr<dai::Buffer>(std::static_pointer_cast<dai::Buffer>(a->initialConfig));
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_image_align_get_initial_config failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+void dai_image_align_config_set_static_depth_plane(DaiBuffer cfg, uint16_t depth_plane) {
+    try {
+        auto c = _dai_as_image_align_config(cfg, "dai_image_align_config_set_static_depth_plane");
+        if(!c) return;
+        c->staticDepthPlane = depth_plane;
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_image_align_config_set_static_depth_plane failed: ") + e.what();
+    }
+}
+
+uint16_t dai_image_align_config_get_static_depth_plane(DaiBuffer cfg) {
+    try {
+        auto c = _dai_as_image_align_config(cfg, "dai_image_align_config_get_static_depth_plane");
+        if(!c) return 0;
+        return c->staticDepthPlane;
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_image_align_config_get_static_depth_plane failed: ") + e.what();
+        return 0;
+    }
+}
+
+// NeuralDepth node and NeuralDepthConfig helpers; both only exist in builds that ship the
+// NeuralDepth node header.
+#if DAI_HAS_NODE_NEURAL_DEPTH
+bool dai_neural_depth_build(DaiNode neural_depth, DaiOutput left, DaiOutput right, int model) {
+    if(!neural_depth || !left || !right) {
+        last_error = "dai_neural_depth_build: null neural_depth or input";
+        return false;
+    }
+    try {
+        dai::DeviceModelZoo zoo_model;
+        switch(model) {
+            case 0:
+                zoo_model = dai::DeviceModelZoo::NEURAL_DEPTH_LARGE;
+                break;
+            case 1:
+                zoo_model = dai::DeviceModelZoo::NEURAL_DEPTH_MEDIUM;
+                break;
+            case 2:
+                zoo_model = dai::DeviceModelZoo::NEURAL_DEPTH_SMALL;
+                break;
+            case 3:
+                zoo_model = dai::DeviceModelZoo::NEURAL_DEPTH_NANO;
+                break;
+            default:
+                last_error = "dai_neural_depth_build: unknown model " + std::to_string(model);
+                return false;
+        }
+        auto n = static_cast<dai::node::NeuralDepth*>(neural_depth);
+        n->build(*static_cast<dai::Node::Output*>(left), *static_cast<dai::Node::Output*>(right), zoo_model);
+        return true;
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_neural_depth_build failed: ") + e.what();
+        return false;
+    }
+}
+
+// Helper to validate and cast a DaiBuffer to NeuralDepthConfig.
+// Same contract as `_dai_as_image_align_config`.
+static inline std::shared_ptr<dai::NeuralDepthConfig> _dai_as_neural_depth_config(DaiBuffer cfg, const char* ctx) {
+    if(!cfg) {
+        last_error = std::string(ctx) + ": null cfg";
+        return nullptr;
+    }
+    auto base_ptr = static_cast<std::shared_ptr<dai::Buffer>*>(cfg);
+    auto typed = std::dynamic_pointer_cast<dai::NeuralDepthConfig>(*base_ptr);
+    if(!typed) {
+        last_error = std::string(ctx) + ": cfg is not NeuralDepthConfig";
+        return nullptr;
+    }
+    return typed;
+}
+
+DaiBuffer dai_neural_depth_config_new() {
+    try {
+        auto cfg = std::make_shared<dai::NeuralDepthConfig>();
+        return new std::shared_ptr<dai::Buffer>(std::static_pointer_cast<dai::Buffer>(std::move(cfg)));
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_neural_depth_config_new failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+DaiBuffer dai_neural_depth_get_initial_config(DaiNode neural_depth) {
+    if(!neural_depth) {
+        last_error = "dai_neural_depth_get_initial_config: null neural_depth";
+        return nullptr;
+    }
+    try {
+        auto n = static_cast<dai::node::NeuralDepth*>(neural_depth);
+        if(!n->initialConfig) {
+            last_error = "dai_neural_depth_get_initial_config: initialConfig is null";
+            return nullptr;
+        }
+        return new std::shared_ptr<dai::Buffer>(std::static_pointer_cast<dai::Buffer>(n->initialConfig));
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_neural_depth_get_initial_config failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+template <typename R, typename F>
+static R _dai_with_neural_depth_config(DaiBuffer cfg, const char* fn, R fallback, F&& f) {
+    try {
+        auto c = _dai_as_neural_depth_config(cfg, fn);
+        if(!c) return fallback;
+        return f(*c);
+    } catch(const std::exception& e) {
+        last_error = std::string(fn) + " failed: " + e.what();
+        return fallback;
+    }
+}
+#else
+bool dai_neural_depth_build(DaiNode, DaiOutput, DaiOutput, int) {
+    last_error = "dai_neural_depth_build: NeuralDepth is not available in this DepthAI-Core build";
+    return false;
+}
+
+DaiBuffer dai_neural_depth_config_new() {
+    last_error = "dai_neural_depth_config_new: NeuralDepthConfig is not available in this DepthAI-Core build";
+    return nullptr;
+}
+
+DaiBuffer dai_neural_depth_get_initial_config(DaiNode) {
+    last_error = "dai_neural_depth_get_initial_config: NeuralDepth is not available in this DepthAI-Core build";
+    return nullptr;
+}
+
+template <typename R, typename F>
+static R _dai_with_neural_depth_config(DaiBuffer, const char* fn, R fallback, F&&) {
+    last_error = std::string(fn) + ": NeuralDepthConfig is not available in this DepthAI-Core build";
+    return fallback;
+}
+#endif
+
+void dai_neural_depth_config_set_confidence_threshold(DaiBuffer cfg, uint8_t threshold) {
+    _dai_with_neural_depth_config(cfg, "dai_neural_depth_config_set_confidence_threshold", 0, [&](auto& c) {
+        c.setConfidenceThreshold(threshold);
+        return 0;
+    });
+}
+
+uint8_t dai_neural_depth_config_get_confidence_threshold(DaiBuffer cfg) {
+    return _dai_with_neural_depth_config(cfg, "dai_neural_depth_config_get_confidence_threshold", uint8_t{0}, [](auto& c) {
+        return static_cast<uint8_t>(c.getConfidenceThreshold());
+    });
+}
+
+void dai_neural_depth_config_set_edge_threshold(DaiBuffer cfg, uint8_t threshold) {
+    _dai_with_neural_depth_config(cfg, "dai_neural_depth_config_set_edge_threshold", 0, [&](auto& c) {
+        c.setEdgeThreshold(threshold);
+        return 0;
+    });
+}
+
+uint8_t dai_neural_depth_config_get_edge_threshold(DaiBuffer cfg) {
+    return _dai_with_neural_depth_config(cfg, "dai_neural_depth_config_get_edge_threshold", uint8_t{0}, [](auto& c) {
+        return static_cast<uint8_t>(c.getEdgeThreshold());
+    });
+}
+
+void dai_neural_depth_config_set_depth_unit(DaiBuffer cfg, int depth_unit) {
+    _dai_with_neural_depth_config(cfg, "dai_neural_depth_config_set_depth_unit", 0, [&](auto& c) {
+        c.setDepthUnit(static_cast<decltype(c.getDepthUnit())>(depth_unit));
+        return 0;
+    });
+}
+
+int dai_neural_depth_config_get_depth_unit(DaiBuffer cfg) {
+    return _dai_with_neural_depth_config(
+        cfg, "dai_neural_depth_config_get_depth_unit", -1, [](auto& c) { return static_cast<int>(c.getDepthUnit()); });
+}
+
+void dai_neural_depth_config_set_custom_depth_unit_multiplier(DaiBuffer cfg, float multiplier) {
+    _dai_with_neural_depth_config(cfg, "dai_neural_depth_config_set_custom_depth_unit_multiplier", 0, [&](auto& c) {
+        c.setCustomDepthUnitMultiplier(multiplier);
+        return 0;
+    });
+}
+
+float dai_neural_depth_config_get_custom_depth_unit_multiplier(DaiBuffer cfg) {
+    return _dai_with_neural_depth_config(cfg, "dai_neural_depth_config_get_custom_depth_unit_multiplier", 0.0f, [](auto& c) {
+        return c.getCustomDepthUnitMultiplier();
+    });
+}
+
+static inline dai::node::ImageFilters* _dai_as_image_filters(DaiNode filters) {
+    return static_cast<dai::node::ImageFilters*>(filters);
+}
+
+void dai_image_filters_set_run_on_host(DaiNode filters, bool run_on_host) {
+    if(!filters) {
+        last_error = "dai_image_filters_set_run_on_host: null filters";
+        return;
+    }
+    try {
+        _dai_as_image_filters(filters)->setRunOnHost(run_on_host);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_image_filters_set_run_on_host failed: ") + e.what();
+    }
+}
+
+bool dai_image_filters_run_on_host(DaiNode filters) {
+    if(!filters) {
+        last_error = "dai_image_filters_run_on_host: null filters";
+        return false;
+    }
+    try {
+        return _dai_as_image_filters(filters)->runOnHost();
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_image_filters_run_on_host failed: ") + e.what();
+        return false;
+    }
+}
+
+void dai_image_filters_set_default_profile_preset(DaiNode filters, int preset) {
+    if(!filters) {
+        last_error = "dai_image_filters_set_default_profile_preset: null filters";
+        return;
+    }
+    try {
+        _dai_as_image_filters(filters)->setDefaultProfilePreset(static_cast<dai::ImageFiltersPresetMode>(preset));
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_image_filters_set_default_profile_preset failed: ") + e.what();
+    }
+}
+
+static inline std::shared_ptr<dai::ImageFiltersConfig> _dai_as_image_filters_config(DaiBuffer cfg, const char* ctx) {
+    if(!cfg) {
+        last_error = std::string(ctx) + ": null cfg";
+        return nullptr;
+    }
+    auto base_ptr = static_cast<std::shared_ptr<dai::Buffer>*>(cfg);
+    auto typed = std::dynamic_pointer_cast<dai::ImageFiltersConfig>(*base_ptr);
+    if(!typed) {
+        last_error = std::string(ctx) + ": cfg is not ImageFiltersConfig";
+        return nullptr;
+    }
+    return typed;
+}
+
+DaiBuffer dai_image_filters_config_new() {
+    try {
+        auto cfg = std::make_shared<dai::ImageFiltersConfig>();
+        return new std::shared_ptr<dai::Buffer>(std::static_pointer_cast<dai::Buffer>(std::move(cfg)));
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_image_filters_config_new failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+DaiBuffer dai_image_filters_get_initial_config(DaiNode filters) {
+    if(!filters) {
+        last_error = "dai_image_filters_get_initial_config: null filters";
+        return nullptr;
+    }
+    try {
+        auto f = _dai_as_image_filters(filters);
+        if(!f->initialConfig) {
+            last_error = "dai_image_filters_get_initial_config: initialConfig is null";
+            return nullptr;
+        }
+        return new std::shared_ptr<dai::Buffer>(std::static_pointer_cast<dai::Buffer>(f->initialConfig));
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_image_filters_get_initial_config failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+static dai::FilterParams _dai_filter_params_from_json(const nlohmann::json& j) {
+    const auto type = j.at("type").get<std::string>();
+    if(type == "median") {
+        return static_cast<dai::MedianFilterParams>(j.value("kernel", 0));
+    }
+    if(type == "spatial") {
+        dai::SpatialFilterParams p;
+        p.enable = j.value("enable", p.enable);
+        p.holeFillingRadius = static_cast<decltype(p.holeFillingRadius)>(j.value("holeFillingRadius", static_cast<int>(p.holeFillingRadius)));
+        p.alpha = j.value("alpha", p.alpha);
+        p.delta = j.value("delta", p.delta);
+        p.numIterations = j.value("numIterations", p.numIterations);
+        return p;
+    }
+    if(type == "temporal") {
+        dai::TemporalFilterParams p;
+        p.enable = j.value("enable", p.enable);
+        p.persistencyMode = static_cast<decltype(p.persistencyMode)>(j.value("persistencyMode", static_cast<int>(p.persistencyMode)));
+        p.alpha = j.value("alpha", p.alpha);
+        p.delta = j.value("delta", p.delta);
+        return p;
+    }
+    if(type == "speckle") {
+        dai::SpeckleFilterParams p;
+        p.enable = j.value("enable", p.enable);
+        p.speckleRange = j.value("speckleRange", p.speckleRange);
+        p.differenceThreshold = j.value("differenceThreshold", p.differenceThreshold);
+        return p;
+    }
+    throw std::invalid_argument("unknown filter type '" + type + "'");
+}
+
+static nlohmann::json _dai_filter_params_to_json(const dai::FilterParams& params) {
+    return std::visit(
+        [](const auto& p) -> nlohmann::json {
+            using T = std::decay_t<decltype(p)>;
+            nlohmann::json j;
+            if constexpr(std::is_same_v<T, dai::MedianFilterParams>) {
+                j["type"] = "median";
+                j["kernel"] = static_cast<int>(p);
+            } else if constexpr(std::is_same_v<T, dai::SpatialFilterParams>) {
+                j["type"] = "spatial";
+                j["enable"] = p.enable;
+                j["holeFillingRadius"] = static_cast<int>(p.holeFillingRadius);
+                j["alpha"] = p.alpha;
+                j["delta"] = p.delta;
+                j["numIterations"] = p.numIterations;
+            } else if constexpr(std::is_same_v<T, dai::TemporalFilterParams>) {
+                j["type"] = "temporal";
+                j["enable"] = p.enable;
+                j["persistencyMode"] = static_cast<int>(p.persistencyMode);
+                j["alpha"] = p.alpha;
+                j["delta"] = p.delta;
+            } else {
+                j["type"] = "speckle";
+                j["enable"] = p.enable;
+                j["speckleRange"] = p.speckleRange;
+                j["differenceThreshold"] = p.differenceThreshold;
+            }
+            return j;
+        },
+        params);
+}
+
+bool dai_image_filters_config_set_filter_json(DaiBuffer cfg, int64_t index, const char* json) {
+    if(!json) {
+        last_error = "dai_image_filters_config_set_filter_json: null json";
+        return false;
+    }
+    try {
+        auto c = _dai_as_image_filters_config(cfg, "dai_image_filters_config_set_filter_json");
+        if(!c) return false;
+        auto params = _dai_filter_params_from_json(nlohmann::json::parse(json));
+        if(index < 0) {
+            c->insertFilter(std::move(params));
+        } else {
+            c->updateFilterAtIndex(index, std::move(params));
+        }
+        return true;
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_image_filters_config_set_filter_json failed: ") + e.what();
+        return false;
+    }
+}
+
+void dai_image_filters_config_set_profile_preset(DaiBuffer cfg, int preset) {
+    try {
+        auto c = _dai_as_image_filters_config(cfg, "dai_image_filters_config_set_profile_preset");
+        if(!c) return;
+        c->setProfilePreset(static_cast<dai::ImageFiltersPresetMode>(preset));
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_image_filters_config_set_profile_preset failed: ") + e.what();
+    }
+}
+
+char* dai_image_filters_config_get_filters_json(DaiBuffer cfg) {
+    try {
+        auto c = _dai_as_image_filters_config(cfg, "dai_image_filters_config_get_filters_json");
+        if(!c) return nullptr;
+        nlohmann::json j = nlohmann::json::array();
+        for(const auto& params : c->filterParams) {
+            j.push_back(_dai_filter_params_to_json(params));
+        }
+        auto dumped = j.dump();
+        return dai_string_to_cstring(dumped.c_str());
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_image_filters_config_get_filters_json failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+static inline dai::node::ImageManip* _dai_as_image_manip(DaiNode manip) {
+    return static_cast<dai::node::ImageManip*>(manip);
+}
+
+static inline dai::node::VideoEncoder* _dai_as_video_encoder(DaiNode encoder) {
+    return static_cast<dai::node::VideoEncoder*>(encoder);
+}
+
+// Helper to validate and cast a DaiBuffer to ImageManipConfig.
+// 
+// Error handling contract:
+// - Returns nullptr on failure (null cfg or wrong type)
+// - Sets global last_error with context-specific message
+// - Callers MUST check return value and return early on nullptr
+// - Error is propagated to Rust via dai_get_last_error()
+//
+// This pattern ensures all validation failures are consistently reported
+// to the Rust layer without requiring per-function error handling.
+static inline std::shared_ptr<dai::ImageManipConfig> _dai_as_image_manip_config(DaiBuffer cfg, const char* ctx) {
+    if(!cfg) {
+        last_error = std::string(ctx) + ": null cfg";
+        return nullptr;
+    }
+    auto base_ptr = static_cast<std::shared_ptr<dai::Buffer>*>(cfg);
+    auto typed = std::dynamic_pointer_cast<dai::ImageManipConfig>(*base_ptr);
+    if(!typed) {
+        last_error = std::string(ctx) + ": cfg is not ImageManipConfig";
+        return nullptr;
+    }
+    return typed;
+}
+
+void dai_image_manip_set_num_frames_pool(DaiNode manip, int num_frames_pool) {
+    if(!manip) {
+        last_error = "dai_image_manip_set_num_frames_pool: null manip";
+        return;
+    }
+    try {
+        _dai_as_image_manip(manip)->setNumFramesPool(num_frames_pool);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_image_manip_set_num_frames_pool failed: ") + e.what();
+    }
+}
+
+void dai_image_manip_set_max_output_frame_size(DaiNode manip, int max_frame_size) {
     if(!manip) {
         last_error = "dai_image_manip_set_max_output_frame_size: null manip";
         return;
@@ -2155,6 +3486,28 @@ DaiBuffer dai_image_manip_get_initial_config(DaiNode manip) {
     }
 }
 
+DaiBuffer dai_image_manip_config_copy(DaiBuffer cfg) {
+    try {
+        auto c = _dai_as_image_manip_config(cfg, "dai_image_manip_config_copy");
+        if(!c) return nullptr;
+        auto copy = std::make_shared<dai::ImageManipConfig>(*c);
+        return new std::shared_ptr<dai::Buffer>(std::static_pointer_cast<dai::Buffer>(std::move(copy)));
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_image_manip_config_copy failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+void dai_image_manip_config_reset(DaiBuffer cfg) {
+    try {
+        auto c = _dai_as_image_manip_config(cfg, "dai_image_manip_config_reset");
+        if(!c) return;
+        *c = dai::ImageManipConfig();
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_image_manip_config_reset failed: ") + e.what();
+    }
+}
+
 void dai_image_manip_config_clear_ops(DaiBuffer cfg) {
     try {
         auto c = _dai_as_image_manip_config(cfg, "dai_image_manip_config_clear_ops");
@@ -2440,101 +3793,1697 @@ bool dai_image_manip_config_get_skip_current_image(DaiBuffer cfg) {
     }
 }
 
-// Wrapper-owned pointcloud view. PointCloudData::getPointsRGB() returns by value, so we
-// store the returned vector and expose a stable pointer + length to Rust.
-struct DaiPointCloudView {
-    std::shared_ptr<dai::PointCloudData> msg;
-    std::vector<dai::Point3fRGBA> points;
-};
+void dai_script_set_script(DaiNode script, const char* source, const char* name) {
+    if(!script) {
+        last_error = "dai_script_set_script: null script";
+        return;
+    }
+    if(!source) {
+        last_error = "dai_script_set_script: null source";
+        return;
+    }
+    try {
+        static_cast<dai::node::Script*>(script)->setScript(std::string(source), name ? std::string(name) : std::string());
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_script_set_script failed: ") + e.what();
+    }
+}
 
-DaiPointCloud dai_queue_get_pointcloud(DaiDataQueue queue, int timeout_ms) {
-    if(!queue) {
-        last_error = "dai_queue_get_pointcloud: null queue";
-        return nullptr;
+void dai_sync_set_sync_threshold_ns(DaiNode sync, int64_t threshold_ns) {
+    if(!sync) {
+        last_error = "dai_sync_set_sync_threshold_ns: null sync";
+        return;
     }
     try {
-        auto ptr = static_cast<std::shared_ptr<dai::MessageQueue>*>(queue);
-        std::shared_ptr<dai::PointCloudData> pcl;
-        if(timeout_ms < 0) {
-            pcl = (*ptr)->get<dai::PointCloudData>();
-        } else {
-            bool timedOut = false;
-            pcl = (*ptr)->get<dai::PointCloudData>(std::chrono::milliseconds(timeout_ms), timedOut);
-            if(timedOut) return nullptr;
-        }
-        if(!pcl) return nullptr;
+        static_cast<dai::node::Sync*>(sync)->setSyncThreshold(std::chrono::nanoseconds(threshold_ns));
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_sync_set_sync_threshold_ns failed: ") + e.what();
+    }
+}
 
-        auto view = new DaiPointCloudView();
-        view->msg = pcl;
-        view->points = pcl->getPointsRGB();
-        return static_cast<DaiPointCloud>(view);
+void dai_sync_set_sync_attempts(DaiNode sync, int attempts) {
+    if(!sync) {
+        last_error = "dai_sync_set_sync_attempts: null sync";
+        return;
+    }
+    try {
+        static_cast<dai::node::Sync*>(sync)->setSyncAttempts(attempts);
     } catch(const std::exception& e) {
-        last_error = std::string("dai_queue_get_pointcloud failed: ") + e.what();
-        return nullptr;
+        last_error = std::string("dai_sync_set_sync_attempts failed: ") + e.what();
     }
 }
 
-DaiPointCloud dai_queue_try_get_pointcloud(DaiDataQueue queue) {
-    if(!queue) {
-        last_error = "dai_queue_try_get_pointcloud: null queue";
+// Record/Replay node helpers; the nodes only exist in builds that ship their headers.
+template <typename N, typename F>
+static bool _dai_with_record_replay_node(DaiNode node, const char* fn, F&& f) {
+    if(!node) {
+        last_error = std::string(fn) + ": null node";
+        return false;
+    }
+#if DAI_HAS_NODE_RECORD_REPLAY
+    try {
+        f(static_cast<N*>(node));
+        return true;
+    } catch(const std::exception& e) {
+        last_error = std::string(fn) + " failed: " + e.what();
+        return false;
+    }
+#else
+    (void)f;
+    last_error = std::string(fn) + ": Record/Replay nodes are not available in this DepthAI-Core build";
+    return false;
+#endif
+}
+
+#if DAI_HAS_NODE_RECORD_REPLAY
+    #define DAI_RECORD_REPLAY_NODE(name) dai::node::name
+#else
+    // Only used as a template argument that is never instantiated with a call.
+    #define DAI_RECORD_REPLAY_NODE(name) dai::Node
+#endif
+
+bool dai_record_video_set_record_video_file(DaiNode node, const char* path) {
+    if(_dai_cstr_empty(path)) {
+        last_error = "dai_record_video_set_record_video_file: empty path";
+        return false;
+    }
+    return _dai_with_record_replay_node<DAI_RECORD_REPLAY_NODE(RecordVideo)>(
+        node, "dai_record_video_set_record_video_file", [&](auto* n) { n->setRecordVideoFile(std::filesystem::u8path(path)); });
+}
+
+bool dai_record_video_set_record_metadata_file(DaiNode node, const char* path) {
+    if(_dai_cstr_empty(path)) {
+        last_error = "dai_record_video_set_record_metadata_file: empty path";
+        return false;
+    }
+    return _dai_with_record_replay_node<DAI_RECORD_REPLAY_NODE(RecordVideo)>(
+        node, "dai_record_video_set_record_metadata_file", [&](auto* n) { n->setRecordMetadataFile(std::filesystem::u8path(path)); });
+}
+
+bool dai_record_metadata_only_set_record_file(DaiNode node, const char* path) {
+    if(_dai_cstr_empty(path)) {
+        last_error = "dai_record_metadata_only_set_record_file: empty path";
+        return false;
+    }
+    return _dai_with_record_replay_node<DAI_RECORD_REPLAY_NODE(RecordMetadataOnly)>(
+        node, "dai_record_metadata_only_set_record_file", [&](auto* n) { n->setRecordFile(std::filesystem::u8path(path)); });
+}
+
+bool dai_replay_video_set_replay_video_file(DaiNode node, const char* path) {
+    if(_dai_cstr_empty(path)) {
+        last_error = "dai_replay_video_set_replay_video_file: empty path";
+        return false;
+    }
+    return _dai_with_record_replay_node<DAI_RECORD_REPLAY_NODE(ReplayVideo)>(
+        node, "dai_replay_video_set_replay_video_file", [&](auto* n) { n->setReplayVideoFile(std::filesystem::u8path(path)); });
+}
+
+bool dai_replay_video_set_replay_metadata_file(DaiNode node, const char* path) {
+    if(_dai_cstr_empty(path)) {
+        last_error = "dai_replay_video_set_replay_metadata_file: empty path";
+        return false;
+    }
+    return _dai_with_record_replay_node<DAI_RECORD_REPLAY_NODE(ReplayVideo)>(
+        node, "dai_replay_video_set_replay_metadata_file", [&](auto* n) { n->setReplayMetadataFile(std::filesystem::u8path(path)); });
+}
+
+bool dai_replay_metadata_only_set_replay_file(DaiNode node, const char* path) {
+    if(_dai_cstr_empty(path)) {
+        last_error = "dai_replay_metadata_only_set_replay_file: empty path";
+        return false;
+    }
+    return _dai_with_record_replay_node<DAI_RECORD_REPLAY_NODE(ReplayMetadataOnly)>(
+        node, "dai_replay_metadata_only_set_replay_file", [&](auto* n) { n->setReplayFile(std::filesystem::u8path(path)); });
+}
+
+bool dai_replay_video_set_loop(DaiNode node, bool loop) {
+    return _dai_with_record_replay_node<DAI_RECORD_REPLAY_NODE(ReplayVideo)>(node, "dai_replay_video_set_loop", [&](auto* n) { n->setLoop(loop); });
+}
+
+bool dai_replay_metadata_only_set_loop(DaiNode node, bool loop) {
+    return _dai_with_record_replay_node<DAI_RECORD_REPLAY_NODE(ReplayMetadataOnly)>(node, "dai_replay_metadata_only_set_loop", [&](auto* n) { n->setLoop(loop); });
+}
+
+static inline dai::node::Cast* _dai_as_cast(DaiNode cast) {
+    return static_cast<dai::node::Cast*>(cast);
+}
+
+void dai_cast_set_num_frames_pool(DaiNode cast, int num_frames_pool) {
+    if(!cast) {
+        last_error = "dai_cast_set_num_frames_pool: null cast";
+        return;
+    }
+    try {
+        _dai_as_cast(cast)->setNumFramesPool(num_frames_pool);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_cast_set_num_frames_pool failed: ") + e.what();
+    }
+}
+
+void dai_cast_set_output_frame_type(DaiNode cast, int frame_type) {
+    if(!cast) {
+        last_error = "dai_cast_set_output_frame_type: null cast";
+        return;
+    }
+    try {
+        _dai_as_cast(cast)->setOutputFrameType(static_cast<dai::ImgFrame::Type>(frame_type));
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_cast_set_output_frame_type failed: ") + e.what();
+    }
+}
+
+void dai_cast_set_scale(DaiNode cast, float scale) {
+    if(!cast) {
+        last_error = "dai_cast_set_scale: null cast";
+        return;
+    }
+    try {
+        _dai_as_cast(cast)->setScale(scale);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_cast_set_scale failed: ") + e.what();
+    }
+}
+
+void dai_cast_set_offset(DaiNode cast, float offset) {
+    if(!cast) {
+        last_error = "dai_cast_set_offset: null cast";
+        return;
+    }
+    try {
+        _dai_as_cast(cast)->setOffset(offset);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_cast_set_offset failed: ") + e.what();
+    }
+}
+
+void dai_cast_set_num_shaves(DaiNode cast, int num_shaves) {
+    if(!cast) {
+        last_error = "dai_cast_set_num_shaves: null cast";
+        return;
+    }
+    try {
+        _dai_as_cast(cast)->setNumShaves(num_shaves);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_cast_set_num_shaves failed: ") + e.what();
+    }
+}
+
+static inline dai::node::UVC* _dai_as_uvc(DaiNode uvc) {
+    return static_cast<dai::node::UVC*>(uvc);
+}
+
+static inline bool _dai_gpio_map(const int* gpio_nums, const int* values, int count, std::unordered_map<int, int>& out, const char* ctx) {
+    if(count < 0 || (count > 0 && (!gpio_nums || !values))) {
+        last_error = std::string(ctx) + ": invalid gpio list";
+        return false;
+    }
+    for(int i = 0; i < count; ++i) {
+        out[gpio_nums[i]] = values[i];
+    }
+    return true;
+}
+
+void dai_uvc_set_gpios_on_init(DaiNode uvc, const int* gpio_nums, const int* values, int count) {
+    if(!uvc) {
+        last_error = "dai_uvc_set_gpios_on_init: null uvc";
+        return;
+    }
+    try {
+        std::unordered_map<int, int> gpios;
+        if(!_dai_gpio_map(gpio_nums, values, count, gpios, "dai_uvc_set_gpios_on_init")) return;
+        _dai_as_uvc(uvc)->setGpiosOnInit(gpios);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_uvc_set_gpios_on_init failed: ") + e.what();
+    }
+}
+
+void dai_uvc_set_gpios_on_stream_on(DaiNode uvc, const int* gpio_nums, const int* values, int count) {
+    if(!uvc) {
+        last_error = "dai_uvc_set_gpios_on_stream_on: null uvc";
+        return;
+    }
+    try {
+        std::unordered_map<int, int> gpios;
+        if(!_dai_gpio_map(gpio_nums, values, count, gpios, "dai_uvc_set_gpios_on_stream_on")) return;
+        _dai_as_uvc(uvc)->setGpiosOnStreamOn(gpios);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_uvc_set_gpios_on_stream_on failed: ") + e.what();
+    }
+}
+
+void dai_uvc_set_gpios_on_stream_off(DaiNode uvc, const int* gpio_nums, const int* values, int count) {
+    if(!uvc) {
+        last_error = "dai_uvc_set_gpios_on_stream_off: null uvc";
+        return;
+    }
+    try {
+        std::unordered_map<int, int> gpios;
+        if(!_dai_gpio_map(gpio_nums, values, count, gpios, "dai_uvc_set_gpios_on_stream_off")) return;
+        _dai_as_uvc(uvc)->setGpiosOnStreamOff(gpios);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_uvc_set_gpios_on_stream_off failed: ") + e.what();
+    }
+}
+
+void dai_spi_out_set_stream_name(DaiNode spi, const char* name) {
+    if(!spi || !name) {
+        last_error = "dai_spi_out_set_stream_name: null spi or name";
+        return;
+    }
+    try {
+        static_cast<dai::node::SPIOut*>(spi)->setStreamName(name);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_spi_out_set_stream_name failed: ") + e.what();
+    }
+}
+
+void dai_spi_out_set_bus_id(DaiNode spi, int bus_id) {
+    if(!spi) {
+        last_error = "dai_spi_out_set_bus_id: null spi";
+        return;
+    }
+    try {
+        static_cast<dai::node::SPIOut*>(spi)->setBusId(bus_id);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_spi_out_set_bus_id failed: ") + e.what();
+    }
+}
+
+void dai_spi_in_set_stream_name(DaiNode spi, const char* name) {
+    if(!spi || !name) {
+        last_error = "dai_spi_in_set_stream_name: null spi or name";
+        return;
+    }
+    try {
+        static_cast<dai::node::SPIIn*>(spi)->setStreamName(name);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_spi_in_set_stream_name failed: ") + e.what();
+    }
+}
+
+void dai_spi_in_set_bus_id(DaiNode spi, int bus_id) {
+    if(!spi) {
+        last_error = "dai_spi_in_set_bus_id: null spi";
+        return;
+    }
+    try {
+        static_cast<dai::node::SPIIn*>(spi)->setBusId(bus_id);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_spi_in_set_bus_id failed: ") + e.what();
+    }
+}
+
+void dai_spi_in_set_max_data_size(DaiNode spi, uint32_t max_data_size) {
+    if(!spi) {
+        last_error = "dai_spi_in_set_max_data_size: null spi";
+        return;
+    }
+    try {
+        static_cast<dai::node::SPIIn*>(spi)->setMaxDataSize(max_data_size);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_spi_in_set_max_data_size failed: ") + e.what();
+    }
+}
+
+void dai_spi_in_set_num_frames(DaiNode spi, uint32_t num_frames) {
+    if(!spi) {
+        last_error = "dai_spi_in_set_num_frames: null spi";
+        return;
+    }
+    try {
+        static_cast<dai::node::SPIIn*>(spi)->setNumFrames(num_frames);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_spi_in_set_num_frames failed: ") + e.what();
+    }
+}
+
+char* dai_spi_in_get_stream_name(DaiNode spi) {
+    if(!spi) {
+        last_error = "dai_spi_in_get_stream_name: null spi";
         return nullptr;
     }
     try {
-        auto ptr = static_cast<std::shared_ptr<dai::MessageQueue>*>(queue);
-        auto pcl = (*ptr)->tryGet<dai::PointCloudData>();
-        if(!pcl) return nullptr;
-        auto view = new DaiPointCloudView();
-        view->msg = pcl;
-        view->points = pcl->getPointsRGB();
-        return static_cast<DaiPointCloud>(view);
+        return dai_string_to_cstring(static_cast<dai::node::SPIIn*>(spi)->getStreamstatic_cast<dai::node::SPIIn*>(spi)ame().c_str());
     } catch(const std::exception& e) {
-        last_error = std::string("dai_queue_try_get_pointcloud failed: ") + e.what();
+        last_error = std::string("dai_spi_in_get_stream_name failed: ") + e.what();
         return nullptr;
     }
 }
 
-int dai_pointcloud_get_width(DaiPointCloud pcl) {
-    if(!pcl) {
-        last_error = "dai_pointcloud_get_width: null pointcloud";
+int dai_spi_in_get_bus_id(DaiNode spi) {
+    if(!spi) {
+        last_error = "dai_spi_in_get_bus_id: null spi";
+        return 0;
+    }
+    try {
+        return static_cast<dai::node::SPIIn*>(spi)->getBusId();
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_spi_in_get_bus_id failed: ") + e.what();
         return 0;
     }
-    auto view = static_cast<DaiPointCloudView*>(pcl);
-    return static_cast<int>(view->msg ? view->msg->getWidth() : 0);
 }
 
-int dai_pointcloud_get_height(DaiPointCloud pcl) {
-    if(!pcl) {
-        last_error = "dai_pointcloud_get_height: null pointcloud";
+uint32_t dai_spi_in_get_max_data_size(DaiNode spi) {
+    if(!spi) {
+        last_error = "dai_spi_in_get_max_data_size: null spi";
+        return 0;
+    }
+    try {
+        return static_cast<dai::node::SPIIn*>(spi)->getMaxDataSize();
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_spi_in_get_max_data_size failed: ") + e.what();
+        return 0;
+    }
+}
+
+uint32_t dai_spi_in_get_num_frames(DaiNode spi) {
+    if(!spi) {
+        last_error = "dai_spi_in_get_num_frames: null spi";
+        return 0;
+    }
+    try {
+        return static_cast<dai::node::SPIIn*>(spi)->getstatic_cast<dai::node::SPIIn*>(spi)umFrames();
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_spi_in_get_num_frames failed: ") + e.what();
         return 0;
     }
-    auto view = static_cast<DaiPointCloudView*>(pcl);
-    return static_cast<int>(view->msg ? view->msg->getHeight() : 0);
 }
 
-const DaiPoint3fRGBA* dai_pointcloud_get_points_rgba(DaiPointCloud pcl) {
-    if(!pcl) {
-        last_error = "dai_pointcloud_get_points_rgba: null pointcloud";
-        return nullptr;
+// Same contract as `_dai_as_image_align_config`.
+static inline std::shared_ptr<dai::SpatialLocationCalculatorConfig> _dai_as_spatial_location_config(DaiBuffer cfg, const char* ctx) {
+    if(!cfg) {
+        last_error = std::string(ctx) + ": null cfg";
+        return nullptr;
+    }
+    auto base_ptr = static_cast<std::shared_ptr<dai::Buffer>*>(cfg);
+    auto typed = std::dynamic_pointer_cast<dai::SpatialLocationCalculatorConfig>(*base_ptr);
+    if(!typed) {
+        last_error = std::string(ctx) + ": cfg is not SpatialLocationCalculatorConfig";
+        return nullptr;
+    }
+    return typed;
+}
+
+static inline void _dai_read_spatial_location_roi(
+    const dai::SpatialLocationCalculatorConfigData& data, float* roi, uint32_t* lower_threshold, uint32_t* upper_threshold, int* algorithm, int* step) {
+    roi[0] = data.roi.x;
+    roi[1] = data.roi.y;
+    roi[2] = data.roi.width;
+    roi[3] = data.roi.height;
+    *lower_threshold = data.depthThresholds.lowerThreshold;
+    *upper_threshold = data.depthThresholds.upperThreshold;
+    *algorithm = static_cast<int>(data.calculationAlgorithm);
+    *step = static_cast<int>(data.stepSize);
+}
+
+DaiBuffer dai_spatial_location_config_new() {
+    try {
+        auto cfg = std::make_shared<dai::SpatialLocationCalculatorConfig>();
+        return new std::shared_ptr<dai::Buffer>(std::static_pointer_cast<dai::Buffer>(std::move(cfg)));
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_spatial_location_config_new failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+DaiBuffer dai_spatial_location_calculator_get_initial_config(DaiNode calc) {
+    if(!calc) {
+        last_error = "dai_spatial_location_calculator_get_initial_config: null calc";
+        return nullptr;
+    }
+    try {
+        auto c = static_cast<dai::node::SpatialLocationCalculator*>(calc);
+        if(!c->initialConfig) {
+            last_error = "dai_spatial_location_calculator_get_initial_config: initialConfig is null";
+            return nullptr;
+        }
+        return new std::shared_ptr<dai::Buffer>(std::static_pointer_cast<dai::Buffer>(c->initialConfig));
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_spatial_location_calculator_get_initial_config failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+void dai_spatial_location_calculator_set_wait_for_config_input(DaiNode calc, bool wait) {
+    if(!calc) {
+        last_error = "dai_spatial_location_calculator_set_wait_for_config_input: null calc";
+        return;
+    }
+    try {
+        static_cast<dai::node::SpatialLocationCalculator*>(calc)->inputConfig.setWaitForMessage(wait);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_spatial_location_calculator_set_wait_for_config_input failed: ") + e.what();
+    }
+}
+
+void dai_spatial_location_config_clear_rois(DaiBuffer cfg) {
+    try {
+        auto c = _dai_as_spatial_location_config(cfg, "dai_spatial_location_config_clear_rois");
+        if(!c) return;
+        c->setROIs({});
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_spatial_location_config_clear_rois failed: ") + e.what();
+    }
+}
+
+void dai_spatial_location_config_add_roi(DaiBuffer cfg, const float* roi, uint32_t lower_threshold, uint32_t upper_threshold, int algorithm, int step) {
+    if(!roi) {
+        last_error = "dai_spatial_location_config_add_roi: null roi";
+        return;
+    }
+    try {
+        auto c = _dai_as_spatial_location_config(cfg, "dai_spatial_location_config_add_roi");
+        if(!c) return;
+        dai::SpatialLocationCalculatorConfigData data;
+        data.roi = dai::Rect(roi[0], roi[1], roi[2], roi[3]);
+        data.depthThresholds.lowerThreshold = lower_threshold;
+        data.depthThresholds.upperThreshold = upper_threshold;
+        data.calculationAlgorithm = static_cast<dai::SpatialLocationCalculatorAlgorithm>(algorithm);
+        data.stepSize = step;
+        c->addROI(data);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_spatial_location_config_add_roi failed: ") + e.what();
+    }
+}
+
+size_t dai_spatial_location_config_get_num_rois(DaiBuffer cfg) {
+    try {
+        auto c = _dai_as_spatial_location_config(cfg, "dai_spatial_location_config_get_num_rois");
+        if(!c) return 0;
+        return c->getConfigData().size();
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_spatial_location_config_get_num_rois failed: ") + e.what();
+        return 0;
+    }
+}
+
+bool dai_spatial_location_config_get_roi(DaiBuffer cfg, size_t index, float* roi, uint32_t* lower_threshold, uint32_t* upper_threshold, int* algorithm, int* step) {
+    if(!roi || !lower_threshold || !upper_threshold || !algorithm || !step) {
+        last_error = "dai_spatial_location_config_get_roi: null argument";
+        return false;
+    }
+    try {
+        auto c = _dai_as_spatial_location_config(cfg, "dai_spatial_location_config_get_roi");
+        if(!c) return false;
+        auto rois = c->getConfigData();
+        if(index >= rois.size()) {
+            last_error = "dai_spatial_location_config_get_roi: index out of range";
+            return false;
+        }
+        _dai_read_spatial_location_roi(rois[index], roi, lower_threshold, upper_threshold, algorithm, step);
+        return true;
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_spatial_location_config_get_roi failed: ") + e.what();
+        return false;
+    }
+}
+
+DaiSpatialLocationData dai_datatype_as_spatial_location_data(DaiDatatype msg) {
+    if(!msg) {
+        last_error = "dai_datatype_as_spatial_location_data: null msg";
+        return nullptr;
+    }
+    try {
+        auto ptr = static_cast<std::shared_ptr<dai::ADatatype>*>(msg);
+        auto data = std::dynamic_pointer_cast<dai::SpatialLocationCalculatorData>(*ptr);
+        if(!data) return nullptr;
+        return static_cast<DaiSpatialLocationData>(new std::shared_ptr<dai::SpatialLocationCalculatorData>(data));
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_datatype_as_spatial_location_data failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+size_t dai_spatial_location_data_get_count(DaiSpatialLocationData data) {
+    if(!data) {
+        last_error = "dai_spatial_location_data_get_count: null data";
+        return 0;
+    }
+    auto ptr = static_cast<std::shared_ptr<dai::SpatialLocationCalculatorData>*>(data);
+    return ptr->get() ? (*ptr)->spatialLocations.size() : 0;
+}
+
+// Bounds-checked access to one spatial location, sets the last error on failure.
+static inline const dai::SpatialLocations* _dai_spatial_location_at(DaiSpatialLocationData data, size_t index, const char* ctx) {
+    auto ptr = static_cast<std::shared_ptr<dai::SpatialLocationCalculatorData>*>(data);
+    if(!ptr->get() || index >= (*ptr)->spatialLocations.size()) {
+        last_error = std::string(ctx) + ": index out of range";
+        return nullptr;
+    }
+    return &(*ptr)->spatialLocations[index];
+}
+
+bool dai_spatial_location_data_get_roi(DaiSpatialLocationData data, size_t index, float* roi, uint32_t* lower_threshold, uint32_t* upper_threshold, int* algorithm, int* step) {
+    if(!data || !roi || !lower_threshold || !upper_threshold || !algorithm || !step) {
+        last_error = "dai_spatial_location_data_get_roi: null argument";
+        return false;
+    }
+    try {
+        auto loc = _dai_spatial_location_at(data, index, "dai_spatial_location_data_get_roi");
+        if(!loc) return false;
+        _dai_read_spatial_location_roi(loc->config, roi, lower_threshold, upper_threshold, algorithm, step);
+        return true;
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_spatial_location_data_get_roi failed: ") + e.what();
+        return false;
+    }
+}
+
+bool dai_spatial_location_data_get_location(DaiSpatialLocationData data, size_t index, float* depth, uint32_t* pixel_count, float* xyz) {
+    if(!data || !depth || !pixel_count || !xyz) {
+        last_error = "dai_spatial_location_data_get_location: null argument";
+        return false;
+    }
+    try {
+        auto loc = _dai_spatial_location_at(data, index, "dai_spatial_location_data_get_location");
+        if(!loc) return false;
+        depth[0] = loc->depthAverage;
+        depth[1] = loc->depthMode;
+        depth[2] = loc->depthMedian;
+        depth[3] = static_cast<float>(loc->depthMin);
+        depth[4] = static_cast<float>(loc->depthMax);
+        *pixel_count = loc->depthAveragePixelCount;
+        xyz[0] = loc->spatialCoordinates.x;
+        xyz[1] = loc->spatialCoordinates.y;
+        xyz[2] = loc->spatialCoordinates.z;
+        return true;
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_spatial_location_data_get_location failed: ") + e.what();
+        return false;
+    }
+}
+
+void dai_spatial_location_data_release(DaiSpatialLocationData data) {
+    if(data) {
+        delete static_cast<std::shared_ptr<dai::SpatialLocationCalculatorData>*>(data);
+    }
+}
+
+DaiDatatype dai_input_get_datatype(DaiInput input) {
+    if(!input) {
+        last_error = "dai_input_get_datatype: null input";
+        return nullptr;
+    }
+    try {
+        auto in = static_cast<dai::Node::Input*>(input);
+        auto msg = in->get<dai::ADatatype>();
+        if(!msg) return nullptr;
+        return new std::shared_ptr<dai::ADatatype>(msg);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_input_get_datatype failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+DaiDatatype dai_input_try_get_datatype(DaiInput input) {
+    if(!input) {
+        last_error = "dai_input_try_get_datatype: null input";
+        return nullptr;
+    }
+    try {
+        auto in = static_cast<dai::Node::Input*>(input);
+        auto msg = in->tryGet<dai::ADatatype>();
+        if(!msg) return nullptr;
+        return new std::shared_ptr<dai::ADatatype>(msg);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_input_try_get_datatype failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+// Wrapper-owned detections view, so plain and spatial detections share one accessor set.
+struct DaiImgDetectionsView {
+    std::shared_ptr<dai::Buffer> msg;
+    std::vector<dai::ImgDetection> detections;
+};
+
+DaiImgDetections dai_datatype_as_img_detections(DaiDatatype msg) {
+    if(!msg) {
+        last_error = "dai_datatype_as_img_detections: null msg";
+        return nullptr;
+    }
+    try {
+        auto ptr = static_cast<std::shared_ptr<dai::ADatatype>*>(msg);
+        if(auto dets = std::dynamic_pointer_cast<dai::ImgDetections>(*ptr)) {
+            auto view = new DaiImgDetectionsView();
+            view->msg = dets;
+            view->detections = dets->detections;
+            return static_cast<DaiImgDetections>(view);
+        }
+        if(auto dets = std::dynamic_pointer_cast<dai::SpatialImgDetections>(*ptr)) {
+            auto view = new DaiImgDetectionsView();
+            view->msg = dets;
+            view->detections.assign(dets->detections.begin(), dets->detections.end());
+            return static_cast<DaiImgDetections>(view);
+        }
+        return nullptr;
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_datatype_as_img_detections failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+size_t dai_img_detections_get_count(DaiImgDetections dets) {
+    if(!dets) {
+        last_error = "dai_img_detections_get_count: null detections";
+        return 0;
+    }
+    return static_cast<DaiImgDetectionsView*>(dets)->detections.size();
+}
+
+bool dai_img_detections_get(DaiImgDetections dets, size_t index, int* label, float* confidence, float* bbox) {
+    if(!dets || !label || !confidence || !bbox) {
+        last_error = "dai_img_detections_get: null argument";
+        return false;
+    }
+    auto view = static_cast<DaiImgDetectionsView*>(dets);
+    if(index >= view->detections.size()) {
+        last_error = "dai_img_detections_get: index out of range";
+        return false;
+    }
+    const auto& det = view->detections[index];
+    *label = static_cast<int>(det.label);
+    *confidence = det.confidence;
+    bbox[0] = det.xmin;
+    bbox[1] = det.ymin;
+    bbox[2] = det.xmax;
+    bbox[3] = det.ymax;
+    return true;
+}
+
+int64_t dai_img_detections_get_timestamp_ns(DaiImgDetections dets) {
+    if(!dets) {
+        last_error = "dai_img_detections_get_timestamp_ns: null detections";
+        return 0;
+    }
+    try {
+        auto ts = static_cast<DaiImgDetectionsView*>(dets)->msg->getTimestamp();
+        return std::chrono::duration_cast<std::chrono::nanoseconds>(ts.time_since_epoch()).count();
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_img_detections_get_timestamp_ns failed: ") + e.what();
+        return 0;
+    }
+}
+
+void dai_img_detections_set_timestamp_ns(DaiImgDetections dets, int64_t ns) {
+    if(!dets) {
+        last_error = "dai_img_detections_set_timestamp_ns: null detections";
+        return;
+    }
+    try {
+        static_cast<DaiImgDetectionsView*>(dets)->msg->setTimestamp(std::chrono::steady_clock::time_point(std::chrono::nanoseconds(ns)));
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_img_detections_set_timestamp_ns failed: ") + e.what();
+    }
+}
+
+int64_t dai_img_detections_get_sequence_num(DaiImgDetections dets) {
+    if(!dets) {
+        last_error = "dai_img_detections_get_sequence_num: null detections";
+        return 0;
+    }
+    try {
+        return static_cast<DaiImgDetectionsView*>(dets)->msg->getSequenceNum();
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_img_detections_get_sequence_num failed: ") + e.what();
+        return 0;
+    }
+}
+
+void dai_img_detections_set_sequence_num(DaiImgDetections dets, int64_t seq) {
+    if(!dets) {
+        last_error = "dai_img_detections_set_sequence_num: null detections";
+        return;
+    }
+    try {
+        static_cast<DaiImgDetectionsView*>(dets)->msg->setSequenceNum(seq);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_img_detections_set_sequence_num failed: ") + e.what();
+    }
+}
+
+void dai_img_detections_release(DaiImgDetections dets) {
+    if(dets) {
+        delete static_cast<DaiImgDetectionsView*>(dets);
+    }
+}
+
+char* dai_model_zoo_get_model(const char* model, const char* platform, const char* optimization_level, const char* compression_level, const char* snpe_version, const char* precision, bool use_cached, const char* cache_dir, const char* api_key, bool show_progress) {
+    if(!model) {
+        last_error = "dai_model_zoo_get_model: null model";
+        return nullptr;
+    }
+    try {
+        auto str = [](const char* s) { return s ? std::string(s) : std::string(); };
+        dai::NNModelDescription description;
+        description.model = model;
+        description.platform = str(platform);
+        description.optimizationLevel = str(optimization_level);
+        description.compressionLevel = str(compression_level);
+        description.snpeVersion = str(snpe_version);
+        description.modelPrecisionType = str(precision);
+        auto path = dai::getModelFromZoo(description, use_cached, str(cache_dir), str(api_key), show_progress ? "pretty" : "none");
+        return dai_string_to_cstring(path.c_str());
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_model_zoo_get_model failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+bool dai_model_zoo_download_models(const char* yaml_dir, const char* cache_dir, const char* api_key, bool show_progress) {
+    if(!yaml_dir) {
+        last_error = "dai_model_zoo_download_models: null path";
+        return false;
+    }
+    try {
+        auto str = [](const char* s) { return s ? std::string(s) : std::string(); };
+        return dai::downloadModelsFromZoo(yaml_dir, str(cache_dir), str(api_key), show_progress ? "pretty" : "none");
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_model_zoo_download_models failed: ") + e.what();
+        return false;
+    }
+}
+
+DaiNNArchive dai_nn_archive_new(const char* path) {
+    if(!path) {
+        last_error = "dai_nn_archive_new: null path";
+        return nullptr;
+    }
+    try {
+        return static_cast<DaiNNArchive>(new dai::NNArchive(path));
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_nn_archive_new failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+void dai_nn_archive_release(DaiNNArchive archive) {
+    delete static_cast<dai::NNArchive*>(archive);
+}
+
+char* dai_nn_archive_get_info_json(DaiNNArchive archive) {
+    if(!archive) {
+        last_error = "dai_nn_archive_get_info_json: null archive";
+        return nullptr;
+    }
+    try {
+        auto nn = static_cast<dai::NNArchive*>(archive);
+        nlohmann::json info;
+        switch(nn->getModelType()) {
+            case dai::model::ModelType::BLOB: info["model_type"] = "blob"; break;
+            case dai::model::ModelType::SUPERBLOB: info["model_type"] = "superblob"; break;
+            case dai::model::ModelType::DLC: info["model_type"] = "dlc"; break;
+            case dai::model::ModelType::NNARCHIVE: info["model_type"] = "nnarchive"; break;
+            default: info["model_type"] = "other"; break;
+        }
+        auto platforms = nlohmann::json::array();
+        for(auto platform : nn->getSupportedPlatforms()) {
+            platforms.push_back(dai::platform2string(platform));
+        }
+        info["platforms"] = platforms;
+
+        const auto& model = nn->getConfig<dai::nn_archive::v1::Config>().model;
+        info["name"] = model.metadata.name;
+        auto inputs = nlohmann::json::array();
+        for(const auto& input : model.inputs) {
+            nlohmann::json entry;
+            entry["name"] = input.name;
+            entry["shape"] = input.shape;
+            if(input.layout) entry["layout"] = *input.layout;
+            inputs.push_back(entry);
+        }
+        info["inputs"] = inputs;
+        auto outputs = nlohmann::json::array();
+        for(const auto& output : model.outputs) {
+            outputs.push_back(output.name);
+        }
+        info["outputs"] = outputs;
+        auto heads = nlohmann::json::array();
+        if(model.heads) {
+            for(const auto& head : *model.heads) {
+                nlohmann::json entry;
+                entry["parser"] = head.parser;
+                entry["classes"] = head.metadata.classes.value_or(std::vector<std::string>{});
+                if(head.metadata.nClasses) entry["n_classes"] = *head.metadata.nClasses;
+                heads.push_back(entry);
+            }
+        }
+        info["heads"] = heads;
+        return dai_string_to_cstring(info.dump().c_str());
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_nn_archive_get_info_json failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+bool dai_neural_network_set_nn_archive(DaiNode nn, DaiNNArchive archive, int num_shaves) {
+    if(!nn || !archive) {
+        last_error = "dai_neural_network_set_nn_archive: null argument";
+        return false;
+    }
+    try {
+        auto node = static_cast<dai::node::NeuralNetwork*>(nn);
+        auto a = static_cast<dai::NNArchive*>(archive);
+        if(num_shaves > 0) {
+            node->setNNArchive(*a, num_shaves);
+        } else {
+            node->setNNArchive(*a);
+        }
+        return true;
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_neural_network_set_nn_archive failed: ") + e.what();
+        return false;
+    }
+}
+
+void dai_neural_network_set_num_inference_threads(DaiNode nn, int threads) {
+    if(!nn) {
+        last_error = "dai_neural_network_set_num_inference_threads: null node";
+        return;
+    }
+    try {
+        static_cast<dai::node::NeuralNetwork*>(nn)->setNumInferenceThreads(threads);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_neural_network_set_num_inference_threads failed: ") + e.what();
+    }
+}
+
+bool dai_detection_network_set_nn_archive(DaiNode nn, DaiNNArchive archive, int num_shaves) {
+    if(!nn || !archive) {
+        last_error = "dai_detection_network_set_nn_archive: null argument";
+        return false;
+    }
+    try {
+        auto node = static_cast<dai::node::DetectionNetwork*>(nn);
+        auto a = static_cast<dai::NNArchive*>(archive);
+        if(num_shaves > 0) {
+            node->setNNArchive(*a, num_shaves);
+        } else {
+            node->setNNArchive(*a);
+        }
+        return true;
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_detection_network_set_nn_archive failed: ") + e.what();
+        return false;
+    }
+}
+
+void dai_detection_network_set_confidence_threshold(DaiNode nn, float threshold) {
+    if(!nn) {
+        last_error = "dai_detection_network_set_confidence_threshold: null node";
+        return;
+    }
+    try {
+        static_cast<dai::node::DetectionNetwork*>(nn)->setConfidenceThreshold(threshold);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_detection_network_set_confidence_threshold failed: ") + e.what();
+    }
+}
+
+void dai_neural_network_set_num_shaves_per_inference_thread(DaiNode nn, int shaves) {
+    if(!nn) {
+        last_error = "dai_neural_network_set_num_shaves_per_inference_thread: null node";
+        return;
+    }
+    try {
+        static_cast<dai::node::NeuralNetwork*>(nn)->setNumShavesPerInferenceThread(shaves);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_neural_network_set_num_shaves_per_inference_thread failed: ") + e.what();
+    }
+}
+
+void dai_neural_network_set_num_nce_per_inference_thread(DaiNode nn, int nces) {
+    if(!nn) {
+        last_error = "dai_neural_network_set_num_nce_per_inference_thread: null node";
+        return;
+    }
+    try {
+        static_cast<dai::node::NeuralNetwork*>(nn)->setNumNCEPerInferenceThread(nces);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_neural_network_set_num_nce_per_inference_thread failed: ") + e.what();
+    }
+}
+
+void dai_neural_network_set_num_pool_frames(DaiNode nn, int frames) {
+    if(!nn) {
+        last_error = "dai_neural_network_set_num_pool_frames: null node";
+        return;
+    }
+    try {
+        static_cast<dai::node::NeuralNetwork*>(nn)->setNumPoolFrames(frames);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_neural_network_set_num_pool_frames failed: ") + e.what();
+    }
+}
+
+void dai_detection_network_set_num_inference_threads(DaiNode nn, int threads) {
+    if(!nn) {
+        last_error = "dai_detection_network_set_num_inference_threads: null node";
+        return;
+    }
+    try {
+        static_cast<dai::node::DetectionNetwork*>(nn)->setNumInferenceThreads(threads);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_detection_network_set_num_inference_threads failed: ") + e.what();
+    }
+}
+
+void dai_detection_network_set_num_shaves_per_inference_thread(DaiNode nn, int shaves) {
+    if(!nn) {
+        last_error = "dai_detection_network_set_num_shaves_per_inference_thread: null node";
+        return;
+    }
+    try {
+        static_cast<dai::node::DetectionNetwork*>(nn)->setNumShavesPerInferenceThread(shaves);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_detection_network_set_num_shaves_per_inference_thread failed: ") + e.what();
+    }
+}
+
+void dai_detection_network_set_num_nce_per_inference_thread(DaiNode nn, int nces) {
+    if(!nn) {
+        last_error = "dai_detection_network_set_num_nce_per_inference_thread: null node";
+        return;
+    }
+    try {
+        static_cast<dai::node::DetectionNetwork*>(nn)->setNumNCEPerInferenceThread(nces);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_detection_network_set_num_nce_per_inference_thread failed: ") + e.what();
+    }
+}
+
+static inline std::shared_ptr<dai::NNData>& _dai_nn_data(DaiNNData data) {
+    return *static_cast<std::shared_ptr<dai::NNData>*>(data);
+}
+
+DaiNNData dai_datatype_as_nn_data(DaiDatatype msg) {
+    if(!msg) {
+        last_error = "dai_datatype_as_nn_data: null msg";
+        return nullptr;
+    }
+    try {
+        auto ptr = static_cast<std::shared_ptr<dai::ADatatype>*>(msg);
+        auto nn = std::dynamic_pointer_cast<dai::NNData>(*ptr);
+        if(!nn) return nullptr;
+        return static_cast<DaiNNData>(new std::shared_ptr<dai::NNData>(nn));
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_datatype_as_nn_data failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+DaiNNData dai_message_group_get_nn_data(DaiMessageGroup group, const char* name) {
+    if(!group) {
+        last_error = "dai_message_group_get_nn_data: null group";
+        return nullptr;
+    }
+    if(_dai_cstr_empty(name)) {
+        last_error = "dai_message_group_get_nn_data: empty name";
+        return nullptr;
+    }
+    try {
+        auto ptr = static_cast<std::shared_ptr<dai::MessageGroup>*>(group);
+        auto nn = std::dynamic_pointer_cast<dai::NNData>((*ptr)->get(std::string(name)));
+        if(!nn) return nullptr;
+        return static_cast<DaiNNData>(new std::shared_ptr<dai::NNData>(nn));
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_message_group_get_nn_data failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+char* dai_nn_data_get_layers_json(DaiNNData data) {
+    if(!data) {
+        last_error = "dai_nn_data_get_layers_json: null data";
+        return nullptr;
+    }
+    try {
+        nlohmann::json layers = nlohmann::json::array();
+        for(const auto& tensor : _dai_nn_data(data)->getAllLayers()) {
+            layers.push_back({{"name", tensor.name}, {"dims", tensor.dims}, {"dataType", static_cast<int>(tensor.dataType)}});
+        }
+        return dai_string_to_cstring(layers.dump().c_str());
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_nn_data_get_layers_json failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+size_t dai_nn_data_get_tensor_f32(DaiNNData data, const char* name, float* out, size_t capacity) {
+    if(!data) {
+        last_error = "dai_nn_data_get_tensor_f32: null data";
+        return 0;
+    }
+    if(_dai_cstr_empty(name)) {
+        last_error = "dai_nn_data_get_tensor_f32: empty name";
+        return 0;
+    }
+    try {
+        auto tensor = _dai_nn_data(data)->getTensor<float>(std::string(name), /*dequantize=*/true);
+        const size_t count = tensor.size();
+        if(out) {
+            std::copy_n(tensor.data(), std::min(count, capacity), out);
+        }
+        return count;
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_nn_data_get_tensor_f32 failed: ") + e.what();
+        return 0;
+    }
+}
+
+int64_t dai_nn_data_get_timestamp_ns(DaiNNData data) {
+    if(!data) {
+        last_error = "dai_nn_data_get_timestamp_ns: null data";
+        return 0;
+    }
+    try {
+        auto ts = _dai_nn_data(data)->getTimestamp();
+        return std::chrono::duration_cast<std::chrono::nanoseconds>(ts.time_since_epoch()).count();
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_nn_data_get_timestamp_ns failed: ") + e.what();
+        return 0;
+    }
+}
+
+int64_t dai_nn_data_get_sequence_num(DaiNNData data) {
+    if(!data) {
+        last_error = "dai_nn_data_get_sequence_num: null data";
+        return 0;
+    }
+    try {
+        return _dai_nn_data(data)->getSequenceNum();
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_nn_data_get_sequence_num failed: ") + e.what();
+        return 0;
+    }
+}
+
+void dai_nn_data_release(DaiNNData data) {
+    if(data) {
+        delete static_cast<std::shared_ptr<dai::NNData>*>(data);
+    }
+}
+
+DaiImgDetections dai_img_detections_new() {
+    try {
+        dai_clear_last_error();
+        auto view = new DaiImgDetectionsView();
+        view->msg = std::make_shared<dai::ImgDetections>();
+        return static_cast<DaiImgDetections>(view);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_img_detections_new failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+bool dai_img_detections_push(DaiImgDetections dets, int label, float confidence, const float* bbox) {
+    if(!dets || !bbox) {
+        last_error = "dai_img_detections_push: null argument";
+        return false;
+    }
+    try {
+        auto view = static_cast<DaiImgDetectionsView*>(dets);
+        auto msg = std::dynamic_pointer_cast<dai::ImgDetections>(view->msg);
+        if(!msg) {
+            last_error = "dai_img_detections_push: received detections are read-only";
+            return false;
+        }
+        dai::ImgDetection det;
+        det.label = static_cast<uint32_t>(std::max(label, 0));
+        det.confidence = confidence;
+        det.xmin = bbox[0];
+        det.ymin = bbox[1];
+        det.xmax = bbox[2];
+        det.ymax = bbox[3];
+        msg->detections.push_back(det);
+        view->detections.push_back(det);
+        return true;
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_img_detections_push failed: ") + e.what();
+        return false;
+    }
+}
+
+void dai_img_detections_copy_metadata(DaiImgDetections dets, DaiNNData source) {
+    if(!dets || !source) {
+        last_error = "dai_img_detections_copy_metadata: null argument";
+        return;
+    }
+    try {
+        auto msg = static_cast<DaiImgDetectionsView*>(dets)->msg;
+        const auto& src = _dai_nn_data(source);
+        msg->setTimestamp(src->getTimestamp());
+        msg->setTimestampDevice(src->getTimestampDevice());
+        msg->setSequenceNum(src->getSequenceNum());
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_img_detections_copy_metadata failed: ") + e.what();
+    }
+}
+
+DaiBuffer dai_img_detections_to_buffer(DaiImgDetections dets) {
+    if(!dets) {
+        last_error = "dai_img_detections_to_buffer: null detections";
+        return nullptr;
+    }
+    return static_cast<DaiBuffer>(new std::shared_ptr<dai::Buffer>(static_cast<DaiImgDetectionsView*>(dets)->msg));
+}
+
+void dai_output_send_img_detections(DaiOutput output, DaiImgDetections dets) {
+    if(!output || !dets) {
+        last_error = "dai_output_send_img_detections: null argument";
+        return;
+    }
+    try {
+        static_cast<dai::Node::Output*>(output)->send(static_cast<DaiImgDetectionsView*>(dets)->msg);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_output_send_img_detections failed: ") + e.what();
+    }
+}
+
+DaiBuffer dai_img_annotations_from_json(const char* json) {
+    if(!json) {
+        last_error = "dai_img_annotations_from_json: null json";
+        return nullptr;
+    }
+    try {
+        auto j = nlohmann::json::parse(json);
+        auto color = [](const nlohmann::json& c) {
+            dai::Color out{};
+            if(c.is_array() && c.size() == 4) {
+                out.r = c[0].get<float>();
+                out.g = c[1].get<float>();
+                out.b = c[2].get<float>();
+                out.a = c[3].get<float>();
+            }
+            return out;
+        };
+        auto point = [](const nlohmann::json& p) { return dai::Point2f(p.at(0).get<float>(), p.at(1).get<float>()); };
+
+        dai::ImgAnnotation annotation;
+        for(const auto& item : j.value("points", nlohmann::json::array())) {
+            dai::PointsAnnotation points;
+            points.type = static_cast<dai::PointsAnnotationType>(item.value("type", 0));
+            for(const auto& p : item.at("points")) points.points.push_back(point(p));
+            points.outlineColor = color(item.value("outlineColor", nlohmann::json()));
+            points.fillColor = color(item.value("fillColor", nlohmann::json()));
+            points.thickness = item.value("thickness", 1.0f);
+            annotation.points.push_back(std::move(points));
+        }
+        for(const auto& item : j.value("texts", nlohmann::json::array())) {
+            dai::TextAnnotation text;
+            text.position = point(item.at("position"));
+            text.text = item.value("text", std::string());
+            text.fontSize = item.value("fontSize", 0.0f);
+            text.textColor = color(item.value("textColor", nlohmann::json()));
+            text.backgroundColor = color(item.value("backgroundColor", nlohmann::json()));
+            annotation.texts.push_back(std::move(text));
+        }
+        auto msg = std::make_shared<dai::ImgAnnotations>();
+        msg->annotations.push_back(std::move(annotation));
+        return static_cast<DaiBuffer>(new std::shared_ptr<dai::Buffer>(std::move(msg)));
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_img_annotations_from_json failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+bool dai_detection_parser_set_nn_archive(DaiNode parser, DaiNNArchive archive) {
+    if(!parser || !archive) {
+        last_error = "dai_detection_parser_set_nn_archive: null argument";
+        return false;
+    }
+    try {
+        static_cast<dai::node::DetectionParser*>(parser)->setNNArchive(*static_cast<dai::NNArchive*>(archive));
+        return true;
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_detection_parser_set_nn_archive failed: ") + e.what();
+        return false;
+    }
+}
+
+void dai_detection_parser_set_confidence_threshold(DaiNode parser, float threshold) {
+    if(!parser) {
+        last_error = "dai_detection_parser_set_confidence_threshold: null node";
+        return;
+    }
+    try {
+        static_cast<dai::node::DetectionParser*>(parser)->setConfidenceThreshold(threshold);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_detection_parser_set_confidence_threshold failed: ") + e.what();
+    }
+}
+
+void dai_detection_parser_set_num_classes(DaiNode parser, int num_classes) {
+    if(!parser) {
+        last_error = "dai_detection_parser_set_num_classes: null node";
+        return;
+    }
+    try {
+        static_cast<dai::node::DetectionParser*>(parser)->setNumClasses(num_classes);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_detection_parser_set_num_classes failed: ") + e.what();
+    }
+}
+
+void dai_detection_parser_set_iou_threshold(DaiNode parser, float threshold) {
+    if(!parser) {
+        last_error = "dai_detection_parser_set_iou_threshold: null node";
+        return;
+    }
+    try {
+        static_cast<dai::node::DetectionParser*>(parser)->setIouThreshold(threshold);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_detection_parser_set_iou_threshold failed: ") + e.what();
+    }
+}
+
+DaiDatatype dai_input_get_datatype_timeout(DaiInput input, int timeout_ms) {
+    if(!input) {
+        last_error = "dai_input_get_datatype_timeout: null input";
+        return nullptr;
+    }
+    try {
+        auto in = static_cast<dai::Node::Input*>(input);
+        bool timedOut = false;
+        auto msg = in->get<dai::ADatatype>(std::chrono::milliseconds(std::max(timeout_ms, 0)), timedOut);
+        if(timedOut || !msg) return nullptr;
+        return new std::shared_ptr<dai::ADatatype>(msg);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_input_get_datatype_timeout failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+struct _DaiLogCallbackState {
+    void* ctx = nullptr;
+    dai::DaiLogCallback cb = nullptr;
+    dai::DaiHostNodeCallback drop = nullptr;
+    ~_DaiLogCallbackState() {
+        if(drop) {
+            drop(ctx);
+        }
+    }
+};
+
+int dai_device_add_log_callback(DaiDevice device, void* ctx, uintptr_t cb, uintptr_t drop_cb) {
+    if(!device) {
+        last_error = "dai_device_add_log_callback: null device";
+        return -1;
+    }
+    if(cb == 0) {
+        last_error = "dai_device_add_log_callback: null callback";
+        return -1;
+    }
+    try {
+        auto dev = static_cast<std::shared_ptr<dai::Device>*>(device);
+        if(!dev->get() || !(*dev)) {
+            last_error = "dai_device_add_log_callback: invalid device";
+            return -1;
+        }
+        auto state = std::make_shared<_DaiLogCallbackState>();
+        state->ctx = ctx;
+        state->cb = reinterpret_cast<DaiLogCallback>(cb);
+        state->drop = drop_cb == 0 ? nullptr : reinterpret_cast<DaiHostNodeCallback>(drop_cb);
+        return (*dev)->addLogCallback([state](dai::LogMessage msg) {
+            if(!state || !state->cb) return;
+            int64_t time_ns = msg.time.sec * 1000000000LL + msg.time.nsec;
+            state->cb(state->ctx, msg.nodeIdName.c_str(), static_cast<int>(msg.level), time_ns, msg.payload.c_str());
+        });
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_device_add_log_callback failed: ") + e.what();
+        return -1;
+    }
+}
+
+bool dai_device_remove_log_callback(DaiDevice device, int callback_id) {
+    if(!device) {
+        last_error = "dai_device_remove_log_callback: null device";
+        return false;
+    }
+    try {
+        auto dev = static_cast<std::shared_ptr<dai::Device>*>(device);
+        if(!dev->get() || !(*dev)) {
+            last_error = "dai_device_remove_log_callback: invalid device";
+            return false;
+        }
+        return (*dev)->removeLogCallback(callback_id);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_device_remove_log_callback failed: ") + e.what();
+        return false;
+    }
+}
+
+// Same contract as `_dai_as_image_manip_config`.
+static inline std::shared_ptr<dai::CameraControl> _dai_as_camera_control(DaiBuffer ctrl, const char* ctx) {
+    if(!ctrl) {
+        last_error = std::string(ctx) + ": null ctrl";
+        return nullptr;
+    }
+    auto base_ptr = static_cast<std::shared_ptr<dai::Buffer>*>(ctrl);
+    auto typed = std::dynamic_pointer_cast<dai::CameraControl>(*base_ptr);
+    if(!typed) {
+        last_error = std::string(ctx) + ": ctrl is not CameraControl";
+        return nullptr;
+    }
+    return typed;
+}
+
+DaiBuffer dai_camera_control_new() {
+    try {
+        auto ctrl = std::make_shared<dai::CameraControl>();
+        return new std::shared_ptr<dai::Buffer>(std::static_pointer_cast<dai::Buffer>(std::move(ctrl)));
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_camera_control_new failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+void dai_camera_control_set_auto_exposure_region(DaiBuffer ctrl, uint16_t x, uint16_t y, uint16_t width, uint16_t height) {
+    try {
+        auto c = _dai_as_camera_control(ctrl, "dai_camera_control_set_auto_exposure_region");
+        if(!c) return;
+        c->setAutoExposureRegion(x, y, width, height);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_camera_control_set_auto_exposure_region failed: ") + e.what();
+    }
+}
+
+void dai_camera_control_set_auto_exposure_limit(DaiBuffer ctrl, uint32_t max_exposure_us) {
+    try {
+        auto c = _dai_as_camera_control(ctrl, "dai_camera_control_set_auto_exposure_limit");
+        if(!c) return;
+        c->setAutoExposureLimit(max_exposure_us);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_camera_control_set_auto_exposure_limit failed: ") + e.what();
+    }
+}
+
+void dai_camera_control_set_auto_exposure_lock(DaiBuffer ctrl, bool lock) {
+    try {
+        auto c = _dai_as_camera_control(ctrl, "dai_camera_control_set_auto_exposure_lock");
+        if(!c) return;
+        c->setAutoExposureLock(lock);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_camera_control_set_auto_exposure_lock failed: ") + e.what();
+    }
+}
+
+void dai_camera_control_set_auto_focus_mode(DaiBuffer ctrl, int mode) {
+    try {
+        auto c = _dai_as_camera_control(ctrl, "dai_camera_control_set_auto_focus_mode");
+        if(!c) return;
+        c->setAutoFocusMode(static_cast<dai::CameraControl::AutoFocusMode>(mode));
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_camera_control_set_auto_focus_mode failed: ") + e.what();
+    }
+}
+
+void dai_camera_control_set_auto_focus_trigger(DaiBuffer ctrl) {
+    try {
+        auto c = _dai_as_camera_control(ctrl, "dai_camera_control_set_auto_focus_trigger");
+        if(!c) return;
+        c->setAutoFocusTrigger();
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_camera_control_set_auto_focus_trigger failed: ") + e.what();
+    }
+}
+
+void dai_camera_control_set_auto_white_balance_lock(DaiBuffer ctrl, bool lock) {
+    try {
+        auto c = _dai_as_camera_control(ctrl, "dai_camera_control_set_auto_white_balance_lock");
+        if(!c) return;
+        c->setAutoWhiteBalanceLock(lock);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_camera_control_set_auto_white_balance_lock failed: ") + e.what();
+    }
+}
+
+void dai_camera_control_set_streaming(DaiBuffer ctrl, bool streaming) {
+    try {
+        auto c = _dai_as_camera_control(ctrl, "dai_camera_control_set_streaming");
+        if(!c) return;
+        if(streaming) {
+            c->setStartStreaming();
+        } else {
+            c->setStopStreaming();
+        }
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_camera_control_set_streaming failed: ") + e.what();
+    }
+}
+
+void dai_camera_control_set_frame_sync_mode(DaiBuffer ctrl, int mode) {
+    try {
+        auto c = _dai_as_camera_control(ctrl, "dai_camera_control_set_frame_sync_mode");
+        if(!c) return;
+        c->setFrameSyncMode(static_cast<dai::CameraControl::FrameSyncMode>(mode));
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_camera_control_set_frame_sync_mode failed: ") + e.what();
+    }
+}
+
+void dai_camera_control_set_external_trigger(DaiBuffer ctrl, int num_frames_burst, int num_frames_discard) {
+    try {
+        auto c = _dai_as_camera_control(ctrl, "dai_camera_control_set_external_trigger");
+        if(!c) return;
+        c->setExternalTrigger(num_frames_burst, num_frames_discard);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_camera_control_set_external_trigger failed: ") + e.what();
+    }
+}
+
+// Wrapper-owned pointcloud view. PointCloudData::getPointsRGB() returns by value, so we
+// store the returned vector and expose a stable pointer + length to Rust.
+struct DaiPointCloudView {
+    std::shared_ptr<dai::PointCloudData> msg;
+    std::vector<dai::Point3fRGBA> points;
+};
+
+DaiPointCloud dai_queue_get_pointcloud(DaiDataQueue queue, int timeout_ms) {
+    if(!queue) {
+        last_error = "dai_queue_get_pointcloud: null queue";
+        return nullptr;
+    }
+    try {
+        auto ptr = static_cast<std::shared_ptr<dai::MessageQueue>*>(queue);
+        std::shared_ptr<dai::PointCloudData> pcl;
+        if(timeout_ms < 0) {
+            pcl = (*ptr)->get<dai::PointCloudData>();
+        } else {
+            bool timedOut = false;
+            pcl = (*ptr)->get<dai::PointCloudData>(std::chrono::milliseconds(timeout_ms), timedOut);
+            if(timedOut) return nullptr;
+        }
+        if(!pcl) return nullptr;
+
+        auto view = new DaiPointCloudView();
+        view->msg = pcl;
+        view->points = pcl->getPointsRGB();
+        return static_cast<DaiPointCloud>(view);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_queue_get_pointcloud failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+DaiPointCloud dai_queue_try_get_pointcloud(DaiDataQueue queue) {
+    if(!queue) {
+        last_error = "dai_queue_try_get_pointcloud: null queue";
+        return nullptr;
+    }
+    try {
+        auto ptr = static_cast<std::shared_ptr<dai::MessageQueue>*>(queue);
+        auto pcl = (*ptr)->tryGet<dai::PointCloudData>();
+        if(!pcl) return nullptr;
+        auto view = new DaiPointCloudView();
+        view->msg = pcl;
+        view->points = pcl->getPointsRGB();
+        return static_cast<DaiPointCloud>(view);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_queue_try_get_pointcloud failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+int dai_pointcloud_get_width(DaiPointCloud pcl) {
+    if(!pcl) {
+        last_error = "dai_pointcloud_get_width: null pointcloud";
+        return 0;
+    }
+    auto view = static_cast<DaiPointCloudView*>(pcl);
+    return static_cast<int>(view->msg ? view->msg->getWidth() : 0);
+}
+
+int dai_pointcloud_get_height(DaiPointCloud pcl) {
+    if(!pcl) {
+        last_error = "dai_pointcloud_get_height: null pointcloud";
+        return 0;
+    }
+    auto view = static_cast<DaiPointCloudView*>(pcl);
+    return static_cast<int>(view->msg ? view->msg->getHeight() : 0);
+}
+
+const DaiPoint3fRGBA* dai_pointcloud_get_points_rgba(DaiPointCloud pcl) {
+    if(!pcl) {
+        last_error = "dai_pointcloud_get_points_rgba: null pointcloud";
+        return nullptr;
+    }
+    auto view = static_cast<DaiPointCloudView*>(pcl);
+    if(view->points.empty()) return nullptr;
+    return reinterpret_cast<const DaiPoint3fRGBA*>(view->points.data());
+}
+
+size_t dai_pointcloud_get_points_rgba_len(DaiPointCloud pcl) {
+    if(!pcl) {
+        last_error = "dai_pointcloud_get_points_rgba_len: null pointcloud";
+        return 0;
+    }
+    auto view = static_cast<DaiPointCloudView*>(pcl);
+    return view->points.size();
+}
+
+void dai_pointcloud_release(DaiPointCloud pcl) {
+    if(pcl) {
+        auto view = static_cast<DaiPointCloudView*>(pcl);
+        delete view;
+    }
+}
+
+static inline dai::node::IMU* _dai_as_imu(DaiNode imu) {
+    return static_cast<dai::node::IMU*>(imu);
+}
+
+void dai_imu_enable_sensor(DaiNode imu, int sensor, int report_rate_hz) {
+    if(!imu) {
+        last_error = "dai_imu_enable_sensor: null imu";
+        return;
+    }
+    try {
+        _dai_as_imu(imu)->enableIMUSensor(static_cast<dai::IMUSensor>(sensor), static_cast<uint32_t>(report_rate_hz));
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_imu_enable_sensor failed: ") + e.what();
+    }
+}
+
+void dai_imu_set_batch_report_threshold(DaiNode imu, int threshold) {
+    if(!imu) {
+        last_error = "dai_imu_set_batch_report_threshold: null imu";
+        return;
+    }
+    try {
+        _dai_as_imu(imu)->setBatchReportThreshold(threshold);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_imu_set_batch_report_threshold failed: ") + e.what();
+    }
+}
+
+void dai_imu_set_max_batch_reports(DaiNode imu, int max_batch_reports) {
+    if(!imu) {
+        last_error = "dai_imu_set_max_batch_reports: null imu";
+        return;
+    }
+    try {
+        _dai_as_imu(imu)->setMaxBatchReports(max_batch_reports);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_imu_set_max_batch_reports failed: ") + e.what();
+    }
+}
+
+DaiImuData dai_datatype_as_imu_data(DaiDatatype msg) {
+    if(!msg) {
+        last_error = "dai_datatype_as_imu_data: null msg";
+        return nullptr;
+    }
+    try {
+        auto ptr = static_cast<std::shared_ptr<dai::ADatatype>*>(msg);
+        auto imu = std::dynamic_pointer_cast<dai::IMUData>(*ptr);
+        if(!imu) return nullptr;
+        return static_cast<DaiImuData>(new std::shared_ptr<dai::IMUData>(imu));
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_datatype_as_imu_data failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+size_t dai_imu_data_get_num_packets(DaiImuData imu) {
+    if(!imu) {
+        last_error = "dai_imu_data_get_num_packets: null imu data";
+        return 0;
+    }
+    auto ptr = static_cast<std::shared_ptr<dai::IMUData>*>(imu);
+    return ptr->get() ? (*ptr)->packets.size() : 0;
+}
+
+bool dai_imu_data_get_report(DaiImuData imu, size_t packet, int kind, float* values, int64_t* timestamp_ns, int* sequence, int* accuracy) {
+    if(!imu || !values || !timestamp_ns || !sequence || !accuracy) {
+        last_error = "dai_imu_data_get_report: null argument";
+        return false;
+    }
+    try {
+        auto ptr = static_cast<std::shared_ptr<dai::IMUData>*>(imu);
+        if(!ptr->get() || packet >= (*ptr)->packets.size()) {
+            last_error = "dai_imu_data_get_report: packet index out of range";
+            return false;
+        }
+        const auto& p = (*ptr)->packets[packet];
+        auto vec = [&](const dai::IMUReportVec& r) -> const dai::IMUReport& {
+            values[0] = r.x;
+            values[1] = r.y;
+            values[2] = r.z;
+            values[3] = 0.0f;
+            values[4] = 0.0f;
+            return r;
+        };
+        const dai::IMUReport* report = nullptr;
+        switch(kind) {
+            case 0:
+                report = &vec(p.acceleroMeter);
+                break;
+            case 1:
+                report = &vec(p.gyroscope);
+                break;
+            case 2:
+                report = &vec(p.magneticField);
+                break;
+            case 3:
+                values[0] = p.rotationVector.i;
+                values[1] = p.rotationVector.j;
+                values[2] = p.rotationVector.k;
+                values[3] = p.rotationVector.real;
+                values[4] = p.rotationVector.rotationVectorAccuracy;
+                report = &p.rotationVector;
+                break;
+            default:
+                last_error = "dai_imu_data_get_report: unknown report kind";
+                return false;
+        }
+        *timestamp_ns = std::chrono::duration_cast<std::chrono::nanoseconds>(report->getTimestamp().time_since_epoch()).count();
+        *sequence = static_cast<int>(report->sequence);
+        *accuracy = static_cast<int>(report->accuracy);
+        return true;
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_imu_data_get_report failed: ") + e.what();
+        return false;
+    }
+}
+
+void dai_imu_data_release(DaiImuData imu) {
+    if(imu) {
+        delete static_cast<std::shared_ptr<dai::IMUData>*>(imu);
     }
-    auto view = static_cast<DaiPointCloudView*>(pcl);
-    if(view->points.empty()) return nullptr;
-    return reinterpret_cast<const DaiPoint3fRGBA*>(view->points.data());
 }
 
-size_t dai_pointcloud_get_points_rgba_len(DaiPointCloud pcl) {
+int64_t dai_pointcloud_get_timestamp_ns(DaiPointCloud pcl) {
     if(!pcl) {
-        last_error = "dai_pointcloud_get_points_rgba_len: null pointcloud";
+        last_error = "dai_pointcloud_get_timestamp_ns: null pointcloud";
         return 0;
     }
     auto view = static_cast<DaiPointCloudView*>(pcl);
-    return view->points.size();
-}
-
-void dai_pointcloud_release(DaiPointCloud pcl) {
-    if(pcl) {
-        auto view = static_cast<DaiPointCloudView*>(pcl);
-        delete view;
+    if(!view->msg) {
+        return 0;
     }
+    auto ts = view->msg->getTimestamp().time_since_epoch();
+    return std::chrono::duration_cast<std::chrono::nanoseconds>(ts).count();
 }
 
 DaiRGBDData dai_queue_get_rgbd(DaiDataQueue queue, int timeout_ms) {
@@ -2719,6 +5668,287 @@ void dai_buffer_set_data(DaiBuffer buffer, const void* data, size_t len) {
     }
 }
 
+DaiImgFrame dai_frame_new(int width, int height, int type) {
+    if(width <= 0 || height <= 0) {
+        last_error = "dai_frame_new: width and height must be positive";
+        return nullptr;
+    }
+    try {
+        auto frame = std::make_shared<dai::ImgFrame>();
+        frame->setWidth(width);
+        frame->setHeight(height);
+        frame->setType(static_cast<dai::ImgFrame::Type>(type));
+        frame->setTimestamp(std::chrono::steady_clock::now());
+        return new std::shared_ptr<dai::ImgFrame>(std::move(frame));
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_frame_new failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+void dai_frame_set_data(DaiImgFrame frame, const void* data, size_t len) {
+    if(!frame) {
+        last_error = "dai_frame_set_data: null frame";
+        return;
+    }
+    if(!data && len > 0) {
+        last_error = "dai_frame_set_data: null data";
+        return;
+    }
+    try {
+        auto ptr = static_cast<std::shared_ptr<dai::ImgFrame>*>(frame);
+        std::vector<std::uint8_t> bytes(len);
+        if(len > 0) {
+            std::memcpy(bytes.data(), data, len);
+        }
+        (*ptr)->setData(std::move(bytes));
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_frame_set_data failed: ") + e.what();
+    }
+}
+
+// Shared Buffer accessors for the typed handles (`std::shared_ptr<T>*`, T derived from dai::Buffer).
+template <typename T>
+static void _dai_msg_set_timestamp_ns(void* handle, int64_t ns, const char* ctx) {
+    if(!handle) {
+        last_error = std::string(ctx) + ": null message";
+        return;
+    }
+    try {
+        auto ptr = static_cast<std::shared_ptr<T>*>(handle);
+        (*ptr)->setTimestamp(std::chrono::steady_clock::time_point(std::chrono::nanoseconds(ns)));
+    } catch(const std::exception& e) {
+        last_error = std::string(ctx) + " failed: " + e.what();
+    }
+}
+
+template <typename T>
+static int64_t _dai_msg_get_timestamp_ns(void* handle, const char* ctx) {
+    if(!handle) {
+        last_error = std::string(ctx) + ": null message";
+        return 0;
+    }
+    try {
+        auto ptr = static_cast<std::shared_ptr<T>*>(handle);
+        auto ts = (*ptr)->getTimestamp().time_since_epoch();
+        return std::chrono::duration_cast<std::chrono::nanoseconds>(ts).count();
+    } catch(const std::exception& e) {
+        last_error = std::string(ctx) + " failed: " + e.what();
+        return 0;
+    }
+}
+
+template <typename T>
+static void _dai_msg_set_sequence_num(void* handle, int64_t seq, const char* ctx) {
+    if(!handle) {
+        last_error = std::string(ctx) + ": null message";
+        return;
+    }
+    try {
+        auto ptr = static_cast<std::shared_ptr<T>*>(handle);
+        (*ptr)->setSequenceNum(seq);
+    } catch(const std::exception& e) {
+        last_error = std::string(ctx) + " failed: " + e.what();
+    }
+}
+
+template <typename T>
+static int64_t _dai_msg_get_sequence_num(void* handle, const char* ctx) {
+    if(!handle) {
+        last_error = std::string(ctx) + ": null message";
+        return 0;
+    }
+    try {
+        auto ptr = static_cast<std::shared_ptr<T>*>(handle);
+        return (*ptr)->getSequenceNum();
+    } catch(const std::exception& e) {
+        last_error = std::string(ctx) + " failed: " + e.what();
+        return 0;
+    }
+}
+
+template <typename T>
+static int64_t _dai_msg_use_count(void* handle, const char* ctx) {
+    if(!handle) {
+        last_error = std::string(ctx) + ": null message";
+        return 0;
+    }
+    return static_cast<int64_t>(static_cast<std::shared_ptr<T>*>(handle)->use_count());
+}
+
+template <typename T>
+static void _dai_msg_write_data(void* handle, const void* data, size_t len, const char* ctx) {
+    if(!handle) {
+        last_error = std::string(ctx) + ": null message";
+        return;
+    }
+    if(!data && len > 0) {
+        last_error = std::string(ctx) + ": null data";
+        return;
+    }
+    try {
+        auto ptr = static_cast<std::shared_ptr<T>*>(handle);
+        auto span = (*ptr)->getData();
+        if(span.size() == len) {
+            if(len > 0) std::memcpy(span.data(), data, len);
+            return;
+        }
+        std::vector<std::uint8_t> bytes(len);
+        if(len > 0) std::memcpy(bytes.data(), data, len);
+        (*ptr)->setData(std::move(bytes));
+    } catch(const std::exception& e) {
+        last_error = std::string(ctx) + " failed: " + e.what();
+    }
+}
+
+int64_t dai_buffer_use_count(DaiBuffer buffer) {
+    return _dai_msg_use_count<dai::Buffer>(buffer, "dai_buffer_use_count");
+}
+
+int64_t dai_frame_use_count(DaiImgFrame frame) {
+    return _dai_msg_use_count<dai::ImgFrame>(frame, "dai_frame_use_count");
+}
+
+void dai_buffer_write_data(DaiBuffer buffer, const void* data, size_t len) {
+    _dai_msg_write_data<dai::Buffer>(buffer, data, len, "dai_buffer_write_data");
+}
+
+void dai_frame_write_data(DaiImgFrame frame, const void* data, size_t len) {
+    _dai_msg_write_data<dai::ImgFrame>(frame, data, len, "dai_frame_write_data");
+}
+
+static int64_t _dai_steady_now_ns() {
+    auto now = std::chrono::steady_clock::now().time_since_epoch();
+    return std::chrono::duration_cast<std::chrono::nanoseconds>(now).count();
+}
+
+void dai_buffer_set_timestamp_ns(DaiBuffer buffer, int64_t ns) {
+    _dai_msg_set_timestamp_ns<dai::Buffer>(buffer, ns, "dai_buffer_set_timestamp_ns");
+}
+
+int64_t dai_buffer_get_timestamp_ns(DaiBuffer buffer) {
+    return _dai_msg_get_timestamp_ns<dai::Buffer>(buffer, "dai_buffer_get_timestamp_ns");
+}
+
+void dai_buffer_set_timestamp_now(DaiBuffer buffer) {
+    _dai_msg_set_timestamp_ns<dai::Buffer>(buffer, _dai_steady_now_ns(), "dai_buffer_set_timestamp_now");
+}
+
+void dai_buffer_set_sequence_num(DaiBuffer buffer, int64_t seq) {
+    _dai_msg_set_sequence_num<dai::Buffer>(buffer, seq, "dai_buffer_set_sequence_num");
+}
+
+int64_t dai_buffer_get_sequence_num(DaiBuffer buffer) {
+    return _dai_msg_get_sequence_num<dai::Buffer>(buffer, "dai_buffer_get_sequence_num");
+}
+
+void dai_frame_set_timestamp_ns(DaiImgFrame frame, int64_t ns) {
+    _dai_msg_set_timestamp_ns<dai::ImgFrame>(frame, ns, "dai_frame_set_timestamp_ns");
+}
+
+int64_t dai_frame_get_timestamp_ns(DaiImgFrame frame) {
+    return _dai_msg_get_timestamp_ns<dai::ImgFrame>(frame, "dai_frame_get_timestamp_ns");
+}
+
+void dai_frame_set_timestamp_now(DaiImgFrame frame) {
+    _dai_msg_set_timestamp_ns<dai::ImgFrame>(frame, _dai_steady_now_ns(), "dai_frame_set_timestamp_now");
+}
+
+void dai_frame_set_sequence_num(DaiImgFrame frame, int64_t seq) {
+    _dai_msg_set_sequence_num<dai::ImgFrame>(frame, seq, "dai_frame_set_sequence_num");
+}
+
+int64_t dai_frame_get_sequence_num(DaiImgFrame frame) {
+    return _dai_msg_get_sequence_num<dai::ImgFrame>(frame, "dai_frame_get_sequence_num");
+}
+
+int64_t dai_clock_now_ns() {
+    return _dai_steady_now_ns();
+}
+
+int64_t dai_frame_get_timestamp_device_ns(DaiImgFrame frame) {
+    if(!frame) {
+        last_error = "dai_frame_get_timestamp_device_ns: null frame";
+        return 0;
+    }
+    try {
+        auto ptr = static_cast<std::shared_ptr<dai::ImgFrame>*>(frame);
+        auto ts = (*ptr)->getTimestampDevice().time_since_epoch();
+        return std::chrono::duration_cast<std::chrono::nanoseconds>(ts).count();
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_frame_get_timestamp_device_ns failed: ") + e.what();
+        return 0;
+    }
+}
+
+int dai_frame_get_instance_num(DaiImgFrame frame) {
+    if(!frame) {
+        last_error = "dai_frame_get_instance_num: null frame";
+        return -1;
+    }
+    try {
+        auto ptr = static_cast<std::shared_ptr<dai::ImgFrame>*>(frame);
+        return static_cast<int>((*ptr)->getInstanceNum());
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_frame_get_instance_num failed: ") + e.what();
+        return -1;
+    }
+}
+
+bool dai_frame_get_intrinsic_matrix(DaiImgFrame frame, float* out_matrix) {
+    if(!frame || !out_matrix) {
+        last_error = "dai_frame_get_intrinsic_matrix: null frame or output";
+        return false;
+    }
+    try {
+        auto ptr = static_cast<std::shared_ptr<dai::ImgFrame>*>(frame);
+        auto m = (*ptr)->transformation.getIntrinsicMatrix();
+        for(size_t r = 0; r < 3; ++r) {
+            for(size_t c = 0; c < 3; ++c) {
+                out_matrix[r * 3 + c] = m[r][c];
+            }
+        }
+        return true;
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_frame_get_intrinsic_matrix failed: ") + e.what();
+        return false;
+    }
+}
+
+void* dai_buffer_get_data(DaiBuffer buffer) {
+    if(!buffer) {
+        last_error = "dai_buffer_get_data: null buffer";
+        return nullptr;
+    }
+    try {
+        auto ptr = static_cast<std::shared_ptr<dai::Buffer>*>(buffer);
+        if(!ptr->get()) {
+            return nullptr;
+        }
+        return (*ptr)->getData().data();
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_buffer_get_data failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+size_t dai_buffer_get_size(DaiBuffer buffer) {
+    if(!buffer) {
+        last_error = "dai_buffer_get_size: null buffer";
+        return 0;
+    }
+    try {
+        auto ptr = static_cast<std::shared_ptr<dai::Buffer>*>(buffer);
+        if(!ptr->get()) {
+            return 0;
+        }
+        return (*ptr)->getData().size();
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_buffer_get_size failed: ") + e.what();
+        return 0;
+    }
+}
+
 DaiBuffer dai_input_get_buffer(DaiInput input) {
     if(!input) {
         last_error = "dai_input_get_buffer: null input";
@@ -2828,6 +6058,42 @@ void dai_input_queue_send(DaiInputQueue queue, DaiDatatype msg) {
     }
 }
 
+void dai_input_queue_send_img_frame(DaiInputQueue queue, DaiImgFrame frame) {
+    if(!queue || !frame) {
+        last_error = "dai_input_queue_send_img_frame: null queue/frame";
+        return;
+    }
+    try {
+        auto q = static_cast<std::shared_ptr<dai::InputQueue>*>(queue);
+        auto img = static_cast<std::shared_ptr<dai::ImgFrame>*>(frame);
+        if(!(*q)) {
+            last_error = "dai_input_queue_send_img_frame: invalid queue";
+            return;
+        }
+        (*q)->send(*img);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_input_queue_send_img_frame failed: ") + e.what();
+    }
+}
+
+void dai_input_queue_send_buffer(DaiInputQueue queue, DaiBuffer buffer) {
+    if(!queue || !buffer) {
+        last_error = "dai_input_queue_send_buffer: null queue/buffer";
+        return;
+    }
+    try {
+        auto q = static_cast<std::shared_ptr<dai::InputQueue>*>(queue);
+        auto buf = static_cast<std::shared_ptr<dai::Buffer>*>(buffer);
+        if(!(*q)) {
+            last_error = "dai_input_queue_send_buffer: invalid queue";
+            return;
+        }
+        (*q)->send(*buf);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_input_queue_send_buffer failed: ") + e.what();
+    }
+}
+
 void dai_output_send_buffer(DaiOutput output, DaiBuffer buffer) {
     if(!output || !buffer) {
         last_error = "dai_output_send_buffer: null output/buffer";
@@ -2908,24 +6174,106 @@ static inline std::vector<dai::Node::Output*> _dai_collect_outputs(dai::Node* no
     return outs;
 }
 
-static inline std::vector<dai::Node::Input*> _dai_collect_inputs(dai::Node* node) {
-    std::vector<dai::Node::Input*> ins;
-    if(!node) return ins;
-    auto refs = node->getInputRefs();
-    ins.insert(ins.end(), refs.begin(), refs.end());
-    auto maps = node->getInputMapRefs();
-    for(auto* m : maps) {
-        if(!m) continue;
-        for(auto& kv : *m) {
-            ins.push_back(&kv.second);
-        }
+static inline std::vector<dai::Node::Input*> _dai_collect_inputs(dai::Node* node) {
+    std::vector<dai::Node::Input*> ins;
+    if(!node) return ins;
+    auto refs = node->getInputRefs();
+    ins.insert(ins.end(), refs.begin(), refs.end());
+    auto maps = node->getInputMapRefs();
+    for(auto* m : maps) {
+        if(!m) continue;
+        for(auto& kv : *m) {
+            ins.push_back(&kv.second);
+        }
+    }
+    return ins;
+}
+
+static inline bool _dai_group_matches(const std::string& portGroup, const char* filterGroup) {
+    if(filterGroup == nullptr) return true;
+    return portGroup == std::string(filterGroup);
+}
+
+char* dai_node_get_inputs_json(DaiNode node) {
+    if(!node) {
+        last_error = "dai_node_get_inputs_json: null node";
+        return nullptr;
+    }
+    try {
+        dai_clear_last_error();
+        auto n = static_cast<dai::Node*>(node);
+        nlohmann::json j = nlohmann::json::array();
+        for(auto* i : _dai_collect_inputs(n)) {
+            if(!i) continue;
+            nlohmann::json item;
+            item["name"] = i->getName();
+            item["group"] = i->getGroup();
+            item["blocking"] = i->getBlocking();
+            item["queueSize"] = i->getMaxSize();
+            item["waitForMessage"] = i->getWaitForMessage();
+            j.push_back(std::move(item));
+        }
+        auto dumped = j.dump();
+        return dai_string_to_cstring(dumped.c_str());
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_node_get_inputs_json failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+template <typename Port>
+static inline nlohmann::json _dai_port_info_json(const Port* p, const std::string* mapName) {
+    nlohmann::json types = nlohmann::json::array();
+    for(const auto& h : p->getPossibleDatatypes()) {
+        types.push_back({{"datatype", static_cast<int>(h.datatype)}, {"descendants", h.descendants}});
     }
-    return ins;
+    nlohmann::json item;
+    item["name"] = p->getName();
+    item["group"] = p->getGroup();
+    item["map"] = mapName ? nlohmann::json(*mapName) : nlohmann::json(nullptr);
+    item["possibleDatatypes"] = std::move(types);
+    return item;
 }
 
-static inline bool _dai_group_matches(const std::string& portGroup, const char* filterGroup) {
-    if(filterGroup == nullptr) return true;
-    return portGroup == std::string(filterGroup);
+char* dai_node_get_io_json(DaiNode node) {
+    if(!node) {
+        last_error = "dai_node_get_io_json: null node";
+        return nullptr;
+    }
+    try {
+        dai_clear_last_error();
+        auto n = static_cast<dai::Node*>(node);
+        nlohmann::json inputs = nlohmann::json::array();
+        nlohmann::json outputs = nlohmann::json::array();
+        nlohmann::json inputMaps = nlohmann::json::array();
+        nlohmann::json outputMaps = nlohmann::json::array();
+        for(auto* i : n->getInputRefs()) {
+            if(i) inputs.push_back(_dai_port_info_json(i, nullptr));
+        }
+        for(auto* m : n->getInputMapRefs()) {
+            if(!m) continue;
+            inputMaps.push_back(m->name);
+            for(auto& kv : *m) inputs.push_back(_dai_port_info_json(&kv.second, &m->name));
+        }
+        for(auto* o : n->getOutputRefs()) {
+            if(o) outputs.push_back(_dai_port_info_json(o, nullptr));
+        }
+        for(auto* m : n->getOutputMapRefs()) {
+            if(!m) continue;
+            outputMaps.push_back(m->name);
+            for(auto& kv : *m) outputs.push_back(_dai_port_info_json(&kv.second, &m->name));
+        }
+        nlohmann::json j;
+        j["inputs"] = std::move(inputs);
+        j["outputs"] = std::move(outputs);
+        j["inputMaps"] = std::move(inputMaps);
+        j["outputMaps"] = std::move(outputMaps);
+        auto dumped = j.dump();
+        return dai_string_to_cstring(dumped.c_str());
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_node_get_io_json failed: ") + e.what();
+        return nullptr;
+    }
 }
 
 static inline dai::Node::Output* _dai_pick_output_for_input(dai::Node* fromNode, dai::Node::Input* input, const char* out_group) {
@@ -3183,6 +6531,23 @@ void dai_hostnode_send_processing_to_pipeline(DaiNode node, bool send) {
     }
 }
 
+void dai_hostnode_process_on_arrival(DaiNode node) {
+    if(!node) {
+        last_error = "dai_hostnode_process_on_arrival: null node";
+        return;
+    }
+    try {
+        auto host = dynamic_cast<RustHostNode*>(static_cast<dai::Node*>(node));
+        if(!host) {
+            last_error = "dai_hostnode_process_on_arrival: node is not a Rust host node";
+            return;
+        }
+        host->processOnArrival();
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_hostnode_process_on_arrival failed: ") + e.what();
+    }
+}
+
 static inline bool _dai_assign_input_desc(dai::Node::InputDescription& desc,
                                           const char* name,
                                           const char* group) {
@@ -3384,6 +6749,19 @@ void dai_camera_set_sensor_type(DaiCameraNode camera, int sensor_type) {
     }
 }
 
+void dai_camera_set_frame_sync_mode(DaiCameraNode camera, int mode) {
+    if(!camera) {
+        last_error = "dai_camera_set_frame_sync_mode: null camera";
+        return;
+    }
+    try {
+        auto cam = static_cast<dai::node::Camera*>(camera);
+        cam->initialControl.setFrameSyncMode(static_cast<dai::CameraControl::FrameSyncMode>(mode));
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_camera_set_frame_sync_mode failed: ") + e.what();
+    }
+}
+
 int dai_camera_get_sensor_type(DaiCameraNode camera) {
     if(!camera) {
         last_error = "dai_camera_get_sensor_type: null camera";
@@ -3469,173 +6847,426 @@ void dai_camera_set_max_size_pools(DaiCameraNode camera, int raw, int isp, int o
         return;
     }
     try {
-        auto cam = static_cast<dai::node::Camera*>(camera);
-        cam->setMaxSizePools(raw, isp, outputs);
+        auto cam = static_cast<dai::node::Camera*>(camera);
+        cam->setMaxSizePools(raw, isp, outputs);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_camera_set_max_size_pools failed: ") + e.what();
+    }
+}
+
+void dai_camera_set_outputs_num_frames_pool(DaiCameraNode camera, int num) {
+    if(!camera) {
+        last_error = "dai_camera_set_outputs_num_frames_pool: null camera";
+        return;
+    }
+    try {
+        auto cam = static_cast<dai::node::Camera*>(camera);
+        cam->setOutputsNumFramesPool(num);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_camera_set_outputs_num_frames_pool failed: ") + e.what();
+    }
+}
+
+void dai_camera_set_outputs_max_size_pool(DaiCameraNode camera, int size) {
+    if(!camera) {
+        last_error = "dai_camera_set_outputs_max_size_pool: null camera";
+        return;
+    }
+    try {
+        auto cam = static_cast<dai::node::Camera*>(camera);
+        cam->setOutputsMaxSizePool(size);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_camera_set_outputs_max_size_pool failed: ") + e.what();
+    }
+}
+
+int dai_camera_get_raw_num_frames_pool(DaiCameraNode camera) {
+    if(!camera) {
+        last_error = "dai_camera_get_raw_num_frames_pool: null camera";
+        return 0;
+    }
+    try {
+        auto cam = static_cast<dai::node::Camera*>(camera);
+        return cam->getRawNumFramesPool();
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_camera_get_raw_num_frames_pool failed: ") + e.what();
+        return 0;
+    }
+}
+
+int dai_camera_get_max_size_pool_raw(DaiCameraNode camera) {
+    if(!camera) {
+        last_error = "dai_camera_get_max_size_pool_raw: null camera";
+        return 0;
+    }
+    try {
+        auto cam = static_cast<dai::node::Camera*>(camera);
+        return cam->getMaxSizePoolRaw();
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_camera_get_max_size_pool_raw failed: ") + e.what();
+        return 0;
+    }
+}
+
+int dai_camera_get_isp_num_frames_pool(DaiCameraNode camera) {
+    if(!camera) {
+        last_error = "dai_camera_get_isp_num_frames_pool: null camera";
+        return 0;
+    }
+    try {
+        auto cam = static_cast<dai::node::Camera*>(camera);
+        return cam->getIspNumFramesPool();
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_camera_get_isp_num_frames_pool failed: ") + e.what();
+        return 0;
+    }
+}
+
+int dai_camera_get_max_size_pool_isp(DaiCameraNode camera) {
+    if(!camera) {
+        last_error = "dai_camera_get_max_size_pool_isp: null camera";
+        return 0;
+    }
+    try {
+        auto cam = static_cast<dai::node::Camera*>(camera);
+        return cam->getMaxSizePoolIsp();
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_camera_get_max_size_pool_isp failed: ") + e.what();
+        return 0;
+    }
+}
+
+bool dai_camera_get_outputs_num_frames_pool(DaiCameraNode camera, int* out_num) {
+    if(!camera || !out_num) {
+        last_error = "dai_camera_get_outputs_num_frames_pool: null camera or out_num";
+        return false;
+    }
+    try {
+        auto cam = static_cast<dai::node::Camera*>(camera);
+        auto value = cam->getOutputsNumFramesPool();
+        return _dai_optionalish_to_out(value, out_num);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_camera_get_outputs_num_frames_pool failed: ") + e.what();
+        return false;
+    }
+}
+
+bool dai_camera_get_outputs_max_size_pool(DaiCameraNode camera, size_t* out_size) {
+    if(!camera || !out_size) {
+        last_error = "dai_camera_get_outputs_max_size_pool: null camera or out_size";
+        return false;
+    }
+    try {
+        auto cam = static_cast<dai::node::Camera*>(camera);
+        auto value = cam->getOutputsMaxSizePool();
+        return _dai_optionalish_to_out(value, out_size);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_camera_get_outputs_max_size_pool failed: ") + e.what();
+        return false;
+    }
+}
+DaiCameraNode dai_pipeline_create_camera(DaiPipeline pipeline, int board_socket) {
+    if (!pipeline) {
+        last_error = "dai_pipeline_create_camera: null pipeline";
+        return nullptr;
+    }
+    try {
+        auto pipe = static_cast<dai::Pipeline*>(pipeline);
+        auto cameraBuilder = pipe->create<dai::node::Camera>();
+        auto socket = static_cast<dai::CameraBoardSocket>(board_socket);
+        auto camera = cameraBuilder->build(socket);
+        return static_cast<DaiCameraNode>(camera.get());
+    } catch (const std::exception& e) {
+        last_error = std::string("dai_pipeline_create_camera failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+DaiOutput dai_camera_request_output(DaiCameraNode camera, int width, int height, int type, int resize_mode, float fps, int enable_undistortion) {
+    if (!camera) {
+        last_error = "dai_camera_request_output: null camera";
+        return nullptr;
+    }
+    try {
+        auto cam = static_cast<dai::node::Camera*>(camera);
+        std::pair<uint32_t, uint32_t> size(static_cast<uint32_t>(width), static_cast<uint32_t>(height));
+        std::optional<dai::ImgFrame::Type> opt_type = (type >= 0) ? std::optional<dai::ImgFrame::Type>(static_cast<dai::ImgFrame::Type>(type)) : std::nullopt;
+        dai::ImgResizeMode resize = static_cast<dai::ImgResizeMode>(resize_mode);
+        std::optional<float> opt_fps = (fps > 0.0f) ? std::optional<float>(fps) : std::nullopt;
+        std::optional<bool> opt_undist = (enable_undistortion >= 0) ? std::optional<bool>(enable_undistortion != 0) : std::nullopt;
+        dai::Node::Output* output = cam->requestOutput(size, opt_type, resize, opt_fps, opt_undist);
+        return static_cast<DaiOutput>(output);
+    } catch (const std::exception& e) {
+        last_error = std::string("dai_camera_request_output failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+DaiDataQueue dai_output_create_queue(DaiOutput output, unsigned int max_size, bool blocking) {
+    if (!output) {
+        last_error = "dai_output_create_queue: null output";
+        return nullptr;
+    }
+    try {
+        auto out = _dai_rgbd_filtered_output(static_cast<dai::Node::Output*>(output));
+        auto queue = out->createOutputQueue(max_size, blocking);
+        return new std::shared_ptr<dai::MessageQueue>(queue);
+    } catch (const std::exception& e) {
+        last_error = std::string("dai_output_create_queue failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+template <typename Port>
+static inline char* _dai_port_possible_datatypes_json(const Port* p) {
+    nlohmann::json arr = nlohmann::json::array();
+    for(const auto& h : p->getPossibleDatatypes()) {
+        arr.push_back({{"datatype", static_cast<int>(h.datatype)}, {"descendants", h.descendants}});
+    }
+    auto dumped = arr.dump();
+    return dai_string_to_cstring(dumped.c_str());
+}
+
+char* dai_output_get_name(DaiOutput output) {
+    if(!output) {
+        last_error = "dai_output_get_name: null output";
+        return nullptr;
+    }
+    try {
+        auto p = static_cast<dai::Node::Output*>(output);
+        return dai_string_to_cstring(p->getName().c_str());
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_output_get_name failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+char* dai_output_get_group(DaiOutput output) {
+    if(!output) {
+        last_error = "dai_output_get_group: null output";
+        return nullptr;
+    }
+    try {
+        auto p = static_cast<dai::Node::Output*>(output);
+        return dai_string_to_cstring(p->getGroup().c_str());
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_output_get_group failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+char* dai_output_get_possible_datatypes_json(DaiOutput output) {
+    if(!output) {
+        last_error = "dai_output_get_possible_datatypes_json: null output";
+        return nullptr;
+    }
+    try {
+        auto p = static_cast<dai::Node::Output*>(output);
+        return _dai_port_possible_datatypes_json(p);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_output_get_possible_datatypes_json failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+bool dai_output_can_connect(DaiOutput output, DaiInput input) {
+    if(!output || !input) {
+        last_error = "dai_output_can_connect: null output or input";
+        return false;
+    }
+    try {
+        auto out = static_cast<dai::Node::Output*>(output);
+        auto in = static_cast<dai::Node::Input*>(input);
+        return out->canConnect(*in);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_output_can_connect failed: ") + e.what();
+        return false;
+    }
+}
+
+DaiNode dai_output_get_parent(DaiOutput output) {
+    if(!output) {
+        last_error = "dai_output_get_parent: null output";
+        return nullptr;
+    }
+    try {
+        auto p = static_cast<dai::Node::Output*>(output);
+        return static_cast<DaiNode>(&p->getParent());
     } catch(const std::exception& e) {
-        last_error = std::string("dai_camera_set_max_size_pools failed: ") + e.what();
+        last_error = std::string("dai_output_get_parent failed: ") + e.what();
+        return nullptr;
     }
 }
 
-void dai_camera_set_outputs_num_frames_pool(DaiCameraNode camera, int num) {
-    if(!camera) {
-        last_error = "dai_camera_set_outputs_num_frames_pool: null camera";
-        return;
+DaiNode dai_input_get_parent(DaiInput input) {
+    if(!input) {
+        last_error = "dai_input_get_parent: null input";
+        return nullptr;
     }
     try {
-        auto cam = static_cast<dai::node::Camera*>(camera);
-        cam->setOutputsNumFramesPool(num);
+        auto p = static_cast<dai::Node::Input*>(input);
+        return static_cast<DaiNode>(&p->getParent());
     } catch(const std::exception& e) {
-        last_error = std::string("dai_camera_set_outputs_num_frames_pool failed: ") + e.what();
+        last_error = std::string("dai_input_get_parent failed: ") + e.what();
+        return nullptr;
     }
 }
 
-void dai_camera_set_outputs_max_size_pool(DaiCameraNode camera, int size) {
-    if(!camera) {
-        last_error = "dai_camera_set_outputs_max_size_pool: null camera";
-        return;
+char* dai_output_get_connections_json(DaiOutput output) {
+    if(!output) {
+        last_error = "dai_output_get_connections_json: null output";
+        return nullptr;
     }
     try {
-        auto cam = static_cast<dai::node::Camera*>(camera);
-        cam->setOutputsMaxSizePool(size);
+        auto out = static_cast<dai::Node::Output*>(output);
+        nlohmann::json j = nlohmann::json::array();
+        for(const auto& c : out->getConnections()) {
+            auto inNode = c.inputNode.lock();
+            nlohmann::json item;
+            item["outputId"] = out->getParent().id;
+            item["outputGroup"] = c.outputGroup;
+            item["outputName"] = c.outputName;
+            item["inputId"] = inNode ? inNode->id : -1;
+            item["inputNodeName"] = inNode ? std::string(inNode->getName()) : std::string();
+            item["inputGroup"] = c.inputGroup;
+            item["inputName"] = c.inputName;
+            if(c.in != nullptr) {
+                item["blocking"] = c.in->getBlocking();
+                item["queueSize"] = c.in->getMaxSize();
+            } else {
+                item["blocking"] = nullptr;
+                item["queueSize"] = nullptr;
+            }
+            j.push_back(std::move(item));
+        }
+        auto dumped = j.dump();
+        return dai_string_to_cstring(dumped.c_str());
     } catch(const std::exception& e) {
-        last_error = std::string("dai_camera_set_outputs_max_size_pool failed: ") + e.what();
+        last_error = std::string("dai_output_get_connections_json failed: ") + e.what();
+        return nullptr;
     }
 }
 
-int dai_camera_get_raw_num_frames_pool(DaiCameraNode camera) {
-    if(!camera) {
-        last_error = "dai_camera_get_raw_num_frames_pool: null camera";
-        return 0;
+char* dai_input_get_name(DaiInput input) {
+    if(!input) {
+        last_error = "dai_input_get_name: null input";
+        return nullptr;
     }
     try {
-        auto cam = static_cast<dai::node::Camera*>(camera);
-        return cam->getRawNumFramesPool();
+        auto p = static_cast<dai::Node::Input*>(input);
+        return dai_string_to_cstring(p->getName().c_str());
     } catch(const std::exception& e) {
-        last_error = std::string("dai_camera_get_raw_num_frames_pool failed: ") + e.what();
-        return 0;
+        last_error = std::string("dai_input_get_name failed: ") + e.what();
+        return nullptr;
     }
 }
 
-int dai_camera_get_max_size_pool_raw(DaiCameraNode camera) {
-    if(!camera) {
-        last_error = "dai_camera_get_max_size_pool_raw: null camera";
-        return 0;
+char* dai_input_get_group(DaiInput input) {
+    if(!input) {
+        last_error = "dai_input_get_group: null input";
+        return nullptr;
     }
     try {
-        auto cam = static_cast<dai::node::Camera*>(camera);
-        return cam->getMaxSizePoolRaw();
+        auto p = static_cast<dai::Node::Input*>(input);
+        return dai_string_to_cstring(p->getGroup().c_str());
     } catch(const std::exception& e) {
-        last_error = std::string("dai_camera_get_max_size_pool_raw failed: ") + e.what();
-        return 0;
+        last_error = std::string("dai_input_get_group failed: ") + e.what();
+        return nullptr;
     }
 }
 
-int dai_camera_get_isp_num_frames_pool(DaiCameraNode camera) {
-    if(!camera) {
-        last_error = "dai_camera_get_isp_num_frames_pool: null camera";
-        return 0;
+char* dai_input_get_possible_datatypes_json(DaiInput input) {
+    if(!input) {
+        last_error = "dai_input_get_possible_datatypes_json: null input";
+        return nullptr;
     }
     try {
-        auto cam = static_cast<dai::node::Camera*>(camera);
-        return cam->getIspNumFramesPool();
+        auto p = static_cast<dai::Node::Input*>(input);
+        return _dai_port_possible_datatypes_json(p);
     } catch(const std::exception& e) {
-        last_error = std::string("dai_camera_get_isp_num_frames_pool failed: ") + e.what();
-        return 0;
+        last_error = std::string("dai_input_get_possible_datatypes_json failed: ") + e.what();
+        return nullptr;
     }
 }
 
-int dai_camera_get_max_size_pool_isp(DaiCameraNode camera) {
-    if(!camera) {
-        last_error = "dai_camera_get_max_size_pool_isp: null camera";
-        return 0;
+void dai_input_set_blocking(DaiInput input, bool blocking) {
+    if(!input) {
+        last_error = "dai_input_set_blocking: null input";
+        return;
     }
     try {
-        auto cam = static_cast<dai::node::Camera*>(camera);
-        return cam->getMaxSizePoolIsp();
+        auto p = static_cast<dai::Node::Input*>(input);
+        p->setBlocking(blocking);
     } catch(const std::exception& e) {
-        last_error = std::string("dai_camera_get_max_size_pool_isp failed: ") + e.what();
-        return 0;
+        last_error = std::string("dai_input_set_blocking failed: ") + e.what();
     }
 }
 
-bool dai_camera_get_outputs_num_frames_pool(DaiCameraNode camera, int* out_num) {
-    if(!camera || !out_num) {
-        last_error = "dai_camera_get_outputs_num_frames_pool: null camera or out_num";
+bool dai_input_get_blocking(DaiInput input) {
+    if(!input) {
+        last_error = "dai_input_get_blocking: null input";
         return false;
     }
     try {
-        auto cam = static_cast<dai::node::Camera*>(camera);
-        auto value = cam->getOutputsNumFramesPool();
-        return _dai_optionalish_to_out(value, out_num);
+        auto p = static_cast<dai::Node::Input*>(input);
+        return p->getBlocking();
     } catch(const std::exception& e) {
-        last_error = std::string("dai_camera_get_outputs_num_frames_pool failed: ") + e.what();
+        last_error = std::string("dai_input_get_blocking failed: ") + e.what();
         return false;
     }
 }
 
-bool dai_camera_get_outputs_max_size_pool(DaiCameraNode camera, size_t* out_size) {
-    if(!camera || !out_size) {
-        last_error = "dai_camera_get_outputs_max_size_pool: null camera or out_size";
-        return false;
+void dai_input_set_max_size(DaiInput input, unsigned int max_size) {
+    if(!input) {
+        last_error = "dai_input_set_max_size: null input";
+        return;
     }
     try {
-        auto cam = static_cast<dai::node::Camera*>(camera);
-        auto value = cam->getOutputsMaxSizePool();
-        return _dai_optionalish_to_out(value, out_size);
+        auto p = static_cast<dai::Node::Input*>(input);
+        p->setMaxSize(max_size);
     } catch(const std::exception& e) {
-        last_error = std::string("dai_camera_get_outputs_max_size_pool failed: ") + e.what();
-        return false;
+        last_error = std::string("dai_input_set_max_size failed: ") + e.what();
     }
 }
-DaiCameraNode dai_pipeline_create_camera(DaiPipeline pipeline, int board_socket) {
-    if (!pipeline) {
-        last_error = "dai_pipeline_create_camera: null pipeline";
-        return nullptr;
+
+unsigned int dai_input_get_max_size(DaiInput input) {
+    if(!input) {
+        last_error = "dai_input_get_max_size: null input";
+        return 0;
     }
     try {
-        auto pipe = static_cast<dai::Pipeline*>(pipeline);
-        auto cameraBuilder = pipe->create<dai::node::Camera>();
-        auto socket = static_cast<dai::CameraBoardSocket>(board_socket);
-        auto camera = cameraBuilder->build(socket);
-        return static_cast<DaiCameraNode>(camera.get());
-    } catch (const std::exception& e) {
-        last_error = std::string("dai_pipeline_create_camera failed: ") + e.what();
-        return nullptr;
+        auto p = static_cast<dai::Node::Input*>(input);
+        return static_cast<unsigned int>(p->getMaxSize());
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_input_get_max_size failed: ") + e.what();
+        return 0;
     }
 }
 
-DaiOutput dai_camera_request_output(DaiCameraNode camera, int width, int height, int type, int resize_mode, float fps, int enable_undistortion) {
-    if (!camera) {
-        last_error = "dai_camera_request_output: null camera";
-        return nullptr;
+void dai_input_set_reuse_previous_message(DaiInput input, bool reuse) {
+    if(!input) {
+        last_error = "dai_input_set_reuse_previous_message: null input";
+        return;
     }
     try {
-        auto cam = static_cast<dai::node::Camera*>(camera);
-        std::pair<uint32_t, uint32_t> size(static_cast<uint32_t>(width), static_cast<uint32_t>(height));
-        std::optional<dai::ImgFrame::Type> opt_type = (type >= 0) ? std::optional<dai::ImgFrame::Type>(static_cast<dai::ImgFrame::Type>(type)) : std::nullopt;
-        dai::ImgResizeMode resize = static_cast<dai::ImgResizeMode>(resize_mode);
-        std::optional<float> opt_fps = (fps > 0.0f) ? std::optional<float>(fps) : std::nullopt;
-        std::optional<bool> opt_undist = (enable_undistortion >= 0) ? std::optional<bool>(enable_undistortion != 0) : std::nullopt;
-        dai::Node::Output* output = cam->requestOutput(size, opt_type, resize, opt_fps, opt_undist);
-        return static_cast<DaiOutput>(output);
-    } catch (const std::exception& e) {
-        last_error = std::string("dai_camera_request_output failed: ") + e.what();
-        return nullptr;
+        auto p = static_cast<dai::Node::Input*>(input);
+        p->setReusePreviousMessage(reuse);
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_input_set_reuse_previous_message failed: ") + e.what();
     }
 }
 
-DaiDataQueue dai_output_create_queue(DaiOutput output, unsigned int max_size, bool blocking) {
-    if (!output) {
-        last_error = "dai_output_create_queue: null output";
-        return nullptr;
+bool dai_input_get_reuse_previous_message(DaiInput input) {
+    if(!input) {
+        last_error = "dai_input_get_reuse_previous_message: null input";
+        return false;
     }
     try {
-        auto out = static_cast<dai::Node::Output*>(output);
-        auto queue = out->createOutputQueue(max_size, blocking);
-        return new std::shared_ptr<dai::MessageQueue>(queue);
-    } catch (const std::exception& e) {
-        last_error = std::string("dai_output_create_queue failed: ") + e.what();
-        return nullptr;
+        auto p = static_cast<dai::Node::Input*>(input);
+        return p->getReusePreviousMessage();
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_input_get_reuse_previous_message failed: ") + e.what();
+        return false;
     }
 }
 
@@ -4170,6 +7801,12 @@ DaiDatatype dai_datatype_clone(DaiDatatype msg) {
     }
 }
 
+uintptr_t dai_datatype_get_native_id(DaiDatatype msg) {
+    if(!msg) return 0;
+    auto ptr = static_cast<std::shared_ptr<dai::ADatatype>*>(msg);
+    return reinterpret_cast<uintptr_t>(ptr->get());
+}
+
 int dai_datatype_get_datatype_enum(DaiDatatype msg) {
     if(!msg) {
         last_error = "dai_datatype_get_datatype_enum: null msg";
@@ -4269,6 +7906,54 @@ DaiBuffer dai_datatype_as_buffer(DaiDatatype msg) {
     }
 }
 
+static std::shared_ptr<dai::Buffer> _dai_datatype_buffer(DaiDatatype msg, const char* ctx) {
+    if(!msg) {
+        last_error = std::string(ctx) + ": null msg";
+        return nullptr;
+    }
+    auto ptr = static_cast<std::shared_ptr<dai::ADatatype>*>(msg);
+    auto buf = std::dynamic_pointer_cast<dai::Buffer>(*ptr);
+    if(!buf) {
+        last_error = std::string(ctx) + ": message is not a Buffer";
+    }
+    return buf;
+}
+
+int64_t dai_datatype_get_timestamp_ns(DaiDatatype msg) {
+    try {
+        auto buf = _dai_datatype_buffer(msg, "dai_datatype_get_timestamp_ns");
+        if(!buf) return 0;
+        auto ts = buf->getTimestamp().time_since_epoch();
+        return std::chrono::duration_cast<std::chrono::nanoseconds>(ts).count();
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_datatype_get_timestamp_ns failed: ") + e.what();
+        return 0;
+    }
+}
+
+int64_t dai_datatype_get_timestamp_device_ns(DaiDatatype msg) {
+    try {
+        auto buf = _dai_datatype_buffer(msg, "dai_datatype_get_timestamp_device_ns");
+        if(!buf) return 0;
+        auto ts = buf->getTimestampDevice().time_since_epoch();
+        return std::chrono::duration_cast<std::chrono::nanoseconds>(ts).count();
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_datatype_get_timestamp_device_ns failed: ") + e.what();
+        return 0;
+    }
+}
+
+int64_t dai_datatype_get_sequence_num(DaiDatatype msg) {
+    try {
+        auto buf = _dai_datatype_buffer(msg, "dai_datatype_get_sequence_num");
+        if(!buf) return 0;
+        return buf->getSequenceNum();
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_datatype_get_sequence_num failed: ") + e.what();
+        return 0;
+    }
+}
+
 DaiMessageGroup dai_datatype_as_message_group(DaiDatatype msg) {
     if(!msg) {
         last_error = "dai_datatype_as_message_group: null msg";
@@ -4416,6 +8101,94 @@ void dai_frame_release(DaiImgFrame frame) {
     }
 }
 
+int dai_frame_get_stride(DaiImgFrame frame) {
+    if(!frame) {
+        last_error = "dai_frame_get_stride: null frame";
+        return 0;
+    }
+    try {
+        auto ptr = static_cast<std::shared_ptr<dai::ImgFrame>*>(frame);
+        if(!ptr->get()) {
+            return 0;
+        }
+        return static_cast<int>((*ptr)->getStride());
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_frame_get_stride failed: ") + e.what();
+        return 0;
+    }
+}
+
+#ifdef DEPTHAI_HAVE_OPENCV_SUPPORT
+void* dai_frame_get_cv_frame(DaiImgFrame frame) {
+    if(!frame) {
+        last_error = "dai_frame_get_cv_frame: null frame";
+        return nullptr;
+    }
+    try {
+        auto ptr = static_cast<std::shared_ptr<dai::ImgFrame>*>(frame);
+        if(!ptr->get()) {
+            last_error = "dai_frame_get_cv_frame: null frame";
+            return nullptr;
+        }
+        return new cv::Mat((*ptr)->getCvFrame());
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_frame_get_cv_frame failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+void* dai_cv_mat_get_data(void* mat) {
+    return mat ? static_cast<cv::Mat*>(mat)->data : nullptr;
+}
+
+int dai_cv_mat_get_rows(void* mat) {
+    return mat ? static_cast<cv::Mat*>(mat)->rows : 0;
+}
+
+int dai_cv_mat_get_cols(void* mat) {
+    return mat ? static_cast<cv::Mat*>(mat)->cols : 0;
+}
+
+int dai_cv_mat_get_type(void* mat) {
+    return mat ? static_cast<cv::Mat*>(mat)->type() : 0;
+}
+
+size_t dai_cv_mat_get_step(void* mat) {
+    return mat ? static_cast<size_t>(static_cast<cv::Mat*>(mat)->step[0]) : 0;
+}
+
+void dai_cv_mat_release(void* mat) {
+    delete static_cast<cv::Mat*>(mat);
+}
+#else
+void* dai_frame_get_cv_frame(DaiImgFrame) {
+    last_error = "dai_frame_get_cv_frame: depthai-sys was built without DEPTHAI_OPENCV_SUPPORT";
+    return nullptr;
+}
+
+void* dai_cv_mat_get_data(void*) {
+    return nullptr;
+}
+
+int dai_cv_mat_get_rows(void*) {
+    return 0;
+}
+
+int dai_cv_mat_get_cols(void*) {
+    return 0;
+}
+
+int dai_cv_mat_get_type(void*) {
+    return 0;
+}
+
+size_t dai_cv_mat_get_step(void*) {
+    return 0;
+}
+
+void dai_cv_mat_release(void*) {}
+#endif
+
 void* dai_encoded_frame_get_data(DaiEncodedFrame frame) {
     if(!frame) {
         last_error = "dai_encoded_frame_get_data: null frame";
@@ -4620,6 +8393,14 @@ int dai_encoded_frame_get_instance_num(DaiEncodedFrame frame) {
     }
 }
 
+int64_t dai_encoded_frame_get_timestamp_ns(DaiEncodedFrame frame) {
+    return _dai_msg_get_timestamp_ns<dai::EncodedFrame>(frame, "dai_encoded_frame_get_timestamp_ns");
+}
+
+int64_t dai_encoded_frame_get_sequence_num(DaiEncodedFrame frame) {
+    return _dai_msg_get_sequence_num<dai::EncodedFrame>(frame, "dai_encoded_frame_get_sequence_num");
+}
+
 void dai_encoded_frame_release(DaiEncodedFrame frame) {
     if(frame) {
         auto ptr = static_cast<std::shared_ptr<dai::EncodedFrame>*>(frame);
@@ -4653,6 +8434,44 @@ int dai_device_get_connected_camera_sockets(DaiDevice device, int* sockets, int
     }
 }
 
+char* dai_device_get_connected_camera_features_json(DaiDevice device) {
+    if(!device) {
+        last_error = "dai_device_get_connected_camera_features_json: null device";
+        return nullptr;
+    }
+    try {
+        auto dev = static_cast<std::shared_ptr<dai::Device>*>(device);
+        if(!dev->get() || !(*dev)) {
+            last_error = "dai_device_get_connected_camera_features_json: invalid device";
+            return nullptr;
+        }
+        nlohmann::json j = (*dev)->getConnectedCameraFeatures();
+        return dai_string_to_cstring(j.dump().c_str());
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_device_get_connected_camera_features_json failed: ") + e.what();
+        return nullptr;
+    }
+}
+
+char* dai_device_get_stereo_pairs_json(DaiDevice device) {
+    if(!device) {
+        last_error = "dai_device_get_stereo_pairs_json: null device";
+        return nullptr;
+    }
+    try {
+        auto dev = static_cast<std::shared_ptr<dai::Device>*>(device);
+        if(!dev->get() || !(*dev)) {
+            last_error = "dai_device_get_stereo_pairs_json: invalid device";
+            return nullptr;
+        }
+        nlohmann::json j = (*dev)->getAvailableStereoPairs();
+        return dai_string_to_cstring(j.dump().c_str());
+    } catch(const std::exception& e) {
+        last_error = std::string("dai_device_get_stereo_pairs_json failed: ") + e.what();
+        return nullptr;
+    }
+}
+
 const char* dai_camera_socket_name(int socket) {
     try {
         auto board_socket = static_cast<dai::CameraBoardSocket>(socket);
@@ -34,6 +34,9 @@ API const char* dai_build_device_version();
 API const char* dai_build_bootloader_version();
 API const char* dai_build_device_rvc3_version();
 API const char* dai_build_device_rvc4_version();
+API bool dai_build_has_node(const char* name);
+API bool dai_build_has_opencv_support();
+API bool dai_build_has_dynamic_calibration_support();
 
 // Helper to duplicate/free returned strings (caller must free)
 API char* dai_string_to_cstring(const char* str);
@@ -55,6 +58,11 @@ typedef void* DaiRGBDData;    // currently: `std::shared_ptr<dai::RGBDData>*`
 typedef void* DaiMessageGroup; // currently: `std::shared_ptr<dai::MessageGroup>*`
 typedef void* DaiBuffer;       // currently: `std::shared_ptr<dai::Buffer>*`
 typedef void* DaiInputQueue;   // currently: `std::shared_ptr<dai::InputQueue>*`
+typedef void* DaiImuData;      // currently: `std::shared_ptr<dai::IMUData>*`
+typedef void* DaiSpatialLocationData; // currently: `std::shared_ptr<dai::SpatialLocationCalculatorData>*`
+typedef void* DaiImgDetections; // currently: wrapper-owned view of `ImgDetections` / `SpatialImgDetections`
+typedef void* DaiNNArchive;     // currently: `dai::NNArchive*`
+typedef void* DaiNNData;        // currently: `std::shared_ptr<dai::NNData>*`
 
 // Opaque handle to a heap-allocated array of `DaiDatatype` handles.
 //
@@ -70,6 +78,9 @@ typedef void (*DaiThreadedHostNodeRun)(void* ctx);
 // Queue callback types
 typedef void (*DaiQueueCallback)(void* ctx, const char* queue_name, DaiDatatype msg);
 
+// Device log callback: `level` is `dai::LogLevel`, `time_ns` the device timestamp.
+typedef void (*DaiLogCallback)(void* ctx, const char* node_id_name, int level, int64_t time_ns, const char* payload);
+
 // POD view of `dai::Point3fRGBA`
 typedef struct DaiPoint3fRGBA {
 	float x;
@@ -87,6 +98,32 @@ API DaiDevice dai_device_clone(DaiDevice device);
 API void dai_device_delete(DaiDevice device);
 API bool dai_device_is_closed(DaiDevice device);
 API void dai_device_close(DaiDevice device);
+// Address of the underlying `dai::Device`, shared by all clones of a handle; 0 for a null handle.
+API uintptr_t dai_device_get_native_id(DaiDevice device);
+// Strong references to the underlying `dai::Device` (handles and pipelines); 0 for a null handle.
+API int dai_device_use_count(DaiDevice device);
+// Open a new (non-default) device with `dai::DeviceBase::Config` overrides; negative values keep defaults.
+// `firmware_path` (nullable) boots the device with that firmware binary instead of the bundled one.
+// `device_id` (nullable) opens the device with that ID or name instead of the first available one.
+API DaiDevice dai_device_new_with_config(int max_usb_speed,
+                                         int watchdog_timeout_ms,
+                                         int watchdog_initial_delay_ms,
+                                         bool non_exclusive,
+                                         const char* firmware_path,
+                                         const char* device_id);
+API int dai_device_get_usb_speed(DaiDevice device);
+// Close the connection, resetting the device, and wait up to `timeout_ms` for it to re-enumerate unbooted.
+API bool dai_device_reset(DaiDevice device, int timeout_ms);
+// JSON array of the devices XLink can see: [{"deviceId", "name", "state", "protocol", "platform"}].
+// Returned strings must be freed with dai_free_cstring.
+API char* dai_device_get_all_available_json();
+API char* dai_device_get_device_id(DaiDevice device);
+// Version of the bootloader flashed on the device, empty if unknown. Freed with dai_free_cstring.
+API char* dai_device_get_bootloader_version(DaiDevice device);
+// Die temperatures in degrees Celsius: css, mss, upa, dss, average.
+API bool dai_device_get_chip_temperature(DaiDevice device, float* out_temperatures);
+// Flash the bootloader bundled with DepthAI-Core to the (not opened) device with that ID or name.
+API bool dai_device_flash_bootloader(const char* device_id);
 
 // Low-level pipeline operations  
 API DaiPipeline dai_pipeline_new();
@@ -130,6 +167,12 @@ API char* dai_pipeline_get_connection_map_json(DaiPipeline pipeline);
 API bool dai_pipeline_is_calibration_data_available(DaiPipeline pipeline);
 API char* dai_pipeline_get_calibration_data_json(DaiPipeline pipeline);
 API bool dai_pipeline_set_calibration_data_json(DaiPipeline pipeline, const char* eeprom_data_json);
+API char* dai_device_read_calibration_json(DaiDevice device);
+API char* dai_device_read_factory_calibration_json(DaiDevice device);
+// Write `eeprom_data_json` to the device EEPROM (user calibration area).
+API bool dai_device_flash_calibration_json(DaiDevice device, const char* eeprom_data_json);
+// Row-major 3x3 intrinsic matrix of `socket`, scaled to width x height (-1/-1 keeps the calibrated size).
+API bool dai_calibration_get_camera_intrinsics(const char* eeprom_data_json, int socket, int width, int height, bool keep_aspect_ratio, float* out_matrix);
 
 // Pipeline configuration via JSON (portable ABI, avoids binding large struct graphs).
 // Returned strings must be freed with dai_free_cstring.
@@ -176,12 +219,29 @@ API DaiNode dai_pipeline_create_node_by_name(DaiPipeline pipeline, const char* n
 // Output/Input helpers
 API DaiOutput dai_node_get_output(DaiNode node, const char* group, const char* name);
 API DaiInput dai_node_get_input(DaiNode node, const char* group, const char* name);
+// Port `key` of the node's input/output map `map_name` (e.g. Sync's "inputs"), created on first use.
+API DaiInput dai_node_get_input_in_map(DaiNode node, const char* map_name, const char* key);
+API DaiOutput dai_node_get_output_in_map(DaiNode node, const char* map_name, const char* key);
 // Node introspection
 // Returned strings must be freed with dai_free_cstring.
 API int dai_node_get_id(DaiNode node);
 API char* dai_node_get_alias(DaiNode node);
 API bool dai_node_set_alias(DaiNode node, const char* alias);
+// `level` is `dai::LogLevel`; only threaded (host or device) nodes have a log level.
+API int dai_node_get_log_level(DaiNode node);
+API bool dai_node_set_log_level(DaiNode node, int level);
 API char* dai_node_get_name(DaiNode node);
+// JSON array of `{"name", "group", "blocking", "queueSize", "waitForMessage"}` for every input port.
+API char* dai_node_get_inputs_json(DaiNode node);
+// JSON object `{"inputs", "outputs", "inputMaps", "outputMaps"}`: every port as
+// `{"name", "group", "map", "possibleDatatypes"}` (`map` is null outside port maps), and the names of
+// the node's port maps, including empty ones.
+API char* dai_node_get_io_json(DaiNode node);
+// Properties of a device node as JSON, the form DepthAI-Core serializes them in (camelCase keys).
+API char* dai_node_get_properties_json(DaiNode node);
+// Replace the property at JSON pointer `path` (e.g. "/initialConfig/fps") with `value_json`; the
+// property must already exist and the result must deserialize back into the node's properties.
+API bool dai_node_set_property_json(DaiNode node, const char* path, const char* value_json);
 API bool dai_output_link(DaiOutput from, DaiNode to, const char* in_group, const char* in_name);
 API bool dai_output_link_input(DaiOutput from, DaiInput to);
 API bool dai_node_link(DaiNode from, const char* out_group, const char* out_name, DaiNode to, const char* in_group, const char* in_name);
@@ -192,6 +252,7 @@ API DaiInput dai_hostnode_get_input(DaiNode node, const char* name);
 API void dai_hostnode_run_sync_on_host(DaiNode node);
 API void dai_hostnode_run_sync_on_device(DaiNode node);
 API void dai_hostnode_send_processing_to_pipeline(DaiNode node, bool send);
+API void dai_hostnode_process_on_arrival(DaiNode node);
 
 // Threaded host node helpers
 API DaiInput dai_threaded_hostnode_create_input(DaiNode node,
@@ -208,6 +269,9 @@ API bool dai_threaded_node_is_running(DaiNode node);
 // Device helpers
 API int dai_device_get_platform(DaiDevice device);
 API void dai_device_set_ir_laser_dot_projector_intensity(DaiDevice device, float intensity);
+API void dai_device_set_ir_flood_light_intensity(DaiDevice device, float intensity);
+// Number of IR drivers (dot projector / flood light) on the device.
+API int dai_device_get_ir_driver_count(DaiDevice device);
 
 // StereoDepth configuration helpers
 API void dai_stereo_set_subpixel(DaiNode stereo, bool enable);
@@ -218,16 +282,73 @@ API void dai_stereo_set_rectify_edge_fill_color(DaiNode stereo, int color);
 API void dai_stereo_enable_distortion_correction(DaiNode stereo, bool enable);
 API void dai_stereo_set_output_size(DaiNode stereo, int width, int height);
 API void dai_stereo_set_output_keep_aspect_ratio(DaiNode stereo, bool keep);
+// `align`: 0 = rectified right, 1 = rectified left, 2 = center (dai::StereoDepthProperties::DepthAlign).
+API void dai_stereo_set_depth_align(DaiNode stereo, int align);
+API void dai_stereo_set_depth_align_socket(DaiNode stereo, int socket);
+API void dai_stereo_set_rectification(DaiNode stereo, bool enable);
+API void dai_stereo_set_alpha_scaling(DaiNode stereo, float alpha);
+API void dai_stereo_set_focal_length_from_calibration(DaiNode stereo, bool enable);
+API void dai_stereo_set_input_resolution(DaiNode stereo, int width, int height);
+API void dai_stereo_set_baseline(DaiNode stereo, float baseline_cm);
+API void dai_stereo_set_focal_length(DaiNode stereo, float focal_length_px);
 API void dai_stereo_initial_set_left_right_check_threshold(DaiNode stereo, int threshold);
 API void dai_stereo_initial_set_threshold_filter_max_range(DaiNode stereo, int max_range);
+API void dai_stereo_set_post_processing_hardware_resources(DaiNode stereo, int num_shaves, int num_memory_slices);
 
 // RGBD configuration helpers
 API void dai_rgbd_set_depth_unit(DaiNode rgbd, int depth_unit);
+API int dai_rgbd_get_depth_unit(DaiNode rgbd);
+API void dai_rgbd_set_output_fps(DaiNode rgbd, float fps);
+API float dai_rgbd_get_output_fps(DaiNode rgbd);
+API void dai_rgbd_set_sparse(DaiNode rgbd, bool sparse);
+API bool dai_rgbd_get_sparse(DaiNode rgbd);
 
 // ImageAlign node helpers
 API void dai_image_align_set_run_on_host(DaiNode align, bool run_on_host);
 API void dai_image_align_set_output_size(DaiNode align, int width, int height);
 API void dai_image_align_set_out_keep_aspect_ratio(DaiNode align, bool keep);
+API void dai_image_align_set_interpolation(DaiNode align, int interpolation);
+API void dai_image_align_set_num_shaves(DaiNode align, int num_shaves);
+API void dai_image_align_set_num_frames_pool(DaiNode align, int num_frames_pool);
+API bool dai_image_align_run_on_host(DaiNode align);
+
+// ImageAlignConfig helpers
+// Returned handle is a `std::shared_ptr<dai::Buffer>*` actually pointing to a `dai::ImageAlignConfig`.
+API DaiBuffer dai_image_align_config_new();
+API DaiBuffer dai_image_align_get_initial_config(DaiNode align);
+API void dai_image_align_config_set_static_depth_plane(DaiBuffer cfg, uint16_t depth_plane);
+API uint16_t dai_image_align_config_get_static_depth_plane(DaiBuffer cfg);
+
+// NeuralDepth node helpers (the node only exists in DepthAI-Core after v3.1.0)
+// `model` is 0..3 for the LARGE, MEDIUM, SMALL and NANO `dai::DeviceModelZoo` models.
+API bool dai_neural_depth_build(DaiNode neural_depth, DaiOutput left, DaiOutput right, int model);
+
+// NeuralDepthConfig helpers
+// Returned handle is a `std::shared_ptr<dai::Buffer>*` actually pointing to a `dai::NeuralDepthConfig`.
+API DaiBuffer dai_neural_depth_config_new();
+API DaiBuffer dai_neural_depth_get_initial_config(DaiNode neural_depth);
+API void dai_neural_depth_config_set_confidence_threshold(DaiBuffer cfg, uint8_t threshold);
+API uint8_t dai_neural_depth_config_get_confidence_threshold(DaiBuffer cfg);
+API void dai_neural_depth_config_set_edge_threshold(DaiBuffer cfg, uint8_t threshold);
+API uint8_t dai_neural_depth_config_get_edge_threshold(DaiBuffer cfg);
+API void dai_neural_depth_config_set_depth_unit(DaiBuffer cfg, int depth_unit);
+API int dai_neural_depth_config_get_depth_unit(DaiBuffer cfg);
+API void dai_neural_depth_config_set_custom_depth_unit_multiplier(DaiBuffer cfg, float multiplier);
+API float dai_neural_depth_config_get_custom_depth_unit_multiplier(DaiBuffer cfg);
+
+// ImageFilters node helpers (the node only runs in builds with OpenCV support)
+API void dai_image_filters_set_run_on_host(DaiNode filters, bool run_on_host);
+API bool dai_image_filters_run_on_host(DaiNode filters);
+API void dai_image_filters_set_default_profile_preset(DaiNode filters, int preset);
+
+// ImageFiltersConfig helpers
+// Returned handle is a `std::shared_ptr<dai::Buffer>*` actually pointing to a `dai::ImageFiltersConfig`.
+API DaiBuffer dai_image_filters_config_new();
+API DaiBuffer dai_image_filters_get_initial_config(DaiNode filters);
+// Set the filter at `index` from JSON, or append it when `index` is negative.
+API bool dai_image_filters_config_set_filter_json(DaiBuffer cfg, int64_t index, const char* json);
+API void dai_image_filters_config_set_profile_preset(DaiBuffer cfg, int preset);
+API char* dai_image_filters_config_get_filters_json(DaiBuffer cfg);
 
 // ImageManip node helpers
 API void dai_image_manip_set_num_frames_pool(DaiNode manip, int num_frames_pool);
@@ -268,6 +389,10 @@ API int dai_video_encoder_get_max_output_frame_size(DaiNode encoder);
 // Returned handle is a `std::shared_ptr<dai::Buffer>*` actually pointing to a `dai::ImageManipConfig`.
 API DaiBuffer dai_image_manip_config_new();
 API DaiBuffer dai_image_manip_get_initial_config(DaiNode manip);
+// Deep copy, independent of the original (unlike sharing the handle).
+API DaiBuffer dai_image_manip_config_copy(DaiBuffer cfg);
+// Restore a default-constructed `dai::ImageManipConfig`.
+API void dai_image_manip_config_reset(DaiBuffer cfg);
 API void dai_image_manip_config_clear_ops(DaiBuffer cfg);
 API void dai_image_manip_config_add_crop_xywh(DaiBuffer cfg, uint32_t x, uint32_t y, uint32_t w, uint32_t h);
 API void dai_image_manip_config_add_crop_rect(DaiBuffer cfg, float x, float y, float w, float h, bool normalized_coords);
@@ -293,6 +418,63 @@ API void dai_image_manip_config_set_skip_current_image(DaiBuffer cfg, bool skip)
 API bool dai_image_manip_config_get_reuse_previous_image(DaiBuffer cfg);
 API bool dai_image_manip_config_get_skip_current_image(DaiBuffer cfg);
 
+// Record/Replay node helpers; they fail in DepthAI-Core builds without these nodes.
+API bool dai_record_video_set_record_video_file(DaiNode node, const char* path);
+API bool dai_record_video_set_record_metadata_file(DaiNode node, const char* path);
+API bool dai_record_metadata_only_set_record_file(DaiNode node, const char* path);
+API bool dai_replay_video_set_replay_video_file(DaiNode node, const char* path);
+API bool dai_replay_video_set_replay_metadata_file(DaiNode node, const char* path);
+API bool dai_replay_metadata_only_set_replay_file(DaiNode node, const char* path);
+API bool dai_replay_video_set_loop(DaiNode node, bool loop);
+API bool dai_replay_metadata_only_set_loop(DaiNode node, bool loop);
+
+// Script node helpers
+API void dai_script_set_script(DaiNode script, const char* source, const char* name);
+
+// Sync node helpers
+API void dai_sync_set_sync_threshold_ns(DaiNode sync, int64_t threshold_ns);
+API void dai_sync_set_sync_attempts(DaiNode sync, int attempts);
+
+// Cast node helpers
+API void dai_cast_set_num_frames_pool(DaiNode cast, int num_frames_pool);
+API void dai_cast_set_output_frame_type(DaiNode cast, int frame_type);
+API void dai_cast_set_scale(DaiNode cast, float scale);
+API void dai_cast_set_offset(DaiNode cast, float offset);
+API void dai_cast_set_num_shaves(DaiNode cast, int num_shaves);
+
+// UVC node helpers
+// GPIO lists are passed as two parallel arrays of `count` entries (gpio number -> value).
+API void dai_uvc_set_gpios_on_init(DaiNode uvc, const int* gpio_nums, const int* values, int count);
+API void dai_uvc_set_gpios_on_stream_on(DaiNode uvc, const int* gpio_nums, const int* values, int count);
+API void dai_uvc_set_gpios_on_stream_off(DaiNode uvc, const int* gpio_nums, const int* values, int count);
+
+// SPIOut / SPIIn node helpers
+API void dai_spi_out_set_stream_name(DaiNode spi, const char* name);
+API void dai_spi_out_set_bus_id(DaiNode spi, int bus_id);
+API void dai_spi_in_set_stream_name(DaiNode spi, const char* name);
+API void dai_spi_in_set_bus_id(DaiNode spi, int bus_id);
+API void dai_spi_in_set_max_data_size(DaiNode spi, uint32_t max_data_size);
+API void dai_spi_in_set_num_frames(DaiNode spi, uint32_t num_frames);
+// Returned string must be freed with dai_free_cstring.
+API char* dai_spi_in_get_stream_name(DaiNode spi);
+API int dai_spi_in_get_bus_id(DaiNode spi);
+API uint32_t dai_spi_in_get_max_data_size(DaiNode spi);
+API uint32_t dai_spi_in_get_num_frames(DaiNode spi);
+
+// CameraControl helpers
+// Returned handle is a `std::shared_ptr<dai::Buffer>*` actually pointing to a `dai::CameraControl`.
+API DaiBuffer dai_camera_control_new();
+API void dai_camera_control_set_auto_exposure_region(DaiBuffer ctrl, uint16_t x, uint16_t y, uint16_t width, uint16_t height);
+API void dai_camera_control_set_auto_exposure_limit(DaiBuffer ctrl, uint32_t max_exposure_us);
+API void dai_camera_control_set_auto_exposure_lock(DaiBuffer ctrl, bool lock);
+API void dai_camera_control_set_auto_focus_mode(DaiBuffer ctrl, int mode);
+API void dai_camera_control_set_auto_focus_trigger(DaiBuffer ctrl);
+API void dai_camera_control_set_auto_white_balance_lock(DaiBuffer ctrl, bool lock);
+// `setStartStreaming()` when `streaming`, `setStopStreaming()` otherwise.
+API void dai_camera_control_set_streaming(DaiBuffer ctrl, bool streaming);
+API void dai_camera_control_set_frame_sync_mode(DaiBuffer ctrl, int mode);
+API void dai_camera_control_set_external_trigger(DaiBuffer ctrl, int num_frames_burst, int num_frames_discard);
+
 // Low-level camera node operations
 API DaiCameraNode dai_pipeline_create_camera(DaiPipeline pipeline, int board_socket);
 
@@ -309,6 +491,8 @@ API uint32_t dai_camera_get_max_height(DaiCameraNode camera);
 
 API void dai_camera_set_sensor_type(DaiCameraNode camera, int sensor_type);
 API int dai_camera_get_sensor_type(DaiCameraNode camera);
+// Sets `initialControl.setFrameSyncMode(mode)`.
+API void dai_camera_set_frame_sync_mode(DaiCameraNode camera, int mode);
 
 // Camera pools configuration
 API void dai_camera_set_raw_num_frames_pool(DaiCameraNode camera, int num);
@@ -330,6 +514,28 @@ API bool dai_camera_get_outputs_max_size_pool(DaiCameraNode camera, size_t* out_
 // Low-level output operations
 API DaiDataQueue dai_output_create_queue(DaiOutput output, unsigned int max_size, bool blocking);
 
+// Port introspection / configuration
+// Returned strings must be freed with dai_free_cstring.
+// Possible datatypes are returned as a JSON array of `{"datatype": int, "descendants": bool}`.
+API char* dai_output_get_name(DaiOutput output);
+API char* dai_output_get_group(DaiOutput output);
+API char* dai_output_get_possible_datatypes_json(DaiOutput output);
+API bool dai_output_can_connect(DaiOutput output, DaiInput input);
+// Node owning the port (not owned by the caller).
+API DaiNode dai_output_get_parent(DaiOutput output);
+API DaiNode dai_input_get_parent(DaiInput input);
+// Links leaving this output, as a JSON array.
+API char* dai_output_get_connections_json(DaiOutput output);
+API char* dai_input_get_name(DaiInput input);
+API char* dai_input_get_group(DaiInput input);
+API char* dai_input_get_possible_datatypes_json(DaiInput input);
+API void dai_input_set_blocking(DaiInput input, bool blocking);
+API bool dai_input_get_blocking(DaiInput input);
+API void dai_input_set_max_size(DaiInput input, unsigned int max_size);
+API unsigned int dai_input_get_max_size(DaiInput input);
+API void dai_input_set_reuse_previous_message(DaiInput input, bool reuse);
+API bool dai_input_get_reuse_previous_message(DaiInput input);
+
 // Low-level queue operations
 API void dai_queue_delete(DaiDataQueue queue);
 
@@ -378,6 +584,8 @@ API DaiRGBDData dai_queue_try_get_rgbd(DaiDataQueue queue);
 // Generic datatype helpers
 API void dai_datatype_release(DaiDatatype msg);
 API DaiDatatype dai_datatype_clone(DaiDatatype msg);
+// Address of the underlying message, shared by all clones of a handle; 0 for a null handle.
+API uintptr_t dai_datatype_get_native_id(DaiDatatype msg);
 API int dai_datatype_get_datatype_enum(DaiDatatype msg);
 API DaiImgFrame dai_datatype_as_img_frame(DaiDatatype msg);
 API DaiEncodedFrame dai_datatype_as_encoded_frame(DaiDatatype msg);
@@ -385,6 +593,10 @@ API DaiPointCloud dai_datatype_as_pointcloud(DaiDatatype msg);
 API DaiRGBDData dai_datatype_as_rgbd(DaiDatatype msg);
 API DaiBuffer dai_datatype_as_buffer(DaiDatatype msg);
 API DaiMessageGroup dai_datatype_as_message_group(DaiDatatype msg);
+// Buffer fields of any message; fail on messages that don't derive from `dai::Buffer`.
+API int64_t dai_datatype_get_timestamp_ns(DaiDatatype msg);
+API int64_t dai_datatype_get_timestamp_device_ns(DaiDatatype msg);
+API int64_t dai_datatype_get_sequence_num(DaiDatatype msg);
 API size_t dai_datatype_array_len(DaiDatatypeArray arr);
 API DaiDatatype dai_datatype_array_take(DaiDatatypeArray arr, size_t index);
 API void dai_datatype_array_free(DaiDatatypeArray arr);
@@ -411,6 +623,8 @@ API DaiImgFrame dai_input_try_get_img_frame(DaiInput input);
 API DaiInputQueue dai_input_create_input_queue(DaiInput input, unsigned int max_size, bool blocking);
 API void dai_input_queue_delete(DaiInputQueue queue);
 API void dai_input_queue_send(DaiInputQueue queue, DaiDatatype msg);
+API void dai_input_queue_send_img_frame(DaiInputQueue queue, DaiImgFrame frame);
+API void dai_input_queue_send_buffer(DaiInputQueue queue, DaiBuffer buffer);
 
 // Output send helpers (host node)
 API void dai_output_send_buffer(DaiOutput output, DaiBuffer buffer);
@@ -426,6 +640,37 @@ API DaiImgFrame dai_message_group_get_img_frame(DaiMessageGroup group, const cha
 API DaiBuffer dai_buffer_new(size_t size);
 API void dai_buffer_release(DaiBuffer buffer);
 API void dai_buffer_set_data(DaiBuffer buffer, const void* data, size_t len);
+API void* dai_buffer_get_data(DaiBuffer buffer);
+API size_t dai_buffer_get_size(DaiBuffer buffer);
+
+// Host-created message helpers
+// Timestamps are nanoseconds on the host steady clock, the domain DepthAI uses for message timestamps.
+API DaiImgFrame dai_frame_new(int width, int height, int type);
+API void dai_frame_set_data(DaiImgFrame frame, const void* data, size_t len);
+// Reuse of host-created messages: the number of owners of the message (1 = only this handle, so
+// nothing queued for sending still refers to it), and a payload copy that keeps the existing
+// allocation when `len` matches its size.
+API int64_t dai_buffer_use_count(DaiBuffer buffer);
+API int64_t dai_frame_use_count(DaiImgFrame frame);
+API void dai_buffer_write_data(DaiBuffer buffer, const void* data, size_t len);
+API void dai_frame_write_data(DaiImgFrame frame, const void* data, size_t len);
+API void dai_buffer_set_timestamp_ns(DaiBuffer buffer, int64_t ns);
+API int64_t dai_buffer_get_timestamp_ns(DaiBuffer buffer);
+API void dai_buffer_set_timestamp_now(DaiBuffer buffer);
+API void dai_buffer_set_sequence_num(DaiBuffer buffer, int64_t seq);
+API int64_t dai_buffer_get_sequence_num(DaiBuffer buffer);
+API void dai_frame_set_timestamp_ns(DaiImgFrame frame, int64_t ns);
+API int64_t dai_frame_get_timestamp_ns(DaiImgFrame frame);
+API void dai_frame_set_timestamp_now(DaiImgFrame frame);
+API void dai_frame_set_sequence_num(DaiImgFrame frame, int64_t seq);
+API int64_t dai_frame_get_sequence_num(DaiImgFrame frame);
+// Device-clock timestamp (not synced to the host) and the current host steady-clock time.
+API int64_t dai_clock_now_ns();
+API int64_t dai_frame_get_timestamp_device_ns(DaiImgFrame frame);
+// Socket of the camera the frame comes from (`ImgFrame::getInstanceNum()`), -1 on error.
+API int dai_frame_get_instance_num(DaiImgFrame frame);
+// Row-major 3x3 intrinsics of the frame after its crops/resizes (`ImgFrame::transformation`).
+API bool dai_frame_get_intrinsic_matrix(DaiImgFrame frame, float* out_matrix);
 
 // Low-level frame operations
 API void* dai_frame_get_data(DaiImgFrame frame);
@@ -435,6 +680,123 @@ API int dai_frame_get_type(DaiImgFrame frame);
 API size_t dai_frame_get_size(DaiImgFrame frame);
 API void dai_frame_release(DaiImgFrame frame);
 
+// OpenCV interop
+// Row stride of the first plane in bytes.
+API int dai_frame_get_stride(DaiImgFrame frame);
+// Converted copy via `ImgFrame::getCvFrame()` (NV12/YUV/planar -> BGR), as a heap `cv::Mat*`.
+// Only available when the wrapper is built with DEPTHAI_OPENCV_SUPPORT; otherwise returns null and sets the last error.
+API void* dai_frame_get_cv_frame(DaiImgFrame frame);
+API void* dai_cv_mat_get_data(void* mat);
+API int dai_cv_mat_get_rows(void* mat);
+API int dai_cv_mat_get_cols(void* mat);
+API int dai_cv_mat_get_type(void* mat);
+API size_t dai_cv_mat_get_step(void* mat);
+API void dai_cv_mat_release(void* mat);
+
+// IMU node and IMUData
+API void dai_imu_enable_sensor(DaiNode imu, int sensor, int report_rate_hz);
+API void dai_imu_set_batch_report_threshold(DaiNode imu, int threshold);
+API void dai_imu_set_max_batch_reports(DaiNode imu, int max_batch_reports);
+API DaiImuData dai_datatype_as_imu_data(DaiDatatype msg);
+API size_t dai_imu_data_get_num_packets(DaiImuData imu);
+// Report `kind`: 0 accelerometer, 1 gyroscope, 2 magnetometer, 3 rotation vector.
+// `values` receives 5 floats: x, y, z (or i, j, k, real, accuracy in radians for the rotation vector).
+API bool dai_imu_data_get_report(DaiImuData imu, size_t packet, int kind, float* values, int64_t* timestamp_ns, int* sequence, int* accuracy);
+API void dai_imu_data_release(DaiImuData imu);
+API int64_t dai_pointcloud_get_timestamp_ns(DaiPointCloud pcl);
+
+// SpatialLocationCalculator node, config and data
+// Returned config handles are `std::shared_ptr<dai::Buffer>*` pointing to a `dai::SpatialLocationCalculatorConfig`.
+// ROI layout: `roi` is 4 floats (x, y, width, height); thresholds are in millimeters;
+// `algorithm` is `dai::SpatialLocationCalculatorAlgorithm`; `step` -1 means auto.
+API DaiBuffer dai_spatial_location_config_new();
+API DaiBuffer dai_spatial_location_calculator_get_initial_config(DaiNode calc);
+API void dai_spatial_location_calculator_set_wait_for_config_input(DaiNode calc, bool wait);
+API void dai_spatial_location_config_clear_rois(DaiBuffer cfg);
+API void dai_spatial_location_config_add_roi(DaiBuffer cfg, const float* roi, uint32_t lower_threshold, uint32_t upper_threshold, int algorithm, int step);
+API size_t dai_spatial_location_config_get_num_rois(DaiBuffer cfg);
+API bool dai_spatial_location_config_get_roi(DaiBuffer cfg, size_t index, float* roi, uint32_t* lower_threshold, uint32_t* upper_threshold, int* algorithm, int* step);
+API DaiSpatialLocationData dai_datatype_as_spatial_location_data(DaiDatatype msg);
+API size_t dai_spatial_location_data_get_count(DaiSpatialLocationData data);
+// Same ROI layout as the config getter.
+API bool dai_spatial_location_data_get_roi(DaiSpatialLocationData data, size_t index, float* roi, uint32_t* lower_threshold, uint32_t* upper_threshold, int* algorithm, int* step);
+// `depth` receives 5 floats (millimeters): average, mode, median, min, max. `xyz` receives the spatial coordinates in millimeters.
+API bool dai_spatial_location_data_get_location(DaiSpatialLocationData data, size_t index, float* depth, uint32_t* pixel_count, float* xyz);
+API void dai_spatial_location_data_release(DaiSpatialLocationData data);
+
+// Generic input reads and ImgDetections
+// Generic input reads (any message type), returned as `DaiDatatype`.
+API DaiDatatype dai_input_get_datatype(DaiInput input);
+API DaiDatatype dai_input_try_get_datatype(DaiInput input);
+// Accepts both `ImgDetections` and `SpatialImgDetections` (spatial coordinates are dropped).
+API DaiImgDetections dai_datatype_as_img_detections(DaiDatatype msg);
+API size_t dai_img_detections_get_count(DaiImgDetections dets);
+// `bbox` receives 4 normalized floats: xmin, ymin, xmax, ymax.
+API bool dai_img_detections_get(DaiImgDetections dets, size_t index, int* label, float* confidence, float* bbox);
+API int64_t dai_img_detections_get_timestamp_ns(DaiImgDetections dets);
+API void dai_img_detections_set_timestamp_ns(DaiImgDetections dets, int64_t ns);
+API int64_t dai_img_detections_get_sequence_num(DaiImgDetections dets);
+API void dai_img_detections_set_sequence_num(DaiImgDetections dets, int64_t seq);
+API void dai_img_detections_release(DaiImgDetections dets);
+
+// Model zoo
+API char* dai_model_zoo_get_model(const char* model, const char* platform, const char* optimization_level, const char* compression_level, const char* snpe_version, const char* precision, bool use_cached, const char* cache_dir, const char* api_key, bool show_progress);
+API bool dai_model_zoo_download_models(const char* yaml_dir, const char* cache_dir, const char* api_key, bool show_progress);
+
+// NNArchive / NeuralNetwork / DetectionNetwork
+API DaiNNArchive dai_nn_archive_new(const char* path);
+API void dai_nn_archive_release(DaiNNArchive archive);
+API char* dai_nn_archive_get_info_json(DaiNNArchive archive);
+API bool dai_neural_network_set_nn_archive(DaiNode nn, DaiNNArchive archive, int num_shaves);
+API void dai_neural_network_set_num_inference_threads(DaiNode nn, int threads);
+API bool dai_detection_network_set_nn_archive(DaiNode nn, DaiNNArchive archive, int num_shaves);
+API void dai_detection_network_set_confidence_threshold(DaiNode nn, float threshold);
+API void dai_neural_network_set_num_shaves_per_inference_thread(DaiNode nn, int shaves);
+API void dai_neural_network_set_num_nce_per_inference_thread(DaiNode nn, int nces);
+API void dai_neural_network_set_num_pool_frames(DaiNode nn, int frames);
+API void dai_detection_network_set_num_inference_threads(DaiNode nn, int threads);
+API void dai_detection_network_set_num_shaves_per_inference_thread(DaiNode nn, int shaves);
+API void dai_detection_network_set_num_nce_per_inference_thread(DaiNode nn, int nces);
+
+// NNData accessors
+API DaiNNData dai_datatype_as_nn_data(DaiDatatype msg);
+API DaiNNData dai_message_group_get_nn_data(DaiMessageGroup group, const char* name);
+// JSON array of `{"name", "dims", "dataType"}`, one per output tensor.
+API char* dai_nn_data_get_layers_json(DaiNNData data);
+// Copy tensor `name` (dequantized, row-major) into `out`; returns the element count (call with
+// `out == nullptr` to query it), or 0 with the last error set on failure.
+API size_t dai_nn_data_get_tensor_f32(DaiNNData data, const char* name, float* out, size_t capacity);
+API int64_t dai_nn_data_get_timestamp_ns(DaiNNData data);
+API int64_t dai_nn_data_get_sequence_num(DaiNNData data);
+API void dai_nn_data_release(DaiNNData data);
+
+// Host-built ImgDetections
+API DaiImgDetections dai_img_detections_new();
+API bool dai_img_detections_push(DaiImgDetections dets, int label, float confidence, const float* bbox);
+// Copy timestamps and sequence number from the NNData the detections were decoded from.
+API void dai_img_detections_copy_metadata(DaiImgDetections dets, DaiNNData source);
+API DaiBuffer dai_img_detections_to_buffer(DaiImgDetections dets);
+API void dai_output_send_img_detections(DaiOutput output, DaiImgDetections dets);
+
+// Host-built ImgAnnotations, from JSON:
+// `{"points": [{"type", "points": [[x, y], ...], "outlineColor": [r, g, b, a], "fillColor", "thickness"}],
+//   "texts": [{"position": [x, y], "text", "fontSize", "textColor", "backgroundColor"}]}`
+// Coordinates are normalized, colors are `0..1`.
+API DaiBuffer dai_img_annotations_from_json(const char* json);
+
+// DetectionParser node
+API bool dai_detection_parser_set_nn_archive(DaiNode parser, DaiNNArchive archive);
+API void dai_detection_parser_set_confidence_threshold(DaiNode parser, float threshold);
+API void dai_detection_parser_set_num_classes(DaiNode parser, int num_classes);
+API void dai_detection_parser_set_iou_threshold(DaiNode parser, float threshold);
+
+// Input receive with timeout
+API DaiDatatype dai_input_get_datatype_timeout(DaiInput input, int timeout_ms);
+
+// Device log callbacks
+API int dai_device_add_log_callback(DaiDevice device, void* ctx, uintptr_t cb, uintptr_t drop_cb);
+API bool dai_device_remove_log_callback(DaiDevice device, int callback_id);
+
 // EncodedFrame accessors
 API void* dai_encoded_frame_get_data(DaiEncodedFrame frame);
 API size_t dai_encoded_frame_get_data_size(DaiEncodedFrame frame);
@@ -448,10 +810,16 @@ API int dai_encoded_frame_get_quality(DaiEncodedFrame frame);
 API int dai_encoded_frame_get_bitrate(DaiEncodedFrame frame);
 API bool dai_encoded_frame_get_lossless(DaiEncodedFrame frame);
 API int dai_encoded_frame_get_instance_num(DaiEncodedFrame frame);
+API int64_t dai_encoded_frame_get_timestamp_ns(DaiEncodedFrame frame);
+API int64_t dai_encoded_frame_get_sequence_num(DaiEncodedFrame frame);
 API void dai_encoded_frame_release(DaiEncodedFrame frame);
 
 // Low-level utility functions
 API int dai_device_get_connected_camera_sockets(DaiDevice device, int* sockets, int max_count);
+// JSON array of `dai::CameraFeatures`, one per connected camera.
+API char* dai_device_get_connected_camera_features_json(DaiDevice device);
+// JSON array of `dai::StereoPair` whose cameras are both connected.
+API char* dai_device_get_stereo_pairs_json(DaiDevice device);
 API const char* dai_camera_socket_name(int socket);
 
 // Error handling
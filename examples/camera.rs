@@ -1,10 +1,6 @@
 use std::time::Duration;
 
-use depthai::camera::{CameraNode, CameraOutputConfig};
-use depthai::common::{CameraBoardSocket, ImageFrameType, ResizeMode};
-use depthai::device::Device;
-use depthai::pipeline::Pipeline;
-use depthai::Result;
+use depthai::prelude::*;
 
 fn main() -> Result<()> {
     let device = Device::new()?;
@@ -1,30 +1,27 @@
-use depthai::camera::{CameraNode, CameraBoardSocket};
-use depthai::stereo_depth::StereoDepthNode;
-use depthai::pipeline::Pipeline;
-use depthai::device::Device;
-use depthai::{Result, depthai_composite};
+use depthai::depthai_composite;
+use depthai::prelude::*;
 
 /// A composite node that bundles a camera and stereo depth.
+// NOTE: `dai::node::Camera` exposes a `raw` output (and dynamic outputs requested via
+// `request_output(...)`). Older examples used `isp`, which is a `ColorCamera` port.
 #[depthai_composite]
+#[link(from = "left.raw", to = "stereo.left")]
+#[link(from = "right.raw", to = "stereo.right")]
 pub struct CameraStereoBundle {
     pub left: CameraNode,
     pub right: CameraNode,
+    #[forward(output = "depth", ty = ImageFrame)]
     pub stereo: StereoDepthNode,
 }
 
 impl CameraStereoBundle {
-    pub fn new(pipeline: &Pipeline) -> Result<Self> {
+    // Private: `pipeline.create()` makes the `#[link]`s after calling it.
+    fn new(pipeline: &Pipeline) -> Result<Self> {
         // Create native nodes
         let left = pipeline.create_with::<CameraNode, _>(CameraBoardSocket::CamB)?;
         let right = pipeline.create_with::<CameraNode, _>(CameraBoardSocket::CamC)?;
         let stereo = pipeline.create::<StereoDepthNode>()?;
 
-        // Link them
-        // NOTE: `dai::node::Camera` exposes a `raw` output (and dynamic outputs requested via
-        // `request_output(...)`). Older examples used `isp`, which is a `ColorCamera` port.
-        left.raw()?.link(&stereo.left()?)?;
-        right.raw()?.link(&stereo.right()?)?;
-
         Ok(Self { left, right, stereo })
     }
 }
@@ -35,6 +32,7 @@ fn main() -> Result<()> {
 
     // Create the composite node using the generic API
     let bundle = pipeline.create::<CameraStereoBundle>()?;
+    let _depth = bundle.depth()?.create_queue(4, false)?;
     
     println!("Created composite bundle with 2 cameras and 1 stereo node");
     
@@ -1,7 +1,7 @@
 use std::time::Duration;
 
-use depthai::camera::{CameraBoardSocket, CameraNode, CameraOutputConfig};
-use depthai::{depthai_host_node, Buffer, MessageGroup, Pipeline, Result};
+use depthai::depthai_host_node;
+use depthai::prelude::*;
 
 #[depthai_host_node]
 struct FrameLogger;
@@ -0,0 +1,37 @@
+use std::time::Duration;
+
+use depthai::prelude::*;
+use depthai::{MjpegServerConfig, MjpegServerHostNode, VideoEncoderProfile};
+
+fn main() -> Result<()> {
+    let device = Device::new()?;
+    let pipeline = Pipeline::new().with_device(&device).build()?;
+
+    let cam = pipeline.create_with::<CameraNode, _>(CameraBoardSocket::CamA)?;
+
+    let fps = 30.0;
+    let (w, h) = (640, 400);
+    let nv12 = cam.request_output(CameraOutputConfig {
+        size: (w, h),
+        frame_type: Some(ImageFrameType::NV12),
+        resize_mode: ResizeMode::Crop,
+        fps: Some(fps),
+        enable_undistortion: None,
+    })?;
+
+    // The encoder turns NV12 frames into JPEGs, which the server sends as they come.
+    let enc = pipeline.create::<VideoEncoderNode>()?;
+    enc.validate_nv12_size(w, h)?;
+    enc.set_default_profile_preset(fps, VideoEncoderProfile::Mjpeg);
+    nv12.link(&enc.input()?)?;
+
+    let server = pipeline.create_with::<MjpegServerHostNode, _>(MjpegServerConfig::default())?;
+    enc.out()?.link(&server.input()?)?;
+
+    pipeline.start()?;
+    eprintln!("streaming at {} (press Ctrl-C to stop)...", server.url());
+    loop {
+        std::thread::sleep(Duration::from_secs(5));
+        eprintln!("{} client(s) connected", server.client_count());
+    }
+}
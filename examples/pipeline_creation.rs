@@ -3,11 +3,7 @@
 /// This example shows:
 /// - typed creation (`CameraNode`)
 /// - and how to build a small “composite” using generic nodes (string name + `Node::link`)
-use depthai::camera::{CameraNode, CameraOutputConfig};
-use depthai::common::CameraBoardSocket;
-use depthai::device::Device;
-use depthai::pipeline::Pipeline;
-use depthai::Result;
+use depthai::prelude::*;
 
 fn main() -> Result<()> {
     println!("Creating pipeline with generic create_with() API...");
@@ -26,6 +26,9 @@ fn main() -> Result<()> {
 
     pipeline.start()?;
     eprintln!("rerun_host_node running (press Ctrl-C to stop)...");
+    if let Some(url) = host.web_viewer_url() {
+        eprintln!("Web viewer: {url}");
+    }
     eprintln!("If the web viewer can't fetch data, make sure the gRPC /proxy port (default 9876) is reachable from your browser (e.g. port-forward it if you're remote).");
     loop {
         std::thread::sleep(Duration::from_secs(1));
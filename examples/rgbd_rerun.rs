@@ -46,7 +46,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     // Start the web viewer server + gRPC server inside the pipeline (host-side).
     // Note: we use a separate `app_id` so this infrastructure stream doesn't collide with the
     // recording we produce below.
-    let _web = pipeline.create_with::<RerunHostNode, _>(RerunHostNodeConfig {
+    let web = pipeline.create_with::<RerunHostNode, _>(RerunHostNodeConfig {
         app_id: "depthai_rgbd_server".to_string(),
         viewer: RerunViewer::Web(RerunWebConfig {
             // In remote/SSH setups, auto-opening a browser is rarely desirable.
@@ -55,6 +55,9 @@ fn main() -> Result<(), Box<dyn Error>> {
         }),
         ..Default::default()
     })?;
+    if let Some(url) = web.web_viewer_url() {
+        eprintln!("Web viewer: {url}");
+    }
 
     // Connect a recording stream to the gRPC /proxy endpoint exposed by the host node.
     // This avoids spawning any GUI viewer and keeps the example usable over SSH.
@@ -130,6 +133,10 @@ fn main() -> Result<(), Box<dyn Error>> {
     // RGBD host node: combines RGB + depth into point cloud + paired frames.
     let rgbd = pipeline.create::<RgbdNode>()?;
     rgbd.set_depth_unit(DepthUnit::Meter);
+    // Logging to Rerun is the bottleneck: keep every 2nd pair at most, and only the points with
+    // a measurement.
+    rgbd.set_output_fps(fps / 2.0);
+    rgbd.set_sparse(true);
     rgbd.build_ex(
         false,
         if is_rvc4 { StereoPresetMode::Default } else { StereoPresetMode::Robotics },
@@ -198,21 +205,13 @@ fn main() -> Result<(), Box<dyn Error>> {
         // Pull point cloud.
         if let Some(q_pcl) = q_pcl.as_ref() {
             if let Some(pcl) = q_pcl.try_next_pointcloud()? {
-                // Downsample to keep logging responsive.
-                let pts = pcl.points();
-                let step = 4usize;
-
-                let mut positions = Vec::with_capacity(pts.len() / step + 1);
-                let mut colors = Vec::with_capacity(pts.len() / step + 1);
+                // Downsample to 2 cm voxels to keep logging responsive.
+                let pts = pcl.voxel_downsample(0.02)?;
 
-                for p in pts.iter().step_by(step) {
-                    if !p.x.is_finite() || !p.y.is_finite() || !p.z.is_finite() {
-                        continue;
-                    }
-                    if p.z == 0.0 {
-                        continue;
-                    }
+                let mut positions = Vec::with_capacity(pts.len());
+                let mut colors = Vec::with_capacity(pts.len());
 
+                for p in &pts {
                     positions.push(rr::Position3D::from([p.x, p.y, p.z]));
                     colors.push(rr::Color::from(rr::Rgba32(rgba32_from_rgba(p.r, p.g, p.b, p.a))));
                 }
@@ -1,7 +1,7 @@
 use std::time::Duration;
 
-use depthai::camera::{CameraBoardSocket, CameraNode, CameraOutputConfig};
-use depthai::{depthai_threaded_host_node, Pipeline, Result, ThreadedHostNodeContext};
+use depthai::prelude::*;
+use depthai::{depthai_threaded_host_node, ThreadedHostNodeContext};
 
 #[depthai_threaded_host_node]
 struct FrameTap {
@@ -10,13 +10,9 @@ struct FrameTap {
 
 impl FrameTap {
     fn run(&mut self, ctx: &ThreadedHostNodeContext) {
-        while ctx.is_running() {
-            match self.input.get_frame() {
-                Ok(frame) => {
-                    println!("threaded host node frame: {}x{}", frame.width(), frame.height());
-                }
-                Err(_) => break,
-            }
+        // `next` returns `Ok(None)` once the pipeline stops, even if no frame arrives.
+        while let Ok(Some(frame)) = ctx.next::<ImageFrame, _>(&self.input) {
+            println!("threaded host node frame: {}x{}", frame.width(), frame.height());
         }
     }
 }
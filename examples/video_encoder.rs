@@ -2,9 +2,8 @@ use std::fs::File;
 use std::io::Write;
 use std::time::Duration;
 
-use depthai::camera::{CameraNode, CameraOutputConfig};
-use depthai::common::{CameraBoardSocket, ImageFrameType, ResizeMode};
-use depthai::{Device, Pipeline, Result, VideoEncoderNode, VideoEncoderProfile};
+use depthai::prelude::*;
+use depthai::VideoEncoderProfile;
 
 fn main() -> Result<()> {
     // Device (single connection)
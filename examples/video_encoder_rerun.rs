@@ -24,7 +24,7 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     // Start the web viewer server + gRPC server inside the pipeline (host-side).
     // For remote dev/SSH, port-forward 9090 (web) and 9876 (gRPC proxy).
-    let _web = pipeline.create_with::<RerunHostNode, _>(RerunHostNodeConfig {
+    let web = pipeline.create_with::<RerunHostNode, _>(RerunHostNodeConfig {
         app_id: "depthai_h265_server".to_string(),
         viewer: RerunViewer::Web(RerunWebConfig {
             // In remote/SSH setups, auto-opening a browser is rarely desirable.
@@ -33,6 +33,9 @@ fn main() -> Result<(), Box<dyn Error>> {
         }),
         ..Default::default()
     })?;
+    if let Some(url) = web.web_viewer_url() {
+        eprintln!("Web viewer: {url}");
+    }
 
     // Connect a recording stream to the gRPC /proxy endpoint exposed by the host node.
     let rec = rr::RecordingStreamBuilder::new("depthai_h265").connect_grpc()?;
@@ -0,0 +1,473 @@
+//! Telemetry export to Apache Arrow and Parquet (requires the `arrow` feature).
+//!
+//! Frame metadata, detections and IMU samples are collected row by row into three tables. A
+//! [`TelemetryBatcher`] turns them into Arrow [`RecordBatch`]es for in-process use; a
+//! [`TelemetryWriter`] writes them as Parquet files readable by pandas, Polars, DuckDB or Spark
+//! without a custom loader:
+//!
+//! ```text
+//! <root>/frames/part-<session>-<n>.parquet       one row per frame (metadata only, no pixels)
+//! <root>/detections/part-<session>-<n>.parquet   one row per detection
+//! <root>/imu/part-<session>-<n>.parquet          one row per IMU packet
+//! ```
+//!
+//! `<session>` is the writer's start time in Unix milliseconds, process id and a per-process
+//! counter, so writers sharing a root never write to the same file.
+//!
+//! ```no_run
+//! # use std::time::Duration;
+//! # use depthai::Result;
+//! # use depthai::camera::OutputQueue;
+//! # use depthai::analytics::{TelemetryConfig, TelemetryWriter};
+//! # fn f(queue: OutputQueue) -> Result<()> {
+//! let mut writer = TelemetryWriter::new(TelemetryConfig {
+//!     device_id: Some("oak-d-lab-3".into()),
+//!     ..TelemetryConfig::new("telemetry")
+//! })?;
+//! while let Some(frame) = queue.blocking_next(Some(Duration::from_secs(1)))? {
+//!     writer.log_frame("rgb", &frame)?;
+//! }
+//! writer.finish()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Timestamps are nanoseconds on the host steady clock, like the messages they come from.
+//!
+//! Every writer session starts new files, so a table directory accumulates files written by
+//! different versions of this crate. Columns are only ever appended to a table, and are
+//! nullable, so such a directory reads as one table when schemas are merged by name (e.g.
+//! DuckDB `read_parquet(..., union_by_name = true)`). The version of the layout is stored in
+//! the schema metadata under [`SCHEMA_VERSION_KEY`].
+
+use std::fs::{self, File, OpenOptions};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use arrow_array::builder::{
+    make_builder, ArrayBuilder, Float32Builder, Int64Builder, StringBuilder, UInt32Builder, UInt64Builder,
+};
+use arrow_array::RecordBatch;
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::file::properties::WriterProperties;
+
+use crate::camera::ImageFrame;
+use crate::detections::ImgDetections;
+use crate::error::{DepthaiError, Result};
+use crate::host_node::duration_to_ns;
+use crate::imu::{ImuData, ImuVectorReport};
+
+/// Schema metadata key holding [`SCHEMA_VERSION`].
+pub const SCHEMA_VERSION_KEY: &str = "depthai.schema_version";
+/// Version of the table layouts; bumped whenever a column is appended.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// The tables telemetry is split into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TelemetryTable {
+    Frames,
+    Detections,
+    Imu,
+}
+
+impl TelemetryTable {
+    pub const ALL: [TelemetryTable; 3] = [TelemetryTable::Frames, TelemetryTable::Detections, TelemetryTable::Imu];
+
+    /// Directory name of the table under [`TelemetryConfig::root`].
+    pub fn name(self) -> &'static str {
+        match self {
+            TelemetryTable::Frames => "frames",
+            TelemetryTable::Detections => "detections",
+            TelemetryTable::Imu => "imu",
+        }
+    }
+
+    pub fn schema(self) -> SchemaRef {
+        use DataType::{Float32, Int64, UInt32, UInt64, Utf8};
+        let mut fields = vec![
+            Field::new("device_id", Utf8, true),
+            Field::new("timestamp_ns", Int64, false),
+            Field::new("sequence_num", Int64, false),
+        ];
+        let f32s = |names: &[&str]| names.iter().map(|n| Field::new(*n, Float32, true)).collect::<Vec<_>>();
+        match self {
+            TelemetryTable::Frames => fields.extend([
+                Field::new("stream", Utf8, false),
+                Field::new("timestamp_device_ns", Int64, true),
+                Field::new("socket", Utf8, true),
+                Field::new("width", UInt32, false),
+                Field::new("height", UInt32, false),
+                Field::new("frame_type", Utf8, true),
+                Field::new("stride", UInt64, false),
+                Field::new("data_size", UInt64, false),
+            ]),
+            TelemetryTable::Detections => {
+                fields.extend([Field::new("index", UInt32, false), Field::new("label", UInt32, false)]);
+                fields.extend(f32s(&["confidence", "xmin", "ymin", "xmax", "ymax"]));
+            }
+            TelemetryTable::Imu => {
+                for sensor in ["accel", "gyro", "mag"] {
+                    fields.push(Field::new(format!("{sensor}_timestamp_ns"), Int64, true));
+                    fields.extend(f32s(&[&format!("{sensor}_x"), &format!("{sensor}_y"), &format!("{sensor}_z")]));
+                }
+                fields.push(Field::new("rot_timestamp_ns", Int64, true));
+                fields.extend(f32s(&["rot_i", "rot_j", "rot_k", "rot_real", "rot_accuracy"]));
+            }
+        }
+        let metadata = [
+            (SCHEMA_VERSION_KEY.to_string(), SCHEMA_VERSION.to_string()),
+            ("depthai.table".to_string(), self.name().to_string()),
+        ];
+        Arc::new(Schema::new_with_metadata(fields, metadata.into_iter().collect()))
+    }
+}
+
+/// Column builders of one table.
+struct Table {
+    schema: SchemaRef,
+    builders: Vec<Box<dyn ArrayBuilder>>,
+    rows: usize,
+}
+
+impl Table {
+    fn new(table: TelemetryTable) -> Self {
+        let schema = table.schema();
+        let builders = schema.fields().iter().map(|f| make_builder(f.data_type(), 0)).collect();
+        Self { schema, builders, rows: 0 }
+    }
+
+    fn row(&mut self) -> Row<'_> {
+        self.rows += 1;
+        Row { table: self, column: 0 }
+    }
+
+    fn take(&mut self) -> Result<Option<RecordBatch>> {
+        if self.rows == 0 {
+            return Ok(None);
+        }
+        self.rows = 0;
+        let columns = self.builders.iter_mut().map(|b| b.finish()).collect();
+        RecordBatch::try_new(Arc::clone(&self.schema), columns)
+            .map(Some)
+            .map_err(|e| DepthaiError::new(format!("failed to build record batch: {e}")))
+    }
+}
+
+/// Appends the values of one row, in schema order.
+struct Row<'a> {
+    table: &'a mut Table,
+    column: usize,
+}
+
+impl Row<'_> {
+    fn next<B: ArrayBuilder>(&mut self) -> &mut B {
+        let builder = self.table.builders[self.column].as_any_mut().downcast_mut::<B>();
+        self.column += 1;
+        builder.expect("row values follow the table schema")
+    }
+
+    fn str(mut self, value: Option<&str>) -> Self {
+        self.next::<StringBuilder>().append_option(value);
+        self
+    }
+
+    fn i64(mut self, value: Option<i64>) -> Self {
+        self.next::<Int64Builder>().append_option(value);
+        self
+    }
+
+    fn u32(mut self, value: u32) -> Self {
+        self.next::<UInt32Builder>().append_value(value);
+        self
+    }
+
+    fn u64(mut self, value: u64) -> Self {
+        self.next::<UInt64Builder>().append_value(value);
+        self
+    }
+
+    fn f32(mut self, value: Option<f32>) -> Self {
+        self.next::<Float32Builder>().append_option(value);
+        self
+    }
+
+    fn vector(self, report: Option<&ImuVectorReport>) -> Self {
+        self.i64(report.map(|r| duration_to_ns(r.timestamp)))
+            .f32(report.map(|r| r.x))
+            .f32(report.map(|r| r.y))
+            .f32(report.map(|r| r.z))
+    }
+
+    fn end(self) {
+        debug_assert_eq!(self.column, self.table.builders.len(), "row is missing columns");
+    }
+}
+
+/// Collects messages into Arrow [`RecordBatch`]es, one table per [`TelemetryTable`].
+pub struct TelemetryBatcher {
+    device_id: Option<String>,
+    tables: [Table; 3],
+}
+
+impl TelemetryBatcher {
+    /// `device_id` fills the `device_id` column of every row, to tell devices of a fleet apart.
+    pub fn new(device_id: Option<String>) -> Self {
+        Self {
+            device_id,
+            tables: TelemetryTable::ALL.map(Table::new),
+        }
+    }
+
+    fn table(&mut self, table: TelemetryTable) -> &mut Table {
+        &mut self.tables[table as usize]
+    }
+
+    /// Add the metadata of `frame` (not its pixels), under the stream name `stream`.
+    pub fn push_frame(&mut self, stream: &str, frame: &ImageFrame) -> Result<()> {
+        let timestamp = duration_to_ns(frame.timestamp()?);
+        let timestamp_device = frame.timestamp_device().ok().map(duration_to_ns);
+        let sequence_num = frame.sequence_num()?;
+        let socket = frame.board_socket().ok().map(|s| format!("{s:?}"));
+        let frame_type = frame.format().map(|t| format!("{t:?}"));
+        let device_id = self.device_id.clone();
+        self.table(TelemetryTable::Frames)
+            .row()
+            .str(device_id.as_deref())
+            .i64(Some(timestamp))
+            .i64(Some(sequence_num))
+            .str(Some(stream))
+            .i64(timestamp_device)
+            .str(socket.as_deref())
+            .u32(frame.width())
+            .u32(frame.height())
+            .str(frame_type.as_deref())
+            .u64(frame.stride() as u64)
+            .u64(frame.byte_len() as u64)
+            .end();
+        Ok(())
+    }
+
+    /// Add one row per detection of `detections`.
+    pub fn push_detections(&mut self, detections: &ImgDetections) -> Result<()> {
+        let timestamp = duration_to_ns(detections.timestamp()?);
+        let sequence_num = detections.sequence_num()?;
+        let device_id = self.device_id.clone();
+        let table = self.table(TelemetryTable::Detections);
+        for (index, d) in detections.detections()?.iter().enumerate() {
+            table
+                .row()
+                .str(device_id.as_deref())
+                .i64(Some(timestamp))
+                .i64(Some(sequence_num))
+                .u32(index as u32)
+                .u32(d.label)
+                .f32(Some(d.confidence))
+                .f32(Some(d.xmin))
+                .f32(Some(d.ymin))
+                .f32(Some(d.xmax))
+                .f32(Some(d.ymax))
+                .end();
+        }
+        Ok(())
+    }
+
+    /// Add one row per packet of `data`. The row timestamp and sequence number are those of the
+    /// first report present in the packet; each sensor also keeps its own timestamp.
+    pub fn push_imu(&mut self, data: &ImuData) -> Result<()> {
+        let device_id = self.device_id.clone();
+        let table = self.table(TelemetryTable::Imu);
+        for packet in data.packets()? {
+            let rotation = packet.rotation_vector.as_ref();
+            let Some((timestamp, sequence)) = [&packet.accelerometer, &packet.gyroscope, &packet.magnetometer]
+                .into_iter()
+                .flatten()
+                .map(|r| (r.timestamp, r.sequence))
+                .chain(rotation.map(|r| (r.timestamp, r.sequence)))
+                .next()
+            else {
+                continue;
+            };
+            table
+                .row()
+                .str(device_id.as_deref())
+                .i64(Some(duration_to_ns(timestamp)))
+                .i64(Some(sequence as i64))
+                .vector(packet.accelerometer.as_ref())
+                .vector(packet.gyroscope.as_ref())
+                .vector(packet.magnetometer.as_ref())
+                .i64(rotation.map(|r| duration_to_ns(r.timestamp)))
+                .f32(rotation.map(|r| r.i))
+                .f32(rotation.map(|r| r.j))
+                .f32(rotation.map(|r| r.k))
+                .f32(rotation.map(|r| r.real))
+                .f32(rotation.map(|r| r.rotation_accuracy))
+                .end();
+        }
+        Ok(())
+    }
+
+    /// Rows collected in `table` since it was last taken.
+    pub fn rows(&self, table: TelemetryTable) -> usize {
+        self.tables[table as usize].rows
+    }
+
+    /// The rows collected in `table` as one batch, `None` if there are none.
+    pub fn take(&mut self, table: TelemetryTable) -> Result<Option<RecordBatch>> {
+        self.table(table).take()
+    }
+}
+
+/// Settings of a [`TelemetryWriter`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TelemetryConfig {
+    /// Directory the table directories are created in.
+    pub root: PathBuf,
+    /// Written to the `device_id` column of every row.
+    pub device_id: Option<String>,
+    /// Rows collected per table before they are written as one row group.
+    pub batch_rows: usize,
+    /// Rows per file before a table moves on to a new file.
+    pub rows_per_file: usize,
+}
+
+impl TelemetryConfig {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            device_id: None,
+            batch_rows: 4096,
+            rows_per_file: 1_000_000,
+        }
+    }
+}
+
+/// The Parquet file a table is currently written to.
+#[derive(Default)]
+struct PartFile {
+    writer: Option<ArrowWriter<File>>,
+    rows: usize,
+    part: u32,
+}
+
+fn parquet_error(e: parquet::errors::ParquetError) -> DepthaiError {
+    DepthaiError::new(format!("failed to write parquet file: {e}"))
+}
+
+impl PartFile {
+    fn write(&mut self, dir: &Path, session: &str, batch: &RecordBatch, rows_per_file: usize) -> Result<()> {
+        if self.writer.is_none() {
+            fs::create_dir_all(dir)
+                .map_err(|e| DepthaiError::new(format!("failed to create {}: {e}", dir.display())))?;
+            let path = dir.join(format!("part-{session}-{:05}.parquet", self.part));
+            // Never append to or truncate another session's file.
+            let file = OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&path)
+                .map_err(|e| DepthaiError::new(format!("failed to create {}: {e}", path.display())))?;
+            let props = WriterProperties::builder().set_compression(Compression::SNAPPY).build();
+            self.writer = Some(ArrowWriter::try_new(file, batch.schema(), Some(props)).map_err(parquet_error)?);
+        }
+        if let Some(writer) = &mut self.writer {
+            writer.write(batch).map_err(parquet_error)?;
+            self.rows += batch.num_rows();
+        }
+        if self.rows >= rows_per_file.max(1) {
+            self.close()?;
+        }
+        Ok(())
+    }
+
+    fn close(&mut self) -> Result<()> {
+        if let Some(writer) = self.writer.take() {
+            writer.close().map_err(parquet_error)?;
+            self.rows = 0;
+            self.part += 1;
+        }
+        Ok(())
+    }
+}
+
+/// Writes telemetry as Parquet files, see the [module docs](self).
+///
+/// Rows are buffered per table and written every [`TelemetryConfig::batch_rows`] rows. Call
+/// [`finish`](Self::finish) to write the rest and the file footers; dropping the writer does
+/// the same but ignores errors.
+pub struct TelemetryWriter {
+    config: TelemetryConfig,
+    session: String,
+    batcher: TelemetryBatcher,
+    files: [PartFile; 3],
+}
+
+impl TelemetryWriter {
+    pub fn new(config: TelemetryConfig) -> Result<Self> {
+        fs::create_dir_all(&config.root)
+            .map_err(|e| DepthaiError::new(format!("failed to create {}: {e}", config.root.display())))?;
+        // Unique across processes and across writers of one process started in the same millisecond.
+        static WRITERS: AtomicU32 = AtomicU32::new(0);
+        let millis = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis();
+        let session = format!("{millis}-{}-{}", std::process::id(), WRITERS.fetch_add(1, Ordering::Relaxed));
+        Ok(Self {
+            batcher: TelemetryBatcher::new(config.device_id.clone()),
+            config,
+            session,
+            files: Default::default(),
+        })
+    }
+
+    pub fn log_frame(&mut self, stream: &str, frame: &ImageFrame) -> Result<()> {
+        self.batcher.push_frame(stream, frame)?;
+        self.flush_full(TelemetryTable::Frames)
+    }
+
+    pub fn log_detections(&mut self, detections: &ImgDetections) -> Result<()> {
+        self.batcher.push_detections(detections)?;
+        self.flush_full(TelemetryTable::Detections)
+    }
+
+    pub fn log_imu(&mut self, data: &ImuData) -> Result<()> {
+        self.batcher.push_imu(data)?;
+        self.flush_full(TelemetryTable::Imu)
+    }
+
+    fn flush_full(&mut self, table: TelemetryTable) -> Result<()> {
+        if self.batcher.rows(table) >= self.config.batch_rows.max(1) {
+            self.flush_table(table)?;
+        }
+        Ok(())
+    }
+
+    fn flush_table(&mut self, table: TelemetryTable) -> Result<()> {
+        if let Some(batch) = self.batcher.take(table)? {
+            let dir = self.config.root.join(table.name());
+            self.files[table as usize].write(&dir, &self.session, &batch, self.config.rows_per_file)?;
+        }
+        Ok(())
+    }
+
+    /// Write the buffered rows of every table. Files stay open; they are readable once closed.
+    pub fn flush(&mut self) -> Result<()> {
+        TelemetryTable::ALL.into_iter().try_for_each(|table| self.flush_table(table))
+    }
+
+    fn close(&mut self) -> Result<()> {
+        self.flush()?;
+        self.files.iter_mut().try_for_each(PartFile::close)
+    }
+
+    /// Write the buffered rows and close the files.
+    pub fn finish(mut self) -> Result<()> {
+        self.close()
+    }
+}
+
+impl Drop for TelemetryWriter {
+    fn drop(&mut self) {
+        let _ = self.close();
+    }
+}
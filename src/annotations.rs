@@ -0,0 +1,233 @@
+//! Overlays for visualizers, as DepthAI `ImgAnnotations` messages.
+//!
+//! [`ImageAnnotations`] holds normalized shapes and text; [`to_buffer`](ImageAnnotations::to_buffer)
+//! turns it into an `ImgAnnotations` message that a host node can send, e.g. to a
+//! `RemoteConnection` topic. [`detections_to_annotations`] draws detector output with one color
+//! per label:
+//!
+//! ```no_run
+//! # use depthai::annotations::detections_to_annotations;
+//! # use depthai::detections::ImgDetections;
+//! # use depthai::Result;
+//! # fn f(detections: &ImgDetections, classes: &[String]) -> Result<()> {
+//! let annotations = detections_to_annotations(detections, classes)?;
+//! let buffer = annotations.to_buffer()?;
+//! # let _ = buffer;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::borrow::Cow;
+use std::ffi::CString;
+use std::time::Duration;
+
+use depthai_sys::depthai;
+use serde_json::{json, Value};
+
+use crate::detections::{ImgDetection, ImgDetections};
+use crate::error::{clear_error_flag, last_error, DepthaiError, Result};
+use crate::host_node::Buffer;
+
+/// RGBA color, each channel `0..1`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Color {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl Color {
+    pub const TRANSPARENT: Self = Self::rgba(0.0, 0.0, 0.0, 0.0);
+    pub const BLACK: Self = Self::rgba(0.0, 0.0, 0.0, 1.0);
+    pub const WHITE: Self = Self::rgba(1.0, 1.0, 1.0, 1.0);
+
+    pub const fn rgba(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Same color with alpha `a`.
+    pub const fn with_alpha(self, a: f32) -> Self {
+        Self { a, ..self }
+    }
+
+    /// A fixed, distinct color for `label`, so a class keeps its color across frames.
+    pub fn for_label(label: u32) -> Self {
+        // Golden-ratio hue steps keep neighbouring labels apart.
+        let hue = (label as f32 * 0.618_034).fract();
+        let (r, g, b) = hsv_to_rgb(hue, 0.75, 0.95);
+        Self::rgba(r, g, b, 1.0)
+    }
+
+    fn to_json(self) -> Value {
+        json!([self.r, self.g, self.b, self.a])
+    }
+}
+
+fn hsv_to_rgb(h: f32, s: f32, v: f32) -> (f32, f32, f32) {
+    let i = (h * 6.0).floor();
+    let f = h * 6.0 - i;
+    let (p, q, t) = (v * (1.0 - s), v * (1.0 - f * s), v * (1.0 - (1.0 - f) * s));
+    match i as i32 % 6 {
+        0 => (v, t, p),
+        1 => (q, v, p),
+        2 => (p, v, t),
+        3 => (p, q, v),
+        4 => (t, p, v),
+        _ => (v, p, q),
+    }
+}
+
+/// How the points of a [`PointsAnnotation`] are connected.
+///
+/// Mirrors C++: `dai::PointsAnnotationType`.
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PointsAnnotationType {
+    #[default]
+    Unknown = 0,
+    Points = 1,
+    /// Closed polygon.
+    LineLoop = 2,
+    LineStrip = 3,
+    /// Separate segments between pairs of points.
+    LineList = 4,
+}
+
+/// Points, lines or a polygon, in normalized coordinates.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PointsAnnotation {
+    pub kind: PointsAnnotationType,
+    pub points: Vec<(f32, f32)>,
+    pub outline_color: Color,
+    pub fill_color: Color,
+    pub thickness: f32,
+}
+
+impl PointsAnnotation {
+    /// Outline of the normalized box `(xmin, ymin)`-`(xmax, ymax)`.
+    pub fn rect(xmin: f32, ymin: f32, xmax: f32, ymax: f32, color: Color, thickness: f32) -> Self {
+        Self {
+            kind: PointsAnnotationType::LineLoop,
+            points: vec![(xmin, ymin), (xmax, ymin), (xmax, ymax), (xmin, ymax)],
+            outline_color: color,
+            fill_color: Color::TRANSPARENT,
+            thickness,
+        }
+    }
+}
+
+/// Text at a normalized position (top left corner).
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextAnnotation {
+    pub position: (f32, f32),
+    pub text: String,
+    /// Font size in pixels.
+    pub font_size: f32,
+    pub text_color: Color,
+    pub background_color: Color,
+}
+
+/// Shapes and text to draw over one frame.
+///
+/// Mirrors C++: `dai::ImgAnnotations` with a single `ImgAnnotation`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ImageAnnotations {
+    pub points: Vec<PointsAnnotation>,
+    pub texts: Vec<TextAnnotation>,
+    /// Copied onto the message, so visualizers can match it to its frame.
+    pub timestamp: Option<Duration>,
+    pub sequence_num: Option<i64>,
+}
+
+impl ImageAnnotations {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty() && self.texts.is_empty()
+    }
+
+    fn to_json(&self) -> Value {
+        let points: Vec<Value> = self
+            .points
+            .iter()
+            .map(|p| {
+                json!({
+                    "type": p.kind as i32,
+                    "points": p.points.iter().map(|(x, y)| json!([x, y])).collect::<Vec<_>>(),
+                    "outlineColor": p.outline_color.to_json(),
+                    "fillColor": p.fill_color.to_json(),
+                    "thickness": p.thickness,
+                })
+            })
+            .collect();
+        let texts: Vec<Value> = self
+            .texts
+            .iter()
+            .map(|t| {
+                json!({
+                    "position": [t.position.0, t.position.1],
+                    "text": t.text,
+                    "fontSize": t.font_size,
+                    "textColor": t.text_color.to_json(),
+                    "backgroundColor": t.background_color.to_json(),
+                })
+            })
+            .collect();
+        json!({ "points": points, "texts": texts })
+    }
+
+    /// Build the `ImgAnnotations` message.
+    pub fn to_buffer(&self) -> Result<Buffer> {
+        let json = CString::new(self.to_json().to_string())
+            .map_err(|_| DepthaiError::new("annotation text must not contain NUL bytes"))?;
+        clear_error_flag();
+        let handle = unsafe { depthai::dai_img_annotations_from_json(json.as_ptr()) };
+        if handle.is_null() {
+            return Err(last_error("failed to create image annotations"));
+        }
+        let buffer = Buffer::from_handle(handle);
+        if let Some(timestamp) = self.timestamp {
+            buffer.set_timestamp(timestamp)?;
+        }
+        if let Some(seq) = self.sequence_num {
+            buffer.set_sequence_num(seq)?;
+        }
+        Ok(buffer)
+    }
+}
+
+/// Class name of `label` in `labels`, or `"label {n}"` when the map doesn't cover it.
+pub fn label_name(labels: &[String], label: u32) -> Cow<'_, str> {
+    match labels.get(label as usize) {
+        Some(name) => Cow::Borrowed(name),
+        None => Cow::Owned(format!("label {label}")),
+    }
+}
+
+/// Box outline and a `"name 87%"` caption per detection, colored by [`Color::for_label`].
+pub fn annotate_detections(detections: &[ImgDetection], labels: &[String]) -> ImageAnnotations {
+    let mut annotations = ImageAnnotations::new();
+    for d in detections {
+        let color = Color::for_label(d.label);
+        annotations.points.push(PointsAnnotation::rect(d.xmin, d.ymin, d.xmax, d.ymax, color, 2.0));
+        annotations.texts.push(TextAnnotation {
+            position: (d.xmin, d.ymin),
+            text: format!("{} {:.0}%", label_name(labels, d.label), d.confidence * 100.0),
+            font_size: 16.0,
+            text_color: Color::WHITE,
+            background_color: color.with_alpha(0.6),
+        });
+    }
+    annotations
+}
+
+/// [`annotate_detections`] for a detections message, keeping its timestamp and sequence number.
+pub fn detections_to_annotations(detections: &ImgDetections, labels: &[String]) -> Result<ImageAnnotations> {
+    let mut annotations = annotate_detections(&detections.detections()?, labels);
+    annotations.timestamp = Some(detections.timestamp()?);
+    annotations.sequence_num = Some(detections.sequence_num()?);
+    Ok(annotations)
+}
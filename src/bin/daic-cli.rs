@@ -0,0 +1,209 @@
+//! `daic-cli`: inspect and sanity-check DepthAI devices (requires the `cli` feature).
+//!
+//! Built only on the public `depthai` API, so it also exercises that API end to end.
+
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use depthai::camera::{CameraNode, CameraOutputConfig};
+use depthai::common::CameraBoardSocket;
+use depthai::version;
+use depthai::{Device, Pipeline, Result};
+
+const USAGE: &str = "\
+usage: daic-cli <command> [options]
+
+commands:
+  list                                  devices visible to DepthAI
+  info [<device-id>]                    firmware, cameras, calibration and temperatures
+  benchmark [<device-id>] [--seconds N] [--size WxH] [--socket CamA]
+                                        frame rate of one camera output
+  flash-bootloader <device-id> --yes    flash the bootloader bundled with this library
+
+<device-id> is a device ID (MXID) or name (USB path / IP address), as printed by `list`;
+without it the first available device is used.";
+
+/// A command line mistake, reported with the usage text.
+struct UsageError(String);
+
+enum Command {
+    List,
+    Info { device: Option<String> },
+    Benchmark { device: Option<String>, seconds: u64, size: (u32, u32), socket: CameraBoardSocket },
+    FlashBootloader { device: String },
+}
+
+fn parse(args: &[String]) -> std::result::Result<Command, UsageError> {
+    let (command, rest) = args.split_first().ok_or_else(|| UsageError("missing command".into()))?;
+    let mut positional = Vec::new();
+    let mut options = Vec::new();
+    let mut rest = rest.iter();
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "--yes" => options.push(("--yes", String::new())),
+            "--seconds" | "--size" | "--socket" => {
+                let value = rest.next().ok_or_else(|| UsageError(format!("{arg} needs a value")))?;
+                options.push((arg.as_str(), value.clone()));
+            }
+            a if a.starts_with("--") => return Err(UsageError(format!("unknown option {a}"))),
+            _ => positional.push(arg.clone()),
+        }
+    }
+    let option = |name: &str| options.iter().rev().find(|(n, _)| *n == name).map(|(_, v)| v.as_str());
+    if positional.len() > 1 {
+        return Err(UsageError(format!("unexpected argument {}", positional[1])));
+    }
+    let device = positional.pop();
+    match command.as_str() {
+        "list" if device.is_none() => Ok(Command::List),
+        "info" => Ok(Command::Info { device }),
+        "benchmark" => {
+            let seconds = match option("--seconds") {
+                Some(s) => s.parse().map_err(|_| UsageError(format!("invalid --seconds {s}")))?,
+                None => 5,
+            };
+            let size = match option("--size") {
+                Some(s) => s
+                    .split_once('x')
+                    .and_then(|(w, h)| Some((w.parse().ok()?, h.parse().ok()?)))
+                    .ok_or_else(|| UsageError(format!("invalid --size {s}, expected WxH")))?,
+                None => (1280, 720),
+            };
+            let socket = match option("--socket") {
+                Some(s) => parse_socket(s).ok_or_else(|| UsageError(format!("invalid --socket {s}")))?,
+                None => CameraBoardSocket::CamA,
+            };
+            Ok(Command::Benchmark { device, seconds, size, socket })
+        }
+        "flash-bootloader" => {
+            let device = device.ok_or_else(|| UsageError("flash-bootloader needs a device id".into()))?;
+            if option("--yes").is_none() {
+                return Err(UsageError(
+                    "flashing must not be interrupted; pass --yes to confirm and keep the device plugged in".into(),
+                ));
+            }
+            Ok(Command::FlashBootloader { device })
+        }
+        "list" => Err(UsageError("list takes no arguments".into())),
+        other => Err(UsageError(format!("unknown command {other}"))),
+    }
+}
+
+/// `CamA`, `cam_a` or `CAM_A`.
+fn parse_socket(name: &str) -> Option<CameraBoardSocket> {
+    let wanted = name.replace('_', "").to_ascii_lowercase();
+    (0..10).map(CameraBoardSocket::from_raw).find(|s| s.to_string().to_ascii_lowercase() == wanted)
+}
+
+fn open(device: Option<&str>) -> Result<Device> {
+    match device {
+        Some(id) => Device::builder().device_id(id).build(),
+        None => Device::builder().build(),
+    }
+}
+
+fn list() -> Result<()> {
+    let devices = Device::list_available()?;
+    if devices.is_empty() {
+        println!("no devices found");
+    }
+    for d in devices {
+        println!("{:<24} {:<20} {:<20} {} {}", d.device_id, d.name, d.state, d.protocol, d.platform);
+    }
+    Ok(())
+}
+
+fn info(device: Option<&str>) -> Result<()> {
+    let device = open(device)?;
+    println!("device id:          {}", device.device_id()?);
+    println!("platform:           {:?}", device.platform()?);
+    println!("usb speed:          {:?}", device.usb_speed()?);
+    println!("depthai-core:       {}", version::depthai_core_version_string());
+    println!("firmware (bundled): {}", version::device_fw_version());
+    let bootloader = device.bootloader_version()?.unwrap_or_else(|| "unknown".into());
+    println!("bootloader:         {bootloader} (bundled {})", version::bootloader_version());
+    match device.chip_temperature() {
+        Ok(t) => println!(
+            "chip temperature:   {:.1} C (css {:.1}, mss {:.1}, upa {:.1}, dss {:.1})",
+            t.average, t.css, t.mss, t.upa, t.dss
+        ),
+        Err(e) => println!("chip temperature:   unavailable ({e})"),
+    }
+    let cameras = device.connected_cameras()?;
+    println!("cameras:            {}", cameras.iter().map(|c| c.to_string()).collect::<Vec<_>>().join(", "));
+    match device.calibration_data()? {
+        Some(calibration) => {
+            println!("calibration:");
+            for socket in calibration.camera_sockets() {
+                match calibration.camera_intrinsics(socket, None, false) {
+                    Ok(k) => println!("  {socket}: fx {:.1} fy {:.1} cx {:.1} cy {:.1}", k.fx, k.fy, k.cx, k.cy),
+                    Err(e) => println!("  {socket}: {e}"),
+                }
+            }
+        }
+        None => println!("calibration:        none"),
+    }
+    Ok(())
+}
+
+fn benchmark(device: Option<&str>, seconds: u64, size: (u32, u32), socket: CameraBoardSocket) -> Result<()> {
+    let device = open(device)?;
+    let pipeline = Pipeline::new().with_device(&device).build()?;
+    let camera = pipeline.create_with::<CameraNode, _>(socket)?;
+    let queue = camera.request_output(CameraOutputConfig::new(size))?.create_queue(4, false)?;
+    pipeline.start()?;
+
+    let duration = Duration::from_secs(seconds.max(1));
+    let started = Instant::now();
+    let mut first = None;
+    let mut frames = 0u64;
+    while started.elapsed() < duration {
+        if queue.blocking_next(Some(Duration::from_millis(500)))?.is_some() {
+            first.get_or_insert_with(|| started.elapsed());
+            frames += 1;
+        }
+    }
+    let elapsed = started.elapsed().as_secs_f64();
+    println!("{socket} {}x{}: {frames} frames in {elapsed:.1} s, {:.1} fps", size.0, size.1, frames as f64 / elapsed);
+    match first {
+        Some(t) => println!("first frame after {} ms", t.as_millis()),
+        None => println!("no frames received"),
+    }
+    Ok(())
+}
+
+fn run(command: Command) -> Result<()> {
+    match command {
+        Command::List => list(),
+        Command::Info { device } => info(device.as_deref()),
+        Command::Benchmark { device, seconds, size, socket } => benchmark(device.as_deref(), seconds, size, socket),
+        Command::FlashBootloader { device } => {
+            println!("flashing bootloader {} to {device}, do not unplug...", version::bootloader_version());
+            Device::flash_bootloader(&device)?;
+            println!("done");
+            Ok(())
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.is_empty() || args.iter().any(|a| a == "-h" || a == "--help") {
+        println!("{USAGE}");
+        return if args.is_empty() { ExitCode::from(2) } else { ExitCode::SUCCESS };
+    }
+    let command = match parse(&args) {
+        Ok(command) => command,
+        Err(UsageError(message)) => {
+            eprintln!("error: {message}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    match run(command) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
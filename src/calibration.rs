@@ -0,0 +1,234 @@
+use std::ffi::CString;
+
+use autocxx::c_int;
+use depthai_sys::depthai;
+
+use crate::common::CameraBoardSocket;
+use crate::device::Device;
+use crate::error::{clear_error_flag, last_error, DepthaiError, Result};
+use crate::pipeline::{parse_json_value, take_owned_json_string, Pipeline};
+
+/// Pinhole camera intrinsics in pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CameraIntrinsics {
+    pub fx: f32,
+    pub fy: f32,
+    pub cx: f32,
+    pub cy: f32,
+}
+
+impl CameraIntrinsics {
+    pub fn new(fx: f32, fy: f32, cx: f32, cy: f32) -> Self {
+        Self { fx, fy, cx, cy }
+    }
+
+    /// From a row-major 3x3 intrinsic matrix `[[fx, 0, cx], [0, fy, cy], [0, 0, 1]]`.
+    pub fn from_matrix(m: [[f32; 3]; 3]) -> Self {
+        Self::new(m[0][0], m[1][1], m[0][2], m[1][2])
+    }
+
+    pub fn to_matrix(&self) -> [[f32; 3]; 3] {
+        [[self.fx, 0.0, self.cx], [0.0, self.fy, self.cy], [0.0, 0.0, 1.0]]
+    }
+
+    /// 3D point (same unit as `depth`) seen at pixel `(x, y)` with depth (Z) `depth`.
+    pub fn deproject(&self, x: f32, y: f32, depth: f32) -> [f32; 3] {
+        [(x - self.cx) * depth / self.fx, (y - self.cy) * depth / self.fy, depth]
+    }
+
+    /// Pixel where `point` projects, `None` for points at or behind the camera.
+    pub fn project(&self, point: [f32; 3]) -> Option<(f32, f32)> {
+        let [x, y, z] = point;
+        (z > 0.0).then(|| (x * self.fx / z + self.cx, y * self.fy / z + self.cy))
+    }
+
+    /// Intrinsics of the same camera after resizing its image by `(sx, sy)`.
+    pub fn scaled(&self, sx: f32, sy: f32) -> Self {
+        Self::new(self.fx * sx, self.fy * sy, self.cx * sx, self.cy * sy)
+    }
+}
+
+/// Device calibration (EEPROM data).
+///
+/// Mirrors C++: `dai::CalibrationHandler`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalibrationHandler {
+    eeprom: serde_json::Value,
+}
+
+impl CalibrationHandler {
+    /// Wrap EEPROM JSON as produced by `CalibrationHandler::eepromToJson()`.
+    pub fn from_json(eeprom: serde_json::Value) -> Self {
+        Self { eeprom }
+    }
+
+    pub fn eeprom_json(&self) -> &serde_json::Value {
+        &self.eeprom
+    }
+
+    /// Intrinsics of the camera on `socket` for an output of `size` (`None` keeps the calibrated
+    /// resolution). With `keep_aspect_ratio`, a size of a different aspect ratio is treated as a
+    /// centered crop, like the ISP does.
+    ///
+    /// Mirrors C++: `CalibrationHandler::getCameraIntrinsics(socket, width, height, {}, {}, keepAspectRatio)`.
+    pub fn camera_intrinsics(
+        &self,
+        socket: CameraBoardSocket,
+        size: Option<(u32, u32)>,
+        keep_aspect_ratio: bool,
+    ) -> Result<CameraIntrinsics> {
+        let json = CString::new(self.eeprom.to_string()).map_err(|_| DepthaiError::new("invalid calibration JSON"))?;
+        let (w, h) = size.map_or((-1, -1), |(w, h)| (w as i32, h as i32));
+        let mut m = [0f32; 9];
+        clear_error_flag();
+        let ok = unsafe {
+            depthai::dai_calibration_get_camera_intrinsics(
+                json.as_ptr(),
+                c_int(socket.as_raw()),
+                c_int(w),
+                c_int(h),
+                keep_aspect_ratio,
+                m.as_mut_ptr(),
+            )
+        };
+        if !ok {
+            return Err(last_error("failed to get camera intrinsics"));
+        }
+        Ok(CameraIntrinsics::from_matrix([[m[0], m[1], m[2]], [m[3], m[4], m[5]], [m[6], m[7], m[8]]]))
+    }
+
+    /// Sockets with an entry in `cameraData`.
+    pub fn camera_sockets(&self) -> Vec<CameraBoardSocket> {
+        self.camera_entries()
+            .filter_map(|(socket, _)| socket)
+            .collect()
+    }
+
+    /// Check that the data is complete enough to be flashed: at least one camera, and every
+    /// camera with a positive resolution and a pinhole intrinsic matrix with positive focal
+    /// lengths. Only the fields a device needs to run are checked, not whether the values are
+    /// accurate.
+    pub fn validate(&self) -> Result<()> {
+        if !self.eeprom.get("cameraData").is_some_and(|c| c.is_array()) {
+            return Err(DepthaiError::new("calibration has no cameraData"));
+        }
+        let mut cameras = 0;
+        for (socket, info) in self.camera_entries() {
+            let socket = socket.ok_or_else(|| DepthaiError::new("calibration cameraData entry has no socket"))?;
+            validate_camera(info).map_err(|e| DepthaiError::new(format!("calibration of {socket:?}: {e}")))?;
+            cameras += 1;
+        }
+        if cameras == 0 {
+            return Err(DepthaiError::new("calibration has no cameras"));
+        }
+        Ok(())
+    }
+
+    /// `cameraData` is serialized as `[[socket, {...}], ...]`.
+    fn camera_entries(&self) -> impl Iterator<Item = (Option<CameraBoardSocket>, &serde_json::Value)> {
+        self.eeprom
+            .get("cameraData")
+            .and_then(|c| c.as_array())
+            .into_iter()
+            .flatten()
+            .map(|entry| {
+                let socket = entry
+                    .get(0)
+                    .and_then(|s| s.as_i64())
+                    .map(|raw| CameraBoardSocket::from_raw(raw as i32));
+                (socket, entry.get(1).unwrap_or(&serde_json::Value::Null))
+            })
+    }
+}
+
+fn validate_camera(info: &serde_json::Value) -> std::result::Result<(), String> {
+    let dimension = |key: &str| info.get(key).and_then(|v| v.as_i64()).filter(|&v| v > 0);
+    if dimension("width").is_none() || dimension("height").is_none() {
+        return Err("missing or non-positive width/height".to_string());
+    }
+    let matrix: Option<Vec<Vec<f64>>> = info
+        .get("intrinsicMatrix")
+        .and_then(|m| m.as_array())
+        .map(|rows| {
+            rows.iter()
+                .filter_map(|row| row.as_array())
+                .map(|row| row.iter().filter_map(|v| v.as_f64()).collect())
+                .collect()
+        });
+    let matrix = match matrix {
+        Some(m) if m.len() == 3 && m.iter().all(|row| row.len() == 3) => m,
+        _ => return Err("intrinsicMatrix is not a 3x3 matrix".to_string()),
+    };
+    if !(matrix[0][0] > 0.0 && matrix[1][1] > 0.0) {
+        return Err(format!("non-positive focal length ({}, {})", matrix[0][0], matrix[1][1]));
+    }
+    Ok(())
+}
+
+impl Device {
+    /// Read the calibration stored on the device.
+    ///
+    /// Mirrors C++: `Device::readCalibration()`.
+    pub fn read_calibration(&self) -> Result<CalibrationHandler> {
+        clear_error_flag();
+        let ptr = unsafe { depthai::dai_device_read_calibration_json(self.handle()) };
+        let s = take_owned_json_string(ptr, "failed to read device calibration")?;
+        Ok(CalibrationHandler::from_json(parse_json_value(&s)?))
+    }
+
+    /// Read the factory calibration, which [`Device::flash_calibration`] never overwrites.
+    ///
+    /// Mirrors C++: `Device::readFactoryCalibration()`.
+    pub fn read_factory_calibration(&self) -> Result<CalibrationHandler> {
+        clear_error_flag();
+        let ptr = unsafe { depthai::dai_device_read_factory_calibration_json(self.handle()) };
+        let s = take_owned_json_string(ptr, "failed to read device factory calibration")?;
+        Ok(CalibrationHandler::from_json(parse_json_value(&s)?))
+    }
+
+    /// Check that `calibration` could be flashed to this device without writing it:
+    /// [`CalibrationHandler::validate`] passes, the C++ side accepts the data, and every camera
+    /// connected to the device is calibrated.
+    pub fn flash_calibration_dry_run(&self, calibration: &CalibrationHandler) -> Result<()> {
+        calibration.validate()?;
+        for socket in calibration.camera_sockets() {
+            calibration.camera_intrinsics(socket, None, false)?;
+        }
+        let calibrated = calibration.camera_sockets();
+        let missing: Vec<_> = self
+            .connected_cameras()?
+            .into_iter()
+            .filter(|socket| !calibrated.contains(socket))
+            .collect();
+        if !missing.is_empty() {
+            return Err(DepthaiError::new(format!(
+                "calibration has no data for connected cameras {missing:?}"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Write `calibration` to the user calibration area of the device EEPROM, after the checks of
+    /// [`Device::flash_calibration_dry_run`]. The factory calibration is kept, see
+    /// [`Device::read_factory_calibration`].
+    ///
+    /// Mirrors C++: `Device::flashCalibration(calibrationDataHandler)`.
+    pub fn flash_calibration(&self, calibration: &CalibrationHandler) -> Result<()> {
+        self.flash_calibration_dry_run(calibration)?;
+        let json = CString::new(calibration.eeprom_json().to_string())
+            .map_err(|_| DepthaiError::new("invalid calibration JSON"))?;
+        clear_error_flag();
+        let ok = unsafe { depthai::dai_device_flash_calibration_json(self.handle(), json.as_ptr()) };
+        if !ok {
+            return Err(last_error("failed to flash calibration"));
+        }
+        Ok(())
+    }
+}
+
+impl Pipeline {
+    /// Calibration set on the pipeline, if any (see [`Pipeline::calibration_data_json`]).
+    pub fn calibration_data(&self) -> Result<Option<CalibrationHandler>> {
+        Ok(self.calibration_data_json()?.map(CalibrationHandler::from_json))
+    }
+}
@@ -5,15 +5,18 @@ use autocxx::c_int;
 use depthai_sys::{depthai, DaiCameraNode, DaiDataQueue, DaiImgFrame, DaiNode};
 
 pub use crate::common::{CameraBoardSocket, CameraSensorType, ImageFrameType, ResizeMode};
-use crate::error::{Result, clear_error_flag, last_error, take_error_if_any};
+use crate::calibration::CameraIntrinsics;
+use crate::camera_control::{CameraControl, FrameSyncMode};
+use crate::error::{DepthaiError, Result, clear_error_flag, last_error, take_error_if_any};
+use crate::host_node::{duration_to_ns, ns_to_duration};
 use crate::pipeline::device_node::CreateInPipelineWith;
-use crate::pipeline::{Pipeline, PipelineInner};
+use crate::pipeline::{OutputHint, Pipeline, PipelineInner};
 use crate::output::Output as NodeOutput;
 
 #[crate::native_node_wrapper(
     native = "dai::node::Camera",
-    inputs(inputControl, mockIsp),
-    outputs(raw)
+    inputs(inputControl: CameraControl, mockIsp: ImageFrame),
+    outputs(raw: ImageFrame)
 )]
 pub struct CameraNode {
     node: crate::pipeline::Node,
@@ -116,7 +119,24 @@ impl CameraNode {
         if handle.is_null() {
             Err(last_error("failed to request camera output"))
         } else {
-            Ok(NodeOutput::from_handle(std::sync::Arc::clone(&self.node.pipeline), handle))
+            let output = NodeOutput::from_handle(std::sync::Arc::clone(&self.node.pipeline), handle);
+            self.record_output_hint(&output, config.size, config.frame_type);
+            Ok(output)
+        }
+    }
+
+    /// Remember the requested size/format of `output` for [`Pipeline::validate`].
+    ///
+    /// Best-effort: failures are ignored since hints only feed validation diagnostics.
+    fn record_output_hint(&self, output: &NodeOutput, size: (u32, u32), frame_type: Option<ImageFrameType>) {
+        if let (Ok(node_id), Ok(group), Ok(name)) = (self.node.id(), output.group(), output.name()) {
+            self.node.pipeline.record_output_hint(OutputHint {
+                node_id,
+                group,
+                name,
+                size,
+                frame_type,
+            });
         }
     }
 
@@ -220,6 +240,19 @@ impl CameraNode {
         Ok(CameraSensorType::from_raw(raw.into()))
     }
 
+    /// FSYNC role of this camera's sensor from the start, see
+    /// [`FrameSyncConfig`](crate::pipeline::FrameSyncConfig).
+    ///
+    /// Mirrors C++: `camera->initialControl.setFrameSyncMode(mode)`.
+    pub fn set_frame_sync_mode(&self, mode: FrameSyncMode) -> Result<()> {
+        clear_error_flag();
+        unsafe { depthai::dai_camera_set_frame_sync_mode(self.node.handle() as DaiCameraNode, c_int(mode as i32)) };
+        if let Some(err) = take_error_if_any("failed to set camera frame sync mode") {
+            return Err(err);
+        }
+        Ok(())
+    }
+
     pub fn set_raw_num_frames_pool(&self, num: i32) -> Result<()> {
         clear_error_flag();
         unsafe { depthai::dai_camera_set_raw_num_frames_pool(self.node.handle() as DaiCameraNode, c_int(num)) };
@@ -437,6 +470,202 @@ impl ImageFrame {
         self.handle
     }
 
+    /// Create a host-side frame holding a copy of `data`, timestamped now.
+    ///
+    /// For frame types with a known packed layout (e.g. `GRAY8`, `NV12`, `BGR888i`) `data` must
+    /// have exactly the expected length. Such frames can be sent into device inputs (e.g. a
+    /// camera's `mockIsp`) via [`Output::send_frame`](crate::output::Output::send_frame) or
+    /// [`InputQueue::send_frame`](crate::queue::InputQueue::send_frame).
+    ///
+    /// Mirrors C++: `std::make_shared<ImgFrame>()` + `setWidth`/`setHeight`/`setType`/`setData`.
+    pub fn new(width: u32, height: u32, frame_type: ImageFrameType, data: &[u8]) -> Result<Self> {
+        if let Some(expected) = packed_frame_size(width, height, frame_type).filter(|&n| n != data.len()) {
+            return Err(DepthaiError::new(format!(
+                "{frame_type:?} frame of {width}x{height} needs {expected} bytes, got {}",
+                data.len()
+            )));
+        }
+        clear_error_flag();
+        let handle = depthai::dai_frame_new(c_int(width as i32), c_int(height as i32), c_int(frame_type as i32));
+        if handle.is_null() {
+            return Err(last_error("failed to create frame"));
+        }
+        let mut frame = Self { handle };
+        frame.set_data(data)?;
+        Ok(frame)
+    }
+
+    /// Create a planar host-side frame from separate planes.
+    ///
+    /// Supported layouts: `NV12`/`NV21` (`[y, uv]`) and `YUV420p` (`[y, u, v]`). Each plane must be
+    /// tightly packed (stride == plane width).
+    pub fn from_planes(width: u32, height: u32, frame_type: ImageFrameType, planes: &[&[u8]]) -> Result<Self> {
+        let (w, h) = (width as usize, height as usize);
+        let expected: &[usize] = match frame_type {
+            ImageFrameType::NV12 | ImageFrameType::NV21 => &[w * h, w * h / 2],
+            ImageFrameType::YUV420p => &[w * h, w * h / 4, w * h / 4],
+            other => {
+                return Err(DepthaiError::new(format!("from_planes does not support {other:?} frames")));
+            }
+        };
+        if (width | height) & 1 != 0 {
+            return Err(DepthaiError::new(format!(
+                "{frame_type:?} frames must have even width and height, got {width}x{height}"
+            )));
+        }
+        if planes.len() != expected.len() {
+            return Err(DepthaiError::new(format!(
+                "{frame_type:?} frames need {} planes, got {}",
+                expected.len(),
+                planes.len()
+            )));
+        }
+        for (i, (plane, len)) in planes.iter().zip(expected).enumerate() {
+            if plane.len() != *len {
+                return Err(DepthaiError::new(format!(
+                    "{frame_type:?} plane {i} needs {len} bytes, got {}",
+                    plane.len()
+                )));
+            }
+        }
+        Self::new(width, height, frame_type, &planes.concat())
+    }
+
+    /// Replace the frame payload with a copy of `data`.
+    ///
+    /// The old payload is freed, so views into it must be gone first:
+    ///
+    /// ```compile_fail
+    /// # use depthai::camera::{ImageFrame, ImageFrameType};
+    /// # fn f() -> depthai::Result<()> {
+    /// let mut frame = ImageFrame::new(2, 1, ImageFrameType::RAW16, &[0; 4])?;
+    /// let pixels = frame.as_u16_slice()?;
+    /// frame.set_data(&[1; 4])?; // `pixels` still borrows the payload
+    /// println!("{pixels:?}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_data(&mut self, data: &[u8]) -> Result<()> {
+        clear_error_flag();
+        unsafe { depthai::dai_frame_set_data(self.handle, data.as_ptr() as *const _, data.len()) };
+        if let Some(err) = take_error_if_any("failed to set frame data") {
+            Err(err)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Copy `data` into the payload, in place when the size is unchanged.
+    pub(crate) fn write_data(&mut self, data: &[u8]) -> Result<()> {
+        clear_error_flag();
+        unsafe { depthai::dai_frame_write_data(self.handle, data.as_ptr() as *const _, data.len()) };
+        if let Some(err) = take_error_if_any("failed to write frame data") {
+            Err(err)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Owners of the underlying message: 1 when nothing but this handle refers to it.
+    pub(crate) fn use_count(&self) -> usize {
+        unsafe { depthai::dai_frame_use_count(self.handle) }.max(0) as usize
+    }
+
+    /// Timestamp on the host steady clock.
+    pub fn timestamp(&self) -> Result<Duration> {
+        clear_error_flag();
+        let ns = unsafe { depthai::dai_frame_get_timestamp_ns(self.handle) };
+        if let Some(err) = take_error_if_any("failed to get frame timestamp") {
+            Err(err)
+        } else {
+            Ok(ns_to_duration(ns))
+        }
+    }
+
+    /// Timestamp on the device clock, as captured (not synced to the host).
+    ///
+    /// Mirrors C++: `ImgFrame::getTimestampDevice()`.
+    pub fn timestamp_device(&self) -> Result<Duration> {
+        clear_error_flag();
+        let ns = unsafe { depthai::dai_frame_get_timestamp_device_ns(self.handle) };
+        if let Some(err) = take_error_if_any("failed to get frame device timestamp") {
+            Err(err)
+        } else {
+            Ok(ns_to_duration(ns))
+        }
+    }
+
+    pub fn set_timestamp(&self, timestamp: Duration) -> Result<()> {
+        clear_error_flag();
+        unsafe { depthai::dai_frame_set_timestamp_ns(self.handle, duration_to_ns(timestamp)) };
+        if let Some(err) = take_error_if_any("failed to set frame timestamp") {
+            Err(err)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Set the timestamp to the current host steady-clock time.
+    pub fn set_timestamp_now(&self) -> Result<()> {
+        clear_error_flag();
+        unsafe { depthai::dai_frame_set_timestamp_now(self.handle) };
+        if let Some(err) = take_error_if_any("failed to set frame timestamp") {
+            Err(err)
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn sequence_num(&self) -> Result<i64> {
+        clear_error_flag();
+        let seq = unsafe { depthai::dai_frame_get_sequence_num(self.handle) };
+        if let Some(err) = take_error_if_any("failed to get frame sequence number") {
+            Err(err)
+        } else {
+            Ok(seq)
+        }
+    }
+
+    pub fn set_sequence_num(&self, seq: i64) -> Result<()> {
+        clear_error_flag();
+        unsafe { depthai::dai_frame_set_sequence_num(self.handle, seq) };
+        if let Some(err) = take_error_if_any("failed to set frame sequence number") {
+            Err(err)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Camera the frame was captured by; frames aligned to another camera report that camera.
+    ///
+    /// Mirrors C++: `ImgFrame::getInstanceNum()`.
+    pub fn board_socket(&self) -> Result<CameraBoardSocket> {
+        clear_error_flag();
+        let raw: i32 = unsafe { depthai::dai_frame_get_instance_num(self.handle) }.into();
+        if let Some(err) = take_error_if_any("failed to get frame instance number") {
+            return Err(err);
+        }
+        Ok(CameraBoardSocket::from_raw(raw))
+    }
+
+    /// Intrinsics of this frame, with the crops and resizes applied since capture.
+    ///
+    /// Mirrors C++: `ImgFrame::transformation.getIntrinsicMatrix()`. Fails on frames without
+    /// calibration, e.g. ones created on the host.
+    pub fn intrinsics(&self) -> Result<CameraIntrinsics> {
+        let mut m = [0f32; 9];
+        clear_error_flag();
+        let ok = unsafe { depthai::dai_frame_get_intrinsic_matrix(self.handle, m.as_mut_ptr()) };
+        if !ok {
+            return Err(last_error("failed to get frame intrinsics"));
+        }
+        let intrinsics = CameraIntrinsics::from_matrix([[m[0], m[1], m[2]], [m[3], m[4], m[5]], [m[6], m[7], m[8]]]);
+        if !(intrinsics.fx > 0.0 && intrinsics.fy > 0.0) {
+            return Err(DepthaiError::new("frame carries no intrinsics"));
+        }
+        Ok(intrinsics)
+    }
+
     pub fn width(&self) -> u32 {
         let raw: ::std::os::raw::c_int = unsafe { depthai::dai_frame_get_width(self.handle) }.into();
         raw as u32
@@ -447,6 +676,14 @@ impl ImageFrame {
         raw as u32
     }
 
+    /// Row stride of the first plane in bytes.
+    ///
+    /// Mirrors C++: `ImgFrame::getStride()`.
+    pub fn stride(&self) -> usize {
+        let raw: ::std::os::raw::c_int = unsafe { depthai::dai_frame_get_stride(self.handle) }.into();
+        raw.max(0) as usize
+    }
+
     pub fn format(&self) -> Option<ImageFrameType> {
         let raw: ::std::os::raw::c_int = unsafe { depthai::dai_frame_get_type(self.handle) }.into();
         ImageFrameType::from_raw(raw)
@@ -469,6 +706,72 @@ impl ImageFrame {
         unsafe { std::slice::from_raw_parts(data_ptr as *const u8, len).to_vec() }
     }
 
+    /// Pixels of a `RAW16` frame (e.g. `StereoDepth` depth, disparity with subpixel) in place,
+    /// without copying.
+    ///
+    /// Rows start [`stride`](Self::stride)` / 2` values apart, so the slice includes any row
+    /// padding; [`DepthFrameView`](crate::depth::DepthFrameView) handles that for depth.
+    /// Frame data is little-endian, so this fails on big-endian hosts, as well as for other
+    /// formats and misaligned buffers; [`to_u16_vec`](Self::to_u16_vec) works everywhere.
+    ///
+    /// The slice borrows the frame, so its payload can't be replaced while the slice is in use:
+    ///
+    /// ```compile_fail
+    /// # use depthai::camera::{ImageFrame, ImageFrameType};
+    /// # fn f(mut frame: ImageFrame) -> depthai::Result<()> {
+    /// let depth = frame.as_u16_slice()?;
+    /// frame.set_data(&[0; 8])?; // would free the memory `depth` points into
+    /// let nearest = depth.iter().min();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn as_u16_slice(&self) -> Result<&[u16]> {
+        self.check_u16_format()?;
+        if cfg!(target_endian = "big") {
+            return Err(DepthaiError::new("RAW16 frames can't be borrowed as u16 on a big-endian host"));
+        }
+        let len = self.byte_len();
+        let data_ptr = unsafe { depthai::dai_frame_get_data(self.handle) } as *const u8;
+        if len == 0 || data_ptr.is_null() {
+            return Ok(&[]);
+        }
+        // SAFETY: the buffer is owned by the frame and lives as long as `self`.
+        let bytes = unsafe { std::slice::from_raw_parts(data_ptr, len) };
+        // SAFETY: every bit pattern is a valid u16.
+        let (prefix, values, _) = unsafe { bytes.align_to::<u16>() };
+        if !prefix.is_empty() || self.stride() % 2 != 0 {
+            return Err(DepthaiError::new("RAW16 frame buffer is not 16-bit aligned"));
+        }
+        Ok(values)
+    }
+
+    /// Copy of the pixels of a `RAW16` frame as packed rows (`width * height` values), with row
+    /// padding removed.
+    pub fn to_u16_vec(&self) -> Result<Vec<u16>> {
+        self.check_u16_format()?;
+        let row_bytes = self.width() as usize * 2;
+        let stride = match self.stride() {
+            0 => row_bytes,
+            stride => stride,
+        };
+        let data = self.bytes();
+        Ok(if stride == row_bytes {
+            crate::convert::raw16_to_u16(&data)
+        } else {
+            (0..self.height() as usize)
+                .filter_map(|row| data.get(row * stride..row * stride + row_bytes))
+                .flat_map(crate::convert::raw16_to_u16)
+                .collect()
+        })
+    }
+
+    fn check_u16_format(&self) -> Result<()> {
+        match self.format() {
+            Some(ImageFrameType::RAW16) => Ok(()),
+            other => Err(DepthaiError::new(format!("expected a RAW16 frame, got {other:?}"))),
+        }
+    }
+
     pub fn describe(&self) -> String {
         let fmt = self
             .format()
@@ -478,6 +781,20 @@ impl ImageFrame {
     }
 }
 
+/// Byte size of a tightly packed frame, for types whose layout is unambiguous.
+pub(crate) fn packed_frame_size(width: u32, height: u32, frame_type: ImageFrameType) -> Option<usize> {
+    use ImageFrameType::*;
+    let pixels = width as usize * height as usize;
+    match frame_type {
+        GRAY8 | RAW8 | YUV400p => Some(pixels),
+        RAW16 | GRAYF16 | YUV422i => Some(pixels * 2),
+        RGB888p | BGR888p | RGB888i | BGR888i | YUV444p | YUV444i => Some(pixels * 3),
+        RGBA8888 | RAW32 => Some(pixels * 4),
+        NV12 | NV21 | YUV420p => Some(pixels * 3 / 2),
+        _ => None,
+    }
+}
+
 // Implement DeviceNodeWithParams for CameraNode to enable pipeline.create_with::<CameraNode, _>(socket)
 impl CreateInPipelineWith<CameraBoardSocket> for CameraNode {
     fn create_with(pipeline: &Pipeline, socket: CameraBoardSocket) -> Result<Self> {
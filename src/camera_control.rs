@@ -0,0 +1,395 @@
+//! Runtime camera control: the [`CameraControl`] message and the [`CameraController`] helper.
+//!
+//! Controls are sent to a running camera through its `inputControl` input. [`CameraController`]
+//! owns that queue and turns common adjustments into one-liners, merging calls made in quick
+//! succession (e.g. while dragging an exposure region in a UI) into a single message.
+//!
+//! ```no_run
+//! # use depthai::{CameraController, Pipeline, Result};
+//! # use depthai::camera::CameraNode;
+//! # use depthai::common::{CameraBoardSocket, Rect};
+//! # fn main() -> Result<()> {
+//! let pipeline = Pipeline::new().build()?;
+//! let camera = pipeline.create_with::<CameraNode, _>(CameraBoardSocket::CamA)?;
+//! // Input queues must be created before the pipeline starts.
+//! let controller = CameraController::new(&camera)?;
+//! pipeline.start()?;
+//!
+//! controller.set_exposure_region(Rect::new(0.25, 0.25, 0.5, 0.5))?;
+//! controller.trigger_autofocus();
+//! controller.lock_awb(true);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use autocxx::c_int;
+use depthai_sys::{depthai, DaiBuffer};
+
+use crate::camera::CameraNode;
+use crate::common::Rect;
+use crate::error::{clear_error_flag, last_error, take_error_if_any, DepthaiError, Result};
+use crate::host_node::Buffer;
+use crate::queue::InputQueue;
+
+/// Autofocus algorithm.
+///
+/// Mirrors C++: `dai::CameraControl::AutoFocusMode`.
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AutoFocusMode {
+    /// Manual focus only.
+    Off = 0,
+    /// Single scan, started by [`CameraControl::set_auto_focus_trigger`].
+    Auto = 1,
+    Macro = 2,
+    ContinuousVideo = 3,
+    ContinuousPicture = 4,
+    Edof = 5,
+}
+
+/// Role of a camera on a hardware frame sync (FSYNC) line.
+///
+/// Mirrors C++: `dai::CameraControl::FrameSyncMode`.
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FrameSyncMode {
+    #[default]
+    Off = 0,
+    /// The sensor drives the FSYNC line at the start of each frame.
+    Output = 1,
+    /// The sensor starts each frame on a pulse of the FSYNC line.
+    Input = 2,
+}
+
+/// Runtime control message for a `Camera` node.
+///
+/// Mirrors C++: `dai::CameraControl`.
+///
+/// Note: this is also a `Buffer` message; send it to the camera's `inputControl`.
+pub struct CameraControl {
+    buffer: Buffer,
+}
+
+impl CameraControl {
+    pub fn new() -> Result<Self> {
+        clear_error_flag();
+        let handle = depthai::dai_camera_control_new();
+        if handle.is_null() {
+            Err(last_error("failed to create CameraControl"))
+        } else {
+            Ok(Self {
+                buffer: Buffer::from_handle(handle),
+            })
+        }
+    }
+
+    pub fn as_buffer(&self) -> &Buffer {
+        &self.buffer
+    }
+
+    pub fn into_buffer(self) -> Buffer {
+        self.buffer
+    }
+
+    pub(crate) fn handle(&self) -> DaiBuffer {
+        self.buffer.handle()
+    }
+
+    /// Meter auto exposure on a region, in sensor pixels.
+    ///
+    /// Mirrors C++: `CameraControl::setAutoExposureRegion(startX, startY, width, height)`.
+    pub fn set_auto_exposure_region(&mut self, x: u16, y: u16, width: u16, height: u16) -> &mut Self {
+        clear_error_flag();
+        unsafe { depthai::dai_camera_control_set_auto_exposure_region(self.handle(), x, y, width, height) };
+        self
+    }
+
+    /// Longest exposure auto exposure may pick.
+    ///
+    /// Mirrors C++: `CameraControl::setAutoExposureLimit(maxExposureTimeUs)`.
+    pub fn set_auto_exposure_limit(&mut self, max_exposure: Duration) -> &mut Self {
+        clear_error_flag();
+        let us = max_exposure.as_micros().min(u32::MAX as u128) as u32;
+        unsafe { depthai::dai_camera_control_set_auto_exposure_limit(self.handle(), us) };
+        self
+    }
+
+    /// Mirrors C++: `CameraControl::setAutoExposureLock(lock)`.
+    pub fn set_auto_exposure_lock(&mut self, lock: bool) -> &mut Self {
+        clear_error_flag();
+        unsafe { depthai::dai_camera_control_set_auto_exposure_lock(self.handle(), lock) };
+        self
+    }
+
+    /// Mirrors C++: `CameraControl::setAutoFocusMode(mode)`.
+    pub fn set_auto_focus_mode(&mut self, mode: AutoFocusMode) -> &mut Self {
+        clear_error_flag();
+        unsafe { depthai::dai_camera_control_set_auto_focus_mode(self.handle(), c_int(mode as i32)) };
+        self
+    }
+
+    /// Start an autofocus scan; only has an effect in [`AutoFocusMode::Auto`] or `Macro`.
+    ///
+    /// Mirrors C++: `CameraControl::setAutoFocusTrigger()`.
+    pub fn set_auto_focus_trigger(&mut self) -> &mut Self {
+        clear_error_flag();
+        unsafe { depthai::dai_camera_control_set_auto_focus_trigger(self.handle()) };
+        self
+    }
+
+    /// Mirrors C++: `CameraControl::setAutoWhiteBalanceLock(lock)`.
+    pub fn set_auto_white_balance_lock(&mut self, lock: bool) -> &mut Self {
+        clear_error_flag();
+        unsafe { depthai::dai_camera_control_set_auto_white_balance_lock(self.handle(), lock) };
+        self
+    }
+
+    /// Stop (`false`) or restart (`true`) the sensor, e.g. to save power and bandwidth while idle.
+    ///
+    /// Mirrors C++: `CameraControl::setStopStreaming()` / `setStartStreaming()`.
+    pub fn set_streaming(&mut self, streaming: bool) -> &mut Self {
+        clear_error_flag();
+        unsafe { depthai::dai_camera_control_set_streaming(self.handle(), streaming) };
+        self
+    }
+
+    /// Usually set once with
+    /// [`CameraNode::set_frame_sync_mode`](crate::camera::CameraNode::set_frame_sync_mode) instead,
+    /// since sensors only resynchronize when they start streaming.
+    ///
+    /// Mirrors C++: `CameraControl::setFrameSyncMode(mode)`.
+    pub fn set_frame_sync_mode(&mut self, mode: FrameSyncMode) -> &mut Self {
+        clear_error_flag();
+        unsafe { depthai::dai_camera_control_set_frame_sync_mode(self.handle(), c_int(mode as i32)) };
+        self
+    }
+
+    /// Capture `burst` frames on each FSYNC pulse, dropping the first `discard` of them.
+    ///
+    /// Mirrors C++: `CameraControl::setExternalTrigger(numFramesBurst, numFramesDiscard)`.
+    pub fn set_external_trigger(&mut self, burst: i32, discard: i32) -> &mut Self {
+        clear_error_flag();
+        unsafe { depthai::dai_camera_control_set_external_trigger(self.handle(), c_int(burst), c_int(discard)) };
+        self
+    }
+
+    /// Error recorded by the last setter, if any.
+    pub fn check(&self) -> Result<()> {
+        match take_error_if_any("failed to configure CameraControl") {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Adjustments not yet sent; later calls override earlier ones.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct PendingControl {
+    exposure_region: Option<[u16; 4]>,
+    exposure_limit: Option<Duration>,
+    autofocus_trigger: bool,
+    awb_lock: Option<bool>,
+}
+
+impl PendingControl {
+    fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    fn to_control(self) -> Result<CameraControl> {
+        let mut control = CameraControl::new()?;
+        if let Some([x, y, w, h]) = self.exposure_region {
+            control.set_auto_exposure_region(x, y, w, h);
+        }
+        if let Some(limit) = self.exposure_limit {
+            control.set_auto_exposure_limit(limit);
+        }
+        if self.autofocus_trigger {
+            control.set_auto_focus_mode(AutoFocusMode::Auto).set_auto_focus_trigger();
+        }
+        if let Some(lock) = self.awb_lock {
+            control.set_auto_white_balance_lock(lock);
+        }
+        control.check()?;
+        Ok(control)
+    }
+}
+
+struct ControllerState {
+    pending: PendingControl,
+    last_sent: Option<Instant>,
+    /// First send failure of the worker, reported by [`CameraController::flush`].
+    error: Option<DepthaiError>,
+    closed: bool,
+}
+
+struct Shared {
+    queue: InputQueue,
+    min_interval: Duration,
+    state: Mutex<ControllerState>,
+    wake: Condvar,
+}
+
+impl Shared {
+    fn lock(&self) -> MutexGuard<'_, ControllerState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    // Sent with the lock held so the worker and `flush` can't reorder messages.
+    fn send_pending(&self, state: &mut ControllerState) -> Result<()> {
+        let pending = std::mem::take(&mut state.pending);
+        state.last_sent = Some(Instant::now());
+        let control = pending.to_control()?;
+        self.queue.send_buffer(control.as_buffer())
+    }
+
+    fn run(&self) {
+        let mut state = self.lock();
+        loop {
+            if state.pending.is_empty() {
+                if state.closed {
+                    return;
+                }
+                state = self.wake.wait(state).unwrap_or_else(|e| e.into_inner());
+                continue;
+            }
+            let due = state.last_sent.map(|t| t + self.min_interval);
+            if let Some(due) = due {
+                let now = Instant::now();
+                if now < due && !state.closed {
+                    state = self.wake.wait_timeout(state, due - now).unwrap_or_else(|e| e.into_inner()).0;
+                    continue;
+                }
+            }
+            if let Err(e) = self.send_pending(&mut state) {
+                state.error.get_or_insert(e);
+            }
+        }
+    }
+}
+
+/// One-call runtime adjustments of a camera, see the [module docs](self).
+///
+/// Calls only record the change; a background thread sends them, at most once per
+/// `min_interval`, merged into a single [`CameraControl`]. The first call after a quiet period
+/// goes out immediately. Pending changes are sent when the controller is dropped.
+pub struct CameraController {
+    shared: Arc<Shared>,
+    sensor_size: (u32, u32),
+    worker: Option<JoinHandle<()>>,
+}
+
+impl CameraController {
+    /// Shortest time between two messages unless configured otherwise.
+    pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_millis(50);
+
+    /// Control `camera` through a new queue on its `inputControl`.
+    ///
+    /// Must be called before the pipeline starts, after the camera is built.
+    pub fn new(camera: &CameraNode) -> Result<Self> {
+        Self::with_min_interval(camera, Self::DEFAULT_MIN_INTERVAL)
+    }
+
+    pub fn with_min_interval(camera: &CameraNode, min_interval: Duration) -> Result<Self> {
+        let queue = camera.inputControl()?.create_input_queue(4, false)?;
+        let sensor_size = (camera.max_width()?, camera.max_height()?);
+        Self::from_queue(queue, sensor_size, min_interval)
+    }
+
+    /// Control a camera through an existing queue to its `inputControl`.
+    ///
+    /// `sensor_size` is used to map normalized regions to sensor pixels.
+    pub fn from_queue(queue: InputQueue, sensor_size: (u32, u32), min_interval: Duration) -> Result<Self> {
+        let shared = Arc::new(Shared {
+            queue,
+            min_interval,
+            state: Mutex::new(ControllerState {
+                pending: PendingControl::default(),
+                last_sent: None,
+                error: None,
+                closed: false,
+            }),
+            wake: Condvar::new(),
+        });
+        let worker_shared = Arc::clone(&shared);
+        let worker = std::thread::Builder::new()
+            .name("depthai-camera-control".to_string())
+            .spawn(move || worker_shared.run())
+            .map_err(|e| DepthaiError::new(format!("failed to start camera control thread: {e}")))?;
+        Ok(Self {
+            shared,
+            sensor_size,
+            worker: Some(worker),
+        })
+    }
+
+    fn update(&self, f: impl FnOnce(&mut PendingControl)) {
+        f(&mut self.shared.lock().pending);
+        self.shared.wake.notify_one();
+    }
+
+    /// Meter auto exposure on `rect`, normalized or in sensor pixels; clipped to the sensor.
+    pub fn set_exposure_region(&self, rect: Rect) -> Result<()> {
+        let (sensor_w, sensor_h) = self.sensor_size;
+        let rect = rect.denormalize(sensor_w, sensor_h);
+        let x0 = rect.x.clamp(0.0, sensor_w as f32);
+        let y0 = rect.y.clamp(0.0, sensor_h as f32);
+        let x1 = (rect.x + rect.width).clamp(0.0, sensor_w as f32);
+        let y1 = (rect.y + rect.height).clamp(0.0, sensor_h as f32);
+        if x1 - x0 < 1.0 || y1 - y0 < 1.0 {
+            return Err(DepthaiError::new(format!("exposure region {rect:?} is outside the sensor")));
+        }
+        let region = [x0, y0, x1 - x0, y1 - y0].map(|v| v.round().min(u16::MAX as f32) as u16);
+        self.update(|p| p.exposure_region = Some(region));
+        Ok(())
+    }
+
+    /// Run one autofocus scan, switching the camera to [`AutoFocusMode::Auto`].
+    pub fn trigger_autofocus(&self) {
+        self.update(|p| p.autofocus_trigger = true);
+    }
+
+    /// Freeze (`true`) or resume (`false`) auto white balance.
+    pub fn lock_awb(&self, lock: bool) {
+        self.update(|p| p.awb_lock = Some(lock));
+    }
+
+    /// Keep auto exposure from lengthening frames below `fps` in low light.
+    ///
+    /// Limits exposure to one frame period; the sensor frame rate itself is fixed when the
+    /// camera is built.
+    pub fn set_fps_cap(&self, fps: f32) -> Result<()> {
+        if !(fps.is_finite() && fps > 0.0) {
+            return Err(DepthaiError::new(format!("invalid fps cap {fps}")));
+        }
+        let limit = Duration::from_secs_f32(1.0 / fps);
+        self.update(|p| p.exposure_limit = Some(limit));
+        Ok(())
+    }
+
+    /// Send pending changes now, and report a failure of an earlier background send.
+    pub fn flush(&self) -> Result<()> {
+        let mut state = self.shared.lock();
+        if let Some(err) = state.error.take() {
+            return Err(err);
+        }
+        if state.pending.is_empty() {
+            return Ok(());
+        }
+        self.shared.send_pending(&mut state)
+    }
+}
+
+impl Drop for CameraController {
+    fn drop(&mut self) {
+        self.shared.lock().closed = true;
+        self.shared.wake.notify_one();
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
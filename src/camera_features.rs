@@ -0,0 +1,349 @@
+//! What the cameras attached to a device can do.
+//!
+//! [`Device::connected_camera_features`] reports, per socket, the sensor model, its native
+//! resolution and orientation, and the resolution/FPS modes it supports, so camera outputs can be
+//! sized for the hardware at hand instead of assuming an OAK-D:
+//!
+//! ```no_run
+//! # use depthai::prelude::*;
+//! # fn main() -> Result<()> {
+//! let device = Device::new()?;
+//! for features in device.connected_camera_features()? {
+//!     let out = features.fit_output(CameraOutputConfig { fps: Some(60.0), ..CameraOutputConfig::new((1280, 800)) });
+//!     println!("{:?} {}: {:?} @ {:?}", features.socket, features.sensor_name, out.size, out.fps);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`Device::stereo_pairs`] lists the camera pairs depth can be computed from, so stereo setups
+//! don't have to assume the `CamB`/`CamC` layout of an OAK-D (an OAK-D-LR pairs `CamB` with
+//! `CamC` too, but also `CamA` with either of them).
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::camera::CameraOutputConfig;
+use crate::common::{CameraBoardSocket, CameraImageOrientation, CameraSensorType, ImageFrameType};
+use crate::device::Device;
+use crate::error::{clear_error_flag, DepthaiError, Result};
+use crate::pipeline::{parse_json_value, take_owned_json_string};
+
+use depthai_sys::depthai;
+
+/// One resolution/FPS mode of a sensor.
+///
+/// Mirrors C++: `dai::CameraSensorConfig`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CameraSensorConfig {
+    pub width: u32,
+    pub height: u32,
+    pub min_fps: f32,
+    pub max_fps: f32,
+    /// Raw frame type the sensor produces in this mode.
+    pub frame_type: Option<ImageFrameType>,
+}
+
+impl CameraSensorConfig {
+    pub fn size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    fn covers(&self, (width, height): (u32, u32), fps: Option<f32>) -> bool {
+        self.width >= width && self.height >= height && fps.is_none_or(|fps| fps <= self.max_fps)
+    }
+}
+
+/// Capabilities of one connected camera.
+///
+/// Mirrors C++: `dai::CameraFeatures`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CameraFeatures {
+    pub socket: CameraBoardSocket,
+    /// Sensor model, e.g. `"IMX378"` or `"OV9282"`.
+    pub sensor_name: String,
+    /// Board name of the socket, e.g. `"color"` or `"left"`.
+    pub name: String,
+    /// Native resolution.
+    pub width: u32,
+    pub height: u32,
+    pub orientation: CameraImageOrientation,
+    pub supported_types: Vec<CameraSensorType>,
+    pub has_autofocus: bool,
+    pub configs: Vec<CameraSensorConfig>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawSensorConfig {
+    width: i32,
+    height: i32,
+    #[serde(default)]
+    min_fps: f32,
+    #[serde(default)]
+    max_fps: f32,
+    #[serde(rename = "type", default)]
+    frame_type: Option<i32>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawFeatures {
+    socket: i32,
+    #[serde(default)]
+    sensor_name: String,
+    #[serde(default)]
+    name: String,
+    width: i32,
+    height: i32,
+    #[serde(default = "auto_orientation")]
+    orientation: i32,
+    #[serde(default)]
+    supported_types: Vec<i32>,
+    #[serde(default)]
+    has_autofocus: bool,
+    #[serde(default)]
+    configs: Vec<RawSensorConfig>,
+}
+
+fn auto_orientation() -> i32 {
+    CameraImageOrientation::Auto as i32
+}
+
+impl CameraFeatures {
+    /// Parse one entry of the JSON produced by C++ `nlohmann::json(device->getConnectedCameraFeatures())`.
+    pub fn from_json(value: &Value) -> Result<Self> {
+        let raw = RawFeatures::deserialize(value)
+            .map_err(|e| DepthaiError::new(format!("invalid camera features JSON: {e}")))?;
+        Ok(Self {
+            socket: CameraBoardSocket::from_raw(raw.socket),
+            sensor_name: raw.sensor_name,
+            name: raw.name,
+            width: raw.width.max(0) as u32,
+            height: raw.height.max(0) as u32,
+            orientation: CameraImageOrientation::from_raw(raw.orientation),
+            supported_types: raw.supported_types.into_iter().map(CameraSensorType::from_raw).collect(),
+            has_autofocus: raw.has_autofocus,
+            configs: raw
+                .configs
+                .into_iter()
+                .map(|c| CameraSensorConfig {
+                    width: c.width.max(0) as u32,
+                    height: c.height.max(0) as u32,
+                    min_fps: c.min_fps,
+                    max_fps: c.max_fps,
+                    frame_type: c.frame_type.and_then(ImageFrameType::from_raw),
+                })
+                .collect(),
+        })
+    }
+
+    /// Native resolution.
+    pub fn max_resolution(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// Highest frame rate of any mode.
+    pub fn max_fps(&self) -> Option<f32> {
+        self.configs.iter().map(|c| c.max_fps).reduce(f32::max)
+    }
+
+    pub fn supports_type(&self, sensor_type: CameraSensorType) -> bool {
+        self.supported_types.contains(&sensor_type)
+    }
+
+    /// Smallest mode at least `size` large that runs at `fps` (any rate if `None`).
+    pub fn find_config(&self, size: (u32, u32), fps: Option<f32>) -> Option<&CameraSensorConfig> {
+        self.configs
+            .iter()
+            .filter(|c| c.covers(size, fps))
+            .min_by_key(|c| c.width as u64 * c.height as u64)
+    }
+
+    /// Whether an output of `size` at `fps` can be produced without upscaling.
+    pub fn supports(&self, size: (u32, u32), fps: Option<f32>) -> bool {
+        self.find_config(size, fps).is_some()
+    }
+
+    /// `config` adjusted to this camera: a size beyond the native resolution is scaled down
+    /// (keeping its aspect ratio, even dimensions) and the FPS is capped at what the sensor
+    /// manages at that size.
+    pub fn fit_output(&self, mut config: CameraOutputConfig) -> CameraOutputConfig {
+        let (w, h) = config.size;
+        if self.width > 0 && self.height > 0 && (w > self.width || h > self.height) {
+            let scale = f64::min(self.width as f64 / w as f64, self.height as f64 / h as f64);
+            let even = |v: u32| ((v as f64 * scale) as u32 & !1).max(2);
+            config.size = (even(w), even(h));
+        }
+        if let Some(fps) = config.fps {
+            let max = self
+                .configs
+                .iter()
+                .filter(|c| c.covers(config.size, None))
+                .map(|c| c.max_fps)
+                .reduce(f32::max);
+            if let Some(max) = max.filter(|&max| max > 0.0) {
+                config.fps = Some(fps.min(max));
+            }
+        }
+        config
+    }
+}
+
+/// Two cameras calibrated as a stereo pair.
+///
+/// Mirrors C++: `dai::StereoPair`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StereoPair {
+    pub left: CameraBoardSocket,
+    pub right: CameraBoardSocket,
+    /// Distance between the cameras in centimeters, negative when unknown.
+    pub baseline: f32,
+    /// Whether `left` is above `right` rather than beside it.
+    pub is_vertical: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawStereoPair {
+    left: i32,
+    right: i32,
+    #[serde(default = "unknown_baseline")]
+    baseline: f32,
+    #[serde(default)]
+    is_vertical: bool,
+}
+
+fn unknown_baseline() -> f32 {
+    -1.0
+}
+
+impl StereoPair {
+    /// Parse one entry of the JSON produced by C++ `nlohmann::json(device->getAvailableStereoPairs())`.
+    pub fn from_json(value: &Value) -> Result<Self> {
+        let raw = RawStereoPair::deserialize(value)
+            .map_err(|e| DepthaiError::new(format!("invalid stereo pair JSON: {e}")))?;
+        Ok(Self {
+            left: CameraBoardSocket::from_raw(raw.left),
+            right: CameraBoardSocket::from_raw(raw.right),
+            baseline: raw.baseline,
+            is_vertical: raw.is_vertical,
+        })
+    }
+
+    /// Order `pairs` most suitable first: side-by-side pairs before vertical ones, then the
+    /// widest baseline, which reaches the farthest.
+    pub fn sort_recommended(pairs: &mut [StereoPair]) {
+        pairs.sort_by(|a, b| a.is_vertical.cmp(&b.is_vertical).then(b.baseline.total_cmp(&a.baseline)));
+    }
+}
+
+impl Device {
+    /// Capabilities of the camera on `socket`, `None` when nothing is connected there.
+    pub fn camera_on_socket(&self, socket: CameraBoardSocket) -> Result<Option<CameraFeatures>> {
+        Ok(self.connected_camera_features()?.into_iter().find(|f| f.socket == socket))
+    }
+
+    /// Stereo pairs whose cameras are both connected, recommended one first (see
+    /// [`StereoPair::sort_recommended`]).
+    ///
+    /// Mirrors C++: `Device::getAvailableStereoPairs()`.
+    pub fn stereo_pairs(&self) -> Result<Vec<StereoPair>> {
+        #[cfg(feature = "mock")]
+        if let Some(mock) = &self.mock {
+            return Ok(mock_stereo_pairs(&mock.config().cameras));
+        }
+        clear_error_flag();
+        let ptr = unsafe { depthai::dai_device_get_stereo_pairs_json(self.handle()) };
+        let s = take_owned_json_string(ptr, "failed to query stereo pairs")?;
+        let mut pairs = match parse_json_value(&s)? {
+            Value::Array(entries) => entries.iter().map(StereoPair::from_json).collect::<Result<Vec<_>>>()?,
+            other => return Err(DepthaiError::new(format!("unexpected stereo pairs JSON: {other}"))),
+        };
+        StereoPair::sort_recommended(&mut pairs);
+        Ok(pairs)
+    }
+
+    /// The recommended stereo pair, `None` on devices without one.
+    pub fn default_stereo_pair(&self) -> Result<Option<StereoPair>> {
+        Ok(self.stereo_pairs()?.into_iter().next())
+    }
+
+    /// Capabilities of every connected camera.
+    ///
+    /// Mirrors C++: `Device::getConnectedCameraFeatures()`.
+    pub fn connected_camera_features(&self) -> Result<Vec<CameraFeatures>> {
+        #[cfg(feature = "mock")]
+        if let Some(mock) = &self.mock {
+            return Ok(mock.config().cameras.iter().map(|&s| mock_camera_features(s)).collect());
+        }
+        clear_error_flag();
+        let ptr = unsafe { depthai::dai_device_get_connected_camera_features_json(self.handle()) };
+        let s = take_owned_json_string(ptr, "failed to query connected camera features")?;
+        match parse_json_value(&s)? {
+            Value::Array(entries) => entries.iter().map(CameraFeatures::from_json).collect(),
+            other => Err(DepthaiError::new(format!("unexpected camera features JSON: {other}"))),
+        }
+    }
+}
+
+/// The 7.5 cm `CamB`/`CamC` pair of an OAK-D, when both cameras are connected.
+#[cfg(feature = "mock")]
+fn mock_stereo_pairs(cameras: &[CameraBoardSocket]) -> Vec<StereoPair> {
+    if cameras.contains(&CameraBoardSocket::CamB) && cameras.contains(&CameraBoardSocket::CamC) {
+        vec![StereoPair {
+            left: CameraBoardSocket::CamB,
+            right: CameraBoardSocket::CamC,
+            baseline: 7.5,
+            is_vertical: false,
+        }]
+    } else {
+        Vec::new()
+    }
+}
+
+/// An OAK-D camera: IMX378 color sensor on `CamA`, OV9282 mono sensors elsewhere.
+#[cfg(feature = "mock")]
+pub(crate) fn mock_camera_features(socket: CameraBoardSocket) -> CameraFeatures {
+    let mode = |width, height, max_fps, frame_type| CameraSensorConfig {
+        width,
+        height,
+        min_fps: 1.0,
+        max_fps,
+        frame_type: Some(frame_type),
+    };
+    let (sensor_name, name, sensor_type, configs) = match socket {
+        CameraBoardSocket::CamA => (
+            "IMX378",
+            "color",
+            CameraSensorType::Color,
+            vec![
+                mode(1920, 1080, 60.0, ImageFrameType::RAW10),
+                mode(3840, 2160, 42.0, ImageFrameType::RAW10),
+                mode(4056, 3040, 30.0, ImageFrameType::RAW10),
+            ],
+        ),
+        _ => (
+            "OV9282",
+            if socket == CameraBoardSocket::CamB { "left" } else { "right" },
+            CameraSensorType::Mono,
+            vec![
+                mode(640, 400, 120.0, ImageFrameType::RAW8),
+                mode(1280, 720, 120.0, ImageFrameType::RAW8),
+                mode(1280, 800, 120.0, ImageFrameType::RAW8),
+            ],
+        ),
+    };
+    let (width, height) = configs.last().map_or((0, 0), CameraSensorConfig::size);
+    CameraFeatures {
+        socket,
+        sensor_name: sensor_name.to_string(),
+        name: name.to_string(),
+        width,
+        height,
+        orientation: CameraImageOrientation::Auto,
+        supported_types: vec![sensor_type],
+        has_autofocus: socket == CameraBoardSocket::CamA,
+        configs,
+    }
+}
@@ -0,0 +1,269 @@
+//! Replaying recorded images or video through a camera.
+//!
+//! A [`CameraNode`]'s `mockIsp` input takes frames in place of the sensor, so everything
+//! downstream (ImageManip, StereoDepth, neural networks, ...) runs on recorded data.
+//! [`CameraNode::feed_from_images`] and [`CameraNode::feed_from_video`] create a host node that
+//! decodes the recording, converts it to `NV12` and pushes it into that input at the recorded rate.
+//! Playback stops at the end of the recording.
+//!
+//! ```no_run
+//! # use depthai::prelude::*;
+//! # fn main() -> Result<()> {
+//! # let pipeline = Pipeline::new().build()?;
+//! let left = pipeline.create_with::<CameraNode, _>(CameraBoardSocket::CamB)?;
+//! let right = pipeline.create_with::<CameraNode, _>(CameraBoardSocket::CamC)?;
+//! left.feed_from_images("recording/left/*.png", 30.0)?;
+//! right.feed_from_images("recording/right/*.png", 30.0)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Video files are decoded by the `ffmpeg` and `ffprobe` executables, which must be on `PATH`.
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Child, ChildStdout, Command, Stdio};
+use std::time::{Duration, Instant};
+
+use crate::camera::{CameraNode, ImageFrame};
+use crate::common::ImageFrameType;
+use crate::convert;
+use crate::error::{DepthaiError, Result};
+use crate::output::TypedOutput;
+use crate::pipeline::{Node, Pipeline};
+use crate::threaded_host_node::{ThreadedHostNode, ThreadedHostNodeContext, ThreadedHostNodeImpl};
+
+/// Files matching `pattern`, sorted by name. Only the last path component may contain the
+/// wildcards `*` and `?`.
+pub fn expand_glob(pattern: &str) -> Result<Vec<PathBuf>> {
+    let path = Path::new(pattern);
+    let name = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .ok_or_else(|| DepthaiError::new(format!("invalid image pattern {pattern:?}")))?;
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    if !name.contains(['*', '?']) {
+        return Ok(vec![path.to_path_buf()]);
+    }
+    let entries = std::fs::read_dir(dir)
+        .map_err(|e| DepthaiError::new(format!("failed to list {}: {e}", dir.display())))?;
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            entry
+                .file_name()
+                .to_str()
+                .is_some_and(|file| wildcard_match(name.as_bytes(), file.as_bytes()))
+        })
+        .map(|entry| entry.path())
+        .collect();
+    paths.sort();
+    Ok(paths)
+}
+
+fn wildcard_match(pattern: &[u8], name: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some((b'*', rest)) => (0..=name.len()).any(|skip| wildcard_match(rest, &name[skip..])),
+        Some((b'?', rest)) => !name.is_empty() && wildcard_match(rest, &name[1..]),
+        Some((c, rest)) => name.first() == Some(c) && wildcard_match(rest, &name[1..]),
+    }
+}
+
+/// A video decoded to raw `NV12` by an `ffmpeg` child process.
+struct VideoDecoder {
+    path: PathBuf,
+    child: Child,
+    stdout: ChildStdout,
+    size: (u32, u32),
+}
+
+impl VideoDecoder {
+    fn open(path: &Path) -> Result<(Self, f32)> {
+        let (width, height, fps) = probe_video(path)?;
+        if (width | height) & 1 != 0 {
+            return Err(DepthaiError::new(format!(
+                "{} is {width}x{height}; NV12 frames need an even size",
+                path.display()
+            )));
+        }
+        let mut child = Command::new("ffmpeg")
+            .args(["-v", "error", "-i"])
+            .arg(path)
+            .args(["-f", "rawvideo", "-pix_fmt", "nv12", "-"])
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .spawn()
+            .map_err(|e| DepthaiError::new(format!("failed to run ffmpeg: {e}")))?;
+        let stdout = child.stdout.take().expect("stdout is piped");
+        let decoder = Self {
+            path: path.to_path_buf(),
+            child,
+            stdout,
+            size: (width, height),
+        };
+        Ok((decoder, fps))
+    }
+
+    fn next_frame(&mut self) -> Result<Option<ImageFrame>> {
+        let (width, height) = self.size;
+        let mut data = vec![0u8; width as usize * height as usize * 3 / 2];
+        match self.stdout.read_exact(&mut data) {
+            Ok(()) => ImageFrame::new(width, height, ImageFrameType::NV12, &data).map(Some),
+            Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => Ok(None),
+            Err(e) => Err(DepthaiError::new(format!("failed to decode {}: {e}", self.path.display()))),
+        }
+    }
+}
+
+impl Drop for VideoDecoder {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// Width, height and frame rate of the first video stream of `path`.
+fn probe_video(path: &Path) -> Result<(u32, u32, f32)> {
+    let output = Command::new("ffprobe")
+        .args(["-v", "error", "-select_streams", "v:0", "-show_entries", "stream=width,height,r_frame_rate"])
+        .args(["-of", "csv=p=0"])
+        .arg(path)
+        .output()
+        .map_err(|e| DepthaiError::new(format!("failed to run ffprobe: {e}")))?;
+    if !output.status.success() {
+        return Err(DepthaiError::new(format!(
+            "ffprobe failed on {}: {}",
+            path.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    let text = String::from_utf8_lossy(&output.stdout);
+    parse_probe(text.trim())
+        .ok_or_else(|| DepthaiError::new(format!("unexpected ffprobe output for {}: {text:?}", path.display())))
+}
+
+/// Parse `width,height,num/den`.
+fn parse_probe(line: &str) -> Option<(u32, u32, f32)> {
+    let mut fields = line.lines().next()?.split(',');
+    let width = fields.next()?.trim().parse().ok()?;
+    let height = fields.next()?.trim().parse().ok()?;
+    let (num, den) = fields.next()?.trim().split_once('/')?;
+    let (num, den): (f32, f32) = (num.parse().ok()?, den.parse().ok()?);
+    (den > 0.0 && num > 0.0).then_some((width, height, num / den))
+}
+
+enum FeedSource {
+    Images { paths: Vec<PathBuf>, next: usize },
+    Video(VideoDecoder),
+}
+
+impl FeedSource {
+    fn next_frame(&mut self) -> Result<Option<ImageFrame>> {
+        match self {
+            Self::Images { paths, next } => {
+                let Some(path) = paths.get(*next) else {
+                    return Ok(None);
+                };
+                *next += 1;
+                let rgb = image::open(path)
+                    .map_err(|e| DepthaiError::new(format!("failed to read {}: {e}", path.display())))?
+                    .to_rgb8();
+                let (width, height) = rgb.dimensions();
+                let nv12 = convert::rgb888i_to_nv12(width, height, rgb.as_raw())?;
+                ImageFrame::new(width, height, ImageFrameType::NV12, &nv12).map(Some)
+            }
+            Self::Video(decoder) => decoder.next_frame(),
+        }
+    }
+}
+
+struct CameraFeedImpl {
+    out: TypedOutput<ImageFrame>,
+    source: FeedSource,
+    interval: Duration,
+}
+
+impl ThreadedHostNodeImpl for CameraFeedImpl {
+    fn run(&mut self, ctx: &ThreadedHostNodeContext) {
+        let mut due = Instant::now();
+        let mut sequence = 0i64;
+        while ctx.is_running() {
+            let frame = match self.source.next_frame() {
+                Ok(Some(frame)) => frame,
+                Ok(None) => break,
+                Err(e) => {
+                    ctx.report_error(&e);
+                    break;
+                }
+            };
+            std::thread::sleep(due.saturating_duration_since(Instant::now()));
+            due += self.interval;
+            let result = frame
+                .set_timestamp_now()
+                .and_then(|()| frame.set_sequence_num(sequence))
+                .and_then(|()| self.out.send(&frame));
+            if let Err(e) = result {
+                ctx.report_error(&e);
+                break;
+            }
+            sequence += 1;
+        }
+    }
+}
+
+/// Host node replaying a recording into a camera, see the [module docs](self).
+#[derive(Clone)]
+pub struct CameraFeed {
+    node: ThreadedHostNode,
+}
+
+impl CameraFeed {
+    pub fn as_node(&self) -> &Node {
+        self.node.as_node()
+    }
+
+    /// The replayed `NV12` frames, already linked to the camera's `mockIsp` input.
+    pub fn out(&self) -> Result<TypedOutput<ImageFrame>> {
+        self.as_node().output("out").map(TypedOutput::from_untyped)
+    }
+}
+
+impl CameraNode {
+    /// Replay the images matching `pattern` (e.g. `"left/*.png"`, sorted by name) at `fps`.
+    ///
+    /// Images are decoded with the `image` crate when they are sent; their size must be even.
+    pub fn feed_from_images(&self, pattern: &str, fps: f32) -> Result<CameraFeed> {
+        if !(fps > 0.0 && fps.is_finite()) {
+            return Err(DepthaiError::new(format!("invalid feed fps {fps}")));
+        }
+        let paths = expand_glob(pattern)?;
+        if paths.is_empty() {
+            return Err(DepthaiError::new(format!("no images match {pattern:?}")));
+        }
+        self.feed(FeedSource::Images { paths, next: 0 }, fps)
+    }
+
+    /// Replay a video file at its own frame rate, decoded by `ffmpeg`.
+    pub fn feed_from_video(&self, path: impl AsRef<Path>) -> Result<CameraFeed> {
+        let (decoder, fps) = VideoDecoder::open(path.as_ref())?;
+        self.feed(FeedSource::Video(decoder), fps)
+    }
+
+    fn feed(&self, source: FeedSource, fps: f32) -> Result<CameraFeed> {
+        let pipeline = Pipeline::from_inner(self.as_node().pipeline.clone());
+        let node = pipeline.create_threaded_host_node(|node| {
+            Ok(CameraFeedImpl {
+                out: node.create_typed_output("out")?,
+                source,
+                interval: Duration::from_secs_f32(1.0 / fps),
+            })
+        })?;
+        let feed = CameraFeed { node };
+        feed.out()?.link(&self.mockIsp()?)?;
+        Ok(feed)
+    }
+}
@@ -0,0 +1,83 @@
+//! Cancelling blocking calls.
+//!
+//! `MessageQueue::get(None)` with no data, [`Pipeline::wait`](crate::Pipeline::wait) and
+//! waiting for a device block inside DepthAI-Core, where a Rust thread can't be interrupted. The
+//! `*_cancellable` variants block in short slices instead and fail with a
+//! [cancelled](crate::DepthaiError::is_cancelled) error as soon as a [`CancellationToken`] is
+//! cancelled, e.g. from a Ctrl-C handler or a supervisor thread:
+//!
+//! ```no_run
+//! # use depthai::{CancellationToken, MessageQueue, Result};
+//! # fn f(queue: MessageQueue) -> Result<()> {
+//! let token = CancellationToken::new();
+//! let stop = token.clone();
+//! // e.g. ctrlc::set_handler(move || stop.cancel())
+//! # let _ = stop;
+//! loop {
+//!     match queue.get_cancellable(None, &token) {
+//!         Ok(Some(msg)) => {
+//!             // ...
+//! #           let _ = msg;
+//!         }
+//!         Ok(None) => {} // only with a timeout
+//!         Err(e) if e.is_cancelled() => break,
+//!         Err(e) => return Err(e),
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Cancellation is noticed within [`POLL_INTERVAL`].
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::error::{DepthaiError, Result};
+
+/// Longest a cancellable call blocks in DepthAI-Core before checking its token again.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Shared flag that aborts the `*_cancellable` calls it is passed to, see the
+/// [module docs](self). Clones share the flag.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel the calls waiting on this token (and on its clones), now and in the future.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// [`DepthaiError::cancelled`] if cancelled.
+    pub(crate) fn check(&self) -> Result<()> {
+        if self.is_cancelled() {
+            Err(DepthaiError::cancelled())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Time slices of at most [`POLL_INTERVAL`] until `timeout` (forever when `None`) has elapsed.
+pub(crate) fn slices(timeout: Option<Duration>) -> impl Iterator<Item = Duration> {
+    let deadline = timeout.map(|t| Instant::now() + t);
+    std::iter::from_fn(move || match deadline {
+        None => Some(POLL_INTERVAL),
+        Some(deadline) => {
+            let left = deadline.saturating_duration_since(Instant::now());
+            (!left.is_zero()).then(|| left.min(POLL_INTERVAL))
+        }
+    })
+}
@@ -0,0 +1,57 @@
+use autocxx::c_int;
+use depthai_sys::depthai;
+
+use crate::camera::ImageFrame;
+use crate::common::ImageFrameType;
+use crate::error::clear_error_flag;
+
+/// Converts frames between data types (e.g. U8 <-> FP16) on-device.
+///
+/// The output is computed as `input * scale + offset`, then converted to the output frame type.
+/// Commonly used to prepare NN inputs or post-process NN outputs.
+#[crate::native_node_wrapper(native = "dai::node::Cast", inputs(input: ImageFrame), outputs(output: ImageFrame, passthroughInput: ImageFrame))]
+pub struct CastNode {
+    node: crate::pipeline::Node,
+}
+
+impl CastNode {
+    /// Specify the number of frames in the output pool.
+    ///
+    /// Mirrors C++: `Cast::setNumFramesPool(int)`.
+    pub fn set_num_frames_pool(&self, num_frames_pool: i32) {
+        clear_error_flag();
+        unsafe { depthai::dai_cast_set_num_frames_pool(self.node.handle(), c_int(num_frames_pool)) };
+    }
+
+    /// Specify the frame type of the output (e.g. `GRAY8`, `RGB888p`, `BGRF16F16F16p`).
+    ///
+    /// Mirrors C++: `Cast::setOutputFrameType(ImgFrame::Type)`.
+    pub fn set_output_frame_type(&self, frame_type: ImageFrameType) {
+        clear_error_flag();
+        unsafe { depthai::dai_cast_set_output_frame_type(self.node.handle(), c_int(frame_type as i32)) };
+    }
+
+    /// Specify the scale applied to every input value.
+    ///
+    /// Mirrors C++: `Cast::setScale(float)`.
+    pub fn set_scale(&self, scale: f32) {
+        clear_error_flag();
+        unsafe { depthai::dai_cast_set_scale(self.node.handle(), scale) };
+    }
+
+    /// Specify the offset added to every (scaled) input value.
+    ///
+    /// Mirrors C++: `Cast::setOffset(float)`.
+    pub fn set_offset(&self, offset: f32) {
+        clear_error_flag();
+        unsafe { depthai::dai_cast_set_offset(self.node.handle(), offset) };
+    }
+
+    /// Specify the number of shaves used by the node.
+    ///
+    /// Mirrors C++: `Cast::setNumShaves(int)`.
+    pub fn set_num_shaves(&self, num_shaves: i32) {
+        clear_error_flag();
+        unsafe { depthai::dai_cast_set_num_shaves(self.node.handle(), c_int(num_shaves)) };
+    }
+}
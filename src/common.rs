@@ -2,6 +2,7 @@ use std::fmt;
 
 #[repr(i32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ImageFrameType {
     YUV422i = 0,
     YUV444p,
@@ -145,6 +146,32 @@ impl CameraSensorType {
     }
 }
 
+/// How a sensor's image is oriented relative to the board.
+///
+/// Mirrors C++: `dai::CameraImageOrientation`.
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CameraImageOrientation {
+    #[default]
+    Auto = -1,
+    Normal = 0,
+    HorizontalMirror = 1,
+    VerticalFlip = 2,
+    Rotate180Deg = 3,
+}
+
+impl CameraImageOrientation {
+    pub fn from_raw(value: i32) -> Self {
+        match value {
+            0 => Self::Normal,
+            1 => Self::HorizontalMirror,
+            2 => Self::VerticalFlip,
+            3 => Self::Rotate180Deg,
+            _ => Self::Auto,
+        }
+    }
+}
+
 impl Default for CameraBoardSocket {
     fn default() -> Self {
         CameraBoardSocket::Auto
@@ -174,6 +201,84 @@ impl CameraBoardSocket {
     }
 }
 
+/// Interpolation used when resampling images.
+///
+/// Mirrors C++: `dai::Interpolation` (`BYPASS` is an alias of `NEAREST_NEIGHBOR`).
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interpolation {
+    Bilinear = 0,
+    Bicubic = 1,
+    NearestNeighbor = 2,
+}
+
+impl Default for Interpolation {
+    fn default() -> Self {
+        Interpolation::Bicubic
+    }
+}
+
+impl Interpolation {
+    pub fn from_raw(value: i32) -> Option<Self> {
+        match value {
+            0 => Some(Interpolation::Bilinear),
+            1 => Some(Interpolation::Bicubic),
+            2 => Some(Interpolation::NearestNeighbor),
+            _ => None,
+        }
+    }
+}
+
+/// A rectangle in pixels, or normalized to `0..=1` of the image size.
+///
+/// Mirrors C++: `dai::Rect`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Rect {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Rect {
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self { x, y, width, height }
+    }
+
+    /// Whether the rectangle is expressed relative to the image size.
+    ///
+    /// Mirrors C++: `Rect::isNormalized()`.
+    pub fn is_normalized(&self) -> bool {
+        self.x + self.width <= 1.0 && self.y + self.height <= 1.0
+    }
+
+    /// The rectangle in pixels of a `width` x `height` image (unchanged if already in pixels).
+    ///
+    /// Mirrors C++: `Rect::denormalize(int, int)`.
+    pub fn denormalize(&self, width: u32, height: u32) -> Self {
+        if !self.is_normalized() {
+            return *self;
+        }
+        let (w, h) = (width as f32, height as f32);
+        Self::new(self.x * w, self.y * h, self.width * w, self.height * h)
+    }
+
+    /// The rectangle normalized to a `width` x `height` image (unchanged if already normalized).
+    ///
+    /// Mirrors C++: `Rect::normalize(int, int)`.
+    pub fn normalize(&self, width: u32, height: u32) -> Self {
+        if self.is_normalized() {
+            return *self;
+        }
+        let (w, h) = (width as f32, height as f32);
+        Self::new(self.x / w, self.y / h, self.width / w, self.height / h)
+    }
+
+    pub fn center(&self) -> (f32, f32) {
+        (self.x + self.width / 2.0, self.y + self.height / 2.0)
+    }
+}
+
 impl fmt::Display for CameraBoardSocket {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:?}", self)
@@ -0,0 +1,361 @@
+//! Pure-Rust pixel format conversions.
+//!
+//! Lets host-side viewers and processing turn DepthAI frames into displayable RGB/GRAY buffers
+//! without OpenCV. Color conversions use BT.601 limited range, like `ImgFrame::getCvFrame()`.
+//! The loops work on whole rows with fixed-point arithmetic so they vectorize well.
+
+use crate::camera::ImageFrame;
+use crate::common::ImageFrameType;
+use crate::error::{DepthaiError, Result};
+
+fn check_len(what: &str, data: &[u8], expected: usize) -> Result<()> {
+    if data.len() < expected {
+        return Err(DepthaiError::new(format!(
+            "{what} needs {expected} bytes, got {}",
+            data.len()
+        )));
+    }
+    Ok(())
+}
+
+fn check_even(what: &str, width: usize, height: usize) -> Result<()> {
+    if (width | height) & 1 != 0 {
+        return Err(DepthaiError::new(format!(
+            "{what} needs even width and height, got {width}x{height}"
+        )));
+    }
+    Ok(())
+}
+
+#[inline(always)]
+fn yuv_to_rgb(y: u8, u: u8, v: u8) -> [u8; 3] {
+    let c = 298 * (y as i32 - 16);
+    let d = u as i32 - 128;
+    let e = v as i32 - 128;
+    let r = (c + 409 * e + 128) >> 8;
+    let g = (c - 100 * d - 208 * e + 128) >> 8;
+    let b = (c + 516 * d + 128) >> 8;
+    [r.clamp(0, 255) as u8, g.clamp(0, 255) as u8, b.clamp(0, 255) as u8]
+}
+
+/// 4:2:0 to RGB888i. `u`/`v` start at the first chroma sample; samples are `chroma_step` bytes
+/// apart within a chroma row and rows are `chroma_stride` bytes apart.
+fn yuv420_to_rgb888i(
+    width: usize,
+    height: usize,
+    y: &[u8],
+    u: &[u8],
+    v: &[u8],
+    chroma_stride: usize,
+    chroma_step: usize,
+) -> Vec<u8> {
+    if width == 0 {
+        return Vec::new();
+    }
+    let mut out = vec![0u8; width * height * 3];
+    for (row, (out_row, y_row)) in out.chunks_exact_mut(width * 3).zip(y.chunks_exact(width)).enumerate() {
+        let chroma_row = (row / 2) * chroma_stride;
+        for (pair, (px, luma)) in out_row.chunks_exact_mut(6).zip(y_row.chunks_exact(2)).enumerate() {
+            let i = chroma_row + pair * chroma_step;
+            let (cu, cv) = (u[i], v[i]);
+            px[..3].copy_from_slice(&yuv_to_rgb(luma[0], cu, cv));
+            px[3..].copy_from_slice(&yuv_to_rgb(luma[1], cu, cv));
+        }
+    }
+    out
+}
+
+/// Convert NV12 (Y plane followed by interleaved UV) to RGB888i.
+pub fn nv12_to_rgb888i(width: u32, height: u32, data: &[u8]) -> Result<Vec<u8>> {
+    let (w, h) = (width as usize, height as usize);
+    check_even("NV12", w, h)?;
+    check_len("NV12", data, w * h * 3 / 2)?;
+    let (y, uv) = data.split_at(w * h);
+    Ok(yuv420_to_rgb888i(w, h, y, uv, &uv[1..], w, 2))
+}
+
+/// Convert NV21 (Y plane followed by interleaved VU) to RGB888i.
+pub fn nv21_to_rgb888i(width: u32, height: u32, data: &[u8]) -> Result<Vec<u8>> {
+    let (w, h) = (width as usize, height as usize);
+    check_even("NV21", w, h)?;
+    check_len("NV21", data, w * h * 3 / 2)?;
+    let (y, vu) = data.split_at(w * h);
+    Ok(yuv420_to_rgb888i(w, h, y, &vu[1..], vu, w, 2))
+}
+
+/// Convert YUV420p (Y, U and V planes) to RGB888i.
+pub fn yuv420p_to_rgb888i(width: u32, height: u32, data: &[u8]) -> Result<Vec<u8>> {
+    let (w, h) = (width as usize, height as usize);
+    check_even("YUV420p", w, h)?;
+    check_len("YUV420p", data, w * h * 3 / 2)?;
+    let (y, chroma) = data.split_at(w * h);
+    let (u, v) = chroma.split_at(w * h / 4);
+    Ok(yuv420_to_rgb888i(w, h, y, u, v, w / 2, 1))
+}
+
+/// Convert RGB888i to NV12, averaging chroma over 2x2 blocks. The inverse of
+/// [`nv12_to_rgb888i`], e.g. to feed decoded images into a camera's `mockIsp` input.
+pub fn rgb888i_to_nv12(width: u32, height: u32, rgb: &[u8]) -> Result<Vec<u8>> {
+    let (w, h) = (width as usize, height as usize);
+    check_even("NV12", w, h)?;
+    check_len("RGB888i", rgb, w * h * 3)?;
+    let mut out = Vec::with_capacity(w * h * 3 / 2);
+    out.extend(rgb[..w * h * 3].chunks_exact(3).map(|p| {
+        let (r, g, b) = (p[0] as i32, p[1] as i32, p[2] as i32);
+        (((66 * r + 129 * g + 25 * b + 128) >> 8) + 16) as u8
+    }));
+    for y in (0..h).step_by(2) {
+        for x in (0..w).step_by(2) {
+            let (mut r, mut g, mut b) = (0, 0, 0);
+            for (dx, dy) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+                let p = &rgb[((y + dy) * w + x + dx) * 3..][..3];
+                r += p[0] as i32;
+                g += p[1] as i32;
+                b += p[2] as i32;
+            }
+            let (r, g, b) = (r / 4, g / 4, b / 4);
+            let u = ((-38 * r - 74 * g + 112 * b + 128) >> 8) + 128;
+            let v = ((112 * r - 94 * g - 18 * b + 128) >> 8) + 128;
+            out.push(u.clamp(0, 255) as u8);
+            out.push(v.clamp(0, 255) as u8);
+        }
+    }
+    Ok(out)
+}
+
+/// Interleave a 3-plane frame (`RGB888p`/`BGR888p`), keeping the channel order.
+pub fn planar_to_interleaved(width: u32, height: u32, data: &[u8]) -> Result<Vec<u8>> {
+    let pixels = width as usize * height as usize;
+    check_len("planar frame", data, pixels * 3)?;
+    let (c0, rest) = data.split_at(pixels);
+    let (c1, c2) = rest.split_at(pixels);
+    let mut out = vec![0u8; pixels * 3];
+    for (((px, &a), &b), &c) in out.chunks_exact_mut(3).zip(c0).zip(c1).zip(&c2[..pixels]) {
+        px.copy_from_slice(&[a, b, c]);
+    }
+    Ok(out)
+}
+
+/// Swap the first and third channel of every 3-byte pixel (RGB888i <-> BGR888i).
+pub fn swap_rb_in_place(data: &mut [u8]) {
+    for px in data.chunks_exact_mut(3) {
+        px.swap(0, 2);
+    }
+}
+
+/// Unpack MIPI RAW10 (4 pixels in 5 bytes: four high bytes, then the 2-bit remainders) into
+/// 10-bit values. `stride` is the row length in bytes, `0` for tightly packed rows.
+pub fn unpack_raw10(width: u32, height: u32, stride: usize, data: &[u8]) -> Result<Vec<u16>> {
+    unpack_mipi("RAW10", 10, 4, width, height, stride, data)
+}
+
+/// Unpack MIPI RAW12 (2 pixels in 3 bytes: two high bytes, then the 4-bit remainders) into
+/// 12-bit values. `stride` is the row length in bytes, `0` for tightly packed rows.
+pub fn unpack_raw12(width: u32, height: u32, stride: usize, data: &[u8]) -> Result<Vec<u16>> {
+    unpack_mipi("RAW12", 12, 2, width, height, stride, data)
+}
+
+/// Unpack MIPI RAW14 (4 pixels in 7 bytes: four high bytes, then the 6-bit remainders) into
+/// 14-bit values. `stride` is the row length in bytes, `0` for tightly packed rows.
+pub fn unpack_raw14(width: u32, height: u32, stride: usize, data: &[u8]) -> Result<Vec<u16>> {
+    unpack_mipi("RAW14", 14, 4, width, height, stride, data)
+}
+
+/// MIPI CSI-2 packing: groups of `pixels` high bytes followed by their low `bits - 8` bits,
+/// first pixel in the least significant bits.
+fn unpack_mipi(
+    name: &str,
+    bits: u32,
+    pixels: usize,
+    width: u32,
+    height: u32,
+    stride: usize,
+    data: &[u8],
+) -> Result<Vec<u16>> {
+    let (w, h) = (width as usize, height as usize);
+    if w == 0 {
+        return Ok(Vec::new());
+    }
+    if w % pixels != 0 {
+        return Err(DepthaiError::new(format!("{name} needs a width divisible by {pixels}, got {w}")));
+    }
+    let low_bits = bits - 8;
+    let group = pixels + pixels * low_bits as usize / 8;
+    let packed_row = w / pixels * group;
+    let stride = if stride == 0 { packed_row } else { stride };
+    if stride < packed_row {
+        return Err(DepthaiError::new(format!(
+            "{name} stride {stride} is shorter than a {w} pixel row ({packed_row} bytes)"
+        )));
+    }
+    if h > 0 {
+        check_len(name, data, stride * (h - 1) + packed_row)?;
+    }
+    let mask = (1u32 << low_bits) - 1;
+    let mut out = vec![0u16; w * h];
+    for (row, out_row) in out.chunks_exact_mut(w).enumerate() {
+        let src = &data[row * stride..][..packed_row];
+        for (px, group) in out_row.chunks_exact_mut(pixels).zip(src.chunks_exact(group)) {
+            let (high, low) = group.split_at(pixels);
+            let low = low.iter().rev().fold(0u32, |acc, &b| acc << 8 | b as u32);
+            for (i, (px, &high)) in px.iter_mut().zip(high).enumerate() {
+                *px = ((high as u32) << low_bits | (low >> (i as u32 * low_bits)) & mask) as u16;
+            }
+        }
+    }
+    Ok(out)
+}
+
+/// Sensor values of a `RAW8`, `RAW10`, `RAW12`, `RAW14` (MIPI packed) or `RAW16` frame, as
+/// tightly packed rows of `u16`. Values keep the sensor's bit depth, see [`raw_bit_depth`].
+pub fn unpack_raw(frame: &ImageFrame) -> Result<Vec<u16>> {
+    use ImageFrameType::*;
+    let (width, height) = (frame.width(), frame.height());
+    let (w, h) = (width as usize, height as usize);
+    let format = frame
+        .format()
+        .ok_or_else(|| DepthaiError::new("frame has an unknown type"))?;
+    let data = frame.bytes();
+    match format {
+        RAW8 => Ok(compact_rows(&data, h, w, frame.stride())?.into_iter().map(u16::from).collect()),
+        RAW10 => unpack_raw10(width, height, frame.stride(), &data),
+        RAW12 => unpack_raw12(width, height, frame.stride(), &data),
+        RAW14 => unpack_raw14(width, height, frame.stride(), &data),
+        RAW16 => Ok(raw16_to_u16(&compact_rows(&data, h, w * 2, frame.stride())?)),
+        other => Err(DepthaiError::new(format!("{other:?} frames are not raw sensor frames"))),
+    }
+}
+
+/// Bits per value of a raw sensor frame type, `None` for other types.
+pub fn raw_bit_depth(frame_type: ImageFrameType) -> Option<u32> {
+    use ImageFrameType::*;
+    match frame_type {
+        RAW8 => Some(8),
+        RAW10 => Some(10),
+        RAW12 => Some(12),
+        RAW14 => Some(14),
+        RAW16 => Some(16),
+        _ => None,
+    }
+}
+
+/// Reinterpret little-endian 16-bit samples (`RAW16`, depth) as `u16` values.
+pub fn raw16_to_u16(data: &[u8]) -> Vec<u16> {
+    data.chunks_exact(2)
+        .map(|b| u16::from_le_bytes([b[0], b[1]]))
+        .collect()
+}
+
+/// Smallest and largest non-zero value, a useful window for depth/disparity (`0` means invalid).
+pub fn gray16_min_max(values: &[u16]) -> Option<(u16, u16)> {
+    values
+        .iter()
+        .filter(|&&v| v != 0)
+        .fold(None, |acc, &v| match acc {
+            None => Some((v, v)),
+            Some((lo, hi)) => Some((lo.min(v), hi.max(v))),
+        })
+}
+
+/// Map 16-bit values to 8 bits, linearly stretching `min..=max` to `0..=255` and clamping
+/// values outside the window.
+pub fn gray16_to_gray8(values: &[u16], min: u16, max: u16) -> Vec<u8> {
+    let lo = min.min(max) as u32;
+    let range = (min.max(max) as u32 - lo).max(1);
+    values
+        .iter()
+        .map(|&v| ((v as u32).clamp(lo, lo + range) - lo) * 255 / range)
+        .map(|v| v as u8)
+        .collect()
+}
+
+/// Copy the first `row_bytes` of every `stride`-byte row into a tightly packed buffer.
+pub(crate) fn compact_rows(data: &[u8], height: usize, row_bytes: usize, stride: usize) -> Result<Vec<u8>> {
+    if stride == 0 || stride == row_bytes {
+        check_len("frame", data, row_bytes * height)?;
+        return Ok(data[..row_bytes * height].to_vec());
+    }
+    if stride < row_bytes {
+        return Err(DepthaiError::new(format!(
+            "stride {stride} is shorter than a row ({row_bytes} bytes)"
+        )));
+    }
+    if height > 0 {
+        check_len("frame", data, stride * (height - 1) + row_bytes)?;
+    }
+    Ok((0..height)
+        .flat_map(|row| &data[row * stride..][..row_bytes])
+        .copied()
+        .collect())
+}
+
+/// Convert a frame to RGB888i.
+///
+/// Supports `RGB888i`, `BGR888i`, `RGB888p`, `BGR888p`, `NV12`, `NV21`, `YUV420p` and the
+/// single-channel `GRAY8`/`RAW8`/`YUV400p`. `RAW16` is windowed to its non-zero range.
+pub fn to_rgb888i(frame: &ImageFrame) -> Result<Vec<u8>> {
+    use ImageFrameType::*;
+    let (width, height) = (frame.width(), frame.height());
+    let (w, h) = (width as usize, height as usize);
+    let format = frame
+        .format()
+        .ok_or_else(|| DepthaiError::new("frame has an unknown type"))?;
+    let data = frame.bytes();
+    match format {
+        RGB888i => compact_rows(&data, h, w * 3, frame.stride()),
+        BGR888i => {
+            let mut rgb = compact_rows(&data, h, w * 3, frame.stride())?;
+            swap_rb_in_place(&mut rgb);
+            Ok(rgb)
+        }
+        RGB888p => planar_to_interleaved(width, height, &data),
+        BGR888p => {
+            let mut rgb = planar_to_interleaved(width, height, &data)?;
+            swap_rb_in_place(&mut rgb);
+            Ok(rgb)
+        }
+        NV12 => nv12_to_rgb888i(width, height, &data),
+        NV21 => nv21_to_rgb888i(width, height, &data),
+        YUV420p => yuv420p_to_rgb888i(width, height, &data),
+        GRAY8 | RAW8 | YUV400p => {
+            let gray = compact_rows(&data, h, w, frame.stride())?;
+            Ok(gray.iter().flat_map(|&v| [v, v, v]).collect())
+        }
+        RAW16 => {
+            let gray = to_gray8(frame)?;
+            Ok(gray.iter().flat_map(|&v| [v, v, v]).collect())
+        }
+        other => Err(DepthaiError::new(format!("cannot convert {other:?} frames to RGB888i"))),
+    }
+}
+
+/// Convert a single-channel frame to GRAY8.
+///
+/// `GRAY8`/`RAW8`/`YUV400p` are copied, `NV12`/`NV21`/`YUV420p` keep their luma plane, `RAW16`
+/// is windowed to its non-zero range and `RAW10` is unpacked and scaled down.
+pub fn to_gray8(frame: &ImageFrame) -> Result<Vec<u8>> {
+    use ImageFrameType::*;
+    let (width, height) = (frame.width(), frame.height());
+    let (w, h) = (width as usize, height as usize);
+    let format = frame
+        .format()
+        .ok_or_else(|| DepthaiError::new("frame has an unknown type"))?;
+    let data = frame.bytes();
+    match format {
+        GRAY8 | RAW8 | YUV400p => compact_rows(&data, h, w, frame.stride()),
+        NV12 | NV21 | YUV420p => {
+            check_len(&format!("{format:?}"), &data, w * h)?;
+            Ok(data[..w * h].to_vec())
+        }
+        RAW16 => {
+            let values = raw16_to_u16(&compact_rows(&data, h, w * 2, frame.stride())?);
+            let (min, max) = gray16_min_max(&values).unwrap_or((0, u16::MAX));
+            Ok(gray16_to_gray8(&values, min, max))
+        }
+        RAW10 => {
+            let values = unpack_raw10(width, height, frame.stride(), &data)?;
+            Ok(values.iter().map(|&v| (v >> 2) as u8).collect())
+        }
+        other => Err(DepthaiError::new(format!("cannot convert {other:?} frames to GRAY8"))),
+    }
+}
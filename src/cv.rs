@@ -0,0 +1,157 @@
+//! OpenCV interop for [`ImageFrame`] (requires the `opencv` feature).
+//!
+//! - [`ImageFrame::as_cv_mat`] borrows the frame memory as a `cv::Mat` without copying.
+//! - [`ImageFrame::to_cv_mat`] converts through DepthAI's `getCvFrame` (NV12/YUV/planar -> BGR).
+//! - [`ImageFrame::from_cv_mat`] builds a host-side frame from a `cv::Mat`.
+
+use std::ffi::c_void;
+
+use depthai_sys::depthai;
+use opencv::boxed_ref::BoxedRef;
+use opencv::core::{self as cv, Mat};
+use opencv::prelude::*;
+
+use crate::camera::ImageFrame;
+use crate::common::ImageFrameType;
+use crate::error::{DepthaiError, Result, clear_error_flag, last_error};
+
+fn cv_error(err: opencv::Error) -> DepthaiError {
+    DepthaiError::new(format!("opencv: {err}"))
+}
+
+/// OpenCV element type and number of `Mat` rows for a frame of `height` image rows.
+///
+/// Semi-planar and planar 4:2:0 frames use OpenCV's single-channel layout of `height * 3 / 2`
+/// rows, which is what `cv::cvtColor(.., COLOR_YUV2BGR_NV12)` and friends expect.
+fn cv_layout(frame_type: ImageFrameType, height: i32) -> Option<(i32, i32)> {
+    use ImageFrameType::*;
+    match frame_type {
+        GRAY8 | RAW8 | YUV400p => Some((cv::CV_8UC1, height)),
+        RAW16 => Some((cv::CV_16UC1, height)),
+        GRAYF16 => Some((cv::CV_16FC1, height)),
+        RGB888i | BGR888i => Some((cv::CV_8UC3, height)),
+        RGBA8888 => Some((cv::CV_8UC4, height)),
+        NV12 | NV21 | YUV420p => Some((cv::CV_8UC1, height * 3 / 2)),
+        _ => None,
+    }
+}
+
+/// Owns the `cv::Mat` returned by `dai_frame_get_cv_frame`.
+struct NativeMat(*mut c_void);
+
+impl Drop for NativeMat {
+    fn drop(&mut self) {
+        unsafe { depthai::dai_cv_mat_release(self.0 as _) };
+    }
+}
+
+impl ImageFrame {
+    /// Borrow the frame as a `cv::Mat` without copying.
+    ///
+    /// Supported: `GRAY8`, `RAW8`, `YUV400p`, `RAW16`, `GRAYF16`, `RGB888i`, `BGR888i`,
+    /// `RGBA8888`, and `NV12`/`NV21`/`YUV420p` as a `height * 3 / 2` single-channel `Mat`
+    /// (convert with `cv::cvtColor`). Other formats need [`to_cv_mat`](Self::to_cv_mat).
+    ///
+    /// The `Mat` points into the payload and borrows the frame, so the payload can't be replaced
+    /// while it is in use ([`set_data`](Self::set_data) takes `&mut self`).
+    pub fn as_cv_mat(&self) -> Result<BoxedRef<'_, Mat>> {
+        let frame_type = self
+            .format()
+            .ok_or_else(|| DepthaiError::new("frame has an unknown type"))?;
+        let (cols, height) = (self.width() as i32, self.height() as i32);
+        let (typ, rows) = cv_layout(frame_type, height).ok_or_else(|| {
+            DepthaiError::new(format!("{frame_type:?} frames cannot be viewed as a cv::Mat; use to_cv_mat"))
+        })?;
+        if cols <= 0 || height <= 0 {
+            return Err(DepthaiError::new("frame is empty"));
+        }
+        let elem_size = match typ {
+            cv::CV_16UC1 | cv::CV_16FC1 => 2,
+            cv::CV_8UC3 => 3,
+            cv::CV_8UC4 => 4,
+            _ => 1,
+        };
+        let packed_step = cols as usize * elem_size;
+        let step = match self.stride() {
+            0 => packed_step,
+            stride => stride,
+        };
+        if rows != height && step != packed_step {
+            return Err(DepthaiError::new(format!(
+                "{frame_type:?} frame with padded rows (stride {step}) cannot be viewed as a cv::Mat; use to_cv_mat"
+            )));
+        }
+        if step < packed_step || self.byte_len() < step * (rows as usize - 1) + packed_step {
+            return Err(DepthaiError::new(format!(
+                "{frame_type:?} frame of {cols}x{height} holds only {} bytes",
+                self.byte_len()
+            )));
+        }
+        let data = unsafe { depthai::dai_frame_get_data(self.handle()) };
+        if data.is_null() {
+            return Err(DepthaiError::new("frame has no data"));
+        }
+        // SAFETY: the view borrows `self`, which keeps the frame alive, and the payload is only
+        // replaced or rewritten through `&mut self`; `BoxedRef` only exposes read-only access.
+        let mat = unsafe { Mat::new_rows_cols_with_data_unsafe(rows, cols, typ, data as *mut c_void, step) }
+            .map_err(cv_error)?;
+        Ok(BoxedRef::from(mat))
+    }
+
+    /// Convert the frame to an owned `cv::Mat`.
+    ///
+    /// Color formats (including NV12/YUV and planar) come back as BGR, grayscale and raw formats
+    /// keep a single channel. Requires `depthai-sys` built with `DEPTHAI_OPENCV_SUPPORT=1`.
+    ///
+    /// Mirrors C++: `ImgFrame::getCvFrame()`.
+    pub fn to_cv_mat(&self) -> Result<Mat> {
+        clear_error_flag();
+        let raw = unsafe { depthai::dai_frame_get_cv_frame(self.handle()) };
+        if raw.is_null() {
+            return Err(last_error("failed to convert frame to cv::Mat"));
+        }
+        let native = NativeMat(raw as *mut c_void);
+        let rows: i32 = unsafe { depthai::dai_cv_mat_get_rows(native.0 as _) }.into();
+        let cols: i32 = unsafe { depthai::dai_cv_mat_get_cols(native.0 as _) }.into();
+        let typ: i32 = unsafe { depthai::dai_cv_mat_get_type(native.0 as _) }.into();
+        let step: usize = unsafe { depthai::dai_cv_mat_get_step(native.0 as _) }.into();
+        let data = unsafe { depthai::dai_cv_mat_get_data(native.0 as _) };
+        if data.is_null() || rows <= 0 || cols <= 0 {
+            return Ok(Mat::default());
+        }
+        // The native Mat may come from a different OpenCV build than the `opencv` crate links,
+        // so hand over a copy instead of the object itself.
+        let view = unsafe { Mat::new_rows_cols_with_data_unsafe(rows, cols, typ, data as *mut c_void, step) }
+            .map_err(cv_error)?;
+        view.try_clone().map_err(cv_error)
+    }
+
+    /// Create a host-side frame holding a copy of `mat`, timestamped now.
+    ///
+    /// `mat` must use the layout [`as_cv_mat`](Self::as_cv_mat) produces for `frame_type`, e.g.
+    /// `CV_8UC3` for `BGR888i` or a `height * 3 / 2` row `CV_8UC1` for `NV12`.
+    pub fn from_cv_mat(mat: &impl MatTraitConst, frame_type: ImageFrameType) -> Result<Self> {
+        let (rows, cols, typ) = (mat.rows(), mat.cols(), mat.typ());
+        let height = match frame_type {
+            ImageFrameType::NV12 | ImageFrameType::NV21 | ImageFrameType::YUV420p => rows * 2 / 3,
+            _ => rows,
+        };
+        match cv_layout(frame_type, height) {
+            Some((expected, expected_rows)) if expected == typ && expected_rows == rows => {}
+            Some((expected, expected_rows)) => {
+                return Err(DepthaiError::new(format!(
+                    "{frame_type:?} frames need a type {expected} Mat with {expected_rows} rows, got type {typ} with {rows} rows"
+                )));
+            }
+            None => {
+                return Err(DepthaiError::new(format!("from_cv_mat does not support {frame_type:?} frames")));
+            }
+        }
+        if mat.is_continuous() {
+            Self::new(cols as u32, height as u32, frame_type, mat.data_bytes().map_err(cv_error)?)
+        } else {
+            let packed = mat.try_clone().map_err(cv_error)?;
+            Self::new(cols as u32, height as u32, frame_type, packed.data_bytes().map_err(cv_error)?)
+        }
+    }
+}
@@ -0,0 +1,611 @@
+//! Host-side depth frame helpers.
+//!
+//! [`DepthFrame`] decodes a `RAW16` depth frame once and answers point queries in meters,
+//! deprojects pixels with [`CameraIntrinsics`], and computes ROI depth the way DepthAI's
+//! `SpatialLocationCalculator` does, for pipelines that don't run that node on the device.
+//! [`DepthFrameView`] reads the same frame in place, or a crop of it, for per-frame statistics
+//! (min/max/median depth, invalid pixels) without decoding a copy.
+//! [`ConfidenceMap`] decodes `StereoDepth.confidenceMap` to mask unreliable depth pixels, and
+//! [`DepthFrame::to_pointcloud`] combines both into a confidence-filtered point cloud, e.g. on
+//! RVC2 where running `PointCloud` next to `StereoDepth` on the device is too costly:
+//!
+//! ```no_run
+//! # use depthai::{CalibrationHandler, ConfidenceMap, DepthFrame, DepthUnit, Result};
+//! # use depthai::camera::ImageFrame;
+//! # use depthai::common::CameraBoardSocket;
+//! # use depthai::depth::PointCloudConfig;
+//! # fn f(calib: &CalibrationHandler, depth: &ImageFrame, confidence: &ImageFrame) -> Result<()> {
+//! let depth = DepthFrame::new(depth, DepthUnit::Millimeter)?;
+//! let intrinsics = calib.camera_intrinsics(CameraBoardSocket::CamA, Some((depth.width(), depth.height())), true)?;
+//! let config = PointCloudConfig { confidence_threshold: 200, ..Default::default() };
+//! let points = depth.to_pointcloud(&intrinsics, Some(&ConfidenceMap::new(confidence)?), &config)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::calibration::CameraIntrinsics;
+use crate::camera::ImageFrame;
+use crate::common::{ImageFrameType, Rect};
+use crate::convert;
+use crate::error::{DepthaiError, Result};
+use crate::pointcloud::Point3fRGBA;
+use crate::rgbd::DepthUnit;
+
+/// How samples inside a ROI are reduced to one depth value.
+///
+/// Mirrors C++: `dai::SpatialLocationCalculatorAlgorithm`.
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DepthAlgorithm {
+    Average = 0,
+    Min = 1,
+    Max = 2,
+    Mode = 3,
+    #[default]
+    Median = 4,
+}
+
+impl DepthAlgorithm {
+    pub(crate) fn from_raw(value: i32) -> Self {
+        match value {
+            0 => Self::Average,
+            1 => Self::Min,
+            2 => Self::Max,
+            3 => Self::Mode,
+            _ => Self::Median,
+        }
+    }
+}
+
+/// ROI depth settings, see [`DepthFrame::roi_depth`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RoiDepthConfig {
+    /// Samples closer than this (meters) are ignored.
+    pub lower_threshold: f32,
+    /// Samples farther than this (meters) are ignored.
+    pub upper_threshold: f32,
+    pub algorithm: DepthAlgorithm,
+    /// Sample every `step`-th pixel in both directions (minimum 1).
+    pub step: usize,
+    /// Reject samples more than this many median absolute deviations (at least 1% of the
+    /// median) from the median before reducing. `None` keeps every sample within the thresholds.
+    pub outlier_mad_factor: Option<f32>,
+}
+
+impl Default for RoiDepthConfig {
+    fn default() -> Self {
+        Self {
+            lower_threshold: 0.0,
+            upper_threshold: f32::INFINITY,
+            algorithm: DepthAlgorithm::Median,
+            step: 1,
+            outlier_mad_factor: Some(3.0),
+        }
+    }
+}
+
+/// Result of [`DepthFrame::roi_depth`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RoiDepth {
+    /// Depth (Z) in meters.
+    pub depth: f32,
+    /// Samples used for `depth`.
+    pub samples: usize,
+    /// Valid samples dropped as outliers.
+    pub rejected: usize,
+}
+
+/// Settings of [`DepthFrame::to_pointcloud`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PointCloudConfig {
+    /// Pixels whose confidence is worse than this are skipped, on the scale of
+    /// [`ConfidenceMap`] (0 most confident, 255 least).
+    pub confidence_threshold: u8,
+    /// Use every `decimation`-th pixel in both directions; at least 1.
+    pub decimation: u32,
+    /// Points closer than this (meters) are skipped.
+    pub min_depth: f32,
+    /// Points farther than this (meters) are skipped.
+    pub max_depth: f32,
+    /// Keep one point per sampled pixel, skipped ones at the origin, like the organized layout
+    /// of [`PointCloudData::points`](crate::pointcloud::PointCloudData::points), instead of only
+    /// the valid points.
+    pub organized: bool,
+}
+
+impl Default for PointCloudConfig {
+    fn default() -> Self {
+        Self {
+            confidence_threshold: 245,
+            decimation: 1,
+            min_depth: 0.0,
+            max_depth: f32::INFINITY,
+            organized: false,
+        }
+    }
+}
+
+/// A decoded depth image.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DepthFrame {
+    width: u32,
+    height: u32,
+    unit: DepthUnit,
+    values: Vec<u16>,
+}
+
+impl DepthFrame {
+    /// Decode a `RAW16` depth frame whose values are in `unit` (StereoDepth and RGBD output
+    /// millimeters, see [`RgbdData::depth`](crate::rgbd::RgbdData::depth)).
+    pub fn new(frame: &ImageFrame, unit: DepthUnit) -> Result<Self> {
+        Self::from_raw(frame.width(), frame.height(), frame.to_u16_vec()?, unit)
+    }
+
+    /// Wrap already decoded depth values (row-major, `0` = invalid).
+    pub fn from_raw(width: u32, height: u32, mut values: Vec<u16>, unit: DepthUnit) -> Result<Self> {
+        let pixels = width as usize * height as usize;
+        if values.len() < pixels {
+            return Err(DepthaiError::new(format!(
+                "depth frame of {width}x{height} needs {pixels} values, got {}",
+                values.len()
+            )));
+        }
+        unit.scale_to_meters()?;
+        values.truncate(pixels);
+        Ok(Self { width, height, unit, values })
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn unit(&self) -> DepthUnit {
+        self.unit
+    }
+
+    /// This frame with its values converted to `unit`, rounded to the nearest raw value.
+    ///
+    /// Values too far for `unit` saturate at `u16::MAX`, and depths that round to zero become
+    /// invalid, so converting to a coarser unit (e.g. millimeters to meters) loses precision.
+    pub fn to_unit(&self, unit: DepthUnit) -> Result<DepthFrame> {
+        let factor = self.unit.scale_to(unit)?;
+        if factor == 1.0 {
+            return Ok(DepthFrame { unit, ..self.clone() });
+        }
+        let values = self
+            .values
+            .iter()
+            .map(|&v| (v as f32 * factor).round().min(u16::MAX as f32) as u16)
+            .collect();
+        Ok(DepthFrame { width: self.width, height: self.height, unit, values })
+    }
+
+    /// Raw values in [`unit`](Self::unit), row-major.
+    pub fn values(&self) -> &[u16] {
+        &self.values
+    }
+
+    fn scale(&self) -> f32 {
+        self.unit.meters_per_unit().unwrap_or(1.0)
+    }
+
+    /// Raw value at `(x, y)`, `None` outside the frame.
+    pub fn raw_at(&self, x: u32, y: u32) -> Option<u16> {
+        (x < self.width && y < self.height).then(|| self.values[(y * self.width + x) as usize])
+    }
+
+    /// Depth (Z) at `(x, y)` in meters, `None` outside the frame or where depth is invalid.
+    pub fn depth_at(&self, x: u32, y: u32) -> Option<f32> {
+        self.raw_at(x, y)
+            .filter(|&v| v != 0)
+            .map(|v| v as f32 * self.scale())
+    }
+
+    /// 3D point in meters (camera frame: X right, Y down, Z forward) seen at `(x, y)`.
+    ///
+    /// `intrinsics` must match this frame's resolution, e.g.
+    /// `calib.camera_intrinsics(socket, Some((depth.width(), depth.height())), true)`.
+    pub fn deproject(&self, x: u32, y: u32, intrinsics: &CameraIntrinsics) -> Option<[f32; 3]> {
+        let z = self.depth_at(x, y)?;
+        Some(intrinsics.deproject(x as f32, y as f32, z))
+    }
+
+    /// Depth of a region, in pixels or normalized (see [`Rect::denormalize`]).
+    ///
+    /// Samples outside the thresholds are ignored, outliers are rejected (see
+    /// [`RoiDepthConfig::outlier_mad_factor`]) and the rest are reduced with the configured
+    /// algorithm. Returns `None` if no valid sample remains.
+    pub fn roi_depth(&self, roi: Rect, config: &RoiDepthConfig) -> Option<RoiDepth> {
+        let roi = roi.denormalize(self.width, self.height);
+        let x0 = roi.x.max(0.0) as u32;
+        let y0 = roi.y.max(0.0) as u32;
+        let x1 = ((roi.x + roi.width).max(0.0) as u32).min(self.width);
+        let y1 = ((roi.y + roi.height).max(0.0) as u32).min(self.height);
+        let step = config.step.max(1);
+        let scale = self.scale();
+
+        let mut samples: Vec<f32> = (y0..y1)
+            .step_by(step)
+            .flat_map(|y| (x0..x1).step_by(step).map(move |x| (x, y)))
+            .filter_map(|(x, y)| self.raw_at(x, y))
+            .filter(|&v| v != 0)
+            .map(|v| v as f32 * scale)
+            .filter(|&d| d >= config.lower_threshold && d <= config.upper_threshold)
+            .collect();
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort_unstable_by(f32::total_cmp);
+
+        let valid = samples.len();
+        if let Some(factor) = config.outlier_mad_factor {
+            let median = median_sorted(&samples);
+            let mut deviations: Vec<f32> = samples.iter().map(|d| (d - median).abs()).collect();
+            deviations.sort_unstable_by(f32::total_cmp);
+            // Floor the MAD at 1% of the median so flat surfaces don't reject every sample.
+            let mad = median_sorted(&deviations).max(median * 0.01);
+            samples.retain(|d| (d - median).abs() <= factor * mad);
+        }
+
+        let depth = match config.algorithm {
+            DepthAlgorithm::Average => samples.iter().sum::<f32>() / samples.len() as f32,
+            DepthAlgorithm::Min => samples[0],
+            DepthAlgorithm::Max => samples[samples.len() - 1],
+            DepthAlgorithm::Median => median_sorted(&samples),
+            DepthAlgorithm::Mode => mode_sorted(&samples, scale),
+        };
+        Some(RoiDepth {
+            depth,
+            samples: samples.len(),
+            rejected: valid - samples.len(),
+        })
+    }
+
+    /// Invalidate (set to 0) every pixel whose confidence is worse than `threshold`, using the
+    /// same scale as `StereoDepth`'s confidence threshold. Returns the number of pixels cleared.
+    ///
+    /// The map must have the depth frame's resolution, i.e. no `set_output_size` scaling.
+    pub fn mask_low_confidence(&mut self, confidence: &ConfidenceMap, threshold: u8) -> Result<usize> {
+        if (confidence.width, confidence.height) != (self.width, self.height) {
+            return Err(DepthaiError::new(format!(
+                "confidence map is {}x{}, depth frame is {}x{}",
+                confidence.width, confidence.height, self.width, self.height
+            )));
+        }
+        let mut cleared = 0;
+        for (value, &c) in self.values.iter_mut().zip(&confidence.values) {
+            if c > threshold && *value != 0 {
+                *value = 0;
+                cleared += 1;
+            }
+        }
+        Ok(cleared)
+    }
+
+    /// Points in meters (camera frame: X right, Y down, Z forward) of the sampled pixels with
+    /// valid depth within the configured range and, given a `confidence` map of this frame's
+    /// resolution, at most [`PointCloudConfig::confidence_threshold`].
+    ///
+    /// Points are white; see [`RgbdData::to_pointcloud`](crate::rgbd::RgbdData::to_pointcloud)
+    /// for colored ones.
+    pub fn to_pointcloud(
+        &self,
+        intrinsics: &CameraIntrinsics,
+        confidence: Option<&ConfidenceMap>,
+        config: &PointCloudConfig,
+    ) -> Result<Vec<Point3fRGBA>> {
+        if let Some(confidence) = confidence {
+            if (confidence.width, confidence.height) != (self.width, self.height) {
+                return Err(DepthaiError::new(format!(
+                    "confidence map is {}x{}, depth frame is {}x{}",
+                    confidence.width, confidence.height, self.width, self.height
+                )));
+            }
+        }
+        if config.decimation == 0 {
+            return Err(DepthaiError::new("point cloud decimation must be at least 1"));
+        }
+        let step = config.decimation as usize;
+        let skipped = Point3fRGBA { x: 0.0, y: 0.0, z: 0.0, r: 0, g: 0, b: 0, a: 0 };
+        let mut points = Vec::new();
+        for y in (0..self.height).step_by(step) {
+            for x in (0..self.width).step_by(step) {
+                let confident = confidence
+                    .is_none_or(|c| c.values[(y * self.width + x) as usize] <= config.confidence_threshold);
+                let point = self
+                    .depth_at(x, y)
+                    .filter(|&z| confident && z >= config.min_depth && z <= config.max_depth)
+                    .map(|z| {
+                        let [px, py, pz] = intrinsics.deproject(x as f32, y as f32, z);
+                        Point3fRGBA { x: px, y: py, z: pz, r: 255, g: 255, b: 255, a: 255 }
+                    });
+                match point {
+                    Some(point) => points.push(point),
+                    None if config.organized => points.push(skipped),
+                    None => {}
+                }
+            }
+        }
+        Ok(points)
+    }
+
+    /// 3D location in meters of a region's center at its [`roi_depth`](Self::roi_depth), like
+    /// `SpatialLocationCalculator`'s `spatialCoordinates`.
+    pub fn roi_location(&self, roi: Rect, config: &RoiDepthConfig, intrinsics: &CameraIntrinsics) -> Option<[f32; 3]> {
+        let depth = self.roi_depth(roi, config)?;
+        let (cx, cy) = roi.denormalize(self.width, self.height).center();
+        Some(intrinsics.deproject(cx, cy, depth.depth))
+    }
+}
+
+/// Summary of the depth values of a [`DepthFrameView`], in meters.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DepthStats {
+    /// `None` when no pixel has valid depth.
+    pub min: Option<f32>,
+    pub max: Option<f32>,
+    pub median: Option<f32>,
+    /// Pixels with depth.
+    pub valid: usize,
+    /// Pixels without depth (raw value `0`).
+    pub invalid: usize,
+}
+
+/// A borrowed view of depth values, either a whole `RAW16` frame or a crop of one.
+///
+/// Unlike [`DepthFrame`] this doesn't copy: [`DepthFrameView::new`] reads the frame buffer in
+/// place (see [`ImageFrame::as_u16_slice`]), which is what per-frame statistics want.
+#[derive(Debug, Clone, Copy)]
+pub struct DepthFrameView<'a> {
+    /// Starts at the view's top-left pixel.
+    data: &'a [u16],
+    width: u32,
+    height: u32,
+    /// Distance between rows, in values.
+    stride: usize,
+    unit: DepthUnit,
+}
+
+impl<'a> DepthFrameView<'a> {
+    /// View a `RAW16` depth frame whose values are in `unit`, without copying it.
+    ///
+    /// Fails where [`ImageFrame::as_u16_slice`] does; decode with [`DepthFrame::new`] then.
+    pub fn new(frame: &'a ImageFrame, unit: DepthUnit) -> Result<Self> {
+        let data = frame.as_u16_slice()?;
+        let (width, height) = (frame.width(), frame.height());
+        let stride = match frame.stride() / 2 {
+            0 => width as usize,
+            stride => stride,
+        };
+        Self::from_slice(data, width, height, stride, unit)
+    }
+
+    /// View row-major depth values whose rows start `stride` values apart.
+    pub fn from_slice(data: &'a [u16], width: u32, height: u32, stride: usize, unit: DepthUnit) -> Result<Self> {
+        if stride < width as usize {
+            return Err(DepthaiError::new(format!("stride {stride} is smaller than the width {width}")));
+        }
+        let needed = if height == 0 { 0 } else { (height as usize - 1) * stride + width as usize };
+        if data.len() < needed {
+            return Err(DepthaiError::new(format!(
+                "depth view of {width}x{height} (stride {stride}) needs {needed} values, got {}",
+                data.len()
+            )));
+        }
+        unit.scale_to_meters()?;
+        Ok(Self { data, width, height, stride, unit })
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn unit(&self) -> DepthUnit {
+        self.unit
+    }
+
+    fn scale(&self) -> f32 {
+        self.unit.meters_per_unit().unwrap_or(1.0)
+    }
+
+    /// Raw values of row `y`, `None` outside the view.
+    pub fn row(&self, y: u32) -> Option<&'a [u16]> {
+        let start = y as usize * self.stride;
+        (y < self.height).then(|| &self.data[start..start + self.width as usize])
+    }
+
+    /// Raw values, row by row.
+    pub fn rows(&self) -> impl Iterator<Item = &'a [u16]> + '_ {
+        (0..self.height).filter_map(|y| self.row(y))
+    }
+
+    /// Raw value at `(x, y)`, `None` outside the view.
+    pub fn raw_at(&self, x: u32, y: u32) -> Option<u16> {
+        (x < self.width).then_some(())?;
+        self.row(y).map(|row| row[x as usize])
+    }
+
+    /// Depth (Z) at `(x, y)` in meters, `None` outside the view or where depth is invalid.
+    pub fn depth_at(&self, x: u32, y: u32) -> Option<f32> {
+        self.raw_at(x, y)
+            .filter(|&v| v != 0)
+            .map(|v| v as f32 * self.scale())
+    }
+
+    /// The part of this view inside `roi`, in pixels or normalized (see [`Rect::denormalize`]).
+    ///
+    /// The region is clamped to the view, so the crop may be smaller than `roi`, or empty.
+    pub fn crop(&self, roi: Rect) -> DepthFrameView<'a> {
+        let roi = roi.denormalize(self.width, self.height);
+        let x0 = (roi.x.max(0.0) as u32).min(self.width);
+        let y0 = (roi.y.max(0.0) as u32).min(self.height);
+        let x1 = ((roi.x + roi.width).max(0.0) as u32).clamp(x0, self.width);
+        let y1 = ((roi.y + roi.height).max(0.0) as u32).clamp(y0, self.height);
+        let (width, height) = match (x1 - x0, y1 - y0) {
+            (0, _) | (_, 0) => (0, 0),
+            size => size,
+        };
+        let start = if height == 0 { 0 } else { y0 as usize * self.stride + x0 as usize };
+        DepthFrameView {
+            data: &self.data[start.min(self.data.len())..],
+            width,
+            height,
+            ..*self
+        }
+    }
+
+    fn valid_values(&self) -> impl Iterator<Item = u16> + '_ {
+        self.rows().flatten().copied().filter(|&v| v != 0)
+    }
+
+    /// Pixels without depth (raw value `0`).
+    pub fn invalid_count(&self) -> usize {
+        self.rows().flatten().filter(|&&v| v == 0).count()
+    }
+
+    /// Nearest valid depth in meters.
+    pub fn min_depth(&self) -> Option<f32> {
+        self.valid_values().min().map(|v| v as f32 * self.scale())
+    }
+
+    /// Farthest valid depth in meters.
+    pub fn max_depth(&self) -> Option<f32> {
+        self.valid_values().max().map(|v| v as f32 * self.scale())
+    }
+
+    /// Median of the valid depths in meters.
+    pub fn median_depth(&self) -> Option<f32> {
+        let mut values: Vec<u16> = self.valid_values().collect();
+        median_raw(&mut values).map(|v| v * self.scale())
+    }
+
+    /// All of the above in one pass over the view.
+    pub fn stats(&self) -> DepthStats {
+        let mut values: Vec<u16> = self.valid_values().collect();
+        let scale = self.scale();
+        let pixels = self.width as usize * self.height as usize;
+        DepthStats {
+            min: values.iter().min().map(|&v| v as f32 * scale),
+            max: values.iter().max().map(|&v| v as f32 * scale),
+            median: median_raw(&mut values).map(|v| v * scale),
+            valid: values.len(),
+            invalid: pixels - values.len(),
+        }
+    }
+}
+
+impl DepthFrame {
+    /// Borrow this frame as a [`DepthFrameView`], e.g. to compute statistics of a crop.
+    pub fn view(&self) -> DepthFrameView<'_> {
+        DepthFrameView {
+            data: &self.values,
+            width: self.width,
+            height: self.height,
+            stride: self.width as usize,
+            unit: self.unit,
+        }
+    }
+}
+
+/// A decoded `StereoDepth.confidenceMap` frame.
+///
+/// Values run from 0 (most confident) to 255 (least), matching
+/// `StereoDepthConfig::setConfidenceThreshold`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfidenceMap {
+    width: u32,
+    height: u32,
+    values: Vec<u8>,
+}
+
+impl ConfidenceMap {
+    /// Decode an 8-bit (`RAW8`/`GRAY8`) confidence frame.
+    pub fn new(frame: &ImageFrame) -> Result<Self> {
+        match frame.format() {
+            Some(ImageFrameType::RAW8 | ImageFrameType::GRAY8) => {}
+            other => {
+                return Err(DepthaiError::new(format!("confidence maps must be RAW8 or GRAY8, got {other:?}")));
+            }
+        }
+        Self::from_raw(frame.width(), frame.height(), convert::to_gray8(frame)?)
+    }
+
+    /// Wrap already decoded confidence values (row-major).
+    pub fn from_raw(width: u32, height: u32, mut values: Vec<u8>) -> Result<Self> {
+        let pixels = width as usize * height as usize;
+        if values.len() < pixels {
+            return Err(DepthaiError::new(format!(
+                "confidence map of {width}x{height} needs {pixels} values, got {}",
+                values.len()
+            )));
+        }
+        values.truncate(pixels);
+        Ok(Self { width, height, values })
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn values(&self) -> &[u8] {
+        &self.values
+    }
+
+    /// Confidence at `(x, y)`, `None` outside the map.
+    pub fn at(&self, x: u32, y: u32) -> Option<u8> {
+        (x < self.width && y < self.height).then(|| self.values[(y * self.width + x) as usize])
+    }
+}
+
+/// Median of raw values (reordering them), as the average of the middle two for even counts.
+fn median_raw(values: &mut [u16]) -> Option<f32> {
+    let n = values.len();
+    if n == 0 {
+        return None;
+    }
+    let (below, &mut upper, _) = values.select_nth_unstable(n / 2);
+    Some(if n & 1 == 1 {
+        upper as f32
+    } else {
+        let lower = below.iter().copied().max().unwrap_or(upper);
+        (lower as f32 + upper as f32) / 2.0
+    })
+}
+
+fn median_sorted(sorted: &[f32]) -> f32 {
+    let n = sorted.len();
+    if n & 1 == 1 {
+        sorted[n / 2]
+    } else {
+        (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0
+    }
+}
+
+/// Most frequent value of a sorted slice (quantized to one raw unit of `resolution` meters).
+fn mode_sorted(sorted: &[f32], resolution: f32) -> f32 {
+    let mut best = (sorted[0], 0usize);
+    let mut run = (sorted[0], 0usize);
+    for &d in sorted {
+        if (d - run.0).abs() < resolution / 2.0 {
+            run.1 += 1;
+        } else {
+            run = (d, 1);
+        }
+        if run.1 > best.1 {
+            best = run;
+        }
+    }
+    best.0
+}
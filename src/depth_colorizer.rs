@@ -0,0 +1,267 @@
+//! Turning depth and disparity frames into RGB images.
+//!
+//! [`DepthColorizer`] sits between a [`StereoDepthNode`](crate::stereo_depth::StereoDepthNode)
+//! output and whatever displays or logs the result, and emits `RGB888i` frames. When the config
+//! allows it, the work runs on the device in an [`ImageManipNode`] colormap; a fixed range,
+//! masking of invalid pixels or depth (rather than disparity) input need the host path, a
+//! threaded host node using [`colorize`]. Near is always at the warm end of the colormap.
+//!
+//! ```no_run
+//! # use depthai::{Pipeline, Result};
+//! # use depthai::depth_colorizer::{DepthColorizer, DepthColorizerConfig};
+//! # use depthai::stereo_depth::StereoDepthNode;
+//! # fn main() -> Result<()> {
+//! # let pipeline = Pipeline::new().build()?;
+//! let stereo = pipeline.create::<StereoDepthNode>()?;
+//! let colorizer = pipeline.create_with::<DepthColorizer, _>(DepthColorizerConfig {
+//!     range: Some((300, 5000)),
+//!     ..DepthColorizerConfig::default()
+//! })?;
+//! stereo.depth()?.link(&colorizer.input()?)?;
+//! let colored = colorizer.out()?.create_queue(4, false)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::camera::ImageFrame;
+use crate::common::ImageFrameType;
+use crate::convert;
+use crate::error::{DepthaiError, Result};
+use crate::image_manip::{Colormap, ImageManipNode};
+use crate::output::{TypedInput, TypedOutput};
+use crate::pipeline::{Node, Pipeline};
+use crate::threaded_host_node::{ThreadedHostNode, ThreadedHostNodeContext, ThreadedHostNodeImpl};
+use crate::CreateInPipelineWith;
+
+/// Largest RGB frame the device path produces (1920x1200, the biggest stereo resolution).
+const MAX_DEVICE_OUTPUT_BYTES: i32 = 1920 * 1200 * 3;
+
+/// What the values of the input frames mean.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DepthColorizerInput {
+    /// `RAW16` depth (StereoDepth `depth`): small values are near.
+    Depth,
+    /// `RAW8`/`GRAY8` or `RAW16` disparity (StereoDepth `disparity`): large values are near.
+    Disparity,
+}
+
+/// Where a [`DepthColorizer`] runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorizerBackend {
+    /// On the device when [`DepthColorizerConfig::supports_device`], on the host otherwise.
+    Auto,
+    /// Always an `ImageManip` colormap; settings it can't honor are ignored.
+    Device,
+    Host,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DepthColorizerConfig {
+    pub input: DepthColorizerInput,
+    /// `Colormap::None` produces gray images.
+    pub colormap: Colormap,
+    /// Values mapped onto the colormap, in input units (millimeters for StereoDepth depth);
+    /// values outside are clamped. `None` stretches each frame to its own valid range.
+    pub range: Option<(u16, u16)>,
+    /// Paint pixels without a measurement (value `0`) black. The `Stereo*` colormaps always do.
+    pub mask_invalid: bool,
+    pub backend: ColorizerBackend,
+}
+
+impl Default for DepthColorizerConfig {
+    fn default() -> Self {
+        Self {
+            input: DepthColorizerInput::Depth,
+            colormap: Colormap::Turbo,
+            range: None,
+            mask_invalid: true,
+            backend: ColorizerBackend::Auto,
+        }
+    }
+}
+
+impl DepthColorizerConfig {
+    /// Whether an `ImageManip` colormap produces the same result: disparity input, no fixed
+    /// range, and invalid pixels either unmasked or masked by a `Stereo*` colormap.
+    pub fn supports_device(&self) -> bool {
+        self.input == DepthColorizerInput::Disparity
+            && self.range.is_none()
+            && (!self.mask_invalid || masks_zero(self.colormap))
+    }
+
+    fn runs_on_device(&self) -> bool {
+        match self.backend {
+            ColorizerBackend::Auto => self.supports_device(),
+            ColorizerBackend::Device => true,
+            ColorizerBackend::Host => false,
+        }
+    }
+}
+
+fn masks_zero(colormap: Colormap) -> bool {
+    matches!(colormap, Colormap::StereoTurbo | Colormap::StereoJet)
+}
+
+/// Color of `t` in `0.0..=1.0` (clamped) on `colormap`, as RGB.
+pub fn colormap_color(colormap: Colormap, t: f32) -> [u8; 3] {
+    let t = t.clamp(0.0, 1.0);
+    let [r, g, b] = match colormap {
+        Colormap::None => [t, t, t],
+        Colormap::Turbo | Colormap::StereoTurbo => {
+            // Polynomial approximation of Google's Turbo colormap.
+            let r = 0.135_721_38
+                + t * (4.615_392_6 + t * (-42.660_32 + t * (132.131_08 + t * (-152.942_4 + t * 59.286_38))));
+            let g = 0.091_402_61
+                + t * (2.194_188_4 + t * (4.842_966_6 + t * (-14.185_033 + t * (4.277_298_5 + t * 2.829_566))));
+            let b = 0.106_673_3
+                + t * (12.641_946 + t * (-60.582_05 + t * (110.362_77 + t * (-89.903_11 + t * 27.348_25))));
+            [r, g, b]
+        }
+        Colormap::Jet | Colormap::StereoJet => {
+            let channel = |center: f32| 1.5 - (4.0 * t - center).abs();
+            [channel(3.0), channel(2.0), channel(1.0)]
+        }
+    };
+    [r, g, b].map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8)
+}
+
+/// Colorize row-major depth or disparity values into an `RGB888i` buffer.
+pub fn colorize(values: &[u16], config: &DepthColorizerConfig) -> Vec<u8> {
+    let lut: Vec<[u8; 3]> = (0..=255u32)
+        .map(|i| colormap_color(config.colormap, i as f32 / 255.0))
+        .collect();
+    let (lo, hi) = config
+        .range
+        .map(|(a, b)| (a.min(b), a.max(b)))
+        .or_else(|| convert::gray16_min_max(values))
+        .unwrap_or((0, u16::MAX));
+    let span = (hi - lo).max(1) as u32;
+    let mask = config.mask_invalid || masks_zero(config.colormap);
+
+    let mut rgb = Vec::with_capacity(values.len() * 3);
+    for &v in values {
+        if v == 0 && mask {
+            rgb.extend_from_slice(&[0, 0, 0]);
+            continue;
+        }
+        let level = (v.clamp(lo, hi) - lo) as u32 * 255 / span;
+        let level = match config.input {
+            DepthColorizerInput::Depth => 255 - level,
+            DepthColorizerInput::Disparity => level,
+        };
+        rgb.extend_from_slice(&lut[level as usize]);
+    }
+    rgb
+}
+
+/// Colorize a `RAW16`, `RAW8` or `GRAY8` frame, keeping its timestamp and sequence number.
+pub fn colorize_frame(frame: &ImageFrame, config: &DepthColorizerConfig) -> Result<ImageFrame> {
+    let (w, h) = (frame.width() as usize, frame.height() as usize);
+    let values: Vec<u16> = match frame.format() {
+        Some(ImageFrameType::RAW16) => {
+            convert::raw16_to_u16(&convert::compact_rows(&frame.bytes(), h, w * 2, frame.stride())?)
+        }
+        Some(ImageFrameType::RAW8 | ImageFrameType::GRAY8) => {
+            convert::to_gray8(frame)?.into_iter().map(u16::from).collect()
+        }
+        other => {
+            return Err(DepthaiError::new(format!(
+                "cannot colorize {other:?} frames, expected RAW16, RAW8 or GRAY8"
+            )));
+        }
+    };
+    let colored = ImageFrame::new(frame.width(), frame.height(), ImageFrameType::RGB888i, &colorize(&values, config))?;
+    colored.set_timestamp(frame.timestamp()?)?;
+    colored.set_sequence_num(frame.sequence_num()?)?;
+    Ok(colored)
+}
+
+struct HostColorizerImpl {
+    input: TypedInput<ImageFrame>,
+    out: TypedOutput<ImageFrame>,
+    config: DepthColorizerConfig,
+}
+
+impl ThreadedHostNodeImpl for HostColorizerImpl {
+    fn run(&mut self, ctx: &ThreadedHostNodeContext) {
+        loop {
+            match ctx.next::<ImageFrame, _>(&self.input) {
+                Ok(Some(frame)) => {
+                    let result = colorize_frame(&frame, &self.config).and_then(|colored| self.out.send(&colored));
+                    if let Err(e) = result {
+                        ctx.report_error(&e);
+                    }
+                }
+                Ok(None) => break,
+                Err(e) => {
+                    ctx.report_error(&e);
+                    break;
+                }
+            }
+        }
+    }
+}
+
+enum Backend {
+    Device(ImageManipNode),
+    Host(ThreadedHostNode),
+}
+
+/// Depth/disparity to RGB, see the [module docs](self).
+pub struct DepthColorizer {
+    backend: Backend,
+}
+
+impl DepthColorizer {
+    /// The `ImageManip` or host node doing the work.
+    pub fn as_node(&self) -> &Node {
+        match &self.backend {
+            Backend::Device(manip) => manip.as_node(),
+            Backend::Host(node) => node.as_node(),
+        }
+    }
+
+    pub fn runs_on_device(&self) -> bool {
+        matches!(self.backend, Backend::Device(_))
+    }
+
+    /// Depth or disparity frames.
+    pub fn input(&self) -> Result<TypedInput<ImageFrame>> {
+        match &self.backend {
+            Backend::Device(manip) => manip.inputImage(),
+            Backend::Host(node) => node.as_node().input("in").map(TypedInput::from_untyped),
+        }
+    }
+
+    /// `RGB888i` frames.
+    pub fn out(&self) -> Result<TypedOutput<ImageFrame>> {
+        match &self.backend {
+            Backend::Device(manip) => manip.out(),
+            Backend::Host(node) => node.as_node().output("out").map(TypedOutput::from_untyped),
+        }
+    }
+}
+
+impl CreateInPipelineWith<DepthColorizerConfig> for DepthColorizer {
+    fn create_with(pipeline: &Pipeline, config: DepthColorizerConfig) -> Result<Self> {
+        let backend = if config.runs_on_device() {
+            let manip = pipeline.create::<ImageManipNode>()?;
+            manip
+                .initial_config()?
+                .set_colormap(config.colormap)
+                .set_frame_type(ImageFrameType::RGB888i);
+            manip.set_max_output_frame_size(MAX_DEVICE_OUTPUT_BYTES);
+            Backend::Device(manip)
+        } else {
+            let node = pipeline.create_threaded_host_node(|node| {
+                Ok(HostColorizerImpl {
+                    input: TypedInput::from_untyped(node.create_input(Some("in"))?),
+                    out: node.create_typed_output("out")?,
+                    config,
+                })
+            })?;
+            Backend::Host(node)
+        };
+        Ok(Self { backend })
+    }
+}
@@ -0,0 +1,155 @@
+//! Decoding raw network outputs into [`ImgDetections`].
+//!
+//! [`DetectionParserNode`] runs DepthAI's built-in decoders (YOLO, SSD, ...) on the output of a
+//! [`NeuralNetworkNode`](crate::nn_archive::NeuralNetworkNode). Models those decoders don't know
+//! can be decoded on the host with [`create_custom_detection_parser`], which hands each
+//! [`NNData`] to a Rust callback and sends the detections it returns.
+//!
+//! ```no_run
+//! # use depthai::{ImgDetection, Pipeline, Result};
+//! # use depthai::detection_parser::create_custom_detection_parser;
+//! # use depthai::nn_archive::NeuralNetworkNode;
+//! # fn main() -> Result<()> {
+//! # let pipeline = Pipeline::new().build()?;
+//! let nn = pipeline.create::<NeuralNetworkNode>()?;
+//! let parser = create_custom_detection_parser(&pipeline, |data| {
+//!     // One row per box: [xmin, ymin, xmax, ymax, score, label].
+//!     let boxes = data.tensor("output")?;
+//!     Ok(boxes
+//!         .data
+//!         .chunks_exact(6)
+//!         .filter(|b| b[4] > 0.5)
+//!         .map(|b| ImgDetection { label: b[5] as u32, confidence: b[4], xmin: b[0], ymin: b[1], xmax: b[2], ymax: b[3] })
+//!         .collect())
+//! })?;
+//! nn.out()?.link(&parser.input()?)?;
+//! let detections = parser.out()?.create_queue(4, false)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use autocxx::c_int;
+use depthai_sys::depthai;
+
+use crate::detections::{ImgDetection, ImgDetections};
+use crate::error::{clear_error_flag, last_error, Result};
+use crate::nn_archive::NNArchive;
+use crate::nn_data::NNData;
+use crate::output::{TypedInput, TypedOutput};
+use crate::pipeline::{Node, Pipeline};
+use crate::threaded_host_node::{ThreadedHostNode, ThreadedHostNodeContext, ThreadedHostNodeImpl};
+
+#[crate::native_node_wrapper(native = "dai::node::DetectionParser", inputs(input: NNData), outputs(out: ImgDetections))]
+pub struct DetectionParserNode {
+    node: crate::pipeline::Node,
+}
+
+impl DetectionParserNode {
+    /// Take the decoder, classes and thresholds from the head of `archive`.
+    ///
+    /// Mirrors C++: `DetectionParser::setNNArchive(nnArchive)`.
+    pub fn set_nn_archive(&self, archive: &NNArchive) -> Result<()> {
+        clear_error_flag();
+        let ok = unsafe { depthai::dai_detection_parser_set_nn_archive(self.node.handle(), archive.handle()) };
+        if ok { Ok(()) } else { Err(last_error("failed to set DetectionParser archive")) }
+    }
+
+    pub fn set_confidence_threshold(&self, threshold: f32) {
+        clear_error_flag();
+        unsafe { depthai::dai_detection_parser_set_confidence_threshold(self.node.handle(), threshold) };
+    }
+
+    pub fn set_num_classes(&self, num_classes: u32) {
+        clear_error_flag();
+        unsafe { depthai::dai_detection_parser_set_num_classes(self.node.handle(), c_int(num_classes as i32)) };
+    }
+
+    /// Overlap above which non-maximum suppression drops the weaker box.
+    pub fn set_iou_threshold(&self, threshold: f32) {
+        clear_error_flag();
+        unsafe { depthai::dai_detection_parser_set_iou_threshold(self.node.handle(), threshold) };
+    }
+}
+
+struct CustomParserImpl<F> {
+    input: TypedInput<NNData>,
+    out: TypedOutput<ImgDetections>,
+    parse: F,
+}
+
+impl<F> CustomParserImpl<F>
+where
+    F: FnMut(&NNData) -> Result<Vec<ImgDetection>> + Send + 'static,
+{
+    fn handle(&mut self, data: &NNData) -> Result<()> {
+        let mut detections = ImgDetections::new()?;
+        for detection in (self.parse)(data)? {
+            detections.push(detection)?;
+        }
+        detections.copy_metadata_from(data)?;
+        self.out.send(&detections)
+    }
+}
+
+impl<F> ThreadedHostNodeImpl for CustomParserImpl<F>
+where
+    F: FnMut(&NNData) -> Result<Vec<ImgDetection>> + Send + 'static,
+{
+    fn run(&mut self, ctx: &ThreadedHostNodeContext) {
+        loop {
+            match ctx.next::<NNData, _>(&self.input) {
+                Ok(Some(data)) => {
+                    // A message that fails to parse is dropped; the node keeps going.
+                    if let Err(e) = self.handle(&data) {
+                        ctx.report_error(&e);
+                    }
+                }
+                Ok(None) => break,
+                Err(e) => {
+                    ctx.report_error(&e);
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// Host node running a Rust detection decoder, see [`create_custom_detection_parser`].
+#[derive(Clone)]
+pub struct CustomDetectionParser {
+    node: ThreadedHostNode,
+}
+
+impl CustomDetectionParser {
+    pub fn as_node(&self) -> &Node {
+        self.node.as_node()
+    }
+
+    /// Network outputs to decode; link a `NeuralNetworkNode`'s `out` here.
+    pub fn input(&self) -> Result<TypedInput<NNData>> {
+        self.as_node().input("in").map(TypedInput::from_untyped)
+    }
+
+    /// Decoded detections, with the timestamps and sequence number of their [`NNData`].
+    pub fn out(&self) -> Result<TypedOutput<ImgDetections>> {
+        self.as_node().output("out").map(TypedOutput::from_untyped)
+    }
+}
+
+/// Create a host node that decodes each incoming [`NNData`] with `parse`.
+///
+/// Errors returned by `parse` drop that message and are recorded on the pipeline, see
+/// [`Pipeline::take_node_errors`].
+pub fn create_custom_detection_parser<F>(pipeline: &Pipeline, parse: F) -> Result<CustomDetectionParser>
+where
+    F: FnMut(&NNData) -> Result<Vec<ImgDetection>> + Send + 'static,
+{
+    let node = pipeline.create_threaded_host_node(|node| {
+        Ok(CustomParserImpl {
+            input: TypedInput::from_untyped(node.create_input(Some("in"))?),
+            out: node.create_typed_output("out")?,
+            parse,
+        })
+    })?;
+    Ok(CustomDetectionParser { node })
+}
@@ -0,0 +1,213 @@
+use std::time::Duration;
+
+use autocxx::c_int;
+use depthai_sys::{depthai, DaiImgDetections};
+
+use crate::common::Rect;
+use crate::error::{clear_error_flag, last_error, take_error_if_any, Result};
+use crate::host_node::{duration_to_ns, ns_to_duration, Buffer};
+use crate::nn_data::NNData;
+
+/// One detected object.
+///
+/// Mirrors C++: `dai::ImgDetection`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ImgDetection {
+    pub label: u32,
+    pub confidence: f32,
+    /// Bounding box, normalized to `0..1` of the input frame.
+    pub xmin: f32,
+    pub ymin: f32,
+    pub xmax: f32,
+    pub ymax: f32,
+}
+
+impl ImgDetection {
+    /// Bounding box as a normalized [`Rect`].
+    pub fn bbox(&self) -> Rect {
+        Rect::new(self.xmin, self.ymin, self.xmax - self.xmin, self.ymax - self.ymin)
+    }
+
+    fn area(&self) -> f32 {
+        (self.xmax - self.xmin).max(0.0) * (self.ymax - self.ymin).max(0.0)
+    }
+
+    /// Intersection over union of the two boxes, `0` when either is empty.
+    pub fn iou(&self, other: &ImgDetection) -> f32 {
+        let w = (self.xmax.min(other.xmax) - self.xmin.max(other.xmin)).max(0.0);
+        let h = (self.ymax.min(other.ymax) - self.ymin.max(other.ymin)).max(0.0);
+        let intersection = w * h;
+        let union = self.area() + other.area() - intersection;
+        if union <= 0.0 { 0.0 } else { intersection / union }
+    }
+}
+
+/// Non-maximum suppression: keep the most confident detection of each cluster of boxes of the
+/// same label overlapping by more than `iou_threshold`.
+///
+/// Returns the kept detections by decreasing confidence. Detections of different labels never
+/// suppress each other.
+pub fn nms(detections: &[ImgDetection], iou_threshold: f32) -> Vec<ImgDetection> {
+    let mut sorted = detections.to_vec();
+    sorted.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+    let mut kept: Vec<ImgDetection> = Vec::with_capacity(sorted.len());
+    for d in sorted {
+        if !kept.iter().any(|k| k.label == d.label && k.iou(&d) > iou_threshold) {
+            kept.push(d);
+        }
+    }
+    kept
+}
+
+/// Detections of one frame.
+///
+/// Mirrors C++: `dai::ImgDetections`. `SpatialImgDetections` messages are also accepted,
+/// without their spatial coordinates.
+pub struct ImgDetections {
+    handle: DaiImgDetections,
+}
+
+unsafe impl Send for ImgDetections {}
+unsafe impl Sync for ImgDetections {}
+
+impl Drop for ImgDetections {
+    fn drop(&mut self) {
+        if !self.handle.is_null() {
+            unsafe { depthai::dai_img_detections_release(self.handle) };
+            self.handle = std::ptr::null_mut();
+        }
+    }
+}
+
+impl ImgDetections {
+    pub(crate) fn from_handle(handle: DaiImgDetections) -> Self {
+        Self { handle }
+    }
+
+    pub(crate) fn handle(&self) -> DaiImgDetections {
+        self.handle
+    }
+
+    /// Empty detections, to be filled by a host node.
+    pub fn new() -> Result<Self> {
+        clear_error_flag();
+        let handle = depthai::dai_img_detections_new();
+        if handle.is_null() {
+            Err(last_error("failed to create detections"))
+        } else {
+            Ok(Self { handle })
+        }
+    }
+
+    /// Append a detection. Only detections created with [`ImgDetections::new`] can be extended.
+    pub fn push(&mut self, detection: ImgDetection) -> Result<()> {
+        let bbox = [detection.xmin, detection.ymin, detection.xmax, detection.ymax];
+        clear_error_flag();
+        let ok = unsafe {
+            depthai::dai_img_detections_push(self.handle, c_int(detection.label as i32), detection.confidence, bbox.as_ptr())
+        };
+        if ok {
+            Ok(())
+        } else {
+            Err(last_error("failed to add detection"))
+        }
+    }
+
+    /// Take timestamps and sequence number from the network output these detections were
+    /// decoded from.
+    pub fn copy_metadata_from(&mut self, source: &NNData) -> Result<()> {
+        clear_error_flag();
+        unsafe { depthai::dai_img_detections_copy_metadata(self.handle, source.handle()) };
+        if let Some(err) = take_error_if_any("failed to copy detections metadata") {
+            Err(err)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// View as a generic [`Buffer`], e.g. to return from a [`HostNodeImpl`](crate::host_node::HostNodeImpl).
+    pub fn to_buffer(&self) -> Result<Buffer> {
+        clear_error_flag();
+        let handle = unsafe { depthai::dai_img_detections_to_buffer(self.handle) };
+        if handle.is_null() {
+            Err(last_error("failed to convert detections to buffer"))
+        } else {
+            Ok(Buffer::from_handle(handle))
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        unsafe { depthai::dai_img_detections_get_count(self.handle) }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Timestamp on the host steady clock.
+    pub fn timestamp(&self) -> Result<Duration> {
+        clear_error_flag();
+        let ns = unsafe { depthai::dai_img_detections_get_timestamp_ns(self.handle) };
+        if let Some(err) = take_error_if_any("failed to get detections timestamp") {
+            Err(err)
+        } else {
+            Ok(ns_to_duration(ns))
+        }
+    }
+
+    pub fn set_timestamp(&self, timestamp: Duration) -> Result<()> {
+        clear_error_flag();
+        unsafe { depthai::dai_img_detections_set_timestamp_ns(self.handle, duration_to_ns(timestamp)) };
+        if let Some(err) = take_error_if_any("failed to set detections timestamp") {
+            Err(err)
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn sequence_num(&self) -> Result<i64> {
+        clear_error_flag();
+        let seq = unsafe { depthai::dai_img_detections_get_sequence_num(self.handle) };
+        if let Some(err) = take_error_if_any("failed to get detections sequence number") {
+            Err(err)
+        } else {
+            Ok(seq)
+        }
+    }
+
+    pub fn set_sequence_num(&self, seq: i64) -> Result<()> {
+        clear_error_flag();
+        unsafe { depthai::dai_img_detections_set_sequence_num(self.handle, seq) };
+        if let Some(err) = take_error_if_any("failed to set detections sequence number") {
+            Err(err)
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn detections(&self) -> Result<Vec<ImgDetection>> {
+        (0..self.len()).map(|i| self.detection(i)).collect()
+    }
+
+    pub fn detection(&self, index: usize) -> Result<ImgDetection> {
+        let mut label = c_int(0);
+        let mut confidence = 0f32;
+        let mut bbox = [0f32; 4];
+        clear_error_flag();
+        let ok = unsafe {
+            depthai::dai_img_detections_get(self.handle, index, &mut label, &mut confidence, bbox.as_mut_ptr())
+        };
+        if !ok {
+            return Err(last_error("failed to read detection"));
+        }
+        Ok(ImgDetection {
+            label: label.0.max(0) as u32,
+            confidence,
+            xmin: bbox[0],
+            ymin: bbox[1],
+            xmax: bbox[2],
+            ymax: bbox[3],
+        })
+    }
+}
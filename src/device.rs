@@ -1,14 +1,68 @@
 use autocxx::c_int;
 use depthai_sys::{depthai, DaiDevice};
+use std::ffi::{c_char, c_void, CStr, CString};
 use std::os::raw::c_int as RawInt;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{LazyLock, Mutex, MutexGuard};
+use std::time::Duration;
 
+use crate::cancel::CancellationToken;
 use crate::common::CameraBoardSocket;
-use crate::error::{Result, clear_error_flag, last_error, take_error_if_any};
+use crate::error::{DepthaiError, Result, clear_error_flag, last_error, take_error_if_any};
+use crate::pipeline::take_owned_json_string;
+use serde::{Deserialize, Serialize};
 
 const MAX_SOCKETS: usize = 16;
 
 pub struct Device {
     handle: DaiDevice,
+    /// Set for [`Device::mock`] devices, which have no native handle.
+    #[cfg(feature = "mock")]
+    pub(crate) mock: Option<std::sync::Arc<crate::mock::MockDevice>>,
+}
+
+/// Severity of a device log message.
+///
+/// Mirrors C++: `dai::LogLevel`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+    Critical,
+    Off,
+}
+
+impl LogLevel {
+    pub(crate) fn from_raw(value: i32) -> Self {
+        match value {
+            0 => Self::Trace,
+            1 => Self::Debug,
+            2 => Self::Info,
+            3 => Self::Warn,
+            4 => Self::Error,
+            5 => Self::Critical,
+            _ => Self::Off,
+        }
+    }
+}
+
+/// A log line emitted by the device firmware.
+///
+/// Mirrors C++: `dai::LogMessage`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FirmwareLogMessage {
+    /// Emitting component, e.g. a node id and name.
+    pub node_id_name: String,
+    pub level: LogLevel,
+    /// Device timestamp.
+    pub time: Duration,
+    pub payload: String,
 }
 
 #[repr(i32)]
@@ -19,9 +73,230 @@ pub enum DevicePlatform {
     Rvc4 = 2,
 }
 
+/// A device XLink can see, see [`Device::list_available`].
+///
+/// Mirrors C++: `dai::DeviceInfo`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeviceInfo {
+    /// MXID on RVC2, serial number on RVC4; pass to [`DeviceBuilder::device_id`].
+    #[serde(rename = "deviceId")]
+    pub device_id: String,
+    /// USB path or IP address.
+    pub name: String,
+    /// XLink state, e.g. `X_LINK_UNBOOTED` (free) or `X_LINK_BOOTED` (in use).
+    pub state: String,
+    /// XLink protocol, e.g. `X_LINK_USB_VSC` or `X_LINK_TCP_IP`.
+    pub protocol: String,
+    /// XLink platform, e.g. `X_LINK_MYRIAD_X` or `X_LINK_RVC4`.
+    pub platform: String,
+}
+
+/// Die temperatures in degrees Celsius.
+///
+/// Mirrors C++: `dai::ChipTemperature`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChipTemperature {
+    pub css: f32,
+    pub mss: f32,
+    pub upa: f32,
+    pub dss: f32,
+    pub average: f32,
+}
+
+/// USB link speed.
+///
+/// Mirrors C++: `dai::UsbSpeed`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(into = "i32", try_from = "i32")]
+pub enum UsbSpeed {
+    Unknown = 0,
+    Low = 1,
+    /// USB 1.1 (12 Mbps).
+    Full = 2,
+    /// USB 2.0 (480 Mbps).
+    High = 3,
+    /// USB 3.x Gen 1 (5 Gbps).
+    Super = 4,
+    /// USB 3.x Gen 2 (10 Gbps).
+    SuperPlus = 5,
+}
+
+impl UsbSpeed {
+    fn from_raw(value: i32) -> Option<Self> {
+        Some(match value {
+            0 => Self::Unknown,
+            1 => Self::Low,
+            2 => Self::Full,
+            3 => Self::High,
+            4 => Self::Super,
+            5 => Self::SuperPlus,
+            _ => return None,
+        })
+    }
+}
+
+impl From<UsbSpeed> for i32 {
+    fn from(speed: UsbSpeed) -> Self {
+        speed as i32
+    }
+}
+
+impl TryFrom<i32> for UsbSpeed {
+    type Error = DepthaiError;
+
+    fn try_from(value: i32) -> Result<Self> {
+        Self::from_raw(value).ok_or_else(|| DepthaiError::new(format!("invalid USB speed {value}")))
+    }
+}
+
+/// Builder for opening a [`Device`] with connection settings.
+///
+/// Mirrors C++: `dai::DeviceBase::Config`. Unlike [`Device::new`], this always opens a new
+/// connection instead of reusing the process-wide default device.
+///
+/// # Example
+/// ```no_run
+/// # use std::time::Duration;
+/// # use depthai::{Device, Result, UsbSpeed};
+/// # fn main() -> Result<()> {
+/// // A USB2-only host with a long cable.
+/// let device = Device::builder()
+///     .max_usb_speed(UsbSpeed::High)
+///     .watchdog_timeout(Duration::from_secs(4))
+///     .watchdog_initial_delay(Duration::from_secs(8))
+///     .firmware_path("firmware/depthai-device-fwp.mvcmd")
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeviceBuilder {
+    max_usb_speed: Option<UsbSpeed>,
+    watchdog_timeout: Option<Duration>,
+    watchdog_initial_delay: Option<Duration>,
+    non_exclusive: bool,
+    firmware_path: Option<PathBuf>,
+    device_id: Option<String>,
+}
+
+impl DeviceBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Highest USB speed to negotiate; defaults to [`UsbSpeed::Super`].
+    pub fn max_usb_speed(mut self, speed: UsbSpeed) -> Self {
+        self.max_usb_speed = Some(speed);
+        self
+    }
+
+    /// Time without host traffic after which the device resets itself; `Duration::ZERO`
+    /// disables the watchdog.
+    pub fn watchdog_timeout(mut self, timeout: Duration) -> Self {
+        self.watchdog_timeout = Some(timeout);
+        self
+    }
+
+    /// Grace period after boot before the watchdog starts, e.g. for slow hosts that take a
+    /// while to start reading queues.
+    pub fn watchdog_initial_delay(mut self, delay: Duration) -> Self {
+        self.watchdog_initial_delay = Some(delay);
+        self
+    }
+
+    /// Connect without exclusive access, e.g. to a device already used by another process (RVC4).
+    pub fn non_exclusive(mut self, non_exclusive: bool) -> Self {
+        self.non_exclusive = non_exclusive;
+        self
+    }
+
+    /// Boot the device with this firmware binary (`.mvcmd` for RVC2) instead of the one bundled
+    /// with DepthAI-Core, e.g. to pin a firmware version or test a patched build.
+    ///
+    /// The firmware must match the host library's protocol version. RVC4 devices run the
+    /// firmware installed on them and ignore this.
+    pub fn firmware_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.firmware_path = Some(path.into());
+        self
+    }
+
+    /// Open the device with this ID or name (see [`DeviceInfo`]) instead of the first one
+    /// available.
+    pub fn device_id(mut self, id: impl Into<String>) -> Self {
+        self.device_id = Some(id.into());
+        self
+    }
+
+    /// [`build`](Self::build), first waiting for the device (or any free one) to show up, e.g.
+    /// while it reboots or is being plugged in. Gives up with a
+    /// [cancelled](DepthaiError::is_cancelled) error once `token` is cancelled.
+    ///
+    /// A device asked for by [`device_id`](Self::device_id) is connected to as soon as it is
+    /// listed, whatever its state, so one already in use fails like with `build` instead of
+    /// being waited for. Connecting itself is not interrupted.
+    pub fn build_cancellable(self, token: &CancellationToken) -> Result<Device> {
+        loop {
+            token.check()?;
+            let matches = |d: &DeviceInfo| match &self.device_id {
+                Some(id) => *id == d.device_id || *id == d.name,
+                None => d.state != "X_LINK_BOOTED",
+            };
+            if Device::list_available()?.iter().any(matches) {
+                return self.build();
+            }
+            std::thread::sleep(crate::cancel::POLL_INTERVAL);
+        }
+    }
+
+    pub fn build(self) -> Result<Device> {
+        let millis = |d: Option<Duration>| d.map_or(-1, |d| d.as_millis().min(i32::MAX as u128) as i32);
+        let firmware = match &self.firmware_path {
+            Some(path) => {
+                if !path.is_file() {
+                    return Err(DepthaiError::new(format!("firmware {} not found", path.display())));
+                }
+                let path = path
+                    .to_str()
+                    .ok_or_else(|| DepthaiError::new(format!("firmware path {} is not UTF-8", path.display())))?;
+                Some(CString::new(path).map_err(|_| DepthaiError::new("invalid firmware path"))?)
+            }
+            None => None,
+        };
+        let device_id = match &self.device_id {
+            Some(id) => Some(CString::new(id.as_str()).map_err(|_| DepthaiError::new("invalid device id"))?),
+            None => None,
+        };
+        clear_error_flag();
+        let handle = unsafe {
+            depthai::dai_device_new_with_config(
+                c_int(self.max_usb_speed.map_or(-1, |s| s as i32)),
+                c_int(millis(self.watchdog_timeout)),
+                c_int(millis(self.watchdog_initial_delay)),
+                self.non_exclusive,
+                firmware.as_ref().map_or(std::ptr::null(), |p| p.as_ptr()),
+                device_id.as_ref().map_or(std::ptr::null(), |id| id.as_ptr()),
+            )
+        };
+        if handle.is_null() {
+            Err(last_error("failed to create DepthAI device"))
+        } else {
+            Ok(Device::from_handle(handle))
+        }
+    }
+}
+
 impl Device {
+    /// Start a [`DeviceBuilder`] to open a device with USB/XLink settings.
+    pub fn builder() -> DeviceBuilder {
+        DeviceBuilder::new()
+    }
+
     pub(crate) fn from_handle(handle: DaiDevice) -> Self {
-        Self { handle }
+        Self {
+            handle,
+            #[cfg(feature = "mock")]
+            mock: None,
+        }
     }
 
     pub fn new() -> Result<Self> {
@@ -30,7 +305,7 @@ impl Device {
         if handle.is_null() {
             Err(last_error("failed to create DepthAI device"))
         } else {
-            Ok(Self { handle })
+            Ok(Self::from_handle(handle))
         }
     }
 
@@ -38,34 +313,208 @@ impl Device {
     ///
     /// This mirrors DepthAI's C++ usage where the device is commonly shared via `std::shared_ptr`.
     pub fn try_clone(&self) -> Result<Self> {
+        #[cfg(feature = "mock")]
+        if let Some(mock) = &self.mock {
+            return Ok(Self::from_mock(std::sync::Arc::clone(mock)));
+        }
         clear_error_flag();
         let handle = unsafe { depthai::dai_device_clone(self.handle) };
         if handle.is_null() {
             Err(last_error("failed to clone DepthAI device"))
         } else {
-            Ok(Self { handle })
+            Ok(Self::from_handle(handle))
         }
     }
 
     pub fn is_connected(&self) -> bool {
+        #[cfg(feature = "mock")]
+        if let Some(mock) = &self.mock {
+            return mock.is_connected();
+        }
         unsafe { !depthai::dai_device_is_closed(self.handle) }
     }
 
-    /// Explicitly close the device connection.
+    /// Whether the connection was closed, through any clone of this handle or by DepthAI-Core
+    /// (e.g. after the device was unplugged).
+    pub fn is_closed(&self) -> bool {
+        !self.is_connected()
+    }
+
+    /// Explicitly close the device connection, for every clone of this handle.
     ///
-    /// Note: other cloned `Device` handles to the same underlying connection will observe the
-    /// closed state as well.
+    /// A pipeline of this process running on the device is stopped first, so its nodes and
+    /// queues fail cleanly instead of using a closed XLink connection. Later calls through any
+    /// clone fail or report [`is_closed`](Self::is_closed); closing again is a no-op. See
+    /// [`try_close`](Self::try_close) to close only a device nothing else uses.
     pub fn close(&self) -> Result<()> {
+        if self.is_closed() {
+            self.notify_closed();
+            return Ok(());
+        }
+        if let Some(key) = self.connection_key() {
+            crate::device_session::stop_pipeline_on(key)?;
+        }
+        #[cfg(feature = "mock")]
+        if let Some(mock) = &self.mock {
+            mock.close();
+            self.notify_closed();
+            return Ok(());
+        }
         clear_error_flag();
         unsafe { depthai::dai_device_close(self.handle) };
         if let Some(err) = take_error_if_any("failed to close DepthAI device") {
             Err(err)
         } else {
+            self.notify_closed();
+            Ok(())
+        }
+    }
+
+    /// [`close`](Self::close) the device only if this is its last handle, failing with
+    /// [`ErrorKind::DeviceBusy`](crate::ErrorKind::DeviceBusy) while clones or pipelines built
+    /// on it are alive.
+    pub fn try_close(&self) -> Result<()> {
+        let others = self.handle_count().saturating_sub(1);
+        if others > 0 && !self.is_closed() {
+            return Err(DepthaiError::device_busy(format!(
+                "device is still used by {others} other handle(s) or pipeline(s)"
+            )));
+        }
+        self.close()
+    }
+
+    /// Handles and pipelines sharing the underlying connection, this one included.
+    fn handle_count(&self) -> usize {
+        #[cfg(feature = "mock")]
+        if let Some(mock) = &self.mock {
+            return std::sync::Arc::strong_count(mock);
+        }
+        let count: i32 = unsafe { depthai::dai_device_use_count(self.handle) }.into();
+        count.max(0) as usize
+    }
+
+    /// Call `callback` once when the device is closed through any of its handles: by
+    /// [`close`](Self::close), [`try_close`](Self::try_close), [`reset`](Self::reset) or dropping
+    /// the last handle. Runs right away if the device is already closed.
+    ///
+    /// Closes by DepthAI-Core itself, such as a lost connection, only show in
+    /// [`is_closed`](Self::is_closed).
+    pub fn on_close<F>(&self, callback: F) -> CloseSubscription
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let key = match self.connection_key() {
+            Some(key) if !self.is_closed() => key,
+            _ => {
+                callback();
+                return CloseSubscription { key: None, id: 0 };
+            }
+        };
+        let id = NEXT_CLOSE_WATCHER.fetch_add(1, Ordering::Relaxed);
+        close_watchers().entry(key).or_default().push((id, Box::new(callback)));
+        CloseSubscription { key: Some(key), id }
+    }
+
+    fn notify_closed(&self) {
+        let Some(key) = self.connection_key() else {
+            return;
+        };
+        // Take the callbacks first: they may subscribe or close again.
+        let watchers = close_watchers().remove(&key).unwrap_or_default();
+        for (_, callback) in watchers {
+            let _ = catch_unwind(AssertUnwindSafe(callback));
+        }
+    }
+
+    /// Reboot the device into its unbooted state, e.g. to recover a wedged camera without
+    /// replugging it.
+    ///
+    /// This closes the connection (for every cloned handle too) and waits up to `timeout` for
+    /// the device to enumerate again; open it anew with [`Device::builder`] afterwards.
+    pub fn reset(&self, timeout: Duration) -> Result<()> {
+        #[cfg(feature = "mock")]
+        if let Some(mock) = &self.mock {
+            mock.close();
+            return Ok(());
+        }
+        clear_error_flag();
+        let timeout_ms = timeout.as_millis().min(i32::MAX as u128) as i32;
+        if unsafe { depthai::dai_device_reset(self.handle, c_int(timeout_ms)) } {
+            self.notify_closed();
+            Ok(())
+        } else {
+            Err(last_error("failed to reset DepthAI device"))
+        }
+    }
+
+    /// Devices connected to this host or reachable on the network, including those already in
+    /// use.
+    ///
+    /// Mirrors C++: `Device::getAllAvailableDevices()`.
+    pub fn list_available() -> Result<Vec<DeviceInfo>> {
+        clear_error_flag();
+        let json = take_owned_json_string(
+            depthai::dai_device_get_all_available_json(),
+            "failed to list DepthAI devices",
+        )?;
+        serde_json::from_str(&json).map_err(|e| DepthaiError::new(format!("invalid device list: {e}")))
+    }
+
+    /// Flash the bootloader bundled with DepthAI-Core (see
+    /// [`version::bootloader_version`](crate::version::bootloader_version)) to the device with
+    /// this ID or name.
+    ///
+    /// The device must not be open. Flashing takes a while and must not be interrupted: a device
+    /// unplugged midway may need a recovery procedure.
+    ///
+    /// Mirrors C++: `DeviceBootloader::flashBootloader()`.
+    pub fn flash_bootloader(device_id: &str) -> Result<()> {
+        let id = CString::new(device_id).map_err(|_| DepthaiError::new("invalid device id"))?;
+        clear_error_flag();
+        if unsafe { depthai::dai_device_flash_bootloader(id.as_ptr()) } {
             Ok(())
+        } else {
+            Err(last_error("failed to flash bootloader"))
         }
     }
 
+    /// Mirrors C++: `Device::getDeviceId()`.
+    pub fn device_id(&self) -> Result<String> {
+        clear_error_flag();
+        take_owned_json_string(
+            unsafe { depthai::dai_device_get_device_id(self.handle) },
+            "failed to get device id",
+        )
+    }
+
+    /// Version of the bootloader flashed on the device, `None` if it can't be told (e.g. the
+    /// device was booted over USB without one).
+    ///
+    /// Mirrors C++: `Device::getBootloaderVersion()`.
+    pub fn bootloader_version(&self) -> Result<Option<String>> {
+        clear_error_flag();
+        let version = take_owned_json_string(
+            unsafe { depthai::dai_device_get_bootloader_version(self.handle) },
+            "failed to get bootloader version",
+        )?;
+        Ok(Some(version).filter(|v| !v.is_empty()))
+    }
+
+    /// Mirrors C++: `Device::getChipTemperature()`.
+    pub fn chip_temperature(&self) -> Result<ChipTemperature> {
+        let mut t = [0f32; 5];
+        clear_error_flag();
+        if !unsafe { depthai::dai_device_get_chip_temperature(self.handle, t.as_mut_ptr()) } {
+            return Err(last_error("failed to get chip temperature"));
+        }
+        Ok(ChipTemperature { css: t[0], mss: t[1], upa: t[2], dss: t[3], average: t[4] })
+    }
+
     pub fn connected_cameras(&self) -> Result<Vec<CameraBoardSocket>> {
+        #[cfg(feature = "mock")]
+        if let Some(mock) = &self.mock {
+            return Ok(mock.config().cameras.clone());
+        }
         clear_error_flag();
         let mut sockets = vec![c_int(0); MAX_SOCKETS];
         let count = unsafe {
@@ -89,7 +538,24 @@ impl Device {
             .collect())
     }
 
+    /// Negotiated USB speed of the connection.
+    ///
+    /// Mirrors C++: `Device::getUsbSpeed()`.
+    pub fn usb_speed(&self) -> Result<UsbSpeed> {
+        #[cfg(feature = "mock")]
+        if let Some(mock) = &self.mock {
+            return Ok(mock.config().usb_speed);
+        }
+        clear_error_flag();
+        let raw: RawInt = unsafe { depthai::dai_device_get_usb_speed(self.handle) }.into();
+        UsbSpeed::from_raw(raw).ok_or_else(|| last_error("failed to get USB speed"))
+    }
+
     pub fn platform(&self) -> Result<DevicePlatform> {
+        #[cfg(feature = "mock")]
+        if let Some(mock) = &self.mock {
+            return Ok(mock.config().platform);
+        }
         clear_error_flag();
         let raw: RawInt = unsafe { depthai::dai_device_get_platform(self.handle) }.into();
         match raw {
@@ -111,9 +577,136 @@ impl Device {
         }
     }
 
+    /// Set IR flood light intensity (0.0..1.0 on supported devices, e.g. OAK-D Pro).
+    ///
+    /// Mirrors C++: `Device::setIrFloodLightIntensity(intensity)`.
+    pub fn set_ir_flood_light_intensity(&self, intensity: f32) -> Result<()> {
+        clear_error_flag();
+        unsafe { depthai::dai_device_set_ir_flood_light_intensity(self.handle, intensity) };
+        if let Some(err) = take_error_if_any("failed to set IR flood light intensity") {
+            Err(err)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Whether the device has an IR dot projector and/or flood light ("Pro" models).
+    ///
+    /// Mirrors C++: `!Device::getIrDrivers().empty()`.
+    pub fn has_ir_illumination(&self) -> Result<bool> {
+        #[cfg(feature = "mock")]
+        if self.mock.is_some() {
+            return Ok(false);
+        }
+        clear_error_flag();
+        let count: RawInt = unsafe { depthai::dai_device_get_ir_driver_count(self.handle) }.into();
+        if count < 0 {
+            return Err(last_error("failed to query IR drivers"));
+        }
+        Ok(count > 0)
+    }
+
+    /// Call `callback` on a DepthAI thread for each firmware log message, until the returned
+    /// handle is dropped.
+    ///
+    /// Mirrors C++: `Device::addLogCallback`.
+    pub fn add_log_callback<F>(&self, callback: F) -> Result<LogCallbackHandle>
+    where
+        F: FnMut(&FirmwareLogMessage) + Send + 'static,
+    {
+        clear_error_flag();
+        let state = Box::into_raw(Box::new(LogCallbackState {
+            callback: Mutex::new(Box::new(callback)),
+        }));
+        let id = unsafe {
+            depthai::dai_device_add_log_callback(
+                self.handle,
+                state as *mut autocxx::c_void,
+                log_callback_trampoline as *const () as usize,
+                log_callback_drop as *const () as usize,
+            )
+        };
+        if id.0 < 0 {
+            unsafe { drop(Box::from_raw(state)) };
+            Err(last_error("failed to add device log callback"))
+        } else {
+            Ok(LogCallbackHandle {
+                device: self.try_clone()?,
+                callback_id: id.0,
+            })
+        }
+    }
+
     pub(crate) fn handle(&self) -> DaiDevice {
         self.handle
     }
+
+    /// Identifies the underlying connection, the same for all clones of this handle.
+    pub(crate) fn connection_key(&self) -> Option<usize> {
+        #[cfg(feature = "mock")]
+        if let Some(mock) = &self.mock {
+            return Some(std::sync::Arc::as_ptr(mock) as usize);
+        }
+        let key = unsafe { depthai::dai_device_get_native_id(self.handle) };
+        (key != 0).then_some(key)
+    }
+}
+
+type LogCallback = Box<dyn FnMut(&FirmwareLogMessage) + Send>;
+
+struct LogCallbackState {
+    callback: Mutex<LogCallback>,
+}
+
+unsafe extern "C" fn log_callback_trampoline(
+    ctx: *mut c_void,
+    node_id_name: *const c_char,
+    level: RawInt,
+    time_ns: i64,
+    payload: *const c_char,
+) {
+    if ctx.is_null() {
+        return;
+    }
+    let text = |p: *const c_char| {
+        if p.is_null() {
+            String::new()
+        } else {
+            unsafe { CStr::from_ptr(p).to_string_lossy().into_owned() }
+        }
+    };
+    let msg = FirmwareLogMessage {
+        node_id_name: text(node_id_name),
+        level: LogLevel::from_raw(level),
+        time: Duration::from_nanos(time_ns.max(0) as u64),
+        payload: text(payload),
+    };
+    let state = unsafe { &*(ctx as *mut LogCallbackState) };
+    let _ = catch_unwind(AssertUnwindSafe(|| {
+        let mut guard = state.callback.lock().unwrap_or_else(|e| e.into_inner());
+        (guard)(&msg);
+    }));
+}
+
+unsafe extern "C" fn log_callback_drop(ctx: *mut c_void) {
+    if ctx.is_null() {
+        return;
+    }
+    unsafe { drop(Box::from_raw(ctx as *mut LogCallbackState)) };
+}
+
+/// Keeps a [`Device::add_log_callback`] callback registered; dropping it removes the callback.
+pub struct LogCallbackHandle {
+    device: Device,
+    callback_id: i32,
+}
+
+impl Drop for LogCallbackHandle {
+    fn drop(&mut self) {
+        // Best-effort: removing a callback shouldn't be able to panic.
+        clear_error_flag();
+        let _ = unsafe { depthai::dai_device_remove_log_callback(self.device.handle, c_int(self.callback_id)) };
+    }
 }
 
 impl Clone for Device {
@@ -126,6 +719,10 @@ impl Clone for Device {
 
 impl Drop for Device {
     fn drop(&mut self) {
+        // The last handle closes the device; notify while the key still identifies it.
+        if self.handle_count() == 1 && !self.is_closed() {
+            self.notify_closed();
+        }
         if !self.handle.is_null() {
             unsafe { depthai::dai_device_delete(self.handle) };
             self.handle = std::ptr::null_mut();
@@ -133,5 +730,35 @@ impl Drop for Device {
     }
 }
 
+type CloseWatchers = HashMap<usize, Vec<(u64, Box<dyn FnOnce() + Send>)>>;
+
+static CLOSE_WATCHERS: LazyLock<Mutex<CloseWatchers>> = LazyLock::new(Mutex::default);
+static NEXT_CLOSE_WATCHER: AtomicU64 = AtomicU64::new(1);
+
+fn close_watchers() -> MutexGuard<'static, CloseWatchers> {
+    CLOSE_WATCHERS.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Keeps a [`Device::on_close`] callback registered; dropping it unsubscribes.
+pub struct CloseSubscription {
+    key: Option<usize>,
+    id: u64,
+}
+
+impl Drop for CloseSubscription {
+    fn drop(&mut self) {
+        let Some(key) = self.key else {
+            return;
+        };
+        let mut watchers = close_watchers();
+        if let Some(list) = watchers.get_mut(&key) {
+            list.retain(|(id, _)| *id != self.id);
+            if list.is_empty() {
+                watchers.remove(&key);
+            }
+        }
+    }
+}
+
 unsafe impl Send for Device {}
 unsafe impl Sync for Device {}
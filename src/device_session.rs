@@ -0,0 +1,218 @@
+//! Sharing one device between several pipelines.
+//!
+//! A device runs one pipeline at a time. [`Pipeline::start`] fails with
+//! [`ErrorKind::DeviceBusy`](crate::ErrorKind::DeviceBusy) while another pipeline of this
+//! process is running on the same device, instead of leaving DepthAI-Core in an undefined
+//! state. To switch between pipelines without reopening the device, take it with
+//! [`Device::exclusive_session`] and start them through the session, which stops the previous
+//! one first:
+//!
+//! ```no_run
+//! # use depthai::{Device, Pipeline, Result};
+//! # fn main() -> Result<()> {
+//! let device = Device::new()?;
+//! let calibration = Pipeline::with_device(&device)?;
+//! let runtime = Pipeline::with_device(&device)?;
+//! // ... build both graphs ...
+//! let mut session = device.exclusive_session()?;
+//! session.start(&calibration)?;
+//! // ... calibrate ...
+//! session.start(&runtime)?; // stops `calibration`
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock, Mutex, MutexGuard, Weak};
+
+use crate::device::Device;
+use crate::error::{DepthaiError, Result};
+use crate::pipeline::{Pipeline, PipelineInner};
+
+/// Who uses a device, by [`Device::connection_key`].
+#[derive(Default)]
+struct Claim {
+    /// Id of the [`DeviceSession`] holding the device.
+    session: Option<u64>,
+    /// The pipeline started last on the device.
+    pipeline: Option<Weak<PipelineInner>>,
+    /// A start of `pipeline` is in progress.
+    starting: bool,
+}
+
+static CLAIMS: LazyLock<Mutex<HashMap<usize, Claim>>> = LazyLock::new(Mutex::default);
+static NEXT_SESSION: AtomicU64 = AtomicU64::new(1);
+
+fn claims() -> MutexGuard<'static, HashMap<usize, Claim>> {
+    CLAIMS.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+impl Claim {
+    /// Whether a pipeline other than `pipeline` is running or starting on the device.
+    fn other_pipeline_active(&self, pipeline: Option<&Arc<PipelineInner>>) -> bool {
+        let Some(other) = self.pipeline.as_ref().and_then(Weak::upgrade) else {
+            return false;
+        };
+        if pipeline.is_some_and(|p| Arc::ptr_eq(p, &other)) {
+            return false;
+        }
+        self.starting || Pipeline::from_inner(other).is_running().unwrap_or(false)
+    }
+
+    fn is_unused(&self) -> bool {
+        self.session.is_none() && self.pipeline.as_ref().is_none_or(|p| p.strong_count() == 0)
+    }
+}
+
+/// Stops the pipeline of this process running on the device `key`, if any.
+pub(crate) fn stop_pipeline_on(key: usize) -> Result<()> {
+    let pipeline = claims().get(&key).and_then(|c| c.pipeline.as_ref()?.upgrade());
+    if let Some(inner) = pipeline {
+        let pipeline = Pipeline::from_inner(inner);
+        if pipeline.is_running()? {
+            pipeline.stop()?;
+        }
+    }
+    Ok(())
+}
+
+/// Marks `pipeline` as starting on the device `key`, failing when the device is taken; the
+/// claim is kept once [`StartClaim::started`] is called and released otherwise.
+pub(crate) fn claim_for_start(key: usize, pipeline: &Arc<PipelineInner>, session: Option<u64>) -> Result<StartClaim> {
+    let mut claims = claims();
+    let claim = claims.entry(key).or_default();
+    if claim.session.is_some() && claim.session != session {
+        return Err(DepthaiError::device_busy(
+            "device is held by an exclusive session; start the pipeline through DeviceSession::start",
+        ));
+    }
+    if claim.other_pipeline_active(Some(pipeline)) {
+        return Err(DepthaiError::device_busy(
+            "another pipeline is running on this device; stop it first or switch through a DeviceSession",
+        ));
+    }
+    claim.pipeline = Some(Arc::downgrade(pipeline));
+    claim.starting = true;
+    Ok(StartClaim { key, started: false })
+}
+
+/// See [`claim_for_start`].
+pub(crate) struct StartClaim {
+    key: usize,
+    started: bool,
+}
+
+impl StartClaim {
+    pub(crate) fn started(mut self) {
+        self.started = true;
+    }
+}
+
+impl Drop for StartClaim {
+    fn drop(&mut self) {
+        let mut claims = claims();
+        if let Some(claim) = claims.get_mut(&self.key) {
+            claim.starting = false;
+            if !self.started {
+                claim.pipeline = None;
+            }
+            if claim.is_unused() {
+                claims.remove(&self.key);
+            }
+        }
+    }
+}
+
+impl Device {
+    /// Take this device for a [`DeviceSession`], the only way to start pipelines on it until
+    /// the session is dropped.
+    ///
+    /// Fails with [`ErrorKind::DeviceBusy`](crate::ErrorKind::DeviceBusy) while another session
+    /// holds the device or a pipeline started outside a session runs on it.
+    pub fn exclusive_session(&self) -> Result<DeviceSession> {
+        let key = self
+            .connection_key()
+            .ok_or_else(|| DepthaiError::new("device has no connection to take"))?;
+        let mut claims = claims();
+        let claim = claims.entry(key).or_default();
+        if claim.session.is_some() {
+            return Err(DepthaiError::device_busy("device is held by another exclusive session"));
+        }
+        if claim.other_pipeline_active(None) {
+            return Err(DepthaiError::device_busy("a pipeline is running on this device; stop it first"));
+        }
+        let id = NEXT_SESSION.fetch_add(1, Ordering::Relaxed);
+        claim.session = Some(id);
+        Ok(DeviceSession {
+            device: self.try_clone()?,
+            key,
+            id,
+            pipeline: None,
+        })
+    }
+}
+
+/// Exclusive use of a device, handing it over between pipelines; see the [module docs](self).
+///
+/// Dropping the session stops its pipeline and releases the device.
+pub struct DeviceSession {
+    device: Device,
+    key: usize,
+    id: u64,
+    pipeline: Option<Pipeline>,
+}
+
+impl DeviceSession {
+    pub fn device(&self) -> &Device {
+        &self.device
+    }
+
+    /// The pipeline started last through the session, if it wasn't stopped since.
+    pub fn pipeline(&self) -> Option<&Pipeline> {
+        self.pipeline.as_ref()
+    }
+
+    /// Stop the session's current pipeline, if it runs, and start `pipeline`, which must have
+    /// been created for the session's device. Starting the current pipeline again is a no-op
+    /// while it runs.
+    pub fn start(&mut self, pipeline: &Pipeline) -> Result<()> {
+        if pipeline.default_device()?.connection_key() != Some(self.key) {
+            return Err(DepthaiError::new("pipeline is bound to a different device than the session"));
+        }
+        if let Some(current) = &self.pipeline {
+            if current.ptr_eq(pipeline) && current.is_running()? {
+                return Ok(());
+            }
+        }
+        self.stop()?;
+        pipeline.start_in_session(Some(self.id))?;
+        self.pipeline = Some(pipeline.clone());
+        Ok(())
+    }
+
+    /// Stop the session's current pipeline, keeping the device.
+    pub fn stop(&mut self) -> Result<()> {
+        if let Some(current) = self.pipeline.take() {
+            if current.is_running()? {
+                current.stop()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Drop for DeviceSession {
+    fn drop(&mut self) {
+        let _ = self.stop();
+        let mut claims = claims();
+        if let Some(claim) = claims.get_mut(&self.key) {
+            if claim.session == Some(self.id) {
+                claim.session = None;
+            }
+            if claim.is_unused() {
+                claims.remove(&self.key);
+            }
+        }
+    }
+}
@@ -0,0 +1,197 @@
+//! Runtime diagnostics helpers.
+
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use depthai_sys::depthai;
+
+use crate::camera::ImageFrame;
+use crate::error::Result;
+use crate::host_node::{ns_to_duration, Buffer};
+
+/// Current time on the host steady clock, the domain of message timestamps
+/// (e.g. [`ImageFrame::timestamp`]).
+pub fn host_clock_now() -> Duration {
+    ns_to_duration(depthai::dai_clock_now_ns())
+}
+
+/// Latency percentiles for one stream, see [`LatencyProbe::stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LatencyStats {
+    /// Samples recorded since creation (or the last reset).
+    pub count: u64,
+    /// Samples in the window the percentiles are computed over.
+    pub window: usize,
+    pub min: Duration,
+    pub mean: Duration,
+    pub p50: Duration,
+    pub p90: Duration,
+    pub p99: Duration,
+    pub max: Duration,
+}
+
+#[derive(Default)]
+struct StreamSamples {
+    count: u64,
+    samples: VecDeque<Duration>,
+}
+
+/// Measures capture-to-host latency per stream.
+///
+/// Frame timestamps are captured on the device and converted to the host steady clock by
+/// DepthAI's clock synchronization, so `host_clock_now() - frame.timestamp()` is the time from
+/// capture to arrival on the host.
+///
+/// # Example
+/// ```no_run
+/// # use depthai::Result;
+/// # use depthai::camera::OutputQueue;
+/// # use depthai::diagnostics::LatencyProbe;
+/// # use std::time::Duration;
+/// # fn f(queue: OutputQueue) -> Result<()> {
+/// let probe = LatencyProbe::new().with_log_interval(Duration::from_secs(5));
+/// while let Some(frame) = queue.blocking_next(None)? {
+///     probe.record_frame("rgb", &frame)?;
+/// }
+/// if let Some(stats) = probe.stats("rgb") {
+///     println!("p99 latency: {:?}", stats.p99);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct LatencyProbe {
+    window: usize,
+    log_interval: Option<Duration>,
+    streams: Mutex<BTreeMap<String, StreamSamples>>,
+    last_log: Mutex<Instant>,
+}
+
+impl Default for LatencyProbe {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LatencyProbe {
+    /// Default number of most recent samples kept per stream.
+    pub const DEFAULT_WINDOW: usize = 1000;
+
+    pub fn new() -> Self {
+        Self {
+            window: Self::DEFAULT_WINDOW,
+            log_interval: None,
+            streams: Mutex::new(BTreeMap::new()),
+            last_log: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Keep only the `window` most recent samples per stream (minimum 1).
+    pub fn with_window(mut self, window: usize) -> Self {
+        self.window = window.max(1);
+        self
+    }
+
+    /// Print a summary of every stream to stderr at most once per `interval`.
+    pub fn with_log_interval(mut self, interval: Duration) -> Self {
+        self.log_interval = Some(interval);
+        self
+    }
+
+    /// Record the latency of `frame` (capture on device to now) and return it.
+    pub fn record_frame(&self, stream: &str, frame: &ImageFrame) -> Result<Duration> {
+        let latency = host_clock_now().saturating_sub(frame.timestamp()?);
+        self.record(stream, latency);
+        Ok(latency)
+    }
+
+    /// Record the latency of `buffer` from its timestamp to now and return it.
+    pub fn record_buffer(&self, stream: &str, buffer: &Buffer) -> Result<Duration> {
+        let latency = host_clock_now().saturating_sub(buffer.timestamp()?);
+        self.record(stream, latency);
+        Ok(latency)
+    }
+
+    /// Record a latency sample measured elsewhere.
+    pub fn record(&self, stream: &str, latency: Duration) {
+        {
+            let mut streams = self.streams.lock().unwrap_or_else(|e| e.into_inner());
+            let entry = streams.entry(stream.to_string()).or_default();
+            entry.count += 1;
+            if entry.samples.len() == self.window {
+                entry.samples.pop_front();
+            }
+            entry.samples.push_back(latency);
+        }
+        self.maybe_log();
+    }
+
+    /// Latency statistics for `stream`, or `None` if nothing was recorded.
+    pub fn stats(&self, stream: &str) -> Option<LatencyStats> {
+        let streams = self.streams.lock().unwrap_or_else(|e| e.into_inner());
+        streams.get(stream).and_then(compute_stats)
+    }
+
+    /// Statistics for every stream, ordered by stream name.
+    pub fn all_stats(&self) -> Vec<(String, LatencyStats)> {
+        let streams = self.streams.lock().unwrap_or_else(|e| e.into_inner());
+        streams
+            .iter()
+            .filter_map(|(name, s)| compute_stats(s).map(|stats| (name.clone(), stats)))
+            .collect()
+    }
+
+    /// Forget every recorded sample.
+    pub fn reset(&self) {
+        self.streams.lock().unwrap_or_else(|e| e.into_inner()).clear();
+    }
+
+    fn maybe_log(&self) {
+        let Some(interval) = self.log_interval else {
+            return;
+        };
+        {
+            let mut last = self.last_log.lock().unwrap_or_else(|e| e.into_inner());
+            if last.elapsed() < interval {
+                return;
+            }
+            *last = Instant::now();
+        }
+        for (name, s) in self.all_stats() {
+            eprintln!(
+                "[latency] {name}: n={} p50={:.1}ms p90={:.1}ms p99={:.1}ms max={:.1}ms",
+                s.window,
+                ms(s.p50),
+                ms(s.p90),
+                ms(s.p99),
+                ms(s.max)
+            );
+        }
+    }
+}
+
+fn compute_stats(stream: &StreamSamples) -> Option<LatencyStats> {
+    if stream.samples.is_empty() {
+        return None;
+    }
+    let mut sorted: Vec<Duration> = stream.samples.iter().copied().collect();
+    sorted.sort_unstable();
+    let n = sorted.len();
+    // Nearest-rank percentile.
+    let pct = |p: usize| sorted[(p * n).div_ceil(100).clamp(1, n) - 1];
+    let total: Duration = sorted.iter().sum();
+    Some(LatencyStats {
+        count: stream.count,
+        window: n,
+        min: sorted[0],
+        mean: total / n as u32,
+        p50: pct(50),
+        p90: pct(90),
+        p99: pct(99),
+        max: sorted[n - 1],
+    })
+}
+
+fn ms(d: Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
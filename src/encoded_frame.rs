@@ -5,6 +5,7 @@ use autocxx::c_int;
 use depthai_sys::{depthai, DaiDataQueue, DaiEncodedFrame};
 
 use crate::error::{clear_error_flag, last_error, take_error_if_any, Result};
+use crate::host_node::ns_to_duration;
 
 #[repr(i32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -84,6 +85,11 @@ impl EncodedFrame {
         EncodedFrameType::from_raw(raw)
     }
 
+    /// Whether this is an intra frame (IDR) a decoder can start from.
+    pub fn is_keyframe(&self) -> bool {
+        self.frame_type() == Some(EncodedFrameType::I)
+    }
+
     pub fn quality(&self) -> u32 {
         let raw: i32 = unsafe { depthai::dai_encoded_frame_get_quality(self.handle) }.into();
         raw as u32
@@ -103,6 +109,27 @@ impl EncodedFrame {
         raw as u32
     }
 
+    /// Timestamp on the host steady clock.
+    pub fn timestamp(&self) -> Result<Duration> {
+        clear_error_flag();
+        let ns = unsafe { depthai::dai_encoded_frame_get_timestamp_ns(self.handle) };
+        if let Some(err) = take_error_if_any("failed to get encoded frame timestamp") {
+            Err(err)
+        } else {
+            Ok(ns_to_duration(ns))
+        }
+    }
+
+    pub fn sequence_num(&self) -> Result<i64> {
+        clear_error_flag();
+        let seq = unsafe { depthai::dai_encoded_frame_get_sequence_num(self.handle) };
+        if let Some(err) = take_error_if_any("failed to get encoded frame sequence number") {
+            Err(err)
+        } else {
+            Ok(seq)
+        }
+    }
+
     pub fn data_len(&self) -> usize {
         unsafe { depthai::dai_encoded_frame_get_data_size(self.handle) }
     }
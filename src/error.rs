@@ -2,19 +2,188 @@ use std::ffi::CStr;
 use std::fmt;
 
 use depthai_sys::depthai;
+use serde_json::Value;
+
+use crate::device::{FirmwareLogMessage, LogLevel};
+use crate::pipeline::pools::find_node;
+use crate::pipeline::resources::{node_properties, schema_nodes};
 
 #[derive(Debug, Clone)]
-pub struct DepthaiError(pub(crate) String);
+pub struct DepthaiError {
+    kind: ErrorKind,
+    message: String,
+    context: Option<Box<ErrorContext>>,
+}
+
+/// Broad category of a [`DepthaiError`], for errors callers are expected to handle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// Reported by DepthAI-Core or the bindings; see the message.
+    Other,
+    /// A [`CancellationToken`](crate::CancellationToken) aborted the call.
+    Cancelled,
+    /// Another pipeline or [`DeviceSession`](crate::device_session::DeviceSession) is using the
+    /// device.
+    DeviceBusy,
+}
+
+const CANCELLED: &str = "operation cancelled";
+
+/// Longest [`ErrorContext::schema_snippet`], in bytes.
+const SCHEMA_SNIPPET_MAX: usize = 2048;
 
 impl DepthaiError {
     pub(crate) fn new(msg: impl Into<String>) -> Self {
-        Self(msg.into())
+        Self {
+            kind: ErrorKind::Other,
+            message: msg.into(),
+            context: None,
+        }
+    }
+
+    /// Returned when a [`CancellationToken`](crate::CancellationToken) aborts a call.
+    pub(crate) fn cancelled() -> Self {
+        Self {
+            kind: ErrorKind::Cancelled,
+            ..Self::new(CANCELLED)
+        }
+    }
+
+    /// Returned when a device is already taken, see [`device_session`](crate::device_session).
+    pub(crate) fn device_busy(msg: impl Into<String>) -> Self {
+        Self {
+            kind: ErrorKind::DeviceBusy,
+            ..Self::new(msg)
+        }
+    }
+
+    pub(crate) fn with_context(mut self, context: ErrorContext) -> Self {
+        self.context = Some(Box::new(context));
+        self
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Whether the call was aborted through a [`CancellationToken`](crate::CancellationToken).
+    pub fn is_cancelled(&self) -> bool {
+        self.kind == ErrorKind::Cancelled
+    }
+
+    /// Whether the device was in use by another pipeline or session.
+    pub fn is_device_busy(&self) -> bool {
+        self.kind == ErrorKind::DeviceBusy
     }
+
+    /// The error message, without the context.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Device-side details, attached e.g. when [`Pipeline::start`](crate::Pipeline::start) fails.
+    pub fn context(&self) -> Option<&ErrorContext> {
+        self.context.as_deref()
+    }
+}
+
+/// What the device reported around a failed call, see [`DepthaiError::context`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ErrorContext {
+    /// Firmware log lines received while the call ran, oldest first.
+    pub device_logs: Vec<FirmwareLogMessage>,
+    /// The node that failed, as named by the error or the last device error log.
+    pub node_id: Option<i32>,
+    pub node_name: Option<String>,
+    /// Schema JSON of the failed node with its properties decoded, cut at 2 KiB.
+    pub schema_snippet: Option<String>,
+}
+
+impl ErrorContext {
+    /// Context for a failure reported as `message`, given the logs the device sent meanwhile and
+    /// the pipeline schema (as returned by [`Pipeline::schema_json`](crate::Pipeline::schema_json)
+    /// or [`Pipeline::serialize_to_json`](crate::Pipeline::serialize_to_json)).
+    pub fn from_logs(message: &str, device_logs: Vec<FirmwareLogMessage>, schema: Option<&Value>) -> Self {
+        let from_device = || {
+            device_logs
+                .iter()
+                .rev()
+                .filter(|log| log.level >= LogLevel::Error && log.level != LogLevel::Off)
+                .find_map(|log| find_node(&log.node_id_name).or_else(|| find_node(&log.payload)))
+        };
+        let (mut node_name, node_id) = find_node(message)
+            .or_else(from_device)
+            .map_or((None, None), |(name, id)| (Some(name), Some(id)));
+
+        let schema = schema.map(|s| s.get("pipeline").unwrap_or(s));
+        let node = node_id.and_then(|id| {
+            schema_nodes(schema?).find(|n| n.get("id").and_then(Value::as_i64) == Some(i64::from(id)))
+        });
+        let schema_snippet = node.map(|node| {
+            if node_name.is_none() {
+                node_name = node.get("name").and_then(Value::as_str).map(str::to_string);
+            }
+            let mut node = node.clone();
+            if let (Some(properties), Some(obj)) = (node_properties(&node), node.as_object_mut()) {
+                obj.insert("properties".to_string(), properties);
+            }
+            truncate(node.to_string(), SCHEMA_SNIPPET_MAX)
+        });
+        Self {
+            device_logs,
+            node_id,
+            node_name,
+            schema_snippet,
+        }
+    }
+
+    /// The most recent error or critical firmware log line.
+    pub fn last_device_error(&self) -> Option<&FirmwareLogMessage> {
+        self.device_logs
+            .iter()
+            .rev()
+            .find(|log| log.level >= LogLevel::Error && log.level != LogLevel::Off)
+    }
+}
+
+fn truncate(mut text: String, max: usize) -> String {
+    if text.len() > max {
+        let mut end = max;
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        text.truncate(end);
+        text.push('…');
+    }
+    text
 }
 
+/// `{}` appends the failed node and the last device error; `{:#}` also lists the device logs
+/// and the node's schema.
 impl fmt::Display for DepthaiError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        write!(f, "{}", self.message)?;
+        let Some(context) = &self.context else {
+            return Ok(());
+        };
+        match (&context.node_name, context.node_id) {
+            (Some(name), Some(id)) => write!(f, " (node {name} #{id})")?,
+            (None, Some(id)) => write!(f, " (node #{id})")?,
+            _ => {}
+        }
+        if let Some(log) = context.last_device_error() {
+            write!(f, "; device: {}", log.payload)?;
+        }
+        if f.alternate() {
+            for log in &context.device_logs {
+                write!(f, "\n  [{:?}] {}: {}", log.level, log.node_id_name, log.payload)?;
+            }
+            if let Some(snippet) = &context.schema_snippet {
+                write!(f, "\n  schema: {snippet}")?;
+            }
+        }
+        Ok(())
     }
 }
 
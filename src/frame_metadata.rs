@@ -0,0 +1,303 @@
+//! Per-frame metadata embedded in encoded bitstreams.
+//!
+//! A raw `.h264`/`.h265`/`.mjpeg` recording loses the capture timestamp, sequence number and
+//! device of every frame once it leaves DepthAI. [`FrameMetadata::inject`] writes them into the
+//! frame itself, so they survive muxing by external tools (ffmpeg, GStreamer) and can be read
+//! back with [`FrameMetadata::extract`]:
+//!
+//! - H.264/H.265: a user-data-unregistered SEI message (payload type 5) tagged with
+//!   [`METADATA_UUID`], placed before the first slice of the access unit;
+//! - MJPEG: an `APP11` segment tagged with [`JPEG_APP_IDENTIFIER`], after the leading `APPn`
+//!   segments (JFIF/Exif stay first).
+//!
+//! Decoders ignore both. The payload is a small JSON object, e.g.
+//! `{"timestampNs":1234,"sequence":42,"deviceId":"1844301011D2C21200"}`.
+//!
+//! ```no_run
+//! # use std::io::Write;
+//! # use depthai::encoded_frame::EncodedFrameQueue;
+//! # use depthai::frame_metadata::FrameMetadata;
+//! # use depthai::{Device, Result};
+//! # fn record(device: &Device, queue: &EncodedFrameQueue, file: &mut std::fs::File) -> Result<()> {
+//! let device_id = device.device_id()?;
+//! while let Some(frame) = queue.blocking_next(None)? {
+//!     let bytes = frame.bytes_with_metadata(Some(&device_id))?;
+//!     file.write_all(&bytes).expect("write");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::encoded_frame::{EncodedFrame, EncodedFrameProfile};
+use crate::error::{DepthaiError, Result};
+use crate::rtp::split_annexb;
+
+/// UUID identifying this crate's SEI messages (`iso_iec_11578` field of the payload).
+pub const METADATA_UUID: [u8; 16] = [
+    0x64, 0x61, 0x69, 0x63, 0x2d, 0x72, 0x73, 0x2d, 0x8f, 0x4e, 0x2b, 0x71, 0x0c, 0x5d, 0x46, 0xa3,
+];
+
+/// Prefix of this crate's JPEG `APP11` segments.
+pub const JPEG_APP_IDENTIFIER: &[u8] = b"DAIMETA\0";
+
+/// `APP11` marker.
+const JPEG_APP_MARKER: u8 = 0xEB;
+
+/// SEI payload type of user data with a UUID.
+const SEI_USER_DATA_UNREGISTERED: u32 = 5;
+
+/// Capture information of one encoded frame.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FrameMetadata {
+    /// Capture time on the host steady clock, as [`EncodedFrame::timestamp`].
+    #[serde(rename = "timestampNs", with = "duration_ns")]
+    pub timestamp: Duration,
+    pub sequence: i64,
+    /// Device ID (MXID) of the camera, see [`Device::device_id`](crate::device::Device::device_id).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub device_id: Option<String>,
+}
+
+mod duration_ns {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(value.as_nanos().min(u64::MAX as u128) as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
+
+/// NAL unit type from the NAL header.
+fn nal_type(profile: EncodedFrameProfile, nal: &[u8]) -> u8 {
+    match profile {
+        EncodedFrameProfile::Hevc => (nal[0] >> 1) & 0x3F,
+        _ => nal[0] & 0x1F,
+    }
+}
+
+/// Whether `nal` carries slice data, before which the SEI must go.
+fn is_vcl(profile: EncodedFrameProfile, nal: &[u8]) -> bool {
+    match profile {
+        EncodedFrameProfile::Hevc => nal_type(profile, nal) < 32,
+        _ => (1..=5).contains(&nal_type(profile, nal)),
+    }
+}
+
+fn is_sei(profile: EncodedFrameProfile, nal: &[u8]) -> bool {
+    match profile {
+        // Prefix and suffix SEI.
+        EncodedFrameProfile::Hevc => matches!(nal_type(profile, nal), 39 | 40),
+        _ => nal_type(profile, nal) == 6,
+    }
+}
+
+/// Insert emulation prevention bytes, so no start code appears in the NAL payload.
+fn escape_rbsp(rbsp: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(rbsp.len() + rbsp.len() / 64);
+    let mut zeros = 0;
+    for &b in rbsp {
+        if zeros >= 2 && b <= 3 {
+            out.push(3);
+            zeros = 0;
+        }
+        out.push(b);
+        zeros = if b == 0 { zeros + 1 } else { 0 };
+    }
+    out
+}
+
+fn unescape_rbsp(ebsp: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(ebsp.len());
+    let mut zeros = 0;
+    for &b in ebsp {
+        if zeros >= 2 && b == 3 {
+            zeros = 0;
+            continue;
+        }
+        out.push(b);
+        zeros = if b == 0 { zeros + 1 } else { 0 };
+    }
+    out
+}
+
+/// SEI values (type, size) are coded as runs of `0xFF` plus a final byte.
+fn push_sei_value(out: &mut Vec<u8>, mut value: usize) {
+    while value >= 255 {
+        out.push(0xFF);
+        value -= 255;
+    }
+    out.push(value as u8);
+}
+
+fn read_sei_value(data: &[u8], pos: &mut usize) -> Option<usize> {
+    let mut value = 0;
+    loop {
+        let b = *data.get(*pos)?;
+        *pos += 1;
+        value += b as usize;
+        if b != 0xFF {
+            return Some(value);
+        }
+    }
+}
+
+impl FrameMetadata {
+    /// Timestamp and sequence number of `frame`, tagged with `device_id`.
+    pub fn from_frame(frame: &EncodedFrame, device_id: Option<&str>) -> Result<Self> {
+        Ok(Self {
+            timestamp: frame.timestamp()?,
+            sequence: frame.sequence_num()?,
+            device_id: device_id.map(str::to_string),
+        })
+    }
+
+    fn payload(&self) -> Result<Vec<u8>> {
+        serde_json::to_vec(self).map_err(|e| DepthaiError::new(format!("failed to serialize frame metadata: {e}")))
+    }
+
+    fn from_payload(payload: &[u8]) -> Option<Self> {
+        serde_json::from_slice(payload).ok()
+    }
+
+    /// A complete SEI NAL unit (without start code) carrying this metadata.
+    fn sei_nal(&self, profile: EncodedFrameProfile) -> Result<Vec<u8>> {
+        let payload = self.payload()?;
+        let mut rbsp = Vec::with_capacity(payload.len() + 24);
+        push_sei_value(&mut rbsp, SEI_USER_DATA_UNREGISTERED as usize);
+        push_sei_value(&mut rbsp, METADATA_UUID.len() + payload.len());
+        rbsp.extend_from_slice(&METADATA_UUID);
+        rbsp.extend_from_slice(&payload);
+        // rbsp_trailing_bits
+        rbsp.push(0x80);
+        let mut nal = match profile {
+            // Prefix SEI, layer 0, temporal id 0.
+            EncodedFrameProfile::Hevc => vec![39 << 1, 0x01],
+            _ => vec![0x06],
+        };
+        nal.extend(escape_rbsp(&rbsp));
+        Ok(nal)
+    }
+
+    /// `data`, one encoded frame of `profile`, with this metadata embedded (see the
+    /// [module docs](self)).
+    pub fn inject(&self, profile: EncodedFrameProfile, data: &[u8]) -> Result<Vec<u8>> {
+        match profile {
+            EncodedFrameProfile::Jpeg => self.inject_jpeg(data),
+            EncodedFrameProfile::Avc | EncodedFrameProfile::Hevc => {
+                let first_slice = split_annexb(data)
+                    .into_iter()
+                    .find(|nal| is_vcl(profile, nal))
+                    .ok_or_else(|| DepthaiError::new("no slice NAL unit in the encoded frame"))?;
+                // Back up over the start code (3 or 4 bytes) of the first slice.
+                let mut at = first_slice.as_ptr() as usize - data.as_ptr() as usize - 3;
+                if at > 0 && data[at - 1] == 0 {
+                    at -= 1;
+                }
+                let sei = self.sei_nal(profile)?;
+                let mut out = Vec::with_capacity(data.len() + sei.len() + 4);
+                out.extend_from_slice(&data[..at]);
+                out.extend_from_slice(&[0, 0, 0, 1]);
+                out.extend_from_slice(&sei);
+                out.extend_from_slice(&data[at..]);
+                Ok(out)
+            }
+        }
+    }
+
+    fn inject_jpeg(&self, data: &[u8]) -> Result<Vec<u8>> {
+        if !data.starts_with(&[0xFF, 0xD8]) {
+            return Err(DepthaiError::new("encoded frame is not a JPEG image"));
+        }
+        let payload = self.payload()?;
+        let len = 2 + JPEG_APP_IDENTIFIER.len() + payload.len();
+        let len = u16::try_from(len)
+            .map_err(|_| DepthaiError::new(format!("frame metadata of {len} bytes doesn't fit a JPEG segment")))?;
+        // Skip the leading APPn segments so JFIF/Exif stay right after SOI.
+        let mut at = 2;
+        while let [0xFF, 0xE0..=0xEF, hi, lo, ..] = data[at..] {
+            at += 2 + u16::from_be_bytes([hi, lo]) as usize;
+            if at > data.len() {
+                return Err(DepthaiError::new("truncated JPEG segment"));
+            }
+        }
+        let mut out = Vec::with_capacity(data.len() + len as usize + 2);
+        out.extend_from_slice(&data[..at]);
+        out.extend_from_slice(&[0xFF, JPEG_APP_MARKER]);
+        out.extend_from_slice(&len.to_be_bytes());
+        out.extend_from_slice(JPEG_APP_IDENTIFIER);
+        out.extend_from_slice(&payload);
+        out.extend_from_slice(&data[at..]);
+        Ok(out)
+    }
+
+    /// Metadata embedded by [`inject`](Self::inject) in one encoded frame, if any.
+    pub fn extract(profile: EncodedFrameProfile, data: &[u8]) -> Option<Self> {
+        match profile {
+            EncodedFrameProfile::Jpeg => Self::extract_jpeg(data),
+            EncodedFrameProfile::Avc | EncodedFrameProfile::Hevc => {
+                let header_len = if profile == EncodedFrameProfile::Hevc { 2 } else { 1 };
+                split_annexb(data)
+                    .into_iter()
+                    .filter(|nal| nal.len() > header_len && is_sei(profile, nal))
+                    .find_map(|nal| Self::extract_sei(&unescape_rbsp(&nal[header_len..])))
+            }
+        }
+    }
+
+    fn extract_sei(rbsp: &[u8]) -> Option<Self> {
+        let mut pos = 0;
+        // Stop at rbsp_trailing_bits.
+        while pos < rbsp.len() && rbsp[pos] != 0x80 {
+            let payload_type = read_sei_value(rbsp, &mut pos)?;
+            let size = read_sei_value(rbsp, &mut pos)?;
+            let payload = rbsp.get(pos..pos + size)?;
+            pos += size;
+            if payload_type == SEI_USER_DATA_UNREGISTERED as usize {
+                if let Some(json) = payload.strip_prefix(&METADATA_UUID[..]) {
+                    return Self::from_payload(json);
+                }
+            }
+        }
+        None
+    }
+
+    fn extract_jpeg(data: &[u8]) -> Option<Self> {
+        let mut at = 2;
+        while let [0xFF, marker, hi, lo, ..] = data.get(at..)? {
+            let len = u16::from_be_bytes([*hi, *lo]) as usize;
+            let segment = data.get(at + 4..at + 2 + len)?;
+            if *marker == JPEG_APP_MARKER {
+                if let Some(json) = segment.strip_prefix(JPEG_APP_IDENTIFIER) {
+                    return Self::from_payload(json);
+                }
+            }
+            // Image data starts at SOS; metadata only lives in the header segments.
+            if *marker == 0xDA {
+                return None;
+            }
+            at += 2 + len;
+        }
+        None
+    }
+}
+
+impl EncodedFrame {
+    /// [`bytes`](Self::bytes) with the frame's [`FrameMetadata`] embedded, see
+    /// [`frame_metadata`](crate::frame_metadata).
+    pub fn bytes_with_metadata(&self, device_id: Option<&str>) -> Result<Vec<u8>> {
+        let profile = self
+            .profile()
+            .ok_or_else(|| DepthaiError::new("encoded frame has an unknown profile"))?;
+        FrameMetadata::from_frame(self, device_id)?.inject(profile, &self.bytes())
+    }
+}
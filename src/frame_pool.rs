@@ -0,0 +1,309 @@
+//! Processing queue messages on a pool of host threads.
+//!
+//! A host loop that encodes or runs CV on every frame falls behind the device at high frame
+//! rates; the output queue fills up and frames are dropped on the device. [`FramePool`] pulls
+//! messages from a [`MessageQueue`] on `N` worker threads instead and hands back the results,
+//! in arrival order or as soon as they are ready:
+//!
+//! ```no_run
+//! # use std::time::Duration;
+//! # use depthai::prelude::*;
+//! # use depthai::frame_pool::{FramePool, FramePoolConfig};
+//! # fn main() -> Result<()> {
+//! # let pipeline = Pipeline::new().build()?;
+//! # let camera = pipeline.create_with::<CameraNode, _>(CameraBoardSocket::CamA)?;
+//! let queue = camera.request_output(CameraOutputConfig::new((1920, 1080)))?.create_message_queue(8, false)?;
+//! let mut pool = FramePool::new(queue, FramePoolConfig::default(), |msg| {
+//!     let frame = msg.as_frame()?.expect("camera sends frames");
+//!     depthai::convert::to_gray8(&frame)
+//! })?;
+//! pipeline.start()?;
+//! while let Some(gray) = pool.recv(Some(Duration::from_secs(1)))? {
+//!     let _gray = gray?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! At most [`FramePoolConfig::max_in_flight`] messages are taken from the queue before their
+//! results are received; when results aren't collected, the pool stops reading and the queue
+//! applies its own overflow policy.
+
+use std::collections::BTreeMap;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use crate::error::{DepthaiError, Result};
+use crate::queue::{Datatype, MessageQueue};
+
+/// How long a worker waits on the source before checking for shutdown.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FramePoolConfig {
+    pub workers: usize,
+    /// Messages taken from the queue whose results haven't been received yet.
+    pub max_in_flight: usize,
+    /// Return results in the order the messages arrived; otherwise as they complete.
+    pub ordered: bool,
+}
+
+impl Default for FramePoolConfig {
+    /// One worker per CPU, two messages in flight per worker, ordered.
+    fn default() -> Self {
+        let workers = std::thread::available_parallelism().map_or(2, |n| n.get());
+        Self {
+            workers,
+            max_in_flight: workers * 2,
+            ordered: true,
+        }
+    }
+}
+
+struct PoolState {
+    /// Slots held by workers, including `reserved` ones.
+    in_flight: usize,
+    /// Slots of workers still waiting for a message.
+    reserved: usize,
+    /// Sequence number of the next message taken from the source.
+    next_seq: u64,
+    /// First source error; workers stop reading after it.
+    error: Option<DepthaiError>,
+    closed: bool,
+}
+
+struct Shared {
+    max_in_flight: usize,
+    state: Mutex<PoolState>,
+    freed: Condvar,
+}
+
+impl Shared {
+    fn lock(&self) -> MutexGuard<'_, PoolState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Wait for a free in-flight slot; `false` once the pool is closed.
+    fn acquire(&self) -> bool {
+        let mut state = self.lock();
+        while state.in_flight >= self.max_in_flight && !state.closed {
+            state = self.freed.wait(state).unwrap_or_else(|e| e.into_inner());
+        }
+        if state.closed {
+            return false;
+        }
+        state.in_flight += 1;
+        state.reserved += 1;
+        true
+    }
+
+    /// Free a slot whose message was handed out, or a reserved one when `unused`.
+    fn release(&self, unused: bool) {
+        let mut state = self.lock();
+        state.in_flight = state.in_flight.saturating_sub(1);
+        if unused {
+            state.reserved = state.reserved.saturating_sub(1);
+        }
+        self.freed.notify_one();
+    }
+
+    fn close(&self) {
+        self.lock().closed = true;
+        self.freed.notify_all();
+    }
+}
+
+/// Worker threads processing messages of a queue, see the [module docs](self).
+///
+/// Dropping the pool stops reading, waits for the messages being processed and discards their
+/// results.
+pub struct FramePool<R> {
+    shared: Arc<Shared>,
+    results: Receiver<(u64, Option<R>)>,
+    /// Results that completed ahead of `next` (ordered mode).
+    pending: BTreeMap<u64, Option<R>>,
+    next: u64,
+    ordered: bool,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl<R: Send + 'static> FramePool<R> {
+    /// Process the messages of `queue` with `f` on `config.workers` threads.
+    pub fn new<F>(queue: MessageQueue, config: FramePoolConfig, f: F) -> Result<Self>
+    where
+        F: Fn(Datatype) -> R + Send + Sync + 'static,
+    {
+        Self::from_source(move |timeout| queue.get(Some(timeout)), config, f)
+    }
+
+    /// Process the values of any source, e.g. a typed queue.
+    ///
+    /// `source` is called with a timeout, from one worker at a time, and returns `Ok(None)` when
+    /// nothing arrived in time.
+    pub fn from_source<T, S, F>(source: S, config: FramePoolConfig, f: F) -> Result<Self>
+    where
+        T: 'static,
+        S: FnMut(Duration) -> Result<Option<T>> + Send + 'static,
+        F: Fn(T) -> R + Send + Sync + 'static,
+    {
+        if config.workers == 0 || config.max_in_flight == 0 {
+            return Err(DepthaiError::new("frame pool needs at least one worker and one message in flight"));
+        }
+        let shared = Arc::new(Shared {
+            max_in_flight: config.max_in_flight,
+            state: Mutex::new(PoolState {
+                in_flight: 0,
+                reserved: 0,
+                next_seq: 0,
+                error: None,
+                closed: false,
+            }),
+            freed: Condvar::new(),
+        });
+        let source = Arc::new(Mutex::new(source));
+        let f = Arc::new(f);
+        let (tx, results) = mpsc::channel();
+        let mut pool = Self {
+            shared: Arc::clone(&shared),
+            results,
+            pending: BTreeMap::new(),
+            next: 0,
+            ordered: config.ordered,
+            workers: Vec::with_capacity(config.workers),
+        };
+        for i in 0..config.workers {
+            let (shared, source, f, tx) = (Arc::clone(&shared), Arc::clone(&source), Arc::clone(&f), tx.clone());
+            let worker = std::thread::Builder::new()
+                .name(format!("depthai-frame-pool-{i}"))
+                .spawn(move || run_worker(&shared, &source, &*f, &tx))
+                .map_err(|e| DepthaiError::new(format!("failed to start frame pool worker: {e}")))?;
+            pool.workers.push(worker);
+        }
+        Ok(pool)
+    }
+
+    /// Next result, waiting up to `timeout` (forever if `None`).
+    ///
+    /// Returns `Ok(None)` on timeout, and the source's error once the results of the messages
+    /// taken before it have been received. Messages whose processing panicked are skipped.
+    pub fn recv(&mut self, timeout: Option<Duration>) -> Result<Option<R>> {
+        let deadline = timeout.map(|t| Instant::now() + t);
+        loop {
+            if self.ordered {
+                while let Some(result) = self.pending.remove(&self.next) {
+                    self.next += 1;
+                    self.shared.release(false);
+                    if result.is_some() {
+                        return Ok(result);
+                    }
+                }
+            }
+            let received = match deadline {
+                Some(deadline) => {
+                    let wait = deadline.saturating_duration_since(Instant::now());
+                    self.results.recv_timeout(wait.min(POLL_INTERVAL))
+                }
+                None => self.results.recv_timeout(POLL_INTERVAL),
+            };
+            match received {
+                Ok((seq, result)) if self.ordered => {
+                    self.pending.insert(seq, result);
+                }
+                Ok((_, result)) => {
+                    self.shared.release(false);
+                    if result.is_some() {
+                        return Ok(result);
+                    }
+                }
+                Err(RecvTimeoutError::Timeout) => {
+                    if let Some(err) = self.take_error() {
+                        return Err(err);
+                    }
+                    if deadline.is_some_and(|d| Instant::now() >= d) {
+                        return Ok(None);
+                    }
+                }
+                // Every worker has stopped after a source error.
+                Err(RecvTimeoutError::Disconnected) => return self.take_error().map_or(Ok(None), Err),
+            }
+        }
+    }
+
+    /// Next result if one is ready.
+    pub fn try_recv(&mut self) -> Result<Option<R>> {
+        self.recv(Some(Duration::ZERO))
+    }
+
+    /// The source error, once every message taken before it has been handed out.
+    fn take_error(&self) -> Option<DepthaiError> {
+        let mut state = self.shared.lock();
+        if state.in_flight == state.reserved { state.error.take() } else { None }
+    }
+
+    /// Messages taken from the queue whose results haven't been received.
+    pub fn in_flight(&self) -> usize {
+        let state = self.shared.lock();
+        state.in_flight - state.reserved
+    }
+}
+
+fn run_worker<T, R, S, F>(shared: &Shared, source: &Mutex<S>, f: &F, tx: &Sender<(u64, Option<R>)>)
+where
+    S: FnMut(Duration) -> Result<Option<T>>,
+    F: Fn(T) -> R,
+{
+    while shared.acquire() {
+        let Some((seq, message)) = next_message(shared, source) else {
+            shared.release(true);
+            return;
+        };
+        // A panicking `f` must not leave a gap that stalls ordered delivery.
+        let result = catch_unwind(AssertUnwindSafe(|| f(message))).ok();
+        if tx.send((seq, result)).is_err() {
+            return;
+        }
+    }
+}
+
+/// Poll `source` until a message arrives, numbering it under the source lock so sequence
+/// numbers follow arrival order. `None` when the pool closes or the source fails.
+fn next_message<T, S>(shared: &Shared, source: &Mutex<S>) -> Option<(u64, T)>
+where
+    S: FnMut(Duration) -> Result<Option<T>>,
+{
+    let mut source = source.lock().unwrap_or_else(|e| e.into_inner());
+    loop {
+        if shared.lock().closed {
+            return None;
+        }
+        match source(POLL_INTERVAL) {
+            Ok(Some(message)) => {
+                let mut state = shared.lock();
+                let seq = state.next_seq;
+                state.next_seq += 1;
+                state.reserved -= 1;
+                return Some((seq, message));
+            }
+            Ok(None) => {}
+            Err(e) => {
+                let mut state = shared.lock();
+                state.error.get_or_insert(e);
+                state.closed = true;
+                shared.freed.notify_all();
+                return None;
+            }
+        }
+    }
+}
+
+impl<R> Drop for FramePool<R> {
+    fn drop(&mut self) {
+        self.shared.close();
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
@@ -0,0 +1,261 @@
+//! Image statistics for quality monitoring.
+//!
+//! [`FrameStats`] holds per-channel histograms, mean, standard deviation and the share of
+//! saturated pixels of a frame, computed on a subsampled grid so it stays cheap at full
+//! resolution. [`FrameStatsHostNode`] computes them for every incoming frame and sends them on as
+//! a small JSON [`Buffer`] and/or hands them to a callback, e.g. to tune IR projector intensity or
+//! to flag a covered lens (a dark, flat histogram).
+//!
+//! ```no_run
+//! # use depthai::{Pipeline, Result};
+//! # use depthai::frame_stats::{create_frame_stats_host_node_with_callback, FrameStatsConfig};
+//! # fn main() -> Result<()> {
+//! # let pipeline = Pipeline::new().build()?;
+//! # let out = pipeline.create_node("dai::node::Camera")?.output("raw")?;
+//! let stats = create_frame_stats_host_node_with_callback(&pipeline, FrameStatsConfig::default(), |stats| {
+//!     let luma = &stats.channels[0];
+//!     if luma.mean < 10.0 && luma.stddev < 2.0 {
+//!         eprintln!("frame {} looks occluded", stats.sequence_num);
+//!     }
+//! })?;
+//! out.link(&stats.input()?)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::camera::ImageFrame;
+use crate::common::ImageFrameType;
+use crate::convert;
+use crate::error::{DepthaiError, Result};
+use crate::host_node::Buffer;
+use crate::output::{TypedInput, TypedOutput};
+use crate::pipeline::{Node, Pipeline};
+use crate::threaded_host_node::{ThreadedHostNode, ThreadedHostNodeContext, ThreadedHostNodeImpl};
+
+/// How [`FrameStats`] are sampled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameStatsConfig {
+    /// Sample every `stride`-th pixel of every `stride`-th row; 1 uses every pixel.
+    pub stride: u32,
+    /// Compute stats for one frame out of `every_n_frames`.
+    pub every_n_frames: u32,
+    /// Values at or above this count as saturated.
+    pub saturation_threshold: u8,
+}
+
+impl Default for FrameStatsConfig {
+    fn default() -> Self {
+        Self {
+            stride: 4,
+            every_n_frames: 1,
+            saturation_threshold: 250,
+        }
+    }
+}
+
+/// Statistics of one channel over the sampled pixels.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChannelStats {
+    /// Pixel count per 8-bit value (256 bins).
+    pub histogram: Vec<u32>,
+    pub mean: f32,
+    pub stddev: f32,
+    /// Percentage (`0..=100`) of samples at or above the saturation threshold.
+    pub saturated_percent: f32,
+}
+
+impl ChannelStats {
+    fn from_histogram(histogram: Vec<u32>, saturation_threshold: u8) -> Self {
+        let count: u64 = histogram.iter().map(|&c| c as u64).sum();
+        if count == 0 {
+            return Self { histogram, mean: 0.0, stddev: 0.0, saturated_percent: 0.0 };
+        }
+        let n = count as f64;
+        let mean = histogram.iter().enumerate().map(|(v, &c)| v as f64 * c as f64).sum::<f64>() / n;
+        let variance = histogram
+            .iter()
+            .enumerate()
+            .map(|(v, &c)| (v as f64 - mean).powi(2) * c as f64)
+            .sum::<f64>()
+            / n;
+        let saturated: u64 = histogram[saturation_threshold as usize..].iter().map(|&c| c as u64).sum();
+        Self {
+            histogram,
+            mean: mean as f32,
+            stddev: variance.sqrt() as f32,
+            saturated_percent: (saturated as f64 * 100.0 / n) as f32,
+        }
+    }
+}
+
+/// Statistics of one frame.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FrameStats {
+    /// Timestamp of the frame on the host steady clock.
+    pub timestamp: Duration,
+    pub sequence_num: i64,
+    pub width: u32,
+    pub height: u32,
+    /// Pixels sampled per channel.
+    pub samples: u64,
+    /// One entry for single-channel frames (`GRAY8`, `RAW8`, `RAW10`, ...), otherwise R, G, B.
+    pub channels: Vec<ChannelStats>,
+}
+
+impl FrameStats {
+    /// Compute the statistics of `frame`.
+    ///
+    /// Single-channel types are measured on their 8-bit gray values (`RAW16` windowed to its
+    /// non-zero range, see [`convert::to_gray8`]); other types after conversion to RGB.
+    pub fn compute(frame: &ImageFrame, config: &FrameStatsConfig) -> Result<Self> {
+        use ImageFrameType::*;
+        let format = frame
+            .format()
+            .ok_or_else(|| DepthaiError::new("frame has an unknown type"))?;
+        let (pixels, channels) = match format {
+            GRAY8 | RAW8 | YUV400p | RAW10 | RAW16 => (convert::to_gray8(frame)?, 1),
+            _ => (convert::to_rgb888i(frame)?, 3),
+        };
+        let (width, height) = (frame.width() as usize, frame.height() as usize);
+        let step = config.stride.max(1) as usize;
+
+        let mut histograms = vec![vec![0u32; 256]; channels];
+        let mut samples = 0u64;
+        for y in (0..height).step_by(step) {
+            let row = &pixels[y * width * channels..][..width * channels];
+            for pixel in row.chunks_exact(channels).step_by(step) {
+                for (histogram, &value) in histograms.iter_mut().zip(pixel) {
+                    histogram[value as usize] += 1;
+                }
+                samples += 1;
+            }
+        }
+
+        Ok(Self {
+            timestamp: frame.timestamp()?,
+            sequence_num: frame.sequence_num()?,
+            width: frame.width(),
+            height: frame.height(),
+            samples,
+            channels: histograms
+                .into_iter()
+                .map(|h| ChannelStats::from_histogram(h, config.saturation_threshold))
+                .collect(),
+        })
+    }
+
+    /// Stats sent by a [`FrameStatsHostNode`].
+    pub fn from_buffer(buffer: &Buffer) -> Result<Self> {
+        buffer.get_json()
+    }
+
+    /// Stats as a JSON buffer carrying the frame's timestamp and sequence number.
+    pub fn to_buffer(&self) -> Result<Buffer> {
+        let buffer = Buffer::from_json(self)?;
+        buffer.set_timestamp(self.timestamp)?;
+        buffer.set_sequence_num(self.sequence_num)?;
+        Ok(buffer)
+    }
+}
+
+/// Called with the stats of each processed frame.
+pub type FrameStatsCallback = Box<dyn FnMut(&FrameStats) + Send>;
+
+struct FrameStatsImpl {
+    input: TypedInput<ImageFrame>,
+    out: TypedOutput<Buffer>,
+    config: FrameStatsConfig,
+    callback: Option<FrameStatsCallback>,
+    frames_to_skip: u32,
+}
+
+impl FrameStatsImpl {
+    fn handle(&mut self, frame: &ImageFrame) -> Result<()> {
+        if self.frames_to_skip > 0 {
+            self.frames_to_skip -= 1;
+            return Ok(());
+        }
+        self.frames_to_skip = self.config.every_n_frames.saturating_sub(1);
+        let stats = FrameStats::compute(frame, &self.config)?;
+        if let Some(callback) = self.callback.as_mut() {
+            callback(&stats);
+        }
+        self.out.send(&stats.to_buffer()?)
+    }
+}
+
+impl ThreadedHostNodeImpl for FrameStatsImpl {
+    fn run(&mut self, ctx: &ThreadedHostNodeContext) {
+        loop {
+            match ctx.next::<ImageFrame, _>(&self.input) {
+                Ok(Some(frame)) => {
+                    // A frame that can't be measured is skipped; the node keeps going.
+                    if let Err(e) = self.handle(&frame) {
+                        ctx.report_error(&e);
+                    }
+                }
+                Ok(None) => break,
+                Err(e) => {
+                    ctx.report_error(&e);
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// Host node computing [`FrameStats`], see [`create_frame_stats_host_node`].
+#[derive(Clone)]
+pub struct FrameStatsHostNode {
+    node: ThreadedHostNode,
+}
+
+impl FrameStatsHostNode {
+    pub fn as_node(&self) -> &Node {
+        self.node.as_node()
+    }
+
+    /// Frames to measure.
+    pub fn input(&self) -> Result<TypedInput<ImageFrame>> {
+        self.as_node().input("in").map(TypedInput::from_untyped)
+    }
+
+    /// One JSON [`Buffer`] per processed frame; decode with [`FrameStats::from_buffer`].
+    pub fn out(&self) -> Result<TypedOutput<Buffer>> {
+        self.as_node().output("out").map(TypedOutput::from_untyped)
+    }
+}
+
+/// Create a host node sending the [`FrameStats`] of incoming frames on its `out` output.
+pub fn create_frame_stats_host_node(pipeline: &Pipeline, config: FrameStatsConfig) -> Result<FrameStatsHostNode> {
+    create(pipeline, config, None)
+}
+
+/// Like [`create_frame_stats_host_node`], also calling `callback` on the node's thread.
+pub fn create_frame_stats_host_node_with_callback<F>(
+    pipeline: &Pipeline,
+    config: FrameStatsConfig,
+    callback: F,
+) -> Result<FrameStatsHostNode>
+where
+    F: FnMut(&FrameStats) + Send + 'static,
+{
+    create(pipeline, config, Some(Box::new(callback)))
+}
+
+fn create(pipeline: &Pipeline, config: FrameStatsConfig, callback: Option<FrameStatsCallback>) -> Result<FrameStatsHostNode> {
+    let node = pipeline.create_threaded_host_node(|node| {
+        Ok(FrameStatsImpl {
+            input: TypedInput::from_untyped(node.create_input(Some("in"))?),
+            out: node.create_typed_output("out")?,
+            config,
+            callback,
+            frames_to_skip: 0,
+        })
+    })?;
+    Ok(FrameStatsHostNode { node })
+}
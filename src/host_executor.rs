@@ -0,0 +1,268 @@
+//! Where Rust host nodes run their processing.
+//!
+//! DepthAI-Core runs every host node on its own thread, which waits for and synchronizes the
+//! node's inputs. By default [`HostNodeImpl::process_group`](crate::host_node::HostNodeImpl::process_group)
+//! runs there too, so ten CPU-heavy host nodes compete as ten busy threads. An
+//! [`ExecutionPolicy`] moves the processing elsewhere:
+//!
+//! - [`ExecutionPolicy::Shared`] runs it on the workers of a [`HostExecutor`] shared by many
+//!   nodes, bounding how many of them process at once;
+//! - [`ExecutionPolicy::CallerDriven`] leaves it to the application, which calls
+//!   [`HostNode::step`](crate::host_node::HostNode::step) from its own loop.
+//!
+//! ```no_run
+//! # use depthai::prelude::*;
+//! # use depthai::host_executor::{ExecutionPolicy, HostExecutor};
+//! # use depthai::{Buffer, HostNodeImpl, MessageGroup};
+//! # struct Blur;
+//! # impl HostNodeImpl for Blur {
+//! #     fn process_group(&mut self, _group: &MessageGroup) -> Option<Buffer> { None }
+//! # }
+//! # fn main() -> Result<()> {
+//! let pipeline = Pipeline::new().build()?;
+//! let executor = HostExecutor::new(2)?;
+//! for _ in 0..10 {
+//!     pipeline.create_host_node_with_policy(Blur, ExecutionPolicy::Shared(executor.clone()))?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Such nodes don't keep a DepthAI thread of their own either: groups are handed over as they
+//! arrive from the node's input syncing, so ten nodes on a two-worker executor process on two
+//! threads. Off the node thread, a node's groups are still processed one at a time and in
+//! order, and returned buffers are sent on its `out` output. At most [`PENDING_GROUPS`] groups
+//! wait per node; when processing falls further behind, the oldest waiting group is dropped.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use crate::error::{DepthaiError, Result};
+use crate::host_node::MessageGroup;
+
+/// Groups waiting per node when processing off the node thread.
+pub const PENDING_GROUPS: usize = 4;
+
+/// Where a host node's `process_group` runs, see the [module docs](self).
+#[derive(Clone, Default)]
+pub enum ExecutionPolicy {
+    /// On the node's own DepthAI thread.
+    #[default]
+    Dedicated,
+    /// On the worker threads of a shared [`HostExecutor`].
+    Shared(HostExecutor),
+    /// On the thread calling [`HostNode::step`](crate::host_node::HostNode::step).
+    CallerDriven,
+}
+
+/// Processes one group of a node.
+type ProcessFn = Box<dyn Fn(MessageGroup) + Send + Sync>;
+
+/// A group waiting to be processed off the node thread.
+struct PendingGroup(MessageGroup);
+
+// SAFETY: a `MessageGroup` owns a heap-allocated `std::shared_ptr<dai::MessageGroup>`, whose
+// reference count is atomic, and DepthAI no longer touches a group once it is handed to the node.
+// A pending group is owned by exactly one thread at a time (it only moves through the
+// `Dispatch` mutex), so moving it to the thread that processes it is sound. `MessageGroup`
+// itself stays `!Send`.
+unsafe impl Send for PendingGroup {}
+
+#[derive(Default)]
+struct DispatchState {
+    pending: VecDeque<PendingGroup>,
+    /// Queued on (or running in) an executor.
+    scheduled: bool,
+}
+
+/// Groups of one node waiting to be processed off its thread.
+pub(crate) struct Dispatch {
+    state: Mutex<DispatchState>,
+    arrived: Condvar,
+    /// Held while a group is processed, so groups of a node never overlap.
+    running: Mutex<()>,
+    process: ProcessFn,
+    executor: Option<HostExecutor>,
+}
+
+impl Dispatch {
+    pub(crate) fn new(executor: Option<HostExecutor>, process: ProcessFn) -> Arc<Self> {
+        Arc::new(Self {
+            state: Mutex::default(),
+            arrived: Condvar::new(),
+            running: Mutex::new(()),
+            process,
+            executor,
+        })
+    }
+
+    fn lock(&self) -> MutexGuard<'_, DispatchState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Queue `group`, dropping the oldest waiting one when full.
+    pub(crate) fn push(self: &Arc<Self>, group: MessageGroup) {
+        let mut state = self.lock();
+        if state.pending.len() >= PENDING_GROUPS {
+            state.pending.pop_front();
+        }
+        state.pending.push_back(PendingGroup(group));
+        let schedule = self.executor.is_some() && !std::mem::replace(&mut state.scheduled, true);
+        drop(state);
+        self.arrived.notify_all();
+        if schedule {
+            if let Some(executor) = &self.executor {
+                executor.submit(Arc::clone(self));
+            }
+        }
+    }
+
+    fn process_next(&self) -> bool {
+        let _running = self.running.lock().unwrap_or_else(|e| e.into_inner());
+        let Some(PendingGroup(group)) = self.lock().pending.pop_front() else {
+            return false;
+        };
+        (self.process)(group);
+        true
+    }
+
+    /// Process waiting groups, waiting up to `timeout` for the first one (`None` waits forever).
+    pub(crate) fn step(&self, timeout: Option<Duration>) -> usize {
+        let deadline = timeout.map(|t| Instant::now() + t);
+        let mut state = self.lock();
+        while state.pending.is_empty() {
+            state = match deadline {
+                None => self.arrived.wait(state).unwrap_or_else(|e| e.into_inner()),
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return 0;
+                    }
+                    self.arrived.wait_timeout(state, deadline - now).unwrap_or_else(|e| e.into_inner()).0
+                }
+            };
+        }
+        drop(state);
+        let mut processed = 0;
+        while self.process_next() {
+            processed += 1;
+        }
+        processed
+    }
+
+    /// Executor side: process one group, then reschedule if more are waiting.
+    fn run_once(self: Arc<Self>) {
+        self.process_next();
+        let mut state = self.lock();
+        if state.pending.is_empty() {
+            state.scheduled = false;
+        } else {
+            drop(state);
+            if let Some(executor) = &self.executor {
+                executor.submit(Arc::clone(&self));
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct ExecutorQueue {
+    ready: VecDeque<Arc<Dispatch>>,
+    closed: bool,
+}
+
+struct ExecutorShared {
+    queue: Mutex<ExecutorQueue>,
+    wake: Condvar,
+}
+
+impl ExecutorShared {
+    fn lock(&self) -> MutexGuard<'_, ExecutorQueue> {
+        self.queue.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn run(&self) {
+        loop {
+            let mut queue = self.lock();
+            let dispatch = loop {
+                if let Some(dispatch) = queue.ready.pop_front() {
+                    break dispatch;
+                }
+                if queue.closed {
+                    return;
+                }
+                queue = self.wake.wait(queue).unwrap_or_else(|e| e.into_inner());
+            };
+            drop(queue);
+            dispatch.run_once();
+        }
+    }
+}
+
+/// Stops the workers when the last [`HostExecutor`] handle is dropped.
+struct Workers {
+    shared: Arc<ExecutorShared>,
+    threads: Vec<JoinHandle<()>>,
+}
+
+impl Drop for Workers {
+    fn drop(&mut self) {
+        self.shared.lock().closed = true;
+        self.shared.wake.notify_all();
+        for thread in self.threads.drain(..) {
+            // The last handle may be dropped by a worker itself, e.g. inside a node.
+            if thread.thread().id() != std::thread::current().id() {
+                let _ = thread.join();
+            }
+        }
+    }
+}
+
+/// A pool of worker threads shared by host nodes, see [`ExecutionPolicy::Shared`].
+///
+/// Cloning shares the pool; the workers stop when the last clone (including those held by
+/// nodes) is dropped.
+#[derive(Clone)]
+pub struct HostExecutor {
+    shared: Arc<ExecutorShared>,
+    workers: Arc<Workers>,
+}
+
+impl HostExecutor {
+    /// Start a pool of `workers` threads (minimum 1).
+    pub fn new(workers: usize) -> Result<Self> {
+        let shared = Arc::new(ExecutorShared {
+            queue: Mutex::default(),
+            wake: Condvar::new(),
+        });
+        // Built first so threads already started are stopped if a later one fails to start.
+        let mut pool = Workers {
+            shared: Arc::clone(&shared),
+            threads: Vec::new(),
+        };
+        for i in 0..workers.max(1) {
+            let worker_shared = Arc::clone(&shared);
+            let thread = std::thread::Builder::new()
+                .name(format!("depthai-host-executor-{i}"))
+                .spawn(move || worker_shared.run())
+                .map_err(|e| DepthaiError::new(format!("failed to start host executor thread: {e}")))?;
+            pool.threads.push(thread);
+        }
+        Ok(Self {
+            shared,
+            workers: Arc::new(pool),
+        })
+    }
+
+    /// Number of worker threads.
+    pub fn workers(&self) -> usize {
+        self.workers.threads.len()
+    }
+
+    fn submit(&self, dispatch: Arc<Dispatch>) {
+        self.shared.lock().ready.push_back(dispatch);
+        self.shared.wake.notify_one();
+    }
+}
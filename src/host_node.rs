@@ -2,16 +2,28 @@ use std::ffi::{c_void, CString};
 use std::panic::{catch_unwind, AssertUnwindSafe};
 use std::ptr;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 use depthai_sys::{depthai, DaiBuffer, DaiMessageGroup, DaiNode};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 
 use crate::camera::ImageFrame;
-use crate::error::{clear_error_flag, last_error, take_error_if_any, Result};
-use crate::output::{Input, Output};
-use crate::pipeline::{Node, Pipeline, PipelineInner};
+use crate::error::{clear_error_flag, last_error, take_error_if_any, DepthaiError, Result};
+use crate::host_executor::{Dispatch, ExecutionPolicy, HostExecutor};
+use crate::nn_data::NNData;
+use crate::output::{Input, Message, Output, TypedOutput};
+use crate::pipeline::{Node, NodeFailure, NodeFailureReporter, NodePhase, Pipeline, PipelineInner};
 
+/// Panics and errors are recorded on the pipeline, see [`Pipeline::take_node_errors`].
 pub trait HostNodeImpl: Send + 'static {
     fn process_group(&mut self, group: &MessageGroup) -> Option<Buffer>;
+
+    /// Fallible `process_group`, override it to report errors; an error drops the group.
+    fn try_process_group(&mut self, group: &MessageGroup) -> Result<Option<Buffer>> {
+        Ok(self.process_group(group))
+    }
+
     fn on_start(&mut self) {}
     fn on_stop(&mut self) {}
 }
@@ -19,15 +31,29 @@ pub trait HostNodeImpl: Send + 'static {
 #[derive(Clone)]
 pub struct HostNode {
     node: Node,
+    /// Set for [`ExecutionPolicy::CallerDriven`] nodes.
+    caller_driven: Option<Arc<Dispatch>>,
 }
 
 impl HostNode {
     pub(crate) fn from_handle(pipeline: Arc<PipelineInner>, handle: DaiNode) -> Self {
         Self {
             node: Node::from_handle(pipeline, handle),
+            caller_driven: None,
         }
     }
 
+    /// Process the groups that arrived for an [`ExecutionPolicy::CallerDriven`] node, waiting
+    /// up to `timeout` for the first one (`None` waits indefinitely). Returns how many were
+    /// processed.
+    pub fn step(&self, timeout: Option<Duration>) -> Result<usize> {
+        let dispatch = self
+            .caller_driven
+            .as_ref()
+            .ok_or_else(|| DepthaiError::new("HostNode::step requires ExecutionPolicy::CallerDriven"))?;
+        Ok(dispatch.step(timeout))
+    }
+
     pub fn as_node(&self) -> &Node {
         &self.node
     }
@@ -47,6 +73,24 @@ impl HostNode {
         self.node.output("out")
     }
 
+    /// Declare an additional named output, in addition to the implicit `out`.
+    ///
+    /// Messages are sent explicitly with [`Output::send_buffer`] / [`Output::send_frame`],
+    /// typically from [`HostNodeImpl::process_group`].
+    pub fn create_output(&self, name: &str) -> Result<Output> {
+        self.create_output_with(Some(name), None)
+    }
+
+    pub fn create_output_with(&self, name: Option<&str>, group: Option<&str>) -> Result<Output> {
+        // `dai::node::HostNode` derives from `ThreadedHostNode`, so the same helper applies.
+        create_host_output(&self.node, name, group)
+    }
+
+    /// Declare an additional output carrying messages of type `M`.
+    pub fn create_typed_output<M: Message>(&self, name: &str) -> Result<TypedOutput<M>> {
+        self.create_output(name).map(TypedOutput::from_untyped)
+    }
+
     pub fn run_syncing_on_host(&self) -> Result<()> {
         clear_error_flag();
         unsafe { depthai::dai_hostnode_run_sync_on_host(self.node.handle()) };
@@ -125,6 +169,21 @@ impl MessageGroup {
             Ok(Some(ImageFrame::from_handle(handle)))
         }
     }
+
+    pub fn get_nn_data(&self, name: &str) -> Result<Option<NNData>> {
+        clear_error_flag();
+        let name_c = CString::new(name).map_err(|_| last_error("invalid message name"))?;
+        let handle = unsafe { depthai::dai_message_group_get_nn_data(self.handle, name_c.as_ptr()) };
+        if handle.is_null() {
+            if let Some(err) = take_error_if_any("failed to get NNData from group") {
+                Err(err)
+            } else {
+                Ok(None)
+            }
+        } else {
+            Ok(Some(NNData::from_handle(handle)))
+        }
+    }
 }
 
 pub struct Buffer {
@@ -156,13 +215,39 @@ impl Buffer {
     }
 
     pub fn from_bytes(data: &[u8]) -> Result<Self> {
-        let buffer = Self::new(data.len())?;
+        let mut buffer = Self::new(data.len())?;
         buffer.set_data(data)?;
         Ok(buffer)
     }
 
-    pub fn set_data(&self, data: &[u8]) -> Result<()> {
+    /// Create a buffer whose payload is `value` serialized as JSON.
+    pub fn from_json<T: Serialize + ?Sized>(value: &T) -> Result<Self> {
+        let mut buffer = Self::new(0)?;
+        buffer.set_json(value)?;
+        Ok(buffer)
+    }
+
+    /// Payload bytes.
+    ///
+    /// Mirrors C++: `Buffer::getData()`.
+    pub fn data(&self) -> &[u8] {
+        let len = unsafe { depthai::dai_buffer_get_size(self.handle) };
+        if len == 0 {
+            return &[];
+        }
+        let ptr = unsafe { depthai::dai_buffer_get_data(self.handle) };
+        if ptr.is_null() {
+            return &[];
+        }
+        unsafe { std::slice::from_raw_parts(ptr as *const u8, len) }
+    }
+
+    /// Replace the payload with a copy of `data` (accepts `Vec<u8>`, `&[u8]`, ...).
+    ///
+    /// Mirrors C++: `Buffer::setData(data)`.
+    pub fn set_data(&mut self, data: impl AsRef<[u8]>) -> Result<()> {
         clear_error_flag();
+        let data = data.as_ref();
         unsafe { depthai::dai_buffer_set_data(self.handle, data.as_ptr() as *const _, data.len()) };
         if let Some(err) = take_error_if_any("failed to set buffer data") {
             Err(err)
@@ -171,6 +256,87 @@ impl Buffer {
         }
     }
 
+    /// Copy `data` into the payload, in place when the size is unchanged.
+    pub(crate) fn write_data(&mut self, data: &[u8]) -> Result<()> {
+        clear_error_flag();
+        unsafe { depthai::dai_buffer_write_data(self.handle, data.as_ptr() as *const _, data.len()) };
+        if let Some(err) = take_error_if_any("failed to write buffer data") {
+            Err(err)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Owners of the underlying message: 1 when nothing but this handle refers to it.
+    pub(crate) fn use_count(&self) -> usize {
+        unsafe { depthai::dai_buffer_use_count(self.handle) }.max(0) as usize
+    }
+
+    /// Serialize `value` as JSON into the payload.
+    pub fn set_json<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        let bytes = serde_json::to_vec(value)
+            .map_err(|e| DepthaiError::new(format!("failed to serialize buffer JSON: {e}")))?;
+        self.set_data(bytes)
+    }
+
+    /// Deserialize the payload as JSON.
+    pub fn get_json<T: DeserializeOwned>(&self) -> Result<T> {
+        serde_json::from_slice(self.data())
+            .map_err(|e| DepthaiError::new(format!("invalid buffer JSON: {e}")))
+    }
+
+    /// Timestamp on the host steady clock.
+    pub fn timestamp(&self) -> Result<Duration> {
+        clear_error_flag();
+        let ns = unsafe { depthai::dai_buffer_get_timestamp_ns(self.handle) };
+        if let Some(err) = take_error_if_any("failed to get buffer timestamp") {
+            Err(err)
+        } else {
+            Ok(ns_to_duration(ns))
+        }
+    }
+
+    pub fn set_timestamp(&self, timestamp: Duration) -> Result<()> {
+        clear_error_flag();
+        unsafe { depthai::dai_buffer_set_timestamp_ns(self.handle, duration_to_ns(timestamp)) };
+        if let Some(err) = take_error_if_any("failed to set buffer timestamp") {
+            Err(err)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Set the timestamp to the current host steady-clock time.
+    pub fn set_timestamp_now(&self) -> Result<()> {
+        clear_error_flag();
+        unsafe { depthai::dai_buffer_set_timestamp_now(self.handle) };
+        if let Some(err) = take_error_if_any("failed to set buffer timestamp") {
+            Err(err)
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn sequence_num(&self) -> Result<i64> {
+        clear_error_flag();
+        let seq = unsafe { depthai::dai_buffer_get_sequence_num(self.handle) };
+        if let Some(err) = take_error_if_any("failed to get buffer sequence number") {
+            Err(err)
+        } else {
+            Ok(seq)
+        }
+    }
+
+    pub fn set_sequence_num(&self, seq: i64) -> Result<()> {
+        clear_error_flag();
+        unsafe { depthai::dai_buffer_set_sequence_num(self.handle, seq) };
+        if let Some(err) = take_error_if_any("failed to set buffer sequence number") {
+            Err(err)
+        } else {
+            Ok(())
+        }
+    }
+
     pub(crate) fn handle(&self) -> DaiBuffer {
         self.handle
     }
@@ -181,10 +347,65 @@ impl Buffer {
     }
 }
 
+pub(crate) fn create_host_output(node: &Node, name: Option<&str>, group: Option<&str>) -> Result<Output> {
+    clear_error_flag();
+    let name_c = name
+        .map(|s| CString::new(s).map_err(|_| last_error("invalid output name")))
+        .transpose()?;
+    let group_c = group
+        .map(|s| CString::new(s).map_err(|_| last_error("invalid output group")))
+        .transpose()?;
+    let handle = unsafe {
+        depthai::dai_threaded_hostnode_create_output(
+            node.handle(),
+            name_c.as_ref().map(|s| s.as_ptr()).unwrap_or(ptr::null()),
+            group_c.as_ref().map(|s| s.as_ptr()).unwrap_or(ptr::null()),
+        )
+    };
+    if handle.is_null() {
+        Err(last_error("failed to create host node output"))
+    } else {
+        Ok(Output::from_handle(Arc::clone(&node.pipeline), handle))
+    }
+}
+
+pub(crate) fn duration_to_ns(d: Duration) -> i64 {
+    i64::try_from(d.as_nanos()).unwrap_or(i64::MAX)
+}
+
+pub(crate) fn ns_to_duration(ns: i64) -> Duration {
+    Duration::from_nanos(ns.max(0) as u64)
+}
+
 pub(crate) fn create_host_node<T: HostNodeImpl>(pipeline: &Pipeline, node: T) -> Result<HostNode> {
+    create_host_node_with(pipeline, |_| Ok(node))
+}
+
+pub(crate) fn create_host_node_with<T, F>(pipeline: &Pipeline, init: F) -> Result<HostNode>
+where
+    T: HostNodeImpl,
+    F: FnOnce(&HostNode) -> Result<T>,
+{
+    create_host_node_with_policy(pipeline, ExecutionPolicy::Dedicated, init)
+}
+
+pub(crate) fn create_host_node_with_policy<T, F>(
+    pipeline: &Pipeline,
+    policy: ExecutionPolicy,
+    init: F,
+) -> Result<HostNode>
+where
+    T: HostNodeImpl,
+    F: FnOnce(&HostNode) -> Result<T>,
+{
     clear_error_flag();
-    let state = Box::new(HostNodeState {
-        inner: Mutex::new(node),
+    let core = Arc::new(HostNodeCore::<T> {
+        inner: Mutex::new(None),
+        failures: NodeFailureReporter::new(&pipeline.inner_arc()),
+    });
+    let state = Box::new(HostNodeState::<T> {
+        core: Arc::clone(&core),
+        dispatch: None,
     });
     let ctx = Box::into_raw(state) as *mut c_void;
     let handle = unsafe {
@@ -199,14 +420,89 @@ pub(crate) fn create_host_node<T: HostNodeImpl>(pipeline: &Pipeline, node: T) ->
     };
     if handle.is_null() {
         unsafe { drop(Box::from_raw(ctx as *mut HostNodeState<T>)) };
-        Err(last_error("failed to create host node"))
-    } else {
-        Ok(HostNode::from_handle(pipeline.inner_arc(), handle))
+        return Err(last_error("failed to create host node"));
+    }
+
+    let mut node = HostNode::from_handle(pipeline.inner_arc(), handle);
+    if !matches!(policy, ExecutionPolicy::Dedicated) {
+        // Groups are only handed over; don't keep a DepthAI thread per node for that.
+        unsafe { depthai::dai_hostnode_process_on_arrival(handle) };
+        if let Some(err) = take_error_if_any("failed to process host node groups off its thread") {
+            return Err(err);
+        }
+    }
+    let dispatch = match policy {
+        ExecutionPolicy::Dedicated => None,
+        ExecutionPolicy::Shared(executor) => Some(off_thread_dispatch(&core, &node, Some(executor))?),
+        ExecutionPolicy::CallerDriven => {
+            let dispatch = off_thread_dispatch(&core, &node, None)?;
+            node.caller_driven = Some(Arc::clone(&dispatch));
+            Some(dispatch)
+        }
+    };
+    let impl_node = init(&node)?;
+    core.failures.set_node_id(node.as_node().id().unwrap_or(-1));
+    *core.inner.lock().unwrap_or_else(|e| e.into_inner()) = Some(impl_node);
+    // Set before the pipeline starts, so no group can arrive concurrently.
+    unsafe { (*(ctx as *mut HostNodeState<T>)).dispatch = dispatch };
+
+    Ok(node)
+}
+
+/// Processing of `core` for groups handed off the node thread; results go out on `out`.
+fn off_thread_dispatch<T: HostNodeImpl>(
+    core: &Arc<HostNodeCore<T>>,
+    node: &HostNode,
+    executor: Option<HostExecutor>,
+) -> Result<Arc<Dispatch>> {
+    let core = Arc::clone(core);
+    let out = node.out()?;
+    Ok(Dispatch::new(
+        executor,
+        Box::new(move |group| {
+            if let Some(buffer) = core.process(&group) {
+                if let Err(err) = out.send_buffer(&buffer) {
+                    core.failures.report(NodePhase::Process, NodeFailure::Error(err.to_string()));
+                }
+            }
+        }),
+    ))
+}
+
+struct HostNodeCore<T: HostNodeImpl> {
+    inner: Mutex<Option<T>>,
+    failures: NodeFailureReporter,
+}
+
+impl<T: HostNodeImpl> HostNodeCore<T> {
+    fn process(&self, group: &MessageGroup) -> Option<Buffer> {
+        let mut guard = match self.inner.lock() {
+            Ok(g) => g,
+            Err(e) => e.into_inner(),
+        };
+        let inner = guard.as_mut()?;
+        let result = catch_unwind(AssertUnwindSafe(|| inner.try_process_group(group)));
+        match result {
+            Ok(Ok(buffer)) => {
+                self.failures.success();
+                buffer
+            }
+            Ok(Err(err)) => {
+                self.failures.report(NodePhase::Process, NodeFailure::Error(err.to_string()));
+                None
+            }
+            Err(payload) => {
+                self.failures.report_panic(NodePhase::Process, payload);
+                None
+            }
+        }
     }
 }
 
 struct HostNodeState<T: HostNodeImpl> {
-    inner: Mutex<T>,
+    core: Arc<HostNodeCore<T>>,
+    /// Where groups go when they are not processed on the node thread.
+    dispatch: Option<Arc<Dispatch>>,
 }
 
 unsafe extern "C" fn hostnode_process<T: HostNodeImpl>(ctx: *mut c_void, group: DaiMessageGroup) -> DaiBuffer {
@@ -214,41 +510,48 @@ unsafe extern "C" fn hostnode_process<T: HostNodeImpl>(ctx: *mut c_void, group:
         return ptr::null_mut();
     }
     let state = unsafe { &*(ctx as *mut HostNodeState<T>) };
-    let mut guard = match state.inner.lock() {
-        Ok(g) => g,
-        Err(e) => e.into_inner(),
-    };
     let group = MessageGroup::from_handle(group);
-    let result = catch_unwind(AssertUnwindSafe(|| guard.process_group(&group)));
-    match result {
-        Ok(Some(buffer)) => buffer.into_raw(),
-        Ok(None) => ptr::null_mut(),
-        Err(_) => ptr::null_mut(),
+    if let Some(dispatch) = &state.dispatch {
+        dispatch.push(group);
+        return ptr::null_mut();
     }
+    state.core.process(&group).map_or(ptr::null_mut(), Buffer::into_raw)
 }
 
 unsafe extern "C" fn hostnode_on_start<T: HostNodeImpl>(ctx: *mut c_void) {
     if ctx.is_null() {
         return;
     }
-    let state = unsafe { &*(ctx as *mut HostNodeState<T>) };
+    let state = unsafe { &(*(ctx as *mut HostNodeState<T>)).core };
     let mut guard = match state.inner.lock() {
         Ok(g) => g,
         Err(e) => e.into_inner(),
     };
-    let _ = catch_unwind(AssertUnwindSafe(|| guard.on_start()));
+    let Some(inner) = guard.as_mut() else {
+        return;
+    };
+    if let Err(payload) = catch_unwind(AssertUnwindSafe(|| inner.on_start())) {
+        state.failures.report_panic(NodePhase::Start, payload);
+    }
+    state.failures.started();
 }
 
 unsafe extern "C" fn hostnode_on_stop<T: HostNodeImpl>(ctx: *mut c_void) {
     if ctx.is_null() {
         return;
     }
-    let state = unsafe { &*(ctx as *mut HostNodeState<T>) };
+    let state = unsafe { &(*(ctx as *mut HostNodeState<T>)).core };
     let mut guard = match state.inner.lock() {
         Ok(g) => g,
         Err(e) => e.into_inner(),
     };
-    let _ = catch_unwind(AssertUnwindSafe(|| guard.on_stop()));
+    let Some(inner) = guard.as_mut() else {
+        return;
+    };
+    if let Err(payload) = catch_unwind(AssertUnwindSafe(|| inner.on_stop())) {
+        state.failures.report_panic(NodePhase::Stop, payload);
+    }
+    state.failures.stopped();
 }
 
 unsafe extern "C" fn hostnode_drop<T: HostNodeImpl>(ctx: *mut c_void) {
@@ -0,0 +1,275 @@
+//! Stereo depth on the host CPU (requires the `host-stereo` feature).
+//!
+//! [`HostStereoDepth`] computes disparity and depth from a pair of rectified frames with OpenCV's
+//! semi-global block matcher (`cv::StereoSGBM`), configured through the same setters as
+//! [`StereoDepthNode`](crate::stereo_depth::StereoDepthNode). It lets depth algorithms be
+//! developed against recorded pairs when no device is attached; results are close to, not
+//! identical with, the device's:
+//!
+//! ```no_run
+//! # use depthai::camera::ImageFrame;
+//! # use depthai::host_stereo::HostStereoDepth;
+//! # use depthai::StereoPresetMode;
+//! # fn f(left: &ImageFrame, right: &ImageFrame) -> depthai::Result<()> {
+//! let mut stereo = HostStereoDepth::new();
+//! stereo.set_default_profile_preset(StereoPresetMode::Robotics);
+//! stereo.set_subpixel(true);
+//! stereo.set_baseline(7.5);
+//! stereo.set_focal_length(450.0);
+//! let output = stereo.process(left, right)?;
+//! if let Some(depth) = &output.depth {
+//!     println!("{}x{} depth", depth.width(), depth.height());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use opencv::calib3d::{self, StereoSGBM};
+use opencv::core::{Mat, Ptr};
+use opencv::prelude::*;
+
+use crate::camera::ImageFrame;
+use crate::common::ImageFrameType;
+use crate::convert::to_gray8;
+use crate::error::{DepthaiError, Result};
+use crate::stereo_depth::PresetMode;
+
+fn cv_error(err: opencv::Error) -> DepthaiError {
+    DepthaiError::new(format!("opencv: {err}"))
+}
+
+/// Fractional bits of subpixel disparity, as on the device by default.
+pub const SUBPIXEL_FRACTIONAL_BITS: u32 = 3;
+
+/// Disparity search range of the device: 0..95, or 0..190 with extended disparity.
+const MAX_DISPARITY: i32 = 95;
+
+/// Matcher parameters, as set by the presets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct MatcherParams {
+    block_size: i32,
+    uniqueness_ratio: i32,
+    speckle_window_size: i32,
+    speckle_range: i32,
+    mode: i32,
+}
+
+impl MatcherParams {
+    fn for_preset(mode: PresetMode) -> Self {
+        let (block_size, uniqueness_ratio, speckle_window_size, mode) = match mode {
+            PresetMode::FastAccuracy => (7, 15, 100, calib3d::StereoSGBM_MODE_SGBM_3WAY),
+            PresetMode::FastDensity => (5, 5, 50, calib3d::StereoSGBM_MODE_SGBM_3WAY),
+            PresetMode::Default => (5, 10, 100, calib3d::StereoSGBM_MODE_SGBM),
+            PresetMode::Face => (3, 10, 50, calib3d::StereoSGBM_MODE_SGBM),
+            PresetMode::HighDetail => (3, 10, 50, calib3d::StereoSGBM_MODE_HH),
+            PresetMode::Robotics => (7, 10, 200, calib3d::StereoSGBM_MODE_SGBM),
+        };
+        Self {
+            block_size,
+            uniqueness_ratio,
+            speckle_window_size,
+            speckle_range: 2,
+            mode,
+        }
+    }
+}
+
+/// Result of [`HostStereoDepth::process`], timestamped like the left frame.
+pub struct HostStereoOutput {
+    /// `RAW16` disparity in pixels, or in 1/8 pixels with subpixel enabled; 0 where invalid.
+    pub disparity: ImageFrame,
+    /// `RAW16` depth in millimeters, once baseline and focal length are set.
+    pub depth: Option<ImageFrame>,
+}
+
+/// CPU stereo matcher with a `StereoDepthNode`-like API, see the [module docs](self).
+pub struct HostStereoDepth {
+    params: MatcherParams,
+    left_right_check: bool,
+    left_right_check_threshold: i32,
+    subpixel: bool,
+    extended_disparity: bool,
+    baseline_cm: Option<f32>,
+    focal_length_px: Option<f32>,
+    max_range_mm: Option<u16>,
+    /// Rebuilt when a setter changes the parameters.
+    matcher: Option<Ptr<StereoSGBM>>,
+}
+
+// `cv::Ptr` isn't `Send`, but the matcher is owned exclusively and only used through `&mut self`.
+unsafe impl Send for HostStereoDepth {}
+
+impl Default for HostStereoDepth {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HostStereoDepth {
+    /// Matcher with the [`PresetMode::Default`] preset and the left-right check on.
+    pub fn new() -> Self {
+        Self {
+            params: MatcherParams::for_preset(PresetMode::Default),
+            left_right_check: true,
+            left_right_check_threshold: 10,
+            subpixel: false,
+            extended_disparity: false,
+            baseline_cm: None,
+            focal_length_px: None,
+            max_range_mm: None,
+            matcher: None,
+        }
+    }
+
+    pub fn set_default_profile_preset(&mut self, mode: PresetMode) {
+        self.params = MatcherParams::for_preset(mode);
+        self.matcher = None;
+    }
+
+    pub fn set_left_right_check(&mut self, enable: bool) {
+        self.left_right_check = enable;
+        self.matcher = None;
+    }
+
+    /// Maximum left-right disparity difference, in pixels, kept by the left-right check.
+    pub fn set_left_right_check_threshold(&mut self, threshold: i32) {
+        self.left_right_check_threshold = threshold.max(0);
+        self.matcher = None;
+    }
+
+    pub fn set_subpixel(&mut self, enable: bool) {
+        self.subpixel = enable;
+    }
+
+    /// Search disparities up to 190 instead of 95, for closer objects.
+    pub fn set_extended_disparity(&mut self, enable: bool) {
+        self.extended_disparity = enable;
+        self.matcher = None;
+    }
+
+    /// Distance between the two cameras, needed for depth.
+    pub fn set_baseline(&mut self, baseline_cm: f32) {
+        self.baseline_cm = Some(baseline_cm);
+    }
+
+    /// Focal length of the rectified frames, needed for depth.
+    pub fn set_focal_length(&mut self, focal_length_px: f32) {
+        self.focal_length_px = Some(focal_length_px);
+    }
+
+    /// Drop depth beyond `max_range` millimeters.
+    pub fn set_threshold_filter_max_range(&mut self, max_range: i32) {
+        self.max_range_mm = u16::try_from(max_range).ok().filter(|&r| r > 0);
+    }
+
+    /// Largest disparity the output can hold, in its own units.
+    pub fn max_disparity(&self) -> u32 {
+        let pixels = if self.extended_disparity { 2 * MAX_DISPARITY } else { MAX_DISPARITY } as u32;
+        if self.subpixel { pixels << SUBPIXEL_FRACTIONAL_BITS } else { pixels }
+    }
+
+    fn matcher(&mut self) -> Result<&mut Ptr<StereoSGBM>> {
+        if self.matcher.is_none() {
+            let p = self.params;
+            // SGBM searches a multiple of 16 disparities.
+            let num_disparities = if self.extended_disparity { 192 } else { 96 };
+            let area = p.block_size * p.block_size;
+            let matcher = StereoSGBM::create(
+                0,
+                num_disparities,
+                p.block_size,
+                8 * area,
+                32 * area,
+                if self.left_right_check { self.left_right_check_threshold } else { -1 },
+                63,
+                p.uniqueness_ratio,
+                p.speckle_window_size,
+                p.speckle_range,
+                p.mode,
+            )
+            .map_err(cv_error)?;
+            self.matcher = Some(matcher);
+        }
+        Ok(self.matcher.as_mut().expect("matcher was just created"))
+    }
+
+    /// Match a rectified pair. Frames of any format are converted to grayscale first; both must
+    /// have the same size.
+    pub fn process(&mut self, left: &ImageFrame, right: &ImageFrame) -> Result<HostStereoOutput> {
+        let (width, height) = (left.width(), left.height());
+        if (right.width(), right.height()) != (width, height) {
+            return Err(DepthaiError::new(format!(
+                "stereo pair sizes differ: {width}x{height} vs {}x{}",
+                right.width(),
+                right.height()
+            )));
+        }
+        let (left_gray, right_gray) = (to_gray8(left)?, to_gray8(right)?);
+        let disparity = self.compute(width, height, &left_gray, &right_gray)?;
+        let to_frame = |values: &[u16]| -> Result<ImageFrame> {
+            let bytes: Vec<u8> = values.iter().flat_map(|v| v.to_le_bytes()).collect();
+            let frame = ImageFrame::new(width, height, ImageFrameType::RAW16, &bytes)?;
+            frame.set_timestamp(left.timestamp()?)?;
+            frame.set_sequence_num(left.sequence_num()?)?;
+            Ok(frame)
+        };
+        let depth = self.depth_from_disparity(&disparity).map(|d| to_frame(&d)).transpose()?;
+        Ok(HostStereoOutput {
+            disparity: to_frame(&disparity)?,
+            depth,
+        })
+    }
+
+    /// Match a rectified pair of packed `GRAY8` images; returns disparity as in
+    /// [`HostStereoOutput::disparity`].
+    pub fn compute(&mut self, width: u32, height: u32, left: &[u8], right: &[u8]) -> Result<Vec<u16>> {
+        let len = width as usize * height as usize;
+        if left.len() != len || right.len() != len {
+            return Err(DepthaiError::new(format!(
+                "expected {len} bytes per {width}x{height} GRAY8 image, got {} and {}",
+                left.len(),
+                right.len()
+            )));
+        }
+        let as_mat = |data: &[u8]| -> Result<Mat> {
+            let mat = Mat::new_rows_cols_with_data(height as i32, width as i32, data).map_err(cv_error)?;
+            mat.try_clone().map_err(cv_error)
+        };
+        let (left, right) = (as_mat(left)?, as_mat(right)?);
+        let mut raw = Mat::default();
+        self.matcher()?.compute(&left, &right, &mut raw).map_err(cv_error)?;
+
+        // SGBM returns 1/16 pixel fixed point, negative where no match was found.
+        let shift = 4 - if self.subpixel { SUBPIXEL_FRACTIONAL_BITS } else { 0 };
+        let round = (1 << shift) / 2;
+        let max = self.max_disparity() as i32;
+        Ok(raw
+            .data_typed::<i16>()
+            .map_err(cv_error)?
+            .iter()
+            .map(|&d| if d <= 0 { 0 } else { ((i32::from(d) + round) >> shift).min(max) as u16 })
+            .collect())
+    }
+
+    /// Depth in millimeters for `disparity` as returned by [`compute`](Self::compute), `None`
+    /// until baseline and focal length are set.
+    pub fn depth_from_disparity(&self, disparity: &[u16]) -> Option<Vec<u16>> {
+        let (baseline_cm, focal) = (self.baseline_cm?, self.focal_length_px?);
+        let scale = if self.subpixel { (1u32 << SUBPIXEL_FRACTIONAL_BITS) as f32 } else { 1.0 };
+        // depth = focal * baseline / disparity, with disparity in pixels and baseline in mm.
+        let numerator = focal * baseline_cm * 10.0 * scale;
+        let max = self.max_range_mm.unwrap_or(u16::MAX);
+        Some(
+            disparity
+                .iter()
+                .map(|&d| {
+                    if d == 0 {
+                        return 0;
+                    }
+                    let depth = (numerator / f32::from(d)).round();
+                    if depth > f32::from(max) { 0 } else { depth as u16 }
+                })
+                .collect(),
+        )
+    }
+}
@@ -0,0 +1,262 @@
+//! IR dot projector / flood light scheduling for "Pro" devices.
+//!
+//! Stereo matching wants the dot projector's texture, while feature tracking and VIO want evenly
+//! lit images without dots. [`Illumination`] alternates the two per frame: it watches the frames
+//! of one camera of the stereo pair and, from their sequence numbers, switches the dot projector
+//! and flood light ahead of the frames they should light. Frames are forwarded on `depth` (dot
+//! projector on) or `tracking` (flood light on) accordingly. The other camera of a hardware-synced
+//! pair has the same sequence numbers, so [`IlluminationConfig::phase`] classifies its frames too.
+//!
+//! ```no_run
+//! # use depthai::prelude::*;
+//! # use depthai::illumination::{Illumination, IlluminationConfig};
+//! # fn main() -> Result<()> {
+//! # let pipeline = Pipeline::new().build()?;
+//! let left = pipeline.create_with::<CameraNode, _>(CameraBoardSocket::CamB)?;
+//! let illumination = pipeline.create_with::<Illumination, _>(IlluminationConfig::alternating(1, 1))?;
+//! left.request_output(CameraOutputConfig::new((640, 400)))?.link(&illumination.input()?)?;
+//! let tracking = illumination.tracking()?.create_queue(4, false)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The schedule is approximate. Switching is driven from the host with
+//! `setIrLaserDotProjectorIntensity`/`setIrFloodLightIntensity`, not strobed from the sensors'
+//! frame-sync signal, so it lags the camera by a few frames at high frame rates and the lag
+//! varies with host load. Tune [`IlluminationConfig::latency_frames`] until `tracking` frames
+//! show no dots, and expect an occasional mislit frame around a switch; at rates where that
+//! matters, prefer longer runs (e.g. `alternating(4, 4)`) over single-frame alternation.
+
+use crate::camera::ImageFrame;
+use crate::device::Device;
+use crate::error::{DepthaiError, Result};
+use crate::output::{TypedInput, TypedOutput};
+use crate::pipeline::{Node, Pipeline};
+use crate::threaded_host_node::{ThreadedHostNode, ThreadedHostNodeContext, ThreadedHostNodeImpl};
+use crate::CreateInPipelineWith;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IlluminationMode {
+    /// Both emitters off.
+    Off,
+    DotProjector,
+    FloodLight,
+    /// [`IlluminationConfig::dot_frames`] dot-lit frames, then
+    /// [`IlluminationConfig::flood_frames`] flood-lit frames, repeated.
+    Alternating,
+}
+
+/// Which emitter lights a frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IlluminationPhase {
+    Dot,
+    Flood,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IlluminationConfig {
+    pub mode: IlluminationMode,
+    /// Dot projector intensity, `0.0..=1.0`.
+    pub dot_intensity: f32,
+    /// Flood light intensity, `0.0..=1.0`.
+    pub flood_intensity: f32,
+    pub dot_frames: u32,
+    pub flood_frames: u32,
+    /// Frames between switching an emitter and the first frame it lights.
+    pub latency_frames: u32,
+}
+
+impl Default for IlluminationConfig {
+    fn default() -> Self {
+        Self {
+            mode: IlluminationMode::DotProjector,
+            dot_intensity: 0.7,
+            flood_intensity: 0.5,
+            dot_frames: 1,
+            flood_frames: 1,
+            latency_frames: 1,
+        }
+    }
+}
+
+impl IlluminationConfig {
+    /// Alternate `dot_frames` dot-lit frames with `flood_frames` flood-lit frames.
+    pub fn alternating(dot_frames: u32, flood_frames: u32) -> Self {
+        Self {
+            mode: IlluminationMode::Alternating,
+            dot_frames,
+            flood_frames,
+            ..Self::default()
+        }
+    }
+
+    /// Share of frames lit by the dot projector.
+    pub fn duty_cycle(&self) -> f32 {
+        match self.mode {
+            IlluminationMode::Off | IlluminationMode::FloodLight => 0.0,
+            IlluminationMode::DotProjector => 1.0,
+            IlluminationMode::Alternating => {
+                self.dot_frames as f32 / (self.dot_frames + self.flood_frames).max(1) as f32
+            }
+        }
+    }
+
+    /// Emitter lighting the frame with `sequence_num`; `None` with [`IlluminationMode::Off`].
+    pub fn phase(&self, sequence_num: i64) -> Option<IlluminationPhase> {
+        match self.mode {
+            IlluminationMode::Off => None,
+            IlluminationMode::DotProjector => Some(IlluminationPhase::Dot),
+            IlluminationMode::FloodLight => Some(IlluminationPhase::Flood),
+            IlluminationMode::Alternating => {
+                let cycle = (self.dot_frames as i64 + self.flood_frames as i64).max(1);
+                if sequence_num.rem_euclid(cycle) < self.dot_frames as i64 {
+                    Some(IlluminationPhase::Dot)
+                } else {
+                    Some(IlluminationPhase::Flood)
+                }
+            }
+        }
+    }
+
+    /// Emitter to switch to on receiving frame `sequence_num`: the one for the frame
+    /// [`latency_frames`](Self::latency_frames) after the next. `-1` gives the setting before
+    /// the first frame.
+    pub fn switch_for(&self, sequence_num: i64) -> Option<IlluminationPhase> {
+        self.phase(sequence_num + 1 + self.latency_frames as i64)
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        for (name, intensity) in [("dot", self.dot_intensity), ("flood", self.flood_intensity)] {
+            if !(0.0..=1.0).contains(&intensity) {
+                return Err(DepthaiError::new(format!(
+                    "{name} intensity must be within 0.0..=1.0, got {intensity}"
+                )));
+            }
+        }
+        if self.mode == IlluminationMode::Alternating && (self.dot_frames == 0 || self.flood_frames == 0) {
+            return Err(DepthaiError::new("alternating illumination needs at least one dot and one flood frame"));
+        }
+        Ok(())
+    }
+}
+
+struct IlluminationImpl {
+    input: TypedInput<ImageFrame>,
+    depth: TypedOutput<ImageFrame>,
+    tracking: TypedOutput<ImageFrame>,
+    device: Device,
+    config: IlluminationConfig,
+    /// What the emitters are currently set to, `None` before the first switch.
+    applied: Option<Option<IlluminationPhase>>,
+}
+
+impl IlluminationImpl {
+    fn apply(&mut self, phase: Option<IlluminationPhase>) -> Result<()> {
+        if self.applied == Some(phase) {
+            return Ok(());
+        }
+        let (dot, flood) = match phase {
+            None => (0.0, 0.0),
+            Some(IlluminationPhase::Dot) => (self.config.dot_intensity, 0.0),
+            Some(IlluminationPhase::Flood) => (0.0, self.config.flood_intensity),
+        };
+        self.device.set_ir_laser_dot_projector_intensity(dot)?;
+        self.device.set_ir_flood_light_intensity(flood)?;
+        self.applied = Some(phase);
+        Ok(())
+    }
+
+    fn handle(&mut self, frame: &ImageFrame) -> Result<()> {
+        let sequence = frame.sequence_num()?;
+        self.apply(self.config.switch_for(sequence))?;
+        match self.config.phase(sequence) {
+            Some(IlluminationPhase::Dot) => self.depth.send(frame),
+            Some(IlluminationPhase::Flood) => self.tracking.send(frame),
+            None => {
+                self.depth.send(frame)?;
+                self.tracking.send(frame)
+            }
+        }
+    }
+}
+
+impl ThreadedHostNodeImpl for IlluminationImpl {
+    fn run(&mut self, ctx: &ThreadedHostNodeContext) {
+        loop {
+            match ctx.next::<ImageFrame, _>(&self.input) {
+                Ok(Some(frame)) => {
+                    if let Err(e) = self.handle(&frame) {
+                        ctx.report_error(&e);
+                    }
+                }
+                Ok(None) => break,
+                Err(e) => {
+                    ctx.report_error(&e);
+                    break;
+                }
+            }
+        }
+    }
+
+    fn on_start(&mut self) {
+        let _ = self.apply(self.config.switch_for(-1));
+    }
+
+    fn on_stop(&mut self) {
+        let _ = self.apply(None);
+    }
+}
+
+/// Host node scheduling the IR emitters, see the [module docs](self).
+#[derive(Clone)]
+pub struct Illumination {
+    node: ThreadedHostNode,
+    config: IlluminationConfig,
+}
+
+impl Illumination {
+    pub fn as_node(&self) -> &Node {
+        self.node.as_node()
+    }
+
+    pub fn config(&self) -> &IlluminationConfig {
+        &self.config
+    }
+
+    /// Frames of one camera of the stereo pair.
+    pub fn input(&self) -> Result<TypedInput<ImageFrame>> {
+        self.as_node().input("in").map(TypedInput::from_untyped)
+    }
+
+    /// Frames lit by the dot projector (all frames with [`IlluminationMode::Off`]).
+    pub fn depth(&self) -> Result<TypedOutput<ImageFrame>> {
+        self.as_node().output("depth").map(TypedOutput::from_untyped)
+    }
+
+    /// Frames lit by the flood light (all frames with [`IlluminationMode::Off`]).
+    pub fn tracking(&self) -> Result<TypedOutput<ImageFrame>> {
+        self.as_node().output("tracking").map(TypedOutput::from_untyped)
+    }
+}
+
+impl CreateInPipelineWith<IlluminationConfig> for Illumination {
+    /// Fails when the pipeline's device has no IR emitters.
+    fn create_with(pipeline: &Pipeline, config: IlluminationConfig) -> Result<Self> {
+        config.validate()?;
+        let device = pipeline.default_device()?;
+        if !device.has_ir_illumination()? {
+            return Err(DepthaiError::new("device has no IR dot projector or flood light"));
+        }
+        let node = pipeline.create_threaded_host_node(|node| {
+            Ok(IlluminationImpl {
+                input: TypedInput::from_untyped(node.create_input(Some("in"))?),
+                depth: node.create_typed_output("depth")?,
+                tracking: node.create_typed_output("tracking")?,
+                device,
+                config,
+                applied: None,
+            })
+        })?;
+        Ok(Self { node, config })
+    }
+}
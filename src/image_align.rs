@@ -1,12 +1,77 @@
 use autocxx::c_int;
-use depthai_sys::depthai;
+use depthai_sys::{depthai, DaiBuffer};
 
-use crate::error::clear_error_flag;
+use crate::camera::ImageFrame;
+use crate::common::Interpolation;
+use crate::error::{clear_error_flag, last_error, take_error_if_any, Result};
+use crate::host_node::Buffer;
+
+/// Runtime configuration message for `ImageAlign`.
+///
+/// Mirrors C++: `dai::ImageAlignConfig`.
+///
+/// Note: this is also a `Buffer` message, so it can be sent to the node's `inputConfig`.
+pub struct ImageAlignConfig {
+    buffer: Buffer,
+}
+
+impl ImageAlignConfig {
+    pub fn new() -> Result<Self> {
+        clear_error_flag();
+        let handle = depthai::dai_image_align_config_new();
+        if handle.is_null() {
+            Err(last_error("failed to create ImageAlignConfig"))
+        } else {
+            Ok(Self {
+                buffer: Buffer::from_handle(handle),
+            })
+        }
+    }
+
+    pub fn as_buffer(&self) -> &Buffer {
+        &self.buffer
+    }
+
+    pub fn into_buffer(self) -> Buffer {
+        self.buffer
+    }
+
+    pub(crate) fn from_handle(handle: DaiBuffer) -> Self {
+        Self {
+            buffer: Buffer::from_handle(handle),
+        }
+    }
+
+    pub(crate) fn handle(&self) -> DaiBuffer {
+        self.buffer.handle()
+    }
+
+    /// Depth (in millimeters) of the plane used when aligning without a depth input.
+    ///
+    /// A value of `0` disables the static plane and uses the incoming depth instead.
+    ///
+    /// Mirrors C++: `ImageAlignConfig::staticDepthPlane`.
+    pub fn set_static_depth_plane(&mut self, depth_plane_mm: u16) -> &mut Self {
+        clear_error_flag();
+        unsafe { depthai::dai_image_align_config_set_static_depth_plane(self.handle(), depth_plane_mm) };
+        self
+    }
+
+    pub fn static_depth_plane(&self) -> Result<u16> {
+        clear_error_flag();
+        let v = unsafe { depthai::dai_image_align_config_get_static_depth_plane(self.handle()) };
+        if let Some(err) = take_error_if_any("failed to get ImageAlignConfig staticDepthPlane") {
+            Err(err)
+        } else {
+            Ok(v)
+        }
+    }
+}
 
 #[crate::native_node_wrapper(
     native = "dai::node::ImageAlign",
-    inputs(inputConfig, input, inputAlignTo),
-    outputs(outputAligned, passthroughInput)
+    inputs(inputConfig: ImageAlignConfig, input: ImageFrame, inputAlignTo: ImageFrame),
+    outputs(outputAligned: ImageFrame, passthroughInput: ImageFrame)
 )]
 pub struct ImageAlignNode {
     node: crate::pipeline::Node,
@@ -21,6 +86,19 @@ impl ImageAlignNode {
         unsafe { depthai::dai_image_align_set_run_on_host(self.node.handle(), run_on_host) };
     }
 
+    /// Whether the node is configured to run on the host.
+    ///
+    /// Mirrors C++: `ImageAlign::runOnHost()`.
+    pub fn run_on_host(&self) -> Result<bool> {
+        clear_error_flag();
+        let v = unsafe { depthai::dai_image_align_run_on_host(self.node.handle()) };
+        if let Some(err) = take_error_if_any("failed to read ImageAlign runOnHost") {
+            Err(err)
+        } else {
+            Ok(v)
+        }
+    }
+
     /// Specify the output size of the aligned image.
     ///
     /// Mirrors C++: `ImageAlign::setOutputSize(width, height)`.
@@ -36,4 +114,39 @@ impl ImageAlignNode {
         clear_error_flag();
         unsafe { depthai::dai_image_align_set_out_keep_aspect_ratio(self.node.handle(), keep) };
     }
+
+    /// Specify the interpolation used when warping the input.
+    ///
+    /// Mirrors C++: `ImageAlign::setInterpolation(Interpolation)`.
+    pub fn set_interpolation(&self, interpolation: Interpolation) {
+        clear_error_flag();
+        unsafe { depthai::dai_image_align_set_interpolation(self.node.handle(), c_int(interpolation as i32)) };
+    }
+
+    /// Specify the number of shaves used by the node (device execution only).
+    ///
+    /// Mirrors C++: `ImageAlign::setNumShaves(int)`.
+    pub fn set_num_shaves(&self, num_shaves: i32) {
+        clear_error_flag();
+        unsafe { depthai::dai_image_align_set_num_shaves(self.node.handle(), c_int(num_shaves)) };
+    }
+
+    /// Specify the number of frames in the output pool.
+    ///
+    /// Mirrors C++: `ImageAlign::setNumFramesPool(int)`.
+    pub fn set_num_frames_pool(&self, num_frames_pool: i32) {
+        clear_error_flag();
+        unsafe { depthai::dai_image_align_set_num_frames_pool(self.node.handle(), c_int(num_frames_pool)) };
+    }
+
+    /// Access the node's initial config (shared, modifications affect the node).
+    pub fn initial_config(&self) -> Result<ImageAlignConfig> {
+        clear_error_flag();
+        let handle = unsafe { depthai::dai_image_align_get_initial_config(self.node.handle()) };
+        if handle.is_null() {
+            Err(last_error("failed to get ImageAlign initialConfig"))
+        } else {
+            Ok(ImageAlignConfig::from_handle(handle))
+        }
+    }
 }
@@ -0,0 +1,411 @@
+//! Depth post-processing filters as a standalone node.
+//!
+//! `ImageFilters` runs a chain of median, spatial, speckle and temporal filters over depth (or
+//! any 16-bit) frames, on the device or the host. DepthAI-Core only ships a working node when it
+//! is built with OpenCV support (`DEPTHAI_OPENCV_SUPPORT`); otherwise creating it fails, so check
+//! [`Feature::ImageFilters`](crate::version::Feature::ImageFilters) first:
+//!
+//! ```no_run
+//! # use depthai::image_filters::{ImageFilter, ImageFiltersNode, MedianKernel, SpatialFilter};
+//! # use depthai::version::{has_feature, Feature};
+//! # use depthai::{Pipeline, Result, StereoDepthNode};
+//! # fn main() -> Result<()> {
+//! # let pipeline = Pipeline::new().build()?;
+//! # let stereo = pipeline.create::<StereoDepthNode>()?;
+//! if has_feature(Feature::ImageFilters) {
+//!     let filters = pipeline.create::<ImageFiltersNode>()?;
+//!     let mut config = filters.initial_config()?;
+//!     config.insert_filter(ImageFilter::Median(MedianKernel::Kernel5x5))?;
+//!     config.insert_filter(ImageFilter::Spatial(SpatialFilter::default()))?;
+//!     stereo.as_node().output("depth")?.link(&filters.input()?)?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The chain can be changed while running by sending an [`ImageFiltersConfig`] to the node's
+//! `inputConfig`.
+
+use std::ffi::{CStr, CString};
+
+use autocxx::c_int;
+use depthai_sys::{depthai, DaiBuffer};
+use serde_json::{json, Value};
+
+use crate::camera::ImageFrame;
+use crate::error::{clear_error_flag, last_error, take_error_if_any, DepthaiError, Result};
+use crate::host_node::Buffer;
+
+/// Filter chain presets, tuned for ToF depth.
+///
+/// Mirrors C++: `dai::ImageFiltersPresetMode`.
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFiltersPresetMode {
+    TofLowRange = 0,
+    TofMidRange = 1,
+    TofHighRange = 2,
+}
+
+/// Kernel of the median filter.
+///
+/// Mirrors C++: `dai::filters::params::MedianFilter`.
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MedianKernel {
+    Off = 0,
+    #[default]
+    Kernel3x3 = 3,
+    Kernel5x5 = 5,
+    Kernel7x7 = 7,
+}
+
+impl MedianKernel {
+    fn from_raw(value: i64) -> Option<Self> {
+        match value {
+            0 => Some(Self::Off),
+            3 => Some(Self::Kernel3x3),
+            5 => Some(Self::Kernel5x5),
+            7 => Some(Self::Kernel7x7),
+            _ => None,
+        }
+    }
+}
+
+/// Edge-preserving smoothing that also fills small holes.
+///
+/// Mirrors C++: `dai::filters::params::SpatialFilter`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpatialFilter {
+    pub enable: bool,
+    /// Search radius for filling holes, in pixels.
+    pub hole_filling_radius: u8,
+    /// Weight of the current pixel against its neighbours, `0..1`.
+    pub alpha: f32,
+    /// Step size boundary: differences above it are treated as edges and not smoothed.
+    pub delta: i32,
+    /// Filter passes.
+    pub num_iterations: i32,
+}
+
+impl Default for SpatialFilter {
+    /// Enabled, with DepthAI's parameters.
+    fn default() -> Self {
+        Self {
+            enable: true,
+            hole_filling_radius: 2,
+            alpha: 0.5,
+            delta: 0,
+            num_iterations: 1,
+        }
+    }
+}
+
+/// When the temporal filter keeps a pixel's last valid value over invalid ones.
+///
+/// Mirrors C++: `dai::filters::params::TemporalFilter::PersistencyMode`.
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PersistencyMode {
+    Off = 0,
+    Valid8OutOf8 = 1,
+    Valid2InLast3 = 2,
+    #[default]
+    Valid2InLast4 = 3,
+    Valid2OutOf8 = 4,
+    Valid1InLast2 = 5,
+    Valid1InLast5 = 6,
+    Valid1InLast8 = 7,
+    Indefinitely = 8,
+}
+
+impl PersistencyMode {
+    fn from_raw(value: i64) -> Option<Self> {
+        Some(match value {
+            0 => Self::Off,
+            1 => Self::Valid8OutOf8,
+            2 => Self::Valid2InLast3,
+            3 => Self::Valid2InLast4,
+            4 => Self::Valid2OutOf8,
+            5 => Self::Valid1InLast2,
+            6 => Self::Valid1InLast5,
+            7 => Self::Valid1InLast8,
+            8 => Self::Indefinitely,
+            _ => return None,
+        })
+    }
+}
+
+/// Smoothing over consecutive frames, for static scenes.
+///
+/// Mirrors C++: `dai::filters::params::TemporalFilter`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TemporalFilter {
+    pub enable: bool,
+    pub persistency_mode: PersistencyMode,
+    /// Weight of the current frame against the history, `0..1`.
+    pub alpha: f32,
+    /// Differences above it are treated as motion and not smoothed.
+    pub delta: i32,
+}
+
+impl Default for TemporalFilter {
+    /// Enabled, with DepthAI's parameters.
+    fn default() -> Self {
+        Self {
+            enable: true,
+            persistency_mode: PersistencyMode::default(),
+            alpha: 0.4,
+            delta: 0,
+        }
+    }
+}
+
+/// Removal of small isolated patches of depth.
+///
+/// Mirrors C++: `dai::filters::params::SpeckleFilter`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpeckleFilter {
+    pub enable: bool,
+    /// Largest patch removed, in pixels.
+    pub speckle_range: u32,
+    /// Largest difference between neighbours of one patch.
+    pub difference_threshold: u32,
+}
+
+impl Default for SpeckleFilter {
+    /// Enabled, with DepthAI's parameters.
+    fn default() -> Self {
+        Self {
+            enable: true,
+            speckle_range: 50,
+            difference_threshold: 2,
+        }
+    }
+}
+
+/// One step of an `ImageFilters` chain.
+///
+/// Mirrors C++: `dai::FilterParams`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImageFilter {
+    Median(MedianKernel),
+    Spatial(SpatialFilter),
+    Speckle(SpeckleFilter),
+    Temporal(TemporalFilter),
+}
+
+impl ImageFilter {
+    fn to_json(self) -> Value {
+        match self {
+            Self::Median(kernel) => json!({ "type": "median", "kernel": kernel as i32 }),
+            Self::Spatial(f) => json!({
+                "type": "spatial",
+                "enable": f.enable,
+                "holeFillingRadius": f.hole_filling_radius,
+                "alpha": f.alpha,
+                "delta": f.delta,
+                "numIterations": f.num_iterations,
+            }),
+            Self::Speckle(f) => json!({
+                "type": "speckle",
+                "enable": f.enable,
+                "speckleRange": f.speckle_range,
+                "differenceThreshold": f.difference_threshold,
+            }),
+            Self::Temporal(f) => json!({
+                "type": "temporal",
+                "enable": f.enable,
+                "persistencyMode": f.persistency_mode as i32,
+                "alpha": f.alpha,
+                "delta": f.delta,
+            }),
+        }
+    }
+
+    fn from_json(v: &Value) -> Option<Self> {
+        let int = |key: &str| v.get(key).and_then(Value::as_i64);
+        let float = |key: &str| v.get(key).and_then(Value::as_f64).map(|f| f as f32);
+        let enable = v.get("enable").and_then(Value::as_bool);
+        Some(match v.get("type")?.as_str()? {
+            "median" => Self::Median(MedianKernel::from_raw(int("kernel")?)?),
+            "spatial" => Self::Spatial(SpatialFilter {
+                enable: enable?,
+                hole_filling_radius: int("holeFillingRadius")? as u8,
+                alpha: float("alpha")?,
+                delta: int("delta")? as i32,
+                num_iterations: int("numIterations")? as i32,
+            }),
+            "speckle" => Self::Speckle(SpeckleFilter {
+                enable: enable?,
+                speckle_range: int("speckleRange")? as u32,
+                difference_threshold: int("differenceThreshold")? as u32,
+            }),
+            "temporal" => Self::Temporal(TemporalFilter {
+                enable: enable?,
+                persistency_mode: PersistencyMode::from_raw(int("persistencyMode")?)?,
+                alpha: float("alpha")?,
+                delta: int("delta")? as i32,
+            }),
+            _ => return None,
+        })
+    }
+}
+
+/// Runtime configuration message for `ImageFilters`: the filter chain, applied in order.
+///
+/// Mirrors C++: `dai::ImageFiltersConfig`.
+///
+/// Note: this is also a `Buffer` message, so it can be sent to the node's `inputConfig`.
+pub struct ImageFiltersConfig {
+    buffer: Buffer,
+}
+
+impl ImageFiltersConfig {
+    /// An empty chain.
+    pub fn new() -> Result<Self> {
+        clear_error_flag();
+        let handle = depthai::dai_image_filters_config_new();
+        if handle.is_null() {
+            Err(last_error("failed to create ImageFiltersConfig"))
+        } else {
+            Ok(Self {
+                buffer: Buffer::from_handle(handle),
+            })
+        }
+    }
+
+    pub fn as_buffer(&self) -> &Buffer {
+        &self.buffer
+    }
+
+    pub fn into_buffer(self) -> Buffer {
+        self.buffer
+    }
+
+    pub(crate) fn from_handle(handle: DaiBuffer) -> Self {
+        Self {
+            buffer: Buffer::from_handle(handle),
+        }
+    }
+
+    pub(crate) fn handle(&self) -> DaiBuffer {
+        self.buffer.handle()
+    }
+
+    fn set_filter(&mut self, index: i64, filter: ImageFilter) -> Result<&mut Self> {
+        let json = CString::new(filter.to_json().to_string())
+            .map_err(|_| DepthaiError::new("invalid ImageFilters filter JSON"))?;
+        clear_error_flag();
+        let ok = unsafe { depthai::dai_image_filters_config_set_filter_json(self.handle(), index, json.as_ptr()) };
+        if ok {
+            Ok(self)
+        } else {
+            Err(last_error("failed to set ImageFiltersConfig filter"))
+        }
+    }
+
+    /// Append `filter` to the chain.
+    ///
+    /// Mirrors C++: `ImageFiltersConfig::insertFilter(FilterParams)`.
+    pub fn insert_filter(&mut self, filter: ImageFilter) -> Result<&mut Self> {
+        self.set_filter(-1, filter)
+    }
+
+    /// Replace the filter at `index`, e.g. to retune it at runtime.
+    ///
+    /// Mirrors C++: `ImageFiltersConfig::updateFilterAtIndex(index, FilterParams)`.
+    pub fn update_filter_at(&mut self, index: usize, filter: ImageFilter) -> Result<&mut Self> {
+        self.set_filter(index as i64, filter)
+    }
+
+    /// Replace the chain with a preset.
+    ///
+    /// Mirrors C++: `ImageFiltersConfig::setProfilePreset(ImageFiltersPresetMode)`.
+    pub fn set_profile_preset(&mut self, preset: ImageFiltersPresetMode) -> Result<&mut Self> {
+        clear_error_flag();
+        unsafe { depthai::dai_image_filters_config_set_profile_preset(self.handle(), c_int(preset as i32)) };
+        if let Some(err) = take_error_if_any("failed to set ImageFiltersConfig preset") {
+            Err(err)
+        } else {
+            Ok(self)
+        }
+    }
+
+    /// The chain, in order.
+    ///
+    /// Mirrors C++: `ImageFiltersConfig::filterParams`.
+    pub fn filters(&self) -> Result<Vec<ImageFilter>> {
+        clear_error_flag();
+        let ptr = unsafe { depthai::dai_image_filters_config_get_filters_json(self.handle()) };
+        if ptr.is_null() {
+            return Err(last_error("failed to get ImageFiltersConfig filters"));
+        }
+        let json = unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() };
+        unsafe { depthai::dai_free_cstring(ptr) };
+        let v: Value = serde_json::from_str(&json)
+            .map_err(|e| DepthaiError::new(format!("invalid ImageFiltersConfig JSON from depthai-core: {e}")))?;
+        v.as_array()
+            .into_iter()
+            .flatten()
+            .map(|f| {
+                ImageFilter::from_json(f)
+                    .ok_or_else(|| DepthaiError::new(format!("unsupported ImageFiltersConfig filter: {f}")))
+            })
+            .collect()
+    }
+}
+
+/// Chain of depth filters, see the [module docs](self).
+#[crate::native_node_wrapper(
+    native = "dai::node::ImageFilters",
+    inputs(input: ImageFrame, inputConfig: ImageFiltersConfig),
+    outputs(output: ImageFrame)
+)]
+pub struct ImageFiltersNode {
+    node: crate::pipeline::Node,
+}
+
+impl ImageFiltersNode {
+    /// Specify whether to run on host or device.
+    ///
+    /// Mirrors C++: `ImageFilters::setRunOnHost(bool)`.
+    pub fn set_run_on_host(&self, run_on_host: bool) {
+        clear_error_flag();
+        unsafe { depthai::dai_image_filters_set_run_on_host(self.node.handle(), run_on_host) };
+    }
+
+    /// Whether the node is configured to run on the host.
+    ///
+    /// Mirrors C++: `ImageFilters::runOnHost()`.
+    pub fn run_on_host(&self) -> Result<bool> {
+        clear_error_flag();
+        let v = unsafe { depthai::dai_image_filters_run_on_host(self.node.handle()) };
+        if let Some(err) = take_error_if_any("failed to read ImageFilters runOnHost") {
+            Err(err)
+        } else {
+            Ok(v)
+        }
+    }
+
+    /// Start from a preset filter chain.
+    ///
+    /// Mirrors C++: `ImageFilters::setDefaultProfilePreset(ImageFiltersPresetMode)`.
+    pub fn set_default_profile_preset(&self, preset: ImageFiltersPresetMode) {
+        clear_error_flag();
+        unsafe {
+            depthai::dai_image_filters_set_default_profile_preset(self.node.handle(), c_int(preset as i32))
+        };
+    }
+
+    /// Access the node's initial config (shared, modifications affect the node).
+    pub fn initial_config(&self) -> Result<ImageFiltersConfig> {
+        clear_error_flag();
+        let handle = unsafe { depthai::dai_image_filters_get_initial_config(self.node.handle()) };
+        if handle.is_null() {
+            Err(last_error("failed to get ImageFilters initialConfig"))
+        } else {
+            Ok(ImageFiltersConfig::from_handle(handle))
+        }
+    }
+}
@@ -1,9 +1,11 @@
 use autocxx::c_int;
 use depthai_sys::{depthai, DaiBuffer};
 
+use crate::camera::ImageFrame;
 use crate::common::ImageFrameType;
 use crate::error::{clear_error_flag, last_error, take_error_if_any, Result};
 use crate::host_node::Buffer;
+use crate::queue::InputQueue;
 
 /// Resize mode for `ImageManipConfig::set_output_size`.
 ///
@@ -62,6 +64,26 @@ pub enum PerformanceMode {
 /// Mirrors C++: `dai::ImageManipConfig`.
 ///
 /// Note: this is also a `Buffer` message, so it can be sent through XLink or script nodes.
+///
+/// A sent config is shared with the pipeline, so build each runtime update on a
+/// [`try_clone`](Self::try_clone) of a base config instead of mutating one that was already sent:
+///
+/// ```no_run
+/// # use depthai::Result;
+/// # use depthai::image_manip::{ImageManipConfig, ImageManipNode, ImageManipResizeMode};
+/// # fn f(manip: &ImageManipNode) -> Result<()> {
+/// let queue = manip.input_config_queue()?;
+/// let mut base = ImageManipConfig::new()?;
+/// base.set_output_size(640, 360, ImageManipResizeMode::Stretch);
+/// // Digital pan: slide a 50% window across the frame.
+/// for step in 0..=10 {
+///     let mut config = base.try_clone()?;
+///     config.add_crop_rect(step as f32 * 0.05, 0.25, 0.5, 0.5, true);
+///     queue.send_config(&config)?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
 pub struct ImageManipConfig {
     buffer: Buffer,
 }
@@ -97,6 +119,24 @@ impl ImageManipConfig {
         self.buffer.handle()
     }
 
+    /// Deep copy that can be changed without affecting `self`.
+    pub fn try_clone(&self) -> Result<Self> {
+        clear_error_flag();
+        let handle = unsafe { depthai::dai_image_manip_config_copy(self.handle()) };
+        if handle.is_null() {
+            Err(last_error("failed to copy ImageManipConfig"))
+        } else {
+            Ok(Self::from_handle(handle))
+        }
+    }
+
+    /// Restore the defaults: no operations, no output size, default frame type and flags.
+    pub fn reset(&mut self) -> &mut Self {
+        clear_error_flag();
+        unsafe { depthai::dai_image_manip_config_reset(self.handle()) };
+        self
+    }
+
     pub fn clear_ops(&mut self) -> &mut Self {
         clear_error_flag();
         unsafe { depthai::dai_image_manip_config_clear_ops(self.handle()) };
@@ -296,7 +336,7 @@ impl ImageManipConfig {
 }
 
 #[allow(non_snake_case)]
-#[crate::native_node_wrapper(native = "dai::node::ImageManip", inputs(inputConfig, inputImage), outputs(out))]
+#[crate::native_node_wrapper(native = "dai::node::ImageManip", inputs(inputConfig: ImageManipConfig, inputImage: ImageFrame), outputs(out: ImageFrame))]
 pub struct ImageManipNode {
     node: crate::pipeline::Node,
 }
@@ -343,6 +383,15 @@ impl ImageManipNode {
         unsafe { depthai::dai_image_manip_run(self.node.handle()) };
     }
 
+    /// Host queue to `inputConfig` for updating the config while the pipeline runs, see
+    /// [`InputQueue::send_config`].
+    ///
+    /// Must be called before the pipeline starts. Non-blocking: when configs arrive faster than
+    /// frames, the oldest pending ones are dropped.
+    pub fn input_config_queue(&self) -> Result<InputQueue> {
+        self.inputConfig()?.create_input_queue(4, false)
+    }
+
     /// Access the node's initial config (shared, modifications affect the node).
     pub fn initial_config(&self) -> Result<ImageManipConfig> {
         clear_error_flag();
@@ -0,0 +1,249 @@
+use std::time::Duration;
+
+use autocxx::c_int;
+use depthai_sys::{depthai, DaiImuData};
+
+use crate::error::{clear_error_flag, last_error, take_error_if_any, Result};
+use crate::host_node::ns_to_duration;
+
+/// IMU sensor reports that can be enabled on an [`ImuNode`].
+///
+/// Mirrors C++: `dai::IMUSensor`.
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImuSensor {
+    /// Calibrated acceleration including gravity, in m/s^2.
+    Accelerometer = 0x01,
+    /// Calibrated angular velocity, in rad/s.
+    GyroscopeCalibrated = 0x02,
+    /// Calibrated magnetic field, in uTesla.
+    MagnetometerCalibrated = 0x03,
+    /// Acceleration without gravity, in m/s^2.
+    LinearAcceleration = 0x04,
+    /// Orientation from accelerometer, gyroscope and magnetometer.
+    RotationVector = 0x05,
+    /// Gravity, in m/s^2.
+    Gravity = 0x06,
+    GyroscopeUncalibrated = 0x07,
+    /// Orientation from accelerometer and gyroscope (no magnetometer).
+    GameRotationVector = 0x08,
+    /// Orientation from accelerometer and magnetometer (no gyroscope).
+    GeomagneticRotationVector = 0x09,
+    MagnetometerUncalibrated = 0x0f,
+    AccelerometerRaw = 0x14,
+    GyroscopeRaw = 0x15,
+    MagnetometerRaw = 0x16,
+    ArvrStabilizedRotationVector = 0x28,
+    ArvrStabilizedGameRotationVector = 0x29,
+}
+
+/// Reported accuracy of an IMU sample.
+///
+/// Mirrors C++: `dai::IMUReport::Accuracy`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ImuAccuracy {
+    Unreliable,
+    Low,
+    Medium,
+    High,
+}
+
+impl ImuAccuracy {
+    fn from_raw(value: i32) -> Self {
+        match value {
+            1 => Self::Low,
+            2 => Self::Medium,
+            3 => Self::High,
+            _ => Self::Unreliable,
+        }
+    }
+}
+
+/// A 3-axis IMU sample (accelerometer, gyroscope or magnetometer).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ImuVectorReport {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub accuracy: ImuAccuracy,
+    /// Timestamp on the host steady clock.
+    pub timestamp: Duration,
+    pub sequence: i32,
+}
+
+/// An orientation sample as a unit quaternion.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ImuRotationReport {
+    pub i: f32,
+    pub j: f32,
+    pub k: f32,
+    pub real: f32,
+    /// Estimated heading accuracy in radians.
+    pub rotation_accuracy: f32,
+    pub accuracy: ImuAccuracy,
+    /// Timestamp on the host steady clock.
+    pub timestamp: Duration,
+    pub sequence: i32,
+}
+
+/// One IMU packet. Reports whose sensor is not enabled are `None`.
+///
+/// Mirrors C++: `dai::IMUPacket`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ImuPacket {
+    pub accelerometer: Option<ImuVectorReport>,
+    pub gyroscope: Option<ImuVectorReport>,
+    pub magnetometer: Option<ImuVectorReport>,
+    pub rotation_vector: Option<ImuRotationReport>,
+}
+
+/// A batch of IMU packets.
+///
+/// Mirrors C++: `dai::IMUData`.
+pub struct ImuData {
+    handle: DaiImuData,
+}
+
+unsafe impl Send for ImuData {}
+unsafe impl Sync for ImuData {}
+
+impl Drop for ImuData {
+    fn drop(&mut self) {
+        if !self.handle.is_null() {
+            unsafe { depthai::dai_imu_data_release(self.handle) };
+            self.handle = std::ptr::null_mut();
+        }
+    }
+}
+
+struct RawReport {
+    values: [f32; 5],
+    timestamp: Duration,
+    sequence: i32,
+    accuracy: ImuAccuracy,
+}
+
+impl ImuData {
+    pub(crate) fn from_handle(handle: DaiImuData) -> Self {
+        Self { handle }
+    }
+
+    pub fn len(&self) -> usize {
+        unsafe { depthai::dai_imu_data_get_num_packets(self.handle) }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// All packets in this batch, oldest first.
+    pub fn packets(&self) -> Result<Vec<ImuPacket>> {
+        (0..self.len()).map(|i| self.packet(i)).collect()
+    }
+
+    pub fn packet(&self, index: usize) -> Result<ImuPacket> {
+        let vector = |kind| {
+            self.report(index, kind).map(|r| {
+                r.map(|r| ImuVectorReport {
+                    x: r.values[0],
+                    y: r.values[1],
+                    z: r.values[2],
+                    accuracy: r.accuracy,
+                    timestamp: r.timestamp,
+                    sequence: r.sequence,
+                })
+            })
+        };
+        Ok(ImuPacket {
+            accelerometer: vector(0)?,
+            gyroscope: vector(1)?,
+            magnetometer: vector(2)?,
+            rotation_vector: self.report(index, 3)?.map(|r| ImuRotationReport {
+                i: r.values[0],
+                j: r.values[1],
+                k: r.values[2],
+                real: r.values[3],
+                rotation_accuracy: r.values[4],
+                accuracy: r.accuracy,
+                timestamp: r.timestamp,
+                sequence: r.sequence,
+            }),
+        })
+    }
+
+    /// Report `kind` of packet `index`, `None` when that sensor produced no sample (zero timestamp).
+    fn report(&self, index: usize, kind: i32) -> Result<Option<RawReport>> {
+        let mut values = [0f32; 5];
+        let mut timestamp_ns = 0i64;
+        let mut sequence = c_int(0);
+        let mut accuracy = c_int(0);
+        clear_error_flag();
+        let ok = unsafe {
+            depthai::dai_imu_data_get_report(
+                self.handle,
+                index,
+                c_int(kind),
+                values.as_mut_ptr(),
+                &mut timestamp_ns,
+                &mut sequence,
+                &mut accuracy,
+            )
+        };
+        if !ok {
+            return Err(last_error("failed to read IMU report"));
+        }
+        if timestamp_ns == 0 {
+            return Ok(None);
+        }
+        Ok(Some(RawReport {
+            values,
+            timestamp: ns_to_duration(timestamp_ns),
+            sequence: sequence.0,
+            accuracy: ImuAccuracy::from_raw(accuracy.0),
+        }))
+    }
+}
+
+#[crate::native_node_wrapper(native = "dai::node::IMU", outputs(out: ImuData))]
+pub struct ImuNode {
+    node: crate::pipeline::Node,
+}
+
+impl ImuNode {
+    /// Enable a sensor report at `report_rate_hz`.
+    ///
+    /// Mirrors C++: `IMU::enableIMUSensor(IMUSensor, uint32_t)`.
+    pub fn enable_sensor(&self, sensor: ImuSensor, report_rate_hz: u32) -> Result<()> {
+        clear_error_flag();
+        unsafe { depthai::dai_imu_enable_sensor(self.node.handle(), c_int(sensor as i32), c_int(report_rate_hz as i32)) };
+        match take_error_if_any("failed to enable IMU sensor") {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    /// Enable several sensors at the same rate.
+    pub fn enable_sensors(&self, sensors: &[ImuSensor], report_rate_hz: u32) -> Result<()> {
+        sensors.iter().try_for_each(|&s| self.enable_sensor(s, report_rate_hz))
+    }
+
+    /// Send a batch once this many packets are available.
+    ///
+    /// Mirrors C++: `IMU::setBatchReportThreshold(int)`.
+    pub fn set_batch_report_threshold(&self, threshold: i32) {
+        clear_error_flag();
+        unsafe { depthai::dai_imu_set_batch_report_threshold(self.node.handle(), c_int(threshold)) };
+    }
+
+    /// Maximum number of packets in one batch.
+    ///
+    /// Mirrors C++: `IMU::setMaxBatchReports(int)`.
+    pub fn set_max_batch_reports(&self, max_batch_reports: i32) {
+        clear_error_flag();
+        unsafe { depthai::dai_imu_set_max_batch_reports(self.node.handle(), c_int(max_batch_reports)) };
+    }
+}
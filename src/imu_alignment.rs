@@ -0,0 +1,348 @@
+//! Per-frame IMU state for frames and IMU packets of the same device.
+//!
+//! [`ImuFrameAligner`] consumes [`ImuData`] batches, tracks the device orientation with a
+//! complementary filter (gyroscope integration, tilt corrected towards the accelerometer's
+//! gravity), and interpolates angular velocity and orientation at any timestamp it has IMU
+//! samples around, typically the timestamp of an image frame. It is a building block for
+//! rolling-shutter correction, which queries a pose per row, and for VIO front ends that
+//! pre-integrate between frames:
+//!
+//! ```no_run
+//! # use depthai::camera::ImageFrame;
+//! # use depthai::imu_alignment::ImuFrameAligner;
+//! # use depthai::{ImuData, Result};
+//! # fn f(imu: &ImuData, frame: &ImageFrame) -> Result<()> {
+//! let mut aligner = ImuFrameAligner::new();
+//! aligner.push_imu_data(imu)?;
+//! if let Some(pose) = aligner.pose_for(frame)? {
+//!     println!("{:?} rad/s, {:?}", pose.angular_velocity, pose.orientation);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Enable [`ImuSensor::GyroscopeCalibrated`](crate::imu::ImuSensor::GyroscopeCalibrated) and
+//! [`ImuSensor::Accelerometer`](crate::imu::ImuSensor::Accelerometer); without the accelerometer
+//! the orientation is pure gyroscope integration and drifts. Yaw is never corrected.
+
+use std::collections::VecDeque;
+use std::ops::Mul;
+use std::time::Duration;
+
+use crate::camera::ImageFrame;
+use crate::error::Result;
+use crate::imu::{ImuData, ImuPacket};
+
+/// Standard gravity, in m/s^2.
+const GRAVITY: f32 = 9.806_65;
+/// Accelerometer samples further than this from 1 g are accelerating and don't correct tilt.
+const GRAVITY_TOLERANCE: f32 = 0.1;
+
+/// A rotation as a unit quaternion.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quaternion {
+    pub w: f32,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl Default for Quaternion {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Quaternion {
+    pub const IDENTITY: Self = Self {
+        w: 1.0,
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+    };
+
+    /// Rotation of `angle` radians around `axis`, which needn't be normalized.
+    pub fn from_axis_angle(axis: [f32; 3], angle: f32) -> Self {
+        let norm = length(axis);
+        if norm == 0.0 {
+            return Self::IDENTITY;
+        }
+        let (sin, cos) = (angle / 2.0).sin_cos();
+        let s = sin / norm;
+        Self {
+            w: cos,
+            x: axis[0] * s,
+            y: axis[1] * s,
+            z: axis[2] * s,
+        }
+    }
+
+    /// Rotation by the vector's length around its direction, e.g. angular velocity times time.
+    pub fn from_rotation_vector(v: [f32; 3]) -> Self {
+        Self::from_axis_angle(v, length(v))
+    }
+
+    /// The smallest rotation taking the direction of `from` onto the direction of `to`.
+    pub fn from_to(from: [f32; 3], to: [f32; 3]) -> Self {
+        let (from, to) = (normalized(from), normalized(to));
+        let axis = cross(from, to);
+        let angle = dot(from, to).clamp(-1.0, 1.0).acos();
+        if length(axis) < 1e-6 && angle > 1.0 {
+            // Opposite directions: any perpendicular axis works.
+            let other = if from[0].abs() < 0.9 {
+                [1.0, 0.0, 0.0]
+            } else {
+                [0.0, 1.0, 0.0]
+            };
+            return Self::from_axis_angle(cross(from, other), angle);
+        }
+        Self::from_axis_angle(axis, angle)
+    }
+
+    pub fn conjugate(self) -> Self {
+        Self {
+            w: self.w,
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+
+    pub fn normalize(self) -> Self {
+        let norm = (self.w * self.w + self.x * self.x + self.y * self.y + self.z * self.z).sqrt();
+        if norm == 0.0 {
+            return Self::IDENTITY;
+        }
+        Self {
+            w: self.w / norm,
+            x: self.x / norm,
+            y: self.y / norm,
+            z: self.z / norm,
+        }
+    }
+
+    pub fn rotate(self, v: [f32; 3]) -> [f32; 3] {
+        let p = Self {
+            w: 0.0,
+            x: v[0],
+            y: v[1],
+            z: v[2],
+        };
+        let r = self * p * self.conjugate();
+        [r.x, r.y, r.z]
+    }
+
+    /// Rotation angle in radians, in `0..=PI`.
+    pub fn angle(self) -> f32 {
+        2.0 * self.w.abs().clamp(0.0, 1.0).acos()
+    }
+
+    /// Spherical interpolation from `self` (`t = 0`) to `other` (`t = 1`) along the shorter arc.
+    pub fn slerp(self, other: Self, t: f32) -> Self {
+        let mut cos = self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z;
+        let mut other = other;
+        if cos < 0.0 {
+            cos = -cos;
+            other = Self {
+                w: -other.w,
+                x: -other.x,
+                y: -other.y,
+                z: -other.z,
+            };
+        }
+        let (a, b) = if cos > 0.9995 {
+            // Nearly equal: linear interpolation is exact enough and avoids dividing by ~0.
+            (1.0 - t, t)
+        } else {
+            let theta = cos.acos();
+            let sin = theta.sin();
+            (((1.0 - t) * theta).sin() / sin, (t * theta).sin() / sin)
+        };
+        Self {
+            w: a * self.w + b * other.w,
+            x: a * self.x + b * other.x,
+            y: a * self.y + b * other.y,
+            z: a * self.z + b * other.z,
+        }
+        .normalize()
+    }
+}
+
+/// Hamilton product: `self` applied after `rhs`.
+impl Mul for Quaternion {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            w: self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+            x: self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            y: self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            z: self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+        }
+    }
+}
+
+fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn length(v: [f32; 3]) -> f32 {
+    dot(v, v).sqrt()
+}
+
+fn normalized(v: [f32; 3]) -> [f32; 3] {
+    let norm = length(v);
+    if norm == 0.0 {
+        v
+    } else {
+        [v[0] / norm, v[1] / norm, v[2] / norm]
+    }
+}
+
+/// IMU state at a timestamp, see [`ImuFrameAligner::pose_at`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FramePose {
+    /// Timestamp on the host steady clock.
+    pub timestamp: Duration,
+    /// Angular velocity in the IMU frame, in rad/s.
+    pub angular_velocity: [f32; 3],
+    /// Rotation from the IMU frame to a gravity-aligned world frame (z up) whose yaw is set by
+    /// the first sample.
+    pub orientation: Quaternion,
+}
+
+/// Interpolates IMU state at frame timestamps, see the [module docs](self).
+pub struct ImuFrameAligner {
+    gain: f32,
+    history: Duration,
+    samples: VecDeque<FramePose>,
+}
+
+impl Default for ImuFrameAligner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ImuFrameAligner {
+    /// Aligner with a filter gain of 0.02 keeping one second of samples.
+    pub fn new() -> Self {
+        Self {
+            gain: 0.02,
+            history: Duration::from_secs(1),
+            samples: VecDeque::new(),
+        }
+    }
+
+    /// Fraction of the tilt error towards the accelerometer removed per sample, in `0..=1`:
+    /// higher trusts the accelerometer more (less drift, more noise from vibrations).
+    pub fn set_filter_gain(&mut self, gain: f32) {
+        self.gain = gain.clamp(0.0, 1.0);
+    }
+
+    /// How far before the newest sample timestamps can still be queried.
+    pub fn set_history(&mut self, history: Duration) {
+        self.history = history;
+    }
+
+    /// Add the packets of `data`; packets without a gyroscope report are skipped.
+    pub fn push_imu_data(&mut self, data: &ImuData) -> Result<()> {
+        for packet in data.packets()? {
+            self.push_packet(&packet);
+        }
+        Ok(())
+    }
+
+    /// Add one packet, timestamped by its gyroscope report; `false` if it has none.
+    pub fn push_packet(&mut self, packet: &ImuPacket) -> bool {
+        let Some(gyro) = packet.gyroscope else {
+            return false;
+        };
+        let accel = packet.accelerometer.map(|a| [a.x, a.y, a.z]);
+        self.push_sample(gyro.timestamp, [gyro.x, gyro.y, gyro.z], accel);
+        true
+    }
+
+    /// Add a gyroscope sample in rad/s, with the accelerometer sample in m/s^2 if there is one.
+    /// Samples older than the newest one are ignored.
+    pub fn push_sample(&mut self, timestamp: Duration, angular_velocity: [f32; 3], accel: Option<[f32; 3]>) {
+        let orientation = match self.samples.back() {
+            None => match accel {
+                Some(a) if length(a) > 0.0 => Quaternion::from_to(a, [0.0, 0.0, 1.0]),
+                _ => Quaternion::IDENTITY,
+            },
+            Some(prev) if timestamp < prev.timestamp => return,
+            Some(prev) => {
+                let dt = (timestamp - prev.timestamp).as_secs_f32();
+                // Trapezoidal integration of the body-frame angular velocity.
+                let w = [0, 1, 2].map(|i| (prev.angular_velocity[i] + angular_velocity[i]) / 2.0 * dt);
+                let predicted = (prev.orientation * Quaternion::from_rotation_vector(w)).normalize();
+                match accel {
+                    Some(a) if (length(a) / GRAVITY - 1.0).abs() <= GRAVITY_TOLERANCE => {
+                        // Tilt the measured "up", seen in the world frame, a bit towards z.
+                        let up = predicted.rotate(a);
+                        let correction = Quaternion::from_to(up, [0.0, 0.0, 1.0]);
+                        let partial = Quaternion::IDENTITY.slerp(correction, self.gain);
+                        (partial * predicted).normalize()
+                    }
+                    _ => predicted,
+                }
+            }
+        };
+        self.samples.push_back(FramePose {
+            timestamp,
+            angular_velocity,
+            orientation,
+        });
+        while self
+            .samples
+            .front()
+            .is_some_and(|s| timestamp.saturating_sub(s.timestamp) > self.history)
+        {
+            self.samples.pop_front();
+        }
+    }
+
+    /// Timestamp of the newest sample; frames up to it can be aligned.
+    pub fn latest_timestamp(&self) -> Option<Duration> {
+        self.samples.back().map(|s| s.timestamp)
+    }
+
+    /// IMU state at `timestamp`, interpolated between the samples around it. `None` until a
+    /// sample at or after `timestamp` arrived, or once it is older than the kept history.
+    pub fn pose_at(&self, timestamp: Duration) -> Option<FramePose> {
+        let after = self.samples.partition_point(|s| s.timestamp < timestamp);
+        let next = self.samples.get(after)?;
+        if next.timestamp == timestamp {
+            return Some(*next);
+        }
+        let prev = self.samples.get(after.checked_sub(1)?)?;
+        let t = (timestamp - prev.timestamp).as_secs_f32() / (next.timestamp - prev.timestamp).as_secs_f32();
+        Some(FramePose {
+            timestamp,
+            angular_velocity: [0, 1, 2]
+                .map(|i| prev.angular_velocity[i] + (next.angular_velocity[i] - prev.angular_velocity[i]) * t),
+            orientation: prev.orientation.slerp(next.orientation, t),
+        })
+    }
+
+    /// [`pose_at`](Self::pose_at) the timestamp of `frame`.
+    pub fn pose_for(&self, frame: &ImageFrame) -> Result<Option<FramePose>> {
+        Ok(self.pose_at(frame.timestamp()?))
+    }
+
+    /// Rotation of the IMU frame between two timestamps: from its orientation at `from` to its
+    /// orientation at `to`, in the IMU frame at `from`.
+    pub fn rotation_between(&self, from: Duration, to: Duration) -> Option<Quaternion> {
+        let (a, b) = (self.pose_at(from)?, self.pose_at(to)?);
+        Some((a.orientation.conjugate() * b.orientation).normalize())
+    }
+}
@@ -0,0 +1,251 @@
+//! Decoding MJPEG frames on the host (requires the `jpeg` feature).
+//!
+//! An MJPEG [`VideoEncoderNode`](crate::video_encoder::VideoEncoderNode) output costs a fraction
+//! of the USB bandwidth of raw frames. [`EncodedFrame::decode_to_image_frame`] turns such a frame
+//! back into an [`ImageFrame`] (`RGB888i`, or `GRAY8` for grayscale JPEGs) with the pure-Rust
+//! decoder of the `image` crate, keeping the timestamp and sequence number. At high resolutions
+//! and frame rates one core can't keep up; [`JpegDecoder`] decodes on several worker threads and
+//! returns the frames in order:
+//!
+//! ```no_run
+//! # use depthai::encoded_frame::EncodedFrameQueue;
+//! # use depthai::jpeg::JpegDecoder;
+//! # use depthai::Result;
+//! # fn run(queue: &EncodedFrameQueue) -> Result<()> {
+//! let mut decoder = JpegDecoder::new(4)?;
+//! while let Some(encoded) = queue.blocking_next(None)? {
+//!     decoder.submit(&encoded)?;
+//!     while let Some(frame) = decoder.try_next() {
+//!         let frame = frame?;
+//!         println!("{}x{}", frame.width(), frame.height());
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::BTreeMap;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use crate::camera::{ImageFrame, ImageFrameType};
+use crate::encoded_frame::{EncodedFrame, EncodedFrameProfile};
+use crate::error::{DepthaiError, Result};
+
+/// Pixels of a decoded JPEG, before they are copied into an [`ImageFrame`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedJpeg {
+    pub width: u32,
+    pub height: u32,
+    /// `RGB888i` or `GRAY8`.
+    pub frame_type: ImageFrameType,
+    pub data: Vec<u8>,
+}
+
+impl DecodedJpeg {
+    /// Copy into a host-side frame.
+    pub fn to_image_frame(&self) -> Result<ImageFrame> {
+        ImageFrame::new(self.width, self.height, self.frame_type, &self.data)
+    }
+}
+
+/// Decode one JPEG image.
+pub fn decode(data: &[u8]) -> Result<DecodedJpeg> {
+    let image = image::load_from_memory_with_format(data, image::ImageFormat::Jpeg)
+        .map_err(|e| DepthaiError::new(format!("failed to decode JPEG: {e}")))?;
+    let (width, height) = (image.width(), image.height());
+    if image.color().has_color() {
+        Ok(DecodedJpeg {
+            width,
+            height,
+            frame_type: ImageFrameType::RGB888i,
+            data: image.into_rgb8().into_raw(),
+        })
+    } else {
+        Ok(DecodedJpeg {
+            width,
+            height,
+            frame_type: ImageFrameType::GRAY8,
+            data: image.into_luma8().into_raw(),
+        })
+    }
+}
+
+/// Bytes and capture information of a frame submitted to a [`JpegDecoder`].
+struct Job {
+    index: u64,
+    data: Vec<u8>,
+    timestamp: Option<Duration>,
+    sequence: Option<i64>,
+}
+
+struct Decoded {
+    index: u64,
+    result: Result<DecodedJpeg>,
+    timestamp: Option<Duration>,
+    sequence: Option<i64>,
+}
+
+/// Decodes JPEG frames on worker threads, returning them in submission order. See the
+/// [module docs](self).
+pub struct JpegDecoder {
+    jobs: Option<Sender<Job>>,
+    results: Receiver<Decoded>,
+    workers: Vec<JoinHandle<()>>,
+    next_submitted: u64,
+    next_returned: u64,
+    /// Decoded out of order, waiting for earlier frames.
+    reordered: BTreeMap<u64, Decoded>,
+}
+
+impl JpegDecoder {
+    /// Start `threads` decoding threads (at least 1).
+    pub fn new(threads: usize) -> Result<Self> {
+        let (jobs, job_rx) = mpsc::channel::<Job>();
+        let (result_tx, results) = mpsc::channel();
+        let job_rx = Arc::new(Mutex::new(job_rx));
+        let mut workers = Vec::new();
+        for i in 0..threads.max(1) {
+            let job_rx = Arc::clone(&job_rx);
+            let result_tx = result_tx.clone();
+            let worker = std::thread::Builder::new()
+                .name(format!("depthai-jpeg-{i}"))
+                .spawn(move || {
+                    loop {
+                        // Hold the lock only while waiting for a job, not while decoding it.
+                        let job = match job_rx.lock() {
+                            Ok(rx) => rx.recv(),
+                            Err(_) => return,
+                        };
+                        let Ok(job) = job else { return };
+                        let decoded = Decoded {
+                            index: job.index,
+                            result: decode(&job.data),
+                            timestamp: job.timestamp,
+                            sequence: job.sequence,
+                        };
+                        if result_tx.send(decoded).is_err() {
+                            return;
+                        }
+                    }
+                })
+                .map_err(|e| DepthaiError::new(format!("jpeg: failed to spawn decoder thread: {e}")))?;
+            workers.push(worker);
+        }
+        Ok(Self {
+            jobs: Some(jobs),
+            results,
+            workers,
+            next_submitted: 0,
+            next_returned: 0,
+            reordered: BTreeMap::new(),
+        })
+    }
+
+    pub fn threads(&self) -> usize {
+        self.workers.len()
+    }
+
+    /// Frames submitted but not returned yet.
+    pub fn pending(&self) -> usize {
+        (self.next_submitted - self.next_returned) as usize
+    }
+
+    /// Queue an MJPEG frame for decoding; its timestamp and sequence number carry over.
+    pub fn submit(&mut self, frame: &EncodedFrame) -> Result<()> {
+        check_jpeg(frame)?;
+        let (timestamp, sequence) = (frame.timestamp()?, frame.sequence_num()?);
+        self.send(frame.bytes(), Some(timestamp), Some(sequence))
+    }
+
+    /// Queue a JPEG image from elsewhere (e.g. a recorded `.mjpeg` file); the decoded frame is
+    /// timestamped when it is returned.
+    pub fn submit_bytes(&mut self, data: Vec<u8>) -> Result<()> {
+        self.send(data, None, None)
+    }
+
+    fn send(&mut self, data: Vec<u8>, timestamp: Option<Duration>, sequence: Option<i64>) -> Result<()> {
+        let job = Job {
+            index: self.next_submitted,
+            data,
+            timestamp,
+            sequence,
+        };
+        self.jobs
+            .as_ref()
+            .and_then(|jobs| jobs.send(job).ok())
+            .ok_or_else(|| DepthaiError::new("jpeg: decoder threads have stopped"))?;
+        self.next_submitted += 1;
+        Ok(())
+    }
+
+    /// Next decoded frame if it's ready, `None` if it isn't or nothing is pending.
+    pub fn try_next(&mut self) -> Option<Result<ImageFrame>> {
+        while let Ok(decoded) = self.results.try_recv() {
+            self.reordered.insert(decoded.index, decoded);
+        }
+        self.take_ready()
+    }
+
+    fn take_ready(&mut self) -> Option<Result<ImageFrame>> {
+        let decoded = self.reordered.remove(&self.next_returned)?;
+        self.next_returned += 1;
+        Some(decoded.result.and_then(|d| {
+            let frame = d.to_image_frame()?;
+            if let Some(timestamp) = decoded.timestamp {
+                frame.set_timestamp(timestamp)?;
+            }
+            if let Some(sequence) = decoded.sequence {
+                frame.set_sequence_num(sequence)?;
+            }
+            Ok(frame)
+        }))
+    }
+}
+
+impl Iterator for JpegDecoder {
+    type Item = Result<ImageFrame>;
+
+    /// Wait for the next decoded frame, `None` if nothing is pending.
+    fn next(&mut self) -> Option<Result<ImageFrame>> {
+        while self.pending() > 0 && !self.reordered.contains_key(&self.next_returned) {
+            match self.results.recv() {
+                Ok(decoded) => {
+                    self.reordered.insert(decoded.index, decoded);
+                }
+                Err(_) => return Some(Err(DepthaiError::new("jpeg: decoder threads have stopped"))),
+            }
+        }
+        self.take_ready()
+    }
+}
+
+impl Drop for JpegDecoder {
+    fn drop(&mut self) {
+        // Closing the job channel stops the workers once their current frame is done.
+        self.jobs.take();
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+fn check_jpeg(frame: &EncodedFrame) -> Result<()> {
+    match frame.profile() {
+        Some(EncodedFrameProfile::Jpeg) => Ok(()),
+        other => Err(DepthaiError::new(format!("expected an MJPEG frame, got {other:?}"))),
+    }
+}
+
+impl EncodedFrame {
+    /// Decode an MJPEG frame on the calling thread, see [`jpeg`](crate::jpeg).
+    pub fn decode_to_image_frame(&self) -> Result<ImageFrame> {
+        check_jpeg(self)?;
+        let frame = decode(&self.bytes())?.to_image_frame()?;
+        frame.set_timestamp(self.timestamp()?)?;
+        frame.set_sequence_num(self.sequence_num()?)?;
+        Ok(frame)
+    }
+}
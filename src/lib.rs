@@ -87,6 +87,22 @@
 //! # }
 //! ```
 //!
+//! #### From a config file
+//!
+//! Nodes, their settings and links can be described in JSON (or YAML with the `yaml` feature)
+//! so deployments can change resolutions or stereo presets without recompiling.
+//! See [`pipeline::PipelineConfig`] for the format.
+//!
+//! ```no_run
+//! # use depthai::Result;
+//! # fn main() -> Result<()> {
+//! let configured = depthai::pipeline::from_config("pipeline.yaml")?;
+//! let queue = configured.nodes.output("left", "out")?.create_queue(4, false)?;
+//! configured.pipeline.start()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! #### Composite nodes
 //!
 //! Use the `#[depthai_composite]` macro to bundle multiple nodes:
@@ -184,7 +200,44 @@
 //! # }
 //! ```
 //!
-//! #### RerunHostNode (optional rerun feature)
+//! Host nodes can declare several named outputs and send host-created messages on them:
+//!
+//! ```no_run
+//! # use depthai::{Buffer, Input, Pipeline, Result, ThreadedHostNodeContext, ThreadedHostNodeImpl, TypedOutput};
+//! # use depthai::camera::ImageFrame;
+//! struct Preprocess {
+//!     input: Input,
+//!     image: TypedOutput<ImageFrame>,
+//!     meta: TypedOutput<Buffer>,
+//! }
+//!
+//! impl ThreadedHostNodeImpl for Preprocess {
+//!     fn run(&mut self, ctx: &ThreadedHostNodeContext) {
+//!         while ctx.is_running() {
+//!             let Ok(frame) = self.input.get_frame() else { break };
+//!             let _ = self.image.send(&frame);
+//!             if let Ok(meta) = Buffer::from_bytes(&[frame.width() as u8]) {
+//!                 let _ = meta.set_timestamp(frame.timestamp().unwrap_or_default());
+//!                 let _ = self.meta.send(&meta);
+//!             }
+//!         }
+//!     }
+//! }
+//!
+//! # fn main() -> Result<()> {
+//! # let pipeline = Pipeline::new().build()?;
+//! let host = pipeline.create_threaded_host_node(|node| {
+//!     Ok(Preprocess {
+//!         input: node.create_input(Some("in"))?,
+//!         image: node.create_typed_output("image")?,
+//!         meta: node.create_typed_output("detections")?,
+//!     })
+//! })?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! #### RerunHostNode (optional rerun / rerun-native feature)
 //!
 //! Visualize data streams using Rerun:
 //!
@@ -210,7 +263,55 @@
 //! # fn main() {}
 //! ```
 //!
-//! Requires the `rerun` feature and Tokio runtime support.
+//! One node can also log several streams, each on its own named input:
+//!
+//! ```no_run
+//! # #[cfg(feature = "rerun-native")]
+//! # use depthai::{DepthUnit, Pipeline, Result, RerunHostNode, RerunHostNodeConfig, RerunInput};
+//! # #[cfg(feature = "rerun-native")]
+//! # fn main() -> Result<()> {
+//! # let pipeline = Pipeline::new().build()?;
+//! # let depth_out = pipeline.create_node("dai::node::StereoDepth")?.output("depth")?;
+//! let host = pipeline.create_with::<RerunHostNode, _>(RerunHostNodeConfig {
+//!     inputs: vec![
+//!         RerunInput::image("rgb", "camera/rgb"),
+//!         RerunInput::depth("depth", "camera/depth", DepthUnit::Millimeter),
+//!         RerunInput::point_cloud("pcl", "world/pcl", DepthUnit::Meter),
+//!         RerunInput::detections("dets", "camera/rgb/detections", (640, 400)),
+//!     ],
+//!     ..Default::default()
+//! })?;
+//! depth_out.link(&host.input("depth")?)?;
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "rerun-native"))]
+//! # fn main() {}
+//! ```
+//!
+//! The `rerun` feature brings the web viewer, its server and Tokio, which make up most of the
+//! build time; `rerun-native` only pulls in the Rerun SDK and logs to the native viewer
+//! ([`RerunViewer::Native`], the default). The logging itself lives in
+//! [`RerunSink`], one [`sink::FrameSink`] implementation; other viewers plug into the same
+//! [`SinkHostNode`] plumbing.
+//!
+//! #### OpenCV (optional opencv feature)
+//!
+//! With the `opencv` feature, frames convert to and from `opencv::core::Mat`:
+//!
+//! ```no_run
+//! # #[cfg(feature = "opencv")]
+//! # fn f(frame: depthai::camera::ImageFrame) -> depthai::Result<()> {
+//! use depthai::camera::{ImageFrame, ImageFrameType};
+//!
+//! // Zero-copy view over the frame memory (NV12 as a `height * 3 / 2` row GRAY Mat).
+//! let view = frame.as_cv_mat()?;
+//! // Converted copy through DepthAI's `getCvFrame` (needs `DEPTHAI_OPENCV_SUPPORT=1`).
+//! let bgr = frame.to_cv_mat()?;
+//! let back = ImageFrame::from_cv_mat(&bgr, ImageFrameType::BGR888i)?;
+//! # let _ = (view, back);
+//! # Ok(())
+//! # }
+//! ```
 //!
 //! ### Node linking
 //!
@@ -420,14 +521,33 @@
 //! ```ignore
 //! #[native_node_wrapper(
 //!     native = "dai::node::Camera",
-//!     inputs(inputControl, mockIsp),
-//!     outputs(raw)
+//!     inputs(inputControl, mockIsp: ImageFrame),
+//!     outputs(raw: ImageFrame)
 //! )]
 //! pub struct CameraNode {
 //!     node: crate::pipeline::Node,
 //! }
 //! ```
 //!
+//! Ports annotated with a message type (`raw: ImageFrame`) return [`TypedOutput`] /
+//! [`TypedInput`], so linking e.g. a `PointCloudData` output to an `ImageFrame` input is a
+//! compile error rather than a device-side failure.
+//!
+//! `props(...)` generates setters for simple node properties, written through
+//! [`Node::set_property`](pipeline::Node::set_property) by their JSON path instead of a
+//! dedicated FFI shim each:
+//!
+//! ```ignore
+//! #[native_node_wrapper(
+//!     native = "dai::node::ObjectTracker",
+//!     props(tracker_threshold: f32, max_objects_to_track = "maxObjectsToTrack": u32)
+//! )]
+//! pub struct ObjectTrackerNode {
+//!     node: crate::pipeline::Node,
+//! }
+//! // tracker.set_tracker_threshold(0.5)?;
+//! ```
+//!
 //! ### `#[depthai_host_node]`
 //!
 //! Creates synchronous host nodes:
@@ -485,6 +605,34 @@
 //!     }
 //! }
 //! ```
+//!
+//! With `default`, members are created with [`Pipeline::create`] and no `new` is needed;
+//! `#[link]` wires members together and `#[forward]` re-exports their ports as methods:
+//!
+//! ```no_run
+//! # use depthai::{depthai_composite, Pipeline, Result};
+//! # use depthai::camera::ImageFrame;
+//! # use depthai::stereo_depth::StereoDepthNode;
+//! # use depthai::rgbd::RgbdNode;
+//! #[depthai_composite(default)]
+//! #[link(from = "stereo.depth", to = "rgbd.inDepth")]
+//! #[forward(output = "out", from = "rgbd", name = "rgbd_out")]
+//! pub struct DepthBundle {
+//!     #[forward(input = "left", ty = ImageFrame)]
+//!     #[forward(input = "right", ty = ImageFrame)]
+//!     pub stereo: StereoDepthNode,
+//!     #[forward(input = "inColor", name = "color", ty = ImageFrame)]
+//!     pub rgbd: RgbdNode,
+//! }
+//!
+//! # fn main() -> Result<()> {
+//! # let pipeline = Pipeline::new().build()?;
+//! let bundle = pipeline.create::<DepthBundle>()?;
+//! let rgbd = bundle.rgbd_out()?.create_message_queue(4, false)?;
+//! # let _ = (bundle.left()?, bundle.color()?, rgbd);
+//! # Ok(())
+//! # }
+//! ```
 
 pub use depthai_sys as bindings;
 
@@ -496,35 +644,120 @@ pub use depthai_macros::depthai_composite;
 pub use depthai_macros::depthai_host_node;
 pub use depthai_macros::depthai_threaded_host_node;
 
+#[cfg(feature = "arrow")]
+pub mod analytics;
+pub mod annotations;
+pub mod calibration;
 pub mod camera;
+pub mod camera_control;
+pub mod camera_feed;
+pub mod camera_features;
+pub mod cancel;
+pub mod cast;
 pub mod common;
+pub mod convert;
+pub mod depth;
+pub mod depth_colorizer;
+pub mod detection_parser;
+pub mod detections;
+#[cfg(feature = "opencv")]
+mod cv;
 pub mod device;
+pub mod device_session;
+pub mod diagnostics;
 pub mod error;
+pub mod frame_metadata;
+pub mod frame_pool;
+pub mod frame_stats;
+pub mod host_executor;
 pub mod host_node;
+#[cfg(feature = "host-stereo")]
+pub mod host_stereo;
 pub mod encoded_frame;
+pub mod illumination;
 pub mod image_align;
+pub mod image_filters;
 pub mod image_manip;
+pub mod imu;
+pub mod imu_alignment;
+#[cfg(feature = "jpeg")]
+pub mod jpeg;
+#[cfg(feature = "http-stream")]
+pub mod mjpeg_server;
+#[cfg(feature = "mock")]
+pub mod mock;
 pub mod threaded_host_node;
-#[cfg(feature = "rerun")]
+#[cfg(feature = "rerun-native")]
 pub mod rerun_host_node;
+pub mod message_pool;
+pub mod model_zoo;
+pub mod neural_depth;
+pub mod nn_archive;
+pub mod nn_data;
+pub mod nodes;
 pub mod output;
 pub mod pipeline;
 pub mod pointcloud;
+pub mod pointcloud_filter;
+pub mod presets;
+pub mod prelude;
 pub mod queue;
+pub mod queue_sync;
+pub mod queue_tuner;
+pub mod raw;
+pub mod recorder;
 pub mod rgbd;
+pub mod ring_buffer;
+pub mod runtime;
+pub mod rtp;
+#[cfg(feature = "ros2")]
+pub mod ros2;
+pub mod sink;
+#[cfg(feature = "serde")]
+pub mod snapshot;
+pub mod spatial_location;
+pub mod spi;
 pub mod stereo_depth;
+pub mod uvc;
+pub mod version;
 pub mod video_encoder;
 
-pub use error::{DepthaiError, Result};
-pub use pipeline::{CreateInPipeline, CreateInPipelineWith, DeviceNode, DeviceNodeWithParams};
+pub use depth_colorizer::{ColorizerBackend, DepthColorizer, DepthColorizerConfig, DepthColorizerInput};
+pub use cancel::CancellationToken;
+pub use error::{DepthaiError, ErrorContext, ErrorKind, Result};
+pub use illumination::{Illumination, IlluminationConfig, IlluminationMode, IlluminationPhase};
+pub use frame_pool::{FramePool, FramePoolConfig};
+pub use frame_stats::{
+    create_frame_stats_host_node, create_frame_stats_host_node_with_callback, ChannelStats, FrameStats,
+    FrameStatsConfig, FrameStatsHostNode,
+};
+pub use pipeline::{
+    CreateInPipeline, CreateInPipelineWith, DeviceNode, DeviceNodeWithParams, EventSubscription, NodeError,
+    NodeErrorPolicy, NodeFailure, NodePhase, PipelineEvent,
+};
 
 pub use device::Device;
 pub use device::DevicePlatform;
+pub use device_session::DeviceSession;
+pub use device::{
+    ChipTemperature, CloseSubscription, DeviceBuilder, DeviceInfo, FirmwareLogMessage, LogLevel, UsbSpeed,
+};
 pub use pipeline::Pipeline;
 
-pub use output::{Output, Input};
+pub use model_zoo::{get_model_from_zoo, ModelDescription, ZooOptions};
+pub use neural_depth::{NeuralDepthConfig, NeuralDepthModel, NeuralDepthNode};
+pub use nn_archive::{DetectionNetworkNode, NNArchive, NeuralNetworkNode};
+pub use nn_data::{NNData, Tensor, TensorDataType, TensorInfo};
+pub use output::{
+    DatatypeHierarchy, LinkInfo, LinkOptions, LinkTarget, Message, Output, Input, TypedInput, TypedOutput,
+    DEFAULT_QUEUE_SIZE,
+};
 pub use pointcloud::{Point3fRGBA, PointCloudData};
-pub use queue::{Datatype, DatatypeEnum, InputQueue, MessageQueue, QueueCallbackHandle};
+pub use queue::{
+    Datatype, DatatypeEnum, FromDatatype, InputQueue, MessageQueue, MismatchPolicy, QueueCallbackHandle, QueueStats,
+};
+pub use queue_sync::{QueueSynchronizer, SyncStats, TimestampMatcher};
+pub use queue_tuner::{QueueAutoTuner, QueueAutoTunerConfig, QueueGoal, QueueRecommendation};
 pub use image_manip::{
     Backend as ImageManipBackend,
     Colormap,
@@ -533,12 +766,47 @@ pub use image_manip::{
     ImageManipResizeMode,
     PerformanceMode as ImageManipPerformanceMode,
 };
-pub use image_align::ImageAlignNode;
+pub use camera_control::{AutoFocusMode, CameraControl, CameraController, FrameSyncMode};
+pub use cast::CastNode;
+pub use image_align::{ImageAlignConfig, ImageAlignNode};
+pub use image_filters::{ImageFiltersConfig, ImageFiltersNode};
 pub use encoded_frame::{EncodedFrame, EncodedFrameProfile, EncodedFrameQueue, EncodedFrameType};
-pub use rgbd::{DepthUnit, RgbdData, RgbdNode};
-pub use stereo_depth::{PresetMode as StereoPresetMode, StereoDepthNode};
+pub use recorder::{ColorImageFormat, DatasetRecorder, DatasetRecorderConfig, RecorderStats};
+pub use ring_buffer::{EncodedFrameRing, RingBufferConfig, RingBufferHostNode, RingDump};
+pub use rgbd::{DepthUnit, RgbdAutocreate, RgbdData, RgbdDepthInfo, RgbdNode};
+pub use imu::{ImuData, ImuNode, ImuPacket, ImuSensor};
+pub use imu_alignment::{FramePose, ImuFrameAligner};
+pub use calibration::{CalibrationHandler, CameraIntrinsics};
+pub use camera_features::{CameraFeatures, CameraSensorConfig, StereoPair};
+pub use depth::{ConfidenceMap, DepthFrame, DepthFrameView, DepthStats};
+pub use detection_parser::{create_custom_detection_parser, CustomDetectionParser, DetectionParserNode};
+pub use detections::{ImgDetection, ImgDetections};
+pub use spatial_location::{
+    SpatialLocation,
+    SpatialLocationCalculatorConfig,
+    SpatialLocationCalculatorData,
+    SpatialLocationCalculatorNode,
+    SpatialLocationRoi,
+};
+pub use sink::{create_sink_host_node, FrameSink, SinkContext, SinkHostNode, SinkInput, SinkInputKind, SinkStats};
+pub use spi::{SpiInNode, SpiOutNode};
+pub use stereo_depth::{DepthAlign, PresetMode as StereoPresetMode, StereoDepthNode};
+pub use uvc::UvcNode;
 pub use video_encoder::{VideoEncoderNode, VideoEncoderProfile, VideoEncoderRateControlMode};
 pub use host_node::{HostNode, HostNodeImpl, MessageGroup, Buffer};
+pub use host_executor::{ExecutionPolicy, HostExecutor};
 pub use threaded_host_node::{ThreadedHostNode, ThreadedHostNodeImpl, ThreadedHostNodeContext};
+#[cfg(feature = "rerun-native")]
+pub use rerun_host_node::{
+    create_rerun_host_node,
+    RerunHostNode,
+    RerunHostNodeConfig,
+    RerunInput,
+    RerunInputKind,
+    RerunSink,
+    RerunViewer,
+};
 #[cfg(feature = "rerun")]
-pub use rerun_host_node::{RerunHostNode, RerunHostNodeConfig, RerunViewer, RerunWebConfig, create_rerun_host_node};
+pub use rerun_host_node::RerunWebConfig;
+#[cfg(feature = "http-stream")]
+pub use mjpeg_server::{create_mjpeg_server_host_node, MjpegServerConfig, MjpegServerHostNode};
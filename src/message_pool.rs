@@ -0,0 +1,197 @@
+//! Reusing host-created messages on high-rate send paths.
+//!
+//! Every [`ImageFrame::new`] or [`Buffer::new`] allocates a C++ message and its payload. A host
+//! node feeding a device input at 30+ FPS (e.g. replaying video into a camera's `mockIsp`) does
+//! that for every frame. [`ImageFramePool`] and [`BufferPool`] keep a few messages around and
+//! hand out one that nothing queued for sending still refers to, overwriting its payload in
+//! place:
+//!
+//! ```no_run
+//! # use depthai::camera::ImageFrameType;
+//! # use depthai::message_pool::ImageFramePool;
+//! # use depthai::output::Output;
+//! # use depthai::Result;
+//! # fn replay(output: &Output, frames: &[Vec<u8>]) -> Result<()> {
+//! let mut pool = ImageFramePool::new(1280, 720, ImageFrameType::NV12, 4)?;
+//! for (seq, data) in frames.iter().enumerate() {
+//!     let frame = pool.fill(data)?;
+//!     frame.set_sequence_num(seq as i64)?;
+//!     output.send_frame(frame)?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! A message counts as free once DepthAI dropped every reference it took when sending; when
+//! none is, the pool grows by one and [`MessagePoolStats::allocated`] counts it.
+//!
+//! Like the messages they hold, pools are not `Send`: create one on the thread that sends, e.g.
+//! at the top of a threaded host node's `run`.
+
+use crate::camera::{packed_frame_size, ImageFrame, ImageFrameType};
+use crate::error::Result;
+use crate::host_node::Buffer;
+
+/// Reuse counters of a pool.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MessagePoolStats {
+    /// Messages the pool holds.
+    pub size: usize,
+    /// Acquisitions served by an existing message.
+    pub reused: u64,
+    /// Messages allocated, including the initial ones.
+    pub allocated: u64,
+}
+
+trait Reusable {
+    fn use_count(&self) -> usize;
+}
+
+impl Reusable for ImageFrame {
+    fn use_count(&self) -> usize {
+        ImageFrame::use_count(self)
+    }
+}
+
+impl Reusable for Buffer {
+    fn use_count(&self) -> usize {
+        Buffer::use_count(self)
+    }
+}
+
+/// Messages of one kind, handed out round-robin.
+struct Slots<T> {
+    items: Vec<T>,
+    next: usize,
+    stats: MessagePoolStats,
+}
+
+impl<T: Reusable> Slots<T> {
+    fn new(size: usize, mut alloc: impl FnMut() -> Result<T>) -> Result<Self> {
+        let items = (0..size.max(1)).map(|_| alloc()).collect::<Result<Vec<_>>>()?;
+        let stats = MessagePoolStats {
+            size: items.len(),
+            reused: 0,
+            allocated: items.len() as u64,
+        };
+        Ok(Self { items, next: 0, stats })
+    }
+
+    /// Index of a free message, allocating one when all are still referenced.
+    fn acquire(&mut self, alloc: impl FnOnce() -> Result<T>) -> Result<usize> {
+        let n = self.items.len();
+        // Oldest first: the message sent longest ago is the likeliest to be released.
+        let free = (0..n).map(|i| (self.next + i) % n).find(|&i| self.items[i].use_count() <= 1);
+        let index = match free {
+            Some(index) => {
+                self.stats.reused += 1;
+                index
+            }
+            None => {
+                self.items.push(alloc()?);
+                self.stats.allocated += 1;
+                self.stats.size = self.items.len();
+                n
+            }
+        };
+        self.next = (index + 1) % self.items.len();
+        Ok(index)
+    }
+}
+
+/// Pool of host-created [`ImageFrame`]s of one size and type, see the [module docs](self).
+pub struct ImageFramePool {
+    width: u32,
+    height: u32,
+    frame_type: ImageFrameType,
+    slots: Slots<ImageFrame>,
+}
+
+impl ImageFramePool {
+    /// Pre-allocate `size` (at least 1) frames of `width` x `height` `frame_type`.
+    pub fn new(width: u32, height: u32, frame_type: ImageFrameType, size: usize) -> Result<Self> {
+        let slots = Slots::new(size, || Self::alloc(width, height, frame_type))?;
+        Ok(Self {
+            width,
+            height,
+            frame_type,
+            slots,
+        })
+    }
+
+    fn alloc(width: u32, height: u32, frame_type: ImageFrameType) -> Result<ImageFrame> {
+        let len = packed_frame_size(width, height, frame_type).unwrap_or(0);
+        ImageFrame::new(width, height, frame_type, &vec![0; len])
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn frame_type(&self) -> ImageFrameType {
+        self.frame_type
+    }
+
+    /// A frame nothing else refers to, with the payload, timestamp and sequence number it was
+    /// last sent with.
+    pub fn acquire(&mut self) -> Result<&ImageFrame> {
+        let (w, h, t) = (self.width, self.height, self.frame_type);
+        let index = self.slots.acquire(|| Self::alloc(w, h, t))?;
+        Ok(&self.slots.items[index])
+    }
+
+    /// [`acquire`](Self::acquire) a frame, copy `data` into it and timestamp it now.
+    pub fn fill(&mut self, data: &[u8]) -> Result<&ImageFrame> {
+        let (w, h, t) = (self.width, self.height, self.frame_type);
+        let index = self.slots.acquire(|| Self::alloc(w, h, t))?;
+        let frame = &mut self.slots.items[index];
+        frame.write_data(data)?;
+        frame.set_timestamp_now()?;
+        Ok(frame)
+    }
+
+    pub fn stats(&self) -> MessagePoolStats {
+        self.slots.stats
+    }
+}
+
+/// Pool of host-created [`Buffer`]s, see the [module docs](self).
+pub struct BufferPool {
+    len: usize,
+    slots: Slots<Buffer>,
+}
+
+impl BufferPool {
+    /// Pre-allocate `size` (at least 1) buffers of `len` bytes.
+    pub fn new(len: usize, size: usize) -> Result<Self> {
+        Ok(Self {
+            len,
+            slots: Slots::new(size, || Buffer::new(len))?,
+        })
+    }
+
+    /// A buffer nothing else refers to, with the payload it was last sent with.
+    pub fn acquire(&mut self) -> Result<&Buffer> {
+        let len = self.len;
+        let index = self.slots.acquire(|| Buffer::new(len))?;
+        Ok(&self.slots.items[index])
+    }
+
+    /// [`acquire`](Self::acquire) a buffer, copy `data` into it and timestamp it now.
+    pub fn fill(&mut self, data: &[u8]) -> Result<&Buffer> {
+        let len = self.len;
+        let index = self.slots.acquire(|| Buffer::new(len))?;
+        let buffer = &mut self.slots.items[index];
+        buffer.write_data(data)?;
+        buffer.set_timestamp_now()?;
+        Ok(buffer)
+    }
+
+    pub fn stats(&self) -> MessagePoolStats {
+        self.slots.stats
+    }
+}
@@ -0,0 +1,329 @@
+//! MJPEG-over-HTTP preview server (requires the `http-stream` feature).
+//!
+//! [`MjpegServerHostNode`] takes the MJPEG [`EncodedFrame`]s of a
+//! [`VideoEncoderNode`](crate::video_encoder::VideoEncoderNode) and serves them as a
+//! `multipart/x-mixed-replace` stream at `http://<bind_ip>:<port>/stream.mjpg`, which any browser
+//! (or `ffplay`, VLC, ...) can open. It's meant for a quick look at camera output on a headless box
+//! without Rerun or OpenCV windows: there is no TLS or authentication, so keep it on trusted
+//! networks.
+//!
+//! Every client gets the newest frame when it is ready for one; a slow client skips frames
+//! instead of holding back the pipeline or the other clients.
+//!
+//! ```no_run
+//! # use depthai::{Pipeline, Result, VideoEncoderNode, VideoEncoderProfile};
+//! # use depthai::mjpeg_server::{MjpegServerConfig, MjpegServerHostNode};
+//! # fn main() -> Result<()> {
+//! # let pipeline = Pipeline::new().build()?;
+//! let encoder = pipeline.create::<VideoEncoderNode>()?;
+//! encoder.set_default_profile_preset(30.0, VideoEncoderProfile::Mjpeg);
+//! let server = pipeline.create_with::<MjpegServerHostNode, _>(MjpegServerConfig::default())?;
+//! encoder.out()?.link(&server.input()?)?;
+//! println!("open {}", server.url());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex, Weak};
+use std::thread;
+use std::time::Duration;
+
+use crate::encoded_frame::{EncodedFrame, EncodedFrameProfile};
+use crate::error::{DepthaiError, Result};
+use crate::output::TypedInput;
+use crate::pipeline::{Node, Pipeline};
+use crate::threaded_host_node::{ThreadedHostNode, ThreadedHostNodeContext, ThreadedHostNodeImpl};
+use crate::CreateInPipelineWith;
+
+const STREAM_PATH: &str = "/stream.mjpg";
+const BOUNDARY: &str = "depthai-frame";
+/// How often idle server threads check whether the node is gone.
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+pub struct MjpegServerConfig {
+    pub bind_ip: String,
+    /// `0` picks a free port, see [`MjpegServerHostNode::local_addr`].
+    pub port: u16,
+    /// Connections beyond this are answered with `503 Service Unavailable`.
+    pub max_clients: usize,
+}
+
+impl Default for MjpegServerConfig {
+    fn default() -> Self {
+        Self {
+            bind_ip: "0.0.0.0".to_string(),
+            port: 8080,
+            max_clients: 8,
+        }
+    }
+}
+
+/// Newest frame, shared between the node thread and the client threads.
+struct Latest {
+    sequence: u64,
+    jpeg: Arc<Vec<u8>>,
+}
+
+struct Shared {
+    latest: Mutex<Latest>,
+    updated: Condvar,
+    stopped: AtomicBool,
+    clients: AtomicUsize,
+    max_clients: usize,
+}
+
+impl Shared {
+    fn publish(&self, jpeg: Vec<u8>) {
+        let mut latest = self.latest.lock().unwrap_or_else(|e| e.into_inner());
+        latest.sequence += 1;
+        latest.jpeg = Arc::new(jpeg);
+        self.updated.notify_all();
+    }
+
+    /// Wait up to [`POLL_INTERVAL`] for a frame newer than `seen`.
+    fn next_after(&self, seen: u64) -> Option<(u64, Arc<Vec<u8>>)> {
+        let latest = self.latest.lock().unwrap_or_else(|e| e.into_inner());
+        let (latest, _) = self
+            .updated
+            .wait_timeout_while(latest, POLL_INTERVAL, |l| {
+                l.sequence == seen && !self.stopped.load(Ordering::Relaxed)
+            })
+            .unwrap_or_else(|e| e.into_inner());
+        (latest.sequence != seen).then(|| (latest.sequence, Arc::clone(&latest.jpeg)))
+    }
+
+    fn stop(&self) {
+        self.stopped.store(true, Ordering::Relaxed);
+        self.updated.notify_all();
+    }
+}
+
+/// Server threads only hold a [`Weak`] reference, so they wind down once the node is dropped
+/// even if the pipeline never ran.
+fn upgrade(shared: &Weak<Shared>) -> Option<Arc<Shared>> {
+    shared.upgrade().filter(|s| !s.stopped.load(Ordering::Relaxed))
+}
+
+fn accept_loop(listener: TcpListener, shared: Weak<Shared>) {
+    while let Some(state) = upgrade(&shared) {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                // Accepted sockets inherit non-blocking mode from the listener on some platforms.
+                let _ = stream.set_nonblocking(false);
+                if state.clients.load(Ordering::Relaxed) >= state.max_clients {
+                    let _ = respond(stream, "503 Service Unavailable", "text/plain", b"too many clients\n");
+                    continue;
+                }
+                state.clients.fetch_add(1, Ordering::Relaxed);
+                let shared = shared.clone();
+                thread::spawn(move || {
+                    let _ = serve_client(stream, &shared);
+                    if let Some(state) = shared.upgrade() {
+                        state.clients.fetch_sub(1, Ordering::Relaxed);
+                    }
+                });
+            }
+            // `WouldBlock` when nobody is connecting; other accept errors are transient too.
+            Err(_) => {
+                drop(state);
+                thread::sleep(POLL_INTERVAL);
+            }
+        }
+    }
+}
+
+fn respond(mut stream: TcpStream, status: &str, content_type: &str, body: &[u8]) -> std::io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        body.len()
+    )?;
+    stream.write_all(body)
+}
+
+fn serve_client(stream: TcpStream, shared: &Weak<Shared>) -> std::io::Result<()> {
+    stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+    stream.set_nodelay(true)?;
+
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    // Skip the headers; nothing in them changes the response.
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 2 {
+        header.clear();
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let (method, path) = (parts.next().unwrap_or(""), parts.next().unwrap_or(""));
+    if method != "GET" {
+        return respond(stream, "405 Method Not Allowed", "text/plain", b"only GET is supported\n");
+    }
+    match path.split('?').next().unwrap_or("") {
+        STREAM_PATH => stream_frames(stream, shared),
+        "/" => {
+            let page = format!("<!doctype html><title>depthai</title><img src=\"{STREAM_PATH}\">\n");
+            respond(stream, "200 OK", "text/html", page.as_bytes())
+        }
+        _ => respond(stream, "404 Not Found", "text/plain", b"not found\n"),
+    }
+}
+
+fn stream_frames(mut stream: TcpStream, shared: &Weak<Shared>) -> std::io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 200 OK\r\nContent-Type: multipart/x-mixed-replace; boundary={BOUNDARY}\r\n\
+         Cache-Control: no-cache\r\nConnection: close\r\n\r\n"
+    )?;
+    let mut seen = 0;
+    while let Some(state) = upgrade(shared) {
+        let Some((sequence, jpeg)) = state.next_after(seen) else {
+            continue;
+        };
+        drop(state);
+        seen = sequence;
+        write!(
+            stream,
+            "--{BOUNDARY}\r\nContent-Type: image/jpeg\r\nContent-Length: {}\r\n\r\n",
+            jpeg.len()
+        )?;
+        stream.write_all(&jpeg)?;
+        stream.write_all(b"\r\n")?;
+    }
+    Ok(())
+}
+
+struct MjpegServerImpl {
+    input: TypedInput<EncodedFrame>,
+    shared: Arc<Shared>,
+    warned_format: bool,
+}
+
+impl MjpegServerImpl {
+    fn handle(&mut self, frame: &EncodedFrame) -> Result<()> {
+        if frame.profile() != Some(EncodedFrameProfile::Jpeg) {
+            if self.warned_format {
+                return Ok(());
+            }
+            self.warned_format = true;
+            return Err(DepthaiError::new(format!(
+                "MjpegServerHostNode expects MJPEG frames, got {}; dropping non-MJPEG frames",
+                frame.describe()
+            )));
+        }
+        self.shared.publish(frame.bytes());
+        Ok(())
+    }
+}
+
+impl ThreadedHostNodeImpl for MjpegServerImpl {
+    fn run(&mut self, ctx: &ThreadedHostNodeContext) {
+        loop {
+            match ctx.next::<EncodedFrame, _>(&self.input) {
+                Ok(Some(frame)) => {
+                    if let Err(e) = self.handle(&frame) {
+                        ctx.report_error(&e);
+                    }
+                }
+                Ok(None) => break,
+                Err(e) => {
+                    ctx.report_error(&e);
+                    break;
+                }
+            }
+        }
+    }
+
+    fn on_stop(&mut self) {
+        self.shared.stop();
+    }
+}
+
+/// Host node serving MJPEG frames over HTTP, see the [module docs](self).
+///
+/// The port is bound when the node is created, so a port in use is reported right away. The
+/// server stops with the pipeline.
+#[derive(Clone)]
+pub struct MjpegServerHostNode {
+    node: ThreadedHostNode,
+    shared: Arc<Shared>,
+    local_addr: SocketAddr,
+}
+
+impl MjpegServerHostNode {
+    pub fn as_node(&self) -> &Node {
+        self.node.as_node()
+    }
+
+    /// MJPEG encoded frames; link a `VideoEncoderNode` with the `Mjpeg` profile here.
+    pub fn input(&self) -> Result<TypedInput<EncodedFrame>> {
+        self.as_node().input("in").map(TypedInput::from_untyped)
+    }
+
+    /// Address the server listens on.
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// URL of the stream; an unspecified bind address is shown as `localhost`.
+    pub fn url(&self) -> String {
+        let host = if self.local_addr.ip().is_unspecified() {
+            "localhost".to_string()
+        } else {
+            self.local_addr.ip().to_string()
+        };
+        format!("http://{host}:{}{STREAM_PATH}", self.local_addr.port())
+    }
+
+    /// Clients currently connected.
+    pub fn client_count(&self) -> usize {
+        self.shared.clients.load(Ordering::Relaxed)
+    }
+}
+
+impl CreateInPipelineWith<MjpegServerConfig> for MjpegServerHostNode {
+    fn create_with(pipeline: &Pipeline, config: MjpegServerConfig) -> Result<Self> {
+        let listener = TcpListener::bind((config.bind_ip.as_str(), config.port)).map_err(|e| {
+            DepthaiError::new(format!("failed to bind MJPEG server to {}:{}: {e}", config.bind_ip, config.port))
+        })?;
+        let local_addr = listener.local_addr().map_err(|e| DepthaiError::new(e.to_string()))?;
+        listener
+            .set_nonblocking(true)
+            .map_err(|e| DepthaiError::new(e.to_string()))?;
+
+        let shared = Arc::new(Shared {
+            latest: Mutex::new(Latest {
+                sequence: 0,
+                jpeg: Arc::new(Vec::new()),
+            }),
+            updated: Condvar::new(),
+            stopped: AtomicBool::new(false),
+            clients: AtomicUsize::new(0),
+            max_clients: config.max_clients,
+        });
+        let node_shared = Arc::clone(&shared);
+        let node = pipeline.create_threaded_host_node(|node| {
+            Ok(MjpegServerImpl {
+                input: TypedInput::from_untyped(node.create_input(Some("in"))?),
+                shared: node_shared,
+                warned_format: false,
+            })
+        })?;
+
+        let weak = Arc::downgrade(&shared);
+        thread::Builder::new()
+            .name("mjpeg-server".to_string())
+            .spawn(move || accept_loop(listener, weak))
+            .map_err(|e| DepthaiError::new(format!("failed to start MJPEG server thread: {e}")))?;
+
+        Ok(Self { node, shared, local_addr })
+    }
+}
+
+/// Create an [`MjpegServerHostNode`] listening on `port` of all interfaces.
+pub fn create_mjpeg_server_host_node(pipeline: &Pipeline, port: u16) -> Result<MjpegServerHostNode> {
+    MjpegServerHostNode::create_with(pipeline, MjpegServerConfig { port, ..Default::default() })
+}
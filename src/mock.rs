@@ -0,0 +1,320 @@
+//! Hardware-free devices and cameras for tests (requires the `mock` feature).
+//!
+//! [`Device::mock`] stands in for an OAK: it reports a configurable platform and camera set,
+//! and a pipeline built for it runs host-only. [`MockCameraNode`] takes the place of
+//! [`CameraNode`](crate::camera::CameraNode) there, emitting test patterns, frames read from a
+//! raw video file, or frames from a closure, at the requested size, type and rate. Native device
+//! nodes can still be created but fail when a mock pipeline starts.
+//!
+//! ```no_run
+//! # use std::time::Duration;
+//! # use depthai::prelude::*;
+//! # use depthai::mock::MockCameraNode;
+//! # fn main() -> Result<()> {
+//! let device = Device::mock();
+//! let pipeline = Pipeline::new().with_device(&device).build()?;
+//! let camera = pipeline.create_with::<MockCameraNode, _>(CameraBoardSocket::CamA)?;
+//! let queue = camera.request_output(CameraOutputConfig::new((320, 240)))?.create_queue(4, false)?;
+//! pipeline.start()?;
+//! let frame = queue.blocking_next(Some(Duration::from_secs(1)))?.expect("mock frame");
+//! assert_eq!((frame.width(), frame.height()), (320, 240));
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fs::File;
+use std::io::{ErrorKind, Read, Seek, SeekFrom};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::camera::{packed_frame_size, CameraOutputConfig, ImageFrame};
+use crate::common::{CameraBoardSocket, ImageFrameType};
+use crate::convert;
+use crate::device::{Device, DevicePlatform, UsbSpeed};
+use crate::error::{DepthaiError, Result};
+use crate::output::TypedOutput;
+use crate::pipeline::device_node::CreateInPipelineWith;
+use crate::pipeline::{Node, Pipeline};
+use crate::threaded_host_node::{ThreadedHostNode, ThreadedHostNodeContext, ThreadedHostNodeImpl};
+
+/// Frame rate used when [`CameraOutputConfig::fps`] is unset.
+const DEFAULT_FPS: f32 = 30.0;
+
+/// What a [`Device::mock`] reports about itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockDeviceConfig {
+    pub platform: DevicePlatform,
+    pub cameras: Vec<CameraBoardSocket>,
+    pub usb_speed: UsbSpeed,
+}
+
+impl Default for MockDeviceConfig {
+    /// An RVC2 device with a color and a stereo pair, like an OAK-D.
+    fn default() -> Self {
+        Self {
+            platform: DevicePlatform::Rvc2,
+            cameras: vec![CameraBoardSocket::CamA, CameraBoardSocket::CamB, CameraBoardSocket::CamC],
+            usb_speed: UsbSpeed::Super,
+        }
+    }
+}
+
+/// State shared by the clones of one mock [`Device`].
+pub(crate) struct MockDevice {
+    config: MockDeviceConfig,
+    closed: AtomicBool,
+}
+
+impl MockDevice {
+    pub(crate) fn config(&self) -> &MockDeviceConfig {
+        &self.config
+    }
+
+    pub(crate) fn is_connected(&self) -> bool {
+        !self.closed.load(Ordering::SeqCst)
+    }
+
+    pub(crate) fn close(&self) {
+        self.closed.store(true, Ordering::SeqCst);
+    }
+}
+
+impl Device {
+    /// A simulated device with [`MockDeviceConfig::default`].
+    pub fn mock() -> Self {
+        Self::mock_with(MockDeviceConfig::default())
+    }
+
+    pub fn mock_with(config: MockDeviceConfig) -> Self {
+        Self::from_mock(Arc::new(MockDevice {
+            config,
+            closed: AtomicBool::new(false),
+        }))
+    }
+
+    pub(crate) fn from_mock(mock: Arc<MockDevice>) -> Self {
+        let mut device = Self::from_handle(std::ptr::null_mut());
+        device.mock = Some(mock);
+        device
+    }
+
+    pub fn is_mock(&self) -> bool {
+        self.mock.is_some()
+    }
+}
+
+/// A generated test image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestPattern {
+    /// Red grows left to right, green top to bottom.
+    Gradient,
+    /// Black and white squares of `cell` pixels.
+    Checkerboard { cell: u32 },
+    Solid { r: u8, g: u8, b: u8 },
+    /// A gray background with a white vertical bar advancing a few pixels per frame, so
+    /// consecutive frames differ.
+    MovingBar,
+}
+
+/// Produces the payload of frame `index` for the given size and type.
+pub type FrameGenerator = Box<dyn FnMut(u64, (u32, u32), ImageFrameType) -> Result<Vec<u8>> + Send>;
+
+/// Where a [`MockCameraNode`] output gets its frames.
+pub enum FrameSource {
+    Pattern(TestPattern),
+    /// Packed frames of the requested size and type stored back to back, e.g. the output of
+    /// `ffmpeg -f rawvideo`. Loops at the end of the file.
+    RawFile(PathBuf),
+    Generator(FrameGenerator),
+}
+
+impl Default for FrameSource {
+    fn default() -> Self {
+        Self::Pattern(TestPattern::MovingBar)
+    }
+}
+
+struct MockOutput {
+    output: TypedOutput<ImageFrame>,
+    size: (u32, u32),
+    frame_type: ImageFrameType,
+    interval: Duration,
+    source: FrameSource,
+    file: Option<File>,
+    sequence: u64,
+    next_due: Option<Instant>,
+    failed: bool,
+}
+
+impl MockOutput {
+    fn next_frame(&mut self) -> Result<ImageFrame> {
+        let (width, height) = self.size;
+        let data = match &mut self.source {
+            FrameSource::Pattern(pattern) => pattern_frame(*pattern, self.sequence, width, height, self.frame_type)?,
+            FrameSource::Generator(generate) => generate(self.sequence, self.size, self.frame_type)?,
+            FrameSource::RawFile(path) => {
+                let len = frame_len(self.size, self.frame_type)?;
+                if self.file.is_none() {
+                    let file = File::open(&*path)
+                        .map_err(|e| DepthaiError::new(format!("failed to open {}: {e}", path.display())))?;
+                    self.file = Some(file);
+                }
+                let file = self.file.as_mut().expect("file was just opened");
+                read_looping(file, len).map_err(|e| DepthaiError::new(format!("failed to read {}: {e}", path.display())))?
+            }
+        };
+        let frame = ImageFrame::new(width, height, self.frame_type, &data)?;
+        frame.set_sequence_num(self.sequence as i64)?;
+        self.sequence += 1;
+        Ok(frame)
+    }
+}
+
+fn frame_len(size: (u32, u32), frame_type: ImageFrameType) -> Result<usize> {
+    packed_frame_size(size.0, size.1, frame_type)
+        .ok_or_else(|| DepthaiError::new(format!("mock cameras don't support {frame_type:?} frames")))
+}
+
+/// Read the next `len` bytes, starting over at the end of the file.
+fn read_looping(file: &mut File, len: usize) -> std::io::Result<Vec<u8>> {
+    let mut data = vec![0u8; len];
+    match file.read_exact(&mut data) {
+        Err(e) if e.kind() == ErrorKind::UnexpectedEof => {
+            file.seek(SeekFrom::Start(0))?;
+            file.read_exact(&mut data)?;
+        }
+        other => other?,
+    }
+    Ok(data)
+}
+
+/// Render `pattern` for frame `index` in `frame_type`.
+pub fn pattern_frame(pattern: TestPattern, index: u64, width: u32, height: u32, frame_type: ImageFrameType) -> Result<Vec<u8>> {
+    let (w, h) = (width as usize, height as usize);
+    let mut rgb = Vec::with_capacity(w * h * 3);
+    let bar_x = (index as usize * 4) % w.max(1);
+    for y in 0..h {
+        for x in 0..w {
+            let pixel = match pattern {
+                TestPattern::Gradient => [(x * 255 / w.max(2).saturating_sub(1)) as u8, (y * 255 / h.max(2).saturating_sub(1)) as u8, 128],
+                TestPattern::Checkerboard { cell } => {
+                    let cell = cell.max(1) as usize;
+                    if (x / cell + y / cell) & 1 == 0 { [255; 3] } else { [0; 3] }
+                }
+                TestPattern::Solid { r, g, b } => [r, g, b],
+                TestPattern::MovingBar => {
+                    if x.abs_diff(bar_x) < 4 { [255; 3] } else { [64; 3] }
+                }
+            };
+            rgb.extend_from_slice(&pixel);
+        }
+    }
+    rgb_to(frame_type, w, h, rgb)
+}
+
+fn rgb_to(frame_type: ImageFrameType, w: usize, h: usize, rgb: Vec<u8>) -> Result<Vec<u8>> {
+    let luma = |p: &[u8]| ((77 * p[0] as u32 + 150 * p[1] as u32 + 29 * p[2] as u32) >> 8) as u8;
+    match frame_type {
+        ImageFrameType::RGB888i => Ok(rgb),
+        ImageFrameType::BGR888i => Ok(rgb.chunks_exact(3).flat_map(|p| [p[2], p[1], p[0]]).collect()),
+        ImageFrameType::RGB888p => Ok((0..3).flat_map(|c| rgb.chunks_exact(3).map(move |p| p[c])).collect()),
+        ImageFrameType::GRAY8 | ImageFrameType::RAW8 => Ok(rgb.chunks_exact(3).map(luma).collect()),
+        ImageFrameType::NV12 => convert::rgb888i_to_nv12(w as u32, h as u32, &rgb),
+        other => Err(DepthaiError::new(format!("mock cameras can't render test patterns as {other:?}"))),
+    }
+}
+
+struct MockCameraImpl {
+    outputs: Arc<Mutex<Vec<MockOutput>>>,
+}
+
+impl ThreadedHostNodeImpl for MockCameraImpl {
+    fn run(&mut self, ctx: &ThreadedHostNodeContext) {
+        while ctx.is_running() {
+            let now = Instant::now();
+            let mut wake = now + Duration::from_millis(50);
+            let mut outputs = self.outputs.lock().unwrap_or_else(|e| e.into_inner());
+            for output in outputs.iter_mut().filter(|o| !o.failed) {
+                let due = *output.next_due.get_or_insert(now);
+                if due <= now {
+                    match output.next_frame().and_then(|frame| output.output.send(&frame)) {
+                        Ok(()) => output.next_due = Some(due + output.interval),
+                        Err(e) => {
+                            // A broken source stays broken (missing file, unsupported type).
+                            output.failed = true;
+                            ctx.report_error(&e);
+                            continue;
+                        }
+                    }
+                }
+                wake = wake.min(output.next_due.unwrap_or(now));
+            }
+            drop(outputs);
+            std::thread::sleep(wake.saturating_duration_since(Instant::now()).max(Duration::from_millis(1)));
+        }
+    }
+}
+
+/// A host node emitting simulated camera frames, see the [module docs](self).
+#[derive(Clone)]
+pub struct MockCameraNode {
+    node: ThreadedHostNode,
+    socket: CameraBoardSocket,
+    outputs: Arc<Mutex<Vec<MockOutput>>>,
+}
+
+impl MockCameraNode {
+    pub fn as_node(&self) -> &Node {
+        self.node.as_node()
+    }
+
+    pub fn board_socket(&self) -> CameraBoardSocket {
+        self.socket
+    }
+
+    /// Add an output showing [`TestPattern::MovingBar`].
+    ///
+    /// `frame_type` defaults to `NV12`; `fps` to 30. `resize_mode` and `enable_undistortion`
+    /// are ignored.
+    pub fn request_output(&self, config: CameraOutputConfig) -> Result<TypedOutput<ImageFrame>> {
+        self.request_output_with(config, FrameSource::default())
+    }
+
+    /// Add an output fed by `source`. Outputs must be requested before the pipeline starts.
+    pub fn request_output_with(&self, config: CameraOutputConfig, source: FrameSource) -> Result<TypedOutput<ImageFrame>> {
+        let frame_type = config.frame_type.unwrap_or(ImageFrameType::NV12);
+        frame_len(config.size, frame_type)?;
+        let fps = config.fps.unwrap_or(DEFAULT_FPS);
+        if !(fps > 0.0 && fps.is_finite()) {
+            return Err(DepthaiError::new(format!("invalid mock camera fps {fps}")));
+        }
+        let mut outputs = self.outputs.lock().unwrap_or_else(|e| e.into_inner());
+        let output = self.node.create_typed_output::<ImageFrame>(&format!("out{}", outputs.len()))?;
+        outputs.push(MockOutput {
+            output: output.clone(),
+            size: config.size,
+            frame_type,
+            interval: Duration::from_secs_f32(1.0 / fps),
+            source,
+            file: None,
+            sequence: 0,
+            next_due: None,
+            failed: false,
+        });
+        Ok(output)
+    }
+}
+
+impl CreateInPipelineWith<CameraBoardSocket> for MockCameraNode {
+    fn create_with(pipeline: &Pipeline, socket: CameraBoardSocket) -> Result<Self> {
+        let outputs = Arc::new(Mutex::new(Vec::new()));
+        let node = pipeline.create_threaded_host_node(|_| {
+            Ok(MockCameraImpl {
+                outputs: Arc::clone(&outputs),
+            })
+        })?;
+        Ok(Self { node, socket, outputs })
+    }
+}
@@ -0,0 +1,175 @@
+//! Luxonis model zoo access.
+//!
+//! [`get_model_from_zoo`] resolves a model slug (e.g. `"yolov6-nano"` or
+//! `"luxonis/yolov6-nano:r2-coco-512x288"`) for a device platform, downloads its NNArchive and
+//! returns the cached path, ready for [`NNArchive::open`](crate::nn_archive::NNArchive::open).
+//! Cached archives are reused without network access.
+//!
+//! ```no_run
+//! # use depthai::{Device, Result};
+//! # use depthai::model_zoo::{get_model_from_zoo, ModelDescription, ZooOptions};
+//! # fn main() -> Result<()> {
+//! let device = Device::new()?;
+//! let description = ModelDescription::new("yolov6-nano").with_platform(device.platform()?);
+//! let path = get_model_from_zoo(&description, &ZooOptions::default())?;
+//! println!("model archive: {}", path.display());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::ffi::CString;
+use std::path::{Path, PathBuf};
+
+use depthai_sys::depthai;
+
+use crate::device::DevicePlatform;
+use crate::error::{clear_error_flag, last_error, DepthaiError, Result};
+use crate::pipeline::take_owned_json_string;
+
+/// Which zoo model to fetch.
+///
+/// Mirrors C++: `dai::NNModelDescription`. `None` fields are left for the zoo to pick.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ModelDescription {
+    /// Model slug, optionally with team and variant (`team/model:variant`).
+    pub model: String,
+    /// Target platform; RVC2 resolves to a blob archive, RVC4 to a DLC archive.
+    pub platform: Option<DevicePlatform>,
+    pub optimization_level: Option<String>,
+    pub compression_level: Option<String>,
+    pub snpe_version: Option<String>,
+    /// E.g. `"INT8"` or `"FP16"`.
+    pub precision: Option<String>,
+}
+
+impl ModelDescription {
+    pub fn new(model: &str) -> Self {
+        Self {
+            model: model.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_platform(mut self, platform: DevicePlatform) -> Self {
+        self.platform = Some(platform);
+        self
+    }
+
+    pub fn with_precision(mut self, precision: &str) -> Self {
+        self.precision = Some(precision.to_string());
+        self
+    }
+}
+
+/// Download and cache settings for [`get_model_from_zoo`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZooOptions {
+    /// Reuse a cached archive instead of checking the zoo.
+    pub use_cached: bool,
+    /// Cache directory; defaults to [`default_cache_dir`].
+    pub cache_dir: Option<PathBuf>,
+    /// Hub API key for private models; `DEPTHAI_HUB_API_KEY` is used when unset.
+    pub api_key: Option<String>,
+    /// Print download progress to stdout.
+    pub show_progress: bool,
+}
+
+impl Default for ZooOptions {
+    fn default() -> Self {
+        Self {
+            use_cached: true,
+            cache_dir: None,
+            api_key: None,
+            show_progress: false,
+        }
+    }
+}
+
+/// Platform name as used by the zoo (`"RVC2"`, `"RVC3"`, `"RVC4"`).
+///
+/// Mirrors C++: `dai::platform2string`.
+pub fn platform_name(platform: DevicePlatform) -> &'static str {
+    match platform {
+        DevicePlatform::Rvc2 => "RVC2",
+        DevicePlatform::Rvc3 => "RVC3",
+        DevicePlatform::Rvc4 => "RVC4",
+    }
+}
+
+/// Where models are cached when [`ZooOptions::cache_dir`] is unset.
+///
+/// `DEPTHAI_ZOO_CACHE_PATH` if set, else `$XDG_CACHE_HOME/depthai/models`, else
+/// `~/.cache/depthai/models` (`%LOCALAPPDATA%\depthai\models` on Windows).
+pub fn default_cache_dir() -> PathBuf {
+    let env = |name: &str| std::env::var_os(name).filter(|v| !v.is_empty()).map(PathBuf::from);
+    if let Some(dir) = env("DEPTHAI_ZOO_CACHE_PATH") {
+        return dir;
+    }
+    let base = if cfg!(target_os = "windows") {
+        env("LOCALAPPDATA")
+    } else {
+        env("XDG_CACHE_HOME").or_else(|| env("HOME").map(|home| home.join(".cache")))
+    };
+    base.unwrap_or_else(std::env::temp_dir).join("depthai").join("models")
+}
+
+fn c_string(value: &str, what: &str) -> Result<CString> {
+    CString::new(value).map_err(|_| DepthaiError::new(format!("invalid {what}: contains a NUL byte")))
+}
+
+fn c_path(path: &Path) -> Result<CString> {
+    c_string(&path.to_string_lossy(), "cache directory")
+}
+
+/// Resolve `description` against the zoo, download it if needed and return the archive path.
+///
+/// Mirrors C++: `dai::getModelFromZoo`.
+pub fn get_model_from_zoo(description: &ModelDescription, options: &ZooOptions) -> Result<PathBuf> {
+    let optional = |value: &Option<String>, what: &str| c_string(value.as_deref().unwrap_or(""), what);
+    let model = c_string(&description.model, "model slug")?;
+    let platform = c_string(description.platform.map_or("", platform_name), "platform")?;
+    let optimization_level = optional(&description.optimization_level, "optimization level")?;
+    let compression_level = optional(&description.compression_level, "compression level")?;
+    let snpe_version = optional(&description.snpe_version, "SNPE version")?;
+    let precision = optional(&description.precision, "precision")?;
+    let cache_dir = options.cache_dir.clone().unwrap_or_else(default_cache_dir);
+    let cache_dir_c = c_path(&cache_dir)?;
+    let api_key = optional(&options.api_key, "API key")?;
+
+    clear_error_flag();
+    let ptr = unsafe {
+        depthai::dai_model_zoo_get_model(
+            model.as_ptr(),
+            platform.as_ptr(),
+            optimization_level.as_ptr(),
+            compression_level.as_ptr(),
+            snpe_version.as_ptr(),
+            precision.as_ptr(),
+            options.use_cached,
+            cache_dir_c.as_ptr(),
+            api_key.as_ptr(),
+            options.show_progress,
+        )
+    };
+    let path = take_owned_json_string(ptr, &format!("failed to get model '{}' from zoo", description.model))?;
+    Ok(PathBuf::from(path))
+}
+
+/// Download every model described by the `*.yaml` files in `yaml_dir` into the cache, e.g. to
+/// prepare an offline deployment.
+///
+/// Mirrors C++: `dai::downloadModelsFromZoo`.
+pub fn download_models_from_zoo(yaml_dir: &Path, options: &ZooOptions) -> Result<()> {
+    let yaml_dir = c_string(&yaml_dir.to_string_lossy(), "model directory")?;
+    let cache_dir = c_path(&options.cache_dir.clone().unwrap_or_else(default_cache_dir))?;
+    let api_key = c_string(options.api_key.as_deref().unwrap_or(""), "API key")?;
+    clear_error_flag();
+    let ok = unsafe {
+        depthai::dai_model_zoo_download_models(yaml_dir.as_ptr(), cache_dir.as_ptr(), api_key.as_ptr(), options.show_progress)
+    };
+    if ok {
+        Ok(())
+    } else {
+        Err(last_error("failed to download models from zoo"))
+    }
+}
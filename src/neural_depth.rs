@@ -0,0 +1,206 @@
+//! Neural stereo depth on RVC4 devices.
+//!
+//! [`NeuralDepthNode`] computes disparity and depth from a stereo pair with a neural network
+//! instead of the SGBM engine behind [`StereoDepthNode`](crate::stereo_depth::StereoDepthNode).
+//! The node only exists in DepthAI-Core after v3.1.0, so check for it before choosing it:
+//!
+//! ```no_run
+//! # use depthai::camera::{CameraBoardSocket, CameraNode, CameraOutputConfig};
+//! # use depthai::neural_depth::{NeuralDepthModel, NeuralDepthNode};
+//! # use depthai::version::{has_feature, Feature};
+//! # use depthai::{DepthUnit, Pipeline, Result};
+//! # fn main() -> Result<()> {
+//! let pipeline = Pipeline::new().build()?;
+//! let left = pipeline.create_with::<CameraNode, _>(CameraBoardSocket::CamB)?;
+//! let right = pipeline.create_with::<CameraNode, _>(CameraBoardSocket::CamC)?;
+//! let left = left.request_full_resolution_output()?;
+//! let right = right.request_full_resolution_output()?;
+//! if has_feature(Feature::NeuralDepth) {
+//!     let neural = pipeline.create::<NeuralDepthNode>()?;
+//!     neural.build(&left, &right, NeuralDepthModel::Small)?;
+//!     neural.initial_config()?.set_confidence_threshold(125).set_depth_unit(DepthUnit::Millimeter);
+//!     let depth = neural.depth()?.create_message_queue(4, false)?;
+//!     # let _ = depth;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use autocxx::c_int;
+use depthai_sys::{depthai, DaiBuffer};
+
+use crate::camera::ImageFrame;
+use crate::error::{clear_error_flag, last_error, take_error_if_any, DepthaiError, Result};
+use crate::host_node::Buffer;
+use crate::output::Output;
+use crate::rgbd::DepthUnit;
+
+/// Network run by [`NeuralDepthNode`]; larger models are more accurate and slower.
+///
+/// Mirrors C++: `dai::DeviceModelZoo::NEURAL_DEPTH_*`.
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NeuralDepthModel {
+    Large = 0,
+    Medium = 1,
+    Small = 2,
+    #[default]
+    Nano = 3,
+}
+
+/// Runtime configuration message for `NeuralDepth`.
+///
+/// Mirrors C++: `dai::NeuralDepthConfig`.
+///
+/// Note: this is also a `Buffer` message, so it can be sent to the node's `inputConfig`.
+pub struct NeuralDepthConfig {
+    buffer: Buffer,
+}
+
+impl NeuralDepthConfig {
+    pub fn new() -> Result<Self> {
+        clear_error_flag();
+        let handle = depthai::dai_neural_depth_config_new();
+        if handle.is_null() {
+            Err(last_error("failed to create NeuralDepthConfig"))
+        } else {
+            Ok(Self {
+                buffer: Buffer::from_handle(handle),
+            })
+        }
+    }
+
+    pub fn as_buffer(&self) -> &Buffer {
+        &self.buffer
+    }
+
+    pub fn into_buffer(self) -> Buffer {
+        self.buffer
+    }
+
+    pub(crate) fn from_handle(handle: DaiBuffer) -> Self {
+        Self {
+            buffer: Buffer::from_handle(handle),
+        }
+    }
+
+    pub(crate) fn handle(&self) -> DaiBuffer {
+        self.buffer.handle()
+    }
+
+    /// Invalidate pixels whose confidence is below `threshold` (0..255).
+    ///
+    /// Mirrors C++: `NeuralDepthConfig::setConfidenceThreshold(uint8_t)`.
+    pub fn set_confidence_threshold(&mut self, threshold: u8) -> &mut Self {
+        clear_error_flag();
+        unsafe { depthai::dai_neural_depth_config_set_confidence_threshold(self.handle(), threshold) };
+        self
+    }
+
+    pub fn confidence_threshold(&self) -> Result<u8> {
+        clear_error_flag();
+        let v = unsafe { depthai::dai_neural_depth_config_get_confidence_threshold(self.handle()) };
+        if let Some(err) = take_error_if_any("failed to get NeuralDepthConfig confidence threshold") {
+            Err(err)
+        } else {
+            Ok(v)
+        }
+    }
+
+    /// Invalidate pixels whose edge strength is above `threshold` (0..255).
+    ///
+    /// Mirrors C++: `NeuralDepthConfig::setEdgeThreshold(uint8_t)`.
+    pub fn set_edge_threshold(&mut self, threshold: u8) -> &mut Self {
+        clear_error_flag();
+        unsafe { depthai::dai_neural_depth_config_set_edge_threshold(self.handle(), threshold) };
+        self
+    }
+
+    pub fn edge_threshold(&self) -> Result<u8> {
+        clear_error_flag();
+        let v = unsafe { depthai::dai_neural_depth_config_get_edge_threshold(self.handle()) };
+        if let Some(err) = take_error_if_any("failed to get NeuralDepthConfig edge threshold") {
+            Err(err)
+        } else {
+            Ok(v)
+        }
+    }
+
+    /// Unit of the `depth` output (millimeters by default).
+    ///
+    /// Mirrors C++: `NeuralDepthConfig::setDepthUnit(DepthUnit)`.
+    pub fn set_depth_unit(&mut self, unit: DepthUnit) -> &mut Self {
+        clear_error_flag();
+        unsafe { depthai::dai_neural_depth_config_set_depth_unit(self.handle(), c_int(unit as i32)) };
+        self
+    }
+
+    pub fn depth_unit(&self) -> Result<DepthUnit> {
+        clear_error_flag();
+        let raw: i32 = unsafe { depthai::dai_neural_depth_config_get_depth_unit(self.handle()) }.into();
+        if let Some(err) = take_error_if_any("failed to get NeuralDepthConfig depth unit") {
+            return Err(err);
+        }
+        DepthUnit::from_raw(raw).ok_or_else(|| DepthaiError::new(format!("unknown NeuralDepth depth unit {raw}")))
+    }
+
+    /// Meters per unit of the `depth` output with [`DepthUnit::Custom`].
+    ///
+    /// Mirrors C++: `NeuralDepthConfig::setCustomDepthUnitMultiplier(float)`.
+    pub fn set_custom_depth_unit_multiplier(&mut self, multiplier: f32) -> &mut Self {
+        clear_error_flag();
+        unsafe { depthai::dai_neural_depth_config_set_custom_depth_unit_multiplier(self.handle(), multiplier) };
+        self
+    }
+
+    pub fn custom_depth_unit_multiplier(&self) -> Result<f32> {
+        clear_error_flag();
+        let v = unsafe { depthai::dai_neural_depth_config_get_custom_depth_unit_multiplier(self.handle()) };
+        if let Some(err) = take_error_if_any("failed to get NeuralDepthConfig custom depth unit multiplier") {
+            Err(err)
+        } else {
+            Ok(v)
+        }
+    }
+}
+
+/// Neural-network stereo depth (DepthAI-Core after v3.1.0, see
+/// [`Feature::NeuralDepth`](crate::version::Feature::NeuralDepth)).
+///
+/// Mirrors C++: `dai::node::NeuralDepth`.
+#[crate::native_node_wrapper(
+    native = "dai::node::NeuralDepth",
+    inputs(left: ImageFrame, right: ImageFrame, inputConfig: NeuralDepthConfig),
+    outputs(disparity: ImageFrame, depth: ImageFrame, edge: ImageFrame, confidence: ImageFrame)
+)]
+pub struct NeuralDepthNode {
+    node: crate::pipeline::Node,
+}
+
+impl NeuralDepthNode {
+    /// Link the stereo pair and load `model` onto the device.
+    ///
+    /// Mirrors C++: `NeuralDepth::build(left, right, model)`.
+    pub fn build(&self, left: &Output, right: &Output, model: NeuralDepthModel) -> Result<()> {
+        clear_error_flag();
+        let ok = unsafe {
+            depthai::dai_neural_depth_build(self.node.handle(), left.handle, right.handle, c_int(model as i32))
+        };
+        if ok {
+            Ok(())
+        } else {
+            Err(last_error("failed to build NeuralDepth"))
+        }
+    }
+
+    /// Access the node's initial config (shared, modifications affect the node).
+    pub fn initial_config(&self) -> Result<NeuralDepthConfig> {
+        clear_error_flag();
+        let handle = unsafe { depthai::dai_neural_depth_get_initial_config(self.node.handle()) };
+        if handle.is_null() {
+            Err(last_error("failed to get NeuralDepth initialConfig"))
+        } else {
+            Ok(NeuralDepthConfig::from_handle(handle))
+        }
+    }
+}
@@ -0,0 +1,296 @@
+//! NNArchive models and the nodes that run them.
+//!
+//! An [`NNArchive`] bundles a compiled model with its config (input shapes, class names,
+//! supported platforms), so input sizes no longer have to be hardcoded next to the model.
+//!
+//! ```no_run
+//! # use depthai::{Pipeline, Result};
+//! # use depthai::nn_archive::{DetectionNetworkNode, NNArchive};
+//! # fn main() -> Result<()> {
+//! # let pipeline = Pipeline::new().build()?;
+//! let archive = NNArchive::open("yolov6n.rvc4.tar.xz")?;
+//! let (width, height) = archive.input_size().expect("archive declares an input size");
+//! println!("{} classes, input {width}x{height}", archive.classes().len());
+//!
+//! let nn = pipeline.create::<DetectionNetworkNode>()?;
+//! nn.set_nn_archive(&archive, None)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::ffi::CString;
+use std::path::Path;
+
+use autocxx::c_int;
+use depthai_sys::{depthai, DaiNNArchive};
+use serde::Deserialize;
+
+use crate::camera::ImageFrame;
+use crate::detections::ImgDetections;
+use crate::device::DevicePlatform;
+use crate::error::{clear_error_flag, last_error, DepthaiError, Result};
+use crate::nn_data::NNData;
+use crate::pipeline::take_owned_json_string;
+
+/// How the model inside an archive is compiled.
+///
+/// Mirrors C++: `dai::model::ModelType`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ModelType {
+    /// OpenVINO blob (RVC2).
+    Blob,
+    /// OpenVINO superblob, recompiled per SHAVE count (RVC2).
+    Superblob,
+    /// SNPE DLC (RVC4).
+    Dlc,
+    NnArchive,
+    Other,
+}
+
+/// One model input declared by the archive.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct NNArchiveInput {
+    pub name: String,
+    /// Tensor shape, e.g. `[1, 3, 288, 512]`.
+    pub shape: Vec<i64>,
+    /// Axis names matching `shape`, e.g. `"NCHW"`.
+    #[serde(default)]
+    pub layout: Option<String>,
+}
+
+/// One output head (parser) declared by the archive.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct NNArchiveHead {
+    /// Parser name, e.g. `"YOLO"`.
+    pub parser: String,
+    #[serde(default)]
+    pub classes: Vec<String>,
+    #[serde(default)]
+    pub n_classes: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NNArchiveInfo {
+    name: String,
+    model_type: ModelType,
+    platforms: Vec<String>,
+    inputs: Vec<NNArchiveInput>,
+    outputs: Vec<String>,
+    heads: Vec<NNArchiveHead>,
+}
+
+/// A model archive (`.tar.xz` NNArchive, or a bare `.blob`/`.superblob`/`.dlc`).
+///
+/// Mirrors C++: `dai::NNArchive`.
+pub struct NNArchive {
+    handle: DaiNNArchive,
+    info: NNArchiveInfo,
+}
+
+unsafe impl Send for NNArchive {}
+unsafe impl Sync for NNArchive {}
+
+impl Drop for NNArchive {
+    fn drop(&mut self) {
+        if !self.handle.is_null() {
+            unsafe { depthai::dai_nn_archive_release(self.handle) };
+            self.handle = std::ptr::null_mut();
+        }
+    }
+}
+
+impl NNArchive {
+    /// Open and parse the archive at `path`, e.g. a path returned by
+    /// [`get_model_from_zoo`](crate::model_zoo::get_model_from_zoo).
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let path_c = CString::new(path.to_string_lossy().as_bytes())
+            .map_err(|_| DepthaiError::new("invalid archive path: contains a NUL byte"))?;
+        clear_error_flag();
+        let handle = unsafe { depthai::dai_nn_archive_new(path_c.as_ptr()) };
+        if handle.is_null() {
+            return Err(last_error(&format!("failed to open NNArchive {}", path.display())));
+        }
+        let json = unsafe { depthai::dai_nn_archive_get_info_json(handle) };
+        let info = take_owned_json_string(json, "failed to read NNArchive config").and_then(|s| {
+            serde_json::from_str::<NNArchiveInfo>(&s).map_err(|e| DepthaiError::new(format!("invalid NNArchive info: {e}")))
+        });
+        match info {
+            Ok(info) => Ok(Self { handle, info }),
+            Err(err) => {
+                unsafe { depthai::dai_nn_archive_release(handle) };
+                Err(err)
+            }
+        }
+    }
+
+    pub(crate) fn handle(&self) -> DaiNNArchive {
+        self.handle
+    }
+
+    /// Model name from the archive metadata.
+    pub fn name(&self) -> &str {
+        &self.info.name
+    }
+
+    pub fn model_type(&self) -> ModelType {
+        self.info.model_type
+    }
+
+    /// Platforms the archive can run on.
+    ///
+    /// Mirrors C++: `NNArchive::getSupportedPlatforms()`.
+    pub fn supported_platforms(&self) -> Vec<DevicePlatform> {
+        self.info
+            .platforms
+            .iter()
+            .filter_map(|p| match p.as_str() {
+                "RVC2" => Some(DevicePlatform::Rvc2),
+                "RVC3" => Some(DevicePlatform::Rvc3),
+                "RVC4" => Some(DevicePlatform::Rvc4),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn supports(&self, platform: DevicePlatform) -> bool {
+        self.supported_platforms().contains(&platform)
+    }
+
+    pub fn inputs(&self) -> &[NNArchiveInput] {
+        &self.info.inputs
+    }
+
+    pub fn output_names(&self) -> &[String] {
+        &self.info.outputs
+    }
+
+    pub fn heads(&self) -> &[NNArchiveHead] {
+        &self.info.heads
+    }
+
+    /// `(width, height)` of the first input.
+    ///
+    /// Mirrors C++: `NNArchive::getInputSize()`.
+    pub fn input_size(&self) -> Option<(u32, u32)> {
+        self.input_size_at(0)
+    }
+
+    /// `(width, height)` of input `index`, read from its shape and layout (`NCHW` when the
+    /// archive doesn't declare one).
+    pub fn input_size_at(&self, index: usize) -> Option<(u32, u32)> {
+        let input = self.info.inputs.get(index)?;
+        let layout = input.layout.as_deref().unwrap_or("NCHW");
+        if layout.len() != input.shape.len() {
+            return None;
+        }
+        let dim = |axis: char| layout.find(axis).map(|i| input.shape[i]).filter(|v| *v > 0);
+        Some((dim('W')? as u32, dim('H')? as u32))
+    }
+
+    /// Class names of the first head that declares any.
+    pub fn classes(&self) -> &[String] {
+        self.info
+            .heads
+            .iter()
+            .map(|h| h.classes.as_slice())
+            .find(|c| !c.is_empty())
+            .unwrap_or(&[])
+    }
+}
+
+#[crate::native_node_wrapper(native = "dai::node::NeuralNetwork", inputs(input: ImageFrame), outputs(out: NNData, passthrough: ImageFrame))]
+pub struct NeuralNetworkNode {
+    node: crate::pipeline::Node,
+}
+
+impl NeuralNetworkNode {
+    /// Load the model from `archive`; `num_shaves` picks the superblob variant on RVC2.
+    ///
+    /// Mirrors C++: `NeuralNetwork::setNNArchive(nnArchive, numShaves)`.
+    pub fn set_nn_archive(&self, archive: &NNArchive, num_shaves: Option<u32>) -> Result<()> {
+        clear_error_flag();
+        let shaves = c_int(num_shaves.map_or(0, |s| s as i32));
+        let ok = unsafe { depthai::dai_neural_network_set_nn_archive(self.node.handle(), archive.handle(), shaves) };
+        if ok { Ok(()) } else { Err(last_error("failed to set NeuralNetwork archive")) }
+    }
+
+    pub fn set_num_inference_threads(&self, threads: u32) {
+        clear_error_flag();
+        unsafe { depthai::dai_neural_network_set_num_inference_threads(self.node.handle(), c_int(threads as i32)) };
+    }
+
+    /// SHAVE cores used by each inference thread (RVC2); must match the compiled blob.
+    ///
+    /// Mirrors C++: `NeuralNetwork::setNumShavesPerInferenceThread(numShaves)`.
+    pub fn set_num_shaves_per_inference_thread(&self, shaves: u32) {
+        clear_error_flag();
+        unsafe {
+            depthai::dai_neural_network_set_num_shaves_per_inference_thread(self.node.handle(), c_int(shaves as i32))
+        };
+    }
+
+    /// Neural compute engines used by each inference thread (RVC2, 0..=2).
+    ///
+    /// Mirrors C++: `NeuralNetwork::setNumNCEPerInferenceThread(numNCEPerThread)`.
+    pub fn set_num_nce_per_inference_thread(&self, nces: u32) {
+        clear_error_flag();
+        unsafe { depthai::dai_neural_network_set_num_nce_per_inference_thread(self.node.handle(), c_int(nces as i32)) };
+    }
+
+    /// Output messages allocated in the node's pool.
+    ///
+    /// Mirrors C++: `NeuralNetwork::setNumPoolFrames(numFrames)`.
+    pub fn set_num_pool_frames(&self, frames: u32) {
+        clear_error_flag();
+        unsafe { depthai::dai_neural_network_set_num_pool_frames(self.node.handle(), c_int(frames as i32)) };
+    }
+}
+
+#[crate::native_node_wrapper(
+    native = "dai::node::DetectionNetwork",
+    inputs(input: ImageFrame),
+    outputs(out: ImgDetections, passthrough: ImageFrame)
+)]
+pub struct DetectionNetworkNode {
+    node: crate::pipeline::Node,
+}
+
+impl DetectionNetworkNode {
+    /// Load the model and its decoding settings (anchors, classes, thresholds) from `archive`.
+    ///
+    /// Mirrors C++: `DetectionNetwork::setNNArchive(nnArchive, numShaves)`.
+    pub fn set_nn_archive(&self, archive: &NNArchive, num_shaves: Option<u32>) -> Result<()> {
+        clear_error_flag();
+        let shaves = c_int(num_shaves.map_or(0, |s| s as i32));
+        let ok = unsafe { depthai::dai_detection_network_set_nn_archive(self.node.handle(), archive.handle(), shaves) };
+        if ok { Ok(()) } else { Err(last_error("failed to set DetectionNetwork archive")) }
+    }
+
+    pub fn set_confidence_threshold(&self, threshold: f32) {
+        clear_error_flag();
+        unsafe { depthai::dai_detection_network_set_confidence_threshold(self.node.handle(), threshold) };
+    }
+
+    pub fn set_num_inference_threads(&self, threads: u32) {
+        clear_error_flag();
+        unsafe { depthai::dai_detection_network_set_num_inference_threads(self.node.handle(), c_int(threads as i32)) };
+    }
+
+    /// Mirrors C++: `DetectionNetwork::setNumShavesPerInferenceThread(numShaves)`.
+    pub fn set_num_shaves_per_inference_thread(&self, shaves: u32) {
+        clear_error_flag();
+        unsafe {
+            depthai::dai_detection_network_set_num_shaves_per_inference_thread(self.node.handle(), c_int(shaves as i32))
+        };
+    }
+
+    /// Mirrors C++: `DetectionNetwork::setNumNCEPerInferenceThread(numNCEPerThread)`.
+    pub fn set_num_nce_per_inference_thread(&self, nces: u32) {
+        clear_error_flag();
+        unsafe {
+            depthai::dai_detection_network_set_num_nce_per_inference_thread(self.node.handle(), c_int(nces as i32))
+        };
+    }
+}
@@ -0,0 +1,176 @@
+//! Raw neural network outputs.
+
+use std::ffi::CString;
+use std::time::Duration;
+
+use depthai_sys::{depthai, DaiNNData};
+use serde::Deserialize;
+
+use crate::error::{clear_error_flag, last_error, take_error_if_any, DepthaiError, Result};
+use crate::host_node::ns_to_duration;
+use crate::pipeline::take_owned_json_string;
+
+/// Element type of a tensor as produced by the model.
+///
+/// Mirrors C++: `dai::TensorInfo::DataType`. [`NNData::tensor`] always returns `f32`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TensorDataType {
+    Fp16,
+    U8F,
+    Int,
+    Fp32,
+    I8,
+    Fp64,
+    Unknown(i32),
+}
+
+impl TensorDataType {
+    fn from_raw(value: i32) -> Self {
+        match value {
+            0 => Self::Fp16,
+            1 => Self::U8F,
+            2 => Self::Int,
+            3 => Self::Fp32,
+            4 => Self::I8,
+            5 => Self::Fp64,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+/// Name, shape and type of one output tensor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TensorInfo {
+    pub name: String,
+    pub dims: Vec<u32>,
+    pub data_type: TensorDataType,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawTensorInfo {
+    name: String,
+    dims: Vec<u32>,
+    data_type: i32,
+}
+
+/// A dequantized tensor, row-major.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tensor {
+    pub dims: Vec<u32>,
+    pub data: Vec<f32>,
+}
+
+impl Tensor {
+    /// Element at a multi-dimensional `index`, `None` if it is out of bounds.
+    pub fn at(&self, index: &[usize]) -> Option<f32> {
+        if index.len() != self.dims.len() {
+            return None;
+        }
+        let mut offset = 0;
+        for (&i, &dim) in index.iter().zip(&self.dims) {
+            if i >= dim as usize {
+                return None;
+            }
+            offset = offset * dim as usize + i;
+        }
+        self.data.get(offset).copied()
+    }
+}
+
+/// Output tensors of a `NeuralNetwork` node.
+///
+/// Mirrors C++: `dai::NNData`.
+pub struct NNData {
+    handle: DaiNNData,
+}
+
+unsafe impl Send for NNData {}
+unsafe impl Sync for NNData {}
+
+impl Drop for NNData {
+    fn drop(&mut self) {
+        if !self.handle.is_null() {
+            unsafe { depthai::dai_nn_data_release(self.handle) };
+            self.handle = std::ptr::null_mut();
+        }
+    }
+}
+
+impl NNData {
+    pub(crate) fn from_handle(handle: DaiNNData) -> Self {
+        Self { handle }
+    }
+
+    pub(crate) fn handle(&self) -> DaiNNData {
+        self.handle
+    }
+
+    /// Output tensors, in model order.
+    ///
+    /// Mirrors C++: `NNData::getAllLayers()`.
+    pub fn layers(&self) -> Result<Vec<TensorInfo>> {
+        clear_error_flag();
+        let json = unsafe { depthai::dai_nn_data_get_layers_json(self.handle) };
+        let json = take_owned_json_string(json, "failed to list NNData layers")?;
+        let raw: Vec<RawTensorInfo> =
+            serde_json::from_str(&json).map_err(|e| DepthaiError::new(format!("invalid NNData layers: {e}")))?;
+        Ok(raw
+            .into_iter()
+            .map(|t| TensorInfo {
+                name: t.name,
+                dims: t.dims,
+                data_type: TensorDataType::from_raw(t.data_type),
+            })
+            .collect())
+    }
+
+    pub fn layer_names(&self) -> Result<Vec<String>> {
+        Ok(self.layers()?.into_iter().map(|t| t.name).collect())
+    }
+
+    /// Tensor `name`, dequantized to `f32`.
+    ///
+    /// Mirrors C++: `NNData::getTensor<float>(name, true)`.
+    pub fn tensor(&self, name: &str) -> Result<Tensor> {
+        let dims = self
+            .layers()?
+            .into_iter()
+            .find(|t| t.name == name)
+            .map(|t| t.dims)
+            .ok_or_else(|| DepthaiError::new(format!("NNData has no tensor '{name}'")))?;
+        let name_c = CString::new(name).map_err(|_| DepthaiError::new("invalid tensor name: contains a NUL byte"))?;
+        clear_error_flag();
+        let len = unsafe { depthai::dai_nn_data_get_tensor_f32(self.handle, name_c.as_ptr(), std::ptr::null_mut(), 0) };
+        if let Some(err) = take_error_if_any(&format!("failed to read tensor '{name}'")) {
+            return Err(err);
+        }
+        let mut data = vec![0f32; len];
+        let copied = unsafe { depthai::dai_nn_data_get_tensor_f32(self.handle, name_c.as_ptr(), data.as_mut_ptr(), len) };
+        if copied != len {
+            return Err(last_error(&format!("failed to read tensor '{name}'")));
+        }
+        Ok(Tensor { dims, data })
+    }
+
+    /// Timestamp on the host steady clock.
+    pub fn timestamp(&self) -> Result<Duration> {
+        clear_error_flag();
+        let ns = unsafe { depthai::dai_nn_data_get_timestamp_ns(self.handle) };
+        if let Some(err) = take_error_if_any("failed to get NNData timestamp") {
+            Err(err)
+        } else {
+            Ok(ns_to_duration(ns))
+        }
+    }
+
+    pub fn sequence_num(&self) -> Result<i64> {
+        clear_error_flag();
+        let seq = unsafe { depthai::dai_nn_data_get_sequence_num(self.handle) };
+        if let Some(err) = take_error_if_any("failed to get NNData sequence number") {
+            Err(err)
+        } else {
+            Ok(seq)
+        }
+    }
+}
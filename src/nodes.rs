@@ -0,0 +1,49 @@
+//! Wrappers for the DepthAI nodes without a dedicated module.
+//!
+//! Each wrapper creates its node with [`Pipeline::create`](crate::Pipeline::create) and exposes
+//! the node's ports by their C++ names, typed where the message type is fixed:
+//!
+//! ```no_run
+//! # use depthai::nodes::{FeatureTrackerNode, SyncNode};
+//! # use depthai::camera::{CameraBoardSocket, CameraNode, CameraOutputConfig};
+//! # use depthai::{Pipeline, Result};
+//! # fn main() -> Result<()> {
+//! let pipeline = Pipeline::new().build()?;
+//! let camera = pipeline.create_with::<CameraNode, _>(CameraBoardSocket::CamA)?;
+//! let frames = camera.request_output(CameraOutputConfig::new((640, 400)))?;
+//! let tracker = pipeline.create::<FeatureTrackerNode>()?;
+//! frames.link(&tracker.inputImage()?)?;
+//!
+//! let sync = pipeline.create::<SyncNode>()?;
+//! tracker.outputFeatures()?.link(&sync.input("features")?)?;
+//! tracker.passthroughInputImage()?.link(&sync.input("frame")?)?;
+//! let synced = sync.out()?.create_message_queue(4, false)?;
+//! # let _ = synced;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Nodes that only exist in some DepthAI-Core builds (Record/Replay) fail to create elsewhere;
+//! check with [`version::has_feature`](crate::version::has_feature) first.
+
+mod benchmark;
+mod legacy_camera;
+mod record;
+mod script;
+mod sensors;
+mod spatial_detection;
+mod stereo;
+mod sync;
+mod tracking;
+mod vision;
+
+pub use benchmark::{BenchmarkInNode, BenchmarkOutNode};
+pub use legacy_camera::{ColorCameraNode, MonoCameraNode};
+pub use record::{RecordMetadataOnlyNode, RecordVideoNode, ReplayMetadataOnlyNode, ReplayVideoNode};
+pub use script::ScriptNode;
+pub use sensors::{SystemLoggerNode, ThermalNode, ToFNode};
+pub use spatial_detection::SpatialDetectionNetworkNode;
+pub use stereo::RectificationNode;
+pub use sync::{MessageDemuxNode, SyncNode};
+pub use tracking::{FeatureTrackerNode, ObjectTrackerNode};
+pub use vision::{AprilTagNode, EdgeDetectorNode, PointCloudNode, WarpNode};
@@ -0,0 +1,15 @@
+/// Measures the rate and latency of the messages it receives.
+///
+/// Mirrors C++: `dai::node::BenchmarkIn`.
+#[crate::native_node_wrapper(native = "dai::node::BenchmarkIn", inputs(input), outputs(passthrough, report))]
+pub struct BenchmarkInNode {
+    node: crate::pipeline::Node,
+}
+
+/// Sends copies of one message at a fixed rate.
+///
+/// Mirrors C++: `dai::node::BenchmarkOut`.
+#[crate::native_node_wrapper(native = "dai::node::BenchmarkOut", inputs(input), outputs(out))]
+pub struct BenchmarkOutNode {
+    node: crate::pipeline::Node,
+}
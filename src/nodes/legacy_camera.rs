@@ -0,0 +1,28 @@
+use crate::camera::ImageFrame;
+use crate::camera_control::CameraControl;
+
+/// The v2 color camera node, kept for porting older pipelines; prefer
+/// [`CameraNode`](crate::camera::CameraNode).
+///
+/// Mirrors C++: `dai::node::ColorCamera`.
+#[crate::native_node_wrapper(
+    native = "dai::node::ColorCamera",
+    inputs(inputConfig, inputControl: CameraControl),
+    outputs(raw: ImageFrame, isp: ImageFrame, video: ImageFrame, preview: ImageFrame, still: ImageFrame, frameEvent)
+)]
+pub struct ColorCameraNode {
+    node: crate::pipeline::Node,
+}
+
+/// The v2 mono camera node, kept for porting older pipelines; prefer
+/// [`CameraNode`](crate::camera::CameraNode).
+///
+/// Mirrors C++: `dai::node::MonoCamera`.
+#[crate::native_node_wrapper(
+    native = "dai::node::MonoCamera",
+    inputs(inputControl: CameraControl),
+    outputs(out: ImageFrame, raw: ImageFrame, frameEvent)
+)]
+pub struct MonoCameraNode {
+    node: crate::pipeline::Node,
+}
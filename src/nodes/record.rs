@@ -0,0 +1,125 @@
+use std::ffi::{c_char, CString};
+use std::path::Path;
+
+use depthai_sys::depthai;
+
+use crate::camera::ImageFrame;
+use crate::error::{clear_error_flag, last_error, DepthaiError, Result};
+
+fn set_path(path: &Path, set: impl FnOnce(*const c_char) -> bool, context: &str) -> Result<()> {
+    let path = path
+        .to_str()
+        .ok_or_else(|| DepthaiError::new(format!("{context}: path must be valid UTF-8")))?;
+    let path = CString::new(path).map_err(|_| DepthaiError::new(format!("{context}: invalid path")))?;
+    clear_error_flag();
+    if set(path.as_ptr()) {
+        Ok(())
+    } else {
+        Err(last_error(context))
+    }
+}
+
+/// Records the frames it receives to a video file, with their metadata next to it, on the host
+/// (see [`Feature::RecordReplay`](crate::version::Feature::RecordReplay)).
+///
+/// Mirrors C++: `dai::node::RecordVideo`.
+#[crate::native_node_wrapper(native = "dai::node::RecordVideo", inputs(input))]
+pub struct RecordVideoNode {
+    node: crate::pipeline::Node,
+}
+
+impl RecordVideoNode {
+    /// Mirrors C++: `RecordVideo::setRecordVideoFile(path)`.
+    pub fn set_record_video_file(&self, path: impl AsRef<Path>) -> Result<()> {
+        let node = self.node.handle();
+        let set = |p| unsafe { depthai::dai_record_video_set_record_video_file(node, p) };
+        set_path(path.as_ref(), set, "failed to set RecordVideo video file")
+    }
+
+    /// Mirrors C++: `RecordVideo::setRecordMetadataFile(path)`.
+    pub fn set_record_metadata_file(&self, path: impl AsRef<Path>) -> Result<()> {
+        let node = self.node.handle();
+        let set = |p| unsafe { depthai::dai_record_video_set_record_metadata_file(node, p) };
+        set_path(path.as_ref(), set, "failed to set RecordVideo metadata file")
+    }
+}
+
+/// Records the messages it receives (IMU data, detections, ...) to a file on the host.
+///
+/// Mirrors C++: `dai::node::RecordMetadataOnly`.
+#[crate::native_node_wrapper(native = "dai::node::RecordMetadataOnly", inputs(input))]
+pub struct RecordMetadataOnlyNode {
+    node: crate::pipeline::Node,
+}
+
+impl RecordMetadataOnlyNode {
+    /// Mirrors C++: `RecordMetadataOnly::setRecordFile(path)`.
+    pub fn set_record_file(&self, path: impl AsRef<Path>) -> Result<()> {
+        let node = self.node.handle();
+        let set = |p| unsafe { depthai::dai_record_metadata_only_set_record_file(node, p) };
+        set_path(path.as_ref(), set, "failed to set RecordMetadataOnly file")
+    }
+}
+
+/// Replays frames recorded by [`RecordVideoNode`].
+///
+/// Mirrors C++: `dai::node::ReplayVideo`.
+#[crate::native_node_wrapper(native = "dai::node::ReplayVideo", outputs(out: ImageFrame))]
+pub struct ReplayVideoNode {
+    node: crate::pipeline::Node,
+}
+
+impl ReplayVideoNode {
+    /// Mirrors C++: `ReplayVideo::setReplayVideoFile(path)`.
+    pub fn set_replay_video_file(&self, path: impl AsRef<Path>) -> Result<()> {
+        let node = self.node.handle();
+        let set = |p| unsafe { depthai::dai_replay_video_set_replay_video_file(node, p) };
+        set_path(path.as_ref(), set, "failed to set ReplayVideo video file")
+    }
+
+    /// Mirrors C++: `ReplayVideo::setReplayMetadataFile(path)`.
+    pub fn set_replay_metadata_file(&self, path: impl AsRef<Path>) -> Result<()> {
+        let node = self.node.handle();
+        let set = |p| unsafe { depthai::dai_replay_video_set_replay_metadata_file(node, p) };
+        set_path(path.as_ref(), set, "failed to set ReplayVideo metadata file")
+    }
+
+    /// Start over at the end of the recording.
+    ///
+    /// Mirrors C++: `ReplayVideo::setLoop(loop)`.
+    pub fn set_loop(&self, enable: bool) -> Result<()> {
+        clear_error_flag();
+        if unsafe { depthai::dai_replay_video_set_loop(self.node.handle(), enable) } {
+            Ok(())
+        } else {
+            Err(last_error("failed to set ReplayVideo loop"))
+        }
+    }
+}
+
+/// Replays messages recorded by [`RecordMetadataOnlyNode`].
+///
+/// Mirrors C++: `dai::node::ReplayMetadataOnly`.
+#[crate::native_node_wrapper(native = "dai::node::ReplayMetadataOnly", outputs(out))]
+pub struct ReplayMetadataOnlyNode {
+    node: crate::pipeline::Node,
+}
+
+impl ReplayMetadataOnlyNode {
+    /// Mirrors C++: `ReplayMetadataOnly::setReplayFile(path)`.
+    pub fn set_replay_file(&self, path: impl AsRef<Path>) -> Result<()> {
+        let node = self.node.handle();
+        let set = |p| unsafe { depthai::dai_replay_metadata_only_set_replay_file(node, p) };
+        set_path(path.as_ref(), set, "failed to set ReplayMetadataOnly file")
+    }
+
+    /// Mirrors C++: `ReplayMetadataOnly::setLoop(loop)`.
+    pub fn set_loop(&self, enable: bool) -> Result<()> {
+        clear_error_flag();
+        if unsafe { depthai::dai_replay_metadata_only_set_loop(self.node.handle(), enable) } {
+            Ok(())
+        } else {
+            Err(last_error("failed to set ReplayMetadataOnly loop"))
+        }
+    }
+}
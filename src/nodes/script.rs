@@ -0,0 +1,47 @@
+use std::ffi::CString;
+
+use depthai_sys::depthai;
+
+use crate::error::{clear_error_flag, take_error_if_any, DepthaiError, Result};
+use crate::output::{Input, Output};
+
+/// Runs a Python script on the device. Its ports are named by the script (`node.io['name']`)
+/// and created on first use.
+///
+/// Mirrors C++: `dai::node::Script`.
+#[crate::native_node_wrapper(native = "dai::node::Script")]
+pub struct ScriptNode {
+    node: crate::pipeline::Node,
+}
+
+impl ScriptNode {
+    /// Mirrors C++: `Script::setScript(script, name)`.
+    pub fn set_script(&self, source: &str, name: Option<&str>) -> Result<()> {
+        let source = CString::new(source).map_err(|_| DepthaiError::new("script contains a NUL byte"))?;
+        let name = name
+            .map(|n| CString::new(n).map_err(|_| DepthaiError::new("invalid script name")))
+            .transpose()?;
+        clear_error_flag();
+        unsafe {
+            depthai::dai_script_set_script(
+                self.node.handle(),
+                source.as_ptr(),
+                name.as_ref().map_or(std::ptr::null(), |n| n.as_ptr()),
+            )
+        };
+        match take_error_if_any("failed to set script") {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    /// Mirrors C++: `script.inputs["name"]`.
+    pub fn input(&self, name: &str) -> Result<Input> {
+        self.node.input_in_map("io", name)
+    }
+
+    /// Mirrors C++: `script.outputs["name"]`.
+    pub fn output(&self, name: &str) -> Result<Output> {
+        self.node.output_in_map("io", name)
+    }
+}
@@ -0,0 +1,33 @@
+use crate::camera::ImageFrame;
+
+/// Depth from a time-of-flight sensor.
+///
+/// Mirrors C++: `dai::node::ToF`.
+#[crate::native_node_wrapper(
+    native = "dai::node::ToF",
+    inputs(inputConfig),
+    outputs(depth: ImageFrame, amplitude: ImageFrame, intensity: ImageFrame, phase: ImageFrame)
+)]
+pub struct ToFNode {
+    node: crate::pipeline::Node,
+}
+
+/// Frames from a thermal camera.
+///
+/// Mirrors C++: `dai::node::Thermal`.
+#[crate::native_node_wrapper(
+    native = "dai::node::Thermal",
+    inputs(inputConfig),
+    outputs(temperature: ImageFrame, color: ImageFrame)
+)]
+pub struct ThermalNode {
+    node: crate::pipeline::Node,
+}
+
+/// Periodic device CPU, memory and temperature reports.
+///
+/// Mirrors C++: `dai::node::SystemLogger`.
+#[crate::native_node_wrapper(native = "dai::node::SystemLogger", outputs(out))]
+pub struct SystemLoggerNode {
+    node: crate::pipeline::Node,
+}
@@ -0,0 +1,20 @@
+use crate::camera::ImageFrame;
+
+/// A [`DetectionNetworkNode`](crate::DetectionNetworkNode) that also measures the depth of each
+/// detection.
+///
+/// Mirrors C++: `dai::node::SpatialDetectionNetwork`.
+#[crate::native_node_wrapper(
+    native = "dai::node::SpatialDetectionNetwork",
+    inputs(input: ImageFrame, inputDepth: ImageFrame),
+    outputs(
+        out,
+        passthrough: ImageFrame,
+        passthroughDepth: ImageFrame,
+        boundingBoxMapping,
+        spatialLocationCalculatorOutput
+    )
+)]
+pub struct SpatialDetectionNetworkNode {
+    node: crate::pipeline::Node,
+}
@@ -0,0 +1,14 @@
+use crate::camera::ImageFrame;
+
+/// Rectifies a stereo pair with the device calibration (DepthAI-Core after v3.1.0, see
+/// [`Feature::Rectification`](crate::version::Feature::Rectification)).
+///
+/// Mirrors C++: `dai::node::Rectification`.
+#[crate::native_node_wrapper(
+    native = "dai::node::Rectification",
+    inputs(input1: ImageFrame, input2: ImageFrame),
+    outputs(output1: ImageFrame, output2: ImageFrame, passthrough1: ImageFrame, passthrough2: ImageFrame)
+)]
+pub struct RectificationNode {
+    node: crate::pipeline::Node,
+}
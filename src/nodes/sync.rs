@@ -0,0 +1,61 @@
+use std::time::Duration;
+
+use autocxx::c_int;
+use depthai_sys::depthai;
+
+use crate::error::clear_error_flag;
+use crate::host_node::MessageGroup;
+use crate::output::{Input, Output};
+use crate::Result;
+
+/// Groups messages from its inputs with close timestamps into one [`MessageGroup`], keyed by
+/// input name.
+///
+/// Mirrors C++: `dai::node::Sync`.
+#[crate::native_node_wrapper(native = "dai::node::Sync", outputs(out: MessageGroup))]
+pub struct SyncNode {
+    node: crate::pipeline::Node,
+}
+
+impl SyncNode {
+    /// The input whose messages appear under `name` in the group, created on first use.
+    ///
+    /// Mirrors C++: `sync.inputs["name"]`.
+    pub fn input(&self, name: &str) -> Result<Input> {
+        self.node.input_in_map("inputs", name)
+    }
+
+    /// Largest timestamp difference within a group.
+    ///
+    /// Mirrors C++: `Sync::setSyncThreshold(syncThreshold)`.
+    pub fn set_sync_threshold(&self, threshold: Duration) {
+        clear_error_flag();
+        let ns = i64::try_from(threshold.as_nanos()).unwrap_or(i64::MAX);
+        unsafe { depthai::dai_sync_set_sync_threshold_ns(self.node.handle(), ns) };
+    }
+
+    /// Attempts to complete a group before sending it anyway; `-1` waits indefinitely.
+    ///
+    /// Mirrors C++: `Sync::setSyncAttempts(syncAttempts)`.
+    pub fn set_sync_attempts(&self, attempts: i32) {
+        clear_error_flag();
+        unsafe { depthai::dai_sync_set_sync_attempts(self.node.handle(), c_int(attempts)) };
+    }
+}
+
+/// Splits a [`MessageGroup`] into one output per group member.
+///
+/// Mirrors C++: `dai::node::MessageDemux`.
+#[crate::native_node_wrapper(native = "dai::node::MessageDemux", inputs(input: MessageGroup))]
+pub struct MessageDemuxNode {
+    node: crate::pipeline::Node,
+}
+
+impl MessageDemuxNode {
+    /// The output carrying the group member `name`, created on first use.
+    ///
+    /// Mirrors C++: `demux.outputs["name"]`.
+    pub fn output(&self, name: &str) -> Result<Output> {
+        self.node.output_in_map("outputs", name)
+    }
+}
@@ -0,0 +1,33 @@
+use crate::camera::ImageFrame;
+
+/// Detects and tracks corner features across frames.
+///
+/// Mirrors C++: `dai::node::FeatureTracker`.
+#[crate::native_node_wrapper(
+    native = "dai::node::FeatureTracker",
+    inputs(inputConfig, inputImage: ImageFrame),
+    outputs(outputFeatures, passthroughInputImage: ImageFrame),
+    props(num_shaves: i32, num_memory_slices: i32)
+)]
+pub struct FeatureTrackerNode {
+    node: crate::pipeline::Node,
+}
+
+/// Tracks detections across frames. `inputDetections` takes 2D or spatial detections, so it is
+/// left untyped.
+///
+/// Mirrors C++: `dai::node::ObjectTracker`.
+#[crate::native_node_wrapper(
+    native = "dai::node::ObjectTracker",
+    inputs(inputTrackerFrame: ImageFrame, inputDetectionFrame: ImageFrame, inputDetections, inputConfig),
+    outputs(
+        out,
+        passthroughTrackerFrame: ImageFrame,
+        passthroughDetectionFrame: ImageFrame,
+        passthroughDetections
+    ),
+    props(tracker_threshold: f32, max_objects_to_track: u32, tracking_per_class: bool)
+)]
+pub struct ObjectTrackerNode {
+    node: crate::pipeline::Node,
+}
@@ -0,0 +1,46 @@
+use crate::camera::ImageFrame;
+use crate::pointcloud::PointCloudData;
+
+/// Detects AprilTag markers.
+///
+/// Mirrors C++: `dai::node::AprilTag`.
+#[crate::native_node_wrapper(
+    native = "dai::node::AprilTag",
+    inputs(inputConfig, inputImage: ImageFrame),
+    outputs(out, outConfig, passthroughInputImage: ImageFrame)
+)]
+pub struct AprilTagNode {
+    node: crate::pipeline::Node,
+}
+
+/// Sobel edge detection.
+///
+/// Mirrors C++: `dai::node::EdgeDetector`.
+#[crate::native_node_wrapper(
+    native = "dai::node::EdgeDetector",
+    inputs(inputConfig, inputImage: ImageFrame),
+    outputs(outputImage: ImageFrame, passthroughInputImage: ImageFrame)
+)]
+pub struct EdgeDetectorNode {
+    node: crate::pipeline::Node,
+}
+
+/// Perspective/mesh warp on the warp engine.
+///
+/// Mirrors C++: `dai::node::Warp`.
+#[crate::native_node_wrapper(native = "dai::node::Warp", inputs(inputImage: ImageFrame), outputs(out: ImageFrame))]
+pub struct WarpNode {
+    node: crate::pipeline::Node,
+}
+
+/// Turns depth frames into point clouds.
+///
+/// Mirrors C++: `dai::node::PointCloud`.
+#[crate::native_node_wrapper(
+    native = "dai::node::PointCloud",
+    inputs(inputConfig, inputDepth: ImageFrame),
+    outputs(outputPointCloud: PointCloudData, passthroughDepth: ImageFrame)
+)]
+pub struct PointCloudNode {
+    node: crate::pipeline::Node,
+}
@@ -1,15 +1,29 @@
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
+use std::marker::PhantomData;
+use std::ops::Deref;
 use std::sync::Arc;
+use std::time::Duration;
 
-use autocxx::c_uint;
+use autocxx::{c_int, c_uint};
 use depthai_sys::{depthai, DaiOutput, DaiInput};
 
 use crate::camera::{ImageFrame, OutputQueue};
-use crate::encoded_frame::EncodedFrameQueue;
-use crate::error::{clear_error_flag, last_error, Result};
-use crate::host_node::Buffer;
-use crate::pipeline::{Node, PipelineInner};
-use crate::queue::{InputQueue, MessageQueue};
+use crate::detections::ImgDetections;
+use crate::encoded_frame::{EncodedFrame, EncodedFrameQueue};
+use crate::error::{clear_error_flag, last_error, take_error_if_any, DepthaiError, Result};
+use crate::host_node::{Buffer, MessageGroup};
+use crate::image_align::ImageAlignConfig;
+use crate::image_filters::ImageFiltersConfig;
+use crate::image_manip::ImageManipConfig;
+use crate::camera_control::CameraControl;
+use crate::imu::ImuData;
+use crate::neural_depth::NeuralDepthConfig;
+use crate::nn_data::NNData;
+use crate::pointcloud::PointCloudData;
+use crate::rgbd::RgbdData;
+use crate::spatial_location::{SpatialLocationCalculatorConfig, SpatialLocationCalculatorData};
+use crate::pipeline::{Node, PipelineEvent, PipelineInner};
+use crate::queue::{Datatype, DatatypeEnum, InputQueue, MessageQueue};
 
 #[derive(Clone)]
 pub struct Output {
@@ -29,11 +43,151 @@ pub struct Input {
 unsafe impl Send for Input {}
 unsafe impl Sync for Input {}
 
+/// Size of the queues created by [`Output::create_default_message_queue`].
+pub const DEFAULT_QUEUE_SIZE: u32 = 4;
+
+/// Queue settings of the input end of a link, see [`Output::link_with`].
+///
+/// DepthAI keeps these on the input, so they apply to everything linked into it.
+/// The default matches DepthAI's own input defaults.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LinkOptions {
+    /// Whether a full input queue blocks the sender (`true`) or drops the oldest message.
+    pub blocking: bool,
+    /// Input queue capacity.
+    pub queue_size: u32,
+}
+
+impl Default for LinkOptions {
+    fn default() -> Self {
+        Self {
+            blocking: true,
+            queue_size: 3,
+        }
+    }
+}
+
+impl LinkOptions {
+    /// Keep only the newest `queue_size` messages; the sender never waits. Avoids latency
+    /// building up behind a slow consumer.
+    pub fn latest(queue_size: u32) -> Self {
+        Self {
+            blocking: false,
+            queue_size,
+        }
+    }
+}
+
+/// A message type produced by an output or accepted by an input.
+///
+/// Mirrors C++: `dai::Node::DatatypeHierarchy`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DatatypeHierarchy {
+    pub datatype: DatatypeEnum,
+    /// Whether types derived from `datatype` are also accepted.
+    pub descendants: bool,
+}
+
+/// A link leaving an [`Output`], see [`Output::links`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+pub struct LinkInfo {
+    #[serde(rename = "outputId")]
+    pub output_id: i32,
+    #[serde(rename = "outputGroup")]
+    pub output_group: String,
+    #[serde(rename = "outputName")]
+    pub output_name: String,
+    /// Id of the node the link goes into, -1 if it no longer exists.
+    #[serde(rename = "inputId")]
+    pub input_id: i32,
+    /// DepthAI type name of that node (e.g. `"ImageManip"`).
+    #[serde(rename = "inputNodeName")]
+    pub input_node_name: String,
+    #[serde(rename = "inputGroup")]
+    pub input_group: String,
+    #[serde(rename = "inputName")]
+    pub input_name: String,
+    /// Queue behavior of the input, `None` if the input no longer exists.
+    pub blocking: Option<bool>,
+    #[serde(rename = "queueSize")]
+    pub queue_size: Option<u32>,
+}
+
+#[derive(serde::Deserialize)]
+pub(crate) struct RawDatatypeHierarchy {
+    datatype: i32,
+    descendants: bool,
+}
+
+fn take_owned_string(ptr: *mut std::ffi::c_char, context: &str) -> Result<String> {
+    if ptr.is_null() {
+        return Err(last_error(context));
+    }
+    let s = unsafe { std::ffi::CStr::from_ptr(ptr).to_string_lossy().into_owned() };
+    unsafe { depthai::dai_free_cstring(ptr) };
+    Ok(s)
+}
+
+/// Parses the JSON returned by `dai_*_get_possible_datatypes_json`.
+///
+/// Datatypes unknown to this crate (e.g. from a newer DepthAI-Core) are skipped.
+fn parse_possible_datatypes(json: &str) -> Result<Vec<DatatypeHierarchy>> {
+    let raw: Vec<RawDatatypeHierarchy> = serde_json::from_str(json)
+        .map_err(|e| DepthaiError::new(format!("invalid JSON from depthai-core: {e}")))?;
+    Ok(known_datatypes(raw))
+}
+
+pub(crate) fn known_datatypes(raw: Vec<RawDatatypeHierarchy>) -> Vec<DatatypeHierarchy> {
+    raw.into_iter()
+        .filter_map(|h| {
+            DatatypeEnum::from_raw(h.datatype).map(|datatype| DatatypeHierarchy {
+                datatype,
+                descendants: h.descendants,
+            })
+        })
+        .collect()
+}
+
 impl Output {
     pub(crate) fn from_handle(pipeline: Arc<PipelineInner>, handle: DaiOutput) -> Self {
         Self { pipeline, handle }
     }
 
+    /// Port name (e.g. `"depth"`).
+    pub fn name(&self) -> Result<String> {
+        clear_error_flag();
+        let ptr = unsafe { depthai::dai_output_get_name(self.handle) };
+        take_owned_string(ptr, "failed to get output name")
+    }
+
+    /// Port group (empty for ports that are not part of a map).
+    pub fn group(&self) -> Result<String> {
+        clear_error_flag();
+        let ptr = unsafe { depthai::dai_output_get_group(self.handle) };
+        take_owned_string(ptr, "failed to get output group")
+    }
+
+    /// Message types this output may produce.
+    pub fn possible_datatypes(&self) -> Result<Vec<DatatypeHierarchy>> {
+        clear_error_flag();
+        let ptr = unsafe { depthai::dai_output_get_possible_datatypes_json(self.handle) };
+        let json = take_owned_string(ptr, "failed to get output datatypes")?;
+        parse_possible_datatypes(&json)
+    }
+
+    /// Whether this output's datatypes are accepted by `input`.
+    ///
+    /// Mirrors C++: `Node::Output::canConnect(const Input&)`.
+    pub fn can_connect(&self, input: &Input) -> Result<bool> {
+        clear_error_flag();
+        let v = unsafe { depthai::dai_output_can_connect(self.handle, input.handle) };
+        if let Some(e) = take_error_if_any("failed to check output/input compatibility") {
+            Err(e)
+        } else {
+            Ok(v)
+        }
+    }
+
     pub fn link_to(&self, to: &Node, in_name: Option<&str>) -> Result<()> {
         clear_error_flag();
         let in_name_c = in_name
@@ -59,9 +213,10 @@ impl Output {
         }
     }
 
-    pub fn link(&self, input: &Input) -> Result<()> {
+    /// Link to an input. Accepts both untyped [`Input`]s and [`TypedInput`]s.
+    pub fn link<I: AsRef<Input> + ?Sized>(&self, input: &I) -> Result<()> {
         clear_error_flag();
-        let ok = unsafe { depthai::dai_output_link_input(self.handle, input.handle) };
+        let ok = unsafe { depthai::dai_output_link_input(self.handle, input.as_ref().handle) };
         if ok {
             Ok(())
         } else {
@@ -69,6 +224,129 @@ impl Output {
         }
     }
 
+    /// Link to the input `in_name` of `to`, setting that input's queue behavior first.
+    ///
+    /// ```no_run
+    /// # use depthai::prelude::*;
+    /// # use depthai::output::LinkOptions;
+    /// # fn main() -> Result<()> {
+    /// # let pipeline = Pipeline::new().build()?;
+    /// let camera = pipeline.create_with::<CameraNode, _>(CameraBoardSocket::CamA)?;
+    /// let manip = pipeline.create_node("dai::node::ImageManip")?;
+    /// camera
+    ///     .request_output(CameraOutputConfig::new((640, 400)))?
+    ///     .link_with(&manip, "inputImage", LinkOptions { blocking: false, queue_size: 2 })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn link_with(&self, to: &Node, in_name: &str, options: LinkOptions) -> Result<()> {
+        self.link_with_options(&to.input(in_name)?, options)
+    }
+
+    /// Like [`Output::link`], setting the input's queue behavior first.
+    pub fn link_with_options<I: AsRef<Input> + ?Sized>(&self, input: &I, options: LinkOptions) -> Result<()> {
+        if options.queue_size == 0 {
+            return Err(DepthaiError::new("link queue size must be at least 1"));
+        }
+        let input = input.as_ref();
+        input.set_blocking(options.blocking)?;
+        input.set_max_size(options.queue_size)?;
+        self.link(input)
+    }
+
+    /// Remove the link to `input`, the reverse of [`Output::link`].
+    ///
+    /// Like linking, this only changes the graph before the pipeline is built; fails if the
+    /// two ports aren't linked.
+    ///
+    /// ```no_run
+    /// # use depthai::prelude::*;
+    /// # fn main() -> Result<()> {
+    /// # let pipeline = Pipeline::new().build()?;
+    /// # let camera = pipeline.create_with::<CameraNode, _>(CameraBoardSocket::CamA)?;
+    /// # let encoder = pipeline.create_node("dai::node::VideoEncoder")?;
+    /// # let record = false;
+    /// let out = camera.request_output(CameraOutputConfig::new((1920, 1080)))?;
+    /// let encoder_input = encoder.input("in")?;
+    /// out.link(&encoder_input)?;
+    /// if !record {
+    ///     out.unlink(&encoder_input)?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn unlink<I: AsRef<Input> + ?Sized>(&self, input: &I) -> Result<()> {
+        let input = input.as_ref();
+        let to = input.parent()?;
+        let in_name = CString::new(input.name()?).map_err(|_| DepthaiError::new("invalid input name"))?;
+        let in_group = CString::new(input.group()?).map_err(|_| DepthaiError::new("invalid input group"))?;
+        self.unlink_ports(&to, Some(&in_group), Some(&in_name))
+    }
+
+    /// Remove the link to the input `in_name` of `to`, the reverse of [`Output::link_to`].
+    ///
+    /// Without `in_name`, removes this output's link into `to`, preferring the node's main input
+    /// when there are several.
+    pub fn unlink_from(&self, to: &Node, in_name: Option<&str>) -> Result<()> {
+        let in_name_c = in_name
+            .map(|s| CString::new(s).map_err(|_| DepthaiError::new("invalid in_name")))
+            .transpose()?;
+        self.unlink_ports(to, None, in_name_c.as_deref())
+    }
+
+    fn unlink_ports(&self, to: &Node, in_group: Option<&CStr>, in_name: Option<&CStr>) -> Result<()> {
+        let from = self.parent()?;
+        let out_name = CString::new(self.name()?).map_err(|_| DepthaiError::new("invalid output name"))?;
+        let out_group = CString::new(self.group()?).map_err(|_| DepthaiError::new("invalid output group"))?;
+        // Ports outside of a map have an empty group; null means "any group" to the wrapper.
+        let group_ptr = |g: &CStr| if g.is_empty() { std::ptr::null() } else { g.as_ptr() };
+        clear_error_flag();
+        let ok = unsafe {
+            depthai::dai_node_unlink(
+                from.handle(),
+                group_ptr(&out_group),
+                out_name.as_ptr(),
+                to.handle(),
+                in_group.map_or(std::ptr::null(), group_ptr),
+                in_name.map_or(std::ptr::null(), CStr::as_ptr),
+            )
+        };
+        if ok {
+            Ok(())
+        } else {
+            Err(last_error("failed to unlink output"))
+        }
+    }
+
+    /// Links leaving this output, in the order they were made.
+    pub fn links(&self) -> Result<Vec<LinkInfo>> {
+        clear_error_flag();
+        let ptr = unsafe { depthai::dai_output_get_connections_json(self.handle) };
+        let json = take_owned_string(ptr, "failed to get output links")?;
+        serde_json::from_str(&json).map_err(|e| DepthaiError::new(format!("invalid links JSON from depthai-core: {e}")))
+    }
+
+    /// Whether this output is linked to `input`.
+    pub fn is_linked_to<I: AsRef<Input> + ?Sized>(&self, input: &I) -> Result<bool> {
+        let input = input.as_ref();
+        let (id, name, group) = (input.parent()?.id()?, input.name()?, input.group()?);
+        Ok(self
+            .links()?
+            .iter()
+            .any(|l| l.input_id == id && l.input_name == name && l.input_group == group))
+    }
+
+    /// The node this output belongs to.
+    pub(crate) fn parent(&self) -> Result<Node> {
+        clear_error_flag();
+        let handle = unsafe { depthai::dai_output_get_parent(self.handle) };
+        if handle.is_null() {
+            Err(last_error("failed to get output node"))
+        } else {
+            Ok(Node::from_handle(Arc::clone(&self.pipeline), handle))
+        }
+    }
+
     pub fn create_queue(&self, max_size: u32, blocking: bool) -> Result<OutputQueue> {
         clear_error_flag();
         let handle = unsafe { depthai::dai_output_create_queue(self.handle, c_uint(max_size), blocking) };
@@ -88,10 +366,30 @@ impl Output {
         if handle.is_null() {
             Err(last_error("failed to create message queue"))
         } else {
-            Ok(MessageQueue::from_handle(handle))
+            let queue = MessageQueue::from_handle(handle);
+            // Only a non-blocking queue drops, and counting costs every message a reconcile.
+            if !blocking && self.pipeline.has_event_subscribers() {
+                let name = queue.name().unwrap_or_default();
+                let emit = self.pipeline.event_emitter();
+                queue.set_drop_hook(move |stats| {
+                    emit(PipelineEvent::QueueOverflow {
+                        queue: name.clone(),
+                        stats: *stats,
+                    })
+                });
+            }
+            Ok(queue)
         }
     }
 
+    /// Create a non-blocking [`MessageQueue`] of [`DEFAULT_QUEUE_SIZE`] messages.
+    ///
+    /// A slow consumer then loses the oldest messages, visible in [`MessageQueue::stats`],
+    /// instead of stalling the device; prefer it unless every message must be processed.
+    pub fn create_default_message_queue(&self) -> Result<MessageQueue> {
+        self.create_message_queue(DEFAULT_QUEUE_SIZE, false)
+    }
+
     /// Create an output queue that yields `EncodedFrame` messages.
     ///
     /// This is primarily used with `VideoEncoderNode::out()`.
@@ -108,7 +406,7 @@ impl Output {
     pub fn send_buffer(&self, buffer: &Buffer) -> Result<()> {
         clear_error_flag();
         unsafe { depthai::dai_output_send_buffer(self.handle, buffer.handle()) };
-        if let Some(err) = crate::error::take_error_if_any("failed to send buffer") {
+        if let Some(err) = take_error_if_any("failed to send buffer") {
             Err(err)
         } else {
             Ok(())
@@ -118,7 +416,17 @@ impl Output {
     pub fn send_frame(&self, frame: &ImageFrame) -> Result<()> {
         clear_error_flag();
         unsafe { depthai::dai_output_send_img_frame(self.handle, frame.handle()) };
-        if let Some(err) = crate::error::take_error_if_any("failed to send frame") {
+        if let Some(err) = take_error_if_any("failed to send frame") {
+            Err(err)
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn send_detections(&self, detections: &ImgDetections) -> Result<()> {
+        clear_error_flag();
+        unsafe { depthai::dai_output_send_img_detections(self.handle, detections.handle()) };
+        if let Some(err) = take_error_if_any("failed to send detections") {
             Err(err)
         } else {
             Ok(())
@@ -131,6 +439,111 @@ impl Input {
         Self { pipeline, handle }
     }
 
+    /// The node this input belongs to.
+    pub(crate) fn parent(&self) -> Result<Node> {
+        clear_error_flag();
+        let handle = unsafe { depthai::dai_input_get_parent(self.handle) };
+        if handle.is_null() {
+            Err(last_error("failed to get input node"))
+        } else {
+            Ok(Node::from_handle(Arc::clone(&self.pipeline), handle))
+        }
+    }
+
+    /// Port name (e.g. `"inputConfig"`).
+    pub fn name(&self) -> Result<String> {
+        clear_error_flag();
+        let ptr = unsafe { depthai::dai_input_get_name(self.handle) };
+        take_owned_string(ptr, "failed to get input name")
+    }
+
+    /// Port group (empty for ports that are not part of a map).
+    pub fn group(&self) -> Result<String> {
+        clear_error_flag();
+        let ptr = unsafe { depthai::dai_input_get_group(self.handle) };
+        take_owned_string(ptr, "failed to get input group")
+    }
+
+    /// Message types this input accepts.
+    pub fn possible_datatypes(&self) -> Result<Vec<DatatypeHierarchy>> {
+        clear_error_flag();
+        let ptr = unsafe { depthai::dai_input_get_possible_datatypes_json(self.handle) };
+        let json = take_owned_string(ptr, "failed to get input datatypes")?;
+        parse_possible_datatypes(&json)
+    }
+
+    /// Mirrors C++: `Node::Input::getBlocking()`.
+    pub fn blocking(&self) -> Result<bool> {
+        clear_error_flag();
+        let v = unsafe { depthai::dai_input_get_blocking(self.handle) };
+        if let Some(e) = take_error_if_any("failed to get input blocking") {
+            Err(e)
+        } else {
+            Ok(v)
+        }
+    }
+
+    /// Whether a full input queue blocks the sender (`true`) or drops the oldest message.
+    ///
+    /// Mirrors C++: `Node::Input::setBlocking(bool)`.
+    pub fn set_blocking(&self, blocking: bool) -> Result<()> {
+        clear_error_flag();
+        unsafe { depthai::dai_input_set_blocking(self.handle, blocking) };
+        if let Some(e) = take_error_if_any("failed to set input blocking") {
+            Err(e)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Mirrors C++: `Node::Input::getMaxSize()`.
+    pub fn max_size(&self) -> Result<u32> {
+        clear_error_flag();
+        let v: u32 = unsafe { depthai::dai_input_get_max_size(self.handle).into() };
+        if let Some(e) = take_error_if_any("failed to get input max size") {
+            Err(e)
+        } else {
+            Ok(v)
+        }
+    }
+
+    /// Input queue capacity.
+    ///
+    /// Mirrors C++: `Node::Input::setMaxSize(unsigned int)`.
+    pub fn set_max_size(&self, max_size: u32) -> Result<()> {
+        clear_error_flag();
+        unsafe { depthai::dai_input_set_max_size(self.handle, c_uint(max_size)) };
+        if let Some(e) = take_error_if_any("failed to set input max size") {
+            Err(e)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Mirrors C++: `Node::Input::getReusePreviousMessage()`.
+    pub fn reuse_previous_message(&self) -> Result<bool> {
+        clear_error_flag();
+        let v = unsafe { depthai::dai_input_get_reuse_previous_message(self.handle) };
+        if let Some(e) = take_error_if_any("failed to get input reusePreviousMessage") {
+            Err(e)
+        } else {
+            Ok(v)
+        }
+    }
+
+    /// Keep serving the last message when no new one arrived (useful for config inputs).
+    ///
+    /// Mirrors C++: `Node::Input::setReusePreviousMessage(bool)`.
+    pub fn set_reuse_previous_message(&self, reuse: bool) -> Result<()> {
+        clear_error_flag();
+        unsafe { depthai::dai_input_set_reuse_previous_message(self.handle, reuse) };
+        if let Some(e) = take_error_if_any("failed to set input reusePreviousMessage") {
+            Err(e)
+        } else {
+            Ok(())
+        }
+    }
+
     pub fn get_buffer(&self) -> Result<Buffer> {
         clear_error_flag();
         let handle = unsafe { depthai::dai_input_get_buffer(self.handle) };
@@ -145,7 +558,7 @@ impl Input {
         clear_error_flag();
         let handle = unsafe { depthai::dai_input_try_get_buffer(self.handle) };
         if handle.is_null() {
-            if let Some(err) = crate::error::take_error_if_any("failed to poll buffer from input") {
+            if let Some(err) = take_error_if_any("failed to poll buffer from input") {
                 Err(err)
             } else {
                 Ok(None)
@@ -155,6 +568,47 @@ impl Input {
         }
     }
 
+    /// Wait for the next message of any type.
+    pub fn get_message(&self) -> Result<Datatype> {
+        clear_error_flag();
+        let handle = unsafe { depthai::dai_input_get_datatype(self.handle) };
+        if handle.is_null() {
+            Err(last_error("failed to get message from input"))
+        } else {
+            Ok(Datatype::from_handle(handle))
+        }
+    }
+
+    pub fn try_get_message(&self) -> Result<Option<Datatype>> {
+        clear_error_flag();
+        let handle = unsafe { depthai::dai_input_try_get_datatype(self.handle) };
+        if handle.is_null() {
+            if let Some(err) = take_error_if_any("failed to poll message from input") {
+                Err(err)
+            } else {
+                Ok(None)
+            }
+        } else {
+            Ok(Some(Datatype::from_handle(handle)))
+        }
+    }
+
+    /// Wait up to `timeout` for the next message; `Ok(None)` on timeout.
+    pub fn get_message_timeout(&self, timeout: Duration) -> Result<Option<Datatype>> {
+        clear_error_flag();
+        let timeout_ms = timeout.as_millis().min(i32::MAX as u128) as i32;
+        let handle = unsafe { depthai::dai_input_get_datatype_timeout(self.handle, c_int(timeout_ms)) };
+        if handle.is_null() {
+            if let Some(err) = take_error_if_any("failed to get message from input") {
+                Err(err)
+            } else {
+                Ok(None)
+            }
+        } else {
+            Ok(Some(Datatype::from_handle(handle)))
+        }
+    }
+
     pub fn get_frame(&self) -> Result<ImageFrame> {
         clear_error_flag();
         let handle = unsafe { depthai::dai_input_get_img_frame(self.handle) };
@@ -169,7 +623,7 @@ impl Input {
         clear_error_flag();
         let handle = unsafe { depthai::dai_input_try_get_img_frame(self.handle) };
         if handle.is_null() {
-            if let Some(err) = crate::error::take_error_if_any("failed to poll frame from input") {
+            if let Some(err) = take_error_if_any("failed to poll frame from input") {
                 Err(err)
             } else {
                 Ok(None)
@@ -215,4 +669,228 @@ impl Node {
             Ok(Input::from_handle(Arc::clone(&self.pipeline), handle))
         }
     }
+
+    /// Input `key` of the input map `map` (e.g. `Sync`'s `"inputs"`), created on first use.
+    ///
+    /// Mirrors C++: `node.inputs["key"]`.
+    pub fn input_in_map(&self, map: &str, key: &str) -> Result<Input> {
+        clear_error_flag();
+        let map_c = CString::new(map).map_err(|_| DepthaiError::new("invalid input map name"))?;
+        let key_c = CString::new(key).map_err(|_| DepthaiError::new("invalid input name"))?;
+        let handle = unsafe { depthai::dai_node_get_input_in_map(self.handle(), map_c.as_ptr(), key_c.as_ptr()) };
+        if handle.is_null() {
+            Err(last_error("failed to get node input"))
+        } else {
+            Ok(Input::from_handle(Arc::clone(&self.pipeline), handle))
+        }
+    }
+
+    /// Output `key` of the output map `map` (e.g. `MessageDemux`'s `"outputs"`), created on first
+    /// use.
+    ///
+    /// Mirrors C++: `node.outputs["key"]`.
+    pub fn output_in_map(&self, map: &str, key: &str) -> Result<Output> {
+        clear_error_flag();
+        let map_c = CString::new(map).map_err(|_| DepthaiError::new("invalid output map name"))?;
+        let key_c = CString::new(key).map_err(|_| DepthaiError::new("invalid output name"))?;
+        let handle = unsafe { depthai::dai_node_get_output_in_map(self.handle(), map_c.as_ptr(), key_c.as_ptr()) };
+        if handle.is_null() {
+            Err(last_error("failed to get node output"))
+        } else {
+            Ok(Output::from_handle(Arc::clone(&self.pipeline), handle))
+        }
+    }
+}
+
+/// A message type that can be carried by a typed port.
+///
+/// Used as the type parameter of [`TypedOutput`] / [`TypedInput`] so that linking ports with
+/// incompatible message types fails at compile time.
+pub trait Message {
+    /// DepthAI datatype carried by ports of this message type.
+    const DATATYPE: DatatypeEnum;
+}
+
+macro_rules! impl_message {
+    ($($ty:ty => $datatype:ident),* $(,)?) => {
+        $(
+            impl Message for $ty {
+                const DATATYPE: DatatypeEnum = DatatypeEnum::$datatype;
+            }
+        )*
+    };
+}
+
+impl_message!(
+    Buffer => Buffer,
+    ImageFrame => ImgFrame,
+    EncodedFrame => EncodedFrame,
+    ImageManipConfig => ImageManipConfig,
+    CameraControl => CameraControl,
+    ImageAlignConfig => ImageAlignConfig,
+    ImageFiltersConfig => ImageFiltersConfig,
+    NeuralDepthConfig => NeuralDepthConfig,
+    MessageGroup => MessageGroup,
+    PointCloudData => PointCloudData,
+    RgbdData => RGBDData,
+    ImuData => IMUData,
+    ImgDetections => ImgDetections,
+    NNData => NNData,
+    SpatialLocationCalculatorConfig => SpatialLocationCalculatorConfig,
+    SpatialLocationCalculatorData => SpatialLocationCalculatorData,
+);
+
+/// An [`Output`] known to carry messages of type `M`.
+///
+/// Dereferences to [`Output`], so every untyped operation (queues, `link_to`, ...) is still
+/// available. Generated by `#[native_node_wrapper]` for ports declared as `outputs(name: Type)`.
+pub struct TypedOutput<M> {
+    output: Output,
+    _marker: PhantomData<fn() -> M>,
+}
+
+/// An [`Input`] known to accept messages of type `M`.
+///
+/// Dereferences to [`Input`]. Generated by `#[native_node_wrapper]` for ports declared as
+/// `inputs(name: Type)`.
+pub struct TypedInput<M> {
+    input: Input,
+    _marker: PhantomData<fn() -> M>,
+}
+
+impl<M> Clone for TypedOutput<M> {
+    fn clone(&self) -> Self {
+        Self::from_untyped(self.output.clone())
+    }
+}
+
+impl<M> Clone for TypedInput<M> {
+    fn clone(&self) -> Self {
+        Self::from_untyped(self.input.clone())
+    }
+}
+
+impl<M> Deref for TypedOutput<M> {
+    type Target = Output;
+
+    fn deref(&self) -> &Output {
+        &self.output
+    }
+}
+
+impl<M> Deref for TypedInput<M> {
+    type Target = Input;
+
+    fn deref(&self) -> &Input {
+        &self.input
+    }
+}
+
+/// Inputs that a [`TypedOutput<M>`] may be linked to.
+///
+/// Implemented by [`TypedInput<M>`] (checked at compile time) and by the untyped [`Input`]
+/// (checked by DepthAI-Core when linking).
+#[diagnostic::on_unimplemented(message = "cannot link an output carrying `{M}` to `{Self}`")]
+pub trait LinkTarget<M> {
+    fn as_input(&self) -> &Input;
+}
+
+impl AsRef<Input> for Input {
+    fn as_ref(&self) -> &Input {
+        self
+    }
+}
+
+impl AsRef<Output> for Output {
+    fn as_ref(&self) -> &Output {
+        self
+    }
+}
+
+impl<M> AsRef<Input> for TypedInput<M> {
+    fn as_ref(&self) -> &Input {
+        &self.input
+    }
+}
+
+impl<M> AsRef<Output> for TypedOutput<M> {
+    fn as_ref(&self) -> &Output {
+        &self.output
+    }
+}
+
+impl<M> LinkTarget<M> for TypedInput<M> {
+    fn as_input(&self) -> &Input {
+        &self.input
+    }
+}
+
+impl<M> LinkTarget<M> for Input {
+    fn as_input(&self) -> &Input {
+        self
+    }
+}
+
+impl<M> TypedOutput<M> {
+    /// Tag an untyped output with its message type. The type is not verified.
+    pub fn from_untyped(output: Output) -> Self {
+        Self {
+            output,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Drop the message type.
+    pub fn into_untyped(self) -> Output {
+        self.output
+    }
+
+    /// Link to an input accepting the same message type (or an untyped input).
+    ///
+    /// ```compile_fail
+    /// # use depthai::camera::ImageFrame;
+    /// # use depthai::{PointCloudData, TypedInput, TypedOutput};
+    /// # fn f(pcl: TypedOutput<PointCloudData>, frames: TypedInput<ImageFrame>) {
+    /// pcl.link(&frames); // PointCloudData output cannot feed an ImageFrame input
+    /// # }
+    /// ```
+    pub fn link<I: LinkTarget<M> + ?Sized>(&self, input: &I) -> Result<()> {
+        self.output.link(input.as_input())
+    }
+}
+
+impl TypedOutput<Buffer> {
+    /// Send a buffer on this output (host nodes only).
+    pub fn send(&self, buffer: &Buffer) -> Result<()> {
+        self.output.send_buffer(buffer)
+    }
+}
+
+impl TypedOutput<ImageFrame> {
+    /// Send a frame on this output (host nodes only).
+    pub fn send(&self, frame: &ImageFrame) -> Result<()> {
+        self.output.send_frame(frame)
+    }
+}
+
+impl TypedOutput<ImgDetections> {
+    /// Send detections on this output (host nodes only).
+    pub fn send(&self, detections: &ImgDetections) -> Result<()> {
+        self.output.send_detections(detections)
+    }
+}
+
+impl<M> TypedInput<M> {
+    /// Tag an untyped input with its message type. The type is not verified.
+    pub fn from_untyped(input: Input) -> Self {
+        Self {
+            input,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Drop the message type.
+    pub fn into_untyped(self) -> Input {
+        self.input
+    }
 }
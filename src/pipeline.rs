@@ -1,13 +1,40 @@
+mod config;
 pub mod device_node;
+mod diff;
+mod dot;
+mod events;
+mod host_loop;
 pub mod node;
+mod node_error;
+mod pause;
+pub(crate) mod pools;
+mod properties;
+pub(crate) mod resources;
+mod validate;
 
 use autocxx::c_int;
 use depthai_sys::{depthai, DaiPipeline};
+pub use config::{
+    from_config, CameraOutputSettings, CameraSettings, ConfiguredNodes, ConfiguredPipeline, LinkConfig, NodeConfig,
+    PipelineConfig, StereoSettings,
+};
+pub use diff::{PropertyChange, SchemaConnection, SchemaDiff, SchemaNode};
 pub use device_node::{CreateInPipeline, CreateInPipelineWith, DeviceNode, DeviceNodeWithParams};
-pub use node::Node;
+pub use node::{Node, NodeInputInfo, NodeIoInfo, NodePortInfo};
+pub use events::{EventSubscription, PipelineEvent};
+pub use host_loop::{HostLoopExit, HostLoopOptions, HostLoopStats, LoopControl, LoopTick};
+pub use node_error::{NodeError, NodeErrorPolicy, NodeFailure, NodePhase};
+pub(crate) use node_error::NodeFailureReporter;
+pub use pools::{FramePool, FramePoolReport, PoolExhaustion};
+pub use properties::{
+    BoardConfig, FrameSyncConfig, GlobalProperties, GpioConfig, GpioDirection, GpioDrive, GpioLevel, GpioMode, GpioPull,
+    UartConfig, UsbConfig,
+};
+pub use resources::{NodeResources, ResourceBudget, ResourceEstimate};
+pub use validate::{validate_graph, ValidationIssue, ValidationIssueKind, ValidationSeverity};
 
-use std::collections::HashMap;
-use std::sync::Arc;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
 use std::{
     ffi::{CStr, CString},
     path::{Path, PathBuf},
@@ -15,9 +42,13 @@ use std::{
 
 use crate::{
     camera::{CameraBoardSocket, CameraNode},
-    device::Device,
-    error::{clear_error_flag, last_error, DepthaiError, Result},
-    host_node::{create_host_node, HostNode, HostNodeImpl},
+    cancel::CancellationToken,
+    common::ImageFrameType,
+    device::{Device, FirmwareLogMessage},
+    device_session,
+    error::{clear_error_flag, last_error, DepthaiError, ErrorContext, Result},
+    host_executor::ExecutionPolicy,
+    host_node::{create_host_node, create_host_node_with, create_host_node_with_policy, HostNode, HostNodeImpl},
     threaded_host_node::{create_threaded_host_node, ThreadedHostNode, ThreadedHostNodeImpl},
 };
 
@@ -55,6 +86,9 @@ pub struct PipelineNodeInfo {
     pub alias: String,
     /// DepthAI node type name (e.g. `"Camera"`, `"StereoDepth"`, `"HostNode"`).
     pub name: String,
+    /// Whether the node executes on the host rather than on the device.
+    #[serde(rename = "runOnHost", default)]
+    pub run_on_host: bool,
 }
 
 /// Connection between two nodes (output -> input) in a pipeline.
@@ -74,7 +108,10 @@ pub struct PipelineConnectionInfo {
     pub input_name: String,
 }
 
-fn take_owned_json_string(ptr: *mut std::ffi::c_char, context: &str) -> Result<String> {
+/// Firmware log lines kept for the [`ErrorContext`] of a failed [`Pipeline::start`].
+const START_LOG_TAIL: usize = 32;
+
+pub(crate) fn take_owned_json_string(ptr: *mut std::ffi::c_char, context: &str) -> Result<String> {
     if ptr.is_null() {
         return Err(last_error(context));
     }
@@ -83,13 +120,74 @@ fn take_owned_json_string(ptr: *mut std::ffi::c_char, context: &str) -> Result<S
     Ok(s)
 }
 
-fn parse_json_value(s: &str) -> Result<serde_json::Value> {
+pub(crate) fn parse_json_value(s: &str) -> Result<serde_json::Value> {
     serde_json::from_str(s)
         .map_err(|e| DepthaiError::new(format!("invalid JSON from depthai-core: {e}")))
 }
 
+/// Output metadata recorded by node wrappers when an output is configured (e.g. the size and
+/// frame type passed to `CameraNode::request_output`). Used by [`Pipeline::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputHint {
+    pub node_id: i32,
+    pub group: String,
+    pub name: String,
+    pub size: (u32, u32),
+    pub frame_type: Option<ImageFrameType>,
+}
+
 pub(crate) struct PipelineInner {
     handle: DaiPipeline,
+    output_hints: Mutex<Vec<OutputHint>>,
+    node_errors: node_error::NodeErrorLog,
+    events: Arc<events::EventBus>,
+    pause: pause::PauseControl,
+    /// The [`Device::mock`] a host-only pipeline was built for.
+    #[cfg(feature = "mock")]
+    mock_device: Option<Device>,
+}
+
+impl PipelineInner {
+    fn new(handle: DaiPipeline) -> Self {
+        Self {
+            handle,
+            output_hints: Mutex::new(Vec::new()),
+            node_errors: node_error::NodeErrorLog::default(),
+            events: Arc::default(),
+            pause: pause::PauseControl::default(),
+            #[cfg(feature = "mock")]
+            mock_device: None,
+        }
+    }
+
+    pub(crate) fn emit_event(&self, event: PipelineEvent) {
+        self.events.emit(event);
+    }
+
+    pub(crate) fn has_event_subscribers(&self) -> bool {
+        self.events.has_subscribers()
+    }
+
+    /// An emitter that doesn't keep the pipeline alive, e.g. for queue callbacks.
+    pub(crate) fn event_emitter(&self) -> impl Fn(PipelineEvent) + Send + Sync + 'static {
+        let bus = Arc::downgrade(&self.events);
+        move |event| {
+            if let Some(bus) = bus.upgrade() {
+                bus.emit(event);
+            }
+        }
+    }
+
+    pub(crate) fn record_output_hint(&self, hint: OutputHint) {
+        if let Ok(mut hints) = self.output_hints.lock() {
+            hints.retain(|h| !(h.node_id == hint.node_id && h.group == hint.group && h.name == hint.name));
+            hints.push(hint);
+        }
+    }
+
+    pub(crate) fn output_hints(&self) -> Vec<OutputHint> {
+        self.output_hints.lock().map(|h| h.clone()).unwrap_or_default()
+    }
 }
 
 unsafe impl Send for PipelineInner {}
@@ -111,7 +209,8 @@ pub struct Pipeline {
 /// Builder for constructing a [`Pipeline`] with optional configuration.
 ///
 /// This allows setting pipeline-wide options (device binding, OpenVINO version, tuning blob, etc.)
-/// before creating the underlying DepthAI pipeline handle.
+/// before creating the underlying DepthAI pipeline handle. Use
+/// [`PipelineBuilder::build_from_config`] to also create and link nodes from a [`PipelineConfig`].
 ///
 /// # Example
 /// ```no_run
@@ -137,6 +236,8 @@ pub struct PipelineBuilder {
     global_properties_json: Option<serde_json::Value>,
     board_config_json: Option<serde_json::Value>,
     eeprom_data_json: Option<serde_json::Value>,
+    global_properties: Option<GlobalProperties>,
+    board_config: Option<BoardConfig>,
 
     holistic_record_json: Option<serde_json::Value>,
     holistic_replay_path: Option<PathBuf>,
@@ -202,16 +303,50 @@ impl PipelineBuilder {
         self
     }
 
+    /// Override global properties; `None` fields keep their defaults.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use depthai::{Pipeline, Result};
+    /// # use depthai::pipeline::{BoardConfig, GlobalProperties};
+    /// # fn main() -> Result<()> {
+    /// let pipeline = Pipeline::new()
+    ///     .global_properties(GlobalProperties::new().with_xlink_chunk_size(0))
+    ///     .board_config(BoardConfig::new().with_watchdog_timeout_ms(4000))
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn global_properties(mut self, properties: GlobalProperties) -> Self {
+        self.global_properties = Some(properties);
+        self
+    }
+
+    /// Override the board configuration; `None` fields keep their defaults.
+    pub fn board_config(mut self, config: BoardConfig) -> Self {
+        self.board_config = Some(config);
+        self
+    }
+
+    /// Set EEPROM data from a [`CalibrationHandler`](crate::calibration::CalibrationHandler).
+    pub fn eeprom_data(mut self, calibration: &crate::calibration::CalibrationHandler) -> Self {
+        self.eeprom_data_json = Some(calibration.eeprom_json().clone());
+        self
+    }
+
+    /// Raw JSON form of [`PipelineBuilder::global_properties`], applied before it.
     pub fn global_properties_json(mut self, value: serde_json::Value) -> Self {
         self.global_properties_json = Some(value);
         self
     }
 
+    /// Raw JSON form of [`PipelineBuilder::board_config`], applied before it.
     pub fn board_config_json(mut self, value: serde_json::Value) -> Self {
         self.board_config_json = Some(value);
         self
     }
 
+    /// Raw JSON form of [`PipelineBuilder::eeprom_data`].
     pub fn eeprom_data_json(mut self, value: serde_json::Value) -> Self {
         self.eeprom_data_json = Some(value);
         self
@@ -268,6 +403,12 @@ impl PipelineBuilder {
         if let Some(v) = self.eeprom_data_json {
             pipeline.set_eeprom_data_json(&v)?;
         }
+        if let Some(v) = &self.global_properties {
+            pipeline.set_global_properties(v)?;
+        }
+        if let Some(v) = &self.board_config {
+            pipeline.set_board_config(v)?;
+        }
 
         if let Some(v) = self.holistic_record_json {
             pipeline.enable_holistic_record_json(&v)?;
@@ -301,7 +442,7 @@ impl Pipeline {
             Err(last_error("failed to create pipeline"))
         } else {
             Ok(Self {
-                inner: Arc::new(PipelineInner { handle }),
+                inner: Arc::new(PipelineInner::new(handle)),
             })
         }
     }
@@ -319,7 +460,7 @@ impl Pipeline {
             Err(last_error("failed to create pipeline"))
         } else {
             Ok(Self {
-                inner: Arc::new(PipelineInner { handle }),
+                inner: Arc::new(PipelineInner::new(handle)),
             })
         }
     }
@@ -340,22 +481,48 @@ impl Pipeline {
     }
 
     pub(crate) fn create_with_device(device: &Device) -> Result<Self> {
+        // A mock device has nothing to connect to: run host-only and remember it for
+        // `default_device`.
+        #[cfg(feature = "mock")]
+        if device.is_mock() {
+            clear_error_flag();
+            let handle = depthai::dai_pipeline_new_ex(false);
+            if handle.is_null() {
+                return Err(last_error("failed to create pipeline for mock device"));
+            }
+            let mut inner = PipelineInner::new(handle);
+            inner.mock_device = Some(device.clone());
+            return Ok(Self { inner: Arc::new(inner) });
+        }
         clear_error_flag();
         let handle = unsafe { depthai::dai_pipeline_new_with_device(device.handle()) };
         if handle.is_null() {
             Err(last_error("failed to create pipeline with device"))
         } else {
             Ok(Self {
-                inner: Arc::new(PipelineInner { handle }),
+                inner: Arc::new(PipelineInner::new(handle)),
             })
         }
     }
 
+    /// A reference that doesn't keep the pipeline alive.
+    pub(crate) fn downgrade(&self) -> std::sync::Weak<PipelineInner> {
+        Arc::downgrade(&self.inner)
+    }
+
+    pub(crate) fn upgrade(weak: &std::sync::Weak<PipelineInner>) -> Option<Self> {
+        weak.upgrade().map(|inner| Self { inner })
+    }
+
     /// Get the pipeline's default device handle (shared).
     ///
     /// Use this to avoid accidentally opening a second device connection when the pipeline
     /// was created with an implicit/default device.
     pub fn default_device(&self) -> Result<Device> {
+        #[cfg(feature = "mock")]
+        if let Some(device) = &self.inner.mock_device {
+            return Ok(device.clone());
+        }
         clear_error_flag();
         let handle = unsafe { depthai::dai_pipeline_get_default_device(self.inner.handle) };
         if handle.is_null() {
@@ -398,6 +565,21 @@ impl Pipeline {
         create_host_node(self, node)
     }
 
+    /// Create a custom host node whose state is built from the created node, e.g. to keep
+    /// handles to extra outputs declared with [`HostNode::create_output`].
+    pub fn create_host_node_with<T: HostNodeImpl, F>(&self, init: F) -> Result<HostNode>
+    where
+        F: FnOnce(&HostNode) -> Result<T>,
+    {
+        create_host_node_with(self, init)
+    }
+
+    /// Create a custom host node whose `process_group` runs according to `policy`, e.g. on a
+    /// [`HostExecutor`](crate::host_executor::HostExecutor) shared with other nodes.
+    pub fn create_host_node_with_policy<T: HostNodeImpl>(&self, node: T, policy: ExecutionPolicy) -> Result<HostNode> {
+        create_host_node_with_policy(self, policy, |_| Ok(node))
+    }
+
     /// Create a custom threaded host node implemented in Rust.
     pub fn create_threaded_host_node<T: ThreadedHostNodeImpl, F>(&self, init: F) -> Result<ThreadedHostNode>
     where
@@ -424,14 +606,48 @@ impl Pipeline {
     /// If the pipeline was created via [`Pipeline::with_device`], it will start using
     /// that device. If it was created via [`Pipeline::new`] (builder) without an explicit device,
     /// DepthAI will use the pipeline's internally-managed default device.
+    ///
+    /// On failure the error carries an [`ErrorContext`](crate::ErrorContext) with the firmware
+    /// logs received during the attempt, the node that failed and its schema. Fails with
+    /// [`ErrorKind::DeviceBusy`](crate::ErrorKind::DeviceBusy) while another pipeline runs on the
+    /// device, see [`device_session`](crate::device_session).
     pub fn start(&self) -> Result<()> {
+        self.start_in_session(None)
+    }
+
+    pub(crate) fn start_in_session(&self, session: Option<u64>) -> Result<()> {
+        let device = self.default_device().ok();
+        let claim = match device.as_ref().and_then(Device::connection_key) {
+            Some(key) => Some(device_session::claim_for_start(key, &self.inner, session)?),
+            None => None,
+        };
+        let logs = Arc::new(Mutex::new(VecDeque::with_capacity(START_LOG_TAIL)));
+        let _capture = device.and_then(|device| {
+            let logs = Arc::clone(&logs);
+            device
+                .add_log_callback(move |msg| {
+                    let mut logs = logs.lock().unwrap_or_else(|e| e.into_inner());
+                    if logs.len() == START_LOG_TAIL {
+                        logs.pop_front();
+                    }
+                    logs.push_back(msg.clone());
+                })
+                .ok()
+        });
         clear_error_flag();
         let started = unsafe { depthai::dai_pipeline_start(self.inner.handle) };
         if started {
-            Ok(())
-        } else {
-            Err(last_error("failed to start pipeline"))
+            if let Some(claim) = claim {
+                claim.started();
+            }
+            self.inner.events.emit(PipelineEvent::Started);
+            return Ok(());
         }
+        let error = last_error("failed to start pipeline");
+        let logs: Vec<FirmwareLogMessage> = logs.lock().unwrap_or_else(|e| e.into_inner()).drain(..).collect();
+        let schema = self.schema_json(SerializationType::Json).ok();
+        let context = ErrorContext::from_logs(error.message(), logs, schema.as_ref());
+        Err(error.with_context(context))
     }
 
     /// Returns whether the pipeline is currently running.
@@ -486,6 +702,17 @@ impl Pipeline {
         }
     }
 
+    /// [`wait`](Self::wait) that gives up with a [cancelled](DepthaiError::is_cancelled) error
+    /// once `token` is cancelled, see [`cancel`](crate::cancel).
+    pub fn wait_cancellable(&self, token: &CancellationToken) -> Result<()> {
+        while self.is_running()? {
+            token.check()?;
+            std::thread::sleep(crate::cancel::POLL_INTERVAL);
+        }
+        // Returns at once now, and reports what stopped the pipeline.
+        self.wait()
+    }
+
     /// Stop the pipeline.
     ///
     /// Mirrors C++: `pipeline.stop()`.
@@ -493,12 +720,69 @@ impl Pipeline {
         clear_error_flag();
         let ok = unsafe { depthai::dai_pipeline_stop(self.inner.handle) };
         if ok {
+            self.inner.events.emit(PipelineEvent::Stopped);
             Ok(())
         } else {
             Err(last_error("failed to stop pipeline"))
         }
     }
 
+    /// Take the host node failures (panics and errors) recorded since the last call.
+    ///
+    /// ```no_run
+    /// # use depthai::{NodeErrorPolicy, Pipeline, Result};
+    /// # fn main() -> Result<()> {
+    /// let pipeline = Pipeline::new().build()?;
+    /// pipeline.set_node_error_policy(NodeErrorPolicy { stop_on_panic: true, ..Default::default() });
+    /// // ... create host nodes, start ...
+    /// pipeline.wait()?;
+    /// for error in pipeline.take_node_errors() {
+    ///     eprintln!("{error}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn take_node_errors(&self) -> Vec<NodeError> {
+        self.inner.node_errors.take()
+    }
+
+    pub fn node_error_policy(&self) -> NodeErrorPolicy {
+        self.inner.node_errors.policy()
+    }
+
+    /// Choose when a host node failure stops the pipeline.
+    pub fn set_node_error_policy(&self, policy: NodeErrorPolicy) {
+        self.inner.node_errors.set_policy(policy);
+    }
+
+    /// Call `callback` for every [`PipelineEvent`] until the returned subscription is dropped.
+    ///
+    /// Callbacks run one event at a time on a background thread, so a slow subscriber delays
+    /// the others but never a DepthAI thread. Firmware logs and [`PipelineEvent::DeviceDisconnected`]
+    /// require the pipeline to have a default device when subscribing.
+    ///
+    /// ```no_run
+    /// # use depthai::{Pipeline, PipelineEvent, Result};
+    /// # fn main() -> Result<()> {
+    /// let pipeline = Pipeline::new().build()?;
+    /// let _events = pipeline.subscribe_events(|event| match event {
+    ///     PipelineEvent::QueueOverflow { queue, stats } => eprintln!("{queue}: {} dropped", stats.dropped),
+    ///     PipelineEvent::DeviceDisconnected => eprintln!("device lost"),
+    ///     _ => {}
+    /// })?;
+    /// pipeline.start()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn subscribe_events<F>(&self, callback: F) -> Result<EventSubscription>
+    where
+        F: FnMut(&PipelineEvent) + Send + 'static,
+    {
+        let device = self.default_device().ok();
+        clear_error_flag();
+        Ok(events::subscribe(&self.inner.events, device, Arc::downgrade(&self.inner), Box::new(callback)))
+    }
+
     /// Run the pipeline.
     ///
     /// Mirrors C++: `pipeline.run()`.
@@ -775,6 +1059,38 @@ impl Pipeline {
         }
     }
 
+    /// Global pipeline properties.
+    ///
+    /// Mirrors C++: `pipeline.getGlobalProperties()`.
+    pub fn global_properties(&self) -> Result<GlobalProperties> {
+        properties::from_json(self.global_properties_json()?, "global properties")
+    }
+
+    /// Apply the `Some` fields of `properties` (and its `extra` keys) over the current ones.
+    ///
+    /// Mirrors C++: `pipeline.setGlobalProperties()`.
+    pub fn set_global_properties(&self, properties: &GlobalProperties) -> Result<()> {
+        let mut value = self.global_properties_json()?;
+        properties::merge_json(&mut value, properties::to_json(properties, "global properties")?);
+        self.set_global_properties_json(&value)
+    }
+
+    /// Board configuration.
+    ///
+    /// Mirrors C++: `pipeline.getBoardConfig()`.
+    pub fn board_config(&self) -> Result<BoardConfig> {
+        properties::from_json(self.board_config_json()?, "board config")
+    }
+
+    /// Apply the `Some` fields of `config` (and its `extra` keys) over the current board config.
+    ///
+    /// Mirrors C++: `pipeline.setBoardConfig()`.
+    pub fn set_board_config(&self, config: &BoardConfig) -> Result<()> {
+        let mut value = self.board_config_json()?;
+        properties::merge_json(&mut value, properties::to_json(config, "board config")?);
+        self.set_board_config_json(&value)
+    }
+
     /// Get board configuration as JSON.
     pub fn board_config_json(&self) -> Result<serde_json::Value> {
         clear_error_flag();
@@ -890,9 +1206,18 @@ impl Pipeline {
         self.inner.handle
     }
 
+    /// Whether both handles refer to the same pipeline.
+    pub(crate) fn ptr_eq(&self, other: &Pipeline) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+
     pub(crate) fn inner_arc(&self) -> Arc<PipelineInner> {
         Arc::clone(&self.inner)
     }
+
+    pub(crate) fn from_inner(inner: Arc<PipelineInner>) -> Self {
+        Self { inner }
+    }
 }
 
 unsafe impl Send for Pipeline {}
@@ -0,0 +1,473 @@
+//! Declarative pipeline construction from JSON/YAML documents.
+//!
+//! A config describes nodes (with optional typed settings) and the links between their ports:
+//!
+//! ```yaml
+//! nodes:
+//!   - name: left
+//!     type: Camera
+//!     camera:
+//!       socket: CAM_B
+//!       outputs:
+//!         - { name: out, size: [640, 400], fps: 30 }
+//!   - name: right
+//!     type: Camera
+//!     camera:
+//!       socket: CAM_C
+//!       outputs:
+//!         - { name: out, size: [640, 400], fps: 30 }
+//!   - name: stereo
+//!     type: StereoDepth
+//!     stereo:
+//!       preset: Robotics
+//!       subpixel: true
+//! links:
+//!   - { from: left.out, to: stereo.left }
+//!   - { from: right.out, to: stereo.right, blocking: false, queue_size: 2 }
+//! ```
+//!
+//! Camera outputs are referenced by the `name` given in the config; every other port uses the
+//! DepthAI port name. YAML documents require the `yaml` feature.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::camera::{CameraBuildConfig, CameraNode, CameraOutputConfig};
+use crate::common::{CameraBoardSocket, ImageFrameType, ResizeMode};
+use crate::error::{DepthaiError, Result};
+use crate::output::{LinkOptions, Output};
+use crate::stereo_depth::{aligned_output_size, PresetMode, StereoDepthNode, StereoSizePolicy, STEREO_WIDTH_ALIGNMENT};
+
+use super::{Node, Pipeline, PipelineBuilder};
+
+/// Root of a declarative pipeline document.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PipelineConfig {
+    /// Create a host-only pipeline (no device) when loaded with [`from_config`].
+    #[serde(default)]
+    pub host_only: bool,
+    #[serde(default)]
+    pub nodes: Vec<NodeConfig>,
+    #[serde(default)]
+    pub links: Vec<LinkConfig>,
+}
+
+/// A node to instantiate.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NodeConfig {
+    /// Name used to reference the node in `links` (also set as the node alias).
+    pub name: String,
+    /// DepthAI node type, e.g. `"StereoDepth"` or `"dai::node::StereoDepth"`.
+    #[serde(rename = "type")]
+    pub node_type: String,
+    /// Settings for `Camera` nodes.
+    #[serde(default)]
+    pub camera: Option<CameraSettings>,
+    /// Settings for `StereoDepth` nodes.
+    #[serde(default)]
+    pub stereo: Option<StereoSettings>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CameraSettings {
+    /// Board socket, e.g. `"CAM_A"` (or `"CamA"`, `"AUTO"`).
+    pub socket: String,
+    #[serde(default)]
+    pub sensor_resolution: Option<(u32, u32)>,
+    #[serde(default)]
+    pub sensor_fps: Option<f32>,
+    /// Outputs to request, see [`CameraNode::request_output`].
+    #[serde(default)]
+    pub outputs: Vec<CameraOutputSettings>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CameraOutputSettings {
+    /// Name used to reference this output in `links`.
+    pub name: String,
+    pub size: (u32, u32),
+    /// Frame type name, e.g. `"NV12"`.
+    #[serde(default, rename = "type")]
+    pub frame_type: Option<String>,
+    /// `"Crop"`, `"Stretch"` or `"Letterbox"`.
+    #[serde(default)]
+    pub resize_mode: Option<String>,
+    #[serde(default)]
+    pub fps: Option<f32>,
+    #[serde(default)]
+    pub enable_undistortion: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StereoSettings {
+    /// Preset name, e.g. `"Robotics"` or `"HighDetail"`.
+    #[serde(default)]
+    pub preset: Option<String>,
+    #[serde(default)]
+    pub left_right_check: Option<bool>,
+    #[serde(default)]
+    pub subpixel: Option<bool>,
+    #[serde(default)]
+    pub extended_disparity: Option<bool>,
+    #[serde(default)]
+    pub distortion_correction: Option<bool>,
+    /// Its width is aligned to [`STEREO_WIDTH_ALIGNMENT`] according to `output_size_policy`.
+    #[serde(default)]
+    pub output_size: Option<(i32, i32)>,
+    /// `"Exact"` (the default: an unaligned width is an error), `"RoundDown"`, `"RoundUp"` or
+    /// `"Nearest"`, see [`StereoSizePolicy`].
+    #[serde(default)]
+    pub output_size_policy: Option<String>,
+    #[serde(default)]
+    pub output_keep_aspect_ratio: Option<bool>,
+}
+
+/// A link between two ports, written as `"node.port"`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LinkConfig {
+    pub from: String,
+    pub to: String,
+    /// Queue behavior of the destination input, see [`LinkOptions`]; unset keeps the input's.
+    #[serde(default)]
+    pub blocking: Option<bool>,
+    #[serde(default)]
+    pub queue_size: Option<u32>,
+}
+
+/// Nodes and outputs created from a [`PipelineConfig`], addressable by their config names.
+pub struct ConfiguredNodes {
+    nodes: HashMap<String, Node>,
+    outputs: HashMap<String, Output>,
+}
+
+impl ConfiguredNodes {
+    /// Node created for the config entry `name`.
+    pub fn node(&self, name: &str) -> Option<&Node> {
+        self.nodes.get(name)
+    }
+
+    /// Output `port` of node `node`, e.g. `output("left", "out")` for a requested camera output.
+    pub fn output(&self, node: &str, port: &str) -> Result<Output> {
+        if let Some(out) = self.outputs.get(&format!("{node}.{port}")) {
+            return Ok(out.clone());
+        }
+        self.nodes
+            .get(node)
+            .ok_or_else(|| DepthaiError::new(format!("unknown node '{node}'")))?
+            .output(port)
+    }
+}
+
+/// A pipeline built by [`from_config`].
+pub struct ConfiguredPipeline {
+    pub pipeline: Pipeline,
+    pub nodes: ConfiguredNodes,
+}
+
+/// Load a JSON (`.json`) or YAML (`.yaml`/`.yml`) config and build a pipeline from it.
+///
+/// The pipeline is created with the default (implicit) device unless `host_only` is set.
+/// Use [`PipelineBuilder::build_from_config`] to combine a config with pipeline-wide options
+/// such as an explicit device.
+pub fn from_config(path: impl AsRef<Path>) -> Result<ConfiguredPipeline> {
+    let config = PipelineConfig::from_path(path)?;
+    Pipeline::new().build_from_config(&config)
+}
+
+impl PipelineBuilder {
+    /// Build the pipeline, then create and link the nodes described by `config`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use depthai::{Device, Pipeline, Result};
+    /// # use depthai::pipeline::PipelineConfig;
+    /// # fn main() -> Result<()> {
+    /// let device = Device::new()?;
+    /// let config = PipelineConfig::from_path("pipeline.json")?;
+    /// let configured = Pipeline::new().with_device(&device).build_from_config(&config)?;
+    /// configured.pipeline.start()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn build_from_config(self, config: &PipelineConfig) -> Result<ConfiguredPipeline> {
+        config.validate()?;
+        let builder = if config.host_only { self.host_only() } else { self };
+        let pipeline = builder.build()?;
+        let nodes = config.apply(&pipeline)?;
+        Ok(ConfiguredPipeline { pipeline, nodes })
+    }
+}
+
+impl PipelineConfig {
+    /// Read and validate a config file; the format is picked from the file extension.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| DepthaiError::new(format!("failed to read config '{}': {e}", path.display())))?;
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("yaml" | "yml") => Self::from_yaml_str(&text),
+            _ => Self::from_json_str(&text),
+        }
+    }
+
+    /// Parse and validate a JSON config.
+    pub fn from_json_str(s: &str) -> Result<Self> {
+        let config: Self = serde_json::from_str(s)
+            .map_err(|e| DepthaiError::new(format!("invalid pipeline config: {e}")))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Parse and validate a YAML config.
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml_str(s: &str) -> Result<Self> {
+        let config: Self = serde_yaml::from_str(s)
+            .map_err(|e| DepthaiError::new(format!("invalid pipeline config: {e}")))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Parse and validate a YAML config.
+    #[cfg(not(feature = "yaml"))]
+    pub fn from_yaml_str(_s: &str) -> Result<Self> {
+        Err(DepthaiError::new("YAML pipeline configs require the `yaml` feature"))
+    }
+
+    /// Check names, enum values and link endpoints without touching a pipeline.
+    pub fn validate(&self) -> Result<()> {
+        let mut names = HashSet::new();
+        let mut camera_outputs = HashSet::new();
+        for node in &self.nodes {
+            if node.name.is_empty() || node.name.contains('.') {
+                return Err(DepthaiError::new(format!(
+                    "invalid node name '{}': must be non-empty and must not contain '.'",
+                    node.name
+                )));
+            }
+            if !names.insert(node.name.as_str()) {
+                return Err(DepthaiError::new(format!("duplicate node name '{}'", node.name)));
+            }
+            let ty = short_type(&node.node_type);
+            if node.camera.is_some() != (ty == "Camera") {
+                return Err(DepthaiError::new(format!(
+                    "node '{}': a `camera` section is required for, and only allowed on, Camera nodes",
+                    node.name
+                )));
+            }
+            if node.stereo.is_some() && ty != "StereoDepth" {
+                return Err(DepthaiError::new(format!(
+                    "node '{}': a `stereo` section is only allowed on StereoDepth nodes",
+                    node.name
+                )));
+            }
+            if let Some(camera) = &node.camera {
+                parse_socket(&camera.socket)?;
+                for out in &camera.outputs {
+                    if let Some(t) = &out.frame_type {
+                        parse_frame_type(t)?;
+                    }
+                    if let Some(m) = &out.resize_mode {
+                        parse_resize_mode(m)?;
+                    }
+                    if !camera_outputs.insert(format!("{}.{}", node.name, out.name)) {
+                        return Err(DepthaiError::new(format!(
+                            "node '{}': duplicate camera output '{}'",
+                            node.name, out.name
+                        )));
+                    }
+                }
+            }
+            if let Some(stereo) = &node.stereo {
+                if let Some(preset) = &stereo.preset {
+                    parse_preset(preset)?;
+                }
+                stereo_output_size(stereo).map_err(|e| DepthaiError::new(format!("node '{}': {e}", node.name)))?;
+            }
+        }
+        for link in &self.links {
+            for endpoint in [&link.from, &link.to] {
+                let (node, _) = split_endpoint(endpoint)?;
+                if !names.contains(node) {
+                    return Err(DepthaiError::new(format!("link '{endpoint}' references unknown node '{node}'")));
+                }
+            }
+            if link.queue_size == Some(0) {
+                return Err(DepthaiError::new(format!("link to '{}' has queue_size 0", link.to)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Create the configured nodes in `pipeline`, apply their settings and link them.
+    pub fn apply(&self, pipeline: &Pipeline) -> Result<ConfiguredNodes> {
+        self.validate()?;
+        let mut nodes = HashMap::new();
+        let mut outputs = HashMap::new();
+        for cfg in &self.nodes {
+            let node = match short_type(&cfg.node_type) {
+                "Camera" => {
+                    let settings = cfg.camera.as_ref().expect("validated");
+                    let camera = pipeline.create::<CameraNode>()?;
+                    camera.build(CameraBuildConfig {
+                        board_socket: parse_socket(&settings.socket)?,
+                        sensor_resolution: settings.sensor_resolution,
+                        sensor_fps: settings.sensor_fps,
+                    })?;
+                    for out in &settings.outputs {
+                        let output = camera.request_output(camera_output_config(out)?)?;
+                        outputs.insert(format!("{}.{}", cfg.name, out.name), output);
+                    }
+                    camera.as_node().clone()
+                }
+                "StereoDepth" => {
+                    let stereo = pipeline.create::<StereoDepthNode>()?;
+                    if let Some(settings) = &cfg.stereo {
+                        apply_stereo_settings(&stereo, settings)?;
+                    }
+                    stereo.as_node().clone()
+                }
+                _ => pipeline.create_node(&native_type(&cfg.node_type))?,
+            };
+            node.set_alias(&cfg.name)?;
+            nodes.insert(cfg.name.clone(), node);
+        }
+
+        let configured = ConfiguredNodes { nodes, outputs };
+        for link in &self.links {
+            let (from_node, from_port) = split_endpoint(&link.from)?;
+            let (to_node, to_port) = split_endpoint(&link.to)?;
+            let output = configured.output(from_node, from_port)?;
+            let input = configured.nodes[to_node].input(to_port)?;
+            let result = if link.blocking.is_some() || link.queue_size.is_some() {
+                let options = LinkOptions {
+                    blocking: link.blocking.map_or_else(|| input.blocking(), Ok)?,
+                    queue_size: link.queue_size.map_or_else(|| input.max_size(), Ok)?,
+                };
+                output.link_with_options(&input, options)
+            } else {
+                output.link(&input)
+            };
+            result.map_err(|e| DepthaiError::new(format!("failed to link '{}' -> '{}': {e}", link.from, link.to)))?;
+        }
+        Ok(configured)
+    }
+}
+
+fn camera_output_config(out: &CameraOutputSettings) -> Result<CameraOutputConfig> {
+    Ok(CameraOutputConfig {
+        size: out.size,
+        frame_type: out.frame_type.as_deref().map(parse_frame_type).transpose()?,
+        resize_mode: out.resize_mode.as_deref().map(parse_resize_mode).transpose()?.unwrap_or_default(),
+        fps: out.fps,
+        enable_undistortion: out.enable_undistortion,
+    })
+}
+
+fn apply_stereo_settings(stereo: &StereoDepthNode, settings: &StereoSettings) -> Result<()> {
+    // The preset resets other settings, so it is applied first.
+    if let Some(preset) = &settings.preset {
+        stereo.set_default_profile_preset(parse_preset(preset)?);
+    }
+    if let Some(v) = settings.left_right_check {
+        stereo.set_left_right_check(v);
+    }
+    if let Some(v) = settings.subpixel {
+        stereo.set_subpixel(v);
+    }
+    if let Some(v) = settings.extended_disparity {
+        stereo.set_extended_disparity(v);
+    }
+    if let Some(v) = settings.distortion_correction {
+        stereo.enable_distortion_correction(v);
+    }
+    if let Some((w, h)) = stereo_output_size(settings)? {
+        stereo.set_output_size(w as i32, h as i32);
+    }
+    if let Some(v) = settings.output_keep_aspect_ratio {
+        stereo.set_output_keep_aspect_ratio(v);
+    }
+    Ok(())
+}
+
+fn short_type(node_type: &str) -> &str {
+    node_type.strip_prefix("dai::node::").unwrap_or(node_type)
+}
+
+fn native_type(node_type: &str) -> String {
+    if node_type.contains("::") {
+        node_type.to_string()
+    } else {
+        format!("dai::node::{node_type}")
+    }
+}
+
+fn split_endpoint(endpoint: &str) -> Result<(&str, &str)> {
+    endpoint
+        .split_once('.')
+        .filter(|(node, port)| !node.is_empty() && !port.is_empty())
+        .ok_or_else(|| DepthaiError::new(format!("invalid link endpoint '{endpoint}': expected 'node.port'")))
+}
+
+/// Compare enum variant names ignoring case and `_` (so `CAM_A` matches `CamA`).
+fn same_name(a: &str, b: &str) -> bool {
+    let norm = |s: &str| s.chars().filter(|c| *c != '_').collect::<String>().to_ascii_lowercase();
+    norm(a) == norm(b)
+}
+
+fn parse_socket(s: &str) -> Result<CameraBoardSocket> {
+    (-1..=9)
+        .map(CameraBoardSocket::from_raw)
+        .find(|v| same_name(&format!("{v:?}"), s))
+        .ok_or_else(|| DepthaiError::new(format!("unknown camera socket '{s}'")))
+}
+
+fn parse_frame_type(s: &str) -> Result<ImageFrameType> {
+    (0..64)
+        .filter_map(ImageFrameType::from_raw)
+        .find(|v| same_name(&format!("{v:?}"), s))
+        .ok_or_else(|| DepthaiError::new(format!("unknown frame type '{s}'")))
+}
+
+fn parse_resize_mode(s: &str) -> Result<ResizeMode> {
+    [ResizeMode::Crop, ResizeMode::Stretch, ResizeMode::Letterbox]
+        .into_iter()
+        .find(|v| same_name(&format!("{v:?}"), s))
+        .ok_or_else(|| DepthaiError::new(format!("unknown resize mode '{s}'")))
+}
+
+fn parse_size_policy(s: &str) -> Result<StereoSizePolicy> {
+    use StereoSizePolicy::*;
+    [Exact, RoundDown, RoundUp, Nearest]
+        .into_iter()
+        .find(|v| same_name(&format!("{v:?}"), s))
+        .ok_or_else(|| DepthaiError::new(format!("unknown stereo output size policy '{s}'")))
+}
+
+/// The configured output size, aligned according to the configured policy.
+fn stereo_output_size(settings: &StereoSettings) -> Result<Option<(u32, u32)>> {
+    let policy = match &settings.output_size_policy {
+        Some(policy) => parse_size_policy(policy)?,
+        None => StereoSizePolicy::default(),
+    };
+    let Some((w, h)) = settings.output_size else {
+        return Ok(None);
+    };
+    let size = (u32::try_from(w).unwrap_or(0), u32::try_from(h).unwrap_or(0));
+    aligned_output_size(size, STEREO_WIDTH_ALIGNMENT, policy).map(Some)
+}
+
+fn parse_preset(s: &str) -> Result<PresetMode> {
+    use PresetMode::*;
+    [FastAccuracy, FastDensity, Default, Face, HighDetail, Robotics]
+        .into_iter()
+        .find(|v| same_name(&format!("{v:?}"), s))
+        .ok_or_else(|| DepthaiError::new(format!("unknown stereo preset '{s}'")))
+}
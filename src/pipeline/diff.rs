@@ -0,0 +1,295 @@
+//! Structured comparison of two pipeline schemas.
+//!
+//! A DepthAI-Core upgrade can change the default properties of a node without any API change,
+//! and the pipeline then quietly behaves differently. Saving [`Pipeline::schema_json`] with the
+//! old version and comparing it with the schema built by the new one shows what moved:
+//!
+//! ```no_run
+//! # use depthai::pipeline::{Pipeline, SchemaDiff, SerializationType};
+//! # fn build() -> depthai::Result<Pipeline> { Pipeline::new().build() }
+//! # fn main() -> depthai::Result<()> {
+//! let before: serde_json::Value =
+//!     serde_json::from_str(&std::fs::read_to_string("schema-v3.1.json").expect("snapshot")).expect("json");
+//! let pipeline = build()?;
+//! let diff = SchemaDiff::between(&before, &pipeline.schema_json(SerializationType::Json)?);
+//! if !diff.is_empty() {
+//!     eprintln!("{diff}");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Node ids may shift between versions (e.g. when a node starts creating an internal node), so
+//! nodes are matched by type name and alias, in id order, rather than by id.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use serde_json::Value;
+
+use crate::error::Result;
+
+use super::dot::port_label;
+use super::resources::{node_properties, schema_nodes};
+use super::{Pipeline, SerializationType};
+
+/// A node of a schema, as reported in a [`SchemaDiff`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaNode {
+    /// Id in the schema the node comes from (the newer one for nodes in both).
+    pub id: i32,
+    /// DepthAI node type name (e.g. `"StereoDepth"`).
+    pub name: String,
+    pub alias: String,
+}
+
+impl fmt::Display for SchemaNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} #{}", self.name, self.id)?;
+        if !self.alias.is_empty() {
+            write!(f, " ({})", self.alias)?;
+        }
+        Ok(())
+    }
+}
+
+/// A property that differs between the two schemas.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyChange {
+    /// `None` for the pipeline's global properties.
+    pub node: Option<SchemaNode>,
+    /// Dotted path into the properties, e.g. `initialConfig.postProcessing.median`.
+    pub path: String,
+    /// `None` when the property is new.
+    pub before: Option<Value>,
+    /// `None` when the property is gone.
+    pub after: Option<Value>,
+}
+
+/// A link present in only one of the schemas.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaConnection {
+    pub from: SchemaNode,
+    /// Output name, `group[name]` for grouped outputs.
+    pub output: String,
+    pub to: SchemaNode,
+    pub input: String,
+}
+
+impl fmt::Display for SchemaConnection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{} -> {}.{}", self.from, self.output, self.to, self.input)
+    }
+}
+
+/// Differences between two pipeline schemas, see the [module docs](self).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SchemaDiff {
+    pub nodes_added: Vec<SchemaNode>,
+    pub nodes_removed: Vec<SchemaNode>,
+    /// Property changes of nodes present in both schemas, and of the global properties.
+    pub property_changes: Vec<PropertyChange>,
+    pub connections_added: Vec<SchemaConnection>,
+    pub connections_removed: Vec<SchemaConnection>,
+}
+
+/// Identity of a node across schemas: type name, alias and rank among nodes sharing both.
+type NodeKey = (String, String, usize);
+
+struct ParsedNode {
+    node: SchemaNode,
+    properties: Option<Value>,
+}
+
+struct ParsedSchema {
+    nodes: BTreeMap<NodeKey, ParsedNode>,
+    connections: Vec<(NodeKey, String, NodeKey, String)>,
+    globals: Option<Value>,
+}
+
+impl ParsedSchema {
+    /// Accepts a schema or the output of [`Pipeline::serialize_to_json`].
+    fn parse(json: &Value) -> Self {
+        let schema = json.get("pipeline").unwrap_or(json);
+        let mut nodes: Vec<ParsedNode> = schema_nodes(schema)
+            .map(|n| ParsedNode {
+                node: SchemaNode {
+                    id: n.get("id").and_then(Value::as_i64).unwrap_or(-1) as i32,
+                    name: n.get("name").and_then(Value::as_str).unwrap_or_default().to_string(),
+                    alias: n.get("alias").and_then(Value::as_str).unwrap_or_default().to_string(),
+                },
+                properties: node_properties(n),
+            })
+            .collect();
+        nodes.sort_by_key(|n| n.node.id);
+
+        let mut ranks: HashMap<(String, String), usize> = HashMap::new();
+        let mut parsed = BTreeMap::new();
+        let mut keys = HashMap::new();
+        for n in nodes {
+            let rank = ranks.entry((n.node.name.clone(), n.node.alias.clone())).or_default();
+            let key = (n.node.name.clone(), n.node.alias.clone(), *rank);
+            *rank += 1;
+            keys.insert(n.node.id, key.clone());
+            parsed.insert(key, n);
+        }
+
+        let str_field = |c: &Value, k: &str| c.get(k).and_then(Value::as_str).unwrap_or_default().to_string();
+        let connections = schema
+            .get("connections")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|c| {
+                let from = keys.get(&(c.get("node1Id")?.as_i64()? as i32))?.clone();
+                let to = keys.get(&(c.get("node2Id")?.as_i64()? as i32))?.clone();
+                let output = port_label(&str_field(c, "node1OutputGroup"), &str_field(c, "node1Output"));
+                let input = port_label(&str_field(c, "node2InputGroup"), &str_field(c, "node2Input"));
+                Some((from, output, to, input))
+            })
+            .collect();
+
+        Self {
+            nodes: parsed,
+            connections,
+            globals: schema.get("globalProperties").cloned(),
+        }
+    }
+
+    fn node(&self, key: &NodeKey) -> SchemaNode {
+        self.nodes[key].node.clone()
+    }
+}
+
+/// Path, value before and value after.
+type ValueChange = (String, Option<Value>, Option<Value>);
+
+/// Record the differences between `before` and `after` under `path`.
+fn diff_values(path: &str, before: Option<&Value>, after: Option<&Value>, out: &mut Vec<ValueChange>) {
+    let join = |key: &str| if path.is_empty() { key.to_string() } else { format!("{path}.{key}") };
+    match (before, after) {
+        (Some(Value::Object(a)), Some(Value::Object(b))) => {
+            let mut keys: Vec<&String> = a.keys().chain(b.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                diff_values(&join(key), a.get(key), b.get(key), out);
+            }
+        }
+        (Some(Value::Array(a)), Some(Value::Array(b))) if a.len() == b.len() => {
+            for (i, (x, y)) in a.iter().zip(b).enumerate() {
+                diff_values(&join(&i.to_string()), Some(x), Some(y), out);
+            }
+        }
+        (a, b) if a != b => out.push((path.to_string(), a.cloned(), b.cloned())),
+        _ => {}
+    }
+}
+
+impl SchemaDiff {
+    /// Compare two schemas as returned by [`Pipeline::schema_json`] with
+    /// [`SerializationType::Json`] (or two [`Pipeline::serialize_to_json`] outputs).
+    ///
+    /// Node properties serialized in a binary format can't be compared and are skipped.
+    pub fn between(before: &Value, after: &Value) -> Self {
+        let before = ParsedSchema::parse(before);
+        let after = ParsedSchema::parse(after);
+        let mut diff = SchemaDiff::default();
+
+        let mut changes = Vec::new();
+        diff_values("", before.globals.as_ref(), after.globals.as_ref(), &mut changes);
+        diff.property_changes.extend(changes.drain(..).map(|(path, before, after)| PropertyChange {
+            node: None,
+            path,
+            before,
+            after,
+        }));
+
+        for (key, node) in &after.nodes {
+            match before.nodes.get(key) {
+                None => diff.nodes_added.push(node.node.clone()),
+                Some(old) => {
+                    diff_values("", old.properties.as_ref(), node.properties.as_ref(), &mut changes);
+                    diff.property_changes.extend(changes.drain(..).map(|(path, before, after)| PropertyChange {
+                        node: Some(node.node.clone()),
+                        path,
+                        before,
+                        after,
+                    }));
+                }
+            }
+        }
+        diff.nodes_removed = before
+            .nodes
+            .iter()
+            .filter(|(key, _)| !after.nodes.contains_key(*key))
+            .map(|(_, n)| n.node.clone())
+            .collect();
+
+        let connection = |schema: &ParsedSchema, (from, output, to, input): &(NodeKey, String, NodeKey, String)| {
+            SchemaConnection {
+                from: schema.node(from),
+                output: output.clone(),
+                to: schema.node(to),
+                input: input.clone(),
+            }
+        };
+        diff.connections_added = after
+            .connections
+            .iter()
+            .filter(|c| !before.connections.contains(c))
+            .map(|c| connection(&after, c))
+            .collect();
+        diff.connections_removed = before
+            .connections
+            .iter()
+            .filter(|c| !after.connections.contains(c))
+            .map(|c| connection(&before, c))
+            .collect();
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes_added.is_empty()
+            && self.nodes_removed.is_empty()
+            && self.property_changes.is_empty()
+            && self.connections_added.is_empty()
+            && self.connections_removed.is_empty()
+    }
+}
+
+impl fmt::Display for SchemaDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "schemas are identical");
+        }
+        let value = |v: &Option<Value>| v.as_ref().map_or_else(|| "(none)".to_string(), Value::to_string);
+        for node in &self.nodes_added {
+            writeln!(f, "+ node {node}")?;
+        }
+        for node in &self.nodes_removed {
+            writeln!(f, "- node {node}")?;
+        }
+        for change in &self.property_changes {
+            let owner = change.node.as_ref().map_or_else(|| "globals".to_string(), |n| n.to_string());
+            writeln!(f, "~ {owner}: {} {} -> {}", change.path, value(&change.before), value(&change.after))?;
+        }
+        for connection in &self.connections_added {
+            writeln!(f, "+ link {connection}")?;
+        }
+        for connection in &self.connections_removed {
+            writeln!(f, "- link {connection}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Pipeline {
+    /// Differences from this pipeline's schema to `other`'s, see [`SchemaDiff`].
+    pub fn schema_diff(&self, other: &Pipeline) -> Result<SchemaDiff> {
+        Ok(SchemaDiff::between(
+            &self.schema_json(SerializationType::Json)?,
+            &other.schema_json(SerializationType::Json)?,
+        ))
+    }
+}
@@ -0,0 +1,91 @@
+//! Graphviz (DOT) rendering of the pipeline graph.
+
+use std::fmt::Write as _;
+use std::path::Path;
+
+use crate::error::{DepthaiError, Result};
+
+use super::{Pipeline, PipelineConnectionInfo, PipelineNodeInfo};
+
+const DEVICE_NODE_COLOR: &str = "#cfe2ff";
+const HOST_NODE_COLOR: &str = "#ffe5cc";
+
+impl Pipeline {
+    /// Render the pipeline graph as a Graphviz digraph.
+    ///
+    /// Nodes are labelled with their type, id and alias, and colored by placement
+    /// (device nodes in blue, host nodes in orange). Edges are labelled with the
+    /// `output -> input` port names.
+    ///
+    /// Render with e.g. `dot -Tsvg pipeline.dot -o pipeline.svg`.
+    pub fn to_dot(&self) -> Result<String> {
+        let nodes = self.all_nodes()?;
+        let connections = self.connections()?;
+        Ok(render_dot(&nodes, &connections))
+    }
+
+    /// Write [`Pipeline::to_dot`] to `path`.
+    pub fn write_dot(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let dot = self.to_dot()?;
+        std::fs::write(path, dot)
+            .map_err(|e| DepthaiError::new(format!("failed to write DOT file '{}': {e}", path.display())))
+    }
+}
+
+pub(crate) fn render_dot(nodes: &[PipelineNodeInfo], connections: &[PipelineConnectionInfo]) -> String {
+    let mut out = String::new();
+    out.push_str("digraph pipeline {\n");
+    out.push_str("    rankdir=LR;\n");
+    out.push_str("    node [shape=box, style=\"rounded,filled\", fontname=\"Helvetica\"];\n");
+    out.push_str("    edge [fontname=\"Helvetica\", fontsize=10];\n");
+
+    for node in nodes {
+        let mut label = format!("{} #{}", node.name, node.id);
+        if !node.alias.is_empty() {
+            label.push('\n');
+            label.push_str(&node.alias);
+        }
+        let color = if node.run_on_host { HOST_NODE_COLOR } else { DEVICE_NODE_COLOR };
+        let _ = writeln!(
+            out,
+            "    n{} [label=\"{}\", fillcolor=\"{}\"];",
+            node.id,
+            escape(&label),
+            color
+        );
+    }
+
+    for c in connections {
+        let label = format!(
+            "{} -> {}",
+            port_label(&c.output_group, &c.output_name),
+            port_label(&c.input_group, &c.input_name)
+        );
+        let _ = writeln!(out, "    n{} -> n{} [label=\"{}\"];", c.output_id, c.input_id, escape(&label));
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+pub(super) fn port_label(group: &str, name: &str) -> String {
+    if group.is_empty() {
+        name.to_string()
+    } else {
+        format!("{group}[{name}]")
+    }
+}
+
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for ch in s.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
@@ -0,0 +1,192 @@
+//! Pipeline-wide events delivered to subscribers on a background thread.
+//!
+//! See [`Pipeline::subscribe_events`](super::Pipeline::subscribe_events).
+
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Mutex, Weak};
+use std::time::Duration;
+
+use crate::device::{Device, FirmwareLogMessage, LogCallbackHandle};
+use crate::queue::QueueStats;
+
+use super::node_error::NodeError;
+use super::pools::PoolExhaustion;
+use super::PipelineInner;
+
+/// How often the device connection is checked for [`PipelineEvent::DeviceDisconnected`].
+const DEVICE_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Something that happened in or around a running pipeline.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PipelineEvent {
+    /// [`Pipeline::start`](super::Pipeline::start) succeeded.
+    Started,
+    /// [`Pipeline::stop`](super::Pipeline::stop) succeeded.
+    Stopped,
+    /// [`Pipeline::pause`](super::Pipeline::pause) stopped the cameras.
+    Paused,
+    /// [`Pipeline::resume`](super::Pipeline::resume) restarted the cameras.
+    Resumed,
+    /// A Rust host node's `on_start` ran.
+    NodeStarted { node_id: i32 },
+    /// A Rust host node's `on_stop` ran.
+    NodeStopped { node_id: i32 },
+    /// A Rust host node failed, as also recorded by
+    /// [`Pipeline::take_node_errors`](super::Pipeline::take_node_errors).
+    NodeError(NodeError),
+    /// A non-blocking [`MessageQueue`](crate::queue::MessageQueue) created with
+    /// [`Output::create_message_queue`](crate::output::Output::create_message_queue) discarded
+    /// messages because it was full. Only queues created while the pipeline had subscribers
+    /// report this.
+    QueueOverflow { queue: String, stats: QueueStats },
+    /// The pipeline's device stopped responding. Sent once.
+    DeviceDisconnected,
+    /// A log line from the device firmware.
+    FirmwareLog(FirmwareLogMessage),
+    /// A node on the device couldn't allocate one of its frame pools; sent after the
+    /// [`FirmwareLog`](Self::FirmwareLog) it was recognized from. See
+    /// [`Pipeline::frame_pools`](super::Pipeline::frame_pools).
+    FramePoolExhausted(PoolExhaustion),
+}
+
+type Subscriber = Arc<Mutex<Box<dyn FnMut(&PipelineEvent) + Send>>>;
+
+#[derive(Default)]
+struct BusState {
+    next_id: u64,
+    subscribers: Arc<Mutex<Vec<(u64, Subscriber)>>>,
+    sender: Option<Sender<PipelineEvent>>,
+    log_callback: Option<LogCallbackHandle>,
+    watching_device: bool,
+}
+
+/// Subscriber list and dispatcher thread of one pipeline.
+#[derive(Default)]
+pub(crate) struct EventBus {
+    state: Mutex<BusState>,
+}
+
+impl EventBus {
+    pub(crate) fn has_subscribers(&self) -> bool {
+        let Ok(state) = self.state.lock() else {
+            return false;
+        };
+        state.subscribers.lock().is_ok_and(|s| !s.is_empty())
+    }
+
+    /// Queue `event` for the subscribers; a no-op while there are none.
+    pub(crate) fn emit(&self, event: PipelineEvent) {
+        let Ok(state) = self.state.lock() else {
+            return;
+        };
+        if !state.subscribers.lock().is_ok_and(|s| !s.is_empty()) {
+            return;
+        }
+        if let Some(sender) = &state.sender {
+            let _ = sender.send(event);
+        }
+    }
+
+    fn subscribe(&self, callback: Box<dyn FnMut(&PipelineEvent) + Send>) -> (u64, Sender<PipelineEvent>) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.next_id += 1;
+        let id = state.next_id;
+        state
+            .subscribers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push((id, Arc::new(Mutex::new(callback))));
+        let sender = match &state.sender {
+            Some(sender) => sender.clone(),
+            None => {
+                let (sender, receiver) = mpsc::channel();
+                let subscribers = Arc::clone(&state.subscribers);
+                // Exits once the bus, its log callback and the device watcher are all gone.
+                std::thread::spawn(move || dispatch(receiver, subscribers));
+                state.sender = Some(sender.clone());
+                sender
+            }
+        };
+        (id, sender)
+    }
+
+    fn unsubscribe(&self, id: u64) {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let mut subscribers = state.subscribers.lock().unwrap_or_else(|e| e.into_inner());
+        subscribers.retain(|(sub_id, _)| *sub_id != id);
+    }
+
+    /// Forward firmware logs and watch the connection of `device`, once per pipeline.
+    fn attach_device(&self, device: Device, sender: &Sender<PipelineEvent>, pipeline: Weak<PipelineInner>) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if state.log_callback.is_none() {
+            let sender = sender.clone();
+            state.log_callback = device
+                .add_log_callback(move |msg| {
+                    let _ = sender.send(PipelineEvent::FirmwareLog(msg.clone()));
+                    if let Some(exhaustion) = PoolExhaustion::from_log(msg) {
+                        let _ = sender.send(PipelineEvent::FramePoolExhausted(exhaustion));
+                    }
+                })
+                .ok();
+        }
+        if !state.watching_device {
+            state.watching_device = true;
+            let sender = sender.clone();
+            std::thread::spawn(move || {
+                while pipeline.strong_count() > 0 {
+                    if !device.is_connected() {
+                        let _ = sender.send(PipelineEvent::DeviceDisconnected);
+                        return;
+                    }
+                    std::thread::sleep(DEVICE_POLL_INTERVAL);
+                }
+            });
+        }
+    }
+}
+
+fn dispatch(receiver: mpsc::Receiver<PipelineEvent>, subscribers: Arc<Mutex<Vec<(u64, Subscriber)>>>) {
+    for event in receiver {
+        let current: Vec<Subscriber> = subscribers
+            .lock()
+            .map(|s| s.iter().map(|(_, cb)| Arc::clone(cb)).collect())
+            .unwrap_or_default();
+        for subscriber in current {
+            let mut callback = subscriber.lock().unwrap_or_else(|e| e.into_inner());
+            let _ = catch_unwind(AssertUnwindSafe(|| callback(&event)));
+        }
+    }
+}
+
+/// Keeps a [`Pipeline::subscribe_events`](super::Pipeline::subscribe_events) callback
+/// registered; dropping it unsubscribes.
+pub struct EventSubscription {
+    bus: Weak<EventBus>,
+    id: u64,
+}
+
+impl Drop for EventSubscription {
+    fn drop(&mut self) {
+        if let Some(bus) = self.bus.upgrade() {
+            bus.unsubscribe(self.id);
+        }
+    }
+}
+
+pub(crate) fn subscribe(
+    bus: &Arc<EventBus>,
+    device: Option<Device>,
+    pipeline: Weak<PipelineInner>,
+    callback: Box<dyn FnMut(&PipelineEvent) + Send>,
+) -> EventSubscription {
+    let (id, sender) = bus.subscribe(callback);
+    if let Some(device) = device {
+        bus.attach_device(device, &sender, pipeline);
+    }
+    EventSubscription {
+        bus: Arc::downgrade(bus),
+        id,
+    }
+}
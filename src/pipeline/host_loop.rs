@@ -0,0 +1,265 @@
+//! The main loop of a host-driven application.
+//!
+//! See [`Pipeline::run_host_loop`].
+
+use std::time::{Duration, Instant};
+
+#[cfg(not(feature = "signals"))]
+use crate::error::DepthaiError;
+use crate::error::Result;
+#[cfg(feature = "signals")]
+use crate::runtime;
+use crate::runtime::ShutdownHandle;
+
+use super::Pipeline;
+
+/// What the callback of [`Pipeline::run_host_loop`] wants next.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LoopControl {
+    #[default]
+    Continue,
+    Break,
+}
+
+/// Settings of [`Pipeline::run_host_loop`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HostLoopOptions {
+    /// Calls per second; `None` calls the callback back to back.
+    pub rate_hz: Option<f32>,
+    /// Start the pipeline if it isn't running yet.
+    pub start: bool,
+    /// Stop the pipeline when the loop ends.
+    pub stop_on_exit: bool,
+    /// End the loop on Ctrl-C/SIGTERM, through `runtime::install_shutdown_handler` (needs the
+    /// `signals` feature). The loop has its own registration, left when it returns.
+    pub handle_ctrl_c: bool,
+    /// End the loop after this long.
+    pub max_duration: Option<Duration>,
+}
+
+impl Default for HostLoopOptions {
+    /// Unpaced, starting and stopping the pipeline. Signals are left to the application; set
+    /// [`handle_ctrl_c`](Self::handle_ctrl_c) to end on Ctrl-C.
+    fn default() -> Self {
+        Self {
+            rate_hz: None,
+            start: true,
+            stop_on_exit: true,
+            handle_ctrl_c: false,
+            max_duration: None,
+        }
+    }
+}
+
+impl HostLoopOptions {
+    /// Default options, paced at `rate_hz`.
+    pub fn with_rate(rate_hz: f32) -> Self {
+        Self {
+            rate_hz: Some(rate_hz),
+            ..Self::default()
+        }
+    }
+}
+
+/// Passed to every call of the [`Pipeline::run_host_loop`] callback.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoopTick {
+    /// 0 for the first call.
+    pub iteration: u64,
+    /// Since the loop started.
+    pub elapsed: Duration,
+    /// Since the previous call started, zero for the first.
+    pub delta: Duration,
+}
+
+/// Why [`Pipeline::run_host_loop`] returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HostLoopExit {
+    /// The callback returned [`LoopControl::Break`].
+    Break,
+    /// The pipeline stopped running (a node failed, the device disconnected, ...).
+    PipelineStopped,
+    /// Ctrl-C, SIGTERM or [`ShutdownHandle::shutdown`].
+    Interrupted,
+    /// [`HostLoopOptions::max_duration`] elapsed.
+    Timeout,
+}
+
+/// What a [`Pipeline::run_host_loop`] did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HostLoopStats {
+    pub exit: HostLoopExit,
+    /// Callback calls.
+    pub iterations: u64,
+    pub elapsed: Duration,
+    /// Time spent in the callback.
+    pub busy: Duration,
+    /// Longest callback call.
+    pub max_callback: Duration,
+    /// Calls that ended after the next one was due, when paced.
+    pub overruns: u64,
+}
+
+impl HostLoopStats {
+    /// Achieved calls per second.
+    pub fn rate_hz(&self) -> f64 {
+        let secs = self.elapsed.as_secs_f64();
+        if secs > 0.0 { self.iterations as f64 / secs } else { 0.0 }
+    }
+
+    /// Mean time spent in the callback.
+    pub fn mean_callback(&self) -> Duration {
+        if self.iterations == 0 {
+            Duration::ZERO
+        } else {
+            self.busy.div_f64(self.iterations as f64)
+        }
+    }
+}
+
+impl Pipeline {
+    /// Run `callback` until it breaks, the pipeline stops, Ctrl-C arrives or
+    /// [`max_duration`](HostLoopOptions::max_duration) elapses, processing the pipeline's host
+    /// tasks in between.
+    ///
+    /// With a [`rate_hz`](HostLoopOptions::rate_hz) the callback is called at that rate; the
+    /// time left in each period is spent in [`process_tasks`](Self::process_tasks). A call that
+    /// overruns its period counts in [`HostLoopStats::overruns`] and the schedule restarts from
+    /// it rather than catching up. An error from the callback ends the loop and is returned, in
+    /// preference to one from stopping the pipeline.
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use depthai::prelude::*;
+    /// # use depthai::pipeline::{HostLoopOptions, LoopControl};
+    /// # fn main() -> Result<()> {
+    /// let pipeline = Pipeline::new().build()?;
+    /// # let camera = pipeline.create_with::<CameraNode, _>(CameraBoardSocket::CamA)?;
+    /// let queue = camera.request_output(CameraOutputConfig::new((640, 400)))?.create_message_queue(4, false)?;
+    /// let options = HostLoopOptions { handle_ctrl_c: true, ..HostLoopOptions::with_rate(30.0) };
+    /// let stats = pipeline.run_host_loop(options, |tick| {
+    ///     while let Some(msg) = queue.try_get()? {
+    ///         // ...
+    /// #       let _ = msg;
+    ///     }
+    ///     Ok(if tick.elapsed > Duration::from_secs(10) { LoopControl::Break } else { LoopControl::Continue })
+    /// })?;
+    /// println!("{:.1} Hz, {} overruns", stats.rate_hz(), stats.overruns);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn run_host_loop<F>(&self, options: HostLoopOptions, mut callback: F) -> Result<HostLoopStats>
+    where
+        F: FnMut(LoopTick) -> Result<LoopControl>,
+    {
+        if options.start && !self.is_running()? {
+            self.start()?;
+        }
+        let shutdown: Option<ShutdownHandle> = if options.handle_ctrl_c {
+            Some(install_shutdown_handler(self)?)
+        } else {
+            None
+        };
+        let period = options
+            .rate_hz
+            .filter(|hz| *hz > 0.0)
+            .map(|hz| Duration::from_secs_f64(1.0 / f64::from(hz)));
+
+        let started = Instant::now();
+        let mut stats = HostLoopStats {
+            exit: HostLoopExit::Break,
+            iterations: 0,
+            elapsed: Duration::ZERO,
+            busy: Duration::ZERO,
+            max_callback: Duration::ZERO,
+            overruns: 0,
+        };
+        let mut previous: Option<Instant> = None;
+        let mut due = started;
+        let result = 'main: loop {
+            if shutdown.as_ref().is_some_and(ShutdownHandle::is_shutting_down) {
+                stats.exit = HostLoopExit::Interrupted;
+                break Ok(());
+            }
+            if options.max_duration.is_some_and(|max| started.elapsed() >= max) {
+                stats.exit = HostLoopExit::Timeout;
+                break Ok(());
+            }
+            match self.is_running() {
+                Ok(true) => {}
+                Ok(false) => {
+                    stats.exit = HostLoopExit::PipelineStopped;
+                    break Ok(());
+                }
+                Err(e) => break Err(e),
+            }
+            if let Err(e) = self.process_tasks(false, 0.0) {
+                break Err(e);
+            }
+
+            let call_start = Instant::now();
+            let tick = LoopTick {
+                iteration: stats.iterations,
+                elapsed: call_start - started,
+                delta: previous.map_or(Duration::ZERO, |p| call_start - p),
+            };
+            previous = Some(call_start);
+            let control = callback(tick);
+            let took = call_start.elapsed();
+            stats.iterations += 1;
+            stats.busy += took;
+            stats.max_callback = stats.max_callback.max(took);
+            match control {
+                Ok(LoopControl::Continue) => {}
+                Ok(LoopControl::Break) => {
+                    stats.exit = HostLoopExit::Break;
+                    break Ok(());
+                }
+                Err(e) => break Err(e),
+            }
+
+            if let Some(period) = period {
+                due += period;
+                let now = Instant::now();
+                if now >= due {
+                    stats.overruns += 1;
+                    due = now;
+                    continue;
+                }
+                // Serve host tasks until the next call is due.
+                while let Some(left) = due.checked_duration_since(Instant::now()).filter(|d| !d.is_zero()) {
+                    if let Err(e) = self.process_tasks(true, left.as_secs_f64()) {
+                        break 'main Err(e);
+                    }
+                    // `process_tasks` returns early once a task ran; don't spin on an idle queue.
+                    if Instant::now() < due {
+                        std::thread::sleep((due - Instant::now()).min(Duration::from_millis(1)));
+                    }
+                }
+            }
+        };
+        stats.elapsed = started.elapsed();
+        if let Some(shutdown) = &shutdown {
+            shutdown.unregister();
+        }
+        let stopped = if options.stop_on_exit && self.is_running().unwrap_or(false) {
+            self.stop()
+        } else {
+            Ok(())
+        };
+        result?;
+        stopped.map(|()| stats)
+    }
+}
+
+#[cfg(feature = "signals")]
+fn install_shutdown_handler(pipeline: &Pipeline) -> Result<ShutdownHandle> {
+    runtime::install_shutdown_handler(pipeline.clone())
+}
+
+#[cfg(not(feature = "signals"))]
+fn install_shutdown_handler(_pipeline: &Pipeline) -> Result<ShutdownHandle> {
+    Err(DepthaiError::new(
+        "HostLoopOptions::handle_ctrl_c needs the `signals` feature",
+    ))
+}
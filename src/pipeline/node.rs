@@ -1,12 +1,93 @@
 use std::ffi::CString;
 use std::sync::Arc;
 
+use autocxx::c_int;
 use depthai_sys::{depthai, DaiNode};
 
-use crate::error::{clear_error_flag, last_error, take_error_if_any, Result};
+use crate::device::LogLevel;
+use crate::error::{clear_error_flag, last_error, take_error_if_any, DepthaiError, Result};
+use crate::output::{known_datatypes, DatatypeHierarchy, RawDatatypeHierarchy};
 
 use super::PipelineInner;
 
+/// Description of a node input port, as returned by [`Node::inputs_info`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct NodeInputInfo {
+    pub name: String,
+    pub group: String,
+    pub blocking: bool,
+    #[serde(rename = "queueSize")]
+    pub queue_size: u32,
+    /// Whether the node waits for a message on this input before processing.
+    #[serde(rename = "waitForMessage")]
+    pub wait_for_message: bool,
+}
+
+/// A port of a node, as listed by [`Node::io_info`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodePortInfo {
+    pub name: String,
+    pub group: String,
+    /// Port map holding the port (e.g. `Sync`'s `"inputs"`), reached with
+    /// [`Node::input_in_map`] / [`Node::output_in_map`] instead of by name.
+    pub map: Option<String>,
+    /// Message types the port carries; types unknown to this crate are left out.
+    pub datatypes: Vec<DatatypeHierarchy>,
+}
+
+/// Ports declared by a node, see [`Node::io_info`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NodeIoInfo {
+    pub inputs: Vec<NodePortInfo>,
+    pub outputs: Vec<NodePortInfo>,
+    /// Names of the node's input maps, which create an input for any key on first use.
+    pub input_maps: Vec<String>,
+    /// Names of the node's output maps, which create an output for any key on first use.
+    pub output_maps: Vec<String>,
+}
+
+impl NodeIoInfo {
+    /// The input named `name` outside port maps.
+    pub fn input(&self, name: &str) -> Option<&NodePortInfo> {
+        self.inputs.iter().find(|p| p.map.is_none() && p.name == name)
+    }
+
+    /// The output named `name` outside port maps.
+    pub fn output(&self, name: &str) -> Option<&NodePortInfo> {
+        self.outputs.iter().find(|p| p.map.is_none() && p.name == name)
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct RawPortInfo {
+    name: String,
+    group: String,
+    map: Option<String>,
+    #[serde(rename = "possibleDatatypes")]
+    possible_datatypes: Vec<RawDatatypeHierarchy>,
+}
+
+impl From<RawPortInfo> for NodePortInfo {
+    fn from(raw: RawPortInfo) -> Self {
+        Self {
+            name: raw.name,
+            group: raw.group,
+            map: raw.map,
+            datatypes: known_datatypes(raw.possible_datatypes),
+        }
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct RawIoInfo {
+    inputs: Vec<RawPortInfo>,
+    outputs: Vec<RawPortInfo>,
+    #[serde(rename = "inputMaps")]
+    input_maps: Vec<String>,
+    #[serde(rename = "outputMaps")]
+    output_maps: Vec<String>,
+}
+
 #[derive(Clone)]
 pub struct Node {
     pub(crate) pipeline: Arc<PipelineInner>,
@@ -64,6 +145,33 @@ impl Node {
         }
     }
 
+    /// Level of the messages this node logs, on the device for device nodes.
+    ///
+    /// Mirrors C++: `ThreadedNode::getLogLevel()`. Fails on nodes that don't run a thread.
+    pub fn log_level(&self) -> Result<LogLevel> {
+        clear_error_flag();
+        let level = unsafe { depthai::dai_node_get_log_level(self.handle) };
+        if let Some(e) = take_error_if_any("failed to get node log level") {
+            Err(e)
+        } else {
+            Ok(LogLevel::from_raw(i32::from(level)))
+        }
+    }
+
+    /// Only log messages of `level` and above from this node, e.g. [`LogLevel::Debug`] to trace a
+    /// single node without raising the device-wide level.
+    ///
+    /// Mirrors C++: `ThreadedNode::setLogLevel()`.
+    pub fn set_log_level(&self, level: LogLevel) -> Result<()> {
+        clear_error_flag();
+        let ok = unsafe { depthai::dai_node_set_log_level(self.handle, c_int(level as i32)) };
+        if ok {
+            Ok(())
+        } else {
+            Err(last_error("failed to set node log level"))
+        }
+    }
+
     /// Returns the node type name (C++ node class name constant in DepthAI).
     pub fn name(&self) -> Result<String> {
         clear_error_flag();
@@ -71,6 +179,67 @@ impl Node {
         Self::take_owned_string(ptr, "failed to get node name")
     }
 
+    /// Describe every input port of this node.
+    pub fn inputs_info(&self) -> Result<Vec<NodeInputInfo>> {
+        clear_error_flag();
+        let ptr = unsafe { depthai::dai_node_get_inputs_json(self.handle) };
+        let s = Self::take_owned_string(ptr, "failed to get node inputs")?;
+        serde_json::from_str(&s)
+            .map_err(|e| DepthaiError::new(format!("invalid node inputs JSON from depthai-core: {e}")))
+    }
+
+    /// List the ports the node declares, with their message types, so generic code (e.g. on
+    /// nodes from [`Pipeline::create_node`](crate::Pipeline::create_node)) can find
+    /// valid port names at runtime.
+    ///
+    /// Port maps only list the keys used so far; their names are in
+    /// [`NodeIoInfo::input_maps`] / [`NodeIoInfo::output_maps`].
+    pub fn io_info(&self) -> Result<NodeIoInfo> {
+        clear_error_flag();
+        let ptr = unsafe { depthai::dai_node_get_io_json(self.handle) };
+        let s = Self::take_owned_string(ptr, "failed to get node ports")?;
+        let raw: RawIoInfo = serde_json::from_str(&s)
+            .map_err(|e| DepthaiError::new(format!("invalid node ports JSON from depthai-core: {e}")))?;
+        Ok(NodeIoInfo {
+            inputs: raw.inputs.into_iter().map(NodePortInfo::from).collect(),
+            outputs: raw.outputs.into_iter().map(NodePortInfo::from).collect(),
+            input_maps: raw.input_maps,
+            output_maps: raw.output_maps,
+        })
+    }
+
+    /// Properties of a device node, in the JSON form DepthAI-Core serializes them in.
+    ///
+    /// Fails for nodes without properties, such as host nodes.
+    pub fn properties_json(&self) -> Result<serde_json::Value> {
+        clear_error_flag();
+        let ptr = unsafe { depthai::dai_node_get_properties_json(self.handle) };
+        let s = Self::take_owned_string(ptr, "failed to get node properties")?;
+        super::parse_json_value(&s)
+    }
+
+    /// Set the existing property at `path` in [`properties_json`](Self::properties_json), with
+    /// `.` between nested keys (e.g. `"initialConfig.fps"`), to `value`.
+    ///
+    /// Fails if the property doesn't exist or `value` doesn't fit its type. This is how
+    /// `props(...)` setters of [`native_node_wrapper`](crate::native_node_wrapper) set properties.
+    pub fn set_property(&self, path: &str, value: impl serde::Serialize) -> Result<()> {
+        let pointer: String = path
+            .split('.')
+            .map(|key| format!("/{}", key.replace('~', "~0").replace('/', "~1")))
+            .collect();
+        let value = serde_json::to_string(&value)
+            .map_err(|e| DepthaiError::new(format!("failed to serialize property '{path}': {e}")))?;
+        let pointer = CString::new(pointer).map_err(|_| DepthaiError::new("invalid property path"))?;
+        let value = CString::new(value).map_err(|_| DepthaiError::new("invalid property value"))?;
+        clear_error_flag();
+        if unsafe { depthai::dai_node_set_property_json(self.handle, pointer.as_ptr(), value.as_ptr()) } {
+            Ok(())
+        } else {
+            Err(last_error(&format!("failed to set node property '{path}'")))
+        }
+    }
+
     pub fn link(
         &self,
         out_group: Option<&str>,
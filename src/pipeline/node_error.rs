@@ -0,0 +1,194 @@
+//! Failures of Rust host nodes, collected on the owning pipeline.
+//!
+//! Host node callbacks run on DepthAI threads, where a panic or error has nowhere to go. They
+//! are caught and recorded as [`NodeError`]s, retrieved with [`Pipeline::take_node_errors`],
+//! and can stop the pipeline according to its [`NodeErrorPolicy`].
+//!
+//! [`Pipeline::take_node_errors`]: super::Pipeline::take_node_errors
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicI32, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+
+use depthai_sys::depthai;
+
+use super::{PipelineEvent, PipelineInner};
+
+/// Errors kept per pipeline; older ones are dropped first.
+const MAX_KEPT_ERRORS: usize = 256;
+
+/// Which host node callback failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NodePhase {
+    Start,
+    /// `HostNodeImpl::process_group`.
+    Process,
+    /// `ThreadedHostNodeImpl::run`.
+    Run,
+    Stop,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NodeFailure {
+    /// The callback panicked; holds the panic message.
+    Panic(String),
+    /// The callback returned or reported an error.
+    Error(String),
+}
+
+/// A host node failure recorded on the pipeline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeError {
+    /// Id of the failing node, as in [`Node::id`](super::Node::id).
+    pub node_id: i32,
+    pub phase: NodePhase,
+    pub failure: NodeFailure,
+    /// Failures of this node in a row, including this one.
+    pub consecutive: u32,
+}
+
+impl NodeError {
+    pub fn is_panic(&self) -> bool {
+        matches!(self.failure, NodeFailure::Panic(_))
+    }
+}
+
+impl fmt::Display for NodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (what, message) = match &self.failure {
+            NodeFailure::Panic(m) => ("panicked", m),
+            NodeFailure::Error(m) => ("failed", m),
+        };
+        write!(f, "host node {} {what} in {:?}: {message}", self.node_id, self.phase)?;
+        if self.consecutive > 1 {
+            write!(f, " ({} in a row)", self.consecutive)?;
+        }
+        Ok(())
+    }
+}
+
+/// When a host node failure stops the pipeline. The default only records failures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NodeErrorPolicy {
+    /// Stop on the first panic.
+    pub stop_on_panic: bool,
+    /// Stop once one node fails this many times in a row.
+    pub max_consecutive_errors: Option<u32>,
+}
+
+impl NodeErrorPolicy {
+    fn should_stop(&self, error: &NodeError) -> bool {
+        (self.stop_on_panic && error.is_panic())
+            || self.max_consecutive_errors.is_some_and(|max| error.consecutive >= max)
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct NodeErrorLog {
+    errors: Mutex<VecDeque<NodeError>>,
+    policy: Mutex<NodeErrorPolicy>,
+    stop_requested: AtomicBool,
+}
+
+impl NodeErrorLog {
+    pub(crate) fn take(&self) -> Vec<NodeError> {
+        self.errors.lock().map(|mut e| e.drain(..).collect()).unwrap_or_default()
+    }
+
+    pub(crate) fn policy(&self) -> NodeErrorPolicy {
+        self.policy.lock().map(|p| *p).unwrap_or_default()
+    }
+
+    pub(crate) fn set_policy(&self, policy: NodeErrorPolicy) {
+        if let Ok(mut p) = self.policy.lock() {
+            *p = policy;
+        }
+    }
+
+    /// Record `error`; returns whether the pipeline should now be stopped.
+    fn push(&self, error: NodeError) -> bool {
+        let stop = self.policy().should_stop(&error);
+        if let Ok(mut errors) = self.errors.lock() {
+            if errors.len() == MAX_KEPT_ERRORS {
+                errors.pop_front();
+            }
+            errors.push_back(error);
+        }
+        stop && !self.stop_requested.swap(true, Ordering::SeqCst)
+    }
+}
+
+/// Per-node handle used by host node callbacks to record failures and lifecycle events.
+pub(crate) struct NodeFailureReporter {
+    pipeline: Weak<PipelineInner>,
+    node_id: AtomicI32,
+    consecutive: AtomicU32,
+}
+
+impl NodeFailureReporter {
+    pub(crate) fn new(pipeline: &Arc<PipelineInner>) -> Self {
+        Self {
+            pipeline: Arc::downgrade(pipeline),
+            node_id: AtomicI32::new(-1),
+            consecutive: AtomicU32::new(0),
+        }
+    }
+
+    pub(crate) fn set_node_id(&self, id: i32) {
+        self.node_id.store(id, Ordering::Relaxed);
+    }
+
+    pub(crate) fn success(&self) {
+        self.consecutive.store(0, Ordering::Relaxed);
+    }
+
+    pub(crate) fn started(&self) {
+        self.notify(|node_id| PipelineEvent::NodeStarted { node_id });
+    }
+
+    pub(crate) fn stopped(&self) {
+        self.notify(|node_id| PipelineEvent::NodeStopped { node_id });
+    }
+
+    fn notify(&self, event: impl FnOnce(i32) -> PipelineEvent) {
+        if let Some(pipeline) = self.pipeline.upgrade() {
+            pipeline.emit_event(event(self.node_id.load(Ordering::Relaxed)));
+        }
+    }
+
+    pub(crate) fn report(&self, phase: NodePhase, failure: NodeFailure) {
+        let consecutive = self.consecutive.fetch_add(1, Ordering::Relaxed).saturating_add(1);
+        let Some(pipeline) = self.pipeline.upgrade() else {
+            return;
+        };
+        let error = NodeError {
+            node_id: self.node_id.load(Ordering::Relaxed),
+            phase,
+            failure,
+            consecutive,
+        };
+        pipeline.emit_event(PipelineEvent::NodeError(error.clone()));
+        if pipeline.node_errors.push(error) {
+            // Stopping joins the node threads, so it can't run on the failing node's thread.
+            std::thread::spawn(move || unsafe {
+                depthai::dai_pipeline_stop(pipeline.handle);
+            });
+        }
+    }
+
+    pub(crate) fn report_panic(&self, phase: NodePhase, payload: Box<dyn Any + Send>) {
+        self.report(phase, NodeFailure::Panic(panic_message(payload.as_ref())));
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        (*s).to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "non-string panic payload".to_string()
+    }
+}
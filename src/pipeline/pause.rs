@@ -0,0 +1,96 @@
+//! Pausing the cameras of a running pipeline.
+//!
+//! Tearing a pipeline down and starting it again takes seconds. [`Pipeline::pause`] instead
+//! stops every `Camera` node's sensor through its `inputControl` and [`Pipeline::resume`]
+//! restarts it, while the device connection and the rest of the pipeline stay up. Downstream
+//! nodes simply receive no frames in between.
+//!
+//! ```no_run
+//! # use depthai::prelude::*;
+//! # fn main() -> Result<()> {
+//! let pipeline = Pipeline::new().build()?;
+//! let camera = pipeline.create_with::<CameraNode, _>(CameraBoardSocket::CamA)?;
+//! # let _ = camera;
+//! // Control queues must be created before the pipeline starts.
+//! pipeline.enable_pause()?;
+//! pipeline.start()?;
+//! // Nobody is watching: save power and USB bandwidth.
+//! pipeline.pause()?;
+//! pipeline.resume()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use crate::camera_control::CameraControl;
+use crate::error::{DepthaiError, Result};
+use crate::queue::InputQueue;
+
+use super::{Pipeline, PipelineEvent};
+
+/// Control queues to the pipeline's cameras, see [`Pipeline::enable_pause`].
+#[derive(Default)]
+pub(crate) struct PauseControl {
+    queues: Mutex<Option<Vec<InputQueue>>>,
+    paused: AtomicBool,
+}
+
+impl Pipeline {
+    /// Create a control queue to every `Camera` node so the pipeline can be
+    /// [paused](Self::pause).
+    ///
+    /// Call after creating the cameras and before [`start`](Self::start); cameras created later
+    /// are not paused.
+    pub fn enable_pause(&self) -> Result<()> {
+        let mut queues = Vec::new();
+        for info in self.all_nodes()?.into_iter().filter(|n| n.name == "Camera") {
+            if let Some(node) = self.node_by_id(info.id)? {
+                queues.push(node.input("inputControl")?.create_input_queue(1, false)?);
+            }
+        }
+        *self.pause_control().queues.lock().unwrap_or_else(|e| e.into_inner()) = Some(queues);
+        Ok(())
+    }
+
+    /// Stop streaming on every camera, keeping the device and pipeline alive.
+    ///
+    /// Requires [`enable_pause`](Self::enable_pause). Does nothing if already paused.
+    pub fn pause(&self) -> Result<()> {
+        self.set_streaming(false)
+    }
+
+    /// Restart streaming after [`pause`](Self::pause). Does nothing if not paused.
+    pub fn resume(&self) -> Result<()> {
+        self.set_streaming(true)
+    }
+
+    /// Whether the cameras were paused with [`pause`](Self::pause).
+    pub fn is_paused(&self) -> bool {
+        self.pause_control().paused.load(Ordering::SeqCst)
+    }
+
+    fn set_streaming(&self, streaming: bool) -> Result<()> {
+        let control = self.pause_control();
+        let queues = control.queues.lock().unwrap_or_else(|e| e.into_inner());
+        let queues = queues
+            .as_ref()
+            .ok_or_else(|| DepthaiError::new("call Pipeline::enable_pause before starting the pipeline"))?;
+        if control.paused.load(Ordering::SeqCst) != streaming {
+            return Ok(());
+        }
+        let mut message = CameraControl::new()?;
+        message.set_streaming(streaming).check()?;
+        for queue in queues {
+            queue.send_buffer(message.as_buffer())?;
+        }
+        control.paused.store(!streaming, Ordering::SeqCst);
+        self.inner.emit_event(if streaming { PipelineEvent::Resumed } else { PipelineEvent::Paused });
+        Ok(())
+    }
+
+    fn pause_control(&self) -> &PauseControl {
+        &self.inner.pause
+    }
+}
@@ -0,0 +1,219 @@
+//! Device frame pools and their exhaustion.
+//!
+//! Every node that produces frames on the device allocates a fixed pool of them when the
+//! pipeline starts (`numFramesPool*` properties, e.g.
+//! [`CameraNode::set_num_frames_pools`](crate::camera::CameraNode::set_num_frames_pools)). A
+//! frame stays taken until every consumer, including the XLink stream to the host, is done with
+//! it; when a pool runs dry the node waits, and on the host its frames simply stop arriving.
+//!
+//! [`Pipeline::frame_pools`] lists the pools the pipeline asks for. DepthAI doesn't report how
+//! many frames of a pool are in use while running, but the firmware logs a pool that doesn't fit
+//! in device memory when the pipeline starts, which the event bus turns into
+//! [`PipelineEvent::FramePoolExhausted`](super::PipelineEvent::FramePoolExhausted).
+
+use std::fmt;
+use std::time::Duration;
+
+use serde_json::Value;
+
+use crate::device::{FirmwareLogMessage, LogLevel};
+use crate::error::Result;
+
+use super::resources::{node_properties, schema_nodes};
+use super::Pipeline;
+
+/// One frame pool of a node, as configured.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FramePool {
+    pub node_id: i32,
+    /// DepthAI node type name (e.g. `"Camera"`).
+    pub node_name: String,
+    /// Which of the node's pools, from the property name: `"raw"`, `"isp"`, `"outputs"`, ... and
+    /// `"output"` for nodes with a single pool.
+    pub pool: String,
+    /// Frames allocated at startup; `None` when the device picks the size.
+    pub frames: Option<u32>,
+    /// Largest frame the pool holds, when set.
+    pub max_frame_bytes: Option<u64>,
+}
+
+impl FramePool {
+    /// Memory taken by the pool, when both its size and frame size are known.
+    pub fn bytes(&self) -> Option<u64> {
+        Some(self.frames? as u64 * self.max_frame_bytes?)
+    }
+}
+
+/// Frame pools of a pipeline's nodes, see [`Pipeline::frame_pools`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FramePoolReport {
+    pub pools: Vec<FramePool>,
+}
+
+impl FramePoolReport {
+    /// Pools declared in the output of [`Pipeline::serialize_to_json`].
+    pub fn from_pipeline_json(json: &Value) -> Self {
+        let schema = json.get("pipeline").unwrap_or(json);
+        let mut pools = Vec::new();
+        for node in schema_nodes(schema) {
+            let (Some(name), Some(properties)) = (node.get("name").and_then(Value::as_str), node_properties(node))
+            else {
+                continue;
+            };
+            let id = node.get("id").and_then(Value::as_i64).unwrap_or(-1) as i32;
+            let Some(properties) = properties.as_object() else {
+                continue;
+            };
+            for (key, value) in properties {
+                let Some(suffix) = key.strip_prefix("numFramesPool") else {
+                    continue;
+                };
+                let pool = if suffix.is_empty() { "output".to_string() } else { suffix.to_lowercase() };
+                let max_size = properties.get(&format!("maxSizePool{suffix}")).or_else(|| {
+                    // Single-pool nodes call the frame size differently.
+                    suffix.is_empty().then(|| properties.get("outputFrameSize")).flatten()
+                });
+                pools.push(FramePool {
+                    node_id: id,
+                    node_name: name.to_string(),
+                    pool,
+                    frames: positive(value).map(|v| v as u32),
+                    max_frame_bytes: max_size.and_then(positive),
+                });
+            }
+        }
+        pools.sort_by(|a, b| (a.node_id, &a.pool).cmp(&(b.node_id, &b.pool)));
+        Self { pools }
+    }
+
+    /// Pools of node `node_id`.
+    pub fn node(&self, node_id: i32) -> impl Iterator<Item = &FramePool> {
+        self.pools.iter().filter(move |p| p.node_id == node_id)
+    }
+
+    /// Pools of fewer than `frames` frames, the usual suspects when a stream stalls.
+    pub fn smaller_than(&self, frames: u32) -> impl Iterator<Item = &FramePool> {
+        self.pools.iter().filter(move |p| p.frames.is_some_and(|f| f < frames))
+    }
+}
+
+impl fmt::Display for FramePoolReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:<24} {:<10} {:>6} {:>10}", "node", "pool", "frames", "KiB")?;
+        for pool in &self.pools {
+            write!(
+                f,
+                "\n{:<24} {:<10} {:>6} {:>10}",
+                format!("{} #{}", pool.node_name, pool.node_id),
+                pool.pool,
+                pool.frames.map_or_else(|| "auto".to_string(), |v| v.to_string()),
+                pool.bytes().map_or_else(|| "-".to_string(), |b| (b / 1024).to_string())
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn positive(value: &Value) -> Option<u64> {
+    value.as_i64().filter(|&v| v > 0).map(|v| v as u64)
+}
+
+/// Start of the firmware error recognized by [`PoolExhaustion::from_log`].
+const OUT_OF_MEMORY: &str = "Out of memory while creating pool for '";
+
+/// A node that couldn't allocate a frame pool, recognized from a firmware log line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoolExhaustion {
+    /// Node id, when the log line names the node.
+    pub node_id: Option<i32>,
+    pub node_name: Option<String>,
+    /// Pool as the firmware names it, e.g. `"isp"`, or `"frames"` for single-pool nodes.
+    pub pool: String,
+    /// Frames the pool asked for.
+    pub frames: Option<u32>,
+    /// Size of each frame.
+    pub frame_bytes: Option<u64>,
+    /// Device timestamp of the log line.
+    pub time: Duration,
+    /// The firmware message.
+    pub message: String,
+}
+
+impl PoolExhaustion {
+    /// Recognize the firmware error `Out of memory while creating pool for 'isp' frames. Number of
+    /// frames: 3 each with size: 2764800B` (`'frames'.` for nodes with a single pool).
+    pub fn from_log(msg: &FirmwareLogMessage) -> Option<Self> {
+        if !matches!(msg.level, LogLevel::Warn | LogLevel::Error | LogLevel::Critical) {
+            return None;
+        }
+        let rest = &msg.payload[msg.payload.find(OUT_OF_MEMORY)? + OUT_OF_MEMORY.len()..];
+        let pool = &rest[..rest.find('\'')?];
+        let (node_name, node_id) = parse_node(&msg.node_id_name)
+            .or_else(|| find_node(&msg.payload))
+            .map_or((None, None), |(name, id)| (Some(name), Some(id)));
+        Some(Self {
+            node_id,
+            node_name,
+            pool: pool.to_string(),
+            frames: number_after(rest, "Number of frames:"),
+            frame_bytes: number_after(rest, "each with size:"),
+            time: msg.time,
+            message: msg.payload.clone(),
+        })
+    }
+
+    /// What to change, naming the node when the log line did.
+    pub fn hint(&self) -> String {
+        let node = match (&self.node_name, self.node_id) {
+            (Some(name), Some(id)) => format!("{name} #{id}"),
+            _ => "the node".to_string(),
+        };
+        let size = match (self.frames, self.frame_bytes) {
+            (Some(frames), Some(bytes)) => format!(" ({frames} frames of {} KiB)", bytes / 1024),
+            _ => String::new(),
+        };
+        format!(
+            "the '{}' pool of {node}{size} doesn't fit in device memory: lower its frame count \
+             (set_num_frames_pool*) or its frame size",
+            self.pool
+        )
+    }
+}
+
+/// The number right after `label` in `text`, e.g. `3` in `Number of frames: 3 each`.
+fn number_after<T: std::str::FromStr>(text: &str, label: &str) -> Option<T> {
+    let rest = text[text.find(label)? + label.len()..].trim_start();
+    let end = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+    rest[..end].parse().ok()
+}
+
+/// The first `Name(id)` anywhere in `text`, e.g. in `failed to build StereoDepth(2): ...`.
+pub(crate) fn find_node(text: &str) -> Option<(String, i32)> {
+    let is_word = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    text.char_indices()
+        .filter(|&(i, c)| c.is_ascii_alphabetic() && !text[..i].chars().next_back().is_some_and(is_word))
+        .find_map(|(i, _)| parse_node(&text[i..]))
+}
+
+/// `Name(id)` at the start of `text`, e.g. `ImageManip(3)`.
+fn parse_node(text: &str) -> Option<(String, i32)> {
+    let text = text.trim_start_matches(['[', ' ']);
+    let open = text.find('(')?;
+    let name = &text[..open];
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return None;
+    }
+    let close = open + text[open..].find(')')?;
+    let id = text[open + 1..close].trim().parse().ok()?;
+    Some((name.to_string(), id))
+}
+
+impl Pipeline {
+    /// Frame pools the pipeline's nodes allocate on the device.
+    ///
+    /// Print it when a stream stops delivering frames and watch for
+    /// [`PipelineEvent::FramePoolExhausted`](super::PipelineEvent::FramePoolExhausted).
+    pub fn frame_pools(&self) -> Result<FramePoolReport> {
+        Ok(FramePoolReport::from_pipeline_json(&self.serialize_to_json(false)?))
+    }
+}
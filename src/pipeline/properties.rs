@@ -0,0 +1,445 @@
+//! Typed pipeline-wide settings: [`GlobalProperties`] and [`BoardConfig`], plus the hardware frame
+//! sync of a camera rig ([`FrameSyncConfig`]).
+//!
+//! Fields left as `None` keep the pipeline's current value, so a struct only needs the settings
+//! being changed. Keys this crate doesn't model yet are kept in `extra` and round-trip unchanged;
+//! the raw JSON setters remain available as an escape hatch.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::camera::CameraNode;
+use crate::camera_control::FrameSyncMode;
+use crate::common::CameraBoardSocket;
+use crate::device::UsbSpeed;
+use crate::error::{DepthaiError, Result};
+
+/// Pipeline-wide properties sent to the device.
+///
+/// Mirrors C++: `dai::GlobalProperties`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GlobalProperties {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub leon_css_frequency_hz: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub leon_mss_frequency_hz: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pipeline_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pipeline_version: Option<String>,
+    /// XLink chunk size in bytes; 0 disables chunking.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub xlink_chunk_size: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sipp_buffer_size: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sipp_dma_buffer_size: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub camera_tuning_blob_uri: Option<String>,
+    /// Keys not modeled above.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl GlobalProperties {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_leon_css_frequency_hz(mut self, hz: f64) -> Self {
+        self.leon_css_frequency_hz = Some(hz);
+        self
+    }
+
+    pub fn with_leon_mss_frequency_hz(mut self, hz: f64) -> Self {
+        self.leon_mss_frequency_hz = Some(hz);
+        self
+    }
+
+    pub fn with_pipeline_name(mut self, name: &str) -> Self {
+        self.pipeline_name = Some(name.to_string());
+        self
+    }
+
+    pub fn with_pipeline_version(mut self, version: &str) -> Self {
+        self.pipeline_version = Some(version.to_string());
+        self
+    }
+
+    pub fn with_xlink_chunk_size(mut self, size_bytes: i32) -> Self {
+        self.xlink_chunk_size = Some(size_bytes);
+        self
+    }
+
+    pub fn with_sipp_buffer_size(mut self, size_bytes: i32) -> Self {
+        self.sipp_buffer_size = Some(size_bytes);
+        self
+    }
+
+    pub fn with_sipp_dma_buffer_size(mut self, size_bytes: i32) -> Self {
+        self.sipp_dma_buffer_size = Some(size_bytes);
+        self
+    }
+}
+
+/// USB identity and speed of the device.
+///
+/// Mirrors C++: `dai::BoardConfig::USB`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsbConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vid: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flash_booted_vid: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flash_booted_pid: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_speed: Option<UsbSpeed>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub manufacturer: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Declares a C-like enum serialized as its integer value, as DepthAI's JSON does.
+macro_rules! numeric_enum {
+    ($(#[$meta:meta])* $name:ident { $($(#[$vmeta:meta])* $variant:ident = $value:literal),+ $(,)? }) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+        #[serde(into = "i32", try_from = "i32")]
+        pub enum $name {
+            $($(#[$vmeta])* $variant = $value),+
+        }
+
+        impl From<$name> for i32 {
+            fn from(value: $name) -> Self {
+                value as i32
+            }
+        }
+
+        impl TryFrom<i32> for $name {
+            type Error = DepthaiError;
+
+            fn try_from(value: i32) -> Result<Self> {
+                match value {
+                    $($value => Ok(Self::$variant),)+
+                    _ => Err(DepthaiError::new(format!("invalid {} value {value}", stringify!($name)))),
+                }
+            }
+        }
+    };
+}
+
+numeric_enum!(
+    /// Mirrors C++: `dai::BoardConfig::GPIO::Mode`.
+    GpioMode {
+        AltMode0 = 0,
+        AltMode1 = 1,
+        AltMode2 = 2,
+        AltMode3 = 3,
+        AltMode4 = 4,
+        AltMode5 = 5,
+        AltMode6 = 6,
+        /// Plain GPIO.
+        Direct = 7,
+    }
+);
+
+numeric_enum!(
+    /// Mirrors C++: `dai::BoardConfig::GPIO::Direction`.
+    GpioDirection {
+        Input = 0,
+        Output = 1,
+    }
+);
+
+numeric_enum!(
+    /// Mirrors C++: `dai::BoardConfig::GPIO::Level`.
+    GpioLevel {
+        Low = 0,
+        High = 1,
+    }
+);
+
+numeric_enum!(
+    /// Mirrors C++: `dai::BoardConfig::GPIO::Pull`.
+    GpioPull {
+        NoPull = 0,
+        PullUp = 1,
+        PullDown = 2,
+        BusKeeper = 3,
+    }
+);
+
+numeric_enum!(
+    /// Drive strength. Mirrors C++: `dai::BoardConfig::GPIO::Drive`.
+    GpioDrive {
+        Ma2 = 2,
+        Ma4 = 4,
+        Ma8 = 8,
+        Ma12 = 12,
+    }
+);
+
+/// Configuration of one GPIO pin.
+///
+/// Mirrors C++: `dai::BoardConfig::GPIO`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GpioConfig {
+    pub mode: GpioMode,
+    pub direction: GpioDirection,
+    pub level: GpioLevel,
+    pub pull: GpioPull,
+    pub drive: GpioDrive,
+    pub schmitt: bool,
+    pub slew_fast: bool,
+}
+
+impl Default for GpioConfig {
+    fn default() -> Self {
+        Self {
+            mode: GpioMode::Direct,
+            direction: GpioDirection::Input,
+            level: GpioLevel::Low,
+            pull: GpioPull::NoPull,
+            drive: GpioDrive::Ma2,
+            schmitt: false,
+            slew_fast: false,
+        }
+    }
+}
+
+impl GpioConfig {
+    /// A plain GPIO output driven to `level`.
+    pub fn output(level: GpioLevel) -> Self {
+        Self {
+            direction: GpioDirection::Output,
+            level,
+            ..Self::default()
+        }
+    }
+
+    /// A plain GPIO input with the given pull.
+    pub fn input(pull: GpioPull) -> Self {
+        Self { pull, ..Self::default() }
+    }
+}
+
+/// Configuration of one UART.
+///
+/// Mirrors C++: `dai::BoardConfig::UART`, which has no stable fields yet.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UartConfig {
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Board-level device configuration applied at boot.
+///
+/// Mirrors C++: `dai::BoardConfig`. `gpio` and `uart` are keyed by pin/UART number and, when set,
+/// replace the whole table.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usb: Option<UsbConfig>,
+    /// Time without host traffic after which the device resets itself.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub watchdog_timeout_ms: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub watchdog_initial_delay_ms: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gpio: Option<Vec<(i8, GpioConfig)>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uart: Option<Vec<(i8, UartConfig)>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pcie_internal_clock: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usb3_phy_internal_clock: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emmc: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_size_max: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_device_prints: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub non_exclusive_mode: Option<bool>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl BoardConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_max_usb_speed(mut self, speed: UsbSpeed) -> Self {
+        self.usb.get_or_insert_with(UsbConfig::default).max_speed = Some(speed);
+        self
+    }
+
+    pub fn with_watchdog_timeout_ms(mut self, timeout_ms: u32) -> Self {
+        self.watchdog_timeout_ms = Some(timeout_ms);
+        self
+    }
+
+    pub fn with_watchdog_initial_delay_ms(mut self, delay_ms: u32) -> Self {
+        self.watchdog_initial_delay_ms = Some(delay_ms);
+        self
+    }
+
+    /// Configure GPIO `pin`, replacing a previous entry for it.
+    pub fn with_gpio(mut self, pin: i8, config: GpioConfig) -> Self {
+        let gpio = self.gpio.get_or_insert_with(Vec::new);
+        gpio.retain(|(p, _)| *p != pin);
+        gpio.push((pin, config));
+        self
+    }
+
+    /// Configure the FSYNC pins of `sync` in their FSYNC function, see [`FrameSyncConfig`].
+    pub fn with_frame_sync(self, sync: &FrameSyncConfig) -> Self {
+        sync.pins
+            .iter()
+            .filter_map(|&(pin, mode, function)| Some((pin, FrameSyncConfig::gpio(mode, function)?)))
+            .fold(self, |config, (pin, gpio)| config.with_gpio(pin, gpio))
+    }
+
+    /// Configure UART `index`, replacing a previous entry for it.
+    pub fn with_uart(mut self, index: i8, config: UartConfig) -> Self {
+        let uart = self.uart.get_or_insert_with(Vec::new);
+        uart.retain(|(i, _)| *i != index);
+        uart.push((index, config));
+        self
+    }
+}
+
+/// Hardware frame sync (FSYNC) of a device's cameras, and of the GPIO pins carrying the FSYNC
+/// signal to or from other devices.
+///
+/// DepthAI's board config has no FSYNC section: socket modes are set on the camera nodes with
+/// [`apply`](Self::apply), and pins become GPIO entries with [`BoardConfig::with_frame_sync`].
+/// A pin only carries FSYNC in the alternate function the board wires it to, so it is given
+/// with that [`GpioMode::AltMode0`]..[`GpioMode::AltMode6`] mode.
+/// For a rig of several devices, the leader drives its FSYNC pin and every other device listens
+/// on it:
+///
+/// ```no_run
+/// # use depthai::camera::CameraNode;
+/// # use depthai::common::CameraBoardSocket;
+/// # use depthai::pipeline::{BoardConfig, FrameSyncConfig, GpioMode};
+/// # use depthai::{FrameSyncMode, Pipeline, Result};
+/// # fn main() -> Result<()> {
+/// # let leader = true;
+/// // From the board's pinout.
+/// const FSYNC_PIN: i8 = 41;
+/// const FSYNC_FUNCTION: GpioMode = GpioMode::AltMode1;
+/// let sync = if leader {
+///     FrameSyncConfig::new()
+///         .with_pair(CameraBoardSocket::CamB, CameraBoardSocket::CamC)
+///         .with_pin(FSYNC_PIN, FrameSyncMode::Output, FSYNC_FUNCTION)
+/// } else {
+///     FrameSyncConfig::new()
+///         .with_socket(CameraBoardSocket::CamB, FrameSyncMode::Input)
+///         .with_socket(CameraBoardSocket::CamC, FrameSyncMode::Input)
+///         .with_pin(FSYNC_PIN, FrameSyncMode::Input, FSYNC_FUNCTION)
+/// };
+/// let pipeline = Pipeline::new().board_config(BoardConfig::new().with_frame_sync(&sync)).build()?;
+/// for socket in [CameraBoardSocket::CamB, CameraBoardSocket::CamC] {
+///     sync.apply(&pipeline.create_with::<CameraNode, _>(socket)?)?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Which pin carries FSYNC, and in which alternate function, depends on the board, see its
+/// hardware documentation.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FrameSyncConfig {
+    pub sockets: Vec<(CameraBoardSocket, FrameSyncMode)>,
+    /// `(pin, mode, function)`, see [`with_pin`](Self::with_pin).
+    pub pins: Vec<(i8, FrameSyncMode, GpioMode)>,
+}
+
+impl FrameSyncConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the mode of the camera on `socket`, replacing a previous entry for it.
+    pub fn with_socket(mut self, socket: CameraBoardSocket, mode: FrameSyncMode) -> Self {
+        self.sockets.retain(|(s, _)| *s != socket);
+        self.sockets.push((socket, mode));
+        self
+    }
+
+    /// Sync `follower` to `leader` on the same board, e.g. the two sensors of a stereo pair.
+    pub fn with_pair(self, leader: CameraBoardSocket, follower: CameraBoardSocket) -> Self {
+        self.with_socket(leader, FrameSyncMode::Output).with_socket(follower, FrameSyncMode::Input)
+    }
+
+    /// Route FSYNC through GPIO `pin`: out of the device for [`FrameSyncMode::Output`], into it
+    /// for [`FrameSyncMode::Input`]. `function` is the pin's FSYNC alternate function
+    /// (`GpioMode::AltModeN`) from the board's pinout; as [`GpioMode::Direct`] the pin would be a
+    /// plain GPIO not connected to the camera sync. Replaces a previous entry for the pin.
+    pub fn with_pin(mut self, pin: i8, mode: FrameSyncMode, function: GpioMode) -> Self {
+        self.pins.retain(|(p, _, _)| *p != pin);
+        self.pins.push((pin, mode, function));
+        self
+    }
+
+    /// Mode of the camera on `socket` ([`FrameSyncMode::Off`] when not listed).
+    pub fn mode(&self, socket: CameraBoardSocket) -> FrameSyncMode {
+        self.sockets.iter().find(|(s, _)| *s == socket).map_or(FrameSyncMode::Off, |(_, m)| *m)
+    }
+
+    /// Set the mode of `camera`'s socket on it; cameras not listed are left unchanged.
+    pub fn apply(&self, camera: &CameraNode) -> Result<()> {
+        match self.mode(camera.board_socket()?) {
+            FrameSyncMode::Off => Ok(()),
+            mode => camera.set_frame_sync_mode(mode),
+        }
+    }
+
+    /// GPIO configuration of an FSYNC pin in its alternate `function`.
+    fn gpio(mode: FrameSyncMode, function: GpioMode) -> Option<GpioConfig> {
+        let gpio = match mode {
+            FrameSyncMode::Off => return None,
+            // The pulse travels over a cable to the other devices.
+            FrameSyncMode::Output => GpioConfig {
+                drive: GpioDrive::Ma8,
+                ..GpioConfig::output(GpioLevel::Low)
+            },
+            FrameSyncMode::Input => GpioConfig::input(GpioPull::PullDown),
+        };
+        Some(GpioConfig { mode: function, ..gpio })
+    }
+}
+
+pub(crate) fn to_json<T: Serialize>(value: &T, what: &str) -> Result<Value> {
+    serde_json::to_value(value).map_err(|e| DepthaiError::new(format!("failed to serialize {what}: {e}")))
+}
+
+pub(crate) fn from_json<T: for<'de> Deserialize<'de>>(value: Value, what: &str) -> Result<T> {
+    serde_json::from_value(value).map_err(|e| DepthaiError::new(format!("invalid {what}: {e}")))
+}
+
+/// Recursively overlay the keys of `overlay` onto `base`; non-object values replace.
+pub(crate) fn merge_json(base: &mut Value, overlay: Value) {
+    match (base, overlay) {
+        (Value::Object(base), Value::Object(overlay)) => {
+            for (key, value) in overlay {
+                merge_json(base.entry(key).or_insert(Value::Null), value);
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
@@ -0,0 +1,230 @@
+//! Estimate of the device resources a pipeline asks for.
+//!
+//! On RVC2 devices, SHAVE cores, CMX memory slices and the neural compute engines are split
+//! between nodes when the pipeline starts; asking for more than the device has fails the start
+//! with a firmware error that doesn't say which node is to blame. [`Pipeline::resource_estimate`]
+//! reads the demand off the node properties so it can be checked up front.
+
+use std::fmt;
+
+use serde_json::Value;
+
+use crate::error::Result;
+
+use super::Pipeline;
+
+/// Device resources available to a pipeline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResourceBudget {
+    pub shaves: u32,
+    pub cmx_slices: u32,
+    pub nce: u32,
+    pub ddr_bytes: u64,
+}
+
+impl ResourceBudget {
+    /// Myriad X (OAK-D, OAK-1, ...): 16 SHAVEs, 20 CMX slices of 128 KiB, 2 NCEs, 512 MiB DDR.
+    pub const RVC2: Self = Self {
+        shaves: 16,
+        cmx_slices: 20,
+        nce: 2,
+        ddr_bytes: 512 * 1024 * 1024,
+    };
+}
+
+/// Resources requested by one node. `None` means the device picks the amount at startup.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeResources {
+    pub node_id: i32,
+    /// DepthAI node type name (e.g. `"StereoDepth"`).
+    pub node_name: String,
+    pub shaves: Option<u32>,
+    pub cmx_slices: Option<u32>,
+    pub nce: Option<u32>,
+    /// Model blob and output frame pools; frame pools whose size depends on the input are left
+    /// out.
+    pub ddr_bytes: u64,
+}
+
+impl NodeResources {
+    /// Whether the device picks some of the amounts.
+    pub fn is_automatic(&self) -> bool {
+        self.shaves.is_none() || self.cmx_slices.is_none() || self.nce.is_none()
+    }
+}
+
+/// Resources requested by the nodes of a pipeline, see [`Pipeline::resource_estimate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceEstimate {
+    /// Nodes that use SHAVEs, CMX, NCEs or a known amount of DDR.
+    pub nodes: Vec<NodeResources>,
+    pub budget: ResourceBudget,
+}
+
+impl ResourceEstimate {
+    /// Estimate from the output of [`Pipeline::serialize_to_json`].
+    pub fn from_pipeline_json(json: &Value, budget: ResourceBudget) -> Self {
+        let schema = json.get("pipeline").unwrap_or(json);
+        let nodes = schema_nodes(schema)
+            .filter_map(|node| {
+                let name = node.get("name")?.as_str()?;
+                let id = node.get("id").and_then(Value::as_i64).unwrap_or(-1) as i32;
+                let properties = node_properties(node)?;
+                node_resources(id, name, &properties)
+            })
+            .collect();
+        Self { nodes, budget }
+    }
+
+    /// SHAVEs requested explicitly.
+    pub fn shaves(&self) -> u32 {
+        self.nodes.iter().filter_map(|n| n.shaves).sum()
+    }
+
+    /// CMX slices requested explicitly.
+    pub fn cmx_slices(&self) -> u32 {
+        self.nodes.iter().filter_map(|n| n.cmx_slices).sum()
+    }
+
+    /// NCEs requested explicitly.
+    pub fn nce(&self) -> u32 {
+        self.nodes.iter().filter_map(|n| n.nce).sum()
+    }
+
+    pub fn ddr_bytes(&self) -> u64 {
+        self.nodes.iter().map(|n| n.ddr_bytes).sum()
+    }
+
+    /// One message per resource requested beyond the budget; empty if the pipeline fits.
+    ///
+    /// Nodes left on automatic allocation take what is left, so a pipeline can still fail to
+    /// start when this is empty and [`NodeResources::is_automatic`] holds for some node.
+    pub fn overcommitted(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        let mut check = |what: &str, used: u64, available: u64| {
+            if used > available {
+                problems.push(format!("{what}: {used} requested, {available} available"));
+            }
+        };
+        check("SHAVEs", self.shaves().into(), self.budget.shaves.into());
+        check("CMX slices", self.cmx_slices().into(), self.budget.cmx_slices.into());
+        check("NCEs", self.nce().into(), self.budget.nce.into());
+        check("DDR bytes", self.ddr_bytes(), self.budget.ddr_bytes);
+        problems
+    }
+
+    pub fn fits(&self) -> bool {
+        self.overcommitted().is_empty()
+    }
+}
+
+impl fmt::Display for ResourceEstimate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let amount = |v: Option<u32>| v.map_or_else(|| "auto".to_string(), |v| v.to_string());
+        writeln!(f, "{:<24} {:>6} {:>6} {:>4} {:>10}", "node", "SHAVE", "CMX", "NCE", "DDR KiB")?;
+        for node in &self.nodes {
+            writeln!(
+                f,
+                "{:<24} {:>6} {:>6} {:>4} {:>10}",
+                format!("{} #{}", node.node_name, node.node_id),
+                amount(node.shaves),
+                amount(node.cmx_slices),
+                amount(node.nce),
+                node.ddr_bytes / 1024
+            )?;
+        }
+        write!(
+            f,
+            "{:<24} {:>6} {:>6} {:>4} {:>10}",
+            "total / available",
+            format!("{}/{}", self.shaves(), self.budget.shaves),
+            format!("{}/{}", self.cmx_slices(), self.budget.cmx_slices),
+            format!("{}/{}", self.nce(), self.budget.nce),
+            self.ddr_bytes() / 1024
+        )
+    }
+}
+
+/// Schema nodes are serialized as `[[id, node], ...]`.
+pub(crate) fn schema_nodes(schema: &Value) -> impl Iterator<Item = &Value> {
+    let nodes = schema.get("nodes");
+    let pairs = nodes.and_then(Value::as_array).into_iter().flatten().filter_map(|n| n.get(1));
+    let map = nodes.and_then(Value::as_object).into_iter().flat_map(|m| m.values());
+    pairs.chain(map)
+}
+
+/// Node properties, either inline JSON or the JSON text as a byte array.
+pub(crate) fn node_properties(node: &Value) -> Option<Value> {
+    match node.get("properties")? {
+        Value::Array(bytes) => {
+            let bytes: Option<Vec<u8>> = bytes.iter().map(|b| b.as_u64().map(|b| b as u8)).collect();
+            serde_json::from_slice(&bytes?).ok()
+        }
+        properties => Some(properties.clone()),
+    }
+}
+
+/// Positive integer property, `None` when missing or on automatic (`0`/`-1`).
+fn positive(properties: &Value, key: &str) -> Option<u32> {
+    properties
+        .get(key)
+        .and_then(Value::as_i64)
+        .filter(|&v| v > 0)
+        .map(|v| v as u32)
+}
+
+fn node_resources(node_id: i32, node_name: &str, properties: &Value) -> Option<NodeResources> {
+    let resources = |shaves, cmx_slices, nce, ddr_bytes| NodeResources {
+        node_id,
+        node_name: node_name.to_string(),
+        shaves,
+        cmx_slices,
+        nce,
+        ddr_bytes,
+    };
+    match node_name {
+        "NeuralNetwork" => {
+            // Automatic threading runs two inference threads.
+            let threads = positive(properties, "numThreads").unwrap_or(2);
+            let shaves = positive(properties, "numShavesPerThread").map(|s| s * threads);
+            let nce = positive(properties, "numNCEPerThread").map(|n| n * threads);
+            let blob = properties.get("blobSize").and_then(Value::as_u64).unwrap_or(0);
+            // Each SHAVE works out of its own CMX slice.
+            Some(resources(shaves, shaves, nce, blob))
+        }
+        "StereoDepth" => {
+            let shaves = properties
+                .get("numPostProcessingShaves")
+                .and_then(Value::as_i64)
+                .filter(|&v| v >= 0)
+                .map(|v| v as u32);
+            let slices = properties
+                .get("numPostProcessingMemorySlices")
+                .and_then(Value::as_i64)
+                .filter(|&v| v >= 0)
+                .map(|v| v as u32);
+            Some(resources(shaves, slices, Some(0), 0))
+        }
+        "ImageManip" | "ImageManipV2" => {
+            let frame = positive(properties, "outputFrameSize").unwrap_or(0) as u64;
+            let pool = positive(properties, "numFramesPool").unwrap_or(0) as u64;
+            Some(resources(Some(0), Some(0), Some(0), frame * pool))
+        }
+        _ => None,
+    }
+}
+
+impl Pipeline {
+    /// SHAVE, CMX, NCE and DDR demand of the pipeline's nodes against the RVC2 budget.
+    ///
+    /// Run it before `start()` to find which node a "pipeline doesn't fit" startup error comes
+    /// from; lower the demand with e.g.
+    /// [`StereoDepthNode::set_post_processing_hardware_resources`](crate::stereo_depth::StereoDepthNode::set_post_processing_hardware_resources)
+    /// or [`NeuralNetworkNode::set_num_shaves_per_inference_thread`](crate::NeuralNetworkNode::set_num_shaves_per_inference_thread).
+    pub fn resource_estimate(&self) -> Result<ResourceEstimate> {
+        Ok(ResourceEstimate::from_pipeline_json(
+            &self.serialize_to_json(false)?,
+            ResourceBudget::RVC2,
+        ))
+    }
+}
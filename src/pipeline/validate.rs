@@ -0,0 +1,243 @@
+//! Pre-start validation of the pipeline graph.
+
+use std::fmt;
+
+use crate::common::ImageFrameType;
+use crate::device::DevicePlatform;
+use crate::encoded_frame::validate_nv12_dimensions;
+use crate::error::Result;
+use crate::stereo_depth::STEREO_WIDTH_ALIGNMENT;
+
+use super::{OutputHint, Pipeline, PipelineConnectionInfo, PipelineNodeInfo};
+
+/// Node types that are not available on RVC4 devices.
+const RVC4_UNSUPPORTED_NODES: &[&str] = &["SPIIn", "SPIOut", "UVC"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ValidationSeverity {
+    /// The pipeline will start, but likely not behave as intended (e.g. a node that never runs).
+    Warning,
+    /// The pipeline is expected to fail to start or to fail on-device.
+    Error,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValidationIssueKind {
+    /// An input the node waits on is not linked, so the node never processes.
+    UnlinkedInput,
+    /// A StereoDepth input width is not a multiple of 16.
+    StereoWidthAlignment,
+    /// StereoDepth `left` and `right` inputs have different sizes.
+    StereoSizeMismatch,
+    /// NV12 frames with odd width/height feed a VideoEncoder.
+    Nv12OddDimensions,
+    /// The node (or link) is not supported on the connected device platform.
+    UnsupportedOnPlatform,
+}
+
+/// A problem found by [`Pipeline::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    pub severity: ValidationSeverity,
+    pub kind: ValidationIssueKind,
+    pub node_id: i32,
+    /// DepthAI node type name (e.g. `"StereoDepth"`).
+    pub node_name: String,
+    /// Offending port, if the issue is about a specific input.
+    pub port: Option<String>,
+    pub message: String,
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = match self.severity {
+            ValidationSeverity::Warning => "warning",
+            ValidationSeverity::Error => "error",
+        };
+        write!(f, "[{severity}] {} #{}", self.node_name, self.node_id)?;
+        if let Some(port) = &self.port {
+            write!(f, " ({port})")?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+impl Pipeline {
+    /// Check the pipeline graph for common misconfigurations before calling `start()`.
+    ///
+    /// Returns every issue found (empty if none). Checks include:
+    /// - inputs the node waits on that are not linked,
+    /// - StereoDepth input width alignment and left/right size mismatches,
+    /// - odd NV12 dimensions feeding a VideoEncoder,
+    /// - nodes unsupported on the bound device platform (RVC4).
+    ///
+    /// Frame sizes are only known for outputs configured through this crate
+    /// (e.g. [`CameraNode::request_output`](crate::camera::CameraNode::request_output)).
+    pub fn validate(&self) -> Result<Vec<ValidationIssue>> {
+        let nodes = self.all_nodes()?;
+        let connections = self.connections()?;
+        let hints = self.inner.output_hints();
+        // Host-only pipelines have no device; platform checks are skipped then.
+        let platform = self.default_device().and_then(|d| d.platform()).ok();
+
+        let mut issues = Vec::new();
+        for node in &nodes {
+            self.check_unlinked_inputs(node, &connections, &mut issues)?;
+        }
+        issues.extend(validate_graph(&nodes, &connections, &hints, platform));
+        Ok(issues)
+    }
+
+    fn check_unlinked_inputs(
+        &self,
+        node: &PipelineNodeInfo,
+        connections: &[PipelineConnectionInfo],
+        issues: &mut Vec<ValidationIssue>,
+    ) -> Result<()> {
+        let Some(handle) = self.node_by_id(node.id)? else {
+            return Ok(());
+        };
+        for input in handle.inputs_info()? {
+            if !input.wait_for_message {
+                continue;
+            }
+            let linked = connections.iter().any(|c| {
+                c.input_id == node.id && c.input_name == input.name && c.input_group == input.group
+            });
+            if !linked {
+                issues.push(issue(
+                    ValidationSeverity::Warning,
+                    ValidationIssueKind::UnlinkedInput,
+                    node,
+                    Some(&input.name),
+                    "input is not linked but the node waits for it; the node will never run".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The checks of [`Pipeline::validate`] that only need the graph: link sizes and formats
+/// against the recorded `hints`, and node support on `platform` (skipped when `None`).
+///
+/// Unlinked inputs are not reported here since finding them needs the live nodes.
+pub fn validate_graph(
+    nodes: &[PipelineNodeInfo],
+    connections: &[PipelineConnectionInfo],
+    hints: &[OutputHint],
+    platform: Option<DevicePlatform>,
+) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    check_links(nodes, connections, hints, platform, &mut issues);
+    if platform == Some(DevicePlatform::Rvc4) {
+        for node in nodes.iter().filter(|n| RVC4_UNSUPPORTED_NODES.contains(&n.name.as_str())) {
+            issues.push(issue(
+                ValidationSeverity::Error,
+                ValidationIssueKind::UnsupportedOnPlatform,
+                node,
+                None,
+                format!("{} is not supported on RVC4 devices", node.name),
+            ));
+        }
+    }
+    issues
+}
+
+fn check_links(
+    nodes: &[PipelineNodeInfo],
+    connections: &[PipelineConnectionInfo],
+    hints: &[OutputHint],
+    platform: Option<DevicePlatform>,
+    issues: &mut Vec<ValidationIssue>,
+) {
+    let hint_for = |c: &PipelineConnectionInfo| {
+        hints
+            .iter()
+            .find(|h| h.node_id == c.output_id && h.group == c.output_group && h.name == c.output_name)
+    };
+
+    for node in nodes {
+        let incoming = connections.iter().filter(|c| c.input_id == node.id);
+        match node.name.as_str() {
+            "StereoDepth" => {
+                let mut sizes = Vec::new();
+                for c in incoming {
+                    if c.input_name == "inputAlignTo" && platform == Some(DevicePlatform::Rvc4) {
+                        issues.push(issue(
+                            ValidationSeverity::Error,
+                            ValidationIssueKind::UnsupportedOnPlatform,
+                            node,
+                            Some(&c.input_name),
+                            "StereoDepth.inputAlignTo is not supported on RVC4; use ImageAlign instead".to_string(),
+                        ));
+                    }
+                    if c.input_name != "left" && c.input_name != "right" {
+                        continue;
+                    }
+                    let Some(hint) = hint_for(c) else { continue };
+                    let (width, _) = hint.size;
+                    if width % STEREO_WIDTH_ALIGNMENT != 0 {
+                        issues.push(issue(
+                            ValidationSeverity::Error,
+                            ValidationIssueKind::StereoWidthAlignment,
+                            node,
+                            Some(&c.input_name),
+                            format!(
+                                "input width {width} is not a multiple of {STEREO_WIDTH_ALIGNMENT} (try {})",
+                                width.next_multiple_of(STEREO_WIDTH_ALIGNMENT)
+                            ),
+                        ));
+                    }
+                    sizes.push(hint.size);
+                }
+                if sizes.len() == 2 && sizes[0] != sizes[1] {
+                    let (a, b) = (sizes[0], sizes[1]);
+                    issues.push(issue(
+                        ValidationSeverity::Error,
+                        ValidationIssueKind::StereoSizeMismatch,
+                        node,
+                        None,
+                        format!("left/right inputs differ in size: {}x{} vs {}x{}", a.0, a.1, b.0, b.1),
+                    ));
+                }
+            }
+            "VideoEncoder" => {
+                for c in incoming {
+                    let Some(hint) = hint_for(c) else { continue };
+                    if hint.frame_type != Some(ImageFrameType::NV12) {
+                        continue;
+                    }
+                    let (width, height) = hint.size;
+                    if validate_nv12_dimensions(width, height).is_err() {
+                        issues.push(issue(
+                            ValidationSeverity::Error,
+                            ValidationIssueKind::Nv12OddDimensions,
+                            node,
+                            Some(&c.input_name),
+                            format!("NV12 input {width}x{height} must have even width and height"),
+                        ));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn issue(
+    severity: ValidationSeverity,
+    kind: ValidationIssueKind,
+    node: &PipelineNodeInfo,
+    port: Option<&str>,
+    message: String,
+) -> ValidationIssue {
+    ValidationIssue {
+        severity,
+        kind,
+        node_id: node.id,
+        node_name: node.name.clone(),
+        port: port.map(str::to_string),
+        message,
+    }
+}
@@ -5,9 +5,11 @@ use depthai_sys::{depthai, DaiPointCloud};
 
 use crate::camera::OutputQueue;
 use crate::error::{clear_error_flag, take_error_if_any, Result};
+use crate::host_node::ns_to_duration;
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Point3fRGBA {
     pub x: f32,
     pub y: f32,
@@ -18,6 +20,13 @@ pub struct Point3fRGBA {
     pub a: u8,
 }
 
+impl Point3fRGBA {
+    /// Whether the point carries a measurement: DepthAI marks pixels without depth with `z == 0`.
+    pub fn is_valid(&self) -> bool {
+        self.z != 0.0 && self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+}
+
 pub struct PointCloudData {
     handle: DaiPointCloud,
 }
@@ -46,6 +55,17 @@ impl PointCloudData {
         raw.max(0) as u32
     }
 
+    /// Timestamp on the host steady clock.
+    pub fn timestamp(&self) -> Result<Duration> {
+        clear_error_flag();
+        let ns = unsafe { depthai::dai_pointcloud_get_timestamp_ns(self.handle) };
+        if let Some(err) = take_error_if_any("failed to get pointcloud timestamp") {
+            Err(err)
+        } else {
+            Ok(ns_to_duration(ns))
+        }
+    }
+
     pub fn points(&self) -> &[Point3fRGBA] {
         let len: usize = unsafe { depthai::dai_pointcloud_get_points_rgba_len(self.handle) }.into();
         if len == 0 {
@@ -57,6 +77,22 @@ impl PointCloudData {
         }
         unsafe { std::slice::from_raw_parts(ptr as *const Point3fRGBA, len) }
     }
+
+    /// Whether every point is valid (see [`Point3fRGBA::is_valid`]).
+    pub fn is_dense(&self) -> bool {
+        self.points().iter().all(Point3fRGBA::is_valid)
+    }
+
+    /// Points with a measurement, i.e. the sparse form of an organized cloud. Use
+    /// [`points`](Self::points) for the dense `width * height` layout.
+    pub fn valid_points(&self) -> impl Iterator<Item = &Point3fRGBA> {
+        self.points().iter().filter(|p| p.is_valid())
+    }
+
+    /// Owned copy of [`valid_points`](Self::valid_points).
+    pub fn to_sparse(&self) -> Vec<Point3fRGBA> {
+        self.valid_points().copied().collect()
+    }
 }
 
 impl OutputQueue {
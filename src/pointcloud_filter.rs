@@ -0,0 +1,197 @@
+//! Host-side point cloud filtering.
+//!
+//! [`voxel_downsample`] thins a cloud to one point per cube of a given size, and
+//! [`remove_statistical_outliers`] drops the isolated points stereo matching leaves around
+//! object edges. Both work on any slice of [`Point3fRGBA`], e.g. [`PointCloudData::points`] or
+//! [`RgbdData::to_pointcloud`](crate::rgbd::RgbdData::to_pointcloud), skip invalid points and
+//! return a new owned cloud, so they can be chained:
+//!
+//! ```no_run
+//! # use depthai::pointcloud::PointCloudData;
+//! # use depthai::pointcloud_filter::remove_statistical_outliers;
+//! # fn f(pcl: &PointCloudData) -> depthai::Result<()> {
+//! let thinned = pcl.voxel_downsample(0.02)?;
+//! let cleaned = remove_statistical_outliers(&thinned, 16, 2.0)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! With the `rayon` feature, the neighbor searches of outlier removal run on the rayon thread
+//! pool.
+
+use std::collections::HashMap;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::error::{DepthaiError, Result};
+use crate::pointcloud::{Point3fRGBA, PointCloudData};
+
+/// Running sums of the points falling into one voxel.
+#[derive(Default)]
+struct VoxelSum {
+    position: [f64; 3],
+    color: [u32; 4],
+    count: u32,
+}
+
+impl VoxelSum {
+    fn add(&mut self, p: &Point3fRGBA) {
+        self.position[0] += p.x as f64;
+        self.position[1] += p.y as f64;
+        self.position[2] += p.z as f64;
+        for (sum, c) in self.color.iter_mut().zip([p.r, p.g, p.b, p.a]) {
+            *sum += c as u32;
+        }
+        self.count += 1;
+    }
+
+    fn centroid(&self) -> Point3fRGBA {
+        let n = self.count.max(1);
+        let position = self.position.map(|v| (v / n as f64) as f32);
+        let color = self.color.map(|c| (c / n) as u8);
+        Point3fRGBA {
+            x: position[0],
+            y: position[1],
+            z: position[2],
+            r: color[0],
+            g: color[1],
+            b: color[2],
+            a: color[3],
+        }
+    }
+}
+
+/// One point per occupied cube of `voxel_size` (same unit as the points): the centroid of the
+/// cube's points, with their average color.
+///
+/// Points are returned in the order their cubes are first hit.
+pub fn voxel_downsample(points: &[Point3fRGBA], voxel_size: f32) -> Result<Vec<Point3fRGBA>> {
+    if !(voxel_size > 0.0 && voxel_size.is_finite()) {
+        return Err(DepthaiError::new(format!("invalid voxel size {voxel_size}")));
+    }
+    let mut voxels: HashMap<[i64; 3], usize> = HashMap::new();
+    let mut sums: Vec<VoxelSum> = Vec::new();
+    for p in points.iter().filter(|p| p.is_valid()) {
+        let key = [p.x, p.y, p.z].map(|v| (v / voxel_size).floor() as i64);
+        let index = *voxels.entry(key).or_insert_with(|| {
+            sums.push(VoxelSum::default());
+            sums.len() - 1
+        });
+        sums[index].add(p);
+    }
+    Ok(sums.iter().map(VoxelSum::centroid).collect())
+}
+
+/// Drop points whose mean distance to their `k` nearest neighbors exceeds the mean of that
+/// distance over the cloud by more than `std_ratio` standard deviations.
+///
+/// Mirrors PCL's `StatisticalOutlierRemoval`; `k = 16`, `std_ratio = 2.0` is a good start for
+/// stereo depth.
+pub fn remove_statistical_outliers(points: &[Point3fRGBA], k: usize, std_ratio: f32) -> Result<Vec<Point3fRGBA>> {
+    if k == 0 {
+        return Err(DepthaiError::new("outlier removal needs at least one neighbor"));
+    }
+    if !std_ratio.is_finite() {
+        return Err(DepthaiError::new(format!("invalid standard deviation ratio {std_ratio}")));
+    }
+    let valid: Vec<Point3fRGBA> = points.iter().filter(|p| p.is_valid()).copied().collect();
+    if valid.len() <= k {
+        return Ok(valid);
+    }
+    let positions: Vec<[f32; 3]> = valid.iter().map(|p| [p.x, p.y, p.z]).collect();
+    let tree = KdTree::new(&positions);
+
+    #[cfg(feature = "rayon")]
+    let mean_distances: Vec<f32> = (0..positions.len())
+        .into_par_iter()
+        .map(|i| tree.mean_neighbor_distance(i, k))
+        .collect();
+    #[cfg(not(feature = "rayon"))]
+    let mean_distances: Vec<f32> = (0..positions.len())
+        .map(|i| tree.mean_neighbor_distance(i, k))
+        .collect();
+
+    let n = mean_distances.len() as f64;
+    let mean = mean_distances.iter().map(|&d| d as f64).sum::<f64>() / n;
+    let variance = mean_distances.iter().map(|&d| (d as f64 - mean).powi(2)).sum::<f64>() / (n - 1.0).max(1.0);
+    let threshold = (mean + std_ratio as f64 * variance.sqrt()) as f32;
+    Ok(valid
+        .into_iter()
+        .zip(mean_distances)
+        .filter(|&(_, d)| d <= threshold)
+        .map(|(p, _)| p)
+        .collect())
+}
+
+/// Static 3D k-d tree over point indices, split at the median of `x`, `y`, `z` in turn.
+struct KdTree<'a> {
+    points: &'a [[f32; 3]],
+    order: Vec<usize>,
+}
+
+impl<'a> KdTree<'a> {
+    fn new(points: &'a [[f32; 3]]) -> Self {
+        let mut order: Vec<usize> = (0..points.len()).collect();
+        Self::build(points, &mut order, 0);
+        Self { points, order }
+    }
+
+    fn build(points: &[[f32; 3]], order: &mut [usize], axis: usize) {
+        if order.len() <= 1 {
+            return;
+        }
+        let mid = order.len() / 2;
+        order.select_nth_unstable_by(mid, |&a, &b| points[a][axis].total_cmp(&points[b][axis]));
+        let (left, right) = order.split_at_mut(mid);
+        Self::build(points, left, (axis + 1) % 3);
+        Self::build(points, &mut right[1..], (axis + 1) % 3);
+    }
+
+    /// Mean Euclidean distance from point `index` to its `k` nearest other points.
+    fn mean_neighbor_distance(&self, index: usize, k: usize) -> f32 {
+        let mut nearest = Vec::with_capacity(k + 1);
+        self.search(&self.order, 0, index, k, &mut nearest);
+        nearest.iter().map(|d: &f32| d.sqrt()).sum::<f32>() / nearest.len().max(1) as f32
+    }
+
+    /// Collect the `k` smallest squared distances into `nearest`, kept sorted.
+    fn search(&self, order: &[usize], axis: usize, index: usize, k: usize, nearest: &mut Vec<f32>) {
+        if order.is_empty() {
+            return;
+        }
+        let mid = order.len() / 2;
+        let (query, split) = (self.points[index], self.points[order[mid]]);
+        if order[mid] != index {
+            let d = (0..3).map(|a| (query[a] - split[a]).powi(2)).sum::<f32>();
+            if nearest.len() < k || d < nearest[k - 1] {
+                let at = nearest.partition_point(|&n| n <= d);
+                nearest.insert(at, d);
+                nearest.truncate(k);
+            }
+        }
+        let diff = query[axis] - split[axis];
+        let (near, far) = if diff < 0.0 {
+            (&order[..mid], &order[mid + 1..])
+        } else {
+            (&order[mid + 1..], &order[..mid])
+        };
+        let next = (axis + 1) % 3;
+        self.search(near, next, index, k, nearest);
+        if nearest.len() < k || diff * diff < nearest[k - 1] {
+            self.search(far, next, index, k, nearest);
+        }
+    }
+}
+
+impl PointCloudData {
+    /// [`voxel_downsample`] of the valid points.
+    pub fn voxel_downsample(&self, voxel_size: f32) -> Result<Vec<Point3fRGBA>> {
+        voxel_downsample(self.points(), voxel_size)
+    }
+
+    /// [`remove_statistical_outliers`] of the valid points.
+    pub fn remove_statistical_outliers(&self, k: usize, std_ratio: f32) -> Result<Vec<Point3fRGBA>> {
+        remove_statistical_outliers(self.points(), k, std_ratio)
+    }
+}
@@ -0,0 +1,38 @@
+//! The types most pipelines need, for a single glob import.
+//!
+//! ```no_run
+//! use depthai::prelude::*;
+//!
+//! fn main() -> Result<()> {
+//!     let device = Device::new()?;
+//!     let pipeline = Pipeline::new().with_device(&device).build()?;
+//!     let camera = pipeline.create_with::<CameraNode, _>(CameraBoardSocket::CamA)?;
+//!     let output = camera.request_output(CameraOutputConfig {
+//!         frame_type: Some(ImageFrameType::NV12),
+//!         ..CameraOutputConfig::new((640, 400))
+//!     })?;
+//!     let queue = output.create_queue(4, false)?;
+//!     pipeline.start()?;
+//!     if let Some(frame) = queue.blocking_next(None)? {
+//!         println!("{}", frame.describe());
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+pub use crate::camera::{CameraNode, CameraOutputConfig, ImageFrame, OutputQueue};
+pub use crate::common::{CameraBoardSocket, ImageFrameType, ResizeMode};
+pub use crate::depth::DepthFrame;
+pub use crate::detections::{ImgDetection, ImgDetections};
+pub use crate::device::Device;
+pub use crate::error::{DepthaiError, Result};
+pub use crate::host_node::{Buffer, MessageGroup};
+pub use crate::image_manip::{ImageManipConfig, ImageManipNode};
+pub use crate::imu::ImuData;
+pub use crate::nn_data::NNData;
+pub use crate::output::{Input, Output, TypedInput, TypedOutput};
+pub use crate::pipeline::Pipeline;
+pub use crate::pointcloud::PointCloudData;
+pub use crate::queue::{Datatype, InputQueue, MessageQueue};
+pub use crate::stereo_depth::{PresetMode as StereoPresetMode, StereoDepthNode};
+pub use crate::video_encoder::VideoEncoderNode;
@@ -0,0 +1,254 @@
+//! Ready-made pipelines for common OAK setups.
+//!
+//! Each preset creates the pipeline, its nodes and links, and the host queues to read from, and
+//! returns them in a small struct. The nodes stay accessible for further configuration before
+//! `pipeline.start()`.
+//!
+//! ```no_run
+//! # use std::time::Duration;
+//! # use depthai::{presets, Device, Result};
+//! # fn main() -> Result<()> {
+//! let device = Device::new()?;
+//! let rgbd = presets::rgbd(&device)?;
+//! rgbd.pipeline.start()?;
+//! while let Some(frame) = rgbd.rgbd_queue.blocking_next_rgbd(Some(Duration::from_secs(1)))? {
+//!     println!("{}x{}", frame.rgb_frame()?.width(), frame.rgb_frame()?.height());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::path::Path;
+use std::time::Duration;
+
+use crate::calibration::CameraIntrinsics;
+use crate::camera::{CameraNode, CameraOutputConfig, OutputQueue};
+use crate::common::{CameraBoardSocket, ImageFrameType, ResizeMode};
+use crate::depth::{DepthFrame, RoiDepthConfig};
+use crate::detections::ImgDetection;
+use crate::device::{Device, DevicePlatform};
+use crate::error::{DepthaiError, Result};
+use crate::model_zoo::{get_model_from_zoo, ModelDescription, ZooOptions};
+use crate::nn_archive::{DetectionNetworkNode, NNArchive};
+use crate::pipeline::Pipeline;
+use crate::queue::MessageQueue;
+use crate::rgbd::{DepthUnit, RgbdNode};
+use crate::stereo_depth::{DepthAlign, PresetMode, StereoDepthNode};
+
+/// Mono camera resolution used by the stereo presets.
+const STEREO_SIZE: (u32, u32) = (640, 400);
+const QUEUE_SIZE: u32 = 4;
+
+/// RVC4 runs the presets at a lower rate to leave headroom for alignment.
+fn preset_fps(platform: DevicePlatform) -> f32 {
+    match platform {
+        DevicePlatform::Rvc4 => 15.0,
+        _ => 30.0,
+    }
+}
+
+/// Result of [`rgbd`].
+pub struct RgbdPreset {
+    pub pipeline: Pipeline,
+    pub rgbd: RgbdNode,
+    /// Color frames paired with depth aligned to them.
+    pub rgbd_queue: OutputQueue,
+    /// Colored point clouds in meters.
+    pub pointcloud_queue: OutputQueue,
+}
+
+/// Color camera and stereo depth combined into RGBD frames and point clouds, at 640x400.
+///
+/// The setup of the `rgbd_rerun` example: the stereo pair runs the `Robotics` preset (`Default`
+/// on RVC4) and point clouds are reported in meters.
+pub fn rgbd(device: &Device) -> Result<RgbdPreset> {
+    let platform = device.platform()?;
+    let pipeline = Pipeline::new().with_device(device).build()?;
+    let rgbd = pipeline.create::<RgbdNode>()?;
+    rgbd.set_depth_unit(DepthUnit::Meter);
+    let preset = match platform {
+        DevicePlatform::Rvc4 => PresetMode::Default,
+        _ => PresetMode::Robotics,
+    };
+    rgbd.autocreate()
+        .preset(preset)
+        .size(STEREO_SIZE.0, STEREO_SIZE.1)
+        .fps(preset_fps(platform))
+        .build()?;
+    let rgbd_queue = rgbd.as_node().output("rgbd")?.create_queue(QUEUE_SIZE, false)?;
+    let pointcloud_queue = rgbd.as_node().output("pcl")?.create_queue(QUEUE_SIZE, false)?;
+    Ok(RgbdPreset {
+        pipeline,
+        rgbd,
+        rgbd_queue,
+        pointcloud_queue,
+    })
+}
+
+/// Result of [`stereo_depth_only`].
+pub struct StereoDepthPreset {
+    pub pipeline: Pipeline,
+    pub left: CameraNode,
+    pub right: CameraNode,
+    pub stereo: StereoDepthNode,
+    /// `RAW16` depth in millimeters, aligned to the rectified right camera.
+    pub depth_queue: OutputQueue,
+}
+
+/// The recommended stereo pair of the default device feeding a `StereoDepth` node.
+pub fn stereo_depth_only() -> Result<StereoDepthPreset> {
+    let pipeline = Pipeline::new().build()?;
+    let (left, right, stereo) = create_stereo(&pipeline, 30.0)?;
+    let depth_queue = stereo.depth()?.create_queue(QUEUE_SIZE, false)?;
+    Ok(StereoDepthPreset {
+        pipeline,
+        left,
+        right,
+        stereo,
+        depth_queue,
+    })
+}
+
+/// Cameras of the device's recommended stereo pair; `CamB`/`CamC` when it reports none.
+fn create_stereo(pipeline: &Pipeline, fps: f32) -> Result<(CameraNode, CameraNode, StereoDepthNode)> {
+    let (left, right) = pipeline
+        .default_device()?
+        .default_stereo_pair()?
+        .map_or((CameraBoardSocket::CamB, CameraBoardSocket::CamC), |pair| (pair.left, pair.right));
+    let left = pipeline.create_with::<CameraNode, _>(left)?;
+    let right = pipeline.create_with::<CameraNode, _>(right)?;
+    let stereo = pipeline.create::<StereoDepthNode>()?;
+    stereo.set_default_profile_preset(PresetMode::Robotics);
+    let mono = CameraOutputConfig {
+        fps: Some(fps),
+        ..CameraOutputConfig::new(STEREO_SIZE)
+    };
+    left.request_output(mono.clone())?.link(&stereo.left()?)?;
+    right.request_output(mono)?.link(&stereo.right()?)?;
+    Ok((left, right, stereo))
+}
+
+/// A detection with its position relative to the color camera.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpatialDetection {
+    pub detection: ImgDetection,
+    /// Class name from the model archive, if it has one for the label.
+    pub label_name: Option<String>,
+    /// `[x, y, z]` in meters, `None` when the box has no valid depth.
+    pub location: Option<[f32; 3]>,
+}
+
+/// Result of [`yolo_spatial`].
+pub struct YoloSpatialPreset {
+    pub pipeline: Pipeline,
+    pub camera: CameraNode,
+    pub detection_network: DetectionNetworkNode,
+    pub left: CameraNode,
+    pub right: CameraNode,
+    pub stereo: StereoDepthNode,
+    pub detections_queue: MessageQueue,
+    /// Frames the network ran on.
+    pub passthrough_queue: OutputQueue,
+    /// Depth aligned to, and the same size as, the network input.
+    pub depth_queue: OutputQueue,
+    pub roi_depth: RoiDepthConfig,
+    intrinsics: CameraIntrinsics,
+    classes: Vec<String>,
+    latest_depth: Option<DepthFrame>,
+}
+
+impl YoloSpatialPreset {
+    /// Next detections, located with the newest depth frame (median depth inside each box).
+    ///
+    /// Returns `Ok(None)` when no detections arrive within `timeout`.
+    pub fn next(&mut self, timeout: Option<Duration>) -> Result<Option<Vec<SpatialDetection>>> {
+        let Some(message) = self.detections_queue.get(timeout)? else {
+            return Ok(None);
+        };
+        let detections = message
+            .as_img_detections()?
+            .ok_or_else(|| DepthaiError::new("detection network sent a non-detection message"))?;
+        while let Some(frame) = self.depth_queue.try_next()? {
+            self.latest_depth = Some(DepthFrame::new(&frame, DepthUnit::Millimeter)?);
+        }
+        let located = detections
+            .detections()?
+            .into_iter()
+            .map(|detection| SpatialDetection {
+                label_name: self.classes.get(detection.label as usize).cloned(),
+                location: self
+                    .latest_depth
+                    .as_ref()
+                    .and_then(|depth| depth.roi_location(detection.bbox(), &self.roi_depth, &self.intrinsics)),
+                detection,
+            })
+            .collect();
+        Ok(Some(located))
+    }
+}
+
+/// A YOLO detection network on the color camera with stereo depth aligned to it, on a new
+/// connection to the default device.
+///
+/// `model` is a path to an NNArchive or a model zoo slug such as `"yolov6-nano"`. The network
+/// input is a stretched view of the full color field of view and depth is produced at the same
+/// size, so each box is located by [`YoloSpatialPreset::next`] on the host.
+pub fn yolo_spatial(model: &str) -> Result<YoloSpatialPreset> {
+    let device = Device::new()?;
+    let platform = device.platform()?;
+    let archive_path = if Path::new(model).is_file() {
+        Path::new(model).to_path_buf()
+    } else {
+        get_model_from_zoo(&ModelDescription::new(model).with_platform(platform), &ZooOptions::default())?
+    };
+    let archive = NNArchive::open(&archive_path)?;
+    let size = archive
+        .input_size()
+        .ok_or_else(|| DepthaiError::new(format!("model {model:?} has no image input size")))?;
+    let fps = preset_fps(platform);
+
+    let pipeline = Pipeline::new().with_device(&device).build()?;
+    let camera = pipeline.create_with::<CameraNode, _>(CameraBoardSocket::CamA)?;
+    let detection_network = pipeline.create::<DetectionNetworkNode>()?;
+    detection_network.set_nn_archive(&archive, None)?;
+    let frame_type = match platform {
+        DevicePlatform::Rvc4 => ImageFrameType::BGR888i,
+        _ => ImageFrameType::BGR888p,
+    };
+    camera
+        .request_output(CameraOutputConfig {
+            frame_type: Some(frame_type),
+            resize_mode: ResizeMode::Stretch,
+            fps: Some(fps),
+            ..CameraOutputConfig::new(size)
+        })?
+        .link(&detection_network.input()?)?;
+
+    let (left, right, stereo) = create_stereo(&pipeline, fps)?;
+    stereo.set_depth_align(DepthAlign::Camera(CameraBoardSocket::CamA));
+    stereo.set_output_size(size.0 as i32, size.1 as i32);
+    stereo.set_output_keep_aspect_ratio(false);
+
+    let intrinsics = device
+        .read_calibration()?
+        .camera_intrinsics(CameraBoardSocket::CamA, Some(size), false)?;
+
+    Ok(YoloSpatialPreset {
+        detections_queue: detection_network.out()?.create_message_queue(QUEUE_SIZE, false)?,
+        passthrough_queue: detection_network.passthrough()?.create_queue(QUEUE_SIZE, false)?,
+        depth_queue: stereo.depth()?.create_queue(QUEUE_SIZE, false)?,
+        roi_depth: RoiDepthConfig {
+            step: 2,
+            ..RoiDepthConfig::default()
+        },
+        intrinsics,
+        classes: archive.classes().to_vec(),
+        latest_depth: None,
+        pipeline,
+        camera,
+        detection_network,
+        left,
+        right,
+        stereo,
+    })
+}
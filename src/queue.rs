@@ -1,17 +1,26 @@
+use std::collections::VecDeque;
 use std::ffi::{c_char, c_void as std_c_void, CStr, CString};
 use std::panic::{catch_unwind, AssertUnwindSafe};
-use std::sync::{Arc, Mutex};
-use std::time::Duration;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
 
 use autocxx::{c_int, c_uint, c_void as autocxx_c_void};
 use depthai_sys::{depthai, DaiDataQueue, DaiDatatype, DaiInputQueue};
 
 use crate::camera::{ImageFrame};
+use crate::cancel::{self, CancellationToken};
+use crate::detections::ImgDetections;
+use crate::diagnostics::host_clock_now;
 use crate::encoded_frame::EncodedFrame;
-use crate::error::{clear_error_flag, last_error, take_error_if_any, Result};
-use crate::host_node::{Buffer, MessageGroup};
+use crate::error::{clear_error_flag, last_error, take_error_if_any, DepthaiError, Result};
+use crate::host_node::{ns_to_duration, Buffer, MessageGroup};
+use crate::image_manip::ImageManipConfig;
+use crate::imu::ImuData;
+use crate::nn_data::NNData;
 use crate::pointcloud::PointCloudData;
 use crate::rgbd::RgbdData;
+use crate::spatial_location::SpatialLocationCalculatorData;
 
 #[repr(i32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -108,6 +117,7 @@ impl DatatypeEnum {
 
 pub struct Datatype {
     handle: DaiDatatype,
+    received_at: Option<Duration>,
 }
 
 unsafe impl Send for Datatype {}
@@ -124,7 +134,15 @@ impl Drop for Datatype {
 
 impl Datatype {
     pub(crate) fn from_handle(handle: DaiDatatype) -> Self {
-        Self { handle }
+        Self {
+            handle,
+            received_at: None,
+        }
+    }
+
+    fn with_receive_time(mut self, received_at: Option<Duration>) -> Self {
+        self.received_at = received_at;
+        self
     }
 
     pub fn clone_handle(&self) -> Result<Self> {
@@ -137,7 +155,7 @@ impl Datatype {
                 Err(last_error("failed to clone datatype"))
             }
         } else {
-            Ok(Self::from_handle(h))
+            Ok(Self::from_handle(h).with_receive_time(self.received_at))
         }
     }
 
@@ -206,6 +224,62 @@ impl Datatype {
         }
     }
 
+    pub fn as_imu_data(&self) -> Result<Option<ImuData>> {
+        clear_error_flag();
+        let h = unsafe { depthai::dai_datatype_as_imu_data(self.handle) };
+        if h.is_null() {
+            if let Some(err) = take_error_if_any("failed to cast datatype to IMUData") {
+                Err(err)
+            } else {
+                Ok(None)
+            }
+        } else {
+            Ok(Some(ImuData::from_handle(h)))
+        }
+    }
+
+    pub fn as_img_detections(&self) -> Result<Option<ImgDetections>> {
+        clear_error_flag();
+        let h = unsafe { depthai::dai_datatype_as_img_detections(self.handle) };
+        if h.is_null() {
+            if let Some(err) = take_error_if_any("failed to cast datatype to ImgDetections") {
+                Err(err)
+            } else {
+                Ok(None)
+            }
+        } else {
+            Ok(Some(ImgDetections::from_handle(h)))
+        }
+    }
+
+    pub fn as_nn_data(&self) -> Result<Option<NNData>> {
+        clear_error_flag();
+        let h = unsafe { depthai::dai_datatype_as_nn_data(self.handle) };
+        if h.is_null() {
+            if let Some(err) = take_error_if_any("failed to cast datatype to NNData") {
+                Err(err)
+            } else {
+                Ok(None)
+            }
+        } else {
+            Ok(Some(NNData::from_handle(h)))
+        }
+    }
+
+    pub fn as_spatial_location_data(&self) -> Result<Option<SpatialLocationCalculatorData>> {
+        clear_error_flag();
+        let h = unsafe { depthai::dai_datatype_as_spatial_location_data(self.handle) };
+        if h.is_null() {
+            if let Some(err) = take_error_if_any("failed to cast datatype to SpatialLocationCalculatorData") {
+                Err(err)
+            } else {
+                Ok(None)
+            }
+        } else {
+            Ok(Some(SpatialLocationCalculatorData::from_handle(h)))
+        }
+    }
+
     pub fn as_buffer(&self) -> Result<Option<Buffer>> {
         clear_error_flag();
         let h = unsafe { depthai::dai_datatype_as_buffer(self.handle) };
@@ -234,13 +308,241 @@ impl Datatype {
         }
     }
 
+    /// Host steady-clock timestamp of any `Buffer`-derived message, without downcasting.
+    ///
+    /// Fails on messages that aren't buffers (`ADatatype` itself).
+    pub fn timestamp(&self) -> Result<Duration> {
+        clear_error_flag();
+        let ns = unsafe { depthai::dai_datatype_get_timestamp_ns(self.handle) };
+        if let Some(err) = take_error_if_any("failed to get message timestamp") {
+            Err(err)
+        } else {
+            Ok(ns_to_duration(ns))
+        }
+    }
+
+    /// Device-clock timestamp, see [`ImageFrame::timestamp_device`].
+    pub fn timestamp_device(&self) -> Result<Duration> {
+        clear_error_flag();
+        let ns = unsafe { depthai::dai_datatype_get_timestamp_device_ns(self.handle) };
+        if let Some(err) = take_error_if_any("failed to get message device timestamp") {
+            Err(err)
+        } else {
+            Ok(ns_to_duration(ns))
+        }
+    }
+
+    /// When the message arrived in the host queue it was taken from, on the host steady clock
+    /// like [`timestamp`](Self::timestamp); `receive - timestamp` is the capture-to-host latency:
+    ///
+    /// ```no_run
+    /// # fn f(queue: &depthai::MessageQueue) -> depthai::Result<()> {
+    /// if let Some(msg) = queue.get(None)? {
+    ///     if let Some(received) = msg.host_receive_time() {
+    ///         println!("latency: {:?}", received.saturating_sub(msg.timestamp()?));
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// `None` for messages that weren't received through a [`MessageQueue`], e.g. created on the
+    /// host.
+    pub fn host_receive_time(&self) -> Option<Duration> {
+        self.received_at
+    }
+
+    pub(crate) fn native_id(&self) -> usize {
+        unsafe { depthai::dai_datatype_get_native_id(self.handle) }
+    }
+
+    pub fn sequence_num(&self) -> Result<i64> {
+        clear_error_flag();
+        let seq = unsafe { depthai::dai_datatype_get_sequence_num(self.handle) };
+        if let Some(err) = take_error_if_any("failed to get message sequence number") {
+            Err(err)
+        } else {
+            Ok(seq)
+        }
+    }
+
     pub(crate) fn handle(&self) -> DaiDatatype {
         self.handle
     }
 }
 
+/// A message type that can be taken out of a generic [`Datatype`].
+///
+/// `from_datatype` returns `Ok(None)` when the message has a different type.
+pub trait FromDatatype: Sized {
+    fn from_datatype(msg: &Datatype) -> Result<Option<Self>>;
+}
+
+impl FromDatatype for Datatype {
+    fn from_datatype(msg: &Datatype) -> Result<Option<Self>> {
+        msg.clone_handle().map(Some)
+    }
+}
+
+/// Take `M` out of `msg`, failing if it has a different type.
+pub(crate) fn downcast<M: FromDatatype>(msg: &Datatype) -> Result<M> {
+    M::from_datatype(msg)?.ok_or_else(|| {
+        DepthaiError::new(format!(
+            "unexpected message type {:?} (wanted {})",
+            msg.datatype().ok().flatten(),
+            std::any::type_name::<M>()
+        ))
+    })
+}
+
+macro_rules! impl_from_datatype {
+    ($($ty:ty => $cast:ident),* $(,)?) => {
+        $(
+            impl FromDatatype for $ty {
+                fn from_datatype(msg: &Datatype) -> Result<Option<Self>> {
+                    msg.$cast()
+                }
+            }
+
+            impl TryFrom<Datatype> for $ty {
+                type Error = DepthaiError;
+
+                fn try_from(msg: Datatype) -> Result<Self> {
+                    downcast(&msg)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_datatype!(
+    ImageFrame => as_frame,
+    EncodedFrame => as_encoded_frame,
+    RgbdData => as_rgbd,
+    PointCloudData => as_pointcloud,
+    ImuData => as_imu_data,
+    ImgDetections => as_img_detections,
+    NNData => as_nn_data,
+    SpatialLocationCalculatorData => as_spatial_location_data,
+    Buffer => as_buffer,
+    MessageGroup => as_message_group,
+);
+
+/// What [`MessageQueue::get_typed_with`] does with a message of another type.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MismatchPolicy {
+    /// Drop it and keep waiting.
+    #[default]
+    Skip,
+    /// Fail with an error naming both types; the message is consumed.
+    Error,
+}
+
+/// Message counters of a [`MessageQueue`], see [`MessageQueue::stats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct QueueStats {
+    /// Messages that arrived at the queue since counting started (including those already
+    /// waiting then).
+    pub produced: u64,
+    /// Messages taken out through `get`/`try_get`/`get_all`/`try_get_all`.
+    pub consumed: u64,
+    /// Messages discarded because a non-blocking queue was full.
+    ///
+    /// Derived from `produced - consumed - size`, so it may lag by one message while a message
+    /// is being delivered.
+    pub dropped: u64,
+    /// Current occupancy.
+    pub size: u32,
+    pub max_size: u32,
+}
+
+type DropCallback = Box<dyn FnMut(&QueueStats) + Send>;
+
+#[derive(Default)]
+struct QueueCounters {
+    /// Set once the counting callback is registered, see [`MessageQueue::count_messages`].
+    counting: AtomicBool,
+    produced: AtomicU64,
+    consumed: AtomicU64,
+    dropped: AtomicU64,
+    on_drop: Mutex<Option<DropCallback>>,
+    /// Internal listener (pipeline events), kept apart from the user's `on_drop`.
+    drop_hook: Mutex<Option<DropCallback>>,
+    /// Host receive time of the messages in the queue, by [`Datatype::native_id`], oldest first.
+    receive_times: Mutex<VecDeque<(usize, Duration)>>,
+}
+
+impl QueueCounters {
+    /// Record the arrival of `msg` in a queue holding up to `max_size` messages.
+    fn record_receive_time(&self, msg: &Datatype, max_size: u32) {
+        let Some(received_at) = msg.received_at else {
+            return;
+        };
+        let mut times = self.receive_times.lock().unwrap_or_else(|e| e.into_inner());
+        // A full queue drops its oldest message to make room, so the oldest times go with them.
+        // One more is kept for the message being enqueued.
+        while times.len() > max_size as usize {
+            times.pop_front();
+        }
+        times.push_back((msg.native_id(), received_at));
+    }
+
+    /// Attach the receive time of `msg`, forgetting it if `msg` was taken out of the queue.
+    fn receive_time_of(&self, msg: Datatype, taken: bool) -> Datatype {
+        let id = msg.native_id();
+        let mut times = self.receive_times.lock().unwrap_or_else(|e| e.into_inner());
+        // Messages leave in arrival order: `msg` is the oldest one still queued.
+        let pos = times.iter().position(|&(i, _)| i == id);
+        let received_at = match pos {
+            Some(pos) if taken => {
+                // Anything recorded before it was dropped by a full queue.
+                times.drain(..pos);
+                times.pop_front()
+            }
+            Some(pos) => times.get(pos).copied(),
+            None => None,
+        };
+        drop(times);
+        msg.with_receive_time(received_at.map(|(_, t)| t))
+    }
+
+    /// Reconcile the drop counter against the current occupancy and notify on growth.
+    ///
+    /// `in_flight` is the number of produced messages that may not be enqueued yet.
+    fn reconcile(&self, handle: DaiDataQueue, in_flight: u64) -> QueueStats {
+        let size: u32 = unsafe { depthai::dai_queue_get_size(handle) }.into();
+        let max_size: u32 = unsafe { depthai::dai_queue_get_max_size(handle) }.into();
+        let produced = self.produced.load(Ordering::SeqCst);
+        let consumed = self.consumed.load(Ordering::SeqCst);
+        let missing = produced.saturating_sub(consumed + size as u64 + in_flight);
+        let previous = self.dropped.fetch_max(missing, Ordering::SeqCst);
+        let stats = QueueStats {
+            produced,
+            consumed,
+            dropped: previous.max(missing),
+            size,
+            max_size,
+        };
+        if missing > previous {
+            let mut guard = self.on_drop.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(cb) = guard.as_mut() {
+                let _ = catch_unwind(AssertUnwindSafe(|| cb(&stats)));
+            }
+            drop(guard);
+            let mut hook = self.drop_hook.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(cb) = hook.as_mut() {
+                let _ = catch_unwind(AssertUnwindSafe(|| cb(&stats)));
+            }
+        }
+        stats
+    }
+}
+
 struct MessageQueueInner {
     handle: DaiDataQueue,
+    counters: Arc<QueueCounters>,
+    receive_callback_id: Option<i32>,
+    counter_callback_id: OnceLock<Option<i32>>,
 }
 
 unsafe impl Send for MessageQueueInner {}
@@ -249,6 +551,11 @@ unsafe impl Sync for MessageQueueInner {}
 impl Drop for MessageQueueInner {
     fn drop(&mut self) {
         if !self.handle.is_null() {
+            let counter_callback_id = self.counter_callback_id.get().copied().flatten();
+            for id in self.receive_callback_id.into_iter().chain(counter_callback_id) {
+                clear_error_flag();
+                let _ = unsafe { depthai::dai_queue_remove_callback(self.handle, c_int(id)) };
+            }
             unsafe { depthai::dai_queue_delete(self.handle) };
             self.handle = std::ptr::null_mut();
         }
@@ -262,11 +569,99 @@ pub struct MessageQueue {
 
 impl MessageQueue {
     pub(crate) fn from_handle(handle: DaiDataQueue) -> Self {
+        let counters = Arc::new(QueueCounters::default());
+        // Stamp arrivals for `Datatype::host_receive_time`. Best-effort: without it the time is `None`.
+        let receive_callback_id = {
+            let counters = Arc::clone(&counters);
+            // The callback is removed before the queue is deleted, so the handle outlives it.
+            let raw = handle as usize;
+            register_queue_callback(handle, move |_, msg| {
+                let max_size: u32 = unsafe { depthai::dai_queue_get_max_size(raw as DaiDataQueue) }.into();
+                counters.record_receive_time(&msg, max_size);
+            })
+            .ok()
+        };
         Self {
-            inner: Arc::new(MessageQueueInner { handle }),
+            inner: Arc::new(MessageQueueInner {
+                handle,
+                counters,
+                receive_callback_id,
+                counter_callback_id: OnceLock::new(),
+            }),
         }
     }
 
+    /// Start counting arrivals for [`stats`](Self::stats) and the drop callbacks, once.
+    ///
+    /// Counting reconciles against the queue occupancy on every message, so queues nobody asks
+    /// about don't pay for it. Best-effort: if the callback can't be registered, `produced`
+    /// stops growing.
+    fn count_messages(&self) {
+        self.inner.counter_callback_id.get_or_init(|| {
+            let counters = Arc::clone(&self.inner.counters);
+            let handle = self.handle();
+            // Messages already waiting count as produced, so they aren't mistaken for drops.
+            let size: u32 = unsafe { depthai::dai_queue_get_size(handle) }.into();
+            counters.produced.store(size as u64, Ordering::SeqCst);
+            counters.counting.store(true, Ordering::SeqCst);
+            // The callback is removed before the queue is deleted, so the handle outlives it.
+            let raw = handle as usize;
+            register_queue_callback(handle, move |_, _| {
+                counters.produced.fetch_add(1, Ordering::SeqCst);
+                counters.reconcile(raw as DaiDataQueue, 1);
+            })
+            .ok()
+        });
+    }
+
+    fn record_consumed(&self, count: usize) {
+        let counters = &self.inner.counters;
+        if counters.counting.load(Ordering::SeqCst) {
+            counters.consumed.fetch_add(count as u64, Ordering::SeqCst);
+        }
+    }
+
+    fn received(&self, handle: DaiDatatype, taken: bool) -> Datatype {
+        self.inner.counters.receive_time_of(Datatype::from_handle(handle), taken)
+    }
+
+    /// Produced/consumed/dropped message counts and current occupancy.
+    ///
+    /// Counting starts with the first call to this or [`on_drop`](Self::on_drop).
+    pub fn stats(&self) -> Result<QueueStats> {
+        self.count_messages();
+        clear_error_flag();
+        let stats = self.inner.counters.reconcile(self.handle(), 0);
+        if let Some(err) = take_error_if_any("failed to get queue stats") {
+            Err(err)
+        } else {
+            Ok(stats)
+        }
+    }
+
+    /// Call `callback` whenever messages are found to have been dropped (replaces any previous one).
+    ///
+    /// Runs on the thread delivering messages to the queue, or on the thread calling [`stats`](Self::stats).
+    pub fn on_drop<F>(&self, callback: F)
+    where
+        F: FnMut(&QueueStats) + Send + 'static,
+    {
+        let mut guard = self.inner.counters.on_drop.lock().unwrap_or_else(|e| e.into_inner());
+        *guard = Some(Box::new(callback));
+        drop(guard);
+        self.count_messages();
+    }
+
+    pub(crate) fn set_drop_hook<F>(&self, hook: F)
+    where
+        F: FnMut(&QueueStats) + Send + 'static,
+    {
+        let mut guard = self.inner.counters.drop_hook.lock().unwrap_or_else(|e| e.into_inner());
+        *guard = Some(Box::new(hook));
+        drop(guard);
+        self.count_messages();
+    }
+
     pub(crate) fn handle(&self) -> DaiDataQueue {
         self.inner.handle
     }
@@ -405,7 +800,70 @@ impl MessageQueue {
                 Ok(None)
             }
         } else {
-            Ok(Some(Datatype::from_handle(msg)))
+            self.record_consumed(1);
+            Ok(Some(self.received(msg, true)))
+        }
+    }
+
+    /// [`get`](Self::get) that gives up with a [cancelled](DepthaiError::is_cancelled) error
+    /// once `token` is cancelled, see [`cancel`](crate::cancel). `None` on timeout; a zero
+    /// timeout polls the queue once, like `get(Some(Duration::ZERO))`.
+    pub fn get_cancellable(&self, timeout: Option<Duration>, token: &CancellationToken) -> Result<Option<Datatype>> {
+        token.check()?;
+        if let Some(msg) = self.try_get()? {
+            return Ok(Some(msg));
+        }
+        for slice in cancel::slices(timeout) {
+            token.check()?;
+            if let Some(msg) = self.get(Some(slice))? {
+                return Ok(Some(msg));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Wait for the next message of type `M`, skipping others; `None` on timeout.
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use depthai::camera::ImageFrame;
+    /// # use depthai::MessageQueue;
+    /// # fn f(queue: &MessageQueue) -> depthai::Result<()> {
+    /// if let Some(frame) = queue.get_typed::<ImageFrame>(Some(Duration::from_secs(1)))? {
+    ///     println!("{}x{}", frame.width(), frame.height());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_typed<M: FromDatatype>(&self, timeout: Option<Duration>) -> Result<Option<M>> {
+        self.get_typed_with(timeout, MismatchPolicy::Skip)
+    }
+
+    /// [`get_typed`](Self::get_typed) with a choice of what happens to messages of other types.
+    ///
+    /// `timeout` bounds the whole wait, including time spent on skipped messages.
+    pub fn get_typed_with<M: FromDatatype>(
+        &self,
+        timeout: Option<Duration>,
+        policy: MismatchPolicy,
+    ) -> Result<Option<M>> {
+        let deadline = timeout.map(|t| Instant::now() + t);
+        loop {
+            let remaining = deadline.map(|d| d.saturating_duration_since(Instant::now()));
+            let Some(msg) = self.get(remaining)? else {
+                return Ok(None);
+            };
+            match policy {
+                MismatchPolicy::Skip => {
+                    if let Some(typed) = M::from_datatype(&msg)? {
+                        return Ok(Some(typed));
+                    }
+                }
+                MismatchPolicy::Error => return downcast(&msg).map(Some),
+            }
+            if remaining.is_some_and(|r| r.is_zero()) {
+                return Ok(None);
+            }
         }
     }
 
@@ -419,7 +877,8 @@ impl MessageQueue {
                 Ok(None)
             }
         } else {
-            Ok(Some(Datatype::from_handle(msg)))
+            self.record_consumed(1);
+            Ok(Some(self.received(msg, true)))
         }
     }
 
@@ -433,7 +892,7 @@ impl MessageQueue {
                 Ok(None)
             }
         } else {
-            Ok(Some(Datatype::from_handle(msg)))
+            Ok(Some(self.received(msg, false)))
         }
     }
 
@@ -453,10 +912,11 @@ impl MessageQueue {
         for i in 0..len {
             let h = unsafe { depthai::dai_datatype_array_take(arr, i) };
             if !h.is_null() {
-                out.push(Datatype::from_handle(h));
+                out.push(self.received(h, true));
             }
         }
         unsafe { depthai::dai_datatype_array_free(arr) };
+        self.record_consumed(len);
         Ok(out)
     }
 
@@ -477,10 +937,11 @@ impl MessageQueue {
         for i in 0..len {
             let h = unsafe { depthai::dai_datatype_array_take(arr, i) };
             if !h.is_null() {
-                out.push(Datatype::from_handle(h));
+                out.push(self.received(h, true));
             }
         }
         unsafe { depthai::dai_datatype_array_free(arr) };
+        self.record_consumed(len);
         Ok((out, timed_out))
     }
 
@@ -518,29 +979,37 @@ impl MessageQueue {
     where
         F: FnMut(&str, Datatype) + Send + 'static,
     {
-        clear_error_flag();
+        let callback_id = register_queue_callback(self.handle(), callback)?;
+        Ok(QueueCallbackHandle {
+            queue: self.clone(),
+            callback_id,
+        })
+    }
+}
 
-        let state = Box::new(QueueCallbackState {
-            callback: Mutex::new(Box::new(callback)),
-        });
-        let ctx_state = Box::into_raw(state);
-        let ctx = ctx_state as *mut std_c_void;
+fn register_queue_callback<F>(handle: DaiDataQueue, callback: F) -> Result<i32>
+where
+    F: FnMut(&str, Datatype) + Send + 'static,
+{
+    clear_error_flag();
 
-        let cb_fn = queue_callback_trampoline as usize;
-        let drop_fn = queue_callback_drop as usize;
+    let state = Box::new(QueueCallbackState {
+        callback: Mutex::new(Box::new(callback)),
+    });
+    let ctx_state = Box::into_raw(state);
+    let ctx = ctx_state as *mut std_c_void;
 
-        let id = unsafe { depthai::dai_queue_add_callback(self.handle(), ctx as *mut autocxx_c_void, cb_fn, drop_fn) };
-        let id_i32: i32 = id.0;
+    let cb_fn = queue_callback_trampoline as usize;
+    let drop_fn = queue_callback_drop as usize;
 
-        if id_i32 < 0 {
-            unsafe { drop(Box::from_raw(ctx_state)) };
-            Err(last_error("failed to add queue callback"))
-        } else {
-            Ok(QueueCallbackHandle {
-                queue: self.clone(),
-                callback_id: id_i32,
-            })
-        }
+    let id = unsafe { depthai::dai_queue_add_callback(handle, ctx as *mut autocxx_c_void, cb_fn, drop_fn) };
+    let id_i32: i32 = id.0;
+
+    if id_i32 < 0 {
+        unsafe { drop(Box::from_raw(ctx_state)) };
+        Err(last_error("failed to add queue callback"))
+    } else {
+        Ok(id_i32)
     }
 }
 
@@ -561,7 +1030,7 @@ unsafe extern "C" fn queue_callback_trampoline(ctx: *mut std_c_void, queue_name:
 
     let state = unsafe { &*(ctx as *mut QueueCallbackState) };
 
-    let datatype = Datatype::from_handle(msg);
+    let datatype = Datatype::from_handle(msg).with_receive_time(Some(host_clock_now()));
     let _ = catch_unwind(AssertUnwindSafe(|| {
         let mut guard = match state.callback.lock() {
             Ok(g) => g,
@@ -621,4 +1090,30 @@ impl InputQueue {
             Ok(())
         }
     }
+
+    /// Send a (typically host-created) frame, e.g. into a camera's `mockIsp` input.
+    pub fn send_frame(&self, frame: &ImageFrame) -> Result<()> {
+        clear_error_flag();
+        unsafe { depthai::dai_input_queue_send_img_frame(self.handle, frame.handle()) };
+        if let Some(err) = take_error_if_any("failed to send frame to input queue") {
+            Err(err)
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn send_buffer(&self, buffer: &Buffer) -> Result<()> {
+        clear_error_flag();
+        unsafe { depthai::dai_input_queue_send_buffer(self.handle, buffer.handle()) };
+        if let Some(err) = take_error_if_any("failed to send buffer to input queue") {
+            Err(err)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Send an `ImageManip` config, e.g. through [`ImageManipNode::input_config_queue`](crate::image_manip::ImageManipNode::input_config_queue).
+    pub fn send_config(&self, config: &ImageManipConfig) -> Result<()> {
+        self.send_buffer(config.as_buffer())
+    }
 }
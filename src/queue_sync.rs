@@ -0,0 +1,224 @@
+//! Host-side matching of messages across queues by timestamp.
+//!
+//! The device `Sync` node groups messages before they leave the device; when that isn't an
+//! option (queues from different pipelines, outputs added after the fact) [`QueueSynchronizer`]
+//! does the same on the host. It reads N [`MessageQueue`]s and yields one message per queue
+//! whose device timestamps all fall within a tolerance, dropping messages that can no longer be
+//! matched and counting them per queue:
+//!
+//! ```no_run
+//! # use std::time::Duration;
+//! # use depthai::prelude::*;
+//! # use depthai::queue_sync::QueueSynchronizer;
+//! # fn main() -> Result<()> {
+//! # let pipeline = Pipeline::new().build()?;
+//! # let camera = pipeline.create_with::<CameraNode, _>(CameraBoardSocket::CamA)?;
+//! # let stereo = pipeline.create::<StereoDepthNode>()?;
+//! let rgb = camera.request_output(CameraOutputConfig::new((640, 400)))?.create_message_queue(4, false)?;
+//! let depth = stereo.depth()?.create_message_queue(4, false)?;
+//! let mut sync = QueueSynchronizer::new(&[rgb, depth], Duration::from_millis(10));
+//! pipeline.start()?;
+//! while let Some(set) = sync.next(Some(Duration::from_secs(1)))? {
+//!     let (rgb, depth) = (&set[0], &set[1]);
+//!     # let _ = (rgb, depth);
+//! }
+//! eprintln!("{:?}", sync.stats());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The matching itself lives in [`TimestampMatcher`], which works on any timestamped values.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use crate::error::Result;
+use crate::queue::{Datatype, MessageQueue};
+
+/// Default number of unmatched messages buffered per queue, see
+/// [`TimestampMatcher::set_max_pending`].
+pub const DEFAULT_MAX_PENDING: usize = 16;
+
+/// Matched and dropped message counts of a [`TimestampMatcher`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SyncStats {
+    /// Complete sets yielded.
+    pub matched: u64,
+    /// Messages dropped without a match, per stream.
+    pub dropped: Vec<u64>,
+}
+
+impl SyncStats {
+    /// Messages dropped over all streams.
+    pub fn total_dropped(&self) -> u64 {
+        self.dropped.iter().sum()
+    }
+}
+
+/// Groups timestamped values from N streams into sets within a tolerance.
+///
+/// Values must be pushed in timestamp order per stream. The oldest buffered value is dropped
+/// as soon as the newest head of another stream is more than `tolerance` after it, since
+/// nothing that arrives later can match it anymore.
+#[derive(Debug, Clone)]
+pub struct TimestampMatcher<T> {
+    tolerance: Duration,
+    max_pending: usize,
+    pending: Vec<VecDeque<(Duration, T)>>,
+    stats: SyncStats,
+}
+
+impl<T> TimestampMatcher<T> {
+    pub fn new(streams: usize, tolerance: Duration) -> Self {
+        Self {
+            tolerance,
+            max_pending: DEFAULT_MAX_PENDING,
+            pending: (0..streams).map(|_| VecDeque::new()).collect(),
+            stats: SyncStats {
+                matched: 0,
+                dropped: vec![0; streams],
+            },
+        }
+    }
+
+    pub fn streams(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn tolerance(&self) -> Duration {
+        self.tolerance
+    }
+
+    /// Unmatched values kept per stream (at least 1); older ones are dropped when a stream
+    /// runs ahead of the others.
+    pub fn set_max_pending(&mut self, max_pending: usize) {
+        self.max_pending = max_pending.max(1);
+    }
+
+    /// Buffer `value` of `stream`, captured at `timestamp`.
+    ///
+    /// # Panics
+    ///
+    /// If `stream` is out of range.
+    pub fn push(&mut self, stream: usize, timestamp: Duration, value: T) {
+        let queue = &mut self.pending[stream];
+        queue.push_back((timestamp, value));
+        while queue.len() > self.max_pending {
+            queue.pop_front();
+            self.stats.dropped[stream] += 1;
+        }
+    }
+
+    /// Next complete set, one value per stream in stream order, if one is buffered.
+    pub fn pop(&mut self) -> Option<Vec<T>> {
+        if self.pending.is_empty() {
+            return None;
+        }
+        loop {
+            let heads = self.pending.iter().map(|q| q.front().map(|(ts, _)| *ts)).collect::<Option<Vec<_>>>()?;
+            let newest = *heads.iter().max()?;
+            let (oldest_stream, oldest) = heads.iter().enumerate().min_by_key(|(_, ts)| **ts)?;
+            if newest - *oldest <= self.tolerance {
+                self.stats.matched += 1;
+                return Some(self.pending.iter_mut().filter_map(|q| q.pop_front().map(|(_, v)| v)).collect());
+            }
+            self.pending[oldest_stream].pop_front();
+            self.stats.dropped[oldest_stream] += 1;
+        }
+    }
+
+    /// Buffered, unmatched values per stream.
+    pub fn pending(&self) -> Vec<usize> {
+        self.pending.iter().map(VecDeque::len).collect()
+    }
+
+    /// Streams with nothing buffered, which a complete set is waiting on.
+    pub fn waiting_on(&self) -> impl Iterator<Item = usize> + '_ {
+        self.pending.iter().enumerate().filter(|(_, q)| q.is_empty()).map(|(i, _)| i)
+    }
+
+    pub fn stats(&self) -> &SyncStats {
+        &self.stats
+    }
+
+    /// Drop everything buffered, without counting it as dropped.
+    pub fn clear(&mut self) {
+        self.pending.iter_mut().for_each(VecDeque::clear);
+    }
+}
+
+/// Yields sets of messages, one per queue, whose device timestamps are within a tolerance. See
+/// the [module docs](self).
+pub struct QueueSynchronizer {
+    queues: Vec<MessageQueue>,
+    matcher: TimestampMatcher<Datatype>,
+}
+
+impl QueueSynchronizer {
+    /// Match messages of `queues` (clones of the handles are kept) at most `tolerance` apart.
+    pub fn new(queues: &[MessageQueue], tolerance: Duration) -> Self {
+        Self {
+            queues: queues.to_vec(),
+            matcher: TimestampMatcher::new(queues.len(), tolerance),
+        }
+    }
+
+    /// See [`TimestampMatcher::set_max_pending`].
+    pub fn with_max_pending(mut self, max_pending: usize) -> Self {
+        self.matcher.set_max_pending(max_pending);
+        self
+    }
+
+    fn push(&mut self, stream: usize, msg: Datatype) -> Result<()> {
+        let timestamp = msg.timestamp_device()?;
+        self.matcher.push(stream, timestamp, msg);
+        Ok(())
+    }
+
+    /// Read whatever the queues hold and return the next complete set, without waiting.
+    pub fn try_next(&mut self) -> Result<Option<Vec<Datatype>>> {
+        for stream in 0..self.queues.len() {
+            for msg in self.queues[stream].try_get_all()? {
+                self.push(stream, msg)?;
+            }
+        }
+        Ok(self.matcher.pop())
+    }
+
+    /// Wait up to `timeout` (forever with `None`) for the next complete set.
+    ///
+    /// Returns `Ok(None)` on timeout; messages read so far stay buffered for the next call.
+    pub fn next(&mut self, timeout: Option<Duration>) -> Result<Option<Vec<Datatype>>> {
+        let deadline = timeout.map(|t| Instant::now() + t);
+        loop {
+            if let Some(set) = self.try_next()? {
+                return Ok(Some(set));
+            }
+            let Some(stream) = self.matcher.waiting_on().next() else {
+                // Every stream has something buffered, yet nothing matched: the pops above
+                // dropped until one stream ran empty, so this only happens with no queues.
+                return Ok(None);
+            };
+            let remaining = match deadline {
+                Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                    Some(remaining) if !remaining.is_zero() => Some(remaining),
+                    _ => return Ok(None),
+                },
+                None => None,
+            };
+            match self.queues[stream].get(remaining)? {
+                Some(msg) => self.push(stream, msg)?,
+                None => return Ok(None),
+            }
+        }
+    }
+
+    /// Sets yielded and messages dropped per queue so far.
+    pub fn stats(&self) -> &SyncStats {
+        self.matcher.stats()
+    }
+
+    pub fn tolerance(&self) -> Duration {
+        self.matcher.tolerance()
+    }
+}
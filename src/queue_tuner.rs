@@ -0,0 +1,266 @@
+//! Recommending queue sizes from observed traffic.
+//!
+//! `create_message_queue(2, false)` is a fine default for a preview window, but it drops frames
+//! for a recorder that needs every one of them and adds latency to a tracker that only wants the
+//! freshest. [`QueueAutoTuner`] watches a set of queues for a warmup period, then recommends a
+//! `max_size`/blocking setting for each based on how full they ran and how many messages they
+//! dropped, and optionally applies it:
+//!
+//! ```no_run
+//! # use std::time::Duration;
+//! # use depthai::prelude::*;
+//! # use depthai::queue_tuner::{QueueAutoTuner, QueueAutoTunerConfig, QueueGoal};
+//! # fn main() -> Result<()> {
+//! # let pipeline = Pipeline::new().build()?;
+//! # let camera = pipeline.create_with::<CameraNode, _>(CameraBoardSocket::CamA)?;
+//! let queue = camera.request_output(CameraOutputConfig::new((1280, 720)))?.create_message_queue(2, false)?;
+//! let mut tuner = QueueAutoTuner::new(QueueAutoTunerConfig { apply: true, ..Default::default() });
+//! tuner.watch("rgb", &queue, QueueGoal::Lossless);
+//! pipeline.start()?;
+//! while let Some(msg) = queue.get(Some(Duration::from_secs(1)))? {
+//!     if let Some(recommendations) = tuner.poll()? {
+//!         for r in recommendations.iter().filter(|r| r.changes()) {
+//!             eprintln!("{}: max_size {} blocking {} ({})", r.name, r.max_size, r.blocking, r.reason);
+//!         }
+//!     }
+//! #   let _ = msg;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Occupancy is sampled whenever [`QueueAutoTuner::sample`] (or [`poll`](QueueAutoTuner::poll))
+//! is called, so call it from the loop that reads the queues.
+
+use std::time::{Duration, Instant};
+
+use crate::error::Result;
+use crate::queue::MessageQueue;
+
+/// What a watched queue is for, which decides how drops and occupancy are traded off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QueueGoal {
+    /// Only the freshest message matters (previews, tracking): dropping stale messages is fine,
+    /// waiting behind them is not.
+    LowLatency,
+    /// Every message matters (recording, offline processing): the queue may grow and block.
+    Lossless,
+}
+
+/// Settings of a [`QueueAutoTuner`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueueAutoTunerConfig {
+    /// How long to observe before recommending.
+    pub warmup: Duration,
+    /// Largest `max_size` that will be recommended.
+    pub max_size_limit: u32,
+    /// Apply the recommendations to the queues instead of only reporting them.
+    pub apply: bool,
+}
+
+impl Default for QueueAutoTunerConfig {
+    fn default() -> Self {
+        Self {
+            warmup: Duration::from_secs(5),
+            max_size_limit: 30,
+            apply: false,
+        }
+    }
+}
+
+/// Traffic of one queue over the warmup period.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct QueueObservation {
+    /// Messages that arrived during the observation.
+    pub produced: u64,
+    /// Messages dropped during the observation.
+    pub dropped: u64,
+    /// Occupancy samples taken.
+    pub samples: u64,
+    pub mean_occupancy: f32,
+    pub peak_occupancy: u32,
+    pub max_size: u32,
+    pub blocking: bool,
+}
+
+impl QueueObservation {
+    /// Share of produced messages that were dropped.
+    pub fn drop_rate(&self) -> f32 {
+        if self.produced == 0 {
+            0.0
+        } else {
+            self.dropped as f32 / self.produced as f32
+        }
+    }
+
+    fn runs_full(&self) -> bool {
+        self.mean_occupancy >= 0.8 * self.max_size as f32
+    }
+
+    /// Recommended `(max_size, blocking, reason)` for `goal`, never above `max_size_limit`.
+    pub fn recommend(&self, goal: QueueGoal, max_size_limit: u32) -> (u32, bool, &'static str) {
+        let limit = max_size_limit.max(1);
+        let grown = (self.max_size.max(1) * 2).min(limit);
+        match goal {
+            QueueGoal::LowLatency => {
+                if self.runs_full() {
+                    (1, false, "consumer is slower than the producer; a deeper queue only adds latency")
+                } else if self.dropped > 0 && self.drop_rate() > 0.05 {
+                    (grown, false, "consumer reads in bursts; a little headroom avoids drops")
+                } else if self.dropped == 0 && self.peak_occupancy + 1 < self.max_size {
+                    (self.peak_occupancy + 1, false, "queue never fills; a smaller one keeps messages fresh")
+                } else {
+                    (self.max_size, false, "keep")
+                }
+            }
+            QueueGoal::Lossless => {
+                if self.dropped > 0 && !self.blocking {
+                    (grown.max(self.max_size), true, "messages were dropped; block instead")
+                } else if self.blocking && self.runs_full() {
+                    (grown.max(self.max_size), true, "queue runs full and back-pressures the pipeline")
+                } else {
+                    (self.max_size, true, "keep")
+                }
+            }
+        }
+    }
+}
+
+/// Recommended settings for one watched queue, see [`QueueAutoTuner::recommendations`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueueRecommendation {
+    /// Name given to [`QueueAutoTuner::watch`].
+    pub name: String,
+    pub goal: QueueGoal,
+    pub observation: QueueObservation,
+    pub max_size: u32,
+    pub blocking: bool,
+    /// Why, in a few words.
+    pub reason: &'static str,
+}
+
+impl QueueRecommendation {
+    /// Whether the recommendation differs from the observed settings.
+    pub fn changes(&self) -> bool {
+        self.max_size != self.observation.max_size || self.blocking != self.observation.blocking
+    }
+}
+
+struct WatchedQueue {
+    name: String,
+    queue: MessageQueue,
+    goal: QueueGoal,
+    start_produced: Option<u64>,
+    start_dropped: u64,
+    observation: QueueObservation,
+    occupancy_sum: u64,
+}
+
+/// Observes queues and recommends `max_size`/blocking settings for them, see the
+/// [module docs](self).
+pub struct QueueAutoTuner {
+    config: QueueAutoTunerConfig,
+    queues: Vec<WatchedQueue>,
+    started: Option<Instant>,
+    done: bool,
+}
+
+impl QueueAutoTuner {
+    pub fn new(config: QueueAutoTunerConfig) -> Self {
+        Self {
+            config,
+            queues: Vec::new(),
+            started: None,
+            done: false,
+        }
+    }
+
+    /// Observe `queue` under `name` (a clone of the handle is kept).
+    pub fn watch(&mut self, name: impl Into<String>, queue: &MessageQueue, goal: QueueGoal) {
+        self.queues.push(WatchedQueue {
+            name: name.into(),
+            queue: queue.clone(),
+            goal,
+            start_produced: None,
+            start_dropped: 0,
+            observation: QueueObservation::default(),
+            occupancy_sum: 0,
+        });
+    }
+
+    /// Record the counters and occupancy of every watched queue; the warmup starts at the first call.
+    pub fn sample(&mut self) -> Result<()> {
+        self.started.get_or_insert_with(Instant::now);
+        for watched in &mut self.queues {
+            let stats = watched.queue.stats()?;
+            let blocking = watched.queue.blocking()?;
+            let start_produced = *watched.start_produced.get_or_insert_with(|| {
+                watched.start_dropped = stats.dropped;
+                stats.produced
+            });
+            watched.occupancy_sum += stats.size as u64;
+            let obs = &mut watched.observation;
+            obs.samples += 1;
+            obs.produced = stats.produced - start_produced;
+            obs.dropped = stats.dropped - watched.start_dropped;
+            obs.mean_occupancy = watched.occupancy_sum as f32 / obs.samples as f32;
+            obs.peak_occupancy = obs.peak_occupancy.max(stats.size);
+            obs.max_size = stats.max_size;
+            obs.blocking = blocking;
+        }
+        Ok(())
+    }
+
+    /// Whether the warmup period has elapsed.
+    pub fn is_warmed_up(&self) -> bool {
+        self.started.is_some_and(|t| t.elapsed() >= self.config.warmup)
+    }
+
+    /// Recommendations from what was observed so far.
+    pub fn recommendations(&self) -> Vec<QueueRecommendation> {
+        self.queues
+            .iter()
+            .map(|w| {
+                let (max_size, blocking, reason) = w.observation.recommend(w.goal, self.config.max_size_limit);
+                QueueRecommendation {
+                    name: w.name.clone(),
+                    goal: w.goal,
+                    observation: w.observation,
+                    max_size,
+                    blocking,
+                    reason,
+                }
+            })
+            .collect()
+    }
+
+    /// Set the recommended `max_size`/blocking on the watched queues.
+    pub fn apply(&self, recommendations: &[QueueRecommendation]) -> Result<()> {
+        for r in recommendations.iter().filter(|r| r.changes()) {
+            if let Some(w) = self.queues.iter().find(|w| w.name == r.name) {
+                w.queue.set_max_size(r.max_size)?;
+                w.queue.set_blocking(r.blocking)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// [`sample`](Self::sample), and once the warmup has elapsed return the recommendations
+    /// (applied if [`QueueAutoTunerConfig::apply`] is set). Returns `Some` only once; later calls
+    /// do nothing.
+    pub fn poll(&mut self) -> Result<Option<Vec<QueueRecommendation>>> {
+        if self.done {
+            return Ok(None);
+        }
+        self.sample()?;
+        if !self.is_warmed_up() {
+            return Ok(None);
+        }
+        self.done = true;
+        let recommendations = self.recommendations();
+        if self.config.apply {
+            self.apply(&recommendations)?;
+        }
+        Ok(Some(recommendations))
+    }
+}
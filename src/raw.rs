@@ -0,0 +1,173 @@
+//! Unprocessed sensor (Bayer) frames.
+//!
+//! A [`CameraNode`](crate::camera::CameraNode)'s `raw` output carries the sensor data before the
+//! ISP, usually MIPI-packed `RAW10` or `RAW12` depending on the sensor. [`RawImage`] unpacks it
+//! into one `u16` per pixel, at the sensor's bit depth; with the `demosaic` feature it can also
+//! be turned into RGB with a simple bilinear demosaic:
+//!
+//! ```no_run
+//! # use std::time::Duration;
+//! # use depthai::camera::CameraNode;
+//! # use depthai::common::CameraBoardSocket;
+//! # use depthai::raw::RawImage;
+//! # use depthai::{Pipeline, Result};
+//! # fn main() -> Result<()> {
+//! let pipeline = Pipeline::new().build()?;
+//! let camera = pipeline.create_with::<CameraNode, _>(CameraBoardSocket::CamA)?;
+//! let raw = camera.raw()?.create_queue(4, false)?;
+//! pipeline.start()?;
+//! if let Some(frame) = raw.blocking_next(Some(Duration::from_secs(1)))? {
+//!     let image = RawImage::new(&frame)?;
+//!     println!("{}x{} at {} bits", image.width(), image.height(), image.bit_depth());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The color filter layout isn't part of the frame: look it up for the sensor (most OAK color
+//! sensors are [`BayerPattern::Rggb`] at full resolution).
+
+use crate::camera::ImageFrame;
+use crate::convert;
+use crate::error::{DepthaiError, Result};
+
+/// Color filter layout of a sensor, named after its top-left 2x2 block.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BayerPattern {
+    #[default]
+    Rggb,
+    Bggr,
+    Grbg,
+    Gbrg,
+}
+
+impl BayerPattern {
+    /// Channel (0 = R, 1 = G, 2 = B) sampled at `(x, y)`.
+    pub fn channel_at(self, x: usize, y: usize) -> usize {
+        let layout = match self {
+            BayerPattern::Rggb => [0, 1, 1, 2],
+            BayerPattern::Bggr => [2, 1, 1, 0],
+            BayerPattern::Grbg => [1, 0, 2, 1],
+            BayerPattern::Gbrg => [1, 2, 0, 1],
+        };
+        layout[(y & 1) * 2 + (x & 1)]
+    }
+}
+
+/// Unpacked sensor values of a raw frame, row-major.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawImage {
+    width: u32,
+    height: u32,
+    bit_depth: u32,
+    values: Vec<u16>,
+}
+
+impl RawImage {
+    /// Unpack a `RAW8`, `RAW10`, `RAW12`, `RAW14` or `RAW16` frame, see [`convert::unpack_raw`].
+    pub fn new(frame: &ImageFrame) -> Result<Self> {
+        let bit_depth = frame
+            .format()
+            .and_then(convert::raw_bit_depth)
+            .ok_or_else(|| DepthaiError::new(format!("{:?} frames are not raw sensor frames", frame.format())))?;
+        Self::from_raw(frame.width(), frame.height(), bit_depth, convert::unpack_raw(frame)?)
+    }
+
+    /// Wrap already unpacked values of `bit_depth` bits (8 to 16).
+    pub fn from_raw(width: u32, height: u32, bit_depth: u32, mut values: Vec<u16>) -> Result<Self> {
+        if !(8..=16).contains(&bit_depth) {
+            return Err(DepthaiError::new(format!("raw bit depth must be 8 to 16, got {bit_depth}")));
+        }
+        let pixels = width as usize * height as usize;
+        if values.len() < pixels {
+            return Err(DepthaiError::new(format!(
+                "raw image of {width}x{height} needs {pixels} values, got {}",
+                values.len()
+            )));
+        }
+        values.truncate(pixels);
+        Ok(Self { width, height, bit_depth, values })
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Significant bits per value, e.g. 10 for `RAW10`.
+    pub fn bit_depth(&self) -> u32 {
+        self.bit_depth
+    }
+
+    pub fn values(&self) -> &[u16] {
+        &self.values
+    }
+
+    pub fn into_values(self) -> Vec<u16> {
+        self.values
+    }
+
+    /// Value at `(x, y)`, `None` outside the image.
+    pub fn at(&self, x: u32, y: u32) -> Option<u16> {
+        (x < self.width && y < self.height).then(|| self.values[(y * self.width + x) as usize])
+    }
+
+    /// Interleaved RGB at the image's bit depth, each missing channel the average of its nearest
+    /// samples (bilinear demosaic). Edges mirror the adjacent row or column.
+    ///
+    /// No white balance or color correction is applied, so images look green and dark next to
+    /// the ISP output.
+    #[cfg(feature = "demosaic")]
+    pub fn demosaic(&self, pattern: BayerPattern) -> Result<Vec<u16>> {
+        let (w, h) = (self.width as usize, self.height as usize);
+        if w < 2 || h < 2 {
+            return Err(DepthaiError::new(format!("cannot demosaic a {w}x{h} image, need at least 2x2")));
+        }
+        // Mirror at the edges so neighbours keep the Bayer parity of the missing side.
+        let mirror = |i: isize, n: usize| -> usize {
+            if i < 0 {
+                1
+            } else if i as usize >= n {
+                n - 2
+            } else {
+                i as usize
+            }
+        };
+        let at = |x: isize, y: isize| self.values[mirror(y, h) * w + mirror(x, w)] as u32;
+        let mut rgb = vec![0u16; w * h * 3];
+        for y in 0..h {
+            for x in 0..w {
+                let (xi, yi) = (x as isize, y as isize);
+                let here = pattern.channel_at(x, y);
+                let out = &mut rgb[(y * w + x) * 3..][..3];
+                out[here] = at(xi, yi) as u16;
+                let cross = (at(xi - 1, yi) + at(xi + 1, yi) + at(xi, yi - 1) + at(xi, yi + 1)) / 4;
+                let diagonal =
+                    (at(xi - 1, yi - 1) + at(xi + 1, yi - 1) + at(xi - 1, yi + 1) + at(xi + 1, yi + 1)) / 4;
+                let horizontal = (at(xi - 1, yi) + at(xi + 1, yi)) / 2;
+                let vertical = (at(xi, yi - 1) + at(xi, yi + 1)) / 2;
+                if here == 1 {
+                    // Green site: red and blue are on the row and column, which one depends on
+                    // the row.
+                    let row_channel = pattern.channel_at(x + 1, y);
+                    out[row_channel] = horizontal as u16;
+                    out[2 - row_channel] = vertical as u16;
+                } else {
+                    out[1] = cross as u16;
+                    out[2 - here] = diagonal as u16;
+                }
+            }
+        }
+        Ok(rgb)
+    }
+
+    /// [`demosaic`](Self::demosaic) scaled down to 8 bits, e.g. for a preview.
+    #[cfg(feature = "demosaic")]
+    pub fn demosaic_rgb888(&self, pattern: BayerPattern) -> Result<Vec<u8>> {
+        let shift = self.bit_depth - 8;
+        Ok(self.demosaic(pattern)?.into_iter().map(|v| (v >> shift).min(255) as u8).collect())
+    }
+}
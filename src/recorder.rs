@@ -0,0 +1,523 @@
+//! Headless RGB-D capture to disk.
+//!
+//! [`DatasetRecorder`] pairs color and depth frames by timestamp and writes them in the TUM RGB-D
+//! layout most SLAM and reconstruction tools read:
+//!
+//! ```text
+//! <root>/rgb/<timestamp>.png       color (PNG or JPEG)
+//! <root>/depth/<timestamp>.png     16-bit depth, `depth_scale` units per meter
+//! <root>/rgb.txt, depth.txt        "<timestamp> <file>" indexes
+//! <root>/associations.txt          "<t_rgb> <rgb file> <t_depth> <depth file>"
+//! <root>/calibration.json          device EEPROM calibration, when available
+//! <root>/meta.json                 format, scale, intrinsics and final counters
+//! ```
+//!
+//! Timestamps are host steady-clock seconds. Encoding and disk I/O run on a writer thread behind
+//! a bounded queue; pairs that don't fit are dropped and counted, so a slow disk never stalls the
+//! pipeline.
+//!
+//! ```no_run
+//! # use depthai::{DepthUnit, Pipeline, Result};
+//! # use depthai::recorder::{DatasetRecorder, DatasetRecorderConfig};
+//! # fn main() -> Result<()> {
+//! # let pipeline = Pipeline::new().build()?;
+//! # let color_out = pipeline.create_node("dai::node::Camera")?.output("raw")?;
+//! # let depth_out = pipeline.create_node("dai::node::StereoDepth")?.output("depth")?;
+//! let recorder = pipeline.create_with::<DatasetRecorder, _>(DatasetRecorderConfig {
+//!     rate_hz: Some(10.0),
+//!     ..DatasetRecorderConfig::new("capture", DepthUnit::Millimeter)
+//! })?;
+//! color_out.link(&recorder.color()?)?;
+//! depth_out.link(&recorder.depth()?)?;
+//! pipeline.start()?;
+//! // ...
+//! let stats = recorder.stats();
+//! println!("written={} dropped={}", stats.written, stats.dropped_queue_full);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use serde_json::json;
+
+use crate::calibration::{CalibrationHandler, CameraIntrinsics};
+use crate::camera::ImageFrame;
+use crate::convert;
+use crate::depth::DepthFrame;
+use crate::error::{DepthaiError, Result};
+use crate::output::Input;
+use crate::pipeline::{Node, Pipeline};
+use crate::rgbd::DepthUnit;
+use crate::sink::{create_sink_host_node, FrameSink, SinkContext, SinkHostNode, SinkInput, SinkInputKind};
+use crate::CreateInPipelineWith;
+
+/// How color frames are encoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorImageFormat {
+    #[default]
+    Png,
+    /// JPEG with quality `1..=100`.
+    Jpeg(u8),
+}
+
+impl ColorImageFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            ColorImageFormat::Png => "png",
+            ColorImageFormat::Jpeg(_) => "jpg",
+        }
+    }
+}
+
+pub struct DatasetRecorderConfig {
+    /// Dataset directory; created if missing.
+    pub root: PathBuf,
+    /// Unit of the incoming depth frames.
+    pub depth_unit: DepthUnit,
+    /// Units per meter of the written depth PNGs: 1000 writes millimeters, TUM uses 5000.
+    /// Ignored for [`DepthUnit::Custom`], whose values are written unchanged.
+    pub depth_scale: f32,
+    pub color_format: ColorImageFormat,
+    /// Maximum pairs per second; `None` records every pair.
+    pub rate_hz: Option<f32>,
+    /// Largest color/depth timestamp difference still considered the same instant.
+    pub max_sync_delta: Duration,
+    /// Pairs waiting for the writer thread before new ones are dropped.
+    pub queue_size: usize,
+    /// Written to `calibration.json` and used for the intrinsics in `meta.json`.
+    pub calibration: Option<CalibrationHandler>,
+    /// Color camera intrinsics for `meta.json`, e.g. from [`CalibrationHandler::camera_intrinsics`].
+    pub intrinsics: Option<CameraIntrinsics>,
+    pub color_input_name: String,
+    pub depth_input_name: String,
+}
+
+impl DatasetRecorderConfig {
+    pub fn new(root: impl Into<PathBuf>, depth_unit: DepthUnit) -> Self {
+        Self {
+            root: root.into(),
+            depth_unit,
+            depth_scale: 1000.0,
+            color_format: ColorImageFormat::Png,
+            rate_hz: None,
+            max_sync_delta: Duration::from_millis(20),
+            queue_size: 16,
+            calibration: None,
+            intrinsics: None,
+            color_input_name: "color".to_string(),
+            depth_input_name: "depth".to_string(),
+        }
+    }
+}
+
+/// Counters of a [`DatasetRecorder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RecorderStats {
+    /// Pairs written to disk.
+    pub written: u64,
+    /// Pairs dropped because the writer queue was full.
+    pub dropped_queue_full: u64,
+    /// Frames that never found a partner within `max_sync_delta`.
+    pub dropped_unpaired: u64,
+    /// Frames skipped to honor `rate_hz` (not an error).
+    pub skipped_rate: u64,
+    /// Pairs that failed to convert, encode or write.
+    pub errors: u64,
+}
+
+#[derive(Default)]
+struct SharedStats {
+    written: AtomicU64,
+    dropped_queue_full: AtomicU64,
+    dropped_unpaired: AtomicU64,
+    skipped_rate: AtomicU64,
+    errors: AtomicU64,
+}
+
+impl SharedStats {
+    fn snapshot(&self) -> RecorderStats {
+        RecorderStats {
+            written: self.written.load(Ordering::Relaxed),
+            dropped_queue_full: self.dropped_queue_full.load(Ordering::Relaxed),
+            dropped_unpaired: self.dropped_unpaired.load(Ordering::Relaxed),
+            skipped_rate: self.skipped_rate.load(Ordering::Relaxed),
+            errors: self.errors.load(Ordering::Relaxed),
+        }
+    }
+
+    fn bump(counter: &AtomicU64) {
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+struct ColorImage {
+    timestamp: Duration,
+    width: u32,
+    height: u32,
+    rgb: Vec<u8>,
+}
+
+struct DepthImage {
+    timestamp: Duration,
+    width: u32,
+    height: u32,
+    values: Vec<u16>,
+}
+
+struct Pair {
+    color: ColorImage,
+    depth: DepthImage,
+}
+
+struct RecorderSink {
+    depth_scale: Option<f32>,
+    max_sync_delta: Duration,
+    min_interval: Option<Duration>,
+    next_due: Duration,
+    pending_color: Option<ColorImage>,
+    pending_depth: Option<DepthImage>,
+    tx: Option<SyncSender<Pair>>,
+    writer: Option<JoinHandle<()>>,
+    stats: Arc<SharedStats>,
+}
+
+impl RecorderSink {
+    fn due(&mut self, timestamp: Duration) -> bool {
+        if timestamp + self.max_sync_delta >= self.next_due {
+            return true;
+        }
+        SharedStats::bump(&self.stats.skipped_rate);
+        false
+    }
+
+    fn on_color(&mut self, frame: &ImageFrame) -> Result<()> {
+        let timestamp = frame.timestamp()?;
+        if !self.due(timestamp) {
+            return Ok(());
+        }
+        let color = ColorImage {
+            timestamp,
+            width: frame.width(),
+            height: frame.height(),
+            rgb: convert::to_rgb888i(frame)?,
+        };
+        self.drop_stale_depth(timestamp);
+        match self.pending_depth.take() {
+            Some(depth) if depth.timestamp.abs_diff(timestamp) <= self.max_sync_delta => self.submit(color, depth),
+            depth => {
+                self.pending_depth = depth;
+                if self.pending_color.replace(color).is_some() {
+                    SharedStats::bump(&self.stats.dropped_unpaired);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn on_depth(&mut self, frame: &ImageFrame, unit: DepthUnit) -> Result<()> {
+        let timestamp = frame.timestamp()?;
+        if !self.due(timestamp) {
+            return Ok(());
+        }
+        let depth = DepthFrame::new(frame, unit)?;
+        let values = match (self.depth_scale, unit.meters_per_unit()) {
+            (Some(scale), Some(meters)) => {
+                let factor = meters * scale;
+                depth.values().iter().map(|&v| (v as f32 * factor).round().min(u16::MAX as f32) as u16).collect()
+            }
+            _ => depth.values().to_vec(),
+        };
+        let depth = DepthImage {
+            timestamp,
+            width: depth.width(),
+            height: depth.height(),
+            values,
+        };
+        self.drop_stale_color(timestamp);
+        match self.pending_color.take() {
+            Some(color) if color.timestamp.abs_diff(timestamp) <= self.max_sync_delta => self.submit(color, depth),
+            color => {
+                self.pending_color = color;
+                if self.pending_depth.replace(depth).is_some() {
+                    SharedStats::bump(&self.stats.dropped_unpaired);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Frames arrive in order per stream, so a pending frame older than `timestamp - delta` can no
+    // longer be matched.
+    fn drop_stale_color(&mut self, timestamp: Duration) {
+        if self
+            .pending_color
+            .as_ref()
+            .is_some_and(|c| c.timestamp + self.max_sync_delta < timestamp)
+        {
+            self.pending_color = None;
+            SharedStats::bump(&self.stats.dropped_unpaired);
+        }
+    }
+
+    fn drop_stale_depth(&mut self, timestamp: Duration) {
+        if self
+            .pending_depth
+            .as_ref()
+            .is_some_and(|d| d.timestamp + self.max_sync_delta < timestamp)
+        {
+            self.pending_depth = None;
+            SharedStats::bump(&self.stats.dropped_unpaired);
+        }
+    }
+
+    fn submit(&mut self, color: ColorImage, depth: DepthImage) {
+        if let Some(interval) = self.min_interval {
+            self.next_due = color.timestamp + interval;
+        }
+        let Some(tx) = self.tx.as_ref() else {
+            return;
+        };
+        match tx.try_send(Pair { color, depth }) {
+            Ok(()) => {}
+            Err(TrySendError::Full(_)) => SharedStats::bump(&self.stats.dropped_queue_full),
+            Err(TrySendError::Disconnected(_)) => SharedStats::bump(&self.stats.errors),
+        }
+    }
+}
+
+impl FrameSink for RecorderSink {
+    fn on_frame(&mut self, ctx: &SinkContext<'_>, frame: &ImageFrame) -> Result<()> {
+        match ctx.input.kind {
+            SinkInputKind::Depth(unit) => self.on_depth(frame, unit),
+            _ => self.on_color(frame),
+        }
+    }
+
+    fn on_stop(&mut self) {
+        self.close();
+    }
+}
+
+impl RecorderSink {
+    // Closing the channel lets the writer drain the queue, write meta.json and exit.
+    fn close(&mut self) {
+        self.tx = None;
+        if let Some(writer) = self.writer.take() {
+            let _ = writer.join();
+        }
+    }
+}
+
+impl Drop for RecorderSink {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+fn io_err(path: &Path, err: impl std::fmt::Display) -> DepthaiError {
+    DepthaiError::new(format!("recorder: {}: {err}", path.display()))
+}
+
+struct DatasetWriter {
+    root: PathBuf,
+    color_format: ColorImageFormat,
+    rgb_index: BufWriter<File>,
+    depth_index: BufWriter<File>,
+    associations: BufWriter<File>,
+    meta: serde_json::Value,
+    stats: Arc<SharedStats>,
+}
+
+impl DatasetWriter {
+    fn create(config: &DatasetRecorderConfig, stats: Arc<SharedStats>) -> Result<Self> {
+        let root = config.root.clone();
+        for dir in [root.join("rgb"), root.join("depth")] {
+            fs::create_dir_all(&dir).map_err(|e| io_err(&dir, e))?;
+        }
+        let index = |name: &str, header: &str| -> Result<BufWriter<File>> {
+            let path = root.join(name);
+            let mut file = BufWriter::new(File::create(&path).map_err(|e| io_err(&path, e))?);
+            writeln!(file, "{header}").map_err(|e| io_err(&path, e))?;
+            Ok(file)
+        };
+        let rgb_index = index("rgb.txt", "# color images\n# timestamp filename")?;
+        let depth_index = index("depth.txt", "# depth maps\n# timestamp filename")?;
+        let associations = index("associations.txt", "# timestamp_rgb rgb_file timestamp_depth depth_file")?;
+
+        if let Some(calibration) = &config.calibration {
+            let path = root.join("calibration.json");
+            let text = serde_json::to_string_pretty(calibration.eeprom_json()).map_err(|e| io_err(&path, e))?;
+            fs::write(&path, text).map_err(|e| io_err(&path, e))?;
+        }
+
+        let depth_scale = match config.depth_unit {
+            DepthUnit::Custom => None,
+            _ => Some(config.depth_scale),
+        };
+        let meta = json!({
+            "layout": "tum",
+            "color_format": config.color_format.extension(),
+            "depth_scale": depth_scale,
+            "rate_hz": config.rate_hz,
+            "max_sync_delta_s": config.max_sync_delta.as_secs_f64(),
+            "intrinsics": config.intrinsics.map(|k| json!({ "fx": k.fx, "fy": k.fy, "cx": k.cx, "cy": k.cy })),
+        });
+
+        Ok(Self {
+            root,
+            color_format: config.color_format,
+            rgb_index,
+            depth_index,
+            associations,
+            meta,
+            stats,
+        })
+    }
+
+    fn run(mut self, rx: Receiver<Pair>) {
+        let mut last_error_note: Option<std::time::Instant> = None;
+        for pair in rx {
+            match self.write_pair(pair) {
+                Ok(()) => SharedStats::bump(&self.stats.written),
+                Err(err) => {
+                    SharedStats::bump(&self.stats.errors);
+                    if last_error_note.is_none_or(|t| t.elapsed() >= Duration::from_secs(2)) {
+                        eprintln!("{err}");
+                        last_error_note = Some(std::time::Instant::now());
+                    }
+                }
+            }
+        }
+        if let Err(err) = self.finish() {
+            eprintln!("{err}");
+        }
+    }
+
+    fn write_pair(&mut self, pair: Pair) -> Result<()> {
+        let t_rgb = format!("{:.6}", pair.color.timestamp.as_secs_f64());
+        let t_depth = format!("{:.6}", pair.depth.timestamp.as_secs_f64());
+        let rgb_file = format!("rgb/{t_rgb}.{}", self.color_format.extension());
+        let depth_file = format!("depth/{t_depth}.png");
+
+        let path = self.root.join(&rgb_file);
+        let color = pair.color;
+        let rgb = image::RgbImage::from_raw(color.width, color.height, color.rgb)
+            .ok_or_else(|| io_err(&path, "color buffer does not match its size"))?;
+        match self.color_format {
+            ColorImageFormat::Png => rgb.save_with_format(&path, image::ImageFormat::Png),
+            ColorImageFormat::Jpeg(quality) => File::create(&path)
+                .map_err(image::ImageError::IoError)
+                .and_then(|file| {
+                    let mut encoder = image::codecs::jpeg::JpegEncoder::new_with_quality(BufWriter::new(file), quality.clamp(1, 100));
+                    encoder.encode_image(&rgb)
+                }),
+        }
+        .map_err(|e| io_err(&path, e))?;
+
+        let path = self.root.join(&depth_file);
+        let depth = pair.depth;
+        let gray = image::ImageBuffer::<image::Luma<u16>, _>::from_raw(depth.width, depth.height, depth.values)
+            .ok_or_else(|| io_err(&path, "depth buffer does not match its size"))?;
+        gray.save_with_format(&path, image::ImageFormat::Png).map_err(|e| io_err(&path, e))?;
+
+        let root = self.root.clone();
+        let line = |file: &mut BufWriter<File>, text: String| -> Result<()> {
+            writeln!(file, "{text}").and_then(|_| file.flush()).map_err(|e| io_err(&root, e))
+        };
+        line(&mut self.rgb_index, format!("{t_rgb} {rgb_file}"))?;
+        line(&mut self.depth_index, format!("{t_depth} {depth_file}"))?;
+        line(&mut self.associations, format!("{t_rgb} {rgb_file} {t_depth} {depth_file}"))
+    }
+
+    fn finish(mut self) -> Result<()> {
+        let stats = self.stats.snapshot();
+        self.meta["stats"] = json!({
+            "written": stats.written,
+            "dropped_queue_full": stats.dropped_queue_full,
+            "dropped_unpaired": stats.dropped_unpaired,
+            "skipped_rate": stats.skipped_rate,
+            "errors": stats.errors,
+        });
+        let path = self.root.join("meta.json");
+        let text = serde_json::to_string_pretty(&self.meta).map_err(|e| io_err(&path, e))?;
+        fs::write(&path, text).map_err(|e| io_err(&path, e))
+    }
+}
+
+/// Records synchronized color + depth to disk, see the [module docs](self).
+#[derive(Clone)]
+pub struct DatasetRecorder {
+    node: SinkHostNode,
+    stats: Arc<SharedStats>,
+    color_input_name: String,
+    depth_input_name: String,
+}
+
+impl DatasetRecorder {
+    pub fn as_node(&self) -> &Node {
+        self.node.as_node()
+    }
+
+    /// Color input; any format [`convert::to_rgb888i`] accepts.
+    pub fn color(&self) -> Result<Input> {
+        self.node.input(&self.color_input_name)
+    }
+
+    /// `RAW16` depth input in the configured [`DepthUnit`].
+    pub fn depth(&self) -> Result<Input> {
+        self.node.input(&self.depth_input_name)
+    }
+
+    pub fn stats(&self) -> RecorderStats {
+        self.stats.snapshot()
+    }
+}
+
+impl CreateInPipelineWith<DatasetRecorderConfig> for DatasetRecorder {
+    fn create_with(pipeline: &Pipeline, config: DatasetRecorderConfig) -> Result<Self> {
+        if config.queue_size == 0 {
+            return Err(DepthaiError::new("recorder: queue_size must be at least 1"));
+        }
+        let stats = Arc::new(SharedStats::default());
+        let writer = DatasetWriter::create(&config, stats.clone())?;
+        let (tx, rx) = mpsc::sync_channel(config.queue_size);
+        let writer = std::thread::Builder::new()
+            .name("depthai-recorder".to_string())
+            .spawn(move || writer.run(rx))
+            .map_err(|e| DepthaiError::new(format!("recorder: failed to spawn writer thread: {e}")))?;
+
+        let sink = RecorderSink {
+            depth_scale: Some(config.depth_scale).filter(|s| *s > 0.0),
+            max_sync_delta: config.max_sync_delta,
+            min_interval: config
+                .rate_hz
+                .filter(|hz| *hz > 0.0)
+                .map(|hz| Duration::from_secs_f32(1.0 / hz)),
+            next_due: Duration::ZERO,
+            pending_color: None,
+            pending_depth: None,
+            tx: Some(tx),
+            writer: Some(writer),
+            stats: stats.clone(),
+        };
+        let inputs = vec![
+            SinkInput::image(&config.color_input_name, "rgb"),
+            SinkInput::depth(&config.depth_input_name, "depth", config.depth_unit),
+        ];
+        let node = create_sink_host_node(pipeline, inputs, sink)?;
+        Ok(Self {
+            node,
+            stats,
+            color_input_name: config.color_input_name,
+            depth_input_name: config.depth_input_name,
+        })
+    }
+}
@@ -1,13 +1,20 @@
+use crate::camera::ImageFrame;
 use crate::common::ImageFrameType;
+use crate::convert;
+use crate::depth::DepthFrame;
+use crate::detections::ImgDetections;
 use crate::error::{DepthaiError, Result};
 use crate::output::Input;
-use crate::threaded_host_node::{ThreadedHostNode, ThreadedHostNodeContext};
-use crate::{depthai_threaded_host_node, CreateInPipelineWith, Pipeline};
+use crate::pointcloud::PointCloudData;
+use crate::rgbd::DepthUnit;
+use crate::sink::{create_sink_host_node, FrameSink, SinkContext, SinkHostNode, SinkInput, SinkInputKind};
+use crate::{CreateInPipelineWith, Pipeline};
 
 use rerun as rr;
 
-use std::time::{Duration, Instant};
+use std::time::Duration;
 
+#[cfg(feature = "rerun")]
 fn url_encode_component(input: &str) -> String {
     // Minimal percent-encoding for URL query components.
     // We keep the unreserved set from RFC 3986 and percent-encode everything else.
@@ -30,6 +37,8 @@ fn url_encode_component(input: &str) -> String {
     out
 }
 
+/// Where the web viewer is served, for [`RerunViewer::Web`] (`rerun` feature).
+#[cfg(feature = "rerun")]
 pub struct RerunWebConfig {
     pub bind_ip: String,
     /// Port for hosting the Web Viewer (HTTP).
@@ -41,6 +50,7 @@ pub struct RerunWebConfig {
     pub connect_url: Option<String>,
 }
 
+#[cfg(feature = "rerun")]
 impl Default for RerunWebConfig {
     fn default() -> Self {
         Self {
@@ -52,16 +62,36 @@ impl Default for RerunWebConfig {
     }
 }
 
+/// Viewer a [`RerunHostNode`] streams to. The web viewer is opt-in: set
+/// [`RerunViewer::Web`] explicitly.
+#[non_exclusive]
+#[derive(Default)]
 pub enum RerunViewer {
+    /// Serve the web viewer and stream to it over gRPC. Needs the `rerun` feature, which
+    /// adds the viewer's web assets, its HTTP server and Tokio to the build.
+    #[cfg(feature = "rerun")]
     Web(RerunWebConfig),
+    /// Spawn the native `rerun` viewer (it must be on `PATH`) and stream to it.
+    #[default]
     Native,
 }
 
+/// An input of a [`RerunHostNode`], logged to its `entity_path`. Images are logged as `rr::Image`,
+/// depth as `rr::DepthImage` with meter scaling, point clouds as `rr::Points3D` in meters
+/// (invalid points dropped) and detections as `rr::Boxes2D` in pixels of `frame_size`, so they
+/// overlay an image logged on a parent entity.
+pub type RerunInput = SinkInput;
+pub type RerunInputKind = SinkInputKind;
+
 pub struct RerunHostNodeConfig {
     pub app_id: String,
+    /// Entity path of the default image input (used when `inputs` is empty).
     pub entity_path: String,
     pub viewer: RerunViewer,
+    /// Name of the default image input (used when `inputs` is empty).
     pub input_name: String,
+    /// Inputs to create; empty means a single image input `input_name` logged to `entity_path`.
+    pub inputs: Vec<RerunInput>,
 }
 
 impl Default for RerunHostNodeConfig {
@@ -69,30 +99,38 @@ impl Default for RerunHostNodeConfig {
         Self {
             app_id: "depthai_rerun".to_string(),
             entity_path: "camera".to_string(),
-            viewer: RerunViewer::Web(RerunWebConfig::default()),
+            viewer: RerunViewer::default(),
             input_name: "in".to_string(),
+            inputs: Vec::new(),
+        }
+    }
+}
+
+impl RerunHostNodeConfig {
+    fn resolved_inputs(&self) -> Vec<RerunInput> {
+        if self.inputs.is_empty() {
+            vec![RerunInput::image(&self.input_name, &self.entity_path)]
+        } else {
+            self.inputs.clone()
         }
     }
 }
 
-#[depthai_threaded_host_node]
-struct RerunHostNodeImpl {
-    input: Input,
+/// A [`FrameSink`] logging to a Rerun recording, the sink behind [`RerunHostNode`].
+pub struct RerunSink {
     rec: rr::RecordingStream,
+    web_viewer_url: Option<String>,
     // The Rerun gRPC server + web-viewer server require a Tokio runtime.
-    // Keep it alive for the whole lifetime of the node.
+    // Keep it alive for the whole lifetime of the sink.
     #[cfg(feature = "rerun")]
     _tokio_rt: Option<tokio::runtime::Runtime>,
-    entity_path: String,
-    frame_index: i64,
-    logged_frames: u64,
-    skipped_frames: u64,
-    last_skip_note: Instant,
 }
 
-impl RerunHostNodeImpl {
-    pub fn new(input: Input, config: RerunHostNodeConfig) -> Result<Self> {
-        match config.viewer {
+impl RerunSink {
+    /// Start the viewer and open a recording named `app_id`.
+    pub fn new(app_id: &str, viewer: RerunViewer) -> Result<Self> {
+        match viewer {
+            #[cfg(feature = "rerun")]
             RerunViewer::Web(web) => {
                 // Rerun's serving utilities rely on a Tokio runtime existing in the current context.
                 // We create one dedicated runtime for this node and keep it alive.
@@ -104,7 +142,7 @@ impl RerunHostNodeImpl {
                 // Temporarily enter the runtime so rerun can spawn background tasks.
                 let _guard = rt.enter();
 
-                let rec = rr::RecordingStreamBuilder::new(config.app_id.clone())
+                let rec = rr::RecordingStreamBuilder::new(app_id)
                     .serve_grpc()
                     .map_err(rerun_err)?;
 
@@ -140,171 +178,133 @@ impl RerunHostNodeImpl {
                     url_encode_component(&connect_to)
                 );
 
-                eprintln!("rerun: gRPC /proxy connect URL: {connect_to}");
-                eprintln!("rerun: web viewer served at: {base_url}");
-                eprintln!("rerun: web viewer (autoconnect) URL: {autoconnect_url}");
                 web_server.detach();
 
-                eprintln!(
-                    "rerun: host node starting (viewer=web, entity_path='{}')",
-                    config.entity_path
-                );
-
                 Ok(Self {
-                    input,
                     rec,
+                    web_viewer_url: Some(autoconnect_url),
                     _tokio_rt: Some(rt),
-                    entity_path: config.entity_path,
-                    frame_index: 0,
-                    logged_frames: 0,
-                    skipped_frames: 0,
-                    last_skip_note: Instant::now() - Duration::from_secs(60),
                 })
             }
             RerunViewer::Native => {
-                let rec = rr::RecordingStreamBuilder::new(config.app_id.clone())
+                let rec = rr::RecordingStreamBuilder::new(app_id)
                     .spawn()
                     .map_err(rerun_err)?;
 
-                eprintln!(
-                    "rerun: host node starting (viewer=native, entity_path='{}')",
-                    config.entity_path
-                );
-
                 Ok(Self {
-                    input,
                     rec,
+                    web_viewer_url: None,
+                    #[cfg(feature = "rerun")]
                     _tokio_rt: None,
-                    entity_path: config.entity_path,
-                    frame_index: 0,
-                    logged_frames: 0,
-                    skipped_frames: 0,
-                    last_skip_note: Instant::now() - Duration::from_secs(60),
                 })
             }
         }
     }
 
-    pub fn run(&mut self, ctx: &ThreadedHostNodeContext) {
-        while ctx.is_running() {
-            match self.input.get_frame() {
-                Ok(frame) => {
-                    if let Err(e) = self.log_frame(&frame) {
-                        // Previously we silently ignored errors which makes debugging painful.
-                        eprintln!("rerun: failed to process frame: {e}");
-                    }
-                }
-                Err(e) => {
-                    eprintln!("rerun: input.get_frame() failed; stopping host node: {e}");
-                    break;
-                }
-            }
-        }
+    /// The recording, e.g. to log extra entities next to the node's streams.
+    pub fn recording(&self) -> &rr::RecordingStream {
+        &self.rec
+    }
+
+    /// For [`RerunViewer::Web`], the web viewer URL that connects to this recording's gRPC
+    /// `/proxy` endpoint.
+    pub fn web_viewer_url(&self) -> Option<&str> {
+        self.web_viewer_url.as_deref()
+    }
 
-        eprintln!(
-            "rerun: host node stopping (logged={} skipped={})",
-            self.logged_frames, self.skipped_frames
-        );
+    fn set_time(&self, ctx: &SinkContext<'_>, timestamp: Result<Duration>) {
+        self.rec.set_time_sequence("frame", ctx.sequence as i64);
+        if let Ok(ts) = timestamp {
+            self.rec.set_duration_secs("timestamp", ts.as_secs_f64());
+        }
     }
 
-    fn log_frame(&mut self, frame: &crate::camera::ImageFrame) -> Result<()> {
+    fn log_image(&self, ctx: &SinkContext<'_>, frame: &ImageFrame) -> Result<()> {
         let w = frame.width();
         let h = frame.height();
         let format = frame.format();
-        let mut bytes = frame.bytes();
 
-        // Helpful sanity check: if the byte length doesn't match the expected format,
-        // log a note (rate-limited) and skip to avoid confusing downstream.
-        let expected_len = match format {
-            Some(ImageFrameType::RGB888i) | Some(ImageFrameType::BGR888i) => {
-                Some((w as usize).saturating_mul(h as usize).saturating_mul(3))
-            }
-            Some(ImageFrameType::GRAY8) => Some((w as usize).saturating_mul(h as usize)),
-            _ => None,
-        };
+        // Single-channel formats are logged as luminance, everything else goes through RGB.
+        let image = match format {
+            Some(
+                ImageFrameType::GRAY8
+                | ImageFrameType::RAW8
+                | ImageFrameType::YUV400p
+                | ImageFrameType::RAW10
+                | ImageFrameType::RAW16,
+            ) => convert::to_gray8(frame).map(|gray| rr::Image::from_l8(gray, [w, h])),
+            _ => convert::to_rgb888i(frame).map(|rgb| rr::Image::from_rgb24(rgb, [w, h])),
+        }
+        .map_err(|err| {
+            DepthaiError::new(format!("{}x{} format={:?} bytes_len={}: {}", w, h, format, frame.byte_len(), err))
+        })?;
 
-        if let Some(expected) = expected_len {
-            if bytes.len() < expected {
-                self.skipped_frames += 1;
-                if self.last_skip_note.elapsed() >= Duration::from_secs(2) {
-                    eprintln!(
-                        "rerun: skipping frame due to short buffer: {}x{} format={:?} bytes_len={} expected_len={}",
-                        w,
-                        h,
-                        format,
-                        bytes.len(),
-                        expected
-                    );
-                    self.last_skip_note = Instant::now();
-                }
-                return Ok(());
-            }
+        self.set_time(ctx, frame.timestamp());
+        self.rec.log(ctx.input.entity_path.as_str(), &image).map_err(rerun_err)
+    }
 
-            if bytes.len() > expected {
-                // Some backends may include padding or metadata. Log a warning and truncate so we
-                // still try to produce an image instead of silently skipping everything.
-                if self.last_skip_note.elapsed() >= Duration::from_secs(2) {
-                    eprintln!(
-                        "rerun: note: buffer larger than expected; truncating: {}x{} format={:?} bytes_len={} expected_len={}",
-                        w,
-                        h,
-                        format,
-                        bytes.len(),
-                        expected
-                    );
-                    self.last_skip_note = Instant::now();
-                }
-                bytes.truncate(expected);
-            }
+    fn log_depth(&self, ctx: &SinkContext<'_>, frame: &ImageFrame, unit: DepthUnit) -> Result<()> {
+        let depth = DepthFrame::new(frame, unit)?;
+        let bytes: Vec<u8> = depth.values().iter().flat_map(|v| v.to_le_bytes()).collect();
+        let mut image = rr::DepthImage::from_gray16(bytes, [depth.width(), depth.height()]);
+        if let Some(meters) = unit.meters_per_unit() {
+            image = image.with_meter(1.0 / meters);
         }
 
-        let image = match format {
-            Some(ImageFrameType::RGB888i) => {
-                rr::Image::from_rgb24(bytes, [w, h])
-            }
-            Some(ImageFrameType::BGR888i) => {
-                let mut rgb = bytes;
-                for chunk in rgb.chunks_exact_mut(3) {
-                    chunk.swap(0, 2);
-                }
-                rr::Image::from_rgb24(rgb, [w, h])
-            }
-            Some(ImageFrameType::GRAY8) => {
-                rr::Image::from_l8(bytes, [w, h])
-            }
-            _ => {
-                self.skipped_frames += 1;
-                if self.last_skip_note.elapsed() >= Duration::from_secs(2) {
-                    eprintln!(
-                        "rerun: skipping frame with unsupported/unknown format: {}x{} format={:?} bytes_len={}",
-                        w,
-                        h,
-                        format,
-                        bytes.len()
-                    );
-                    eprintln!(
-                        "rerun: supported formats for logging are: RGB888i, BGR888i, GRAY8 (hint: set CameraOutputConfig.frame_type=Some(ImageFrameType::RGB888i))"
-                    );
-                    self.last_skip_note = Instant::now();
-                }
-                return Ok(());
-            }
+        self.set_time(ctx, frame.timestamp());
+        self.rec.log(ctx.input.entity_path.as_str(), &image).map_err(rerun_err)
+    }
+}
+
+impl FrameSink for RerunSink {
+    fn on_frame(&mut self, ctx: &SinkContext<'_>, frame: &ImageFrame) -> Result<()> {
+        match ctx.input.kind {
+            SinkInputKind::Depth(unit) => self.log_depth(ctx, frame, unit),
+            _ => self.log_image(ctx, frame),
+        }
+    }
+
+    fn on_pointcloud(&mut self, ctx: &SinkContext<'_>, cloud: &PointCloudData) -> Result<()> {
+        let scale = match ctx.input.kind {
+            SinkInputKind::PointCloud(unit) => unit.meters_per_unit().unwrap_or(1.0),
+            _ => 1.0,
         };
+        let (positions, colors): (Vec<_>, Vec<_>) = cloud
+            .valid_points()
+            .map(|p| ([p.x * scale, p.y * scale, p.z * scale], rr::Color::from_unmultiplied_rgba(p.r, p.g, p.b, 255)))
+            .unzip();
 
-        self.rec.set_time_sequence("frame", self.frame_index);
-        self.frame_index += 1;
+        self.set_time(ctx, cloud.timestamp());
         self.rec
-            .log(self.entity_path.as_str(), &image)
-            .map_err(rerun_err)?;
+            .log(ctx.input.entity_path.as_str(), &rr::Points3D::new(positions).with_colors(colors))
+            .map_err(rerun_err)
+    }
 
-        self.logged_frames += 1;
-        Ok(())
+    fn on_detections(&mut self, ctx: &SinkContext<'_>, dets: &ImgDetections) -> Result<()> {
+        let SinkInputKind::Detections { frame_size, labels } = &ctx.input.kind else {
+            return Err(DepthaiError::new("detections on a non-detections input"));
+        };
+        let (w, h) = (frame_size.0 as f32, frame_size.1 as f32);
+        let detections = dets.detections()?;
+        let mins = detections.iter().map(|d| [d.xmin * w, d.ymin * h]);
+        let sizes = detections.iter().map(|d| [(d.xmax - d.xmin) * w, (d.ymax - d.ymin) * h]);
+        let names = detections.iter().map(|d| match labels.get(d.label as usize) {
+            Some(name) => format!("{name} {:.0}%", d.confidence * 100.0),
+            None => format!("{} {:.0}%", d.label, d.confidence * 100.0),
+        });
+        let boxes = rr::Boxes2D::from_mins_and_sizes(mins, sizes)
+            .with_labels(names)
+            .with_class_ids(detections.iter().map(|d| d.label as u16));
+
+        self.set_time(ctx, dets.timestamp());
+        self.rec.log(ctx.input.entity_path.as_str(), &boxes).map_err(rerun_err)
     }
 }
 
 #[derive(Clone)]
 pub struct RerunHostNode {
-    node: ThreadedHostNode,
+    node: SinkHostNode,
+    web_viewer_url: Option<String>,
 }
 
 impl RerunHostNode {
@@ -315,16 +315,20 @@ impl RerunHostNode {
     pub fn input(&self, name: &str) -> Result<Input> {
         self.as_node().input(name)
     }
+
+    /// See [`RerunSink::web_viewer_url`].
+    pub fn web_viewer_url(&self) -> Option<&str> {
+        self.web_viewer_url.as_deref()
+    }
 }
 
 impl CreateInPipelineWith<RerunHostNodeConfig> for RerunHostNode {
     fn create_with(pipeline: &Pipeline, config: RerunHostNodeConfig) -> Result<Self> {
-        let input_name = config.input_name.clone();
-        let node = pipeline.create_threaded_host_node(|node| {
-            let input = node.create_input(Some(&input_name))?;
-            RerunHostNodeImpl::new(input, config)
-        })?;
-        Ok(Self { node })
+        let inputs = config.resolved_inputs();
+        let sink = RerunSink::new(&config.app_id, config.viewer)?;
+        let web_viewer_url = sink.web_viewer_url.clone();
+        let node = create_sink_host_node(pipeline, inputs, sink)?;
+        Ok(Self { node, web_viewer_url })
     }
 }
 
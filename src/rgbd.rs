@@ -3,8 +3,13 @@ use std::time::Duration;
 use autocxx::c_int;
 use depthai_sys::{depthai, DaiRGBDData};
 
+use crate::calibration::CameraIntrinsics;
 use crate::camera::{ImageFrame, OutputQueue};
-use crate::error::{clear_error_flag, last_error, take_error_if_any, Result};
+use crate::common::CameraBoardSocket;
+use crate::convert;
+use crate::depth::DepthFrame;
+use crate::error::{clear_error_flag, last_error, take_error_if_any, DepthaiError, Result};
+use crate::pointcloud::Point3fRGBA;
 
 #[repr(i32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -17,8 +22,62 @@ pub enum DepthUnit {
     Custom = 5,
 }
 
+impl DepthUnit {
+    /// Length of one unit in meters, `None` for [`DepthUnit::Custom`].
+    pub fn meters_per_unit(self) -> Option<f32> {
+        match self {
+            DepthUnit::Meter => Some(1.0),
+            DepthUnit::Centimeter => Some(0.01),
+            DepthUnit::Millimeter => Some(0.001),
+            DepthUnit::Inch => Some(0.0254),
+            DepthUnit::Foot => Some(0.3048),
+            DepthUnit::Custom => None,
+        }
+    }
+
+    /// Factor converting values in this unit to meters, an error for [`DepthUnit::Custom`].
+    ///
+    /// Prefer this to `meters_per_unit().unwrap_or(1.0)`, which silently treats custom units
+    /// as meters.
+    pub fn scale_to_meters(self) -> Result<f32> {
+        self.meters_per_unit()
+            .ok_or_else(|| DepthaiError::new("custom depth units cannot be converted to meters"))
+    }
+
+    /// Factor converting values in this unit to `unit`, e.g. `1000.0` from meters to
+    /// millimeters.
+    pub fn scale_to(self, unit: DepthUnit) -> Result<f32> {
+        if self == unit {
+            return Ok(1.0);
+        }
+        Ok(self.scale_to_meters()? / unit.scale_to_meters()?)
+    }
+
+    /// `value`, in this unit, in meters.
+    pub fn to_meters(self, value: f32) -> Result<f32> {
+        Ok(value * self.scale_to_meters()?)
+    }
+
+    /// `meters` in this unit.
+    pub fn from_meters(self, meters: f32) -> Result<f32> {
+        Ok(meters / self.scale_to_meters()?)
+    }
+
+    pub fn from_raw(value: i32) -> Option<Self> {
+        match value {
+            0 => Some(DepthUnit::Meter),
+            1 => Some(DepthUnit::Centimeter),
+            2 => Some(DepthUnit::Millimeter),
+            3 => Some(DepthUnit::Inch),
+            4 => Some(DepthUnit::Foot),
+            5 => Some(DepthUnit::Custom),
+            _ => None,
+        }
+    }
+}
+
 #[allow(non_snake_case)]
-#[crate::native_node_wrapper(native = "dai::node::RGBD", inputs(inColor, inDepth), outputs(out))]
+#[crate::native_node_wrapper(native = "dai::node::RGBD", inputs(inColor: ImageFrame, inDepth: ImageFrame), outputs(out))]
 pub struct RgbdNode {
     node: crate::pipeline::Node,
 }
@@ -69,13 +128,135 @@ impl RgbdNode {
         }
     }
 
+    /// Auto-create the color camera and stereo depth this node consumes and link them, so only
+    /// the outputs need wiring. Configure the created cameras on the returned builder.
+    ///
+    /// Mirrors C++: `RGBD::build(true, mode, size, fps)`.
+    ///
+    /// ```no_run
+    /// # use depthai::{Pipeline, Result, RgbdNode, StereoPresetMode};
+    /// # fn main() -> Result<()> {
+    /// # let pipeline = Pipeline::new().build()?;
+    /// let rgbd = pipeline.create::<RgbdNode>()?;
+    /// rgbd.autocreate().preset(StereoPresetMode::Robotics).size(640, 400).fps(30.0).build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn autocreate(&self) -> RgbdAutocreate<'_> {
+        RgbdAutocreate {
+            node: self,
+            preset: crate::stereo_depth::PresetMode::Default,
+            size: (640, 400),
+            fps: None,
+        }
+    }
+
+    /// Unit of the point cloud output. The depth frames of [`RgbdData`] stay in millimeters.
     pub fn set_depth_unit(&self, unit: DepthUnit) {
         // setter cannot fail at the C ABI level (will record last_error on exception)
         clear_error_flag();
         unsafe { depthai::dai_rgbd_set_depth_unit(self.node.handle(), c_int(unit as i32)) };
     }
+
+    /// Unit of the point cloud output, as last set with [`set_depth_unit`](Self::set_depth_unit)
+    /// (millimeters by default). Scale `pcl` points with it rather than assuming a unit.
+    pub fn depth_unit(&self) -> Result<DepthUnit> {
+        clear_error_flag();
+        let raw: i32 = unsafe { depthai::dai_rgbd_get_depth_unit(self.node.handle()) }.into();
+        if let Some(err) = take_error_if_any("failed to get RGBD depth unit") {
+            return Err(err);
+        }
+        DepthUnit::from_raw(raw).ok_or_else(|| DepthaiError::new(format!("unknown RGBD depth unit {raw}")))
+    }
+
+    /// Cap the rate of the `pcl` and `rgbd` outputs at `fps`, dropping messages in between on the
+    /// host; `0.0` (the default) passes every pair through.
+    ///
+    /// Applies to queues created on the outputs afterwards, so set it before `create_queue`.
+    pub fn set_output_fps(&self, fps: f32) {
+        clear_error_flag();
+        unsafe { depthai::dai_rgbd_set_output_fps(self.node.handle(), fps) };
+    }
+
+    pub fn output_fps(&self) -> Result<f32> {
+        clear_error_flag();
+        let fps = unsafe { depthai::dai_rgbd_get_output_fps(self.node.handle()) };
+        if let Some(err) = take_error_if_any("failed to get RGBD output fps") {
+            Err(err)
+        } else {
+            Ok(fps)
+        }
+    }
+
+    /// Emit sparse point clouds (only points with a measurement, `height() == 1`) instead of the
+    /// dense `width * height` layout with zeroed points, the default.
+    ///
+    /// Applies to queues created on the `pcl` output afterwards, so set it before `create_queue`.
+    pub fn set_sparse(&self, sparse: bool) {
+        clear_error_flag();
+        unsafe { depthai::dai_rgbd_set_sparse(self.node.handle(), sparse) };
+    }
+
+    pub fn is_sparse(&self) -> Result<bool> {
+        clear_error_flag();
+        let sparse = unsafe { depthai::dai_rgbd_get_sparse(self.node.handle()) };
+        if let Some(err) = take_error_if_any("failed to get RGBD sparse flag") {
+            Err(err)
+        } else {
+            Ok(sparse)
+        }
+    }
+}
+
+/// Settings of the color and stereo nodes created by [`RgbdNode::autocreate`].
+#[must_use = "call `build()` to create the nodes"]
+pub struct RgbdAutocreate<'a> {
+    node: &'a RgbdNode,
+    preset: crate::stereo_depth::PresetMode,
+    size: (u32, u32),
+    fps: Option<f32>,
+}
+
+impl RgbdAutocreate<'_> {
+    /// Stereo preset (default: [`PresetMode::Default`](crate::stereo_depth::PresetMode::Default)).
+    pub fn preset(mut self, preset: crate::stereo_depth::PresetMode) -> Self {
+        self.preset = preset;
+        self
+    }
+
+    /// Size of the color and depth frames, and so of organized point clouds (default: 640x400).
+    pub fn size(mut self, width: u32, height: u32) -> Self {
+        self.size = (width, height);
+        self
+    }
+
+    /// Camera FPS, which sets the output rate (default: the camera default).
+    pub fn fps(mut self, fps: f32) -> Self {
+        self.fps = Some(fps);
+        self
+    }
+
+    pub fn build(self) -> Result<()> {
+        let (w, h) = self.size;
+        self.node.build_ex(true, self.preset, (w as i32, h as i32), self.fps)
+    }
+}
+
+/// How the depth of an [`RgbdData`] relates to its color frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RgbdDepthInfo {
+    /// Unit of the raw depth values; RGBD passes the `StereoDepth` millimeters through.
+    pub unit: DepthUnit,
+    /// Camera the depth is aligned to.
+    pub aligned_to: CameraBoardSocket,
+    pub width: u32,
+    pub height: u32,
 }
 
+/// Unit of the depth frames of [`RgbdData`]: RGBD passes the `StereoDepth` millimeters through,
+/// whatever [`RgbdNode::set_depth_unit`] is.
+const RGBD_DEPTH_UNIT: DepthUnit = DepthUnit::Millimeter;
+
 pub struct RgbdData {
     handle: DaiRGBDData,
 }
@@ -113,6 +294,74 @@ impl RgbdData {
             Ok(ImageFrame::from_handle(frame))
         }
     }
+
+    pub fn depth_info(&self) -> Result<RgbdDepthInfo> {
+        let depth = self.depth_frame()?;
+        Ok(RgbdDepthInfo {
+            unit: RGBD_DEPTH_UNIT,
+            aligned_to: self.rgb_frame()?.board_socket()?,
+            width: depth.width(),
+            height: depth.height(),
+        })
+    }
+
+    /// The depth frame decoded, see [`DepthFrame`]. Does not need the color frame.
+    pub fn depth(&self) -> Result<DepthFrame> {
+        DepthFrame::new(&self.depth_frame()?, RGBD_DEPTH_UNIT)
+    }
+
+    /// The depth frame decoded and converted to `unit`, see [`DepthFrame::to_unit`].
+    pub fn depth_in(&self, unit: DepthUnit) -> Result<DepthFrame> {
+        self.depth()?.to_unit(unit)
+    }
+
+    /// Intrinsics of the color stream the depth is aligned to, at the depth frame's size.
+    pub fn intrinsics(&self) -> Result<CameraIntrinsics> {
+        let rgb = self.rgb_frame()?;
+        let depth = self.depth_frame()?;
+        let intrinsics = rgb.intrinsics()?;
+        let (sx, sy) = (
+            depth.width() as f32 / rgb.width().max(1) as f32,
+            depth.height() as f32 / rgb.height().max(1) as f32,
+        );
+        Ok(CameraIntrinsics::new(
+            intrinsics.fx * sx,
+            intrinsics.fy * sy,
+            intrinsics.cx * sx,
+            intrinsics.cy * sy,
+        ))
+    }
+
+    /// Colored points in meters from every `decimation`-th pixel in both directions, computed on
+    /// the host; pixels without depth are skipped.
+    ///
+    /// For occasional 3D points without running the node's `pcl` output.
+    pub fn to_pointcloud(&self, decimation: u32) -> Result<Vec<Point3fRGBA>> {
+        if decimation == 0 {
+            return Err(DepthaiError::new("point cloud decimation must be at least 1"));
+        }
+        let depth = self.depth()?;
+        let intrinsics = self.intrinsics()?;
+        let rgb = self.rgb_frame()?;
+        let colors = convert::to_rgb888i(&rgb)?;
+        let (rgb_w, rgb_h) = (rgb.width().max(1), rgb.height().max(1));
+        let step = decimation as usize;
+        let mut points = Vec::new();
+        for y in (0..depth.height()).step_by(step) {
+            for x in (0..depth.width()).step_by(step) {
+                let Some([px, py, pz]) = depth.deproject(x, y, &intrinsics) else {
+                    continue;
+                };
+                // Color frames may be larger than the depth they are paired with.
+                let cx = (x as u64 * rgb_w as u64 / depth.width() as u64) as usize;
+                let cy = (y as u64 * rgb_h as u64 / depth.height() as u64) as usize;
+                let i = (cy * rgb_w as usize + cx) * 3;
+                let [r, g, b] = colors.get(i..i + 3).map_or([0; 3], |c| [c[0], c[1], c[2]]);
+                points.push(Point3fRGBA { x: px, y: py, z: pz, r, g, b, a: 255 });
+            }
+        }
+        Ok(points)
+    }
 }
 
 impl OutputQueue {
@@ -0,0 +1,462 @@
+//! Record-and-rewind buffering of encoded video.
+//!
+//! [`RingBufferHostNode`] keeps the last seconds of [`EncodedFrame`]s from a
+//! [`VideoEncoderNode`](crate::video_encoder::VideoEncoderNode) in memory and writes them to disk
+//! on demand, for "save the last 10 seconds when something happens" workflows. A dump is
+//! triggered by calling [`RingBufferHostNode::dump`] or by any message arriving on the node's
+//! `trigger` input, e.g. from a script or detection node:
+//!
+//! ```no_run
+//! # use std::time::Duration;
+//! # use depthai::{Pipeline, Result, VideoEncoderNode, VideoEncoderProfile};
+//! # use depthai::ring_buffer::{RingBufferConfig, RingBufferHostNode};
+//! # fn main() -> Result<()> {
+//! # let pipeline = Pipeline::new().build()?;
+//! let encoder = pipeline.create::<VideoEncoderNode>()?;
+//! encoder.set_default_profile_preset(30.0, VideoEncoderProfile::H264Main);
+//! let ring = pipeline.create_with::<RingBufferHostNode, _>(RingBufferConfig {
+//!     duration: Duration::from_secs(10),
+//!     output_dir: "clips".into(),
+//!     ..RingBufferConfig::default()
+//! })?;
+//! encoder.out()?.link(&ring.input()?)?;
+//! pipeline.start()?;
+//! // ... when an event happens:
+//! let clip = ring.dump()?;
+//! println!("saved {} frames to {}", clip.frames, clip.path.display());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Dumps are raw bitstreams (`.h264`, `.h265` or `.mjpeg`) that start at the oldest buffered
+//! keyframe, so keep the encoder's keyframe interval well below the buffered duration. Dumps
+//! asked for on `trigger` are written by a writer thread, so the node keeps buffering meanwhile;
+//! [`RingBufferHostNode::last_dump`] shows them once written.
+//! [`EncodedFrameRing`] is the buffer itself, for use outside a pipeline.
+
+use std::collections::VecDeque;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufWriter, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread::JoinHandle;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::encoded_frame::{EncodedFrame, EncodedFrameProfile};
+use crate::error::{DepthaiError, Result};
+use crate::host_node::Buffer;
+use crate::output::{Input, TypedInput};
+use crate::pipeline::{Node, Pipeline};
+use crate::threaded_host_node::{ThreadedHostNode, ThreadedHostNodeContext, ThreadedHostNodeImpl};
+use crate::CreateInPipelineWith;
+
+/// How long the node thread waits for a frame before checking the trigger again.
+const TRIGGER_POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+pub struct RingBufferConfig {
+    /// History to keep, measured on the frame timestamps.
+    pub duration: Duration,
+    /// Memory bound; the oldest frames are dropped first when it is exceeded.
+    pub max_bytes: usize,
+    /// Directory of the files written by [`RingBufferHostNode::dump`]; created when missing.
+    pub output_dir: PathBuf,
+    /// File name prefix of dumps, followed by the dump time in Unix milliseconds (and a counter
+    /// when several dumps fall in the same millisecond).
+    pub file_prefix: String,
+}
+
+impl Default for RingBufferConfig {
+    fn default() -> Self {
+        Self {
+            duration: Duration::from_secs(10),
+            max_bytes: 256 * 1024 * 1024,
+            output_dir: PathBuf::from("."),
+            file_prefix: "ring".to_string(),
+        }
+    }
+}
+
+/// A file written from an [`EncodedFrameRing`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RingDump {
+    pub path: PathBuf,
+    pub frames: usize,
+    pub bytes: usize,
+    /// Time between the first and the last dumped frame.
+    pub duration: Duration,
+}
+
+struct BufferedFrame {
+    timestamp: Duration,
+    keyframe: bool,
+    data: Arc<[u8]>,
+}
+
+/// The last `duration` of an encoded stream, see the [module docs](self).
+pub struct EncodedFrameRing {
+    duration: Duration,
+    max_bytes: usize,
+    profile: Option<EncodedFrameProfile>,
+    frames: VecDeque<BufferedFrame>,
+    bytes: usize,
+}
+
+impl EncodedFrameRing {
+    pub fn new(duration: Duration, max_bytes: usize) -> Self {
+        Self {
+            duration,
+            max_bytes,
+            profile: None,
+            frames: VecDeque::new(),
+            bytes: 0,
+        }
+    }
+
+    /// Buffer `frame`. A change of codec drops what was buffered before.
+    pub fn push(&mut self, frame: &EncodedFrame) -> Result<()> {
+        let profile = frame
+            .profile()
+            .ok_or_else(|| DepthaiError::new(format!("unknown encoded frame profile: {}", frame.describe())))?;
+        if self.profile != Some(profile) {
+            self.clear();
+            self.profile = Some(profile);
+        }
+        let keyframe = profile == EncodedFrameProfile::Jpeg || frame.is_keyframe();
+        self.push_raw(&frame.bytes(), frame.timestamp()?, keyframe);
+        Ok(())
+    }
+
+    /// Buffer one encoded frame; decoding of a dump starts at its first `keyframe`.
+    pub fn push_raw(&mut self, data: &[u8], timestamp: Duration, keyframe: bool) {
+        self.bytes += data.len();
+        self.frames.push_back(BufferedFrame {
+            timestamp,
+            keyframe,
+            data: data.into(),
+        });
+        while self.frames.len() > 1 {
+            let oldest = &self.frames[0];
+            let too_old = timestamp.saturating_sub(oldest.timestamp) > self.duration;
+            if !too_old && self.bytes <= self.max_bytes {
+                break;
+            }
+            self.bytes -= oldest.data.len();
+            self.frames.pop_front();
+        }
+    }
+
+    /// Codec of the buffered frames, once a frame was [`push`](Self::push)ed.
+    pub fn profile(&self) -> Option<EncodedFrameProfile> {
+        self.profile
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    pub fn buffered_bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// Time between the oldest and the newest buffered frame.
+    pub fn buffered_duration(&self) -> Duration {
+        match (self.frames.front(), self.frames.back()) {
+            (Some(first), Some(last)) => last.timestamp.saturating_sub(first.timestamp),
+            _ => Duration::ZERO,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.frames.clear();
+        self.bytes = 0;
+    }
+
+    /// File extension of a dump: `h264`, `h265`, `mjpeg`, or `bin` for raw pushes.
+    pub fn file_extension(&self) -> &'static str {
+        match self.profile {
+            Some(EncodedFrameProfile::Avc) => "h264",
+            Some(EncodedFrameProfile::Hevc) => "h265",
+            Some(EncodedFrameProfile::Jpeg) => "mjpeg",
+            None => "bin",
+        }
+    }
+
+    /// The decodable part of the buffer: frames from the oldest keyframe on. Cheap, the frame
+    /// data is shared.
+    fn snapshot(&self) -> Vec<(Duration, Arc<[u8]>)> {
+        self.frames
+            .iter()
+            .skip_while(|f| !f.keyframe)
+            .map(|f| (f.timestamp, Arc::clone(&f.data)))
+            .collect()
+    }
+
+    /// Write the buffered stream, from its oldest keyframe on, to `path`. The buffer is kept.
+    pub fn dump_to(&self, path: impl AsRef<Path>) -> Result<RingDump> {
+        let frames = self.snapshot();
+        if frames.is_empty() {
+            return Err(no_keyframe());
+        }
+        let path = path.as_ref();
+        let file = File::create(path).map_err(|e| write_error(path, e))?;
+        write_dump(path, file, &frames)
+    }
+}
+
+fn no_keyframe() -> DepthaiError {
+    DepthaiError::new("ring buffer holds no keyframe to start a dump from")
+}
+
+fn write_error(path: &Path, e: std::io::Error) -> DepthaiError {
+    DepthaiError::new(format!("failed to write {}: {e}", path.display()))
+}
+
+/// Create `<stem>.<extension>` in `dir`, or `<stem>-<n>.<extension>` if it is taken.
+fn create_dump_file(dir: &Path, stem: &str, extension: &str) -> Result<(PathBuf, File)> {
+    fs::create_dir_all(dir).map_err(|e| DepthaiError::new(format!("failed to create {}: {e}", dir.display())))?;
+    for n in 0u32.. {
+        let name = match n {
+            0 => format!("{stem}.{extension}"),
+            n => format!("{stem}-{n}.{extension}"),
+        };
+        let path = dir.join(name);
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(file) => return Ok((path, file)),
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => {}
+            Err(e) => return Err(write_error(&path, e)),
+        }
+    }
+    unreachable!("ran out of dump file names")
+}
+
+fn write_dump(path: &Path, file: File, frames: &[(Duration, Arc<[u8]>)]) -> Result<RingDump> {
+    let (Some(first), Some(last)) = (frames.first(), frames.last()) else {
+        return Err(no_keyframe());
+    };
+    let io_error = |e| write_error(path, e);
+    let mut out = BufWriter::new(file);
+    for (_, data) in frames {
+        out.write_all(data).map_err(io_error)?;
+    }
+    out.flush().map_err(io_error)?;
+    Ok(RingDump {
+        path: path.to_path_buf(),
+        frames: frames.len(),
+        bytes: frames.iter().map(|(_, d)| d.len()).sum(),
+        duration: last.0.saturating_sub(first.0),
+    })
+}
+
+struct Shared {
+    ring: Mutex<EncodedFrameRing>,
+    output_dir: PathBuf,
+    file_prefix: String,
+    last_dump: Mutex<Option<RingDump>>,
+}
+
+/// A snapshot of the buffer waiting to be written to a new file.
+struct DumpJob {
+    frames: Vec<(Duration, Arc<[u8]>)>,
+    /// File name without extension: prefix and time of the request.
+    stem: String,
+    extension: &'static str,
+}
+
+impl Shared {
+    fn ring(&self) -> MutexGuard<'_, EncodedFrameRing> {
+        self.ring.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Snapshot the buffer for a dump to a new file in the output directory.
+    fn prepare(&self) -> Result<DumpJob> {
+        let (frames, extension) = {
+            let ring = self.ring();
+            (ring.snapshot(), ring.file_extension())
+        };
+        if frames.is_empty() {
+            return Err(no_keyframe());
+        }
+        let millis = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        Ok(DumpJob {
+            frames,
+            stem: format!("{}-{}", self.file_prefix, millis.as_millis()),
+            extension,
+        })
+    }
+
+    /// Write `job`, outside the ring lock so the node keeps buffering meanwhile.
+    fn write(&self, job: DumpJob) -> Result<RingDump> {
+        let (path, file) = create_dump_file(&self.output_dir, &job.stem, job.extension)?;
+        self.finish(write_dump(&path, file, &job.frames))
+    }
+
+    fn finish(&self, dump: Result<RingDump>) -> Result<RingDump> {
+        if let Ok(dump) = &dump {
+            *self.last_dump.lock().unwrap_or_else(|e| e.into_inner()) = Some(dump.clone());
+        }
+        dump
+    }
+}
+
+/// Writes the dumps asked for on `trigger`, sending back the errors for the node to report.
+fn run_writer(shared: Arc<Shared>, jobs: Receiver<DumpJob>, errors: Sender<DepthaiError>) {
+    for job in jobs {
+        if let Err(e) = shared.write(job) {
+            let _ = errors.send(e);
+        }
+    }
+}
+
+struct RingBufferImpl {
+    input: TypedInput<EncodedFrame>,
+    trigger: Input,
+    shared: Arc<Shared>,
+    jobs: Option<Sender<DumpJob>>,
+    errors: Receiver<DepthaiError>,
+    writer: Option<JoinHandle<()>>,
+}
+
+impl RingBufferImpl {
+    // Closing the channel lets the writer finish the pending dumps and exit.
+    fn close(&mut self) {
+        self.jobs = None;
+        if let Some(writer) = self.writer.take() {
+            let _ = writer.join();
+        }
+    }
+}
+
+impl ThreadedHostNodeImpl for RingBufferImpl {
+    fn run(&mut self, ctx: &ThreadedHostNodeContext) {
+        while ctx.is_running() {
+            // Any message on `trigger` asks for a dump, whatever its type.
+            match self.trigger.try_get_message() {
+                Ok(Some(_)) => {
+                    let queued = self.shared.prepare().and_then(|job| match &self.jobs {
+                        Some(jobs) if jobs.send(job).is_ok() => Ok(()),
+                        _ => Err(DepthaiError::new("ring buffer writer thread is gone")),
+                    });
+                    if let Err(e) = queued {
+                        ctx.report_error(&e);
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => ctx.report_error(&e),
+            }
+            for e in self.errors.try_iter() {
+                ctx.report_error(&e);
+            }
+            match ctx.recv::<EncodedFrame, _>(&self.input, TRIGGER_POLL_INTERVAL) {
+                Ok(Some(frame)) => {
+                    if let Err(e) = self.shared.ring().push(&frame) {
+                        ctx.report_error(&e);
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    ctx.report_error(&e);
+                    break;
+                }
+            }
+        }
+    }
+
+    fn on_stop(&mut self) {
+        self.close();
+    }
+}
+
+impl Drop for RingBufferImpl {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+/// Host node buffering the last seconds of encoded video, see the [module docs](self).
+#[derive(Clone)]
+pub struct RingBufferHostNode {
+    node: ThreadedHostNode,
+    shared: Arc<Shared>,
+}
+
+impl RingBufferHostNode {
+    pub fn as_node(&self) -> &Node {
+        self.node.as_node()
+    }
+
+    /// Encoded frames; link a `VideoEncoderNode` here.
+    pub fn input(&self) -> Result<TypedInput<EncodedFrame>> {
+        self.as_node().input("in").map(TypedInput::from_untyped)
+    }
+
+    /// Any message arriving here dumps the buffer to a new file in the output directory.
+    pub fn trigger(&self) -> Result<TypedInput<Buffer>> {
+        self.as_node().input("trigger").map(TypedInput::from_untyped)
+    }
+
+    /// Write the buffer to a new file in [`RingBufferConfig::output_dir`].
+    pub fn dump(&self) -> Result<RingDump> {
+        self.shared.write(self.shared.prepare()?)
+    }
+
+    /// Write the buffer to `path`.
+    pub fn dump_to(&self, path: impl AsRef<Path>) -> Result<RingDump> {
+        let path = path.as_ref();
+        // Snapshot first: the node keeps buffering while the file is written.
+        let frames = self.shared.ring().snapshot();
+        if frames.is_empty() {
+            return Err(no_keyframe());
+        }
+        let file = File::create(path).map_err(|e| write_error(path, e))?;
+        self.shared.finish(write_dump(path, file, &frames))
+    }
+
+    /// The last dump written, by a call or a trigger message (once the writer thread is done
+    /// with it).
+    pub fn last_dump(&self) -> Option<RingDump> {
+        self.shared.last_dump.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    pub fn buffered_duration(&self) -> Duration {
+        self.shared.ring().buffered_duration()
+    }
+
+    pub fn buffered_bytes(&self) -> usize {
+        self.shared.ring().buffered_bytes()
+    }
+}
+
+impl CreateInPipelineWith<RingBufferConfig> for RingBufferHostNode {
+    fn create_with(pipeline: &Pipeline, config: RingBufferConfig) -> Result<Self> {
+        let shared = Arc::new(Shared {
+            ring: Mutex::new(EncodedFrameRing::new(config.duration, config.max_bytes)),
+            output_dir: config.output_dir,
+            file_prefix: config.file_prefix,
+            last_dump: Mutex::new(None),
+        });
+        let (jobs, job_rx) = mpsc::channel();
+        let (error_tx, errors) = mpsc::channel();
+        let writer_shared = Arc::clone(&shared);
+        let writer = std::thread::Builder::new()
+            .name("depthai-ring-buffer".to_string())
+            .spawn(move || run_writer(writer_shared, job_rx, error_tx))
+            .map_err(|e| DepthaiError::new(format!("ring buffer: failed to spawn writer thread: {e}")))?;
+        let node_shared = Arc::clone(&shared);
+        let node = pipeline.create_threaded_host_node(|node| {
+            Ok(RingBufferImpl {
+                input: TypedInput::from_untyped(node.create_input(Some("in"))?),
+                trigger: node.create_input(Some("trigger"))?,
+                shared: node_shared,
+                jobs: Some(jobs),
+                errors,
+                writer: Some(writer),
+            })
+        })?;
+        Ok(Self { node, shared })
+    }
+}
@@ -0,0 +1,321 @@
+//! ROS 2 message bridge (requires the `ros2` feature).
+//!
+//! Converts DepthAI messages into plain structs laid out like the ROS 2 `sensor_msgs`,
+//! `std_msgs` and `builtin_interfaces` messages. Field names match the Rust types generated by
+//! rclrs / r2r, so publishing is a field-by-field move and this crate does not depend on a ROS
+//! installation.
+//!
+//! DepthAI timestamps live on the host steady clock; a [`TimeBase`] maps them to ROS time.
+//!
+//! ```no_run
+//! # use depthai::Result;
+//! # use depthai::camera::OutputQueue;
+//! # use depthai::ros2::{self, TimeBase};
+//! # fn f(queue: OutputQueue) -> Result<()> {
+//! let time = TimeBase::now();
+//! while let Some(frame) = queue.blocking_next(None)? {
+//!     let msg = ros2::image_to_ros(&frame, "oak_rgb_camera_optical_frame", &time)?;
+//!     // publisher.publish(sensor_msgs::msg::Image { height: msg.height, .. })
+//! # let _ = msg;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::camera::ImageFrame;
+use crate::common::ImageFrameType;
+use crate::convert;
+use crate::diagnostics::host_clock_now;
+use crate::error::{DepthaiError, Result};
+use crate::imu::ImuData;
+use crate::pointcloud::PointCloudData;
+use crate::rgbd::DepthUnit;
+
+/// `builtin_interfaces/Time`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Time {
+    pub sec: i32,
+    pub nanosec: u32,
+}
+
+impl Time {
+    pub fn from_nanos(ns: i128) -> Self {
+        Self {
+            sec: ns.div_euclid(1_000_000_000) as i32,
+            nanosec: ns.rem_euclid(1_000_000_000) as u32,
+        }
+    }
+
+    pub fn as_nanos(&self) -> i128 {
+        self.sec as i128 * 1_000_000_000 + self.nanosec as i128
+    }
+}
+
+/// Maps host steady-clock timestamps (as carried by DepthAI messages) to ROS time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeBase {
+    ros_ns: i128,
+    steady_ns: i128,
+}
+
+impl TimeBase {
+    /// Anchor the mapping at the current system (wall clock) time.
+    pub fn now() -> Self {
+        let system = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        Self::with_ros_time(Time::from_nanos(system.as_nanos() as i128))
+    }
+
+    /// Anchor the mapping at `ros_now`, e.g. the current time of a ROS node clock (sim time).
+    pub fn with_ros_time(ros_now: Time) -> Self {
+        Self::with_anchor(ros_now, host_clock_now())
+    }
+
+    /// Anchor the mapping so that host steady-clock time `steady` corresponds to `ros`.
+    pub fn with_anchor(ros: Time, steady: Duration) -> Self {
+        Self {
+            ros_ns: ros.as_nanos(),
+            steady_ns: steady.as_nanos() as i128,
+        }
+    }
+
+    /// ROS time of a message timestamp.
+    pub fn stamp(&self, timestamp: Duration) -> Time {
+        Time::from_nanos(self.ros_ns + timestamp.as_nanos() as i128 - self.steady_ns)
+    }
+}
+
+/// `std_msgs/Header`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Header {
+    pub stamp: Time,
+    pub frame_id: String,
+}
+
+/// `sensor_msgs/Image`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Image {
+    pub header: Header,
+    pub height: u32,
+    pub width: u32,
+    /// See `sensor_msgs/image_encodings.hpp`, e.g. `rgb8`, `mono8`, `16UC1`.
+    pub encoding: String,
+    pub is_bigendian: u8,
+    /// Row length in bytes.
+    pub step: u32,
+    pub data: Vec<u8>,
+}
+
+/// `sensor_msgs/PointField`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PointField {
+    pub name: String,
+    pub offset: u32,
+    pub datatype: u8,
+    pub count: u32,
+}
+
+impl PointField {
+    pub const INT8: u8 = 1;
+    pub const UINT8: u8 = 2;
+    pub const INT16: u8 = 3;
+    pub const UINT16: u8 = 4;
+    pub const INT32: u8 = 5;
+    pub const UINT32: u8 = 6;
+    pub const FLOAT32: u8 = 7;
+    pub const FLOAT64: u8 = 8;
+}
+
+/// `sensor_msgs/PointCloud2`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PointCloud2 {
+    pub header: Header,
+    pub height: u32,
+    pub width: u32,
+    pub fields: Vec<PointField>,
+    pub is_bigendian: bool,
+    pub point_step: u32,
+    pub row_step: u32,
+    pub data: Vec<u8>,
+    pub is_dense: bool,
+}
+
+/// `geometry_msgs/Quaternion`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Quaternion {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub w: f64,
+}
+
+/// `geometry_msgs/Vector3`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Vector3 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+/// `sensor_msgs/Imu`. A covariance whose first element is `-1` marks a missing measurement.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Imu {
+    pub header: Header,
+    pub orientation: Quaternion,
+    pub orientation_covariance: [f64; 9],
+    pub angular_velocity: Vector3,
+    pub angular_velocity_covariance: [f64; 9],
+    pub linear_acceleration: Vector3,
+    pub linear_acceleration_covariance: [f64; 9],
+}
+
+const MISSING: [f64; 9] = [-1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0];
+
+/// Convert a frame to `sensor_msgs/Image`.
+///
+/// Interleaved and single-channel frames are copied as-is (`rgb8`, `bgr8`, `rgba8`, `mono8`, and
+/// `16UC1` for `RAW16`, i.e. depth in millimeters). NV12/YUV and planar frames are converted to
+/// `rgb8` with [`convert::to_rgb888i`].
+pub fn image_to_ros(frame: &ImageFrame, frame_id: &str, time: &TimeBase) -> Result<Image> {
+    use ImageFrameType::*;
+    let (width, height) = (frame.width(), frame.height());
+    let format = frame
+        .format()
+        .ok_or_else(|| DepthaiError::new("frame has an unknown type"))?;
+    let (encoding, bytes_per_pixel) = match format {
+        RGB888i => ("rgb8", 3),
+        BGR888i => ("bgr8", 3),
+        RGBA8888 => ("rgba8", 4),
+        GRAY8 | RAW8 | YUV400p => ("mono8", 1),
+        RAW16 => ("16UC1", 2),
+        NV12 | NV21 | YUV420p | RGB888p | BGR888p => ("rgb8", 0),
+        other => {
+            return Err(DepthaiError::new(format!("{other:?} frames have no ROS image encoding")));
+        }
+    };
+    let (step, data) = if bytes_per_pixel == 0 {
+        (width * 3, convert::to_rgb888i(frame)?)
+    } else {
+        let packed = width * bytes_per_pixel;
+        let step = match frame.stride() as u32 {
+            0 => packed,
+            stride => stride.max(packed),
+        };
+        let mut data = frame.bytes();
+        let needed = (step * height.saturating_sub(1) + packed) as usize;
+        if height > 0 && data.len() < needed {
+            return Err(DepthaiError::new(format!(
+                "{format:?} frame of {width}x{height} needs {needed} bytes, got {}",
+                data.len()
+            )));
+        }
+        data.resize((step * height) as usize, 0);
+        (step, data)
+    };
+    Ok(Image {
+        header: Header {
+            stamp: time.stamp(frame.timestamp()?),
+            frame_id: frame_id.to_string(),
+        },
+        height,
+        width,
+        encoding: encoding.to_string(),
+        is_bigendian: 0,
+        step,
+        data,
+    })
+}
+
+/// Convert a point cloud to `sensor_msgs/PointCloud2` with `x`, `y`, `z` (meters) and a packed
+/// `rgb` field (PCL convention: `0x00RRGGBB` stored in a float32).
+///
+/// `unit` is the unit of the source points: DepthAI produces millimeters unless configured
+/// otherwise. Organized clouds keep their width/height. Points with `z == 0` are invalid, so a
+/// cloud containing any is not dense.
+pub fn point_cloud_to_ros(cloud: &PointCloudData, frame_id: &str, unit: DepthUnit, time: &TimeBase) -> Result<PointCloud2> {
+    const POINT_STEP: u32 = 16;
+    let scale = unit.scale_to_meters()?;
+    let points = cloud.points();
+    let (width, height) = if cloud.width() as usize * cloud.height() as usize == points.len() {
+        (cloud.width(), cloud.height())
+    } else {
+        (points.len() as u32, 1)
+    };
+    let mut data = Vec::with_capacity(points.len() * POINT_STEP as usize);
+    let mut is_dense = true;
+    for p in points {
+        if !p.is_valid() {
+            is_dense = false;
+        }
+        let rgb = u32::from_be_bytes([0, p.r, p.g, p.b]);
+        data.extend_from_slice(&(p.x * scale).to_le_bytes());
+        data.extend_from_slice(&(p.y * scale).to_le_bytes());
+        data.extend_from_slice(&(p.z * scale).to_le_bytes());
+        data.extend_from_slice(&rgb.to_le_bytes());
+    }
+    let field = |name: &str, offset| PointField {
+        name: name.to_string(),
+        offset,
+        datatype: PointField::FLOAT32,
+        count: 1,
+    };
+    Ok(PointCloud2 {
+        header: Header {
+            stamp: time.stamp(cloud.timestamp()?),
+            frame_id: frame_id.to_string(),
+        },
+        height,
+        width,
+        fields: vec![field("x", 0), field("y", 4), field("z", 8), field("rgb", 12)],
+        is_bigendian: false,
+        point_step: POINT_STEP,
+        row_step: POINT_STEP * width,
+        data,
+        is_dense,
+    })
+}
+
+/// Convert an IMU batch to one `sensor_msgs/Imu` per packet.
+///
+/// The accelerometer feeds `linear_acceleration` (m/s^2), the gyroscope `angular_velocity`
+/// (rad/s) and the rotation vector `orientation`; missing reports are flagged through their
+/// covariance. Each message is stamped with its accelerometer sample, falling back to the
+/// gyroscope and then the rotation vector. Packets without any report are skipped.
+pub fn imu_to_ros(data: &ImuData, frame_id: &str, time: &TimeBase) -> Result<Vec<Imu>> {
+    let mut out = Vec::new();
+    for packet in data.packets()? {
+        let stamp = packet
+            .accelerometer
+            .map(|r| r.timestamp)
+            .or(packet.gyroscope.map(|r| r.timestamp))
+            .or(packet.rotation_vector.map(|r| r.timestamp));
+        let Some(stamp) = stamp else {
+            continue;
+        };
+        let mut msg = Imu {
+            header: Header {
+                stamp: time.stamp(stamp),
+                frame_id: frame_id.to_string(),
+            },
+            orientation_covariance: MISSING,
+            angular_velocity_covariance: MISSING,
+            linear_acceleration_covariance: MISSING,
+            ..Default::default()
+        };
+        if let Some(r) = packet.rotation_vector {
+            msg.orientation = Quaternion { x: r.i as f64, y: r.j as f64, z: r.k as f64, w: r.real as f64 };
+            msg.orientation_covariance = [0.0; 9];
+        }
+        if let Some(r) = packet.gyroscope {
+            msg.angular_velocity = Vector3 { x: r.x as f64, y: r.y as f64, z: r.z as f64 };
+            msg.angular_velocity_covariance = [0.0; 9];
+        }
+        if let Some(r) = packet.accelerometer {
+            msg.linear_acceleration = Vector3 { x: r.x as f64, y: r.y as f64, z: r.z as f64 };
+            msg.linear_acceleration_covariance = [0.0; 9];
+        }
+        out.push(msg);
+    }
+    Ok(out)
+}
@@ -0,0 +1,333 @@
+//! RTP packetization of H.264 / H.265 bitstreams.
+//!
+//! [`RtpPacketizer`] turns the Annex-B access units produced by a
+//! [`VideoEncoderNode`](crate::video_encoder::VideoEncoderNode) into RTP packets following
+//! RFC 6184 (H.264) and RFC 7798 (H.265): NAL units larger than the MTU are split into
+//! fragmentation units (FU-A / FU), small ones such as SPS/PPS are aggregated (STAP-A / AP), and the
+//! last packet of each frame carries the marker bit. Every packet is a complete datagram, ready for
+//! a UDP socket.
+//!
+//! ```no_run
+//! # use std::net::UdpSocket;
+//! # use depthai::Result;
+//! # use depthai::encoded_frame::EncodedFrameQueue;
+//! # use depthai::rtp::{RtpCodec, RtpConfig, RtpPacketizer};
+//! # fn stream(queue: &EncodedFrameQueue) -> Result<()> {
+//! let socket = UdpSocket::bind("0.0.0.0:0").expect("bind");
+//! let mut rtp = RtpPacketizer::new(RtpCodec::H264, RtpConfig::default())?;
+//! while let Some(frame) = queue.blocking_next(None)? {
+//!     for packet in rtp.packetize(&frame)? {
+//!         socket.send_to(&packet, "127.0.0.1:5004").expect("send");
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use bytes::{Bytes, BytesMut};
+
+use crate::encoded_frame::{EncodedFrame, EncodedFrameProfile};
+use crate::error::{DepthaiError, Result};
+
+/// Size of the fixed RTP header (no CSRCs, no extension).
+pub const RTP_HEADER_LEN: usize = 12;
+
+/// RTP clock rate of H.264 / H.265 payloads.
+const CLOCK_RATE: u128 = 90_000;
+
+/// Video codec of the bitstream being packetized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RtpCodec {
+    H264,
+    H265,
+}
+
+impl RtpCodec {
+    /// Codec of encoder output with `profile`; `None` for MJPEG.
+    pub fn from_profile(profile: EncodedFrameProfile) -> Option<Self> {
+        match profile {
+            EncodedFrameProfile::Avc => Some(Self::H264),
+            EncodedFrameProfile::Hevc => Some(Self::H265),
+            EncodedFrameProfile::Jpeg => None,
+        }
+    }
+
+    fn nal_header_len(self) -> usize {
+        match self {
+            Self::H264 => 1,
+            Self::H265 => 2,
+        }
+    }
+
+    fn nal_type(self, nal: &[u8]) -> u8 {
+        match self {
+            Self::H264 => nal[0] & 0x1F,
+            Self::H265 => (nal[0] >> 1) & 0x3F,
+        }
+    }
+
+    /// SPS / PPS, plus VPS for H.265.
+    fn is_parameter_set(self, nal: &[u8]) -> bool {
+        match self {
+            Self::H264 => matches!(self.nal_type(nal), 7 | 8),
+            Self::H265 => matches!(self.nal_type(nal), 32..=34),
+        }
+    }
+
+    /// IDR picture (H.264) or IRAP picture (H.265).
+    fn is_keyframe(self, nal: &[u8]) -> bool {
+        match self {
+            Self::H264 => self.nal_type(nal) == 5,
+            Self::H265 => matches!(self.nal_type(nal), 16..=21),
+        }
+    }
+}
+
+/// Settings of an [`RtpPacketizer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RtpConfig {
+    /// Largest datagram produced, RTP header included.
+    pub mtu: usize,
+    /// Synchronization source identifier; random by default, as RFC 3550 asks.
+    pub ssrc: u32,
+    /// Dynamic payload type announced in the SDP (`96..=127`).
+    pub payload_type: u8,
+    /// Combine consecutive small NAL units into aggregation packets (STAP-A / AP).
+    pub aggregate: bool,
+}
+
+impl Default for RtpConfig {
+    fn default() -> Self {
+        Self {
+            mtu: 1200,
+            ssrc: random_u32(),
+            payload_type: 96,
+            aggregate: true,
+        }
+    }
+}
+
+/// Splits encoded frames into RTP packets, see the [module docs](self).
+///
+/// The packetizer remembers the last parameter sets it saw and repeats them in front of
+/// keyframes that come without, so receivers joining mid-stream can start decoding at the next
+/// keyframe.
+#[derive(Debug, Clone)]
+pub struct RtpPacketizer {
+    codec: RtpCodec,
+    config: RtpConfig,
+    sequence_number: u16,
+    timestamp_offset: u32,
+    parameter_sets: Vec<Bytes>,
+}
+
+impl RtpPacketizer {
+    /// Fails if `config.mtu` leaves no room for a fragment payload.
+    pub fn new(codec: RtpCodec, config: RtpConfig) -> Result<Self> {
+        if config.mtu <= RTP_HEADER_LEN + codec.nal_header_len() + 1 {
+            return Err(DepthaiError::new(format!("RTP MTU of {} bytes is too small", config.mtu)));
+        }
+        if config.payload_type > 127 {
+            return Err(DepthaiError::new(format!("invalid RTP payload type {}", config.payload_type)));
+        }
+        Ok(Self {
+            codec,
+            config,
+            sequence_number: random_u32() as u16,
+            timestamp_offset: random_u32(),
+            parameter_sets: Vec::new(),
+        })
+    }
+
+    pub fn codec(&self) -> RtpCodec {
+        self.codec
+    }
+
+    pub fn config(&self) -> &RtpConfig {
+        &self.config
+    }
+
+    /// Sequence number of the next packet.
+    pub fn sequence_number(&self) -> u16 {
+        self.sequence_number
+    }
+
+    /// Last SPS/PPS (and VPS) seen, e.g. for `sprop-parameter-sets` in an SDP.
+    pub fn parameter_sets(&self) -> &[Bytes] {
+        &self.parameter_sets
+    }
+
+    /// Packetize one encoded frame, stamped with its host timestamp.
+    pub fn packetize(&mut self, frame: &EncodedFrame) -> Result<Vec<Bytes>> {
+        let profile = frame.profile();
+        if profile.and_then(RtpCodec::from_profile) != Some(self.codec) {
+            return Err(DepthaiError::new(format!(
+                "cannot packetize a {profile:?} frame as {:?}",
+                self.codec
+            )));
+        }
+        Ok(self.packetize_annexb(&frame.bytes(), frame.timestamp()?))
+    }
+
+    /// Packetize one Annex-B access unit (NAL units behind `00 00 01` start codes).
+    ///
+    /// `timestamp` is converted to the 90 kHz RTP clock; all packets of the access unit share it.
+    pub fn packetize_annexb(&mut self, data: &[u8], timestamp: Duration) -> Vec<Bytes> {
+        let codec = self.codec;
+        let nals: Vec<&[u8]> = split_annexb(data)
+            .into_iter()
+            .filter(|nal| nal.len() > codec.nal_header_len())
+            .collect();
+
+        let cached = self.parameter_sets.clone();
+        let units: Vec<&[u8]> = if nals.iter().any(|nal| codec.is_parameter_set(nal)) {
+            self.parameter_sets = nals
+                .iter()
+                .filter(|nal| codec.is_parameter_set(nal))
+                .map(|nal| Bytes::copy_from_slice(nal))
+                .collect();
+            nals
+        } else if nals.iter().any(|nal| codec.is_keyframe(nal)) {
+            cached.iter().map(|nal| nal.as_ref()).chain(nals).collect()
+        } else {
+            nals
+        };
+
+        let ticks = timestamp.as_nanos() * CLOCK_RATE / 1_000_000_000;
+        let rtp_timestamp = (ticks as u32).wrapping_add(self.timestamp_offset);
+
+        let mut packets = Vec::new();
+        let mut pending: Vec<&[u8]> = Vec::new();
+        for nal in units {
+            if nal.len() > self.max_payload() {
+                self.flush(&mut packets, &mut pending, rtp_timestamp);
+                self.fragment(&mut packets, nal, rtp_timestamp);
+                continue;
+            }
+            if !self.config.aggregate || self.aggregate_len(&pending, nal) > self.max_payload() {
+                self.flush(&mut packets, &mut pending, rtp_timestamp);
+            }
+            pending.push(nal);
+        }
+        self.flush(&mut packets, &mut pending, rtp_timestamp);
+
+        if let Some(last) = packets.last_mut() {
+            last[1] |= 0x80;
+        }
+        packets.into_iter().map(BytesMut::freeze).collect()
+    }
+
+    fn max_payload(&self) -> usize {
+        self.config.mtu - RTP_HEADER_LEN
+    }
+
+    /// Payload size of an aggregation packet holding `pending` and `nal`.
+    fn aggregate_len(&self, pending: &[&[u8]], nal: &[u8]) -> usize {
+        self.codec.nal_header_len() + pending.iter().chain([&nal]).map(|n| 2 + n.len()).sum::<usize>()
+    }
+
+    /// Emit `pending` as a single NAL unit packet, or as one aggregation packet.
+    fn flush(&mut self, packets: &mut Vec<BytesMut>, pending: &mut Vec<&[u8]>, timestamp: u32) {
+        match pending.len() {
+            0 => {}
+            1 => {
+                let mut packet = self.header(timestamp);
+                packet.extend_from_slice(pending[0]);
+                packets.push(packet);
+            }
+            _ => {
+                let mut packet = self.header(timestamp);
+                match self.codec {
+                    RtpCodec::H264 => {
+                        // STAP-A: F is the OR and NRI the maximum of the aggregated units.
+                        let f = pending.iter().fold(0, |acc, nal| acc | (nal[0] & 0x80));
+                        let nri = pending.iter().map(|nal| nal[0] & 0x60).max().unwrap_or(0);
+                        packet.extend_from_slice(&[f | nri | 24]);
+                    }
+                    RtpCodec::H265 => {
+                        // AP: lowest LayerId and TID of the aggregated units.
+                        let f = pending.iter().fold(0, |acc, nal| acc | (nal[0] & 0x80));
+                        let layer = pending.iter().map(|nal| ((nal[0] & 1) << 5) | (nal[1] >> 3)).min().unwrap_or(0);
+                        let tid = pending.iter().map(|nal| nal[1] & 0x07).min().unwrap_or(1);
+                        packet.extend_from_slice(&[f | (48 << 1) | (layer >> 5), ((layer & 0x1F) << 3) | tid]);
+                    }
+                }
+                for nal in pending.iter() {
+                    packet.extend_from_slice(&(nal.len() as u16).to_be_bytes());
+                    packet.extend_from_slice(nal);
+                }
+                packets.push(packet);
+            }
+        }
+        pending.clear();
+    }
+
+    /// Split `nal` into fragmentation units (FU-A for H.264, FU for H.265).
+    fn fragment(&mut self, packets: &mut Vec<BytesMut>, nal: &[u8], timestamp: u32) {
+        let (indicator, nal_type, body): (&[u8], u8, &[u8]) = match self.codec {
+            RtpCodec::H264 => (&[(nal[0] & 0xE0) | 28], nal[0] & 0x1F, &nal[1..]),
+            RtpCodec::H265 => (&[(nal[0] & 0x81) | (49 << 1), nal[1]], (nal[0] >> 1) & 0x3F, &nal[2..]),
+        };
+        let chunk_len = self.max_payload() - indicator.len() - 1;
+        let count = body.len().div_ceil(chunk_len);
+        for (i, chunk) in body.chunks(chunk_len).enumerate() {
+            let mut fu_header = nal_type;
+            if i == 0 {
+                fu_header |= 0x80;
+            }
+            if i + 1 == count {
+                fu_header |= 0x40;
+            }
+            let mut packet = self.header(timestamp);
+            packet.extend_from_slice(indicator);
+            packet.extend_from_slice(&[fu_header]);
+            packet.extend_from_slice(chunk);
+            packets.push(packet);
+        }
+    }
+
+    /// RTP header of the next packet, marker bit cleared.
+    fn header(&mut self, timestamp: u32) -> BytesMut {
+        let mut packet = BytesMut::with_capacity(self.config.mtu);
+        packet.extend_from_slice(&[0x80, self.config.payload_type]);
+        packet.extend_from_slice(&self.sequence_number.to_be_bytes());
+        packet.extend_from_slice(&timestamp.to_be_bytes());
+        packet.extend_from_slice(&self.config.ssrc.to_be_bytes());
+        self.sequence_number = self.sequence_number.wrapping_add(1);
+        packet
+    }
+}
+
+/// NAL units of an Annex-B byte stream, without start codes or trailing zero bytes.
+pub fn split_annexb(data: &[u8]) -> Vec<&[u8]> {
+    let mut starts = Vec::new();
+    let mut i = 0;
+    while i + 3 <= data.len() {
+        if data[i] == 0 && data[i + 1] == 0 && data[i + 2] == 1 {
+            starts.push(i + 3);
+            i += 3;
+        } else {
+            i += 1;
+        }
+    }
+    let mut nals = Vec::with_capacity(starts.len());
+    for (n, &start) in starts.iter().enumerate() {
+        let end = starts.get(n + 1).map_or(data.len(), |&next| next - 3);
+        let mut nal = &data[start..end];
+        while let [rest @ .., 0] = nal {
+            nal = rest;
+        }
+        if !nal.is_empty() {
+            nals.push(nal);
+        }
+    }
+    nals
+}
+
+fn random_u32() -> u32 {
+    RandomState::new().build_hasher().finish() as u32
+}
@@ -0,0 +1,174 @@
+//! Process-level helpers.
+//!
+//! A process killed while a pipeline runs (Ctrl-C, `systemctl stop`, a supervisor's SIGTERM)
+//! skips every destructor, and the device is regularly left in a state that takes a replug to
+//! recover from. [`install_shutdown_handler`] catches SIGINT/SIGTERM (Ctrl-C and Ctrl-Break on
+//! Windows) and shuts down cleanly instead:
+//!
+//! 1. every registered pipeline that is still running is stopped, which also runs the host
+//!    nodes' `on_stop` (where e.g. [`DatasetRecorder`](crate::DatasetRecorder) finishes its files);
+//! 2. the pipelines' devices are closed;
+//! 3. the [`CancellationToken`] of every registration is cancelled, so `*_cancellable` calls
+//!    fail with a cancelled error and the application can leave its loops and exit normally.
+//!
+//! ```no_run
+//! # use depthai::prelude::*;
+//! # use depthai::runtime;
+//! # fn main() -> Result<()> {
+//! let pipeline = Pipeline::new().build()?;
+//! # let camera = pipeline.create_with::<CameraNode, _>(CameraBoardSocket::CamA)?;
+//! # let queue = camera.request_output(CameraOutputConfig::new((640, 400)))?.create_message_queue(4, false)?;
+//! let shutdown = runtime::install_shutdown_handler(pipeline.clone())?;
+//! pipeline.start()?;
+//! loop {
+//!     match queue.get_cancellable(None, shutdown.token()) {
+//!         Ok(msg) => {
+//!             // ...
+//! #           let _ = msg;
+//!         }
+//!         Err(e) if e.is_cancelled() => break,
+//!         Err(e) => return Err(e),
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! A second signal during the shutdown exits at once with status 130.
+//!
+//! Each registration gets its own token, cancelled by the next shutdown; registering again after
+//! a shutdown gives a fresh one, so an application can restart its pipelines and shut down again.
+//! Hooking the signals needs the `signals` feature; [`register`] and
+//! [`ShutdownHandle::shutdown`] work without it.
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock, Weak};
+
+use crate::cancel::CancellationToken;
+#[cfg(feature = "signals")]
+use crate::error::{DepthaiError, Result};
+use crate::pipeline::{Pipeline, PipelineInner};
+
+struct Registration {
+    id: u64,
+    /// Not kept alive by the registration.
+    pipeline: Weak<PipelineInner>,
+    token: CancellationToken,
+}
+
+#[derive(Default)]
+struct ShutdownState {
+    registrations: Mutex<Vec<Registration>>,
+    next_id: AtomicU64,
+    /// A shutdown is running.
+    in_progress: AtomicBool,
+    #[cfg(feature = "signals")]
+    signals_hooked: Mutex<bool>,
+}
+
+impl ShutdownState {
+    fn shutdown(&self) {
+        if self.in_progress.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let registrations = std::mem::take(&mut *self.registrations.lock().unwrap_or_else(|e| e.into_inner()));
+        for pipeline in registrations.iter().filter_map(|r| Pipeline::upgrade(&r.pipeline)) {
+            // Best effort: keep going so the other pipelines and devices are released too.
+            if pipeline.is_running().unwrap_or(false) {
+                let _ = pipeline.stop();
+            }
+            if let Ok(device) = pipeline.default_device() {
+                let _ = device.close();
+            }
+        }
+        for registration in &registrations {
+            registration.token.cancel();
+        }
+        self.in_progress.store(false, Ordering::SeqCst);
+    }
+}
+
+fn state() -> &'static ShutdownState {
+    static STATE: OnceLock<ShutdownState> = OnceLock::new();
+    STATE.get_or_init(ShutdownState::default)
+}
+
+/// One registration with the process-wide shutdown, see the [module docs](self).
+#[derive(Clone)]
+pub struct ShutdownHandle {
+    state: &'static ShutdownState,
+    id: u64,
+    token: CancellationToken,
+}
+
+impl ShutdownHandle {
+    /// Cancelled once a shutdown has stopped the pipelines and closed their devices.
+    pub fn token(&self) -> &CancellationToken {
+        &self.token
+    }
+
+    /// Whether a shutdown is running or has ended this registration.
+    pub fn is_shutting_down(&self) -> bool {
+        self.token.is_cancelled() || self.state.in_progress.load(Ordering::SeqCst)
+    }
+
+    /// Shut down now, as if a signal had arrived: stops every registered pipeline, not only
+    /// this one. Does nothing while another shutdown runs.
+    ///
+    /// For applications with their own signal handling, or a supervisor thread.
+    pub fn shutdown(&self) {
+        self.state.shutdown();
+    }
+
+    /// Leave the shutdown: its pipeline is no longer stopped by it and the token is left as is.
+    pub fn unregister(&self) {
+        let mut registrations = self.state.registrations.lock().unwrap_or_else(|e| e.into_inner());
+        registrations.retain(|r| r.id != self.id);
+    }
+}
+
+/// Have the shutdown stop `pipeline` and close its device, without hooking any signal.
+pub fn register(pipeline: &Pipeline) -> ShutdownHandle {
+    let state = state();
+    let handle = ShutdownHandle {
+        state,
+        id: state.next_id.fetch_add(1, Ordering::SeqCst),
+        token: CancellationToken::new(),
+    };
+    let mut registrations = state.registrations.lock().unwrap_or_else(|e| e.into_inner());
+    registrations.retain(|r| r.pipeline.strong_count() > 0);
+    registrations.push(Registration {
+        id: handle.id,
+        pipeline: pipeline.downgrade(),
+        token: handle.token.clone(),
+    });
+    handle
+}
+
+/// Shut down on SIGINT/SIGTERM (Ctrl-C/Ctrl-Break on Windows) and [`register`] `pipeline`.
+///
+/// The signal handler is installed once per process; later calls only register their pipeline.
+/// Fails, without registering, if the application already installed a handler through the
+/// `ctrlc` crate; call [`ShutdownHandle::shutdown`] from that handler instead.
+#[cfg(feature = "signals")]
+pub fn install_shutdown_handler(pipeline: Pipeline) -> Result<ShutdownHandle> {
+    hook_signals()?;
+    Ok(register(&pipeline))
+}
+
+#[cfg(feature = "signals")]
+fn hook_signals() -> Result<()> {
+    let state = state();
+    let mut hooked = state.signals_hooked.lock().unwrap_or_else(|e| e.into_inner());
+    if !*hooked {
+        ctrlc::set_handler(move || {
+            if state.in_progress.load(Ordering::SeqCst) {
+                std::process::exit(130);
+            }
+            state.shutdown();
+        })
+        .map_err(|e| DepthaiError::new(format!("failed to install shutdown handler: {e}")))?;
+        *hooked = true;
+    }
+    Ok(())
+}
@@ -0,0 +1,294 @@
+//! Visualization and recording sinks.
+//!
+//! A [`FrameSink`] receives the messages arriving on the named inputs of a [`SinkHostNode`],
+//! which owns the threaded-host-node plumbing (input creation, polling, error reporting). Rerun
+//! support ([`RerunSink`](crate::rerun_host_node::RerunSink), `rerun` feature) is one
+//! implementation; an egui or web-socket viewer only needs to implement the trait.
+//!
+//! ```no_run
+//! # use depthai::{Pipeline, Result};
+//! # use depthai::camera::ImageFrame;
+//! # use depthai::sink::{create_sink_host_node, FrameSink, SinkContext, SinkInput};
+//! struct Counter(u64);
+//!
+//! impl FrameSink for Counter {
+//!     fn on_frame(&mut self, _ctx: &SinkContext<'_>, _frame: &ImageFrame) -> Result<()> {
+//!         self.0 += 1;
+//!         Ok(())
+//!     }
+//! }
+//!
+//! # fn main() -> Result<()> {
+//! # let pipeline = Pipeline::new().build()?;
+//! # let out = pipeline.create_node("dai::node::Camera")?.output("raw")?;
+//! let node = create_sink_host_node(&pipeline, vec![SinkInput::image("rgb", "camera/rgb")], Counter(0))?;
+//! out.link(&node.input("rgb")?)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::camera::ImageFrame;
+use crate::detections::ImgDetections;
+use crate::error::{DepthaiError, Result};
+use crate::output::Input;
+use crate::pipeline::{Node, Pipeline};
+use crate::pointcloud::PointCloudData;
+use crate::queue::Datatype;
+use crate::rgbd::DepthUnit;
+use crate::threaded_host_node::{ThreadedHostNode, ThreadedHostNodeContext, ThreadedHostNodeImpl};
+
+/// What a [`SinkInput`] carries.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SinkInputKind {
+    /// Color or grayscale frames, delivered to [`FrameSink::on_frame`].
+    Image,
+    /// `RAW16` depth frames in the given unit, delivered to [`FrameSink::on_frame`].
+    Depth(DepthUnit),
+    /// Point clouds in the given unit, delivered to [`FrameSink::on_pointcloud`].
+    PointCloud(DepthUnit),
+    /// `ImgDetections` for frames of `frame_size`, delivered to [`FrameSink::on_detections`].
+    /// `labels` maps label ids to class names.
+    Detections { frame_size: (u32, u32), labels: Vec<String> },
+}
+
+/// One named input of a [`SinkHostNode`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SinkInput {
+    /// Input port name.
+    pub name: String,
+    /// Where the sink should put this stream, e.g. the Rerun entity path.
+    pub entity_path: String,
+    pub kind: SinkInputKind,
+}
+
+impl SinkInput {
+    pub fn image(name: &str, entity_path: &str) -> Self {
+        Self::new(name, entity_path, SinkInputKind::Image)
+    }
+
+    pub fn depth(name: &str, entity_path: &str, unit: DepthUnit) -> Self {
+        Self::new(name, entity_path, SinkInputKind::Depth(unit))
+    }
+
+    pub fn point_cloud(name: &str, entity_path: &str, unit: DepthUnit) -> Self {
+        Self::new(name, entity_path, SinkInputKind::PointCloud(unit))
+    }
+
+    pub fn detections(name: &str, entity_path: &str, frame_size: (u32, u32)) -> Self {
+        Self::new(name, entity_path, SinkInputKind::Detections { frame_size, labels: Vec::new() })
+    }
+
+    fn new(name: &str, entity_path: &str, kind: SinkInputKind) -> Self {
+        Self {
+            name: name.to_string(),
+            entity_path: entity_path.to_string(),
+            kind,
+        }
+    }
+}
+
+/// Where a message delivered to a [`FrameSink`] came from.
+#[derive(Debug, Clone, Copy)]
+pub struct SinkContext<'a> {
+    pub input: &'a SinkInput,
+    /// Messages from this input delivered before this one.
+    pub sequence: u64,
+}
+
+/// Receives the messages of a [`SinkHostNode`] on the node's thread.
+///
+/// An error drops the message; the node counts it and reports it as a node error, see
+/// [`Pipeline::take_node_errors`](crate::pipeline::Pipeline::take_node_errors).
+pub trait FrameSink: Send + 'static {
+    /// Image and depth frames, see [`SinkInputKind`].
+    fn on_frame(&mut self, ctx: &SinkContext<'_>, frame: &ImageFrame) -> Result<()>;
+
+    fn on_pointcloud(&mut self, _ctx: &SinkContext<'_>, _cloud: &PointCloudData) -> Result<()> {
+        Ok(())
+    }
+
+    fn on_detections(&mut self, _ctx: &SinkContext<'_>, _detections: &ImgDetections) -> Result<()> {
+        Ok(())
+    }
+
+    fn on_start(&mut self) {}
+    fn on_stop(&mut self) {}
+}
+
+/// Counters of a [`SinkHostNode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SinkStats {
+    /// Messages the sink accepted.
+    pub delivered: u64,
+    /// Messages of an unexpected type, or that the sink returned an error for.
+    pub dropped: u64,
+}
+
+#[derive(Default)]
+struct SharedStats {
+    delivered: AtomicU64,
+    dropped: AtomicU64,
+}
+
+impl SharedStats {
+    fn snapshot(&self) -> SinkStats {
+        SinkStats {
+            delivered: self.delivered.load(Ordering::Relaxed),
+            dropped: self.dropped.load(Ordering::Relaxed),
+        }
+    }
+}
+
+struct InputState {
+    input: Input,
+    config: SinkInput,
+    sequence: u64,
+}
+
+struct SinkHostNodeImpl<S: FrameSink> {
+    inputs: Vec<InputState>,
+    sink: S,
+    stats: Arc<SharedStats>,
+}
+
+impl<S: FrameSink> SinkHostNodeImpl<S> {
+    fn handle_message(&mut self, ctx: &ThreadedHostNodeContext, index: usize, msg: &Datatype) {
+        let state = &mut self.inputs[index];
+        let sink_ctx = SinkContext {
+            input: &state.config,
+            sequence: state.sequence,
+        };
+        let result = match &state.config.kind {
+            SinkInputKind::Image | SinkInputKind::Depth(_) => msg
+                .as_frame()
+                .and_then(|m| m.ok_or_else(|| DepthaiError::new("expected ImgFrame")))
+                .and_then(|frame| self.sink.on_frame(&sink_ctx, &frame)),
+            SinkInputKind::PointCloud(_) => msg
+                .as_pointcloud()
+                .and_then(|m| m.ok_or_else(|| DepthaiError::new("expected PointCloudData")))
+                .and_then(|cloud| self.sink.on_pointcloud(&sink_ctx, &cloud)),
+            SinkInputKind::Detections { .. } => msg
+                .as_img_detections()
+                .and_then(|m| m.ok_or_else(|| DepthaiError::new("expected ImgDetections")))
+                .and_then(|dets| self.sink.on_detections(&sink_ctx, &dets)),
+        };
+        match result {
+            Ok(()) => {
+                state.sequence += 1;
+                self.stats.delivered.fetch_add(1, Ordering::Relaxed);
+                ctx.report_success();
+            }
+            Err(err) => {
+                self.stats.dropped.fetch_add(1, Ordering::Relaxed);
+                ctx.report_error(&DepthaiError::new(format!(
+                    "sink: dropped a message on '{}': {err}",
+                    state.config.name
+                )));
+            }
+        }
+    }
+
+    /// A broken input stops the node; the failure names the input.
+    fn report_input_failure(&self, ctx: &ThreadedHostNodeContext, index: usize, err: &DepthaiError) {
+        ctx.report_error(&DepthaiError::new(format!(
+            "sink: input '{}' failed; stopping host node: {err}",
+            self.inputs[index].config.name
+        )));
+    }
+}
+
+impl<S: FrameSink> ThreadedHostNodeImpl for SinkHostNodeImpl<S> {
+    fn run(&mut self, ctx: &ThreadedHostNodeContext) {
+        while ctx.is_running() {
+            // A single input can wait; with several, poll them in turn so no stream starves.
+            if self.inputs.len() == 1 {
+                match ctx.next::<Datatype, _>(&self.inputs[0].input) {
+                    Ok(Some(msg)) => self.handle_message(ctx, 0, &msg),
+                    Ok(None) => {}
+                    Err(e) => {
+                        self.report_input_failure(ctx, 0, &e);
+                        break;
+                    }
+                }
+                continue;
+            }
+
+            let mut received = false;
+            for index in 0..self.inputs.len() {
+                match ctx.try_recv::<Datatype, _>(&self.inputs[index].input) {
+                    Ok(Some(msg)) => {
+                        received = true;
+                        self.handle_message(ctx, index, &msg);
+                    }
+                    Ok(None) => {}
+                    Err(e) => {
+                        self.report_input_failure(ctx, index, &e);
+                        return;
+                    }
+                }
+            }
+            if !received {
+                std::thread::sleep(Duration::from_millis(1));
+            }
+        }
+    }
+
+    fn on_start(&mut self) {
+        self.sink.on_start();
+    }
+
+    fn on_stop(&mut self) {
+        self.sink.on_stop();
+    }
+}
+
+/// Threaded host node feeding a [`FrameSink`], see the [module docs](self).
+#[derive(Clone)]
+pub struct SinkHostNode {
+    node: ThreadedHostNode,
+    stats: Arc<SharedStats>,
+}
+
+impl SinkHostNode {
+    pub fn as_node(&self) -> &Node {
+        self.node.as_node()
+    }
+
+    pub fn input(&self, name: &str) -> Result<Input> {
+        self.as_node().input(name)
+    }
+
+    pub fn stats(&self) -> SinkStats {
+        self.stats.snapshot()
+    }
+}
+
+/// Create a [`SinkHostNode`] with one input per entry of `inputs`.
+pub fn create_sink_host_node<S: FrameSink>(pipeline: &Pipeline, inputs: Vec<SinkInput>, sink: S) -> Result<SinkHostNode> {
+    if inputs.is_empty() {
+        return Err(DepthaiError::new("a sink host node needs at least one input"));
+    }
+    let stats = Arc::new(SharedStats::default());
+    let node = pipeline.create_threaded_host_node(|node| {
+        let inputs = inputs
+            .into_iter()
+            .map(|config| {
+                Ok(InputState {
+                    input: node.create_input(Some(&config.name))?,
+                    config,
+                    sequence: 0,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(SinkHostNodeImpl {
+            inputs,
+            sink,
+            stats: Arc::clone(&stats),
+        })
+    })?;
+    Ok(SinkHostNode { node, stats })
+}
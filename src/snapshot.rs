@@ -0,0 +1,202 @@
+//! Owned, serializable copies of messages (requires the `serde` feature).
+//!
+//! Message wrappers point into DepthAI-owned memory, so they can't leave the process as-is. A
+//! snapshot copies the payload and metadata out, e.g. to store golden data for tests or to send
+//! a message to another process. [`ImageFrame`], [`ImgDetections`], [`PointCloudData`] and
+//! [`ImuData`] also implement `Serialize` through their snapshot; frames and detections can be
+//! deserialized back into host-side messages.
+//!
+//! ```no_run
+//! # use depthai::Result;
+//! # use depthai::camera::{ImageFrame, ImageFrameType};
+//! # fn main() -> Result<()> {
+//! let frame = ImageFrame::new(2, 1, ImageFrameType::GRAY8, &[10, 20])?;
+//! let json = serde_json::to_string(&frame).expect("serialize");
+//! let copy: ImageFrame = serde_json::from_str(&json).expect("deserialize");
+//! assert_eq!(copy.bytes(), frame.bytes());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::Duration;
+
+use serde::de::Error as _;
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::camera::ImageFrame;
+use crate::common::ImageFrameType;
+use crate::detections::{ImgDetection, ImgDetections};
+use crate::error::{DepthaiError, Result};
+use crate::imu::{ImuData, ImuPacket};
+use crate::pointcloud::{Point3fRGBA, PointCloudData};
+
+/// Metadata and payload of an [`ImageFrame`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImageFrameSnapshot {
+    pub width: u32,
+    pub height: u32,
+    /// `None` for types this crate doesn't know.
+    pub frame_type: Option<ImageFrameType>,
+    /// Row stride of the first plane in bytes.
+    pub stride: usize,
+    /// Timestamp on the host steady clock.
+    pub timestamp: Duration,
+    pub timestamp_device: Duration,
+    pub sequence_num: i64,
+    pub data: Vec<u8>,
+}
+
+impl ImageFrameSnapshot {
+    pub fn capture(frame: &ImageFrame) -> Result<Self> {
+        Ok(Self {
+            width: frame.width(),
+            height: frame.height(),
+            frame_type: frame.format(),
+            stride: frame.stride(),
+            timestamp: frame.timestamp()?,
+            timestamp_device: frame.timestamp_device()?,
+            sequence_num: frame.sequence_num()?,
+            data: frame.bytes(),
+        })
+    }
+
+    /// Rebuild a host-side frame with the same payload, timestamp and sequence number.
+    ///
+    /// The device timestamp is not restored. Frames whose rows are padded (`stride` wider than
+    /// a packed row) are rejected by [`ImageFrame::new`].
+    pub fn to_frame(&self) -> Result<ImageFrame> {
+        let frame_type = self
+            .frame_type
+            .ok_or_else(|| DepthaiError::new("cannot rebuild a frame of unknown type"))?;
+        let frame = ImageFrame::new(self.width, self.height, frame_type, &self.data)?;
+        frame.set_timestamp(self.timestamp)?;
+        frame.set_sequence_num(self.sequence_num)?;
+        Ok(frame)
+    }
+}
+
+/// Detections of one frame with their metadata.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImgDetectionsSnapshot {
+    /// Timestamp on the host steady clock.
+    pub timestamp: Duration,
+    pub sequence_num: i64,
+    pub detections: Vec<ImgDetection>,
+}
+
+impl ImgDetectionsSnapshot {
+    pub fn capture(detections: &ImgDetections) -> Result<Self> {
+        Ok(Self {
+            timestamp: detections.timestamp()?,
+            sequence_num: detections.sequence_num()?,
+            detections: detections.detections()?,
+        })
+    }
+
+    /// Rebuild host-side detections, e.g. to replay them through a pipeline.
+    pub fn to_detections(&self) -> Result<ImgDetections> {
+        let mut detections = ImgDetections::new()?;
+        for detection in &self.detections {
+            detections.push(*detection)?;
+        }
+        detections.set_timestamp(self.timestamp)?;
+        detections.set_sequence_num(self.sequence_num)?;
+        Ok(detections)
+    }
+}
+
+/// Points of a [`PointCloudData`], row-major for organized clouds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PointCloudSnapshot {
+    pub width: u32,
+    pub height: u32,
+    /// Timestamp on the host steady clock.
+    pub timestamp: Duration,
+    pub points: Vec<Point3fRGBA>,
+}
+
+impl PointCloudSnapshot {
+    pub fn capture(cloud: &PointCloudData) -> Result<Self> {
+        Ok(Self {
+            width: cloud.width(),
+            height: cloud.height(),
+            timestamp: cloud.timestamp()?,
+            points: cloud.points().to_vec(),
+        })
+    }
+}
+
+/// Packets of an [`ImuData`] batch; each report carries its own timestamp.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImuDataSnapshot {
+    pub packets: Vec<ImuPacket>,
+}
+
+impl ImuDataSnapshot {
+    pub fn capture(data: &ImuData) -> Result<Self> {
+        Ok(Self { packets: data.packets()? })
+    }
+}
+
+impl ImageFrame {
+    pub fn snapshot(&self) -> Result<ImageFrameSnapshot> {
+        ImageFrameSnapshot::capture(self)
+    }
+}
+
+impl ImgDetections {
+    pub fn snapshot(&self) -> Result<ImgDetectionsSnapshot> {
+        ImgDetectionsSnapshot::capture(self)
+    }
+}
+
+impl PointCloudData {
+    pub fn snapshot(&self) -> Result<PointCloudSnapshot> {
+        PointCloudSnapshot::capture(self)
+    }
+}
+
+impl ImuData {
+    pub fn snapshot(&self) -> Result<ImuDataSnapshot> {
+        ImuDataSnapshot::capture(self)
+    }
+}
+
+impl Serialize for ImageFrame {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        self.snapshot().map_err(S::Error::custom)?.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ImageFrame {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        ImageFrameSnapshot::deserialize(deserializer)?.to_frame().map_err(D::Error::custom)
+    }
+}
+
+impl Serialize for ImgDetections {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        self.snapshot().map_err(S::Error::custom)?.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ImgDetections {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        ImgDetectionsSnapshot::deserialize(deserializer)?
+            .to_detections()
+            .map_err(D::Error::custom)
+    }
+}
+
+impl Serialize for PointCloudData {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        self.snapshot().map_err(S::Error::custom)?.serialize(serializer)
+    }
+}
+
+impl Serialize for ImuData {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        self.snapshot().map_err(S::Error::custom)?.serialize(serializer)
+    }
+}
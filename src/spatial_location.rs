@@ -0,0 +1,299 @@
+use autocxx::c_int;
+use depthai_sys::{depthai, DaiBuffer, DaiSpatialLocationData};
+
+use crate::camera::ImageFrame;
+use crate::common::Rect;
+use crate::depth::DepthAlgorithm;
+use crate::error::{clear_error_flag, last_error, take_error_if_any, Result};
+use crate::host_node::Buffer;
+
+/// One region whose depth and 3D position the calculator reports.
+///
+/// Mirrors C++: `dai::SpatialLocationCalculatorConfigData`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpatialLocationRoi {
+    /// Region in pixels of the depth frame, or normalized (see [`Rect::is_normalized`]).
+    pub roi: Rect,
+    /// Depth values below this (millimeters) are ignored.
+    pub lower_threshold: u32,
+    /// Depth values above this (millimeters) are ignored.
+    pub upper_threshold: u32,
+    pub algorithm: DepthAlgorithm,
+    /// Sample every `step`-th pixel; `None` lets the device pick from the ROI size.
+    pub step: Option<u32>,
+}
+
+impl SpatialLocationRoi {
+    /// A ROI with the C++ defaults: thresholds `0..=65535`, median, automatic step.
+    pub fn new(roi: Rect) -> Self {
+        Self {
+            roi,
+            lower_threshold: 0,
+            upper_threshold: 65535,
+            algorithm: DepthAlgorithm::Median,
+            step: None,
+        }
+    }
+
+    pub fn with_thresholds(mut self, lower: u32, upper: u32) -> Self {
+        self.lower_threshold = lower;
+        self.upper_threshold = upper;
+        self
+    }
+
+    pub fn with_algorithm(mut self, algorithm: DepthAlgorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
+    pub fn with_step(mut self, step: u32) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    fn from_raw(roi: [f32; 4], lower: u32, upper: u32, algorithm: i32, step: i32) -> Self {
+        Self {
+            roi: Rect::new(roi[0], roi[1], roi[2], roi[3]),
+            lower_threshold: lower,
+            upper_threshold: upper,
+            algorithm: DepthAlgorithm::from_raw(algorithm),
+            step: (step > 0).then_some(step as u32),
+        }
+    }
+}
+
+/// Depth statistics of a ROI, in millimeters.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SpatialDepthStats {
+    pub average: f32,
+    pub mode: f32,
+    pub median: f32,
+    pub min: u16,
+    pub max: u16,
+    /// Number of pixels that contributed to `average`.
+    pub pixel_count: u32,
+}
+
+/// Result for one ROI.
+///
+/// Mirrors C++: `dai::SpatialLocations`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpatialLocation {
+    /// The ROI configuration this result was computed with.
+    pub config: SpatialLocationRoi,
+    pub depth: SpatialDepthStats,
+    /// Position of the ROI center in millimeters (camera frame: X right, Y down, Z forward).
+    pub coordinates: [f32; 3],
+}
+
+/// Runtime configuration message for `SpatialLocationCalculator`.
+///
+/// Mirrors C++: `dai::SpatialLocationCalculatorConfig`.
+///
+/// Note: this is also a `Buffer` message, so it can be sent to the node's `inputConfig` to
+/// replace the ROIs while the pipeline runs.
+pub struct SpatialLocationCalculatorConfig {
+    buffer: Buffer,
+}
+
+impl SpatialLocationCalculatorConfig {
+    pub fn new() -> Result<Self> {
+        clear_error_flag();
+        let handle = depthai::dai_spatial_location_config_new();
+        if handle.is_null() {
+            Err(last_error("failed to create SpatialLocationCalculatorConfig"))
+        } else {
+            Ok(Self {
+                buffer: Buffer::from_handle(handle),
+            })
+        }
+    }
+
+    pub fn as_buffer(&self) -> &Buffer {
+        &self.buffer
+    }
+
+    pub fn into_buffer(self) -> Buffer {
+        self.buffer
+    }
+
+    pub(crate) fn from_handle(handle: DaiBuffer) -> Self {
+        Self {
+            buffer: Buffer::from_handle(handle),
+        }
+    }
+
+    pub(crate) fn handle(&self) -> DaiBuffer {
+        self.buffer.handle()
+    }
+
+    /// Append a ROI.
+    ///
+    /// Mirrors C++: `SpatialLocationCalculatorConfig::addROI(config)`.
+    pub fn add_roi(&mut self, roi: &SpatialLocationRoi) -> &mut Self {
+        let rect = [roi.roi.x, roi.roi.y, roi.roi.width, roi.roi.height];
+        let step = roi.step.map_or(-1, |s| s as i32);
+        clear_error_flag();
+        unsafe {
+            depthai::dai_spatial_location_config_add_roi(
+                self.handle(),
+                rect.as_ptr(),
+                roi.lower_threshold,
+                roi.upper_threshold,
+                c_int(roi.algorithm as i32),
+                c_int(step),
+            )
+        };
+        self
+    }
+
+    /// Replace all ROIs.
+    ///
+    /// Mirrors C++: `SpatialLocationCalculatorConfig::setROIs(configs)`.
+    pub fn set_rois(&mut self, rois: &[SpatialLocationRoi]) -> &mut Self {
+        self.clear_rois();
+        for roi in rois {
+            self.add_roi(roi);
+        }
+        self
+    }
+
+    pub fn clear_rois(&mut self) -> &mut Self {
+        clear_error_flag();
+        unsafe { depthai::dai_spatial_location_config_clear_rois(self.handle()) };
+        self
+    }
+
+    /// Mirrors C++: `SpatialLocationCalculatorConfig::getConfigData()`.
+    pub fn rois(&self) -> Result<Vec<SpatialLocationRoi>> {
+        clear_error_flag();
+        let count = unsafe { depthai::dai_spatial_location_config_get_num_rois(self.handle()) };
+        if let Some(err) = take_error_if_any("failed to read SpatialLocationCalculatorConfig ROIs") {
+            return Err(err);
+        }
+        (0..count)
+            .map(|i| {
+                read_roi("failed to read SpatialLocationCalculatorConfig ROI", |roi, lower, upper, algorithm, step| unsafe {
+                    depthai::dai_spatial_location_config_get_roi(self.handle(), i, roi, lower, upper, algorithm, step)
+                })
+            })
+            .collect()
+    }
+}
+
+/// Calls one of the `*_get_roi` getters, which share the same out-parameter layout.
+fn read_roi(
+    context: &str,
+    get: impl FnOnce(*mut f32, *mut u32, *mut u32, *mut c_int, *mut c_int) -> bool,
+) -> Result<SpatialLocationRoi> {
+    let mut roi = [0f32; 4];
+    let (mut lower, mut upper) = (0u32, 0u32);
+    let (mut algorithm, mut step) = (c_int(0), c_int(0));
+    clear_error_flag();
+    if !get(roi.as_mut_ptr(), &mut lower, &mut upper, &mut algorithm, &mut step) {
+        return Err(last_error(context));
+    }
+    Ok(SpatialLocationRoi::from_raw(roi, lower, upper, algorithm.0, step.0))
+}
+
+/// Spatial locations computed for each configured ROI.
+///
+/// Mirrors C++: `dai::SpatialLocationCalculatorData`.
+pub struct SpatialLocationCalculatorData {
+    handle: DaiSpatialLocationData,
+}
+
+unsafe impl Send for SpatialLocationCalculatorData {}
+unsafe impl Sync for SpatialLocationCalculatorData {}
+
+impl Drop for SpatialLocationCalculatorData {
+    fn drop(&mut self) {
+        if !self.handle.is_null() {
+            unsafe { depthai::dai_spatial_location_data_release(self.handle) };
+            self.handle = std::ptr::null_mut();
+        }
+    }
+}
+
+impl SpatialLocationCalculatorData {
+    pub(crate) fn from_handle(handle: DaiSpatialLocationData) -> Self {
+        Self { handle }
+    }
+
+    pub fn len(&self) -> usize {
+        unsafe { depthai::dai_spatial_location_data_get_count(self.handle) }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Mirrors C++: `SpatialLocationCalculatorData::getSpatialLocations()`.
+    pub fn locations(&self) -> Result<Vec<SpatialLocation>> {
+        (0..self.len()).map(|i| self.location(i)).collect()
+    }
+
+    pub fn location(&self, index: usize) -> Result<SpatialLocation> {
+        let config = read_roi("failed to read spatial location ROI", |roi, lower, upper, algorithm, step| unsafe {
+            depthai::dai_spatial_location_data_get_roi(self.handle, index, roi, lower, upper, algorithm, step)
+        })?;
+        let mut depth = [0f32; 5];
+        let mut pixel_count = 0u32;
+        let mut coordinates = [0f32; 3];
+        clear_error_flag();
+        let ok = unsafe {
+            depthai::dai_spatial_location_data_get_location(
+                self.handle,
+                index,
+                depth.as_mut_ptr(),
+                &mut pixel_count,
+                coordinates.as_mut_ptr(),
+            )
+        };
+        if !ok {
+            return Err(last_error("failed to read spatial location"));
+        }
+        Ok(SpatialLocation {
+            config,
+            depth: SpatialDepthStats {
+                average: depth[0],
+                mode: depth[1],
+                median: depth[2],
+                min: depth[3] as u16,
+                max: depth[4] as u16,
+                pixel_count,
+            },
+            coordinates,
+        })
+    }
+}
+
+#[crate::native_node_wrapper(
+    native = "dai::node::SpatialLocationCalculator",
+    inputs(inputConfig: SpatialLocationCalculatorConfig, inputDepth: ImageFrame),
+    outputs(out: SpatialLocationCalculatorData, passthroughDepth: ImageFrame)
+)]
+pub struct SpatialLocationCalculatorNode {
+    node: crate::pipeline::Node,
+}
+
+impl SpatialLocationCalculatorNode {
+    /// Access the node's initial config (shared, modifications affect the node).
+    pub fn initial_config(&self) -> Result<SpatialLocationCalculatorConfig> {
+        clear_error_flag();
+        let handle = unsafe { depthai::dai_spatial_location_calculator_get_initial_config(self.node.handle()) };
+        if handle.is_null() {
+            Err(last_error("failed to get SpatialLocationCalculator initialConfig"))
+        } else {
+            Ok(SpatialLocationCalculatorConfig::from_handle(handle))
+        }
+    }
+
+    /// Whether to wait for a config on `inputConfig` before processing each depth frame.
+    ///
+    /// Mirrors C++: `inputConfig.setWaitForMessage(bool)`.
+    pub fn set_wait_for_config_input(&self, wait: bool) {
+        clear_error_flag();
+        unsafe { depthai::dai_spatial_location_calculator_set_wait_for_config_input(self.node.handle(), wait) };
+    }
+}
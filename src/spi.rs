@@ -0,0 +1,137 @@
+use std::ffi::CString;
+
+use autocxx::c_int;
+use depthai_sys::depthai;
+
+use crate::error::{clear_error_flag, last_error, take_error_if_any, Result};
+use crate::output::Input;
+
+/// Sends messages from the device to an external MCU (e.g. ESP32) over SPI.
+///
+/// Mirrors C++: `dai::node::SPIOut`.
+#[crate::native_node_wrapper(native = "dai::node::SPIOut")]
+pub struct SpiOutNode {
+    node: crate::pipeline::Node,
+}
+
+impl SpiOutNode {
+    /// Messages to send over SPI (the native port is named `in`).
+    pub fn input(&self) -> Result<Input> {
+        self.node.input("in")
+    }
+
+    /// Specify the stream name the MCU uses to address this output.
+    ///
+    /// Mirrors C++: `SPIOut::setStreamName(std::string)`.
+    pub fn set_stream_name(&self, name: &str) -> Result<()> {
+        clear_error_flag();
+        let c = CString::new(name).map_err(|_| last_error("invalid stream name"))?;
+        unsafe { depthai::dai_spi_out_set_stream_name(self.node.handle(), c.as_ptr()) };
+        if let Some(e) = take_error_if_any("failed to set SPIOut stream name") {
+            Err(e)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Specify the SPI bus id.
+    ///
+    /// Mirrors C++: `SPIOut::setBusId(int)`.
+    pub fn set_bus_id(&self, bus_id: i32) {
+        clear_error_flag();
+        unsafe { depthai::dai_spi_out_set_bus_id(self.node.handle(), c_int(bus_id)) };
+    }
+}
+
+/// Receives messages from an external MCU (e.g. ESP32) over SPI.
+///
+/// Mirrors C++: `dai::node::SPIIn`.
+#[crate::native_node_wrapper(native = "dai::node::SPIIn", outputs(out))]
+pub struct SpiInNode {
+    node: crate::pipeline::Node,
+}
+
+impl SpiInNode {
+    /// Specify the stream name the MCU uses to address this input.
+    ///
+    /// Mirrors C++: `SPIIn::setStreamName(std::string)`.
+    pub fn set_stream_name(&self, name: &str) -> Result<()> {
+        clear_error_flag();
+        let c = CString::new(name).map_err(|_| last_error("invalid stream name"))?;
+        unsafe { depthai::dai_spi_in_set_stream_name(self.node.handle(), c.as_ptr()) };
+        if let Some(e) = take_error_if_any("failed to set SPIIn stream name") {
+            Err(e)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Mirrors C++: `SPIIn::getStreamName()`.
+    pub fn stream_name(&self) -> Result<String> {
+        clear_error_flag();
+        let ptr = unsafe { depthai::dai_spi_in_get_stream_name(self.node.handle()) };
+        if ptr.is_null() {
+            return Err(last_error("failed to get SPIIn stream name"));
+        }
+        let s = unsafe { std::ffi::CStr::from_ptr(ptr).to_string_lossy().into_owned() };
+        unsafe { depthai::dai_free_cstring(ptr) };
+        Ok(s)
+    }
+
+    /// Specify the SPI bus id.
+    ///
+    /// Mirrors C++: `SPIIn::setBusId(int)`.
+    pub fn set_bus_id(&self, bus_id: i32) {
+        clear_error_flag();
+        unsafe { depthai::dai_spi_in_set_bus_id(self.node.handle(), c_int(bus_id)) };
+    }
+
+    /// Mirrors C++: `SPIIn::getBusId()`.
+    pub fn bus_id(&self) -> Result<i32> {
+        clear_error_flag();
+        let v = unsafe { depthai::dai_spi_in_get_bus_id(self.node.handle()) };
+        if let Some(e) = take_error_if_any("failed to get SPIIn bus id") {
+            Err(e)
+        } else {
+            Ok(v.into())
+        }
+    }
+
+    /// Specify the maximum size (in bytes) of a received message.
+    ///
+    /// Mirrors C++: `SPIIn::setMaxDataSize(std::uint32_t)`.
+    pub fn set_max_data_size(&self, max_data_size: u32) {
+        clear_error_flag();
+        unsafe { depthai::dai_spi_in_set_max_data_size(self.node.handle(), max_data_size) };
+    }
+
+    /// Mirrors C++: `SPIIn::getMaxDataSize()`.
+    pub fn max_data_size(&self) -> Result<u32> {
+        clear_error_flag();
+        let v = unsafe { depthai::dai_spi_in_get_max_data_size(self.node.handle()) };
+        if let Some(e) = take_error_if_any("failed to get SPIIn max data size") {
+            Err(e)
+        } else {
+            Ok(v)
+        }
+    }
+
+    /// Specify the number of frames in the receive pool.
+    ///
+    /// Mirrors C++: `SPIIn::setNumFrames(std::uint32_t)`.
+    pub fn set_num_frames(&self, num_frames: u32) {
+        clear_error_flag();
+        unsafe { depthai::dai_spi_in_set_num_frames(self.node.handle(), num_frames) };
+    }
+
+    /// Mirrors C++: `SPIIn::getNumFrames()`.
+    pub fn num_frames(&self) -> Result<u32> {
+        clear_error_flag();
+        let v = unsafe { depthai::dai_spi_in_get_num_frames(self.node.handle()) };
+        if let Some(e) = take_error_if_any("failed to get SPIIn num frames") {
+            Err(e)
+        } else {
+            Ok(v)
+        }
+    }
+}
@@ -1,7 +1,73 @@
 use autocxx::c_int;
 use depthai_sys::depthai;
 
-use crate::error::clear_error_flag;
+use crate::camera::ImageFrame;
+use crate::common::CameraBoardSocket;
+use crate::error::{clear_error_flag, DepthaiError, Result};
+
+/// StereoDepth input and output widths must be a multiple of this value.
+pub const STEREO_WIDTH_ALIGNMENT: u32 = 16;
+
+/// Widths that are a multiple of this value work on every platform and stereo mode (e.g. with
+/// subpixel or extended disparity), so prefer them when the exact width doesn't matter.
+pub const STEREO_SAFE_WIDTH_ALIGNMENT: u32 = 128;
+
+/// What [`StereoDepthNode::set_output_size_aligned`] does with a width that is not aligned.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StereoSizePolicy {
+    /// Fail, listing the aligned widths around the requested one.
+    #[default]
+    Exact,
+    RoundDown,
+    RoundUp,
+    /// The closest aligned width, rounding up on ties.
+    Nearest,
+}
+
+/// `size` with its width aligned to `alignment` according to `policy`; the height is kept.
+///
+/// ```
+/// # use depthai::stereo_depth::{aligned_output_size, StereoSizePolicy, STEREO_WIDTH_ALIGNMENT};
+/// assert_eq!(aligned_output_size((1000, 600), STEREO_WIDTH_ALIGNMENT, StereoSizePolicy::Nearest)?, (1008, 600));
+/// assert!(aligned_output_size((1000, 600), STEREO_WIDTH_ALIGNMENT, StereoSizePolicy::Exact).is_err());
+/// # Ok::<(), depthai::DepthaiError>(())
+/// ```
+pub fn aligned_output_size(size: (u32, u32), alignment: u32, policy: StereoSizePolicy) -> Result<(u32, u32)> {
+    let (width, height) = size;
+    if width == 0 || height == 0 {
+        return Err(DepthaiError::new(format!("invalid stereo output size {width}x{height}")));
+    }
+    let alignment = alignment.max(1);
+    if width % alignment == 0 {
+        return Ok(size);
+    }
+    let down = width / alignment * alignment;
+    let up = down + alignment;
+    let aligned = match policy {
+        StereoSizePolicy::Exact => {
+            let nearby: Vec<String> = [down.checked_sub(alignment), Some(down), Some(up), Some(up + alignment)]
+                .into_iter()
+                .flatten()
+                .filter(|&w| w > 0)
+                .map(|w| format!("{w}x{height}"))
+                .collect();
+            return Err(DepthaiError::new(format!(
+                "stereo output width {width} is not a multiple of {alignment}; nearby sizes: {}",
+                nearby.join(", ")
+            )));
+        }
+        StereoSizePolicy::RoundDown => down,
+        StereoSizePolicy::RoundUp => up,
+        StereoSizePolicy::Nearest if width - down < up - width => down,
+        StereoSizePolicy::Nearest => up,
+    };
+    if aligned == 0 {
+        return Err(DepthaiError::new(format!(
+            "stereo output width {width} rounds down to 0; the smallest width is {alignment}"
+        )));
+    }
+    Ok((aligned, height))
+}
 
 #[repr(i32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -14,10 +80,37 @@ pub enum PresetMode {
     Robotics = 5,
 }
 
+/// Which viewpoint the depth/disparity output is aligned to.
+///
+/// Mirrors C++: `StereoDepthProperties::DepthAlign`, plus the `CameraBoardSocket` overload of
+/// `StereoDepth::setDepthAlign`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DepthAlign {
+    RectifiedRight,
+    RectifiedLeft,
+    /// Between the two stereo cameras.
+    Center,
+    /// Reprojected onto another camera, e.g. the color camera for RGB-D.
+    Camera(CameraBoardSocket),
+}
+
+/// Stereo matcher producing depth and disparity from a left/right camera pair.
+///
+/// Besides `depth`/`disparity` it exposes the per-pixel `confidenceMap` (decode with
+/// [`ConfidenceMap`](crate::depth::ConfidenceMap)), the `rectifiedLeft`/`rectifiedRight` images
+/// that were matched and the `syncedLeft`/`syncedRight` input pairs, for debugging rectification.
 #[crate::native_node_wrapper(
     native = "dai::node::StereoDepth",
-    inputs(left, right),
-    outputs(depth, disparity)
+    inputs(left: ImageFrame, right: ImageFrame),
+    outputs(
+        depth: ImageFrame,
+        disparity: ImageFrame,
+        confidenceMap: ImageFrame,
+        rectifiedLeft: ImageFrame,
+        rectifiedRight: ImageFrame,
+        syncedLeft: ImageFrame,
+        syncedRight: ImageFrame,
+    )
 )]
 pub struct StereoDepthNode {
     node: crate::pipeline::Node,
@@ -57,6 +150,15 @@ impl StereoDepthNode {
         unsafe { depthai::dai_stereo_set_output_size(self.node.handle(), c_int(width), c_int(height)) };
     }
 
+    /// [`set_output_size`](Self::set_output_size) with the width checked against
+    /// [`STEREO_WIDTH_ALIGNMENT`] and handled according to `policy`, see
+    /// [`aligned_output_size`]. Returns the size that was set.
+    pub fn set_output_size_aligned(&self, width: u32, height: u32, policy: StereoSizePolicy) -> Result<(u32, u32)> {
+        let (w, h) = aligned_output_size((width, height), STEREO_WIDTH_ALIGNMENT, policy)?;
+        self.set_output_size(w as i32, h as i32);
+        Ok((w, h))
+    }
+
     /// Whether resizing done by `set_output_size` should keep aspect ratio (with potential cropping).
     ///
     /// Mirrors C++: `StereoDepth::setOutputKeepAspectRatio(keep)`.
@@ -64,4 +166,107 @@ impl StereoDepthNode {
         clear_error_flag();
         unsafe { depthai::dai_stereo_set_output_keep_aspect_ratio(self.node.handle(), keep) };
     }
+
+    /// Mirrors C++: `StereoDepth::setDepthAlign(align)`.
+    pub fn set_depth_align(&self, align: DepthAlign) {
+        clear_error_flag();
+        let raw = match align {
+            DepthAlign::RectifiedRight => 0,
+            DepthAlign::RectifiedLeft => 1,
+            DepthAlign::Center => 2,
+            DepthAlign::Camera(socket) => {
+                unsafe { depthai::dai_stereo_set_depth_align_socket(self.node.handle(), c_int(socket as i32)) };
+                return;
+            }
+        };
+        unsafe { depthai::dai_stereo_set_depth_align(self.node.handle(), c_int(raw)) };
+    }
+
+    /// Rectify the inputs before matching (default: enabled).
+    ///
+    /// Mirrors C++: `StereoDepth::setRectification(enable)`.
+    pub fn set_rectification(&self, enable: bool) {
+        clear_error_flag();
+        unsafe { depthai::dai_stereo_set_rectification(self.node.handle(), enable) };
+    }
+
+    /// Free scaling of the rectified images: 0.0 keeps only valid pixels, 1.0 keeps all source
+    /// pixels (with black borders).
+    ///
+    /// Mirrors C++: `StereoDepth::setAlphaScaling(alpha)`.
+    pub fn set_alpha_scaling(&self, alpha: f32) {
+        clear_error_flag();
+        unsafe { depthai::dai_stereo_set_alpha_scaling(self.node.handle(), alpha) };
+    }
+
+    /// Take the focal length from the calibration intrinsics instead of deriving it from the FOV.
+    ///
+    /// Mirrors C++: `StereoDepth::setFocalLengthFromCalibration(enable)`.
+    pub fn set_focal_length_from_calibration(&self, enable: bool) {
+        clear_error_flag();
+        unsafe { depthai::dai_stereo_set_focal_length_from_calibration(self.node.handle(), enable) };
+    }
+
+    /// Resolution of the left/right inputs, when they can't be inferred from the linked cameras.
+    ///
+    /// Mirrors C++: `StereoDepth::setInputResolution(width, height)`.
+    pub fn set_input_resolution(&self, width: i32, height: i32) {
+        clear_error_flag();
+        unsafe { depthai::dai_stereo_set_input_resolution(self.node.handle(), c_int(width), c_int(height)) };
+    }
+
+    /// Override the calibrated baseline, in centimeters.
+    ///
+    /// Mirrors C++: `StereoDepth::setBaseline(baseline)`.
+    pub fn set_baseline(&self, baseline_cm: f32) {
+        clear_error_flag();
+        unsafe { depthai::dai_stereo_set_baseline(self.node.handle(), baseline_cm) };
+    }
+
+    /// Override the calibrated focal length, in pixels.
+    ///
+    /// Mirrors C++: `StereoDepth::setFocalLength(focalLength)`.
+    pub fn set_focal_length(&self, focal_length_px: f32) {
+        clear_error_flag();
+        unsafe { depthai::dai_stereo_set_focal_length(self.node.handle(), focal_length_px) };
+    }
+
+    /// Gray level (0..255) of rectified pixels without a source pixel; -1 replicates the edge.
+    ///
+    /// Mirrors C++: `StereoDepth::setRectifyEdgeFillColor(color)`.
+    pub fn set_rectify_edge_fill_color(&self, color: i32) {
+        clear_error_flag();
+        unsafe { depthai::dai_stereo_set_rectify_edge_fill_color(self.node.handle(), c_int(color)) };
+    }
+
+    /// Maximum left-right disparity difference (0..255) kept by the left-right check.
+    ///
+    /// Mirrors C++: `StereoDepth::initialConfig->setLeftRightCheckThreshold(threshold)`.
+    pub fn set_left_right_check_threshold(&self, threshold: i32) {
+        clear_error_flag();
+        unsafe { depthai::dai_stereo_initial_set_left_right_check_threshold(self.node.handle(), c_int(threshold)) };
+    }
+
+    /// Drop depth beyond `max_range` (in depth units, millimeters by default).
+    ///
+    /// Mirrors C++: `StereoDepth::initialConfig->postProcessing.thresholdFilter.maxRange`.
+    pub fn set_threshold_filter_max_range(&self, max_range: i32) {
+        clear_error_flag();
+        unsafe { depthai::dai_stereo_initial_set_threshold_filter_max_range(self.node.handle(), c_int(max_range)) };
+    }
+
+    /// SHAVE cores and CMX memory slices reserved for the post-processing filters (RVC2); `-1`
+    /// lets the device pick. See [`Pipeline::resource_estimate`](crate::Pipeline::resource_estimate).
+    ///
+    /// Mirrors C++: `StereoDepth::setPostProcessingHardwareResources(numShaves, numMemorySlices)`.
+    pub fn set_post_processing_hardware_resources(&self, num_shaves: i32, num_memory_slices: i32) {
+        clear_error_flag();
+        unsafe {
+            depthai::dai_stereo_set_post_processing_hardware_resources(
+                self.node.handle(),
+                c_int(num_shaves),
+                c_int(num_memory_slices),
+            )
+        };
+    }
 }
@@ -2,13 +2,16 @@ use std::ffi::{c_void, CString};
 use std::panic::{catch_unwind, AssertUnwindSafe};
 use std::ptr;
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use autocxx::c_int;
 use depthai_sys::{depthai, DaiNode};
 
-use crate::error::{clear_error_flag, last_error, Result};
-use crate::output::{Input, Output};
-use crate::pipeline::{Node, Pipeline, PipelineInner};
+use crate::error::{clear_error_flag, last_error, DepthaiError, Result};
+use crate::host_node::create_host_output;
+use crate::output::{Input, Message, Output, TypedOutput};
+use crate::pipeline::{Node, NodeFailure, NodeFailureReporter, NodePhase, Pipeline, PipelineInner};
+use crate::queue::{downcast, FromDatatype};
 
 pub trait ThreadedHostNodeImpl: Send + 'static {
     fn run(&mut self, ctx: &ThreadedHostNodeContext);
@@ -72,42 +75,107 @@ impl ThreadedHostNode {
     }
 
     pub fn create_output_with(&self, name: Option<&str>, group: Option<&str>) -> Result<Output> {
-        clear_error_flag();
-        let name_c = name
-            .map(|s| CString::new(s).map_err(|_| last_error("invalid output name")))
-            .transpose()?;
-        let group_c = group
-            .map(|s| CString::new(s).map_err(|_| last_error("invalid output group")))
-            .transpose()?;
-        let handle = unsafe {
-            depthai::dai_threaded_hostnode_create_output(
-                self.node.handle(),
-                name_c.as_ref().map(|s| s.as_ptr()).unwrap_or(ptr::null()),
-                group_c.as_ref().map(|s| s.as_ptr()).unwrap_or(ptr::null()),
-            )
-        };
-        if handle.is_null() {
-            Err(last_error("failed to create threaded host output"))
-        } else {
-            Ok(Output::from_handle(Arc::clone(&self.node.pipeline), handle))
-        }
+        create_host_output(&self.node, name, group)
+    }
+
+    /// Create an output carrying messages of type `M`, e.g. `TypedOutput<ImageFrame>`.
+    pub fn create_typed_output<M: Message>(&self, name: &str) -> Result<TypedOutput<M>> {
+        self.create_output(Some(name)).map(TypedOutput::from_untyped)
     }
 }
 
 pub struct ThreadedHostNodeContext {
     node: DaiNode,
+    failures: Arc<NodeFailureReporter>,
 }
 
 impl ThreadedHostNodeContext {
-    pub(crate) fn new(node: DaiNode) -> Self {
-        Self { node }
+    pub(crate) fn new(node: DaiNode, failures: Arc<NodeFailureReporter>) -> Self {
+        Self { node, failures }
+    }
+
+    /// Record a failure of this node on the pipeline (see [`Pipeline::take_node_errors`]),
+    /// e.g. before giving up on a broken input. May stop the pipeline, depending on its
+    /// [`NodeErrorPolicy`](crate::pipeline::NodeErrorPolicy).
+    pub fn report_error(&self, err: &DepthaiError) {
+        self.failures.report(NodePhase::Run, NodeFailure::Error(err.to_string()));
+    }
+
+    /// Reset the run of consecutive failures, e.g. after a message was handled.
+    pub(crate) fn report_success(&self) {
+        self.failures.success();
     }
 
     pub fn is_running(&self) -> bool {
         unsafe { depthai::dai_threaded_node_is_running(self.node) }
     }
+
+    /// Block until the node is stopped, e.g. in nodes that only react to callbacks.
+    pub fn wait_until_stopped(&self) {
+        while self.is_running() {
+            std::thread::sleep(STOP_POLL_INTERVAL);
+        }
+    }
+
+    /// Wait up to `timeout` for a message on `input`; `Ok(None)` on timeout or once the node
+    /// stops. Errors if the message is not an `M`.
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use depthai::camera::ImageFrame;
+    /// # use depthai::{Input, ThreadedHostNodeContext};
+    /// fn run(ctx: &ThreadedHostNodeContext, input: &Input) {
+    ///     while ctx.is_running() {
+    ///         match ctx.recv::<ImageFrame, _>(input, Duration::from_millis(100)) {
+    ///             Ok(Some(frame)) => println!("{}x{}", frame.width(), frame.height()),
+    ///             Ok(None) => {}
+    ///             Err(e) => eprintln!("{e}"),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn recv<M: FromDatatype, I: AsRef<Input> + ?Sized>(&self, input: &I, timeout: Duration) -> Result<Option<M>> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if !self.is_running() {
+                return Ok(None);
+            }
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            // Wait in short slices so a stop request is noticed even on a silent input.
+            if let Some(msg) = input.as_ref().get_message_timeout(remaining.min(STOP_POLL_INTERVAL))? {
+                return downcast(&msg).map(Some);
+            }
+            if remaining.is_zero() {
+                return Ok(None);
+            }
+        }
+    }
+
+    /// Wait for the next message on `input` until the node stops; `Ok(None)` once stopped.
+    ///
+    /// Replaces hand-rolled `while ctx.is_running()` loops around blocking `get_*` calls,
+    /// which never return if the input goes silent during shutdown.
+    pub fn next<M: FromDatatype, I: AsRef<Input> + ?Sized>(&self, input: &I) -> Result<Option<M>> {
+        while self.is_running() {
+            if let Some(msg) = input.as_ref().get_message_timeout(STOP_POLL_INTERVAL)? {
+                return downcast(&msg).map(Some);
+            }
+        }
+        Ok(None)
+    }
+
+    /// Take a message from `input` if one is queued, without blocking.
+    pub fn try_recv<M: FromDatatype, I: AsRef<Input> + ?Sized>(&self, input: &I) -> Result<Option<M>> {
+        match input.as_ref().try_get_message()? {
+            Some(msg) => downcast(&msg).map(Some),
+            None => Ok(None),
+        }
+    }
 }
 
+/// How often blocking context helpers check [`ThreadedHostNodeContext::is_running`].
+const STOP_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
 pub(crate) fn create_threaded_host_node<T, F>(pipeline: &Pipeline, init: F) -> Result<ThreadedHostNode>
 where
     T: ThreadedHostNodeImpl,
@@ -117,6 +185,7 @@ where
     let state = Box::new(ThreadedHostNodeState::<T> {
         inner: Mutex::new(None),
         node: Mutex::new(ptr::null_mut()),
+        failures: Arc::new(NodeFailureReporter::new(&pipeline.inner_arc())),
     });
     let ctx = Box::into_raw(state) as *mut c_void;
     let handle = unsafe {
@@ -139,6 +208,7 @@ where
         let state = unsafe { &*(ctx as *mut ThreadedHostNodeState<T>) };
         let mut guard = state.node.lock().unwrap_or_else(|e| e.into_inner());
         *guard = handle;
+        state.failures.set_node_id(node.as_node().id().unwrap_or(-1));
     }
 
     let impl_node = init(&node)?;
@@ -154,6 +224,7 @@ where
 struct ThreadedHostNodeState<T: ThreadedHostNodeImpl> {
     inner: Mutex<Option<T>>,
     node: Mutex<DaiNode>,
+    failures: Arc<NodeFailureReporter>,
 }
 
 unsafe extern "C" fn threaded_hostnode_run<T: ThreadedHostNodeImpl>(ctx: *mut c_void) {
@@ -172,8 +243,10 @@ unsafe extern "C" fn threaded_hostnode_run<T: ThreadedHostNodeImpl>(ctx: *mut c_
     let Some(inner) = guard.as_mut() else {
         return;
     };
-    let ctx = ThreadedHostNodeContext::new(node);
-    let _ = catch_unwind(AssertUnwindSafe(|| inner.run(&ctx)));
+    let ctx = ThreadedHostNodeContext::new(node, Arc::clone(&state.failures));
+    if let Err(payload) = catch_unwind(AssertUnwindSafe(|| inner.run(&ctx))) {
+        state.failures.report_panic(NodePhase::Run, payload);
+    }
 }
 
 unsafe extern "C" fn threaded_hostnode_on_start<T: ThreadedHostNodeImpl>(ctx: *mut c_void) {
@@ -188,7 +261,10 @@ unsafe extern "C" fn threaded_hostnode_on_start<T: ThreadedHostNodeImpl>(ctx: *m
     let Some(inner) = guard.as_mut() else {
         return;
     };
-    let _ = catch_unwind(AssertUnwindSafe(|| inner.on_start()));
+    if let Err(payload) = catch_unwind(AssertUnwindSafe(|| inner.on_start())) {
+        state.failures.report_panic(NodePhase::Start, payload);
+    }
+    state.failures.started();
 }
 
 unsafe extern "C" fn threaded_hostnode_on_stop<T: ThreadedHostNodeImpl>(ctx: *mut c_void) {
@@ -203,7 +279,10 @@ unsafe extern "C" fn threaded_hostnode_on_stop<T: ThreadedHostNodeImpl>(ctx: *mu
     let Some(inner) = guard.as_mut() else {
         return;
     };
-    let _ = catch_unwind(AssertUnwindSafe(|| inner.on_stop()));
+    if let Err(payload) = catch_unwind(AssertUnwindSafe(|| inner.on_stop())) {
+        state.failures.report_panic(NodePhase::Stop, payload);
+    }
+    state.failures.stopped();
 }
 
 unsafe extern "C" fn threaded_hostnode_drop<T: ThreadedHostNodeImpl>(ctx: *mut c_void) {
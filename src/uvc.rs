@@ -0,0 +1,91 @@
+use autocxx::c_int;
+use depthai_sys::depthai;
+
+use crate::error::{clear_error_flag, take_error_if_any, Result};
+use crate::output::Input;
+
+/// Streams frames to the host as a standard UVC webcam.
+///
+/// Link an NV12 camera output to [`UvcNode::input`], start the pipeline and keep it running;
+/// the device then enumerates as a webcam on the host.
+///
+/// ```no_run
+/// # use depthai::{Pipeline, Result, UvcNode};
+/// # use depthai::camera::{CameraNode, CameraOutputConfig};
+/// # use depthai::common::{CameraBoardSocket, ImageFrameType};
+/// # fn main() -> Result<()> {
+/// let pipeline = Pipeline::new().build()?;
+/// let cam = pipeline.create_with::<CameraNode, _>(CameraBoardSocket::CamA)?;
+/// let mut cfg = CameraOutputConfig::new((1920, 1080));
+/// cfg.frame_type = Some(ImageFrameType::NV12);
+/// let out = cam.request_output(cfg)?;
+///
+/// let uvc = pipeline.create::<UvcNode>()?;
+/// out.link(&uvc.input()?)?;
+/// pipeline.start()?;
+/// # Ok(())
+/// # }
+/// ```
+#[crate::native_node_wrapper(native = "dai::node::UVC")]
+pub struct UvcNode {
+    node: crate::pipeline::Node,
+}
+
+impl UvcNode {
+    /// Frames to stream (the native port is named `in`).
+    pub fn input(&self) -> Result<Input> {
+        self.node.input("in")
+    }
+
+    /// GPIO levels (`(gpio, value)`) applied when the node is initialized.
+    ///
+    /// Mirrors C++: `UVC::setGpiosOnInit(std::unordered_map<int, int>)`.
+    pub fn set_gpios_on_init(&self, gpios: &[(i32, i32)]) -> Result<()> {
+        let (nums, values) = split_gpios(gpios);
+        clear_error_flag();
+        unsafe {
+            depthai::dai_uvc_set_gpios_on_init(self.node.handle(), nums.as_ptr(), values.as_ptr(), c_int(nums.len() as i32))
+        };
+        if let Some(err) = take_error_if_any("failed to set UVC GPIOs on init") {
+            Err(err)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// GPIO levels (`(gpio, value)`) applied when the host starts streaming.
+    ///
+    /// Mirrors C++: `UVC::setGpiosOnStreamOn(std::unordered_map<int, int>)`.
+    pub fn set_gpios_on_stream_on(&self, gpios: &[(i32, i32)]) -> Result<()> {
+        let (nums, values) = split_gpios(gpios);
+        clear_error_flag();
+        unsafe {
+            depthai::dai_uvc_set_gpios_on_stream_on(self.node.handle(), nums.as_ptr(), values.as_ptr(), c_int(nums.len() as i32))
+        };
+        if let Some(err) = take_error_if_any("failed to set UVC GPIOs on stream on") {
+            Err(err)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// GPIO levels (`(gpio, value)`) applied when the host stops streaming.
+    ///
+    /// Mirrors C++: `UVC::setGpiosOnStreamOff(std::unordered_map<int, int>)`.
+    pub fn set_gpios_on_stream_off(&self, gpios: &[(i32, i32)]) -> Result<()> {
+        let (nums, values) = split_gpios(gpios);
+        clear_error_flag();
+        unsafe {
+            depthai::dai_uvc_set_gpios_on_stream_off(self.node.handle(), nums.as_ptr(), values.as_ptr(), c_int(nums.len() as i32))
+        };
+        if let Some(err) = take_error_if_any("failed to set UVC GPIOs on stream off") {
+            Err(err)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+fn split_gpios(gpios: &[(i32, i32)]) -> (Vec<c_int>, Vec<c_int>) {
+    gpios.iter().map(|&(gpio, value)| (c_int(gpio), c_int(value))).unzip()
+}
@@ -0,0 +1,127 @@
+//! DepthAI-Core version and build capabilities, queried at runtime.
+//!
+//! The linked DepthAI-Core is picked at build time (`latest`, `v3-2-1`, ... features of
+//! `depthai-sys`, or a prebuilt SDK), and some nodes only exist in newer releases or builds with
+//! optional dependencies. Check for them up front instead of failing on node creation:
+//!
+//! ```no_run
+//! # use depthai::version::{depthai_core_version, has_feature, Feature};
+//! if has_feature(Feature::NeuralDepth) {
+//!     // create a NeuralDepth node
+//! } else {
+//!     eprintln!("DepthAI-Core {} has no NeuralDepth node, using StereoDepth", depthai_core_version());
+//! }
+//! ```
+
+use std::ffi::{c_char, CStr, CString};
+
+use depthai_sys::depthai;
+
+/// Optional capabilities of the linked DepthAI-Core.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Feature {
+    /// OpenCV support (`ImgFrame::getCvFrame` and friends).
+    OpenCv,
+    /// The `DynamicCalibration` node, which needs OpenCV support.
+    DynamicCalibration,
+    /// The `NeuralDepth` node, added after v3.1.0.
+    NeuralDepth,
+    /// The `Rectification` node, added after v3.1.0.
+    Rectification,
+    /// A working `ImageFilters` node, which needs OpenCV support.
+    ImageFilters,
+    /// The host `RecordVideo`, `RecordMetadataOnly`, `ReplayVideo` and `ReplayMetadataOnly` nodes.
+    RecordReplay,
+}
+
+/// Whether the linked DepthAI-Core provides `feature`.
+pub fn has_feature(feature: Feature) -> bool {
+    match feature {
+        Feature::OpenCv => depthai::dai_build_has_opencv_support(),
+        Feature::DynamicCalibration => depthai::dai_build_has_dynamic_calibration_support(),
+        Feature::NeuralDepth => has_node("dai::node::NeuralDepth"),
+        Feature::Rectification => has_node("dai::node::Rectification"),
+        Feature::ImageFilters => has_node("dai::node::ImageFilters"),
+        Feature::RecordReplay => has_node("dai::node::RecordVideo"),
+    }
+}
+
+/// Whether a node can be created by C++ class name, e.g. `"dai::node::NeuralDepth"`, with
+/// [`Pipeline::create_node`](crate::pipeline::Pipeline::create_node).
+pub fn has_node(name: &str) -> bool {
+    let Ok(name) = CString::new(name) else {
+        return false;
+    };
+    unsafe { depthai::dai_build_has_node(name.as_ptr()) }
+}
+
+/// Version of the linked DepthAI-Core, e.g. `3.2.1` or `3.3.0-rc.1`.
+///
+/// Mirrors C++: `dai::build::VERSION_MAJOR`, `VERSION_MINOR`, `VERSION_PATCH`,
+/// `PRE_RELEASE_TYPE` and `PRE_RELEASE_VERSION`.
+pub fn depthai_core_version() -> semver::Version {
+    let part = |v: autocxx::c_int| u64::try_from(i32::from(v)).unwrap_or(0);
+    let mut version = semver::Version::new(
+        part(depthai::dai_build_version_major()),
+        part(depthai::dai_build_version_minor()),
+        part(depthai::dai_build_version_patch()),
+    );
+    let pre_type = static_str(depthai::dai_build_pre_release_type());
+    if !pre_type.is_empty() {
+        let pre = format!("{pre_type}.{}", part(depthai::dai_build_pre_release_version()));
+        version.pre = semver::Prerelease::new(&pre).unwrap_or(semver::Prerelease::EMPTY);
+    }
+    version
+}
+
+/// Version string of the linked DepthAI-Core as it reports it.
+///
+/// Mirrors C++: `dai::build::VERSION`.
+pub fn depthai_core_version_string() -> String {
+    static_str(depthai::dai_build_version())
+}
+
+/// Git commit DepthAI-Core was built from.
+///
+/// Mirrors C++: `dai::build::COMMIT`.
+pub fn depthai_core_commit() -> String {
+    static_str(depthai::dai_build_commit())
+}
+
+/// Mirrors C++: `dai::build::BUILD_DATETIME`.
+pub fn depthai_core_build_datetime() -> String {
+    static_str(depthai::dai_build_build_datetime())
+}
+
+/// Firmware version (commit) bundled for RVC2 devices.
+///
+/// Mirrors C++: `dai::build::DEVICE_VERSION`.
+pub fn device_fw_version() -> String {
+    static_str(depthai::dai_build_device_version())
+}
+
+/// Mirrors C++: `dai::build::BOOTLOADER_VERSION`.
+pub fn bootloader_version() -> String {
+    static_str(depthai::dai_build_bootloader_version())
+}
+
+/// Mirrors C++: `dai::build::DEVICE_RVC3_VERSION`.
+pub fn device_rvc3_fw_version() -> String {
+    static_str(depthai::dai_build_device_rvc3_version())
+}
+
+/// Firmware (OS image) version RVC4 devices are expected to run.
+///
+/// Mirrors C++: `dai::build::DEVICE_RVC4_VERSION`.
+pub fn device_rvc4_fw_version() -> String {
+    static_str(depthai::dai_build_device_rvc4_version())
+}
+
+/// Copy a string constant of `dai::build`; these are static and must not be freed.
+fn static_str(ptr: *const c_char) -> String {
+    if ptr.is_null() {
+        return String::new();
+    }
+    unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() }
+}
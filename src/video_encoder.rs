@@ -2,10 +2,12 @@ use autocxx::c_int;
 
 use depthai_sys::depthai;
 
+use crate::camera::ImageFrame;
 use crate::common::ImageFrameType;
-use crate::encoded_frame::validate_nv12_dimensions;
+use crate::encoded_frame::{validate_nv12_dimensions, EncodedFrame};
 use crate::error::{clear_error_flag, take_error_if_any, Result};
 use crate::output::Input;
+use crate::queue::QueueCallbackHandle;
 
 #[repr(i32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -49,7 +51,7 @@ impl VideoEncoderProfile {
 
 #[crate::native_node_wrapper(
     native = "dai::node::VideoEncoder",
-    outputs(bitstream, out)
+    outputs(bitstream: ImageFrame, out: EncodedFrame)
 )]
 pub struct VideoEncoderNode {
     node: crate::pipeline::Node,
@@ -66,6 +68,26 @@ impl VideoEncoderNode {
         self.as_node().input("in")
     }
 
+    /// Call `callback` with every encoded frame, on the thread delivering messages to the host.
+    ///
+    /// Must be called before the pipeline starts. A slow callback holds up delivery of the
+    /// other host queues, so hand frames off (e.g. over a channel) when sending them is slow.
+    /// Frames stop when the returned handle is dropped.
+    pub fn on_encoded_frame<F>(&self, mut callback: F) -> Result<QueueCallbackHandle>
+    where
+        F: FnMut(EncodedFrame) + Send + 'static,
+    {
+        let queue = self.out()?.create_message_queue(1, false)?;
+        let drain = queue.clone();
+        queue.add_callback(move |_, msg| {
+            // The frame is handed over here; drop the queued copy so nothing piles up.
+            let _ = drain.try_get_all();
+            if let Ok(Some(frame)) = msg.as_encoded_frame() {
+                callback(frame);
+            }
+        })
+    }
+
     /// Convenience helper: validate typical NV12 invariants before configuring camera/manip.
     pub fn validate_nv12_size(&self, width: u32, height: u32) -> Result<()> {
         validate_nv12_dimensions(width, height)
@@ -156,6 +178,10 @@ impl VideoEncoderNode {
         }
     }
 
+    /// Emit a keyframe (IDR) every `freq` frames.
+    ///
+    /// DepthAI-Core's encoder can't be asked for a keyframe on demand, so this also bounds how
+    /// long a receiver that lost packets waits before it can decode again.
     pub fn set_keyframe_frequency(&self, freq: i32) {
         clear_error_flag();
         unsafe { depthai::dai_video_encoder_set_keyframe_frequency(self.node.handle(), c_int(freq)) };
@@ -0,0 +1,84 @@
+#![cfg(all(feature = "arrow", not(target_os = "windows")))]
+
+use std::fs::File;
+use std::time::Duration;
+
+use arrow_array::UInt64Array;
+use depthai::analytics::{
+    TelemetryBatcher, TelemetryConfig, TelemetryTable, TelemetryWriter, SCHEMA_VERSION, SCHEMA_VERSION_KEY,
+};
+use depthai::camera::{ImageFrame, ImageFrameType};
+use depthai::{ImgDetection, ImgDetections};
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+fn detections(count: usize) -> depthai::Result<ImgDetections> {
+    let mut detections = ImgDetections::new()?;
+    for label in 0..count as u32 {
+        detections.push(ImgDetection { label, confidence: 0.9, xmin: 0.1, ymin: 0.1, xmax: 0.5, ymax: 0.5 })?;
+    }
+    detections.set_timestamp(Duration::from_millis(20))?;
+    detections.set_sequence_num(7)?;
+    Ok(detections)
+}
+
+#[test]
+fn batcher_builds_record_batches() -> depthai::Result<()> {
+    let mut batcher = TelemetryBatcher::new(Some("oak-1".into()));
+    let frame = ImageFrame::new(4, 2, ImageFrameType::GRAY8, &[0; 8])?;
+    frame.set_timestamp(Duration::from_millis(10))?;
+    frame.set_sequence_num(3)?;
+    batcher.push_frame("left", &frame)?;
+    batcher.push_detections(&detections(2)?)?;
+    assert_eq!(batcher.rows(TelemetryTable::Detections), 2);
+
+    let frames = batcher.take(TelemetryTable::Frames)?.expect("frame rows");
+    assert_eq!(frames.num_rows(), 1);
+    assert_eq!(frames.schema().metadata().get(SCHEMA_VERSION_KEY), Some(&SCHEMA_VERSION.to_string()));
+    let data_size = frames.column_by_name("data_size").expect("data_size column");
+    let data_size = data_size.as_any().downcast_ref::<UInt64Array>().expect("u64 column");
+    assert_eq!(data_size.value(0), 8);
+    assert!(batcher.take(TelemetryTable::Frames)?.is_none());
+    assert!(batcher.take(TelemetryTable::Imu)?.is_none());
+    Ok(())
+}
+
+#[test]
+fn writer_produces_readable_parquet() -> depthai::Result<()> {
+    let root = std::env::temp_dir().join(format!("depthai-telemetry-{}", std::process::id()));
+    let mut writer = TelemetryWriter::new(TelemetryConfig { batch_rows: 2, ..TelemetryConfig::new(&root) })?;
+    for _ in 0..3 {
+        writer.log_detections(&detections(1)?)?;
+    }
+    writer.finish()?;
+
+    let files: Vec<_> = std::fs::read_dir(root.join("detections")).expect("table dir").collect();
+    assert_eq!(files.len(), 1);
+    let file = File::open(files[0].as_ref().expect("entry").path()).expect("open");
+    let reader = ParquetRecordBatchReaderBuilder::try_new(file).expect("parquet").build().expect("reader");
+    let rows: usize = reader.map(|b| b.expect("batch").num_rows()).sum();
+    assert_eq!(rows, 3);
+    let _ = std::fs::remove_dir_all(root);
+    Ok(())
+}
+
+#[test]
+fn concurrent_writers_never_share_a_file() -> depthai::Result<()> {
+    let root = std::env::temp_dir().join(format!("depthai-telemetry-sessions-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    // Started within the same millisecond, in one process.
+    let mut writers = [
+        TelemetryWriter::new(TelemetryConfig::new(&root))?,
+        TelemetryWriter::new(TelemetryConfig::new(&root))?,
+    ];
+    for writer in &mut writers {
+        writer.log_detections(&detections(1)?)?;
+    }
+    for writer in writers {
+        writer.finish()?;
+    }
+
+    let files = std::fs::read_dir(root.join("detections")).expect("table dir").count();
+    assert_eq!(files, 2);
+    let _ = std::fs::remove_dir_all(root);
+    Ok(())
+}
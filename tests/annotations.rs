@@ -0,0 +1,62 @@
+#![cfg(not(target_os = "windows"))]
+
+use depthai::annotations::{annotate_detections, label_name, Color, PointsAnnotationType};
+use depthai::detections::{nms, ImgDetection};
+
+fn det(label: u32, confidence: f32, xmin: f32, ymin: f32, xmax: f32, ymax: f32) -> ImgDetection {
+    ImgDetection { label, confidence, xmin, ymin, xmax, ymax }
+}
+
+#[test]
+fn iou_of_boxes() {
+    let a = det(0, 1.0, 0.0, 0.0, 0.4, 0.4);
+    assert!((a.iou(&a) - 1.0).abs() < 1e-6);
+    // Half of `a` overlaps `b`, which has the same size: 0.08 / (0.16 + 0.16 - 0.08).
+    let b = det(0, 1.0, 0.2, 0.0, 0.6, 0.4);
+    assert!((a.iou(&b) - 1.0 / 3.0).abs() < 1e-6);
+    assert_eq!(a.iou(&det(0, 1.0, 0.5, 0.5, 0.9, 0.9)), 0.0);
+    assert_eq!(det(0, 1.0, 0.1, 0.1, 0.1, 0.1).iou(&det(0, 1.0, 0.1, 0.1, 0.1, 0.1)), 0.0);
+}
+
+#[test]
+fn nms_keeps_the_most_confident_box_per_label() {
+    let detections = [
+        det(0, 0.6, 0.10, 0.10, 0.50, 0.50),
+        det(0, 0.9, 0.12, 0.12, 0.52, 0.52),
+        // Same place, other label: kept.
+        det(1, 0.5, 0.10, 0.10, 0.50, 0.50),
+        // Same label, elsewhere: kept.
+        det(0, 0.7, 0.60, 0.60, 0.90, 0.90),
+    ];
+    let kept = nms(&detections, 0.5);
+    let confidences = kept.iter().map(|d| d.confidence).collect::<Vec<_>>();
+    assert_eq!(confidences, [0.9, 0.7, 0.5]);
+    // A threshold of 1 suppresses nothing.
+    assert_eq!(nms(&detections, 1.0).len(), 4);
+    assert!(nms(&[], 0.5).is_empty());
+}
+
+#[test]
+fn detections_become_boxes_and_captions() {
+    let labels = vec!["person".to_string(), "car".to_string()];
+    let detections = [det(1, 0.87, 0.1, 0.2, 0.3, 0.4), det(7, 0.5, 0.0, 0.0, 1.0, 1.0)];
+    let annotations = annotate_detections(&detections, &labels);
+    assert_eq!(annotations.points.len(), 2);
+    let rect = &annotations.points[0];
+    assert_eq!(rect.kind, PointsAnnotationType::LineLoop);
+    assert_eq!(rect.points, [(0.1, 0.2), (0.3, 0.2), (0.3, 0.4), (0.1, 0.4)]);
+    assert_eq!(rect.outline_color, Color::for_label(1));
+    assert_eq!(annotations.texts[0].text, "car 87%");
+    assert_eq!(annotations.texts[1].text, "label 7 50%");
+    assert_eq!(annotations.timestamp, None);
+}
+
+#[test]
+fn labels_and_colors() {
+    let labels = vec!["person".to_string()];
+    assert_eq!(label_name(&labels, 0), "person");
+    assert_eq!(label_name(&labels, 3), "label 3");
+    assert_ne!(Color::for_label(0), Color::for_label(1));
+    assert_eq!(Color::for_label(4), Color::for_label(4));
+    assert_eq!(Color::WHITE.with_alpha(0.5).a, 0.5);
+}
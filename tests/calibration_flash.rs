@@ -0,0 +1,47 @@
+#![cfg(not(target_os = "windows"))]
+
+use depthai::common::CameraBoardSocket;
+use depthai::CalibrationHandler;
+use serde_json::json;
+
+fn camera(width: i64, fx: f64) -> serde_json::Value {
+    json!({
+        "width": width,
+        "height": 800,
+        "intrinsicMatrix": [[fx, 0.0, 640.0], [0.0, fx, 400.0], [0.0, 0.0, 1.0]],
+        "distortionCoeff": [0.0, 0.0, 0.0, 0.0, 0.0],
+    })
+}
+
+#[test]
+fn validate_accepts_complete_calibration() {
+    let calib = CalibrationHandler::from_json(json!({
+        "version": 7,
+        "cameraData": [[1, camera(1280, 800.0)], [2, camera(1280, 801.0)]],
+    }));
+    calib.validate().unwrap();
+    assert_eq!(calib.camera_sockets(), vec![CameraBoardSocket::CamB, CameraBoardSocket::CamC]);
+}
+
+#[test]
+fn validate_rejects_missing_or_empty_camera_data() {
+    assert!(CalibrationHandler::from_json(json!({ "version": 7 })).validate().is_err());
+    let empty = CalibrationHandler::from_json(json!({ "cameraData": [] }));
+    assert!(empty.validate().is_err());
+    assert!(empty.camera_sockets().is_empty());
+}
+
+#[test]
+fn validate_rejects_bad_camera_entries() {
+    let zero_focal = CalibrationHandler::from_json(json!({ "cameraData": [[0, camera(1280, 0.0)]] }));
+    let err = zero_focal.validate().unwrap_err().to_string();
+    assert!(err.contains("focal"), "{err}");
+
+    let zero_width = CalibrationHandler::from_json(json!({ "cameraData": [[0, camera(0, 800.0)]] }));
+    assert!(zero_width.validate().is_err());
+
+    let bad_matrix = CalibrationHandler::from_json(json!({
+        "cameraData": [[0, { "width": 1280, "height": 800, "intrinsicMatrix": [[800.0, 0.0], [0.0, 800.0]] }]],
+    }));
+    assert!(bad_matrix.validate().is_err());
+}
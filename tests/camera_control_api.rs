@@ -0,0 +1,56 @@
+#![cfg(feature = "hit")]
+
+use std::time::Duration;
+
+use depthai::camera::CameraNode;
+use depthai::common::{CameraBoardSocket, Rect};
+use depthai::pipeline::FrameSyncConfig;
+use depthai::{AutoFocusMode, CameraControl, CameraController, FrameSyncMode, Pipeline, Result};
+
+#[test]
+fn camera_control_message_can_be_built() -> Result<()> {
+    let mut control = CameraControl::new()?;
+    control
+        .set_auto_exposure_region(100, 100, 200, 200)
+        .set_auto_exposure_limit(Duration::from_millis(20))
+        .set_auto_focus_mode(AutoFocusMode::Auto)
+        .set_auto_focus_trigger()
+        .set_auto_white_balance_lock(true)
+        .set_frame_sync_mode(FrameSyncMode::Input)
+        .set_external_trigger(1, 0);
+    control.check()?;
+    let _ = control.into_buffer();
+    Ok(())
+}
+
+#[test]
+fn controller_sends_coalesced_controls() -> Result<()> {
+    let pipeline = Pipeline::new().build()?;
+    let camera = pipeline.create_with::<CameraNode, _>(CameraBoardSocket::CamA)?;
+    let _out = camera.request_full_resolution_output()?.create_queue(4, false)?;
+    let controller = CameraController::with_min_interval(&camera, Duration::from_millis(100))?;
+    pipeline.start()?;
+
+    for i in 0..10 {
+        let offset = i as f32 * 0.02;
+        controller.set_exposure_region(Rect::new(offset, offset, 0.5, 0.5))?;
+    }
+    controller.trigger_autofocus();
+    controller.lock_awb(true);
+    controller.set_fps_cap(30.0)?;
+    controller.flush()?;
+
+    assert!(controller.set_fps_cap(0.0).is_err());
+    assert!(controller.set_exposure_region(Rect::new(5000.0, 5000.0, 10.0, 10.0)).is_err());
+    Ok(())
+}
+
+#[test]
+fn frame_sync_mode_is_set_on_listed_cameras() -> Result<()> {
+    let pipeline = Pipeline::new().build()?;
+    let sync = FrameSyncConfig::new().with_pair(CameraBoardSocket::CamB, CameraBoardSocket::CamC);
+    for socket in [CameraBoardSocket::CamA, CameraBoardSocket::CamB, CameraBoardSocket::CamC] {
+        sync.apply(&pipeline.create_with::<CameraNode, _>(socket)?)?;
+    }
+    Ok(())
+}
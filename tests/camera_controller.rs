@@ -0,0 +1,125 @@
+#![cfg(not(target_os = "windows"))]
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use depthai::common::Rect;
+use depthai::{
+    CameraController, Datatype, DatatypeEnum, Input, Pipeline, Result, ThreadedHostNodeContext, ThreadedHostNodeImpl,
+};
+
+/// Arrival time of each `CameraControl` received.
+type Arrivals = Arc<Mutex<Vec<Instant>>>;
+
+/// Stands in for a camera: records the controls arriving on its `inputControl`.
+struct FakeCamera {
+    input: Input,
+    arrivals: Arrivals,
+}
+
+impl ThreadedHostNodeImpl for FakeCamera {
+    fn run(&mut self, ctx: &ThreadedHostNodeContext) {
+        while let Ok(Some(msg)) = ctx.next::<Datatype, _>(&self.input) {
+            if msg.datatype().ok().flatten() == Some(DatatypeEnum::CameraControl) {
+                self.arrivals.lock().unwrap().push(Instant::now());
+            }
+        }
+    }
+}
+
+/// A running host-only pipeline with a controller of a fake 1000x1000 camera.
+fn controlled(min_interval: Duration) -> Result<(Pipeline, CameraController, Arrivals)> {
+    let pipeline = Pipeline::new_host_only()?;
+    let arrivals = Arrivals::default();
+    let camera = pipeline.create_threaded_host_node(|node| {
+        Ok(FakeCamera {
+            input: node.create_input(Some("inputControl"))?,
+            arrivals: Arc::clone(&arrivals),
+        })
+    })?;
+    let queue = camera.as_node().input("inputControl")?.create_input_queue(4, false)?;
+    let controller = CameraController::from_queue(queue, (1000, 1000), min_interval)?;
+    pipeline.start()?;
+    Ok((pipeline, controller, arrivals))
+}
+
+fn wait_for(arrivals: &Arrivals, count: usize) -> Vec<Instant> {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while arrivals.lock().unwrap().len() < count && Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(5));
+    }
+    arrivals.lock().unwrap().clone()
+}
+
+#[test]
+fn bursts_are_coalesced_and_rate_limited() -> Result<()> {
+    let interval = Duration::from_millis(200);
+    let (pipeline, controller, arrivals) = controlled(interval)?;
+
+    // The first change goes out at once.
+    let start = Instant::now();
+    controller.lock_awb(true);
+    let first = wait_for(&arrivals, 1)[0];
+    assert!(first - start < interval / 2, "first control took {:?}", first - start);
+
+    // A burst right after it waits for the interval and goes out as one message.
+    for i in 0..10 {
+        let offset = i as f32 * 0.02;
+        controller.set_exposure_region(Rect::new(offset, offset, 0.5, 0.5))?;
+    }
+    controller.trigger_autofocus();
+    let seen = wait_for(&arrivals, 2);
+    let gap = seen[1] - first;
+    assert!(gap >= interval - Duration::from_millis(10), "sent after {gap:?}");
+    std::thread::sleep(interval * 2);
+    assert_eq!(arrivals.lock().unwrap().len(), 2);
+
+    // After a quiet period, a change goes out immediately again.
+    let call = Instant::now();
+    controller.set_fps_cap(30.0)?;
+    let seen = wait_for(&arrivals, 3);
+    assert!(seen[2] - call < interval / 2, "took {:?}", seen[2] - call);
+
+    controller.flush()?;
+    drop(controller);
+    pipeline.stop()?;
+    Ok(())
+}
+
+#[test]
+fn flush_and_drop_send_pending_changes() -> Result<()> {
+    let (pipeline, controller, arrivals) = controlled(Duration::from_secs(60))?;
+    controller.lock_awb(true);
+    wait_for(&arrivals, 1);
+
+    // Within the interval, changes wait until flushed.
+    controller.lock_awb(false);
+    std::thread::sleep(Duration::from_millis(100));
+    assert_eq!(arrivals.lock().unwrap().len(), 1);
+    controller.flush()?;
+    assert_eq!(wait_for(&arrivals, 2).len(), 2);
+    // Nothing pending: flushing sends nothing.
+    controller.flush()?;
+
+    controller.trigger_autofocus();
+    drop(controller);
+    assert_eq!(wait_for(&arrivals, 3).len(), 3);
+    pipeline.stop()?;
+    Ok(())
+}
+
+#[test]
+fn invalid_adjustments_are_rejected() -> Result<()> {
+    let (pipeline, controller, arrivals) = controlled(CameraController::DEFAULT_MIN_INTERVAL)?;
+    assert!(controller.set_fps_cap(0.0).is_err());
+    assert!(controller.set_fps_cap(f32::NAN).is_err());
+    // In sensor pixels, entirely right of the 1000 px wide sensor.
+    assert!(controller
+        .set_exposure_region(Rect::new(5000.0, 0.0, 10.0, 10.0))
+        .is_err());
+    controller.flush()?;
+    std::thread::sleep(Duration::from_millis(100));
+    assert!(arrivals.lock().unwrap().is_empty());
+    pipeline.stop()?;
+    Ok(())
+}
@@ -0,0 +1,88 @@
+#![cfg(not(target_os = "windows"))]
+
+use depthai::camera::CameraOutputConfig;
+use depthai::common::{CameraBoardSocket, CameraImageOrientation, CameraSensorType, ImageFrameType};
+use depthai::{CameraFeatures, StereoPair};
+use serde_json::json;
+
+fn ov9282() -> CameraFeatures {
+    CameraFeatures::from_json(&json!({
+        "socket": 1,
+        "sensorName": "OV9282",
+        "name": "left",
+        "width": 1280,
+        "height": 800,
+        "orientation": -1,
+        "supportedTypes": [1],
+        "hasAutofocus": false,
+        "hasAutofocusIC": false,
+        "additionalNames": [],
+        "configs": [
+            { "width": 1280, "height": 800, "minFps": 1.0, "maxFps": 120.0, "type": 18,
+              "fov": { "x": 0.0, "y": 0.0, "width": 1.0, "height": 1.0 } },
+            { "width": 640, "height": 400, "minFps": 1.0, "maxFps": 210.0, "type": 18 },
+        ],
+    }))
+    .unwrap()
+}
+
+#[test]
+fn parses_cpp_camera_features() {
+    let features = ov9282();
+    assert_eq!(features.socket, CameraBoardSocket::CamB);
+    assert_eq!(features.sensor_name, "OV9282");
+    assert_eq!(features.max_resolution(), (1280, 800));
+    assert_eq!(features.orientation, CameraImageOrientation::Auto);
+    assert!(features.supports_type(CameraSensorType::Mono));
+    assert_eq!(features.configs[0].frame_type, Some(ImageFrameType::RAW8));
+    assert_eq!(features.max_fps(), Some(210.0));
+}
+
+#[test]
+fn finds_smallest_matching_mode() {
+    let features = ov9282();
+    assert_eq!(features.find_config((640, 400), Some(200.0)).unwrap().size(), (640, 400));
+    assert_eq!(features.find_config((640, 480), None).unwrap().size(), (1280, 800));
+    assert!(!features.supports((1280, 800), Some(200.0)));
+    assert!(!features.supports((1920, 1080), None));
+}
+
+#[test]
+fn fits_output_to_sensor() {
+    let features = ov9282();
+    let fitted = features.fit_output(CameraOutputConfig {
+        fps: Some(240.0),
+        ..CameraOutputConfig::new((1920, 1080))
+    });
+    assert_eq!(fitted.size, (1280, 720));
+    assert_eq!(fitted.fps, Some(120.0));
+
+    let unchanged = features.fit_output(CameraOutputConfig::new((640, 400)));
+    assert_eq!(unchanged.size, (640, 400));
+    assert_eq!(unchanged.fps, None);
+}
+
+#[test]
+fn rejects_malformed_json() {
+    assert!(CameraFeatures::from_json(&json!({ "sensorName": "x" })).is_err());
+}
+
+#[test]
+fn parses_and_orders_stereo_pairs() {
+    // The pairs of an OAK-D-LR.
+    let mut pairs: Vec<StereoPair> = [
+        json!({ "left": 1, "right": 0, "baseline": 10.0, "isVertical": false }),
+        json!({ "left": 1, "right": 2, "baseline": 15.0, "isVertical": false }),
+        json!({ "left": 0, "right": 2, "baseline": 5.0, "isVertical": false }),
+        json!({ "left": 3, "right": 4, "isVertical": true }),
+    ]
+    .iter()
+    .map(|v| StereoPair::from_json(v).unwrap())
+    .collect();
+    assert_eq!(pairs[3].baseline, -1.0);
+    StereoPair::sort_recommended(&mut pairs);
+    assert_eq!((pairs[0].left, pairs[0].right), (CameraBoardSocket::CamB, CameraBoardSocket::CamC));
+    assert_eq!(pairs[2].baseline, 5.0);
+    assert!(pairs[3].is_vertical);
+    assert!(StereoPair::from_json(&json!({ "left": 1 })).is_err());
+}
@@ -0,0 +1,43 @@
+#![cfg(not(target_os = "windows"))]
+
+use std::fs;
+
+use depthai::camera_feed::expand_glob;
+use depthai::convert;
+
+#[test]
+fn glob_matches_sorted_file_names() -> depthai::Result<()> {
+    let dir = std::env::temp_dir().join(format!("depthai-feed-glob-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    for name in ["frame_002.png", "frame_001.png", "frame_010.jpg", "notes.txt"] {
+        fs::write(dir.join(name), b"").unwrap();
+    }
+
+    let pattern = dir.join("frame_*.png");
+    let paths = expand_glob(pattern.to_str().unwrap())?;
+    let names: Vec<_> = paths.iter().map(|p| p.file_name().unwrap().to_str().unwrap()).collect();
+    assert_eq!(names, ["frame_001.png", "frame_002.png"]);
+
+    let single = expand_glob(dir.join("frame_01?.*").to_str().unwrap())?;
+    assert_eq!(single, vec![dir.join("frame_010.jpg")]);
+    assert!(expand_glob(dir.join("*.bmp").to_str().unwrap())?.is_empty());
+
+    fs::remove_dir_all(&dir).unwrap();
+    Ok(())
+}
+
+#[test]
+fn rgb_to_nv12_round_trips() -> depthai::Result<()> {
+    let colors = [[200u8, 40, 40], [40, 200, 40], [40, 40, 200], [128, 128, 128]];
+    for color in colors {
+        let rgb: Vec<u8> = color.iter().copied().cycle().take(4 * 2 * 3).collect();
+        let nv12 = convert::rgb888i_to_nv12(4, 2, &rgb)?;
+        assert_eq!(nv12.len(), 12);
+        let back = convert::nv12_to_rgb888i(4, 2, &nv12)?;
+        for (a, b) in back.iter().zip(&rgb) {
+            assert!(a.abs_diff(*b) <= 3, "{color:?}: {back:?}");
+        }
+    }
+    assert!(convert::rgb888i_to_nv12(3, 2, &[0; 18]).is_err());
+    Ok(())
+}
@@ -0,0 +1,80 @@
+#![cfg(not(target_os = "windows"))]
+
+use std::time::{Duration, Instant};
+
+use depthai::pipeline::Pipeline;
+use depthai::{Buffer, CancellationToken, Device, ThreadedHostNodeContext, ThreadedHostNodeImpl};
+
+/// Cancels a clone of `token` from another thread after `delay`.
+fn cancel_after(token: &CancellationToken, delay: Duration) -> std::thread::JoinHandle<()> {
+    let canceller = token.clone();
+    std::thread::spawn(move || {
+        std::thread::sleep(delay);
+        canceller.cancel();
+    })
+}
+
+#[test]
+fn cancellation_unblocks_a_queue_get() -> depthai::Result<()> {
+    let pipeline = Pipeline::new_host_only()?;
+    struct Noop;
+    impl ThreadedHostNodeImpl for Noop {
+        fn run(&mut self, _ctx: &ThreadedHostNodeContext) {}
+    }
+    let node = pipeline.create_threaded_host_node(|_| Ok(Noop))?;
+    let out = node.create_output(Some("out"))?;
+    let queue = out.create_message_queue(1, false)?;
+
+    let token = CancellationToken::new();
+    let started = Instant::now();
+    let handle = cancel_after(&token, Duration::from_millis(100));
+    assert!(queue.get_cancellable(None, &token).unwrap_err().is_cancelled());
+    assert!(started.elapsed() < Duration::from_secs(2));
+    handle.join().expect("canceller");
+
+    // A timeout still applies to a token that is never cancelled.
+    assert!(queue
+        .get_cancellable(Some(Duration::from_millis(20)), &CancellationToken::new())?
+        .is_none());
+
+    // A zero timeout still polls the queue once.
+    out.send_buffer(&Buffer::from_bytes(&[1])?)?;
+    assert!(queue
+        .get_cancellable(Some(Duration::ZERO), &CancellationToken::new())?
+        .is_some());
+    Ok(())
+}
+
+#[test]
+fn cancellation_unblocks_a_pipeline_wait() -> depthai::Result<()> {
+    let pipeline = Pipeline::new_host_only()?;
+    struct Idle;
+    impl ThreadedHostNodeImpl for Idle {
+        fn run(&mut self, ctx: &ThreadedHostNodeContext) {
+            ctx.wait_until_stopped();
+        }
+    }
+    pipeline.create_threaded_host_node(|_| Ok(Idle))?;
+    pipeline.start()?;
+
+    let token = CancellationToken::new();
+    let started = Instant::now();
+    let handle = cancel_after(&token, Duration::from_millis(100));
+    assert!(pipeline.wait_cancellable(&token).unwrap_err().is_cancelled());
+    assert!(started.elapsed() < Duration::from_secs(2));
+    handle.join().expect("canceller");
+    assert!(pipeline.is_running()?);
+    pipeline.stop()?;
+    Ok(())
+}
+
+#[test]
+fn cancelled_connect_gives_up_before_looking_for_devices() {
+    let token = CancellationToken::new();
+    token.cancel();
+    let err = Device::builder()
+        .device_id("nope")
+        .build_cancellable(&token)
+        .unwrap_err();
+    assert!(err.is_cancelled());
+}
@@ -0,0 +1,29 @@
+#![cfg(all(feature = "cli", not(target_os = "windows")))]
+
+use std::process::Command;
+
+fn daic_cli(args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_daic-cli")).args(args).output().expect("run daic-cli")
+}
+
+#[test]
+fn usage_errors_exit_with_code_2() {
+    assert!(daic_cli(&["--help"]).status.success());
+    assert_eq!(daic_cli(&[]).status.code(), Some(2));
+    assert_eq!(daic_cli(&["frobnicate"]).status.code(), Some(2));
+    assert_eq!(daic_cli(&["benchmark", "--size", "wide"]).status.code(), Some(2));
+    // Flashing is never started without an explicit confirmation.
+    let output = daic_cli(&["flash-bootloader", "14442C10D13EABCE00"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--yes"));
+}
+
+#[cfg(feature = "hit")]
+#[test]
+fn lists_and_inspects_a_device() {
+    let output = daic_cli(&["list"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let output = daic_cli(&["info"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("device id:"));
+}
@@ -0,0 +1,73 @@
+#![cfg(not(target_os = "windows"))]
+
+use depthai::camera::{ImageFrame, ImageFrameType};
+use depthai::convert;
+
+#[test]
+fn yuv_to_rgb_conversions() -> depthai::Result<()> {
+    // 2x2 mid-gray: Y=126 with neutral chroma maps to ~128 on every channel.
+    let nv12 = [126u8, 126, 126, 126, 128, 128];
+    let rgb = convert::nv12_to_rgb888i(2, 2, &nv12)?;
+    assert_eq!(rgb.len(), 12);
+    assert!(rgb.iter().all(|&v| v.abs_diff(128) <= 1), "{rgb:?}");
+
+    // Pure red in BT.601 limited range: Y=81, U=90, V=240.
+    let red = convert::nv12_to_rgb888i(2, 2, &[81, 81, 81, 81, 90, 240])?;
+    assert!(red[0] >= 250 && red[1] <= 5 && red[2] <= 5, "{:?}", &red[..3]);
+    // NV21 swaps the chroma order, YUV420p stores it in separate planes.
+    assert_eq!(convert::nv21_to_rgb888i(2, 2, &[81, 81, 81, 81, 240, 90])?, red);
+    assert_eq!(convert::yuv420p_to_rgb888i(2, 2, &[81, 81, 81, 81, 90, 240])?, red);
+
+    assert!(convert::nv12_to_rgb888i(3, 2, &[0; 9]).is_err());
+    assert!(convert::nv12_to_rgb888i(2, 2, &[0; 5]).is_err());
+
+    // Frames dispatch on their type.
+    let frame = ImageFrame::new(2, 2, ImageFrameType::NV12, &[81, 81, 81, 81, 90, 240])?;
+    assert_eq!(convert::to_rgb888i(&frame)?, red);
+    assert_eq!(convert::to_gray8(&frame)?, vec![81; 4]);
+
+    Ok(())
+}
+
+#[test]
+fn packed_and_planar_conversions() -> depthai::Result<()> {
+    let planar = [1u8, 2, 10, 20, 100, 200];
+    assert_eq!(convert::planar_to_interleaved(2, 1, &planar)?, vec![1, 10, 100, 2, 20, 200]);
+
+    let frame = ImageFrame::new(2, 1, ImageFrameType::BGR888p, &planar)?;
+    assert_eq!(convert::to_rgb888i(&frame)?, vec![100, 10, 1, 200, 20, 2]);
+
+    let mut bgr = vec![1u8, 2, 3, 4, 5, 6];
+    convert::swap_rb_in_place(&mut bgr);
+    assert_eq!(bgr, vec![3, 2, 1, 6, 5, 4]);
+
+    Ok(())
+}
+
+#[test]
+fn raw_unpacking_and_windowing() -> depthai::Result<()> {
+    // Four pixels: high bytes 0x00, 0x01, 0x80, 0xFF with low bits 0, 1, 2, 3.
+    let raw10 = [0x00u8, 0x01, 0x80, 0xFF, 0b11_10_01_00];
+    assert_eq!(convert::unpack_raw10(4, 1, 0, &raw10)?, vec![0, 5, 514, 1023]);
+    // Padded rows are skipped using the stride.
+    let padded = [raw10.as_slice(), &[0xAA; 3], raw10.as_slice()].concat();
+    assert_eq!(convert::unpack_raw10(4, 2, 8, &padded)?.len(), 8);
+    assert!(convert::unpack_raw10(3, 1, 0, &raw10).is_err());
+
+    // RAW12: high bytes 0x12, 0xAB, then low nibbles 0x4 (first) and 0xC (second).
+    assert_eq!(convert::unpack_raw12(2, 1, 0, &[0x12, 0xAB, 0xC4])?, vec![0x124, 0xABC]);
+    assert!(convert::unpack_raw12(3, 1, 0, &[0; 5]).is_err());
+    // RAW14: four 6-bit remainders 1, 2, 3, 63 spread over three bytes, first in the low bits.
+    let low: u32 = 1 | 2 << 6 | 3 << 12 | 63 << 18;
+    let raw14 = [0x00, 0x01, 0x80, 0xFF, low as u8, (low >> 8) as u8, (low >> 16) as u8];
+    assert_eq!(convert::unpack_raw14(4, 1, 0, &raw14)?, vec![1, 66, 8195, 16383]);
+    assert!(convert::unpack_raw14(4, 2, 0, &raw14).is_err());
+
+    let values = convert::raw16_to_u16(&[0x00, 0x00, 0xE8, 0x03, 0xD0, 0x07]);
+    assert_eq!(values, vec![0, 1000, 2000]);
+    assert_eq!(convert::gray16_min_max(&values), Some((1000, 2000)));
+    assert_eq!(convert::gray16_min_max(&[0, 0]), None);
+    assert_eq!(convert::gray16_to_gray8(&[500, 1000, 1500, 2000, 9000], 1000, 2000), vec![0, 0, 127, 255, 255]);
+
+    Ok(())
+}
@@ -0,0 +1,193 @@
+#![cfg(not(target_os = "windows"))]
+
+use depthai::camera::ImageFrame;
+use depthai::common::{ImageFrameType, Rect};
+use depthai::depth::{DepthAlgorithm, RoiDepthConfig};
+use depthai::{CameraIntrinsics, DepthFrame, DepthFrameView, DepthUnit};
+
+#[test]
+fn depth_point_queries_and_deprojection() -> depthai::Result<()> {
+    // 4x2 frame in millimeters, with one invalid pixel.
+    let depth = DepthFrame::from_raw(4, 2, vec![1000, 1000, 0, 1000, 2000, 2000, 2000, 2000], DepthUnit::Millimeter)?;
+    assert_eq!(depth.depth_at(0, 0), Some(1.0));
+    assert_eq!(depth.depth_at(2, 0), None);
+    assert_eq!(depth.depth_at(4, 0), None);
+    assert_eq!(depth.raw_at(3, 1), Some(2000));
+
+    let intrinsics = CameraIntrinsics::new(2.0, 2.0, 2.0, 1.0);
+    assert_eq!(depth.deproject(0, 0, &intrinsics), Some([-1.0, -0.5, 1.0]));
+    let point = intrinsics.deproject(3.0, 0.5, 4.0);
+    assert_eq!(intrinsics.project(point), Some((3.0, 0.5)));
+    assert_eq!(intrinsics.project([0.0, 0.0, -1.0]), None);
+
+    assert!(DepthFrame::from_raw(4, 2, vec![0; 7], DepthUnit::Millimeter).is_err());
+    assert!(DepthFrame::from_raw(1, 1, vec![0], DepthUnit::Custom).is_err());
+
+    let cm = DepthFrame::from_raw(1, 1, vec![150], DepthUnit::Centimeter)?;
+    assert_eq!(cm.depth_at(0, 0), Some(1.5));
+
+    Ok(())
+}
+
+#[test]
+fn roi_depth_rejects_outliers() -> depthai::Result<()> {
+    // 4x4 at ~1m with one far outlier and one invalid pixel.
+    let mut values = vec![1000u16; 16];
+    values[5] = 1010;
+    values[6] = 990;
+    values[10] = 9000;
+    values[15] = 0;
+    let depth = DepthFrame::from_raw(4, 4, values, DepthUnit::Millimeter)?;
+
+    let average = RoiDepthConfig { algorithm: DepthAlgorithm::Average, ..Default::default() };
+    let roi = depth.roi_depth(Rect::new(0.0, 0.0, 4.0, 4.0), &average).expect("roi depth");
+    assert_eq!((roi.samples, roi.rejected), (14, 1));
+    assert!((roi.depth - 1.0).abs() < 1e-3, "{roi:?}");
+
+    // Without rejection the outlier drags the average.
+    let keep_all = RoiDepthConfig { outlier_mad_factor: None, ..average };
+    let roi = depth.roi_depth(Rect::new(0.0, 0.0, 4.0, 4.0), &keep_all).expect("roi depth");
+    assert_eq!(roi.samples, 15);
+    assert!(roi.depth > 1.5);
+
+    // Normalized ROIs and thresholds.
+    let median = RoiDepthConfig { upper_threshold: 5.0, ..Default::default() };
+    let roi = depth.roi_depth(Rect::new(0.5, 0.5, 0.5, 0.5), &median).expect("roi depth");
+    assert_eq!(roi.samples, 2);
+    assert!(depth.roi_depth(Rect::new(3.0, 3.0, 1.0, 1.0), &median).is_none());
+
+    let location = depth
+        .roi_location(Rect::new(0.0, 0.0, 4.0, 4.0), &RoiDepthConfig::default(), &CameraIntrinsics::new(2.0, 2.0, 2.0, 2.0))
+        .expect("roi location");
+    assert_eq!(location, [0.0, 0.0, 1.0]);
+
+    Ok(())
+}
+
+#[test]
+fn low_confidence_pixels_are_masked() -> depthai::Result<()> {
+    let mut depth = DepthFrame::from_raw(3, 1, vec![1000, 1200, 0], DepthUnit::Millimeter)?;
+    let confidence = depthai::ConfidenceMap::from_raw(3, 1, vec![10, 200, 255])?;
+    assert_eq!(confidence.at(1, 0), Some(200));
+    assert_eq!(confidence.at(3, 0), None);
+
+    assert_eq!(depth.mask_low_confidence(&confidence, 128)?, 1);
+    assert_eq!(depth.values(), &[1000, 0, 0]);
+
+    let wrong_size = depthai::ConfidenceMap::from_raw(1, 1, vec![0])?;
+    assert!(depth.mask_low_confidence(&wrong_size, 128).is_err());
+    Ok(())
+}
+
+#[test]
+fn confidence_filtered_pointcloud() -> depthai::Result<()> {
+    use depthai::depth::PointCloudConfig;
+    use depthai::ConfidenceMap;
+
+    // 2x2 at 1m; one pixel without depth, one with poor confidence.
+    let depth = DepthFrame::from_raw(2, 2, vec![1000, 0, 1000, 1000], DepthUnit::Millimeter)?;
+    let confidence = ConfidenceMap::from_raw(2, 2, vec![10, 10, 250, 10])?;
+    let intrinsics = CameraIntrinsics::new(1.0, 1.0, 0.0, 0.0);
+
+    let config = PointCloudConfig { confidence_threshold: 200, ..Default::default() };
+    let points = depth.to_pointcloud(&intrinsics, Some(&confidence), &config)?;
+    let xyz: Vec<[f32; 3]> = points.iter().map(|p| [p.x, p.y, p.z]).collect();
+    assert_eq!(xyz, [[0.0, 0.0, 1.0], [1.0, 1.0, 1.0]]);
+
+    // Organized clouds keep skipped pixels as invalid points.
+    let organized = depth.to_pointcloud(&intrinsics, Some(&confidence), &PointCloudConfig { organized: true, ..config })?;
+    assert_eq!(organized.len(), 4);
+    assert_eq!(organized.iter().filter(|p| p.is_valid()).count(), 2);
+
+    assert_eq!(depth.to_pointcloud(&intrinsics, None, &PointCloudConfig::default())?.len(), 3);
+    let far_only = PointCloudConfig { min_depth: 1.5, ..Default::default() };
+    assert!(depth.to_pointcloud(&intrinsics, None, &far_only)?.is_empty());
+    assert!(depth.to_pointcloud(&intrinsics, Some(&ConfidenceMap::from_raw(1, 1, vec![0])?), &config).is_err());
+    let no_step = PointCloudConfig { decimation: 0, ..Default::default() };
+    assert!(depth.to_pointcloud(&intrinsics, None, &no_step).is_err());
+    Ok(())
+}
+
+#[test]
+fn raw16_frame_is_read_in_place() -> depthai::Result<()> {
+    let values = [1000u16, 0, 1500, 2000, 0, 0];
+    let bytes: Vec<u8> = values.iter().flat_map(|v| v.to_le_bytes()).collect();
+    let frame = ImageFrame::new(3, 2, ImageFrameType::RAW16, &bytes)?;
+    assert_eq!(frame.to_u16_vec()?, values);
+    // Host-allocated frames are aligned, so borrowing works on little-endian hosts.
+    assert_eq!(&frame.as_u16_slice()?[..6], &values);
+
+    let view = DepthFrameView::new(&frame, DepthUnit::Millimeter)?;
+    assert_eq!((view.width(), view.height()), (3, 2));
+    assert_eq!(view.invalid_count(), 3);
+    assert_eq!(view.min_depth(), Some(1.0));
+    assert_eq!(view.max_depth(), Some(2.0));
+    assert_eq!(view.median_depth(), Some(1.5));
+
+    let gray = ImageFrame::new(2, 1, ImageFrameType::GRAY8, &[1, 2])?;
+    assert!(gray.to_u16_vec().is_err());
+    assert!(DepthFrameView::new(&gray, DepthUnit::Millimeter).is_err());
+    Ok(())
+}
+
+fn close(value: Option<f32>, expected: f32) -> bool {
+    value.is_some_and(|v| (v - expected).abs() < 1e-4)
+}
+
+#[test]
+fn depth_view_crop_statistics() -> depthai::Result<()> {
+    // 4x3 with row padding (stride 5), the padding value must never be read.
+    #[rustfmt::skip]
+    let data = [
+        1000, 1100, 0,    1300, 9,
+        2000, 2100, 2200, 0,    9,
+        3000, 0,    3200, 3300, 9,
+    ];
+    let view = DepthFrameView::from_slice(&data, 4, 3, 5, DepthUnit::Millimeter)?;
+    assert_eq!(view.raw_at(3, 1), Some(0));
+    assert_eq!(view.raw_at(4, 1), None);
+
+    let crop = view.crop(Rect::new(1.0, 1.0, 2.0, 2.0));
+    assert_eq!((crop.width(), crop.height()), (2, 2));
+    assert_eq!(crop.raw_at(0, 0), Some(2100));
+    assert!(close(crop.depth_at(1, 1), 3.3));
+    let stats = crop.stats();
+    assert_eq!((stats.valid, stats.invalid), (3, 1));
+    assert!(close(stats.min, 2.1) && close(stats.max, 3.3) && close(stats.median, 2.2), "{stats:?}");
+
+    // Clamped to the view, and empty when fully outside.
+    let clamped = view.crop(Rect::new(2.0, 2.0, 10.0, 10.0));
+    assert_eq!((clamped.width(), clamped.height()), (2, 1));
+    let empty = view.crop(Rect::new(10.0, 0.0, 2.0, 2.0)).stats();
+    assert_eq!((empty.valid, empty.invalid, empty.median), (0, 0, None));
+
+    assert!(DepthFrameView::from_slice(&data[..13], 4, 3, 5, DepthUnit::Millimeter).is_err());
+    assert!(DepthFrameView::from_slice(&data, 6, 2, 5, DepthUnit::Millimeter).is_err());
+
+    let owned = DepthFrame::from_raw(2, 1, vec![0, 500], DepthUnit::Centimeter)?;
+    assert!(close(owned.view().stats().min, 5.0));
+    Ok(())
+}
+
+#[test]
+fn depth_unit_conversions() -> depthai::Result<()> {
+    assert_eq!(DepthUnit::Millimeter.scale_to_meters()?, 0.001);
+    assert!(close(DepthUnit::Meter.scale_to(DepthUnit::Millimeter).ok(), 1000.0));
+    assert!(close(DepthUnit::Foot.to_meters(10.0).ok(), 3.048));
+    assert!(close(DepthUnit::Centimeter.from_meters(1.5).ok(), 150.0));
+    assert!(DepthUnit::Custom.scale_to_meters().is_err());
+    assert!(DepthUnit::Custom.scale_to(DepthUnit::Meter).is_err());
+    assert_eq!(DepthUnit::from_raw(DepthUnit::Inch as i32), Some(DepthUnit::Inch));
+    assert_eq!(DepthUnit::from_raw(6), None);
+
+    let mm = DepthFrame::from_raw(3, 1, vec![1234, 0, 65000], DepthUnit::Millimeter)?;
+    let cm = mm.to_unit(DepthUnit::Centimeter)?;
+    assert_eq!(cm.unit(), DepthUnit::Centimeter);
+    assert_eq!(cm.values(), &[123, 0, 6500]);
+    assert_eq!(cm.to_unit(DepthUnit::Millimeter)?.values(), &[1230, 0, 65000]);
+    // Saturates rather than wrapping.
+    let far = DepthFrame::from_raw(1, 1, vec![100], DepthUnit::Meter)?;
+    assert_eq!(far.to_unit(DepthUnit::Millimeter)?.values(), &[65535]);
+    assert!(mm.to_unit(DepthUnit::Custom).is_err());
+    Ok(())
+}
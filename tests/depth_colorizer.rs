@@ -0,0 +1,92 @@
+#![cfg(not(target_os = "windows"))]
+
+use depthai::camera::{ImageFrame, ImageFrameType};
+use depthai::depth_colorizer::{colorize, colorize_frame, colormap_color};
+use depthai::{ColorizerBackend, Colormap, DepthColorizerConfig, DepthColorizerInput};
+
+fn pixel(rgb: &[u8], i: usize) -> [u8; 3] {
+    [rgb[i * 3], rgb[i * 3 + 1], rgb[i * 3 + 2]]
+}
+
+#[test]
+fn colormap_endpoints() {
+    assert_eq!(colormap_color(Colormap::Jet, 0.0), [0, 0, 128]);
+    assert_eq!(colormap_color(Colormap::Jet, 0.5), [128, 255, 128]);
+    assert_eq!(colormap_color(Colormap::Jet, 1.0), [128, 0, 0]);
+    assert_eq!(colormap_color(Colormap::None, 1.0), [255, 255, 255]);
+
+    // Turbo runs from blue to red.
+    let [r, _, b] = colormap_color(Colormap::Turbo, 0.15);
+    assert!(b > r, "{r} {b}");
+    let [r, _, b] = colormap_color(Colormap::Turbo, 0.85);
+    assert!(r > b, "{r} {b}");
+    assert_eq!(colormap_color(Colormap::StereoTurbo, 0.3), colormap_color(Colormap::Turbo, 0.3));
+}
+
+#[test]
+fn depth_is_warm_when_near_and_clamped_to_range() {
+    let config = DepthColorizerConfig {
+        colormap: Colormap::Jet,
+        range: Some((1000, 3000)),
+        ..DepthColorizerConfig::default()
+    };
+    let rgb = colorize(&[0, 500, 1000, 3000, 9000], &config);
+    assert_eq!(pixel(&rgb, 0), [0, 0, 0]);
+    assert_eq!(pixel(&rgb, 1), colormap_color(Colormap::Jet, 1.0));
+    assert_eq!(pixel(&rgb, 2), colormap_color(Colormap::Jet, 1.0));
+    assert_eq!(pixel(&rgb, 3), colormap_color(Colormap::Jet, 0.0));
+    assert_eq!(pixel(&rgb, 4), colormap_color(Colormap::Jet, 0.0));
+}
+
+#[test]
+fn disparity_stretches_to_valid_range() {
+    let config = DepthColorizerConfig {
+        input: DepthColorizerInput::Disparity,
+        colormap: Colormap::Jet,
+        mask_invalid: false,
+        ..DepthColorizerConfig::default()
+    };
+    let rgb = colorize(&[0, 10, 20], &config);
+    // Unmasked zeros clamp to the far end of the valid range 10..=20.
+    assert_eq!(pixel(&rgb, 0), colormap_color(Colormap::Jet, 0.0));
+    assert_eq!(pixel(&rgb, 2), colormap_color(Colormap::Jet, 1.0));
+
+    // Stereo colormaps mask zeros regardless.
+    let stereo = DepthColorizerConfig {
+        colormap: Colormap::StereoJet,
+        ..config
+    };
+    assert_eq!(pixel(&colorize(&[0, 10, 20], &stereo), 0), [0, 0, 0]);
+}
+
+#[test]
+fn device_path_only_when_equivalent() {
+    let disparity = DepthColorizerConfig {
+        input: DepthColorizerInput::Disparity,
+        colormap: Colormap::StereoTurbo,
+        ..DepthColorizerConfig::default()
+    };
+    assert!(disparity.supports_device());
+    assert!(!DepthColorizerConfig::default().supports_device());
+    assert!(!DepthColorizerConfig { range: Some((0, 95)), ..disparity }.supports_device());
+    assert!(!DepthColorizerConfig { colormap: Colormap::Turbo, ..disparity }.supports_device());
+    assert_eq!(DepthColorizerConfig::default().backend, ColorizerBackend::Auto);
+}
+
+#[test]
+fn colorize_raw16_frame() -> depthai::Result<()> {
+    let depth: Vec<u8> = [0u16, 1000, 2000, 3000].iter().flat_map(|v| v.to_le_bytes()).collect();
+    let frame = ImageFrame::new(2, 2, ImageFrameType::RAW16, &depth)?;
+    frame.set_sequence_num(7)?;
+    let colored = colorize_frame(&frame, &DepthColorizerConfig::default())?;
+    assert_eq!(colored.format(), Some(ImageFrameType::RGB888i));
+    assert_eq!((colored.width(), colored.height()), (2, 2));
+    assert_eq!(colored.sequence_num()?, 7);
+    let rgb = colored.bytes();
+    assert_eq!(pixel(&rgb, 0), [0, 0, 0]);
+    assert_eq!(pixel(&rgb, 1), colormap_color(Colormap::Turbo, 1.0));
+
+    let nv12 = ImageFrame::new(2, 2, ImageFrameType::NV12, &[0; 6])?;
+    assert!(colorize_frame(&nv12, &DepthColorizerConfig::default()).is_err());
+    Ok(())
+}
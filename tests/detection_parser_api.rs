@@ -0,0 +1,56 @@
+#![cfg(feature = "hit")]
+
+use depthai::nn_data::Tensor;
+use depthai::{create_custom_detection_parser, DetectionParserNode, ImgDetection, ImgDetections, NeuralNetworkNode, Pipeline, Result};
+
+#[test]
+fn detection_parser_exposes_ports() -> Result<()> {
+    let pipeline = Pipeline::new().build()?;
+    let nn = pipeline.create::<NeuralNetworkNode>()?;
+    let parser = pipeline.create::<DetectionParserNode>()?;
+    parser.set_confidence_threshold(0.5);
+    parser.set_iou_threshold(0.45);
+    parser.set_num_classes(80);
+    nn.out()?.link(&parser.input()?)?;
+    let _ = parser.out()?;
+    Ok(())
+}
+
+#[test]
+fn custom_parser_links_to_network() -> Result<()> {
+    let pipeline = Pipeline::new().build()?;
+    let nn = pipeline.create::<NeuralNetworkNode>()?;
+    let parser = create_custom_detection_parser(&pipeline, |_data| Ok(Vec::new()))?;
+    nn.out()?.link(&parser.input()?)?;
+    let _queue = parser.out()?.create_queue(4, false)?;
+    Ok(())
+}
+
+#[test]
+fn host_detections_can_be_built() -> Result<()> {
+    let mut detections = ImgDetections::new()?;
+    let detection = ImgDetection {
+        label: 3,
+        confidence: 0.9,
+        xmin: 0.1,
+        ymin: 0.2,
+        xmax: 0.5,
+        ymax: 0.6,
+    };
+    detections.push(detection)?;
+    assert_eq!(detections.detections()?, vec![detection]);
+    let _ = detections.to_buffer()?;
+    Ok(())
+}
+
+#[test]
+fn tensor_indexing_is_row_major() {
+    let tensor = Tensor {
+        dims: vec![2, 3],
+        data: vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0],
+    };
+    assert_eq!(tensor.at(&[1, 2]), Some(5.0));
+    assert_eq!(tensor.at(&[0, 1]), Some(1.0));
+    assert_eq!(tensor.at(&[2, 0]), None);
+    assert_eq!(tensor.at(&[0]), None);
+}
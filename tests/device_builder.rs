@@ -0,0 +1,36 @@
+#![cfg(feature = "hit")]
+
+use std::time::Duration;
+
+use depthai::{Device, Result, UsbSpeed};
+
+#[test]
+fn usb2_device_negotiates_at_most_high_speed() -> Result<()> {
+    let device = Device::builder()
+        .max_usb_speed(UsbSpeed::High)
+        .watchdog_timeout(Duration::from_secs(4))
+        .build()?;
+    assert!(device.is_connected());
+    assert!(device.usb_speed()? <= UsbSpeed::High);
+    device.close()
+}
+
+#[test]
+fn reset_device_can_be_reopened() -> Result<()> {
+    let device = Device::builder().watchdog_initial_delay(Duration::from_secs(8)).build()?;
+    device.reset(Duration::from_secs(15))?;
+    assert!(!device.is_connected());
+    let device = Device::builder().build()?;
+    assert!(device.is_connected());
+    device.close()
+}
+
+#[test]
+fn listed_device_opens_by_id() -> Result<()> {
+    let info = Device::list_available()?.into_iter().next().expect("a device");
+    let device = Device::builder().device_id(&info.device_id).build()?;
+    assert_eq!(device.device_id()?, info.device_id);
+    let temperature = device.chip_temperature()?;
+    assert!((0.0..150.0).contains(&temperature.average), "{temperature:?}");
+    device.close()
+}
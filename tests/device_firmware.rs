@@ -0,0 +1,15 @@
+#![cfg(not(target_os = "windows"))]
+
+use depthai::Device;
+
+// Checked on the host, so no device is needed.
+#[test]
+fn missing_firmware_is_rejected_before_connecting() {
+    let Err(err) = Device::builder()
+        .firmware_path("/nonexistent/depthai-device.mvcmd")
+        .build()
+    else {
+        panic!("a missing firmware file must fail");
+    };
+    assert!(err.to_string().contains("not found"), "unexpected error: {err}");
+}
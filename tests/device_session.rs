@@ -0,0 +1,121 @@
+#![cfg(all(feature = "mock", not(target_os = "windows")))]
+
+use depthai::camera::{CameraOutputConfig, ImageFrameType};
+use depthai::common::CameraBoardSocket;
+use depthai::mock::MockCameraNode;
+use depthai::{Device, ErrorKind, Pipeline, Result};
+
+fn mock_pipeline(device: &Device) -> Result<Pipeline> {
+    let pipeline = Pipeline::with_device(device)?;
+    let camera = pipeline.create_with::<MockCameraNode, _>(CameraBoardSocket::CamA)?;
+    let config = CameraOutputConfig {
+        frame_type: Some(ImageFrameType::GRAY8),
+        ..CameraOutputConfig::new((8, 4))
+    };
+    let _queue = camera.request_output(config)?.create_queue(2, false)?;
+    Ok(pipeline)
+}
+
+#[test]
+fn second_pipeline_on_a_busy_device_is_refused() -> Result<()> {
+    let device = Device::mock();
+    let first = mock_pipeline(&device)?;
+    let second = mock_pipeline(&device)?;
+
+    first.start()?;
+    let err = second.start().unwrap_err();
+    assert!(err.is_device_busy(), "{err}");
+    assert!(!second.is_running()?);
+    assert_eq!(device.exclusive_session().err().map(|e| e.kind()), Some(ErrorKind::DeviceBusy));
+
+    // Once the first pipeline stops, the device is free again.
+    first.stop()?;
+    second.start()?;
+    second.stop()?;
+
+    // Other devices are unaffected.
+    let third = mock_pipeline(&device)?;
+    let other = mock_pipeline(&Device::mock())?;
+    third.start()?;
+    other.start()?;
+    third.stop()?;
+    other.stop()?;
+    Ok(())
+}
+
+#[test]
+fn session_hands_the_device_over() -> Result<()> {
+    let device = Device::mock();
+    let calibration = mock_pipeline(&device)?;
+    let runtime = mock_pipeline(&device)?;
+
+    let mut session = device.exclusive_session()?;
+    assert!(device.exclusive_session().unwrap_err().is_device_busy());
+
+    session.start(&calibration)?;
+    assert!(calibration.is_running()?);
+    // Starting outside the session is refused while the session holds the device.
+    assert!(runtime.start().unwrap_err().is_device_busy());
+
+    session.start(&runtime)?;
+    assert!(!calibration.is_running()?);
+    assert!(runtime.is_running()?);
+    assert!(session.pipeline().is_some());
+
+    let elsewhere = mock_pipeline(&Device::mock())?;
+    assert!(session.start(&elsewhere).is_err());
+    assert!(runtime.is_running()?);
+
+    drop(session);
+    assert!(!runtime.is_running()?);
+    let after = mock_pipeline(&device)?;
+    after.start()?;
+    after.stop()?;
+    Ok(())
+}
+
+#[test]
+fn try_close_waits_for_the_last_handle() -> Result<()> {
+    let device = Device::mock();
+    let clone = device.clone();
+    assert!(device.try_close().unwrap_err().is_device_busy());
+    assert!(!clone.is_closed());
+
+    drop(clone);
+    device.try_close()?;
+    assert!(device.is_closed());
+    Ok(())
+}
+
+#[test]
+fn close_stops_the_running_pipeline_and_notifies() -> Result<()> {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let device = Device::mock();
+    let pipeline = mock_pipeline(&device)?;
+    pipeline.start()?;
+
+    let closes = Arc::new(AtomicUsize::new(0));
+    let count_close = |closes: &Arc<AtomicUsize>| {
+        let closes = Arc::clone(closes);
+        move || {
+            closes.fetch_add(1, Ordering::SeqCst);
+        }
+    };
+    let _subscription = device.on_close(count_close(&closes));
+    // Dropped subscriptions are not notified.
+    drop(device.on_close(count_close(&closes)));
+
+    let clone = device.clone();
+    clone.close()?;
+    assert!(!pipeline.is_running()?);
+    assert!(device.is_closed());
+    device.close()?;
+    assert_eq!(closes.load(Ordering::SeqCst), 1);
+
+    // Subscribing to a closed device runs the callback right away.
+    let _late = device.on_close(count_close(&closes));
+    assert_eq!(closes.load(Ordering::SeqCst), 2);
+    Ok(())
+}
@@ -0,0 +1,46 @@
+#![cfg(not(target_os = "windows"))]
+
+use std::time::Duration;
+
+use depthai::diagnostics::{host_clock_now, LatencyProbe};
+
+#[test]
+fn latency_probe_percentiles() {
+    let probe = LatencyProbe::new().with_window(100);
+    for ms in 1..=200u64 {
+        probe.record("rgb", Duration::from_millis(ms));
+    }
+    probe.record("depth", Duration::from_millis(7));
+
+    // Only the 100 most recent samples (101..=200 ms) are kept.
+    let rgb = probe.stats("rgb").expect("rgb stats");
+    assert_eq!(rgb.count, 200);
+    assert_eq!(rgb.window, 100);
+    assert_eq!(rgb.min, Duration::from_millis(101));
+    assert_eq!(rgb.p50, Duration::from_millis(150));
+    assert_eq!(rgb.p90, Duration::from_millis(190));
+    assert_eq!(rgb.p99, Duration::from_millis(199));
+    assert_eq!(rgb.max, Duration::from_millis(200));
+    assert_eq!(rgb.mean, Duration::from_micros(150_500));
+
+    let names: Vec<String> = probe.all_stats().into_iter().map(|(name, _)| name).collect();
+    assert_eq!(names, ["depth", "rgb"]);
+    assert!(probe.stats("left").is_none());
+
+    probe.reset();
+    assert!(probe.all_stats().is_empty());
+}
+
+#[test]
+fn latency_probe_host_created_frame() -> depthai::Result<()> {
+    use depthai::camera::{ImageFrame, ImageFrameType};
+
+    let frame = ImageFrame::new(2, 2, ImageFrameType::GRAY8, &[0u8; 4])?;
+    frame.set_timestamp(host_clock_now().saturating_sub(Duration::from_millis(20)))?;
+
+    let probe = LatencyProbe::new();
+    let latency = probe.record_frame("synthetic", &frame)?;
+    assert!(latency >= Duration::from_millis(20));
+    assert_eq!(probe.stats("synthetic").map(|s| s.count), Some(1));
+    Ok(())
+}
@@ -0,0 +1,51 @@
+#![cfg(not(target_os = "windows"))]
+
+use std::time::Duration;
+
+use depthai::device::{FirmwareLogMessage, LogLevel};
+use depthai::ErrorContext;
+use serde_json::json;
+
+fn log(level: LogLevel, node_id_name: &str, payload: &str) -> FirmwareLogMessage {
+    FirmwareLogMessage {
+        node_id_name: node_id_name.to_string(),
+        level,
+        time: Duration::from_millis(250),
+        payload: payload.to_string(),
+    }
+}
+
+fn schema() -> serde_json::Value {
+    json!({ "connections": [], "globalProperties": {}, "nodes": [
+        [0, { "id": 0, "name": "Camera", "properties": { "boardSocket": 0 } }],
+        [2, { "id": 2, "name": "StereoDepth", "properties": { "initialConfig": { "confidenceThreshold": 300 } } }],
+    ] })
+}
+
+#[test]
+fn names_the_node_from_the_device_logs() {
+    let logs = vec![
+        log(LogLevel::Info, "Camera(0)", "started"),
+        log(LogLevel::Error, "StereoDepth(2)", "invalid confidence threshold"),
+        log(LogLevel::Warn, "Camera(0)", "slow"),
+    ];
+    let context = ErrorContext::from_logs("failed to start pipeline", logs, Some(&schema()));
+    assert_eq!((context.node_name.as_deref(), context.node_id), (Some("StereoDepth"), Some(2)));
+    assert_eq!(context.device_logs.len(), 3);
+    assert_eq!(context.last_device_error().unwrap().payload, "invalid confidence threshold");
+    let snippet = context.schema_snippet.unwrap();
+    assert!(snippet.contains("confidenceThreshold"), "{snippet}");
+    assert!(!snippet.contains("boardSocket"), "{snippet}");
+}
+
+#[test]
+fn prefers_the_node_named_by_the_error() {
+    let logs = vec![log(LogLevel::Error, "StereoDepth(2)", "unrelated")];
+    let wrapped = json!({ "pipeline": schema() });
+    let context = ErrorContext::from_logs("Camera(0): sensor not found", logs, Some(&wrapped));
+    assert_eq!((context.node_name.as_deref(), context.node_id), (Some("Camera"), Some(0)));
+    assert!(context.schema_snippet.unwrap().contains("boardSocket"));
+
+    let unknown = ErrorContext::from_logs("failed to start pipeline", Vec::new(), None);
+    assert_eq!(unknown, ErrorContext::default());
+}
@@ -0,0 +1,90 @@
+#![cfg(not(target_os = "windows"))]
+
+use std::time::Duration;
+
+use depthai::encoded_frame::EncodedFrameProfile;
+use depthai::frame_metadata::{FrameMetadata, JPEG_APP_IDENTIFIER, METADATA_UUID};
+
+fn metadata() -> FrameMetadata {
+    FrameMetadata {
+        timestamp: Duration::from_nanos(1_234_567_890),
+        sequence: 42,
+        device_id: Some("1844301011D2C21200".to_string()),
+    }
+}
+
+fn annexb(nals: &[&[u8]]) -> Vec<u8> {
+    let mut out = Vec::new();
+    for nal in nals {
+        out.extend_from_slice(&[0, 0, 0, 1]);
+        out.extend_from_slice(nal);
+    }
+    out
+}
+
+#[test]
+fn h264_sei_goes_before_the_first_slice() {
+    let sps: &[u8] = &[0x67, 0x42, 0x00, 0x1f];
+    let pps: &[u8] = &[0x68, 0xce, 0x3c, 0x80];
+    let idr: &[u8] = &[0x65, 0x88, 0x84, 0x00];
+    let data = annexb(&[sps, pps, idr]);
+
+    let out = metadata().inject(EncodedFrameProfile::Avc, &data).unwrap();
+    let prefix = annexb(&[sps, pps]);
+    assert!(out.starts_with(&prefix));
+    assert_eq!(&out[prefix.len()..prefix.len() + 5], &[0, 0, 0, 1, 0x06]);
+    assert!(out.ends_with(&annexb(&[idr])));
+    assert_eq!(FrameMetadata::extract(EncodedFrameProfile::Avc, &out), Some(metadata()));
+}
+
+#[test]
+fn h265_round_trip() {
+    let vps: &[u8] = &[0x40, 0x01, 0x0c];
+    let idr: &[u8] = &[0x26, 0x01, 0xaf, 0x00];
+    let data = annexb(&[vps, idr]);
+
+    let out = metadata().inject(EncodedFrameProfile::Hevc, &data).unwrap();
+    let prefix = annexb(&[vps]);
+    assert_eq!(&out[prefix.len()..prefix.len() + 6], &[0, 0, 0, 1, 0x4e, 0x01]);
+    assert_eq!(FrameMetadata::extract(EncodedFrameProfile::Hevc, &out), Some(metadata()));
+}
+
+#[test]
+fn sei_payload_is_escaped() {
+    // A sequence number of 0 with no device ID keeps the payload short; the UUID itself must not
+    // produce start codes either.
+    let meta = FrameMetadata { timestamp: Duration::ZERO, sequence: 0, device_id: None };
+    let out = meta.inject(EncodedFrameProfile::Avc, &annexb(&[&[0x41, 0x9a]])).unwrap();
+    let sei = &out[5..out.len() - 6];
+    assert!(!sei.windows(3).any(|w| w[0] == 0 && w[1] == 0 && w[2] <= 2));
+    assert!(sei.windows(16).any(|w| w == METADATA_UUID));
+    assert_eq!(FrameMetadata::extract(EncodedFrameProfile::Avc, &out), Some(meta));
+}
+
+#[test]
+fn h264_without_slices_is_rejected() {
+    let data = annexb(&[&[0x67, 0x42, 0x00, 0x1f]]);
+    assert!(metadata().inject(EncodedFrameProfile::Avc, &data).is_err());
+}
+
+#[test]
+fn jpeg_app_segment_follows_jfif() {
+    let jfif = [0xff, 0xe0, 0x00, 0x04, 0x4a, 0x46];
+    let mut data = vec![0xff, 0xd8];
+    data.extend_from_slice(&jfif);
+    data.extend_from_slice(&[0xff, 0xdb, 0x00, 0x03, 0x00, 0xff, 0xda, 0x00, 0x02, 0x12, 0x34, 0xff, 0xd9]);
+
+    let out = metadata().inject(EncodedFrameProfile::Jpeg, &data).unwrap();
+    assert_eq!(&out[..8], &data[..8]);
+    assert_eq!(&out[8..10], &[0xff, 0xeb]);
+    assert_eq!(&out[12..12 + JPEG_APP_IDENTIFIER.len()], JPEG_APP_IDENTIFIER);
+    assert!(out.ends_with(&data[8..]));
+    assert_eq!(FrameMetadata::extract(EncodedFrameProfile::Jpeg, &out), Some(metadata()));
+}
+
+#[test]
+fn jpeg_without_metadata() {
+    let data = [0xff, 0xd8, 0xff, 0xda, 0x00, 0x02, 0x12, 0xff, 0xd9];
+    assert_eq!(FrameMetadata::extract(EncodedFrameProfile::Jpeg, &data), None);
+    assert!(metadata().inject(EncodedFrameProfile::Jpeg, &[0x00, 0x01]).is_err());
+}
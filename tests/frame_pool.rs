@@ -0,0 +1,111 @@
+#![cfg(not(target_os = "windows"))]
+
+use std::sync::mpsc;
+use std::time::Duration;
+
+use depthai::frame_pool::{FramePool, FramePoolConfig};
+use depthai::pipeline::PipelineConfig;
+use depthai::Result;
+
+const TIMEOUT: Option<Duration> = Some(Duration::from_secs(5));
+
+/// A source yielding `0..count`, then nothing.
+fn counter(count: u32) -> impl FnMut(Duration) -> Result<Option<u32>> + Send + 'static {
+    let mut next = 0;
+    move |_| {
+        if next < count {
+            next += 1;
+            Ok(Some(next - 1))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// Later values finish first.
+fn slow_square(v: u32) -> u32 {
+    std::thread::sleep(Duration::from_millis(u64::from(20 - v % 20)));
+    v * v
+}
+
+#[test]
+fn ordered_results_follow_arrival_order() -> Result<()> {
+    let config = FramePoolConfig {
+        workers: 4,
+        max_in_flight: 6,
+        ordered: true,
+    };
+    let mut pool = FramePool::from_source(counter(40), config, slow_square)?;
+    for v in 0..40 {
+        assert_eq!(pool.recv(TIMEOUT)?, Some(v * v));
+    }
+    assert_eq!(pool.recv(Some(Duration::from_millis(100)))?, None);
+    assert_eq!(pool.in_flight(), 0);
+    Ok(())
+}
+
+#[test]
+fn unordered_results_are_complete() -> Result<()> {
+    let config = FramePoolConfig {
+        workers: 4,
+        max_in_flight: 8,
+        ordered: false,
+    };
+    let mut pool = FramePool::from_source(counter(40), config, slow_square)?;
+    let mut results: Vec<u32> = (0..40).map(|_| pool.recv(TIMEOUT).map(Option::unwrap)).collect::<Result<_>>()?;
+    results.sort_unstable();
+    assert_eq!(results, (0..40).map(|v| v * v).collect::<Vec<_>>());
+    Ok(())
+}
+
+#[test]
+fn in_flight_messages_are_bounded() -> Result<()> {
+    let (tx, rx) = mpsc::channel::<u32>();
+    let source = move |timeout| Ok(rx.recv_timeout(timeout).ok());
+    let config = FramePoolConfig {
+        workers: 4,
+        max_in_flight: 3,
+        ordered: true,
+    };
+    let mut pool = FramePool::from_source(source, config, |v| v)?;
+    for v in 0..10 {
+        tx.send(v).unwrap();
+    }
+    std::thread::sleep(Duration::from_millis(200));
+    // Only three messages were taken while no result was received.
+    assert_eq!(pool.in_flight(), 3);
+    for v in 0..10 {
+        assert_eq!(pool.recv(TIMEOUT)?, Some(v));
+    }
+    Ok(())
+}
+
+#[test]
+fn source_errors_and_panics() -> Result<()> {
+    let mut next = 0;
+    let source = move |_| {
+        next += 1;
+        match next {
+            1..=3 => Ok(Some(next)),
+            _ => PipelineConfig::from_json_str("{ \"queue\": \"closed\" }").map(|_| None),
+        }
+    };
+    let config = FramePoolConfig {
+        workers: 2,
+        max_in_flight: 4,
+        ordered: true,
+    };
+    let mut pool = FramePool::from_source(source, config, |v: i32| {
+        assert_ne!(v, 2, "bad frame");
+        v
+    })?;
+    // The panicking message is skipped; the error comes after the results before it.
+    assert_eq!(pool.recv(TIMEOUT)?, Some(1));
+    assert_eq!(pool.recv(TIMEOUT)?, Some(3));
+    let err = pool.recv(TIMEOUT).unwrap_err();
+    assert!(err.to_string().contains("queue"), "unexpected error: {err}");
+    assert_eq!(pool.recv(Some(Duration::from_millis(100)))?, None);
+
+    assert!(FramePool::from_source(counter(1), FramePoolConfig { workers: 0, ..config }, |v| v).is_err());
+    Ok(())
+}
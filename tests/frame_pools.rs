@@ -0,0 +1,81 @@
+#![cfg(not(target_os = "windows"))]
+
+use std::time::Duration;
+
+use depthai::device::{FirmwareLogMessage, LogLevel};
+use depthai::pipeline::{FramePoolReport, PoolExhaustion};
+use serde_json::json;
+
+fn pipeline_json(nodes: serde_json::Value) -> serde_json::Value {
+    json!({ "pipeline": { "connections": [], "globalProperties": {}, "nodes": nodes } })
+}
+
+fn log(level: LogLevel, node_id_name: &str, payload: &str) -> FirmwareLogMessage {
+    FirmwareLogMessage {
+        node_id_name: node_id_name.to_string(),
+        level,
+        time: Duration::from_millis(1500),
+        payload: payload.to_string(),
+    }
+}
+
+#[test]
+fn lists_pools_per_node() {
+    let json = pipeline_json(json!([
+        [0, { "id": 0, "name": "Camera", "properties": {
+            "numFramesPoolRaw": 3, "maxSizePoolRaw": 2048, "numFramesPoolIsp": 2,
+            "numFramesPoolOutputs": null } }],
+        [3, { "id": 3, "name": "ImageManip", "properties": { "outputFrameSize": 1024, "numFramesPool": 4 } }],
+        [4, { "id": 4, "name": "XLinkOut", "properties": { "streamName": "rgb" } }],
+    ]));
+    let report = FramePoolReport::from_pipeline_json(&json);
+    let camera = report.node(0).map(|p| (p.pool.as_str(), p.frames)).collect::<Vec<_>>();
+    assert_eq!(camera, [("isp", Some(2)), ("outputs", None), ("raw", Some(3))]);
+    assert_eq!(report.node(0).find(|p| p.pool == "raw").unwrap().bytes(), Some(6144));
+
+    let manip = report.node(3).next().unwrap();
+    assert_eq!((manip.pool.as_str(), manip.frames, manip.bytes()), ("output", Some(4), Some(4096)));
+    assert_eq!(report.node(4).count(), 0);
+    assert_eq!(report.smaller_than(3).count(), 1);
+    assert!(report.to_string().contains("ImageManip #3"));
+}
+
+#[test]
+fn recognizes_pool_exhaustion_logs() {
+    let isp = PoolExhaustion::from_log(&log(
+        LogLevel::Error,
+        "Camera(0)",
+        "Out of memory while creating pool for 'isp' frames. Number of frames: 3 each with size: 2764800B",
+    ))
+    .unwrap();
+    assert_eq!((isp.node_name.as_deref(), isp.node_id), (Some("Camera"), Some(0)));
+    assert_eq!(isp.pool, "isp");
+    assert_eq!((isp.frames, isp.frame_bytes), (Some(3), Some(2_764_800)));
+    assert_eq!(isp.time, Duration::from_millis(1500));
+    assert!(isp.hint().contains("Camera #0 (3 frames of 2700 KiB)"), "{}", isp.hint());
+
+    // Single-pool nodes, and the node only named in the payload.
+    let manip = PoolExhaustion::from_log(&log(
+        LogLevel::Error,
+        "",
+        "ImageManip(3) Out of memory while creating pool for 'frames'. Number of frames: 4 each with size: 6220800B",
+    ))
+    .unwrap();
+    assert_eq!((manip.node_id, manip.pool.as_str()), (Some(3), "frames"));
+    assert_eq!(manip.frames, Some(4));
+
+    let anonymous =
+        PoolExhaustion::from_log(&log(LogLevel::Critical, "", "Out of memory while creating pool for 'raw' frames."))
+            .unwrap();
+    assert_eq!((anonymous.node_id, anonymous.frames), (None, None));
+    assert!(anonymous.hint().contains("the node"));
+}
+
+#[test]
+fn ignores_other_logs() {
+    let oom = "Out of memory while creating pool for 'isp' frames. Number of frames: 3 each with size: 2764800B";
+    assert!(PoolExhaustion::from_log(&log(LogLevel::Info, "Camera(0)", oom)).is_none());
+    assert!(PoolExhaustion::from_log(&log(LogLevel::Warn, "Camera(0)", "Exposure out of range")).is_none());
+    assert!(PoolExhaustion::from_log(&log(LogLevel::Warn, "Camera(0)", "Created pool of 4 frames")).is_none());
+    assert!(PoolExhaustion::from_log(&log(LogLevel::Error, "Camera(0)", "Output queue is full")).is_none());
+}
@@ -0,0 +1,64 @@
+#![cfg(not(target_os = "windows"))]
+
+use depthai::camera::{ImageFrame, ImageFrameType};
+use depthai::{FrameStats, FrameStatsConfig};
+
+fn every_pixel() -> FrameStatsConfig {
+    FrameStatsConfig {
+        stride: 1,
+        ..FrameStatsConfig::default()
+    }
+}
+
+#[test]
+fn gray_frame_stats() -> depthai::Result<()> {
+    let frame = ImageFrame::new(4, 1, ImageFrameType::GRAY8, &[0, 100, 200, 255])?;
+    let stats = FrameStats::compute(&frame, &every_pixel())?;
+    assert_eq!(stats.samples, 4);
+    assert_eq!(stats.channels.len(), 1);
+
+    let luma = &stats.channels[0];
+    assert_eq!(luma.histogram.len(), 256);
+    assert_eq!(luma.histogram[100], 1);
+    assert!((luma.mean - 138.75).abs() < 1e-3, "{}", luma.mean);
+    assert!((luma.stddev - 96.98).abs() < 0.01, "{}", luma.stddev);
+    assert_eq!(luma.saturated_percent, 25.0);
+    Ok(())
+}
+
+#[test]
+fn color_frame_stats_per_channel() -> depthai::Result<()> {
+    let rgb = [255u8, 0, 10, 255, 0, 30];
+    let frame = ImageFrame::new(2, 1, ImageFrameType::RGB888i, &rgb)?;
+    let stats = FrameStats::compute(&frame, &every_pixel())?;
+    assert_eq!(stats.channels.len(), 3);
+    assert_eq!(stats.channels[0].saturated_percent, 100.0);
+    assert_eq!(stats.channels[1].mean, 0.0);
+    assert_eq!(stats.channels[2].mean, 20.0);
+    assert_eq!(stats.channels[2].stddev, 10.0);
+    Ok(())
+}
+
+#[test]
+fn stride_subsamples_rows_and_columns() -> depthai::Result<()> {
+    let frame = ImageFrame::new(4, 4, ImageFrameType::GRAY8, &[7; 16])?;
+    let config = FrameStatsConfig {
+        stride: 2,
+        ..FrameStatsConfig::default()
+    };
+    let stats = FrameStats::compute(&frame, &config)?;
+    assert_eq!(stats.samples, 4);
+    assert_eq!(stats.channels[0].histogram[7], 4);
+    Ok(())
+}
+
+#[test]
+fn stats_round_trip_through_buffer() -> depthai::Result<()> {
+    let frame = ImageFrame::new(2, 2, ImageFrameType::GRAY8, &[1, 2, 3, 4])?;
+    frame.set_sequence_num(42)?;
+    let stats = FrameStats::compute(&frame, &every_pixel())?;
+    let buffer = stats.to_buffer()?;
+    assert_eq!(buffer.sequence_num()?, 42);
+    assert_eq!(FrameStats::from_buffer(&buffer)?, stats);
+    Ok(())
+}
@@ -0,0 +1,167 @@
+#![cfg(not(target_os = "windows"))]
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use depthai::host_executor::{ExecutionPolicy, HostExecutor, PENDING_GROUPS};
+use depthai::{
+    Buffer, HostNode, HostNodeImpl, MessageGroup, Pipeline, Result, ThreadedHostNodeContext, ThreadedHostNodeImpl,
+    TypedOutput,
+};
+
+struct Noop;
+impl HostNodeImpl for Noop {
+    fn process_group(&mut self, _group: &MessageGroup) -> Option<Buffer> {
+        None
+    }
+}
+
+#[test]
+fn host_nodes_accept_execution_policies() -> Result<()> {
+    let pipeline = Pipeline::new_host_only()?;
+    let executor = HostExecutor::new(0)?;
+    assert_eq!(executor.workers(), 1);
+
+    let dedicated = pipeline.create_host_node(Noop)?;
+    assert!(dedicated.step(Some(Duration::ZERO)).is_err());
+
+    let shared = pipeline.create_host_node_with_policy(Noop, ExecutionPolicy::Shared(executor.clone()))?;
+    assert!(shared.step(Some(Duration::ZERO)).is_err());
+
+    // Nothing arrives before the pipeline starts.
+    let driven = pipeline.create_host_node_with_policy(Noop, ExecutionPolicy::CallerDriven)?;
+    assert_eq!(driven.step(Some(Duration::from_millis(10)))?, 0);
+    Ok(())
+}
+
+/// Sends `count` buffers numbered from 0, `spacing` apart, then idles.
+struct Source {
+    out: TypedOutput<Buffer>,
+    count: i64,
+    spacing: Duration,
+}
+
+impl ThreadedHostNodeImpl for Source {
+    fn run(&mut self, ctx: &ThreadedHostNodeContext) {
+        for seq in 0..self.count {
+            let sent = Buffer::new(1).and_then(|buffer| {
+                buffer.set_sequence_num(seq)?;
+                self.out.send(&buffer)
+            });
+            if let Err(err) = sent {
+                ctx.report_error(&err);
+            }
+            std::thread::sleep(self.spacing);
+        }
+        ctx.wait_until_stopped();
+    }
+}
+
+/// Sequence numbers of the processed groups, with the name of the processing thread.
+type Seen = Arc<Mutex<Vec<(i64, Option<String>)>>>;
+
+/// Records each group it processes in `seen`.
+struct Recorder {
+    seen: Seen,
+    work: Duration,
+}
+
+impl HostNodeImpl for Recorder {
+    fn process_group(&mut self, group: &MessageGroup) -> Option<Buffer> {
+        let seq = group.get_buffer("seq").ok()??.sequence_num().ok()?;
+        std::thread::sleep(self.work);
+        let thread = std::thread::current().name().map(str::to_string);
+        self.seen.lock().unwrap().push((seq, thread));
+        None
+    }
+}
+
+/// A source of `count` buffers feeding a recording host node run with `policy`.
+fn fed_host_node(
+    pipeline: &Pipeline,
+    policy: ExecutionPolicy,
+    count: i64,
+    spacing: Duration,
+    work: Duration,
+) -> Result<(HostNode, Seen)> {
+    let seen = Seen::default();
+    let source = pipeline.create_threaded_host_node(|node| {
+        Ok(Source {
+            out: node.create_typed_output("out")?,
+            count,
+            spacing,
+        })
+    })?;
+    let host = pipeline.create_host_node_with_policy(
+        Recorder {
+            seen: Arc::clone(&seen),
+            work,
+        },
+        policy,
+    )?;
+    source.as_node().output("out")?.link(&host.input("seq")?)?;
+    Ok((host, seen))
+}
+
+fn wait_for(seen: &Seen, count: usize) -> Vec<(i64, Option<String>)> {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while seen.lock().unwrap().len() < count && Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(5));
+    }
+    seen.lock().unwrap().clone()
+}
+
+#[test]
+fn shared_nodes_process_in_order_on_the_executor() -> Result<()> {
+    let pipeline = Pipeline::new_host_only()?;
+    let executor = HostExecutor::new(1)?;
+    // Processing is slower than arrival, so groups wait and each node is rescheduled.
+    let nodes = (0..2)
+        .map(|_| {
+            let policy = ExecutionPolicy::Shared(executor.clone());
+            fed_host_node(&pipeline, policy, 3, Duration::from_millis(5), Duration::from_millis(20))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    pipeline.start()?;
+    for (node, seen) in &nodes {
+        let seen = wait_for(seen, 3);
+        assert_eq!(seen.iter().map(|(seq, _)| *seq).collect::<Vec<_>>(), [0, 1, 2]);
+        assert!(
+            seen.iter().all(|(_, thread)| thread.as_deref() == Some("depthai-host-executor-0")),
+            "{seen:?}"
+        );
+        assert!(node.step(Some(Duration::ZERO)).is_err());
+    }
+    pipeline.stop()?;
+    Ok(())
+}
+
+#[test]
+fn caller_driven_nodes_keep_the_newest_groups() -> Result<()> {
+    let pipeline = Pipeline::new_host_only()?;
+    let count = PENDING_GROUPS as i64 + 3;
+    let (node, seen) = fed_host_node(
+        &pipeline,
+        ExecutionPolicy::CallerDriven,
+        count,
+        Duration::from_millis(5),
+        Duration::ZERO,
+    )?;
+    pipeline.start()?;
+    // Nothing is processed until the caller steps.
+    std::thread::sleep(Duration::from_millis(300));
+    assert!(seen.lock().unwrap().is_empty());
+
+    assert_eq!(node.step(Some(Duration::from_secs(1)))?, PENDING_GROUPS);
+    let this_thread = std::thread::current().name().map(str::to_string);
+    let seen = seen.lock().unwrap().clone();
+    // The oldest groups were dropped.
+    assert_eq!(
+        seen.iter().map(|(seq, _)| *seq).collect::<Vec<_>>(),
+        (count - PENDING_GROUPS as i64..count).collect::<Vec<_>>()
+    );
+    assert!(seen.iter().all(|(_, thread)| *thread == this_thread));
+    assert_eq!(node.step(Some(Duration::from_millis(10)))?, 0);
+    pipeline.stop()?;
+    Ok(())
+}
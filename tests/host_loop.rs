@@ -0,0 +1,183 @@
+#![cfg(not(target_os = "windows"))]
+
+use std::time::Duration;
+
+#[cfg(feature = "hit")]
+use depthai::camera::CameraNode;
+use depthai::camera::{ImageFrame, ImageFrameType};
+#[cfg(feature = "hit")]
+use depthai::common::CameraBoardSocket;
+use depthai::pipeline::{HostLoopExit, HostLoopOptions, LoopControl};
+use depthai::{Pipeline, Result, ThreadedHostNodeContext, ThreadedHostNodeImpl};
+
+fn options(rate_hz: Option<f32>) -> HostLoopOptions {
+    HostLoopOptions {
+        rate_hz,
+        max_duration: Some(Duration::from_secs(5)),
+        ..Default::default()
+    }
+}
+
+/// Host-only pipeline whose single node idles until the pipeline stops.
+fn idle_pipeline() -> Result<Pipeline> {
+    struct Idle;
+    impl ThreadedHostNodeImpl for Idle {
+        fn run(&mut self, ctx: &ThreadedHostNodeContext) {
+            while ctx.is_running() {
+                std::thread::sleep(Duration::from_millis(1));
+            }
+        }
+    }
+    let pipeline = Pipeline::new_host_only()?;
+    pipeline.create_threaded_host_node(|_| Ok(Idle))?;
+    Ok(pipeline)
+}
+
+/// A failing call: a frame without its payload.
+fn failing_call() -> Result<()> {
+    ImageFrame::new(2, 2, ImageFrameType::GRAY8, &[]).map(|_| ())
+}
+
+#[test]
+fn default_options_leave_signals_alone() {
+    let options = HostLoopOptions::default();
+    assert!(!options.handle_ctrl_c);
+    assert!(options.start && options.stop_on_exit);
+    assert_eq!(HostLoopOptions::with_rate(10.0).rate_hz, Some(10.0));
+}
+
+#[test]
+fn host_only_loop_breaks_and_stops_the_pipeline() -> Result<()> {
+    let pipeline = idle_pipeline()?;
+    let mut ticks = Vec::new();
+    let stats = pipeline.run_host_loop(options(Some(200.0)), |tick| {
+        ticks.push(tick);
+        Ok(if tick.iteration == 4 { LoopControl::Break } else { LoopControl::Continue })
+    })?;
+    assert_eq!(stats.exit, HostLoopExit::Break);
+    assert_eq!(stats.iterations, 5);
+    assert_eq!(ticks.iter().map(|t| t.iteration).collect::<Vec<_>>(), [0, 1, 2, 3, 4]);
+    assert_eq!(ticks[0].delta, Duration::ZERO);
+    // Four periods of 5 ms between the first and the last call.
+    assert!(ticks[4].elapsed >= Duration::from_millis(18), "{:?}", ticks[4].elapsed);
+    assert!(!pipeline.is_running()?);
+    Ok(())
+}
+
+#[test]
+fn host_only_loop_ends_on_timeout_and_counts_overruns() -> Result<()> {
+    let pipeline = idle_pipeline()?;
+    let stats = pipeline.run_host_loop(
+        HostLoopOptions {
+            max_duration: Some(Duration::from_millis(100)),
+            stop_on_exit: false,
+            ..options(Some(500.0))
+        },
+        |tick| {
+            // Every other call overruns its 2 ms period.
+            if tick.iteration % 2 == 0 {
+                std::thread::sleep(Duration::from_millis(4));
+            }
+            Ok(LoopControl::Continue)
+        },
+    )?;
+    assert_eq!(stats.exit, HostLoopExit::Timeout);
+    assert!(stats.elapsed >= Duration::from_millis(100));
+    assert!(stats.overruns > 0 && stats.overruns <= stats.iterations);
+    assert!(stats.max_callback >= Duration::from_millis(4));
+    assert!(stats.mean_callback() <= stats.max_callback);
+    // `stop_on_exit: false` leaves the pipeline running.
+    assert!(pipeline.is_running()?);
+    pipeline.stop()?;
+    Ok(())
+}
+
+#[test]
+fn host_only_loop_notices_a_stopped_pipeline() -> Result<()> {
+    let pipeline = idle_pipeline()?;
+    let inner = pipeline.clone();
+    let stats = pipeline.run_host_loop(options(None), |tick| {
+        if tick.iteration == 2 {
+            inner.stop()?;
+        }
+        Ok(LoopControl::Continue)
+    })?;
+    assert_eq!(stats.exit, HostLoopExit::PipelineStopped);
+    assert_eq!(stats.iterations, 3);
+
+    // Not started and not allowed to start: no call at all.
+    let stats = idle_pipeline()?.run_host_loop(
+        HostLoopOptions {
+            start: false,
+            ..options(None)
+        },
+        |_| panic!("called without a running pipeline"),
+    )?;
+    assert_eq!(stats.exit, HostLoopExit::PipelineStopped);
+    assert_eq!(stats.iterations, 0);
+    Ok(())
+}
+
+#[test]
+fn host_only_loop_returns_the_callback_error() -> Result<()> {
+    let expected = failing_call().unwrap_err().to_string();
+    let pipeline = idle_pipeline()?;
+    let err = pipeline
+        .run_host_loop(options(None), |tick| {
+            if tick.iteration == 3 {
+                failing_call()?;
+            }
+            Ok(LoopControl::Continue)
+        })
+        .unwrap_err();
+    assert_eq!(err.to_string(), expected);
+    assert!(!pipeline.is_running()?);
+    Ok(())
+}
+
+#[cfg(feature = "hit")]
+#[test]
+fn paced_loop_runs_at_the_requested_rate() -> Result<()> {
+    let pipeline = Pipeline::new().build()?;
+    let _camera = pipeline.create_with::<CameraNode, _>(CameraBoardSocket::CamA)?;
+    let mut ticks = Vec::new();
+    let stats = pipeline.run_host_loop(options(Some(50.0)), |tick| {
+        ticks.push(tick);
+        Ok(if tick.iteration == 9 { LoopControl::Break } else { LoopControl::Continue })
+    })?;
+    assert_eq!(stats.exit, HostLoopExit::Break);
+    assert_eq!(stats.iterations, 10);
+    assert_eq!(ticks[0].delta, Duration::ZERO);
+    // Nine periods of 20 ms between the first and the last call.
+    assert!(ticks[9].elapsed >= Duration::from_millis(170), "{:?}", ticks[9].elapsed);
+    assert!(stats.rate_hz() < 60.0, "{}", stats.rate_hz());
+    assert!(!pipeline.is_running()?);
+    Ok(())
+}
+
+#[cfg(feature = "hit")]
+#[test]
+fn loop_ends_on_timeout_and_callback_errors() -> Result<()> {
+    let pipeline = Pipeline::new().build()?;
+    let _camera = pipeline.create_with::<CameraNode, _>(CameraBoardSocket::CamA)?;
+    let stats = pipeline.run_host_loop(
+        HostLoopOptions { max_duration: Some(Duration::from_millis(200)), ..options(Some(100.0)) },
+        |_| Ok(LoopControl::Continue),
+    )?;
+    assert_eq!(stats.exit, HostLoopExit::Timeout);
+    assert!(stats.iterations > 0);
+    assert!(stats.mean_callback() <= stats.max_callback);
+
+    let pipeline = Pipeline::new().build()?;
+    let _camera = pipeline.create_with::<CameraNode, _>(CameraBoardSocket::CamA)?;
+    let err = pipeline.run_host_loop(options(None), |tick| {
+        if tick.iteration == 3 {
+            // Any failing call: a frame without its payload.
+            ImageFrame::new(2, 2, ImageFrameType::GRAY8, &[])?;
+        }
+        Ok(LoopControl::Continue)
+    });
+    assert!(err.is_err());
+    assert!(!pipeline.is_running()?);
+    Ok(())
+}
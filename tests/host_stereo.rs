@@ -0,0 +1,104 @@
+#![cfg(all(feature = "host-stereo", not(target_os = "windows")))]
+
+use std::time::Duration;
+
+use depthai::camera::{ImageFrame, ImageFrameType};
+use depthai::host_stereo::{HostStereoDepth, SUBPIXEL_FRACTIONAL_BITS};
+
+const WIDTH: u32 = 320;
+const HEIGHT: u32 = 120;
+const SHIFT: usize = 20;
+
+/// A rectified pair of random texture where everything is `SHIFT` pixels of disparity away.
+fn shifted_pair() -> (Vec<u8>, Vec<u8>) {
+    let (w, h) = (WIDTH as usize, HEIGHT as usize);
+    // Texture wide enough for the shifted view; coarse blocks keep it matchable.
+    let mut state = 0x2545_f491_u32;
+    let mut noise = || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        (state >> 24) as u8
+    };
+    let blocks: Vec<u8> = (0..((w + SHIFT) / 2 + 1) * (h / 2 + 1)).map(|_| noise()).collect();
+    let texture = |x: usize, y: usize| blocks[(y / 2) * ((w + SHIFT) / 2 + 1) + x / 2];
+    let mut left = Vec::with_capacity(w * h);
+    let mut right = Vec::with_capacity(w * h);
+    for y in 0..h {
+        for x in 0..w {
+            left.push(texture(x, y));
+            right.push(texture(x + SHIFT, y));
+        }
+    }
+    (left, right)
+}
+
+/// Median of the valid disparities away from the left border, where nothing can match.
+fn median_valid(disparity: &[u16]) -> u16 {
+    let mut valid: Vec<u16> = disparity
+        .chunks(WIDTH as usize)
+        .flat_map(|row| row[120..300].iter().copied())
+        .filter(|&d| d > 0)
+        .collect();
+    assert!(valid.len() > disparity.len() / 4, "too few valid disparities: {}", valid.len());
+    valid.sort_unstable();
+    valid[valid.len() / 2]
+}
+
+#[test]
+fn finds_the_shift_of_a_textured_pair() {
+    let (left, right) = shifted_pair();
+    let mut stereo = HostStereoDepth::new();
+    let disparity = stereo.compute(WIDTH, HEIGHT, &left, &right).unwrap();
+    assert_eq!(disparity.len(), (WIDTH * HEIGHT) as usize);
+    assert_eq!(median_valid(&disparity), SHIFT as u16);
+    assert!(disparity.iter().all(|&d| u32::from(d) <= stereo.max_disparity()));
+
+    stereo.set_subpixel(true);
+    let subpixel = stereo.compute(WIDTH, HEIGHT, &left, &right).unwrap();
+    let expected = (SHIFT as u16) << SUBPIXEL_FRACTIONAL_BITS;
+    assert!(median_valid(&subpixel).abs_diff(expected) <= 2);
+}
+
+#[test]
+fn depth_needs_baseline_and_focal_length() {
+    let mut stereo = HostStereoDepth::new();
+    assert!(stereo.depth_from_disparity(&[20]).is_none());
+
+    stereo.set_baseline(7.5);
+    stereo.set_focal_length(400.0);
+    // 400 px * 75 mm / 20 px = 1500 mm; invalid disparity stays invalid.
+    assert_eq!(stereo.depth_from_disparity(&[20, 0]).unwrap(), vec![1500, 0]);
+
+    stereo.set_threshold_filter_max_range(1000);
+    assert_eq!(stereo.depth_from_disparity(&[20, 40]).unwrap(), vec![0, 750]);
+
+    stereo.set_subpixel(true);
+    assert_eq!(stereo.depth_from_disparity(&[20 << SUBPIXEL_FRACTIONAL_BITS]).unwrap(), vec![0]);
+    assert_eq!(stereo.depth_from_disparity(&[40 << SUBPIXEL_FRACTIONAL_BITS]).unwrap(), vec![750]);
+}
+
+#[test]
+fn processes_frames_like_the_device_node() {
+    let (left, right) = shifted_pair();
+    let left = ImageFrame::new(WIDTH, HEIGHT, ImageFrameType::GRAY8, &left).unwrap();
+    let right = ImageFrame::new(WIDTH, HEIGHT, ImageFrameType::GRAY8, &right).unwrap();
+    left.set_timestamp(Duration::from_millis(40)).unwrap();
+    left.set_sequence_num(3).unwrap();
+
+    let mut stereo = HostStereoDepth::new();
+    let output = stereo.process(&left, &right).unwrap();
+    assert_eq!(output.disparity.format(), Some(ImageFrameType::RAW16));
+    assert_eq!(output.disparity.timestamp().unwrap(), Duration::from_millis(40));
+    assert_eq!(output.disparity.sequence_num().unwrap(), 3);
+    assert!(output.depth.is_none());
+
+    stereo.set_baseline(7.5);
+    stereo.set_focal_length(400.0);
+    let depth = stereo.process(&left, &right).unwrap().depth.expect("depth with calibration set");
+    let values = depth.to_u16_vec().unwrap();
+    assert_eq!(median_valid(&values), 1500);
+
+    let small = ImageFrame::new(8, 8, ImageFrameType::GRAY8, &[0; 64]).unwrap();
+    assert!(stereo.process(&left, &small).is_err());
+}
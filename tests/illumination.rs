@@ -0,0 +1,50 @@
+#![cfg(not(target_os = "windows"))]
+
+use depthai::{IlluminationConfig, IlluminationMode, IlluminationPhase};
+
+#[test]
+fn alternating_schedule_follows_sequence_numbers() {
+    let config = IlluminationConfig::alternating(2, 1);
+    let phases: Vec<_> = (0..6).map(|seq| config.phase(seq).unwrap()).collect();
+    use IlluminationPhase::{Dot, Flood};
+    assert_eq!(phases, vec![Dot, Dot, Flood, Dot, Dot, Flood]);
+    assert!((config.duty_cycle() - 2.0 / 3.0).abs() < 1e-6);
+    assert_eq!(config.phase(-1), Some(Flood));
+}
+
+#[test]
+fn emitters_switch_ahead_of_the_frames_they_light() {
+    use IlluminationPhase::{Dot, Flood};
+    let config = IlluminationConfig { latency_frames: 1, ..IlluminationConfig::alternating(2, 1) };
+    // Before the first frame, set up for frame 1 (frame 0 is already on its way).
+    assert_eq!(config.switch_for(-1), Some(Dot));
+    // Receiving frame n switches for frame n + 2.
+    let switches: Vec<_> = (0..6).map(|seq| config.switch_for(seq).unwrap()).collect();
+    assert_eq!(switches, vec![Flood, Dot, Dot, Flood, Dot, Dot]);
+    for seq in 0..6 {
+        assert_eq!(config.switch_for(seq), config.phase(seq + 2));
+    }
+
+    let immediate = IlluminationConfig { latency_frames: 0, ..config };
+    assert_eq!(immediate.switch_for(1), Some(Flood));
+    let off = IlluminationConfig { mode: IlluminationMode::Off, ..config };
+    assert_eq!(off.switch_for(0), None);
+}
+
+#[test]
+fn fixed_modes_have_constant_phase() {
+    let off = IlluminationConfig { mode: IlluminationMode::Off, ..IlluminationConfig::default() };
+    assert_eq!(off.phase(3), None);
+    assert_eq!(off.duty_cycle(), 0.0);
+    let dot = IlluminationConfig::default();
+    assert_eq!(dot.phase(7), Some(IlluminationPhase::Dot));
+    assert_eq!(dot.duty_cycle(), 1.0);
+}
+
+#[test]
+fn validate_rejects_bad_settings() {
+    assert!(IlluminationConfig::alternating(1, 1).validate().is_ok());
+    assert!(IlluminationConfig::alternating(1, 0).validate().is_err());
+    let too_bright = IlluminationConfig { flood_intensity: 1.5, ..IlluminationConfig::default() };
+    assert!(too_bright.validate().is_err());
+}
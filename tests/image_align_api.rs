@@ -0,0 +1,71 @@
+#![cfg(feature = "hit")]
+
+use depthai::camera::{CameraNode, CameraOutputConfig};
+use depthai::common::{CameraBoardSocket, ImageFrameType, Interpolation, ResizeMode};
+use depthai::{ImageAlignConfig, ImageAlignNode, Pipeline, Result, StereoDepthNode};
+
+#[test]
+fn image_align_api_smoke() -> Result<()> {
+    let pipeline = Pipeline::new().build()?;
+
+    let align = pipeline.create::<ImageAlignNode>()?;
+    align.set_output_size(640, 400);
+    align.set_out_keep_aspect_ratio(true);
+    align.set_interpolation(Interpolation::NearestNeighbor);
+    align.set_num_shaves(2);
+    align.set_num_frames_pool(4);
+
+    align.set_run_on_host(true);
+    assert!(align.run_on_host()?);
+
+    // Initial config access + mutation (shared with the node).
+    let mut initial = align.initial_config()?;
+    initial.set_static_depth_plane(1000);
+    assert_eq!(align.initial_config()?.static_depth_plane()?, 1000);
+
+    // Standalone config message.
+    let mut cfg = ImageAlignConfig::new()?;
+    cfg.set_static_depth_plane(0);
+    assert_eq!(cfg.static_depth_plane()?, 0);
+    let _as_buffer = cfg.as_buffer();
+
+    Ok(())
+}
+
+/// Same wiring as the RVC4 branch of `examples/rgbd_rerun.rs`: depth is aligned to the color
+/// stream by a host-side `ImageAlign` instead of `StereoDepth.inputAlignTo`.
+#[test]
+fn image_align_host_path_links() -> Result<()> {
+    let pipeline = Pipeline::new().build()?;
+
+    let cam_color = pipeline.create_with::<CameraNode, _>(CameraBoardSocket::CamA)?;
+    let cam_left = pipeline.create_with::<CameraNode, _>(CameraBoardSocket::CamB)?;
+    let cam_right = pipeline.create_with::<CameraNode, _>(CameraBoardSocket::CamC)?;
+
+    let mut color_cfg = CameraOutputConfig::new((640, 400));
+    color_cfg.frame_type = Some(ImageFrameType::RGB888i);
+    color_cfg.resize_mode = ResizeMode::Crop;
+    let out_color = cam_color.request_output(color_cfg)?;
+
+    let mut mono_cfg = CameraOutputConfig::new((640, 400));
+    mono_cfg.frame_type = Some(ImageFrameType::GRAY8);
+    let out_left = cam_left.request_output(mono_cfg.clone())?;
+    let out_right = cam_right.request_output(mono_cfg)?;
+
+    let stereo = pipeline.create::<StereoDepthNode>()?;
+    out_left.link_to(stereo.as_node(), Some("left"))?;
+    out_right.link_to(stereo.as_node(), Some("right"))?;
+
+    let align = pipeline.create::<ImageAlignNode>()?;
+    align.set_run_on_host(true);
+    align.set_output_size(640, 400);
+    align.set_out_keep_aspect_ratio(true);
+
+    stereo.as_node().output("depth")?.link_to(align.as_node(), Some("input"))?;
+    out_color.link_to(align.as_node(), Some("inputAlignTo"))?;
+
+    let _aligned = align.as_node().output("outputAligned")?;
+    let _aligned_typed = align.outputAligned()?;
+
+    Ok(())
+}
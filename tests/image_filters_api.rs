@@ -0,0 +1,76 @@
+#![cfg(not(target_os = "windows"))]
+
+use depthai::image_filters::{
+    ImageFilter, ImageFiltersPresetMode, MedianKernel, PersistencyMode, SpatialFilter, SpeckleFilter, TemporalFilter,
+};
+use depthai::{ImageFiltersConfig, Result};
+
+#[test]
+fn image_filters_config_round_trips_the_chain() -> Result<()> {
+    let mut config = ImageFiltersConfig::new()?;
+    assert!(config.filters()?.is_empty());
+
+    let temporal = TemporalFilter {
+        persistency_mode: PersistencyMode::Valid1InLast5,
+        alpha: 0.25,
+        ..TemporalFilter::default()
+    };
+    config
+        .insert_filter(ImageFilter::Median(MedianKernel::Kernel5x5))?
+        .insert_filter(ImageFilter::Spatial(SpatialFilter::default()))?
+        .insert_filter(ImageFilter::Speckle(SpeckleFilter::default()))?
+        .insert_filter(ImageFilter::Temporal(temporal))?;
+    assert_eq!(
+        config.filters()?,
+        vec![
+            ImageFilter::Median(MedianKernel::Kernel5x5),
+            ImageFilter::Spatial(SpatialFilter::default()),
+            ImageFilter::Speckle(SpeckleFilter::default()),
+            ImageFilter::Temporal(temporal),
+        ]
+    );
+
+    // Retune one step in place.
+    let spatial = SpatialFilter {
+        num_iterations: 3,
+        ..SpatialFilter::default()
+    };
+    config.update_filter_at(1, ImageFilter::Spatial(spatial))?;
+    assert_eq!(config.filters()?[1], ImageFilter::Spatial(spatial));
+    assert_eq!(config.filters()?.len(), 4);
+
+    config.set_profile_preset(ImageFiltersPresetMode::TofMidRange)?;
+    let _as_buffer = config.as_buffer();
+
+    Ok(())
+}
+
+#[cfg(feature = "hit")]
+#[test]
+fn image_filters_node_only_with_opencv() -> Result<()> {
+    use depthai::version::{has_feature, Feature};
+    use depthai::{ImageFiltersNode, Pipeline};
+
+    let pipeline = Pipeline::new().build()?;
+    if !has_feature(Feature::ImageFilters) {
+        assert!(pipeline.create::<ImageFiltersNode>().is_err());
+        return Ok(());
+    }
+
+    let filters = pipeline.create::<ImageFiltersNode>()?;
+    filters.set_run_on_host(true);
+    assert!(filters.run_on_host()?);
+    filters.set_default_profile_preset(ImageFiltersPresetMode::TofLowRange);
+
+    // Initial config is shared with the node.
+    let mut initial = filters.initial_config()?;
+    let before = initial.filters()?.len();
+    initial.insert_filter(ImageFilter::Median(MedianKernel::Kernel3x3))?;
+    assert_eq!(filters.initial_config()?.filters()?.len(), before + 1);
+
+    let _input = filters.input()?;
+    let _config_input = filters.inputConfig()?;
+    let _output = filters.output()?;
+
+    Ok(())
+}
@@ -36,3 +36,31 @@ fn image_manip_api_smoke() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn image_manip_config_copy_is_independent_and_resettable() -> Result<()> {
+    let mut base = ImageManipConfig::new()?;
+    base.set_undistort(true);
+
+    let mut update = base.try_clone()?;
+    update.set_undistort(false).set_skip_current_image(true);
+    assert!(base.undistort()?);
+    assert!(update.skip_current_image()?);
+
+    base.reset();
+    assert!(!base.undistort()?);
+    Ok(())
+}
+
+#[cfg(feature = "hit")]
+#[test]
+fn image_manip_runtime_config_queue() -> Result<()> {
+    let pipeline = Pipeline::new().build()?;
+    let manip = pipeline.create::<ImageManipNode>()?;
+    let queue = manip.input_config_queue()?;
+
+    let mut cfg = ImageManipConfig::new()?;
+    cfg.add_crop_rect(0.25, 0.25, 0.5, 0.5, true);
+    queue.send_config(&cfg)?;
+    Ok(())
+}
@@ -0,0 +1,94 @@
+#![cfg(not(target_os = "windows"))]
+
+use std::f32::consts::FRAC_PI_2;
+use std::time::Duration;
+
+use depthai::imu_alignment::{ImuFrameAligner, Quaternion};
+
+const UP: [f32; 3] = [0.0, 0.0, 9.81];
+
+fn ms(ms: u64) -> Duration {
+    Duration::from_millis(ms)
+}
+
+fn tilt(q: Quaternion) -> f32 {
+    let z = q.rotate([0.0, 0.0, 1.0]);
+    z[2].clamp(-1.0, 1.0).acos()
+}
+
+#[test]
+fn interpolates_between_samples_around_a_frame() {
+    let mut aligner = ImuFrameAligner::new();
+    // Turning around z at 1 rad/s, sampled at 100 Hz, level.
+    for i in 0..=50 {
+        aligner.push_sample(ms(i * 10), [0.0, 0.0, 1.0], Some(UP));
+    }
+    assert_eq!(aligner.latest_timestamp(), Some(ms(500)));
+
+    let pose = aligner.pose_at(ms(255)).expect("samples around 255 ms");
+    assert_eq!(pose.timestamp, ms(255));
+    assert_eq!(pose.angular_velocity, [0.0, 0.0, 1.0]);
+    assert!(
+        (pose.orientation.angle() - 0.255).abs() < 1e-3,
+        "{:?}",
+        pose.orientation
+    );
+    assert!(tilt(pose.orientation) < 1e-3);
+
+    let turn = aligner.rotation_between(ms(100), ms(400)).unwrap();
+    assert!((turn.angle() - 0.3).abs() < 1e-3);
+
+    // Nothing after the newest sample yet.
+    assert!(aligner.pose_at(ms(505)).is_none());
+}
+
+#[test]
+fn angular_velocity_is_interpolated_linearly() {
+    let mut aligner = ImuFrameAligner::new();
+    aligner.push_sample(ms(0), [0.0, 0.0, 0.0], None);
+    aligner.push_sample(ms(10), [1.0, -2.0, 0.5], None);
+    let pose = aligner.pose_at(ms(5)).unwrap();
+    assert_eq!(pose.angular_velocity, [0.5, -1.0, 0.25]);
+    assert_eq!(aligner.pose_at(ms(10)).unwrap().angular_velocity, [1.0, -2.0, 0.5]);
+}
+
+#[test]
+fn first_accelerometer_sample_sets_the_tilt() {
+    let mut aligner = ImuFrameAligner::new();
+    // Lying on its side: gravity along +x in the IMU frame.
+    aligner.push_sample(ms(0), [0.0; 3], Some([9.81, 0.0, 0.0]));
+    let up = aligner.pose_at(ms(0)).unwrap().orientation.rotate([1.0, 0.0, 0.0]);
+    assert!((up[2] - 1.0).abs() < 1e-5, "{up:?}");
+    assert!((aligner.pose_at(ms(0)).unwrap().orientation.angle() - FRAC_PI_2).abs() < 1e-5);
+}
+
+#[test]
+fn accelerometer_bounds_gyroscope_drift() {
+    let bias = [0.05, 0.0, 0.0];
+    let mut corrected = ImuFrameAligner::new();
+    let mut raw = ImuFrameAligner::new();
+    corrected.set_history(Duration::from_secs(20));
+    raw.set_history(Duration::from_secs(20));
+    for i in 0..=1000 {
+        corrected.push_sample(ms(i * 10), bias, Some(UP));
+        raw.push_sample(ms(i * 10), bias, if i == 0 { Some(UP) } else { None });
+    }
+    let drifted = tilt(raw.pose_at(ms(10_000)).unwrap().orientation);
+    let held = tilt(corrected.pose_at(ms(10_000)).unwrap().orientation);
+    assert!((drifted - 0.5).abs() < 1e-2, "{drifted}");
+    assert!(held < 0.05, "{held}");
+}
+
+#[test]
+fn old_samples_leave_the_history() {
+    let mut aligner = ImuFrameAligner::new();
+    aligner.set_history(ms(100));
+    for i in 0..=30 {
+        aligner.push_sample(ms(i * 10), [0.0; 3], Some(UP));
+    }
+    assert!(aligner.pose_at(ms(150)).is_none());
+    assert!(aligner.pose_at(ms(250)).is_some());
+    // Out-of-order samples are ignored.
+    aligner.push_sample(ms(100), [1.0; 3], None);
+    assert_eq!(aligner.latest_timestamp(), Some(ms(300)));
+}
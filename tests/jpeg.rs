@@ -0,0 +1,60 @@
+#![cfg(all(feature = "jpeg", not(target_os = "windows")))]
+
+use std::io::Cursor;
+
+use depthai::camera::ImageFrameType;
+use depthai::jpeg::{self, JpegDecoder};
+use image::{DynamicImage, GrayImage, ImageFormat, RgbImage};
+
+fn encode(image: DynamicImage) -> Vec<u8> {
+    let mut out = Cursor::new(Vec::new());
+    image.write_to(&mut out, ImageFormat::Jpeg).unwrap();
+    out.into_inner()
+}
+
+fn rgb_jpeg(width: u32, height: u32, shade: u8) -> Vec<u8> {
+    encode(DynamicImage::ImageRgb8(RgbImage::from_pixel(width, height, image::Rgb([shade, 128, 255 - shade]))))
+}
+
+#[test]
+fn decodes_color_and_gray() {
+    let color = jpeg::decode(&rgb_jpeg(32, 16, 200)).unwrap();
+    assert_eq!((color.width, color.height, color.frame_type), (32, 16, ImageFrameType::RGB888i));
+    assert_eq!(color.data.len(), 32 * 16 * 3);
+    assert!(color.data[0].abs_diff(200) <= 4 && color.data[2].abs_diff(55) <= 4);
+
+    let gray = jpeg::decode(&encode(DynamicImage::ImageLuma8(GrayImage::from_pixel(8, 8, image::Luma([90]))))).unwrap();
+    assert_eq!(gray.frame_type, ImageFrameType::GRAY8);
+    assert_eq!(gray.data.len(), 64);
+
+    let frame = color.to_image_frame().unwrap();
+    assert_eq!((frame.width(), frame.height()), (32, 16));
+}
+
+#[test]
+fn rejects_non_jpeg_data() {
+    assert!(jpeg::decode(b"not a jpeg").is_err());
+}
+
+#[test]
+fn decoder_returns_frames_in_order() {
+    let mut decoder = JpegDecoder::new(3).unwrap();
+    assert_eq!(decoder.threads(), 3);
+    // Larger images first, so later frames tend to finish earlier.
+    let sizes = [(640, 480), (320, 240), (64, 48), (16, 16), (8, 8)];
+    for (w, h) in sizes {
+        decoder.submit_bytes(rgb_jpeg(w, h, 10)).unwrap();
+    }
+    decoder.submit_bytes(b"broken".to_vec()).unwrap();
+    assert_eq!(decoder.pending(), 6);
+
+    let decoded = decoder.by_ref().collect::<Vec<_>>();
+    assert_eq!(decoded.len(), 6);
+    for (frame, (w, h)) in decoded.iter().zip(sizes) {
+        let frame = frame.as_ref().unwrap();
+        assert_eq!((frame.width(), frame.height()), (w, h));
+    }
+    assert!(decoded[5].is_err());
+    assert_eq!(decoder.pending(), 0);
+    assert!(decoder.try_next().is_none());
+}
@@ -0,0 +1,30 @@
+#![cfg(not(target_os = "windows"))]
+
+use depthai::camera::ImageFrameType;
+use depthai::message_pool::{BufferPool, ImageFramePool};
+
+#[test]
+fn frames_are_reused_and_overwritten() {
+    let mut pool = ImageFramePool::new(4, 2, ImageFrameType::GRAY8, 2).unwrap();
+    assert_eq!(pool.stats().allocated, 2);
+
+    let frame = pool.fill(&[7; 8]).unwrap();
+    assert_eq!((frame.width(), frame.height()), (4, 2));
+    assert_eq!(frame.bytes(), vec![7; 8]);
+    let frame = pool.fill(&[9; 8]).unwrap();
+    assert_eq!(frame.bytes(), vec![9; 8]);
+    // Round-robin back to the first frame, which nothing else holds.
+    assert_eq!(pool.fill(&[1; 8]).unwrap().bytes(), vec![1; 8]);
+
+    let stats = pool.stats();
+    assert_eq!((stats.size, stats.reused, stats.allocated), (2, 3, 2));
+}
+
+#[test]
+fn buffers_are_reused_and_resized() {
+    let mut pool = BufferPool::new(16, 0).unwrap();
+    assert_eq!(pool.stats().size, 1);
+    assert_eq!(pool.fill(b"hello").unwrap().data(), b"hello");
+    assert_eq!(pool.fill(&[0xAB; 32]).unwrap().data(), &[0xAB; 32]);
+    assert_eq!(pool.stats().reused, 2);
+}
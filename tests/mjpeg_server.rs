@@ -0,0 +1,57 @@
+#![cfg(all(feature = "http-stream", not(target_os = "windows")))]
+
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::time::Duration;
+
+use depthai::{MjpegServerConfig, MjpegServerHostNode, Pipeline, Result};
+
+fn get(server: &MjpegServerHostNode, path: &str) -> String {
+    let mut stream = TcpStream::connect(server.local_addr()).expect("connect");
+    stream.set_read_timeout(Some(Duration::from_secs(2))).unwrap();
+    write!(stream, "GET {path} HTTP/1.1\r\nHost: localhost\r\n\r\n").unwrap();
+    let mut response = vec![0; 512];
+    let n = stream.read(&mut response).expect("read response");
+    String::from_utf8_lossy(&response[..n]).into_owned()
+}
+
+fn local_server(pipeline: &Pipeline) -> Result<MjpegServerHostNode> {
+    pipeline.create_with::<MjpegServerHostNode, _>(MjpegServerConfig {
+        bind_ip: "127.0.0.1".to_string(),
+        port: 0,
+        ..MjpegServerConfig::default()
+    })
+}
+
+#[test]
+fn serves_index_and_stream_headers() -> Result<()> {
+    let pipeline = Pipeline::new_host_only()?;
+    let server = local_server(&pipeline)?;
+    let port = server.local_addr().port();
+    assert_ne!(port, 0);
+    assert_eq!(server.url(), format!("http://127.0.0.1:{port}/stream.mjpg"));
+
+    let index = get(&server, "/");
+    assert!(index.starts_with("HTTP/1.1 200 OK"), "{index}");
+    assert!(index.contains("/stream.mjpg"));
+
+    let stream = get(&server, "/stream.mjpg");
+    assert!(stream.starts_with("HTTP/1.1 200 OK"), "{stream}");
+    assert!(stream.contains("multipart/x-mixed-replace"));
+
+    assert!(get(&server, "/missing").starts_with("HTTP/1.1 404"));
+    Ok(())
+}
+
+#[test]
+fn port_in_use_is_reported_at_creation() -> Result<()> {
+    let pipeline = Pipeline::new_host_only()?;
+    let first = local_server(&pipeline)?;
+    let taken = pipeline.create_with::<MjpegServerHostNode, _>(MjpegServerConfig {
+        bind_ip: "127.0.0.1".to_string(),
+        port: first.local_addr().port(),
+        ..MjpegServerConfig::default()
+    });
+    assert!(taken.is_err());
+    Ok(())
+}
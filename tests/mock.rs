@@ -0,0 +1,106 @@
+#![cfg(all(feature = "mock", not(target_os = "windows")))]
+
+use std::time::Duration;
+
+use depthai::camera::{CameraOutputConfig, ImageFrameType};
+use depthai::common::CameraBoardSocket;
+use depthai::convert;
+use depthai::mock::{pattern_frame, FrameSource, MockCameraNode, MockDeviceConfig, TestPattern};
+use depthai::{Device, DevicePlatform, Pipeline, Result};
+
+#[test]
+fn mock_device_reports_its_config() -> Result<()> {
+    let device = Device::mock_with(MockDeviceConfig {
+        platform: DevicePlatform::Rvc4,
+        cameras: vec![CameraBoardSocket::CamA],
+        ..MockDeviceConfig::default()
+    });
+    assert!(device.is_mock());
+    assert_eq!(device.platform()?, DevicePlatform::Rvc4);
+    assert_eq!(device.connected_cameras()?, vec![CameraBoardSocket::CamA]);
+    let features = device.connected_camera_features()?;
+    assert_eq!(features.len(), 1);
+    assert_eq!(features[0].sensor_name, "IMX378");
+
+    assert_eq!(device.camera_on_socket(CameraBoardSocket::CamA)?.map(|f| f.socket), Some(CameraBoardSocket::CamA));
+    assert!(device.camera_on_socket(CameraBoardSocket::CamB)?.is_none());
+    assert!(device.default_stereo_pair()?.is_none());
+
+    let clone = device.try_clone()?;
+    assert!(clone.is_connected());
+    device.close()?;
+    assert!(!clone.is_connected());
+    Ok(())
+}
+
+#[test]
+fn mock_device_reset_disconnects() -> Result<()> {
+    let device = Device::mock();
+    device.reset(Duration::from_secs(1))?;
+    assert!(!device.is_connected());
+    Ok(())
+}
+
+#[test]
+fn mock_device_has_oak_d_stereo_pair() -> Result<()> {
+    let pair = Device::mock().default_stereo_pair()?.expect("an OAK-D has a stereo pair");
+    assert_eq!((pair.left, pair.right), (CameraBoardSocket::CamB, CameraBoardSocket::CamC));
+    assert!(!pair.is_vertical);
+    Ok(())
+}
+
+#[test]
+fn test_patterns_have_packed_sizes() -> Result<()> {
+    assert_eq!(pattern_frame(TestPattern::Gradient, 0, 4, 2, ImageFrameType::RGB888i)?.len(), 24);
+    assert_eq!(pattern_frame(TestPattern::Gradient, 0, 4, 2, ImageFrameType::NV12)?.len(), 12);
+    assert_eq!(
+        pattern_frame(TestPattern::Checkerboard { cell: 1 }, 0, 2, 1, ImageFrameType::GRAY8)?,
+        vec![255, 0]
+    );
+    assert_ne!(
+        pattern_frame(TestPattern::MovingBar, 0, 16, 1, ImageFrameType::GRAY8)?,
+        pattern_frame(TestPattern::MovingBar, 1, 16, 1, ImageFrameType::GRAY8)?
+    );
+    assert!(pattern_frame(TestPattern::Gradient, 0, 3, 3, ImageFrameType::NV12).is_err());
+    Ok(())
+}
+
+#[test]
+fn nv12_patterns_decode_back_to_their_colors() -> Result<()> {
+    // Limited-range BT.601, like the rest of the crate, so viewers show the color that was asked for.
+    let solid = TestPattern::Solid { r: 200, g: 100, b: 50 };
+    let nv12 = pattern_frame(solid, 0, 4, 2, ImageFrameType::NV12)?;
+    assert_eq!(nv12, convert::rgb888i_to_nv12(4, 2, &[200, 100, 50].repeat(8))?);
+    for pixel in convert::nv12_to_rgb888i(4, 2, &nv12)?.chunks_exact(3) {
+        for (got, want) in pixel.iter().zip([200u8, 100, 50]) {
+            assert!(got.abs_diff(want) <= 2, "{pixel:?}");
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn mock_pipeline_runs_without_hardware() -> Result<()> {
+    let device = Device::mock();
+    let pipeline = Pipeline::new().with_device(&device).build()?;
+    assert!(pipeline.default_device()?.is_mock());
+
+    let camera = pipeline.create_with::<MockCameraNode, _>(CameraBoardSocket::CamA)?;
+    let config = CameraOutputConfig {
+        frame_type: Some(ImageFrameType::GRAY8),
+        fps: Some(60.0),
+        ..CameraOutputConfig::new((8, 4))
+    };
+    let source = FrameSource::Generator(Box::new(|index, (w, h), _| Ok(vec![index as u8; (w * h) as usize])));
+    let queue = camera.request_output_with(config, source)?.create_queue(4, true)?;
+
+    pipeline.start()?;
+    let first = queue.blocking_next(Some(Duration::from_secs(2)))?.expect("first mock frame");
+    let second = queue.blocking_next(Some(Duration::from_secs(2)))?.expect("second mock frame");
+    pipeline.stop()?;
+
+    assert_eq!((first.width(), first.height(), first.format()), (8, 4, Some(ImageFrameType::GRAY8)));
+    assert_eq!(first.bytes(), vec![0; 32]);
+    assert_eq!(second.sequence_num()?, 1);
+    Ok(())
+}
@@ -0,0 +1,32 @@
+#![cfg(not(target_os = "windows"))]
+
+use std::path::PathBuf;
+
+use depthai::DevicePlatform;
+use depthai::model_zoo::{default_cache_dir, platform_name, ModelDescription};
+
+#[test]
+fn model_description_builder() {
+    let description = ModelDescription::new("yolov6-nano")
+        .with_platform(DevicePlatform::Rvc4)
+        .with_precision("INT8");
+    assert_eq!(description.model, "yolov6-nano");
+    assert_eq!(description.platform.map(platform_name), Some("RVC4"));
+    assert_eq!(description.precision.as_deref(), Some("INT8"));
+    assert_eq!(description.optimization_level, None);
+}
+
+#[test]
+fn cache_dir_follows_environment() {
+    // Single test touching the environment, so no other test races on these variables.
+    unsafe {
+        std::env::set_var("DEPTHAI_ZOO_CACHE_PATH", "/tmp/zoo");
+    }
+    assert_eq!(default_cache_dir(), PathBuf::from("/tmp/zoo"));
+
+    unsafe {
+        std::env::remove_var("DEPTHAI_ZOO_CACHE_PATH");
+        std::env::set_var("XDG_CACHE_HOME", "/tmp/xdg");
+    }
+    assert_eq!(default_cache_dir(), PathBuf::from("/tmp/xdg/depthai/models"));
+}
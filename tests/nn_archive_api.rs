@@ -0,0 +1,23 @@
+#![cfg(feature = "hit")]
+
+use depthai::{DetectionNetworkNode, NNArchive, NeuralNetworkNode, Pipeline, Result};
+
+#[test]
+fn nn_archive_open_missing_file_fails() {
+    assert!(NNArchive::open("does-not-exist.tar.xz").is_err());
+}
+
+#[test]
+fn network_nodes_expose_ports() -> Result<()> {
+    let pipeline = Pipeline::new().build()?;
+
+    let nn = pipeline.create::<NeuralNetworkNode>()?;
+    nn.set_num_inference_threads(2);
+    let _ = nn.as_node().output("out")?;
+
+    let det = pipeline.create::<DetectionNetworkNode>()?;
+    det.set_confidence_threshold(0.5);
+    let _ = det.as_node().output("passthrough")?;
+
+    Ok(())
+}
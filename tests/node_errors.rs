@@ -0,0 +1,153 @@
+#![cfg(not(target_os = "windows"))]
+
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+use depthai::camera::{ImageFrame, ImageFrameType};
+use depthai::{
+    NodeError, NodeErrorPolicy, NodeFailure, NodePhase, Pipeline, Result, ThreadedHostNodeContext, ThreadedHostNodeImpl,
+};
+
+#[test]
+fn node_error_display() {
+    let panic = NodeError {
+        node_id: 3,
+        phase: NodePhase::Process,
+        failure: NodeFailure::Panic("index out of bounds".to_string()),
+        consecutive: 1,
+    };
+    assert!(panic.is_panic());
+    assert_eq!(panic.to_string(), "host node 3 panicked in Process: index out of bounds");
+
+    let error = NodeError {
+        node_id: 7,
+        phase: NodePhase::Run,
+        failure: NodeFailure::Error("queue closed".to_string()),
+        consecutive: 4,
+    };
+    assert!(!error.is_panic());
+    assert_eq!(error.to_string(), "host node 7 failed in Run: queue closed (4 in a row)");
+}
+
+#[test]
+fn default_policy_only_records() {
+    let policy = NodeErrorPolicy::default();
+    assert!(!policy.stop_on_panic);
+    assert_eq!(policy.max_consecutive_errors, None);
+}
+
+/// Host-only pipeline whose node reports `errors` failures, panics if asked, then idles until
+/// the pipeline stops. `done` is signalled once all errors are reported.
+fn failing_pipeline(errors: u32, panic: bool, done: mpsc::Sender<()>) -> Result<(Pipeline, i32)> {
+    struct Failing {
+        errors: u32,
+        panic: bool,
+        done: mpsc::Sender<()>,
+    }
+    impl ThreadedHostNodeImpl for Failing {
+        fn run(&mut self, ctx: &ThreadedHostNodeContext) {
+            let err = ImageFrame::new(2, 2, ImageFrameType::GRAY8, &[]).err().expect("empty payload fails");
+            for _ in 0..self.errors {
+                ctx.report_error(&err);
+            }
+            let _ = self.done.send(());
+            if self.panic {
+                panic!("bad frame");
+            }
+            ctx.wait_until_stopped();
+        }
+    }
+    let pipeline = Pipeline::new_host_only()?;
+    let node = pipeline.create_threaded_host_node(|_| Ok(Failing { errors, panic, done }))?;
+    let id = node.as_node().id()?;
+    Ok((pipeline, id))
+}
+
+fn wait_stopped(pipeline: &Pipeline) -> Result<bool> {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while pipeline.is_running()? {
+        if Instant::now() > deadline {
+            return Ok(false);
+        }
+        std::thread::sleep(Duration::from_millis(5));
+    }
+    Ok(true)
+}
+
+#[test]
+fn errors_count_up_and_keep_the_newest_256() -> Result<()> {
+    let (tx, rx) = mpsc::channel();
+    let (pipeline, id) = failing_pipeline(300, false, tx)?;
+    pipeline.start()?;
+    rx.recv_timeout(Duration::from_secs(5)).unwrap();
+
+    let errors = pipeline.take_node_errors();
+    assert_eq!(errors.len(), 256);
+    assert!(errors.iter().all(|e| e.node_id == id && e.phase == NodePhase::Run && !e.is_panic()));
+    // The oldest 44 were dropped; the count keeps going across them.
+    let counts: Vec<u32> = errors.iter().map(|e| e.consecutive).collect();
+    assert_eq!(counts, (45..=300).collect::<Vec<_>>());
+    assert!(pipeline.take_node_errors().is_empty());
+
+    // The default policy never stops the pipeline.
+    assert!(pipeline.is_running()?);
+    pipeline.stop()?;
+    Ok(())
+}
+
+#[test]
+fn consecutive_errors_stop_the_pipeline_once() -> Result<()> {
+    let (tx, rx) = mpsc::channel();
+    let (pipeline, _) = failing_pipeline(5, false, tx)?;
+    let policy = NodeErrorPolicy {
+        max_consecutive_errors: Some(3),
+        ..Default::default()
+    };
+    pipeline.set_node_error_policy(policy);
+    assert_eq!(pipeline.node_error_policy(), policy);
+    pipeline.start()?;
+    rx.recv_timeout(Duration::from_secs(5)).unwrap();
+
+    // Stopping happens off the node thread, which would otherwise wait on itself.
+    assert!(wait_stopped(&pipeline)?, "pipeline still running");
+    let counts: Vec<u32> = pipeline.take_node_errors().iter().map(|e| e.consecutive).collect();
+    assert_eq!(counts, [1, 2, 3, 4, 5]);
+    Ok(())
+}
+
+#[test]
+fn panics_stop_the_pipeline_when_asked() -> Result<()> {
+    let (tx, rx) = mpsc::channel();
+    let (pipeline, _) = failing_pipeline(1, true, tx)?;
+    pipeline.set_node_error_policy(NodeErrorPolicy {
+        stop_on_panic: true,
+        ..Default::default()
+    });
+    pipeline.start()?;
+    rx.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert!(wait_stopped(&pipeline)?, "pipeline still running");
+
+    let errors = pipeline.take_node_errors();
+    assert_eq!(errors.len(), 2, "{errors:?}");
+    assert!(!errors[0].is_panic());
+    assert_eq!(errors[1].failure, NodeFailure::Panic("bad frame".to_string()));
+    assert_eq!(errors[1].consecutive, 2);
+    Ok(())
+}
+
+#[test]
+fn errors_alone_do_not_trigger_stop_on_panic() -> Result<()> {
+    let (tx, rx) = mpsc::channel();
+    let (pipeline, _) = failing_pipeline(10, false, tx)?;
+    pipeline.set_node_error_policy(NodeErrorPolicy {
+        stop_on_panic: true,
+        ..Default::default()
+    });
+    pipeline.start()?;
+    rx.recv_timeout(Duration::from_secs(5)).unwrap();
+    std::thread::sleep(Duration::from_millis(50));
+    assert!(pipeline.is_running()?);
+    assert_eq!(pipeline.take_node_errors().len(), 10);
+    pipeline.stop()?;
+    Ok(())
+}
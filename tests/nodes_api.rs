@@ -0,0 +1,165 @@
+#![cfg(feature = "hit")]
+
+use depthai::common::ImageFrameType;
+use depthai::neural_depth::{NeuralDepthConfig, NeuralDepthNode};
+use depthai::nodes::{
+    AprilTagNode, BenchmarkInNode, BenchmarkOutNode, EdgeDetectorNode, FeatureTrackerNode, MessageDemuxNode,
+    ObjectTrackerNode, PointCloudNode, RecordVideoNode, ReplayVideoNode, ScriptNode, SpatialDetectionNetworkNode,
+    SyncNode, SystemLoggerNode, WarpNode,
+};
+use depthai::version::{has_feature, Feature};
+use depthai::{CastNode, DepthUnit, Pipeline, Result, StereoDepthNode, UvcNode};
+
+#[test]
+fn vision_nodes_expose_their_ports() -> Result<()> {
+    let pipeline = Pipeline::new().build()?;
+    let stereo = pipeline.create::<StereoDepthNode>()?;
+
+    let tracker = pipeline.create::<FeatureTrackerNode>()?;
+    let _features = tracker.outputFeatures()?;
+    let edges = pipeline.create::<EdgeDetectorNode>()?;
+    tracker.passthroughInputImage()?.link(&edges.inputImage()?)?;
+    let warp = pipeline.create::<WarpNode>()?;
+    edges.outputImage()?.link(&warp.inputImage()?)?;
+    let april = pipeline.create::<AprilTagNode>()?;
+    warp.out()?.link(&april.inputImage()?)?;
+    let _tags = april.out()?;
+
+    let pcl = pipeline.create::<PointCloudNode>()?;
+    stereo.as_node().output("depth")?.link(&pcl.inputDepth()?)?;
+    let _cloud = pcl.outputPointCloud()?;
+
+    let spatial = pipeline.create::<SpatialDetectionNetworkNode>()?;
+    stereo.as_node().output("depth")?.link(&spatial.inputDepth()?)?;
+    let objects = pipeline.create::<ObjectTrackerNode>()?;
+    spatial.out()?.link(&objects.inputDetections()?)?;
+    spatial.passthrough()?.link(&objects.inputDetectionFrame()?)?;
+    let _tracklets = objects.out()?;
+
+    let _logger = pipeline.create::<SystemLoggerNode>()?.out()?;
+    Ok(())
+}
+
+#[test]
+fn wrapper_props_set_node_properties() -> Result<()> {
+    let pipeline = Pipeline::new().build()?;
+    let tracker = pipeline.create::<ObjectTrackerNode>()?;
+    tracker.set_tracker_threshold(0.25)?;
+    tracker.set_max_objects_to_track(12)?;
+    tracker.set_tracking_per_class(false)?;
+
+    let props = tracker.as_node().properties_json()?;
+    assert_eq!(props["trackerThreshold"], 0.25);
+    assert_eq!(props["maxObjectsToTrack"], 12);
+    assert_eq!(props["trackingPerClass"], false);
+
+    // Unknown properties and values of the wrong type are refused.
+    assert!(tracker.as_node().set_property("noSuchProperty", 1).is_err());
+    assert!(tracker.as_node().set_property("maxObjectsToTrack", "many").is_err());
+    Ok(())
+}
+
+#[test]
+fn map_ports_are_created_on_demand() -> Result<()> {
+    let pipeline = Pipeline::new().build()?;
+    let out = pipeline.create::<BenchmarkOutNode>()?;
+    let sync = pipeline.create::<SyncNode>()?;
+    out.out()?.link(&sync.input("a")?)?;
+    sync.set_sync_threshold(std::time::Duration::from_millis(20));
+    sync.set_sync_attempts(-1);
+    assert_eq!(sync.input("a")?.name()?, "a");
+
+    let demux = pipeline.create::<MessageDemuxNode>()?;
+    sync.out()?.link(&demux.input()?)?;
+    let bench = pipeline.create::<BenchmarkInNode>()?;
+    demux.output("a")?.link(&bench.input()?)?;
+
+    let script = pipeline.create::<ScriptNode>()?;
+    script.set_script("node.io['out'].send(node.io['in'].get())", Some("echo"))?;
+    bench.passthrough()?.link(&script.input("in")?)?;
+    assert_eq!(script.output("out")?.name()?, "out");
+    Ok(())
+}
+
+#[test]
+fn optional_nodes_follow_the_build() -> Result<()> {
+    let pipeline = Pipeline::new().build()?;
+    assert_eq!(pipeline.create::<RecordVideoNode>().is_ok(), has_feature(Feature::RecordReplay));
+    assert_eq!(pipeline.create::<ReplayVideoNode>().is_ok(), has_feature(Feature::RecordReplay));
+    Ok(())
+}
+
+#[test]
+fn neural_depth_config_round_trips() -> Result<()> {
+    if !has_feature(Feature::NeuralDepth) {
+        assert!(NeuralDepthConfig::new().is_err());
+        return Ok(());
+    }
+    let mut config = NeuralDepthConfig::new()?;
+    config
+        .set_confidence_threshold(125)
+        .set_edge_threshold(10)
+        .set_depth_unit(DepthUnit::Custom)
+        .set_custom_depth_unit_multiplier(0.5);
+    assert_eq!(config.confidence_threshold()?, 125);
+    assert_eq!(config.edge_threshold()?, 10);
+    assert_eq!(config.depth_unit()?, DepthUnit::Custom);
+    assert_eq!(config.custom_depth_unit_multiplier()?, 0.5);
+
+    let pipeline = Pipeline::new().build()?;
+    let neural = pipeline.create::<NeuralDepthNode>()?;
+    neural.initial_config()?.set_confidence_threshold(42);
+    assert_eq!(neural.initial_config()?.confidence_threshold()?, 42);
+    Ok(())
+}
+
+#[test]
+fn cast_node_settings_reach_its_properties() -> Result<()> {
+    let pipeline = Pipeline::new().build()?;
+    let stereo = pipeline.create::<StereoDepthNode>()?;
+    let cast = pipeline.create::<CastNode>()?;
+    stereo.as_node().output("disparity")?.link(&cast.input()?)?;
+    let _passthrough = cast.passthroughInput()?;
+    let _out = cast.output()?;
+
+    cast.set_output_frame_type(ImageFrameType::GRAY8);
+    cast.set_scale(0.5);
+    cast.set_offset(2.0);
+    cast.set_num_frames_pool(6);
+    cast.set_num_shaves(3);
+
+    let props = cast.as_node().properties_json()?;
+    assert_eq!(props["outputType"], ImageFrameType::GRAY8 as i32);
+    assert_eq!(props["scale"], 0.5);
+    assert_eq!(props["offset"], 2.0);
+    assert_eq!(props["numFramesPool"], 6);
+    assert_eq!(props["numShaves"], 3);
+    Ok(())
+}
+
+#[test]
+fn uvc_node_gpios_reach_its_properties() -> Result<()> {
+    let pipeline = Pipeline::new().build()?;
+    let uvc = pipeline.create::<UvcNode>()?;
+    assert_eq!(uvc.input()?.name()?, "in");
+    uvc.set_gpios_on_init(&[(58, 1)])?;
+    uvc.set_gpios_on_stream_on(&[(37, 1), (38, 0)])?;
+    uvc.set_gpios_on_stream_off(&[])?;
+
+    // DepthAI serializes the GPIO maps as `[gpio, value]` pairs, in no particular order.
+    let props = uvc.as_node().properties_json()?;
+    let pairs = |key: &str| {
+        let mut pairs: Vec<(i64, i64)> = props[key]
+            .as_array()
+            .unwrap_or_else(|| panic!("{key}: {props}"))
+            .iter()
+            .map(|pair| (pair[0].as_i64().unwrap(), pair[1].as_i64().unwrap()))
+            .collect();
+        pairs.sort();
+        pairs
+    };
+    assert_eq!(pairs("gpioInit"), [(58, 1)]);
+    assert_eq!(pairs("gpioStreamOn"), [(37, 1), (38, 0)]);
+    assert!(pairs("gpioStreamOff").is_empty());
+    Ok(())
+}
@@ -0,0 +1,75 @@
+#![cfg(not(target_os = "windows"))]
+
+use depthai::pipeline::PipelineConfig;
+
+const STEREO_CONFIG: &str = r#"{
+    "nodes": [
+        { "name": "left", "type": "Camera",
+          "camera": { "socket": "CAM_B", "outputs": [{ "name": "out", "size": [640, 400], "fps": 30 }] } },
+        { "name": "right", "type": "dai::node::Camera",
+          "camera": { "socket": "CamC", "outputs": [{ "name": "out", "size": [640, 400], "type": "GRAY8" }] } },
+        { "name": "stereo", "type": "StereoDepth",
+          "stereo": { "preset": "Robotics", "subpixel": true, "output_size": [640, 400] } }
+    ],
+    "links": [
+        { "from": "left.out", "to": "stereo.left" },
+        { "from": "right.out", "to": "stereo.right" }
+    ]
+}"#;
+
+#[test]
+fn pipeline_config_parses_and_validates_without_hardware() -> depthai::Result<()> {
+    let config = PipelineConfig::from_json_str(STEREO_CONFIG)?;
+    assert_eq!(config.nodes.len(), 3);
+    assert_eq!(config.links.len(), 2);
+    assert_eq!(config.nodes[2].stereo.as_ref().and_then(|s| s.subpixel), Some(true));
+
+    // Schema: unknown fields are rejected.
+    let err = PipelineConfig::from_json_str(r#"{ "nodes": [], "linkz": [] }"#).unwrap_err();
+    assert!(err.to_string().contains("linkz"), "unexpected error: {err}");
+
+    // Links must reference declared nodes.
+    let bad_link = STEREO_CONFIG.replace("\"to\": \"stereo.right\"", "\"to\": \"depth.right\"");
+    assert!(PipelineConfig::from_json_str(&bad_link).is_err());
+
+    // Enum values are checked before touching a pipeline.
+    let bad_preset = STEREO_CONFIG.replace("Robotics", "Fastest");
+    assert!(PipelineConfig::from_json_str(&bad_preset).is_err());
+
+    // Unaligned stereo widths are rejected unless a rounding policy is configured.
+    let unaligned = STEREO_CONFIG.replace("\"output_size\": [640, 400]", "\"output_size\": [1000, 400]");
+    assert!(PipelineConfig::from_json_str(&unaligned).is_err());
+    let rounded = unaligned.replace("[1000, 400]", "[1000, 400], \"output_size_policy\": \"Nearest\"");
+    PipelineConfig::from_json_str(&rounded)?;
+    assert!(PipelineConfig::from_json_str(&rounded.replace("Nearest", "Closest")).is_err());
+
+    // Camera settings only make sense on Camera nodes.
+    let misplaced = r#"{ "nodes": [{ "name": "s", "type": "StereoDepth", "camera": { "socket": "CAM_A" } }] }"#;
+    assert!(PipelineConfig::from_json_str(misplaced).is_err());
+
+    // Per-link queue settings are optional.
+    let queued = STEREO_CONFIG.replace(
+        "\"to\": \"stereo.left\" }",
+        "\"to\": \"stereo.left\", \"blocking\": false, \"queue_size\": 2 }",
+    );
+    let config = PipelineConfig::from_json_str(&queued)?;
+    assert_eq!(config.links[0].blocking, Some(false));
+    assert_eq!(config.links[0].queue_size, Some(2));
+    assert_eq!(config.links[1].queue_size, None);
+    assert!(PipelineConfig::from_json_str(&queued.replace("\"queue_size\": 2", "\"queue_size\": 0")).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn pipeline_from_config_file_host_only() -> depthai::Result<()> {
+    let path = std::env::temp_dir().join(format!("depthai_rs_config_{}.json", std::process::id()));
+    std::fs::write(&path, r#"{ "host_only": true }"#).expect("write config");
+
+    let configured = depthai::pipeline::from_config(&path);
+    let _ = std::fs::remove_file(&path);
+    let configured = configured?;
+    assert!(configured.pipeline.all_nodes()?.is_empty());
+    assert!(configured.nodes.node("missing").is_none());
+    Ok(())
+}
@@ -29,6 +29,25 @@ mod pipeline_create_tests {
             .expect("Failed to request camera output");
     }
 
+    #[test]
+    #[ignore] // Requires hardware
+    fn test_node_alias_and_log_level() {
+        use depthai::LogLevel;
+
+        let pipeline = Pipeline::new().build().expect("Failed to create pipeline");
+        let camera = pipeline
+            .create_with::<CameraNode, _>(CameraBoardSocket::CamA)
+            .expect("Failed to create camera node");
+        let node = camera.as_node();
+
+        node.set_alias("color").expect("Failed to set alias");
+        assert_eq!(node.alias().expect("Failed to get alias"), "color");
+        assert!(node.id().expect("Failed to get node id") >= 0);
+
+        node.set_log_level(LogLevel::Debug).expect("Failed to set log level");
+        assert_eq!(node.log_level().expect("Failed to get log level"), LogLevel::Debug);
+    }
+
     #[test]
     fn test_trait_bounds_compile() {
         // This test ensures the traits are properly defined and can be used
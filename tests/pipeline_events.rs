@@ -0,0 +1,50 @@
+#![cfg(feature = "hit")]
+
+use std::sync::mpsc;
+use std::time::Duration;
+
+use depthai::{Pipeline, PipelineEvent, Result};
+
+#[test]
+fn subscribers_see_start_and_stop() -> Result<()> {
+    let pipeline = Pipeline::new().build()?;
+    let (tx, rx) = mpsc::channel();
+    let subscription = pipeline.subscribe_events(move |event| {
+        if matches!(event, PipelineEvent::Started | PipelineEvent::Stopped) {
+            let _ = tx.send(event.clone());
+        }
+    })?;
+
+    let cam = pipeline.create_camera(depthai::common::CameraBoardSocket::CamA)?;
+    let _queue = cam.request_full_resolution_output()?.create_queue(4, false)?;
+    pipeline.start()?;
+    assert_eq!(rx.recv_timeout(Duration::from_secs(5)).ok(), Some(PipelineEvent::Started));
+    pipeline.stop()?;
+    assert_eq!(rx.recv_timeout(Duration::from_secs(5)).ok(), Some(PipelineEvent::Stopped));
+
+    drop(subscription);
+    Ok(())
+}
+
+#[test]
+fn pause_and_resume_keep_the_pipeline_running() -> Result<()> {
+    use depthai::camera::CameraNode;
+    use depthai::common::CameraBoardSocket;
+
+    let pipeline = Pipeline::new().build()?;
+    let _camera = pipeline.create_with::<CameraNode, _>(CameraBoardSocket::CamA)?;
+    pipeline.enable_pause()?;
+    let (tx, rx) = mpsc::channel();
+    let _subscription = pipeline.subscribe_events(move |event| {
+        if matches!(event, PipelineEvent::Paused | PipelineEvent::Resumed) {
+            let _ = tx.send(event);
+        }
+    })?;
+    pipeline.start()?;
+    pipeline.pause()?;
+    assert_eq!(rx.recv_timeout(Duration::from_secs(5)).ok(), Some(PipelineEvent::Paused));
+    assert!(pipeline.is_running()?);
+    pipeline.resume()?;
+    assert_eq!(rx.recv_timeout(Duration::from_secs(5)).ok(), Some(PipelineEvent::Resumed));
+    pipeline.stop()
+}
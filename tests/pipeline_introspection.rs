@@ -59,5 +59,478 @@ fn pipeline_schema_and_json_serialize_without_hardware() -> depthai::Result<()>
         "expected to find our connection in pipeline.connection_map()"
     );
 
+    // Host-only graph with optional inputs: nothing to report.
+    let issues = pipeline.validate()?;
+    assert!(issues.is_empty(), "unexpected validation issues: {issues:?}");
+
+    // DOT export should contain our host nodes and the link between them.
+    let dot = pipeline.to_dot()?;
+    assert!(dot.starts_with("digraph pipeline {"), "DOT output should be a digraph");
+    assert!(dot.contains("g[out] -> g[in]"), "DOT output should label our connection");
+    assert!(nodes.iter().all(|n| dot.contains(&format!("n{} [", n.id))), "DOT output should list every node");
+
+    Ok(())
+}
+
+#[test]
+fn port_introspection_without_hardware() -> depthai::Result<()> {
+    let pipeline = Pipeline::new_host_only()?;
+
+    struct Noop;
+    impl ThreadedHostNodeImpl for Noop {
+        fn run(&mut self, _ctx: &ThreadedHostNodeContext) {}
+    }
+
+    let a = pipeline.create_threaded_host_node(|_| Ok(Noop))?;
+    let b = pipeline.create_threaded_host_node(|_| Ok(Noop))?;
+
+    let out = a.create_output_with(Some("out"), Some("g"))?;
+    let input = b.create_input_with(Some("in"), Some("g"), Some(3))?;
+
+    assert_eq!(out.name()?, "out");
+    assert_eq!(out.group()?, "g");
+    assert_eq!(input.name()?, "in");
+    assert_eq!(input.group()?, "g");
+    assert!(out.can_connect(&input)?);
+    let _ = out.possible_datatypes()?;
+    let _ = input.possible_datatypes()?;
+
+    assert_eq!(input.max_size()?, 3);
+    input.set_max_size(5)?;
+    assert_eq!(input.max_size()?, 5);
+
+    input.set_blocking(false)?;
+    assert!(!input.blocking()?);
+
+    input.set_reuse_previous_message(true)?;
+    assert!(input.reuse_previous_message()?);
+
+    Ok(())
+}
+
+#[test]
+fn node_io_info_lists_declared_ports() -> depthai::Result<()> {
+    let pipeline = Pipeline::new_host_only()?;
+
+    struct Noop;
+    impl ThreadedHostNodeImpl for Noop {
+        fn run(&mut self, _ctx: &ThreadedHostNodeContext) {}
+    }
+
+    let node = pipeline.create_threaded_host_node(|_| Ok(Noop))?;
+    node.create_output_with(Some("frames"), Some("video"))?;
+    node.create_input_with(Some("control"), None, Some(2))?;
+
+    let info = node.as_node().io_info()?;
+    let frames = info.output("frames").expect("declared output is listed");
+    assert_eq!(frames.group, "video");
+    assert_eq!(frames.map, None);
+    assert!(info.input("control").is_some());
+    assert!(info.input("frames").is_none());
+    assert!(info.input_maps.is_empty() && info.output_maps.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn unlink_and_link_introspection_without_hardware() -> depthai::Result<()> {
+    let pipeline = Pipeline::new_host_only()?;
+
+    struct Noop;
+    impl ThreadedHostNodeImpl for Noop {
+        fn run(&mut self, _ctx: &ThreadedHostNodeContext) {}
+    }
+
+    let a = pipeline.create_threaded_host_node(|_| Ok(Noop))?;
+    let b = pipeline.create_threaded_host_node(|_| Ok(Noop))?;
+
+    let out = a.create_output_with(Some("out"), Some("g"))?;
+    let first = b.create_input_with(Some("first"), Some("g"), Some(3))?;
+    let second = b.create_input_with(Some("second"), None, Some(2))?;
+    assert!(out.links()?.is_empty());
+
+    out.link(&first)?;
+    out.link(&second)?;
+    let links = out.links()?;
+    assert_eq!(links.len(), 2);
+    let link = links.iter().find(|l| l.input_name == "first").expect("link to `first`");
+    assert_eq!(link.output_id, a.as_node().id()?);
+    assert_eq!((link.output_group.as_str(), link.output_name.as_str()), ("g", "out"));
+    assert_eq!(link.input_id, b.as_node().id()?);
+    assert_eq!(link.input_group, "g");
+    assert_eq!(link.queue_size, Some(3));
+    assert!(out.is_linked_to(&first)? && out.is_linked_to(&second)?);
+
+    out.unlink(&first)?;
+    assert!(!out.is_linked_to(&first)?);
+    assert!(out.is_linked_to(&second)?);
+    assert!(out.unlink(&first).is_err(), "unlinking twice should fail");
+
+    out.unlink_from(b.as_node(), Some("second"))?;
+    assert!(out.links()?.is_empty());
+    assert!(!pipeline.connections()?.iter().any(|c| c.output_name == "out"));
+
+    // Relinking after an unlink works like a first link.
+    out.link(&first)?;
+    assert!(out.is_linked_to(&first)?);
+
+    Ok(())
+}
+
+#[test]
+fn host_node_outputs_and_messages_without_hardware() -> depthai::Result<()> {
+    use std::time::Duration;
+
+    use depthai::camera::{ImageFrame, ImageFrameType};
+    use depthai::host_node::{Buffer, MessageGroup};
+    use depthai::{HostNodeImpl, TypedOutput};
+
+    let pipeline = Pipeline::new_host_only()?;
+
+    // Host node with extra named outputs alongside the implicit `out`.
+    struct Preprocess {
+        _image: TypedOutput<ImageFrame>,
+        _meta: TypedOutput<Buffer>,
+    }
+    impl HostNodeImpl for Preprocess {
+        fn process_group(&mut self, _group: &MessageGroup) -> Option<Buffer> {
+            None
+        }
+    }
+    let host = pipeline.create_host_node_with(|node| {
+        Ok(Preprocess {
+            _image: node.create_typed_output("image")?,
+            _meta: node.create_typed_output("detections")?,
+        })
+    })?;
+    assert_eq!(host.as_node().output("detections")?.name()?, "detections");
+
+    struct Noop;
+    impl ThreadedHostNodeImpl for Noop {
+        fn run(&mut self, _ctx: &ThreadedHostNodeContext) {}
+    }
+    let threaded = pipeline.create_threaded_host_node(|_| Ok(Noop))?;
+    let frames: TypedOutput<ImageFrame> = threaded.create_typed_output("frames")?;
+    assert_eq!(frames.name()?, "frames");
+
+    // Host-created messages carry data, timestamp and sequence number.
+    let buffer = Buffer::from_bytes(&[1, 2, 3])?;
+    buffer.set_timestamp(Duration::from_millis(1500))?;
+    buffer.set_sequence_num(42)?;
+    assert_eq!(buffer.timestamp()?, Duration::from_millis(1500));
+    assert_eq!(buffer.sequence_num()?, 42);
+    assert_eq!(buffer.data(), &[1, 2, 3]);
+
+    // Structured payloads round-trip through JSON.
+    #[derive(serde::Serialize, serde::Deserialize, PartialEq, Debug)]
+    struct Detection {
+        label: String,
+        score: f32,
+    }
+    let mut meta = Buffer::new(0)?;
+    meta.set_data(vec![9u8; 4])?;
+    assert_eq!(meta.data().len(), 4);
+    let detections = vec![Detection { label: "person".into(), score: 0.5 }];
+    meta.set_json(&detections)?;
+    assert_eq!(meta.get_json::<Vec<Detection>>()?, detections);
+    assert!(meta.get_json::<u32>().is_err());
+
+    let frame = ImageFrame::new(4, 2, ImageFrameType::GRAY8, &[0u8; 8])?;
+    frame.set_sequence_num(7)?;
+    assert_eq!((frame.width(), frame.height()), (4, 2));
+    assert_eq!(frame.format(), Some(ImageFrameType::GRAY8));
+    assert_eq!(frame.bytes().len(), 8);
+    assert_eq!(frame.sequence_num()?, 7);
+
+    Ok(())
+}
+
+#[test]
+fn host_created_frames_without_hardware() -> depthai::Result<()> {
+    use std::time::Duration;
+
+    use depthai::camera::{ImageFrame, ImageFrameType};
+
+    // Packed layouts are size-checked.
+    assert!(ImageFrame::new(4, 2, ImageFrameType::BGR888i, &[0u8; 8]).is_err());
+    let bgr = ImageFrame::new(4, 2, ImageFrameType::BGR888i, &[7u8; 24])?;
+    assert_eq!(bgr.bytes(), vec![7u8; 24]);
+
+    // NV12 from separate Y / interleaved UV planes.
+    let y = [16u8; 4 * 2];
+    let uv = [128u8; 4];
+    let nv12 = ImageFrame::from_planes(4, 2, ImageFrameType::NV12, &[&y, &uv])?;
+    assert_eq!(nv12.format(), Some(ImageFrameType::NV12));
+    assert_eq!(&nv12.bytes()[..8], &y);
+    assert_eq!(&nv12.bytes()[8..], &uv);
+
+    // YUV420p needs three planes with even dimensions.
+    assert!(ImageFrame::from_planes(4, 2, ImageFrameType::YUV420p, &[&y, &uv]).is_err());
+    assert!(ImageFrame::from_planes(3, 2, ImageFrameType::NV12, &[&[0u8; 6], &[0u8; 3]]).is_err());
+    let yuv = ImageFrame::from_planes(4, 2, ImageFrameType::YUV420p, &[&y, &[1, 2], &[3, 4]])?;
+    assert_eq!(yuv.bytes().len(), 12);
+
+    yuv.set_timestamp(Duration::from_micros(250))?;
+    yuv.set_sequence_num(3)?;
+    assert_eq!(yuv.timestamp()?, Duration::from_micros(250));
+    assert_eq!(yuv.sequence_num()?, 3);
+
+    Ok(())
+}
+
+#[cfg(feature = "opencv")]
+#[test]
+fn image_frame_cv_mat_interop_without_hardware() -> depthai::Result<()> {
+    use depthai::camera::{ImageFrame, ImageFrameType};
+    use opencv::prelude::*;
+
+    let data: Vec<u8> = (0..24).collect();
+    let bgr = ImageFrame::new(4, 2, ImageFrameType::BGR888i, &data)?;
+    let view = bgr.as_cv_mat()?;
+    assert_eq!((view.rows(), view.cols(), view.typ()), (2, 4, opencv::core::CV_8UC3));
+    assert_eq!(view.data_bytes().expect("continuous view"), &data[..]);
+
+    let copy = ImageFrame::from_cv_mat(&view, ImageFrameType::BGR888i)?;
+    assert_eq!(copy.bytes(), data);
+    // The Mat layout must match the requested frame type.
+    assert!(ImageFrame::from_cv_mat(&view, ImageFrameType::GRAY8).is_err());
+
+    // NV12 is exposed in OpenCV's single-channel `height * 3 / 2` layout.
+    let nv12 = ImageFrame::from_planes(4, 2, ImageFrameType::NV12, &[&[16u8; 8], &[128u8; 4]])?;
+    let view = nv12.as_cv_mat()?;
+    assert_eq!((view.rows(), view.cols(), view.typ()), (3, 4, opencv::core::CV_8UC1));
+
+    Ok(())
+}
+
+#[test]
+fn message_queue_stats_without_hardware() -> depthai::Result<()> {
+    let pipeline = Pipeline::new_host_only()?;
+
+    struct Noop;
+    impl ThreadedHostNodeImpl for Noop {
+        fn run(&mut self, _ctx: &ThreadedHostNodeContext) {}
+    }
+    let node = pipeline.create_threaded_host_node(|_| Ok(Noop))?;
+    let out = node.create_output(Some("out"))?;
+
+    let queue = out.create_message_queue(4, false)?;
+    queue.on_drop(|stats| eprintln!("dropped {} messages", stats.dropped));
+    let stats = queue.stats()?;
+    assert_eq!(stats, depthai::QueueStats { max_size: 4, ..Default::default() });
+
+    Ok(())
+}
+
+#[test]
+fn message_queue_counts_drops_without_hardware() -> depthai::Result<()> {
+    use std::sync::{mpsc, Arc, Mutex};
+    use std::time::Duration;
+
+    use depthai::host_node::Buffer;
+    use depthai::{Output, QueueStats, DEFAULT_QUEUE_SIZE};
+
+    /// Sends 10 buffers at once, then idles.
+    struct Burst {
+        out: Output,
+        done: mpsc::Sender<()>,
+    }
+    impl ThreadedHostNodeImpl for Burst {
+        fn run(&mut self, ctx: &ThreadedHostNodeContext) {
+            for _ in 0..10 {
+                if let Err(err) = Buffer::new(1).and_then(|buffer| self.out.send_buffer(&buffer)) {
+                    ctx.report_error(&err);
+                }
+            }
+            let _ = self.done.send(());
+            ctx.wait_until_stopped();
+        }
+    }
+
+    let pipeline = Pipeline::new_host_only()?;
+    let (done, sent) = mpsc::channel();
+    let node = pipeline.create_threaded_host_node(|node| {
+        Ok(Burst {
+            out: node.create_output(Some("out"))?,
+            done,
+        })
+    })?;
+    let queue = node.as_node().output("out")?.create_default_message_queue()?;
+    let reported = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&reported);
+    queue.on_drop(move |stats| sink.lock().unwrap().push(stats.dropped));
+
+    pipeline.start()?;
+    sent.recv_timeout(Duration::from_secs(5)).unwrap();
+    std::thread::sleep(Duration::from_millis(50));
+
+    // Non-blocking by default: the 6 oldest were dropped and reported as they happened.
+    let full = QueueStats {
+        produced: 10,
+        consumed: 0,
+        dropped: 6,
+        size: DEFAULT_QUEUE_SIZE,
+        max_size: DEFAULT_QUEUE_SIZE,
+    };
+    assert_eq!(queue.stats()?, full);
+    assert_eq!(reported.lock().unwrap().last(), Some(&6));
+
+    assert!(queue.try_get()?.is_some());
+    assert_eq!(queue.stats()?, QueueStats { consumed: 1, size: 3, ..full });
+    pipeline.stop()?;
+    Ok(())
+}
+
+#[test]
+fn datatype_buffer_fields_without_hardware() -> depthai::Result<()> {
+    use std::time::Duration;
+
+    use depthai::host_node::Buffer;
+
+    let pipeline = Pipeline::new_host_only()?;
+
+    struct Noop;
+    impl ThreadedHostNodeImpl for Noop {
+        fn run(&mut self, _ctx: &ThreadedHostNodeContext) {}
+    }
+    let node = pipeline.create_threaded_host_node(|_| Ok(Noop))?;
+    let out = node.create_output(Some("out"))?;
+    let queue = out.create_message_queue(4, false)?;
+
+    let buffer = Buffer::from_bytes(&[1, 2, 3])?;
+    buffer.set_timestamp(Duration::from_millis(20))?;
+    buffer.set_sequence_num(5)?;
+    out.send_buffer(&buffer)?;
+
+    // Generic messages expose the Buffer fields without downcasting.
+    let msg = queue.try_get()?.expect("message should be queued");
+    assert_eq!(msg.timestamp()?, Duration::from_millis(20));
+    assert_eq!(msg.sequence_num()?, 5);
+    assert_eq!(msg.timestamp_device()?, Duration::ZERO);
+
+    Ok(())
+}
+
+#[test]
+fn queues_stamp_host_receive_time() -> depthai::Result<()> {
+    use depthai::diagnostics::host_clock_now;
+    use depthai::host_node::Buffer;
+
+    let pipeline = Pipeline::new_host_only()?;
+
+    struct Noop;
+    impl ThreadedHostNodeImpl for Noop {
+        fn run(&mut self, _ctx: &ThreadedHostNodeContext) {}
+    }
+    let node = pipeline.create_threaded_host_node(|_| Ok(Noop))?;
+    let out = node.create_output(Some("out"))?;
+    let queue = out.create_message_queue(4, false)?;
+
+    let before = host_clock_now();
+    out.send_buffer(&Buffer::from_bytes(&[1])?)?;
+    let peeked = queue.front()?.expect("message should be queued");
+    let msg = queue.try_get()?.expect("message should be queued");
+    let received = msg.host_receive_time().expect("queued messages have a receive time");
+    assert!(received >= before && received <= host_clock_now());
+    assert_eq!(peeked.host_receive_time(), Some(received));
+    assert_eq!(msg.clone_handle()?.host_receive_time(), Some(received));
+
+    Ok(())
+}
+
+#[test]
+fn receive_times_follow_messages_past_drops() -> depthai::Result<()> {
+    use std::time::Duration;
+
+    use depthai::diagnostics::host_clock_now;
+    use depthai::host_node::Buffer;
+
+    let pipeline = Pipeline::new_host_only()?;
+
+    struct Noop;
+    impl ThreadedHostNodeImpl for Noop {
+        fn run(&mut self, _ctx: &ThreadedHostNodeContext) {}
+    }
+    let node = pipeline.create_threaded_host_node(|_| Ok(Noop))?;
+    let out = node.create_output(Some("out"))?;
+    let queue = out.create_message_queue(2, false)?;
+
+    // 0, 1 and 2 are dropped by the full queue, 3 and 4 are kept.
+    let mut sent_at = Vec::new();
+    for seq in 0..5 {
+        let buffer = Buffer::new(1)?;
+        buffer.set_sequence_num(seq)?;
+        sent_at.push(host_clock_now());
+        out.send_buffer(&buffer)?;
+        std::thread::sleep(Duration::from_millis(5));
+    }
+    sent_at.push(host_clock_now());
+
+    for seq in [3, 4] {
+        let msg = queue.try_get()?.expect("message should be queued");
+        assert_eq!(msg.sequence_num()?, seq);
+        let received = msg.host_receive_time().expect("queued messages have a receive time");
+        let (from, to) = (sent_at[seq as usize], sent_at[seq as usize + 1]);
+        assert!(received >= from && received <= to, "message {seq}");
+    }
+    Ok(())
+}
+
+#[test]
+fn typed_downcast_without_hardware() -> depthai::Result<()> {
+    use std::time::Duration;
+
+    use depthai::camera::{ImageFrame, ImageFrameType};
+    use depthai::host_node::Buffer;
+    use depthai::{Datatype, MismatchPolicy};
+
+    let pipeline = Pipeline::new_host_only()?;
+
+    struct Noop;
+    impl ThreadedHostNodeImpl for Noop {
+        fn run(&mut self, _ctx: &ThreadedHostNodeContext) {}
+    }
+    let node = pipeline.create_threaded_host_node(|_| Ok(Noop))?;
+    let out = node.create_output(Some("out"))?;
+    let queue = out.create_message_queue(8, false)?;
+
+    let buffer = Buffer::from_bytes(&[1, 2, 3])?;
+    let frame = ImageFrame::new(2, 2, ImageFrameType::GRAY8, &[0; 4])?;
+    frame.set_sequence_num(7)?;
+
+    // The buffer in front is skipped.
+    out.send_buffer(&buffer)?;
+    out.send_frame(&frame)?;
+    let got = queue.get_typed::<ImageFrame>(Some(Duration::from_millis(100)))?.expect("frame should be queued");
+    assert_eq!(got.sequence_num()?, 7);
+    assert!(queue.get_typed::<ImageFrame>(Some(Duration::from_millis(10)))?.is_none());
+
+    // With `Error`, the mismatch is reported.
+    out.send_buffer(&buffer)?;
+    assert!(queue.get_typed_with::<ImageFrame>(Some(Duration::from_millis(100)), MismatchPolicy::Error).is_err());
+
+    // `TryFrom` downcasts a generic message and rejects the wrong type.
+    out.send_frame(&frame)?;
+    let msg: Datatype = queue.try_get()?.expect("frame should be queued");
+    let frame = ImageFrame::try_from(msg)?;
+    assert_eq!((frame.width(), frame.height()), (2, 2));
+    out.send_buffer(&buffer)?;
+    let msg = queue.try_get()?.expect("buffer should be queued");
+    assert!(depthai::rgbd::RgbdData::try_from(msg).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn pause_requires_enabling_first() -> depthai::Result<()> {
+    let pipeline = Pipeline::new_host_only()?;
+    assert!(pipeline.pause().is_err());
+
+    // Without cameras there is nothing to stop, but the state is still tracked.
+    pipeline.enable_pause()?;
+    pipeline.pause()?;
+    assert!(pipeline.is_paused());
+    pipeline.resume()?;
+    assert!(!pipeline.is_paused());
     Ok(())
 }
@@ -0,0 +1,83 @@
+#![cfg(not(target_os = "windows"))]
+
+use depthai::common::CameraBoardSocket;
+use depthai::pipeline::{BoardConfig, FrameSyncConfig, GlobalProperties, GpioConfig, GpioDirection, GpioLevel, GpioMode, GpioPull};
+use depthai::{FrameSyncMode, UsbSpeed};
+use serde_json::json;
+
+#[test]
+fn global_properties_round_trip_keeps_unknown_keys() {
+    let raw = json!({
+        "leonCssFrequencyHz": 700000000.0,
+        "leonMssFrequencyHz": 700000000.0,
+        "pipelineName": null,
+        "xlinkChunkSize": 65536,
+        "sippBufferSize": 18432,
+        "sippDmaBufferSize": 16384,
+        "calibData": null,
+        "eepromId": 0
+    });
+    let props: GlobalProperties = serde_json::from_value(raw.clone()).unwrap();
+    assert_eq!(props.xlink_chunk_size, Some(65536));
+    assert_eq!(props.pipeline_name, None);
+    assert_eq!(props.extra.get("eepromId"), Some(&json!(0)));
+
+    let back = serde_json::to_value(&props).unwrap();
+    assert_eq!(back["calibData"], json!(null));
+    assert_eq!(back["sippDmaBufferSize"], json!(16384));
+    assert!(back.get("pipelineName").is_none(), "unset fields are left to the pipeline");
+}
+
+#[test]
+fn board_config_serializes_like_depthai() {
+    let config = BoardConfig::new()
+        .with_max_usb_speed(UsbSpeed::High)
+        .with_watchdog_timeout_ms(4000)
+        .with_gpio(6, GpioConfig::output(GpioLevel::High))
+        .with_gpio(6, GpioConfig::output(GpioLevel::Low));
+    let value = serde_json::to_value(&config).unwrap();
+    assert_eq!(value["usb"]["maxSpeed"], json!(3));
+    assert_eq!(value["watchdogTimeoutMs"], json!(4000));
+    assert_eq!(value["gpio"].as_array().map(Vec::len), Some(1));
+    assert_eq!(value["gpio"][0][0], json!(6));
+    assert_eq!(value["gpio"][0][1]["direction"], json!(1));
+    assert_eq!(value["gpio"][0][1]["level"], json!(0));
+    assert_eq!(value["gpio"][0][1]["slewFast"], json!(false));
+
+    let parsed: BoardConfig = serde_json::from_value(value).unwrap();
+    assert_eq!(parsed, config);
+    assert_eq!(parsed.gpio.unwrap()[0].1.direction, GpioDirection::Output);
+}
+
+#[test]
+fn invalid_enum_values_are_rejected() {
+    let err = serde_json::from_value::<BoardConfig>(json!({ "usb": { "maxSpeed": 9 } }));
+    assert!(err.is_err());
+}
+
+#[test]
+fn frame_sync_pins_become_gpio_entries() {
+    let sync = FrameSyncConfig::new()
+        .with_pair(CameraBoardSocket::CamB, CameraBoardSocket::CamC)
+        .with_socket(CameraBoardSocket::CamC, FrameSyncMode::Input)
+        .with_pin(41, FrameSyncMode::Output, GpioMode::AltMode1)
+        .with_pin(42, FrameSyncMode::Input, GpioMode::AltMode2)
+        .with_pin(43, FrameSyncMode::Off, GpioMode::AltMode1);
+    assert_eq!(sync.mode(CameraBoardSocket::CamB), FrameSyncMode::Output);
+    assert_eq!(sync.mode(CameraBoardSocket::CamC), FrameSyncMode::Input);
+    assert_eq!(sync.mode(CameraBoardSocket::CamA), FrameSyncMode::Off);
+    assert_eq!(sync.sockets.len(), 2);
+
+    let config = BoardConfig::new().with_gpio(41, GpioConfig::input(GpioPull::PullUp)).with_frame_sync(&sync);
+    let gpio = config.gpio.clone().unwrap();
+    assert_eq!(gpio.len(), 2, "{gpio:?}");
+    let pin = |n: i8| gpio.iter().find(|(p, _)| *p == n).map(|(_, g)| *g).unwrap();
+    assert_eq!(pin(41).direction, GpioDirection::Output);
+    assert_eq!(pin(41).mode, GpioMode::AltMode1);
+    assert_eq!(pin(42).mode, GpioMode::AltMode2);
+    assert_eq!(pin(42).direction, GpioDirection::Input);
+    assert_eq!(pin(42).pull, GpioPull::PullDown);
+
+    let value = serde_json::to_value(&config).unwrap();
+    assert_eq!(serde_json::from_value::<BoardConfig>(value).unwrap(), config);
+}
@@ -0,0 +1,136 @@
+#![cfg(not(target_os = "windows"))]
+
+use depthai::camera::ImageFrameType;
+use depthai::pipeline::{
+    validate_graph, OutputHint, PipelineConnectionInfo, PipelineNodeInfo, ValidationIssueKind, ValidationSeverity,
+};
+use depthai::DevicePlatform;
+
+fn node(id: i32, name: &str) -> PipelineNodeInfo {
+    PipelineNodeInfo {
+        id,
+        alias: String::new(),
+        name: name.to_string(),
+        run_on_host: false,
+    }
+}
+
+fn link(from: i32, output: &str, to: i32, input: &str) -> PipelineConnectionInfo {
+    PipelineConnectionInfo {
+        output_id: from,
+        output_group: String::new(),
+        output_name: output.to_string(),
+        input_id: to,
+        input_group: String::new(),
+        input_name: input.to_string(),
+    }
+}
+
+fn hint(node_id: i32, name: &str, size: (u32, u32), frame_type: Option<ImageFrameType>) -> OutputHint {
+    OutputHint {
+        node_id,
+        group: String::new(),
+        name: name.to_string(),
+        size,
+        frame_type,
+    }
+}
+
+/// Two cameras (#0, #1) feeding StereoDepth #2.
+fn stereo_graph() -> (Vec<PipelineNodeInfo>, Vec<PipelineConnectionInfo>) {
+    (
+        vec![node(0, "Camera"), node(1, "Camera"), node(2, "StereoDepth")],
+        vec![link(0, "out", 2, "left"), link(1, "out", 2, "right")],
+    )
+}
+
+#[test]
+fn aligned_matching_stereo_inputs_pass() {
+    let (nodes, links) = stereo_graph();
+    let hints = [hint(0, "out", (640, 400), None), hint(1, "out", (640, 400), None)];
+    assert!(validate_graph(&nodes, &links, &hints, Some(DevicePlatform::Rvc2)).is_empty());
+    // Outputs without hints are not checked.
+    assert!(validate_graph(&nodes, &links, &[], None).is_empty());
+}
+
+#[test]
+fn stereo_width_must_be_a_multiple_of_16() {
+    let (nodes, links) = stereo_graph();
+    let hints = [hint(0, "out", (650, 400), None), hint(1, "out", (650, 400), None)];
+    let issues = validate_graph(&nodes, &links, &hints, None);
+    assert_eq!(issues.len(), 2, "{issues:?}");
+    for (issue, port) in issues.iter().zip(["left", "right"]) {
+        assert_eq!(issue.kind, ValidationIssueKind::StereoWidthAlignment);
+        assert_eq!(issue.severity, ValidationSeverity::Error);
+        assert_eq!(issue.node_id, 2);
+        assert_eq!(issue.port.as_deref(), Some(port));
+        assert!(issue.message.contains("try 656"), "{}", issue.message);
+    }
+}
+
+#[test]
+fn stereo_left_and_right_must_match() {
+    let (nodes, links) = stereo_graph();
+    let hints = [hint(0, "out", (640, 400), None), hint(1, "out", (640, 480), None)];
+    let issues = validate_graph(&nodes, &links, &hints, None);
+    assert_eq!(issues.len(), 1, "{issues:?}");
+    assert_eq!(issues[0].kind, ValidationIssueKind::StereoSizeMismatch);
+    assert_eq!(issues[0].port, None);
+    assert!(
+        issues[0].message.contains("640x400 vs 640x480"),
+        "{}",
+        issues[0].message
+    );
+
+    // A single known size has nothing to compare against.
+    let issues = validate_graph(&nodes, &links, &hints[..1], None);
+    assert!(issues.is_empty(), "{issues:?}");
+}
+
+#[test]
+fn nv12_into_the_encoder_needs_even_dimensions() {
+    let nodes = [node(0, "Camera"), node(1, "VideoEncoder")];
+    let links = [link(0, "out", 1, "in")];
+
+    let odd = [hint(0, "out", (641, 480), Some(ImageFrameType::NV12))];
+    let issues = validate_graph(&nodes, &links, &odd, None);
+    assert_eq!(issues.len(), 1, "{issues:?}");
+    assert_eq!(issues[0].kind, ValidationIssueKind::Nv12OddDimensions);
+    assert_eq!(issues[0].port.as_deref(), Some("in"));
+    assert!(
+        issues[0].to_string().starts_with("[error] VideoEncoder #1 (in)"),
+        "{}",
+        issues[0]
+    );
+
+    let even = [hint(0, "out", (640, 480), Some(ImageFrameType::NV12))];
+    assert!(validate_graph(&nodes, &links, &even, None).is_empty());
+    // Only NV12 is checked.
+    let other = [hint(0, "out", (641, 480), Some(ImageFrameType::GRAY8))];
+    assert!(validate_graph(&nodes, &links, &other, None).is_empty());
+}
+
+#[test]
+fn rvc4_rejects_unsupported_nodes_and_links() {
+    let nodes = [
+        node(0, "Camera"),
+        node(1, "StereoDepth"),
+        node(2, "UVC"),
+        node(3, "SPIOut"),
+    ];
+    let links = [link(0, "out", 1, "inputAlignTo")];
+
+    let issues = validate_graph(&nodes, &links, &[], Some(DevicePlatform::Rvc4));
+    assert_eq!(issues.len(), 3, "{issues:?}");
+    assert!(issues
+        .iter()
+        .all(|i| i.kind == ValidationIssueKind::UnsupportedOnPlatform));
+    assert_eq!(issues[0].node_id, 1);
+    assert_eq!(issues[0].port.as_deref(), Some("inputAlignTo"));
+    let unsupported: Vec<_> = issues[1..].iter().map(|i| i.node_name.as_str()).collect();
+    assert_eq!(unsupported, ["UVC", "SPIOut"]);
+
+    // Other platforms, or no known platform, accept them.
+    assert!(validate_graph(&nodes, &links, &[], Some(DevicePlatform::Rvc2)).is_empty());
+    assert!(validate_graph(&nodes, &links, &[], None).is_empty());
+}
@@ -0,0 +1,59 @@
+#![cfg(not(target_os = "windows"))]
+
+use depthai::pointcloud::Point3fRGBA;
+use depthai::pointcloud_filter::{remove_statistical_outliers, voxel_downsample};
+
+fn point(x: f32, y: f32, z: f32, gray: u8) -> Point3fRGBA {
+    Point3fRGBA { x, y, z, r: gray, g: gray, b: gray, a: 255 }
+}
+
+/// A 10x10 grid with 1 cm spacing on the plane z = 1 m, offset to stay off voxel borders.
+fn plane() -> Vec<Point3fRGBA> {
+    (0..100)
+        .map(|i| point(0.005 + (i % 10) as f32 * 0.01, 0.005 + (i / 10) as f32 * 0.01, 1.0, 100))
+        .collect()
+}
+
+#[test]
+fn voxel_downsample_averages_points_per_voxel() -> depthai::Result<()> {
+    let points = [
+        point(0.01, 0.01, 1.01, 0),
+        point(0.03, 0.03, 1.03, 200),
+        point(0.11, 0.01, 1.01, 50),
+        // No depth: skipped.
+        point(0.0, 0.0, 0.0, 255),
+    ];
+    let voxels = voxel_downsample(&points, 0.05)?;
+    assert_eq!(voxels.len(), 2);
+    assert!((voxels[0].x - 0.02).abs() < 1e-6 && (voxels[0].z - 1.02).abs() < 1e-6);
+    assert_eq!((voxels[0].r, voxels[0].a), (100, 255));
+    assert_eq!(voxels[1].r, 50);
+
+    // Negative coordinates fall into their own voxels rather than sharing the one at 0.
+    let around_zero = voxel_downsample(&[point(-0.01, 0.0, 1.0, 0), point(0.01, 0.0, 1.0, 0)], 0.05)?;
+    assert_eq!(around_zero.len(), 2);
+
+    assert_eq!(voxel_downsample(&plane(), 0.02)?.len(), 25);
+    assert!(voxel_downsample(&points, 0.0).is_err());
+    assert!(voxel_downsample(&points, f32::NAN).is_err());
+    Ok(())
+}
+
+#[test]
+fn statistical_outlier_removal_drops_isolated_points() -> depthai::Result<()> {
+    let mut points = plane();
+    points.push(point(0.05, 0.05, 1.5, 0));
+    points.push(point(-0.4, 0.2, 0.8, 0));
+    points.push(point(0.0, 0.0, 0.0, 0));
+
+    let kept = remove_statistical_outliers(&points, 8, 1.0)?;
+    assert_eq!(kept.len(), 100);
+    assert!(kept.iter().all(|p| p.z == 1.0));
+
+    // A uniform cloud loses nothing with a generous ratio; its corners sit a few deviations out.
+    assert_eq!(remove_statistical_outliers(&plane(), 8, 10.0)?.len(), 100);
+    // Too few points to estimate neighbors: valid points are returned as is.
+    assert_eq!(remove_statistical_outliers(&points[..4], 8, 1.0)?.len(), 4);
+    assert!(remove_statistical_outliers(&points, 0, 1.0).is_err());
+    Ok(())
+}
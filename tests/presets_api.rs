@@ -0,0 +1,20 @@
+#![cfg(feature = "hit")]
+
+use depthai::{presets, Device, Result};
+
+#[test]
+fn stereo_depth_only_links_cameras() -> Result<()> {
+    let preset = presets::stereo_depth_only()?;
+    let connections = preset.pipeline.connections()?;
+    assert_eq!(connections.len(), 2);
+    assert!(preset.pipeline.validate()?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn rgbd_preset_creates_queues() -> Result<()> {
+    let device = Device::new()?;
+    let preset = presets::rgbd(&device)?;
+    assert!(preset.rgbd_queue.try_next_rgbd()?.is_none());
+    Ok(())
+}
@@ -0,0 +1,56 @@
+#![cfg(not(target_os = "windows"))]
+
+use std::time::Duration;
+
+use depthai::queue_sync::TimestampMatcher;
+
+fn ms(v: u64) -> Duration {
+    Duration::from_millis(v)
+}
+
+#[test]
+fn matches_within_tolerance() {
+    let mut matcher = TimestampMatcher::new(2, ms(5));
+    matcher.push(0, ms(100), "rgb0");
+    assert_eq!(matcher.pop(), None);
+    matcher.push(1, ms(103), "depth0");
+    assert_eq!(matcher.pop(), Some(vec!["rgb0", "depth0"]));
+    assert_eq!(matcher.pop(), None);
+    assert_eq!(matcher.stats().matched, 1);
+    assert_eq!(matcher.stats().total_dropped(), 0);
+}
+
+#[test]
+fn stragglers_are_dropped_and_counted() {
+    let mut matcher = TimestampMatcher::new(3, ms(5));
+    // Stream 0 has a frame nobody else captured.
+    matcher.push(0, ms(0), 0);
+    matcher.push(0, ms(33), 1);
+    matcher.push(1, ms(34), 10);
+    matcher.push(2, ms(31), 20);
+    assert_eq!(matcher.pop(), Some(vec![1, 10, 20]));
+    assert_eq!(matcher.stats().dropped, vec![1, 0, 0]);
+
+    // Stream 2 skipped a frame: its next one is too new for stream 0/1's heads.
+    matcher.push(0, ms(66), 2);
+    matcher.push(1, ms(67), 11);
+    matcher.push(2, ms(100), 21);
+    assert_eq!(matcher.pop(), None);
+    assert_eq!(matcher.stats().dropped, vec![2, 0, 0]);
+    assert_eq!(matcher.pending(), vec![0, 1, 1]);
+    assert_eq!(matcher.waiting_on().collect::<Vec<_>>(), vec![0]);
+}
+
+#[test]
+fn max_pending_bounds_a_stream_running_ahead() {
+    let mut matcher = TimestampMatcher::new(2, ms(1));
+    matcher.set_max_pending(2);
+    for i in 0..5 {
+        matcher.push(0, ms(i * 10), i);
+    }
+    assert_eq!(matcher.pending(), vec![2, 0]);
+    assert_eq!(matcher.stats().dropped, vec![3, 0]);
+    matcher.push(1, ms(40), 100);
+    assert_eq!(matcher.pop(), Some(vec![4, 100]));
+    assert_eq!(matcher.stats().dropped, vec![4, 0]);
+}
@@ -0,0 +1,35 @@
+#![cfg(not(target_os = "windows"))]
+
+use depthai::queue_tuner::{QueueGoal, QueueObservation};
+
+fn observed(max_size: u32, blocking: bool, mean_occupancy: f32, peak_occupancy: u32, dropped: u64) -> QueueObservation {
+    QueueObservation {
+        produced: 300,
+        dropped,
+        samples: 150,
+        mean_occupancy,
+        peak_occupancy,
+        max_size,
+        blocking,
+    }
+}
+
+#[test]
+fn low_latency_queues_stay_shallow() {
+    // A slow consumer only reads stale frames from a deep queue.
+    assert_eq!(observed(8, false, 7.5, 8, 40).recommend(QueueGoal::LowLatency, 30).0, 1);
+    // A bursty consumer drops from a mostly empty queue.
+    let (max_size, blocking, _) = observed(2, false, 0.3, 2, 30).recommend(QueueGoal::LowLatency, 30);
+    assert_eq!((max_size, blocking), (4, false));
+    // A queue that never fills can shrink.
+    assert_eq!(observed(8, true, 0.2, 1, 0).recommend(QueueGoal::LowLatency, 30), (2, false, "queue never fills; a smaller one keeps messages fresh"));
+}
+
+#[test]
+fn lossless_queues_block_and_grow() {
+    assert_eq!(observed(2, false, 1.0, 2, 12).recommend(QueueGoal::Lossless, 30).0, 4);
+    assert_eq!(observed(20, true, 19.0, 20, 0).recommend(QueueGoal::Lossless, 30).0, 30);
+    let (max_size, blocking, reason) = observed(4, true, 0.5, 2, 0).recommend(QueueGoal::Lossless, 30);
+    assert_eq!((max_size, blocking, reason), (4, true, "keep"));
+    assert_eq!(observed(0, false, 0.0, 0, 0).drop_rate(), 0.0);
+}
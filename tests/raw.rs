@@ -0,0 +1,53 @@
+#![cfg(not(target_os = "windows"))]
+
+use depthai::camera::{ImageFrame, ImageFrameType};
+use depthai::raw::{BayerPattern, RawImage};
+
+#[test]
+fn raw_frames_unpack_to_sensor_values() -> depthai::Result<()> {
+    let values = [0u16, 1023, 4095, 65535];
+    let bytes: Vec<u8> = values.iter().flat_map(|v| v.to_le_bytes()).collect();
+    let image = RawImage::new(&ImageFrame::new(2, 2, ImageFrameType::RAW16, &bytes)?)?;
+    assert_eq!((image.width(), image.height(), image.bit_depth()), (2, 2, 16));
+    assert_eq!(image.values(), &values);
+    assert_eq!(image.at(1, 1), Some(65535));
+    assert_eq!(image.at(2, 0), None);
+
+    let image = RawImage::new(&ImageFrame::new(2, 1, ImageFrameType::RAW8, &[7, 200])?)?;
+    assert_eq!((image.bit_depth(), image.values()), (8, [7u16, 200].as_slice()));
+
+    let gray = ImageFrame::new(2, 1, ImageFrameType::GRAY8, &[1, 2])?;
+    assert!(RawImage::new(&gray).is_err());
+    assert!(RawImage::from_raw(2, 2, 10, vec![0; 3]).is_err());
+    assert!(RawImage::from_raw(1, 1, 20, vec![0]).is_err());
+    Ok(())
+}
+
+#[test]
+fn bayer_pattern_layouts() {
+    assert_eq!([0, 1, 0, 1].map(|x| BayerPattern::Rggb.channel_at(x, 0)), [0, 1, 0, 1]);
+    assert_eq!([0, 1].map(|x| BayerPattern::Rggb.channel_at(x, 1)), [1, 2]);
+    assert_eq!([0, 1].map(|x| BayerPattern::Bggr.channel_at(x, 0)), [2, 1]);
+    assert_eq!([0, 1].map(|x| BayerPattern::Grbg.channel_at(x, 1)), [2, 1]);
+    assert_eq!([0, 1].map(|x| BayerPattern::Gbrg.channel_at(x, 0)), [1, 2]);
+}
+
+#[cfg(feature = "demosaic")]
+#[test]
+fn bilinear_demosaic_recovers_flat_colors() -> depthai::Result<()> {
+    // A flat scene of R=400, G=200, B=100 sampled through an RGGB filter.
+    let (w, h) = (6u32, 4u32);
+    let values = (0..h as usize)
+        .flat_map(|y| (0..w as usize).map(move |x| [400, 200, 100][BayerPattern::Rggb.channel_at(x, y)]))
+        .collect();
+    let image = RawImage::from_raw(w, h, 10, values)?;
+    let rgb = image.demosaic(BayerPattern::Rggb)?;
+    assert_eq!(rgb.len(), (w * h * 3) as usize);
+    assert!(rgb.chunks_exact(3).all(|px| px == [400, 200, 100]), "{rgb:?}");
+
+    let preview = image.demosaic_rgb888(BayerPattern::Rggb)?;
+    assert_eq!(&preview[..3], &[100, 50, 25]);
+
+    assert!(RawImage::from_raw(1, 4, 10, vec![0; 4])?.demosaic(BayerPattern::Rggb).is_err());
+    Ok(())
+}
@@ -0,0 +1,186 @@
+#![cfg(not(target_os = "windows"))]
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use depthai::camera::{ImageFrame, ImageFrameType};
+use depthai::{
+    ColorImageFormat, DatasetRecorder, DatasetRecorderConfig, DepthUnit, Output, Pipeline, RecorderStats, Result,
+    ThreadedHostNodeContext, ThreadedHostNodeImpl,
+};
+
+#[derive(Clone, Copy)]
+enum Stream {
+    Color,
+    Depth,
+}
+
+/// Sends host-built frames on its `color` and `depth` outputs at the scripted timestamps (ms),
+/// one at a time so the recorder sees them in script order.
+struct Script {
+    color: Output,
+    depth: Output,
+    steps: Vec<(Stream, u64)>,
+}
+
+const DEPTH_MM: [u16; 4] = [1000, 2000, 0, 500];
+
+impl ThreadedHostNodeImpl for Script {
+    fn run(&mut self, ctx: &ThreadedHostNodeContext) {
+        for &(stream, ms) in &self.steps {
+            let sent = match stream {
+                Stream::Color => ImageFrame::new(2, 2, ImageFrameType::RGB888i, &[128; 12])
+                    .and_then(|frame| send(&self.color, frame, ms)),
+                Stream::Depth => {
+                    let bytes: Vec<u8> = DEPTH_MM.iter().flat_map(|v| v.to_le_bytes()).collect();
+                    ImageFrame::new(2, 2, ImageFrameType::RAW16, &bytes).and_then(|frame| send(&self.depth, frame, ms))
+                }
+            };
+            if let Err(err) = sent {
+                ctx.report_error(&err);
+            }
+            std::thread::sleep(Duration::from_millis(20));
+        }
+        ctx.wait_until_stopped();
+    }
+}
+
+fn send(output: &Output, frame: ImageFrame, ms: u64) -> Result<()> {
+    frame.set_timestamp(Duration::from_millis(ms))?;
+    output.send_frame(&frame)
+}
+
+/// Plays `script` into a recorder writing to a fresh directory; returns the final stats.
+fn record(
+    name: &str,
+    steps: Vec<(Stream, u64)>,
+    config: impl FnOnce(PathBuf) -> DatasetRecorderConfig,
+) -> Result<(PathBuf, RecorderStats)> {
+    let root = std::env::temp_dir().join(format!("depthai_recorder_{name}_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    let pipeline = Pipeline::new_host_only()?;
+    let frames = steps.len() as u64;
+    let recorder = pipeline.create_with::<DatasetRecorder, _>(config(root.clone()))?;
+    let (color, depth) = (recorder.color()?, recorder.depth()?);
+    pipeline.create_threaded_host_node(|node| {
+        let script = Script {
+            color: node.create_output(Some("color"))?,
+            depth: node.create_output(Some("depth"))?,
+            steps,
+        };
+        script.color.link(&color)?;
+        script.depth.link(&depth)?;
+        Ok(script)
+    })?;
+    pipeline.start()?;
+    std::thread::sleep(Duration::from_millis(20 * frames + 200));
+    // Stopping closes the recorder, which flushes its writer.
+    pipeline.stop()?;
+    Ok((root, recorder.stats()))
+}
+
+fn lines(path: &Path) -> Vec<String> {
+    std::fs::read_to_string(path)
+        .unwrap()
+        .lines()
+        .filter(|l| !l.starts_with('#'))
+        .map(str::to_string)
+        .collect()
+}
+
+#[test]
+fn pairs_close_frames_and_scales_depth() -> Result<()> {
+    use Stream::*;
+    let script = vec![(Color, 100), (Depth, 105), (Color, 200), (Depth, 260), (Color, 300)];
+    let (root, stats) = record("pairs", script, |root| DatasetRecorderConfig {
+        depth_scale: 5000.0,
+        ..DatasetRecorderConfig::new(root, DepthUnit::Millimeter)
+    })?;
+    // 200 and 260 are more than 20 ms apart; each is dropped once a newer frame shows it can
+    // no longer be matched.
+    assert_eq!(
+        stats,
+        RecorderStats {
+            written: 1,
+            dropped_unpaired: 2,
+            ..RecorderStats::default()
+        }
+    );
+    assert_eq!(
+        lines(&root.join("associations.txt")),
+        ["0.100000 rgb/0.100000.png 0.105000 depth/0.105000.png"]
+    );
+    assert_eq!(lines(&root.join("rgb.txt")), ["0.100000 rgb/0.100000.png"]);
+
+    // Millimeters written at 5000 units per meter.
+    let depth = image::open(root.join("depth/0.105000.png")).unwrap().into_luma16();
+    assert_eq!(depth.dimensions(), (2, 2));
+    assert_eq!(depth.into_raw(), [5000, 10000, 0, 2500]);
+    let color = image::open(root.join("rgb/0.100000.png")).unwrap().into_rgb8();
+    assert!(color.pixels().all(|p| p.0 == [128; 3]));
+
+    let meta: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(root.join("meta.json")).unwrap()).unwrap();
+    assert_eq!(meta["depth_scale"], 5000.0);
+    assert_eq!(meta["stats"]["written"], 1);
+    let _ = std::fs::remove_dir_all(&root);
+    Ok(())
+}
+
+#[test]
+fn rate_limit_skips_frames_between_pairs() -> Result<()> {
+    use Stream::*;
+    let script = vec![
+        (Color, 100),
+        (Depth, 100),
+        (Color, 150),
+        (Depth, 150),
+        (Color, 350),
+        (Depth, 355),
+    ];
+    let (root, stats) = record("rate", script, |root| DatasetRecorderConfig {
+        rate_hz: Some(5.0),
+        color_format: ColorImageFormat::Jpeg(90),
+        ..DatasetRecorderConfig::new(root, DepthUnit::Millimeter)
+    })?;
+    // After the pair at 100 ms the next one is due at 300 ms.
+    assert_eq!(
+        stats,
+        RecorderStats {
+            written: 2,
+            skipped_rate: 2,
+            ..RecorderStats::default()
+        }
+    );
+    let rgb = lines(&root.join("rgb.txt"));
+    assert_eq!(rgb, ["0.100000 rgb/0.100000.jpg", "0.350000 rgb/0.350000.jpg"]);
+    assert!(root.join("rgb/0.350000.jpg").exists());
+    // The default scale keeps millimeters.
+    let depth = image::open(root.join("depth/0.355000.png")).unwrap().into_luma16();
+    assert_eq!(depth.into_raw(), DEPTH_MM);
+    let _ = std::fs::remove_dir_all(&root);
+    Ok(())
+}
+
+#[cfg(feature = "hit")]
+#[test]
+fn dataset_recorder_creates_layout() -> Result<()> {
+    let root = std::env::temp_dir().join(format!("depthai_recorder_{}", std::process::id()));
+    let pipeline = Pipeline::new().build()?;
+
+    let recorder = pipeline.create_with::<DatasetRecorder, _>(DatasetRecorderConfig {
+        color_format: ColorImageFormat::Jpeg(90),
+        rate_hz: Some(5.0),
+        ..DatasetRecorderConfig::new(&root, DepthUnit::Millimeter)
+    })?;
+    let _color = recorder.color()?;
+    let _depth = recorder.depth()?;
+    assert_eq!(recorder.stats(), RecorderStats::default());
+
+    for entry in ["rgb", "depth", "rgb.txt", "depth.txt", "associations.txt"] {
+        assert!(root.join(entry).exists(), "missing {entry}");
+    }
+
+    let _ = std::fs::remove_dir_all(&root);
+    Ok(())
+}
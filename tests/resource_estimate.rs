@@ -0,0 +1,53 @@
+#![cfg(not(target_os = "windows"))]
+
+use depthai::pipeline::{ResourceBudget, ResourceEstimate};
+use serde_json::json;
+
+fn pipeline_json(nodes: serde_json::Value) -> serde_json::Value {
+    json!({ "pipeline": { "connections": [], "globalProperties": {}, "nodes": nodes } })
+}
+
+#[test]
+fn sums_explicit_requests() {
+    let json = pipeline_json(json!([
+        [0, { "id": 0, "name": "Camera", "properties": {} }],
+        [1, { "id": 1, "name": "StereoDepth", "properties": {
+            "numPostProcessingShaves": 3, "numPostProcessingMemorySlices": 3 } }],
+        [2, { "id": 2, "name": "NeuralNetwork", "properties": {
+            "numThreads": 2, "numShavesPerThread": 6, "numNCEPerThread": 1, "blobSize": 4096 } }],
+        [3, { "id": 3, "name": "ImageManip", "properties": { "outputFrameSize": 1024, "numFramesPool": 4 } }],
+    ]));
+    let estimate = ResourceEstimate::from_pipeline_json(&json, ResourceBudget::RVC2);
+    assert_eq!(estimate.nodes.len(), 3);
+    assert_eq!(estimate.shaves(), 15);
+    assert_eq!(estimate.cmx_slices(), 15);
+    assert_eq!(estimate.nce(), 2);
+    assert_eq!(estimate.ddr_bytes(), 4096 + 4096);
+    assert!(estimate.fits(), "{estimate}");
+}
+
+#[test]
+fn reports_overcommitted_shaves_and_automatic_nodes() {
+    let json = pipeline_json(json!([
+        [1, { "id": 1, "name": "StereoDepth", "properties": {
+            "numPostProcessingShaves": -1, "numPostProcessingMemorySlices": -1 } }],
+        [2, { "id": 2, "name": "NeuralNetwork", "properties": { "numShavesPerThread": 10 } }],
+    ]));
+    let estimate = ResourceEstimate::from_pipeline_json(&json, ResourceBudget::RVC2);
+    assert!(estimate.nodes[0].is_automatic());
+    // Automatic threading counts as two threads.
+    assert_eq!(estimate.shaves(), 20);
+    let problems = estimate.overcommitted();
+    assert!(problems.iter().any(|p| p.starts_with("SHAVEs")), "{problems:?}");
+    assert!(!estimate.fits());
+    assert!(estimate.to_string().contains("StereoDepth #1"));
+}
+
+#[test]
+fn reads_properties_serialized_as_bytes() {
+    let properties = json!({ "numThreads": 1, "numShavesPerThread": 4 }).to_string().into_bytes();
+    let json = pipeline_json(json!([[5, { "id": 5, "name": "NeuralNetwork", "properties": properties }]]));
+    let estimate = ResourceEstimate::from_pipeline_json(&json, ResourceBudget::RVC2);
+    assert_eq!(estimate.shaves(), 4);
+    assert_eq!(estimate.nodes[0].node_id, 5);
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "hit")]
+
+use depthai::{DepthUnit, Pipeline, Result, RgbdNode, StereoPresetMode};
+
+/// `autocreate()` replaces the manual camera + stereo + alignment wiring of `examples/rgbd_rerun.rs`.
+#[test]
+fn rgbd_autocreate_wires_inputs() -> Result<()> {
+    let pipeline = Pipeline::new().build()?;
+
+    let rgbd = pipeline.create::<RgbdNode>()?;
+    assert_eq!(rgbd.depth_unit()?, DepthUnit::Millimeter);
+    rgbd.set_depth_unit(DepthUnit::Meter);
+    assert_eq!(rgbd.depth_unit()?, DepthUnit::Meter);
+    assert_eq!(rgbd.output_fps()?, 0.0);
+    assert!(!rgbd.is_sparse()?);
+    rgbd.set_output_fps(10.0);
+    rgbd.set_sparse(true);
+    assert_eq!(rgbd.output_fps()?, 10.0);
+    assert!(rgbd.is_sparse()?);
+    rgbd.autocreate()
+        .preset(StereoPresetMode::Robotics)
+        .size(640, 400)
+        .fps(15.0)
+        .build()?;
+
+    let _pcl = rgbd.as_node().output("pcl")?.create_queue(2, false)?;
+    let _rgbd = rgbd.as_node().output("rgbd")?.create_queue(2, false)?;
+
+    Ok(())
+}
+
+#[test]
+fn rgbd_data_host_pointcloud() -> Result<()> {
+    use std::time::Duration;
+
+    use depthai::common::CameraBoardSocket;
+    use depthai::{presets, Device};
+
+    let device = Device::new()?;
+    let preset = presets::rgbd(&device)?;
+    preset.pipeline.start()?;
+    let data = preset
+        .rgbd_queue
+        .blocking_next_rgbd(Some(Duration::from_secs(5)))?
+        .expect("no RGBD frame within 5 s");
+
+    let info = data.depth_info()?;
+    assert_eq!(info.unit, DepthUnit::Millimeter);
+    assert_eq!(info.aligned_to, CameraBoardSocket::CamA);
+    assert!(data.intrinsics()?.fx > 0.0);
+
+    let full = data.to_pointcloud(1)?;
+    let decimated = data.to_pointcloud(4)?;
+    assert!(decimated.len() <= full.len());
+    assert!(decimated.iter().all(|p| p.is_valid()));
+    assert!(data.to_pointcloud(0).is_err());
+
+    preset.pipeline.stop()?;
+    Ok(())
+}
@@ -0,0 +1,133 @@
+#![cfg(not(target_os = "windows"))]
+
+use std::time::Duration;
+
+use depthai::ring_buffer::{EncodedFrameRing, RingBufferConfig, RingBufferHostNode};
+use depthai::{Pipeline, Result};
+
+fn ms(ms: u64) -> Duration {
+    Duration::from_millis(ms)
+}
+
+#[test]
+fn keeps_the_last_duration_and_dumps_from_a_keyframe() -> Result<()> {
+    let mut ring = EncodedFrameRing::new(ms(1000), usize::MAX);
+    // 30 fps with a keyframe every 10 frames.
+    for i in 0..60u64 {
+        ring.push_raw(&[i as u8; 4], ms(i * 33), i % 10 == 0);
+    }
+    assert!(ring.buffered_duration() <= ms(1000));
+    assert_eq!(ring.len(), 31);
+    assert_eq!(ring.buffered_bytes(), 31 * 4);
+
+    let path = std::env::temp_dir().join(format!("depthai_ring_{}.bin", std::process::id()));
+    let dump = ring.dump_to(&path)?;
+    let written = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    // Frames 29 and up are buffered; decoding starts at the keyframe 30.
+    assert_eq!(dump.frames, 30);
+    assert_eq!(dump.bytes, 30 * 4);
+    assert_eq!(dump.duration, ms(29 * 33));
+    assert_eq!(&written[..4], &[30; 4]);
+    assert_eq!(written.len(), dump.bytes);
+    // Dumping keeps the buffer.
+    assert_eq!(ring.len(), 31);
+    Ok(())
+}
+
+#[test]
+fn memory_bound_drops_oldest_frames() {
+    let mut ring = EncodedFrameRing::new(ms(10_000), 100);
+    for i in 0..10u64 {
+        ring.push_raw(&[0; 30], ms(i * 33), true);
+    }
+    assert_eq!(ring.len(), 3);
+    assert_eq!(ring.buffered_bytes(), 90);
+
+    // A frame larger than the bound is still kept on its own.
+    ring.push_raw(&[0; 200], ms(400), true);
+    assert_eq!(ring.len(), 1);
+}
+
+#[test]
+fn dump_needs_a_keyframe() {
+    let mut ring = EncodedFrameRing::new(ms(1000), usize::MAX);
+    let path = std::env::temp_dir().join(format!("depthai_ring_empty_{}.bin", std::process::id()));
+    assert!(ring.dump_to(&path).is_err());
+    ring.push_raw(&[1, 2, 3], ms(0), false);
+    assert!(ring.dump_to(&path).is_err());
+    assert!(!path.exists());
+}
+
+#[test]
+fn host_node_exposes_ports_and_dumps_on_request() -> Result<()> {
+    let dir = std::env::temp_dir().join(format!("depthai_ring_node_{}", std::process::id()));
+    let pipeline = Pipeline::new_host_only()?;
+    let ring = pipeline.create_with::<RingBufferHostNode, _>(RingBufferConfig {
+        output_dir: dir.clone(),
+        ..RingBufferConfig::default()
+    })?;
+    assert_eq!(ring.input()?.name()?, "in");
+    assert_eq!(ring.trigger()?.name()?, "trigger");
+    assert_eq!(ring.buffered_duration(), Duration::ZERO);
+    assert!(ring.dump().is_err());
+    assert!(ring.last_dump().is_none());
+    let _ = std::fs::remove_dir_all(&dir);
+    Ok(())
+}
+
+#[cfg(feature = "hit")]
+#[test]
+fn dumps_get_their_own_files_and_triggers_are_written_in_the_background() -> Result<()> {
+    use std::time::Instant;
+
+    use depthai::camera::{CameraNode, CameraOutputConfig};
+    use depthai::common::{CameraBoardSocket, ImageFrameType};
+    use depthai::host_node::Buffer;
+    use depthai::{VideoEncoderNode, VideoEncoderProfile};
+
+    let dir = std::env::temp_dir().join(format!("depthai_ring_hit_{}", std::process::id()));
+    let pipeline = Pipeline::new().build()?;
+    let camera = pipeline.create_with::<CameraNode, _>(CameraBoardSocket::CamA)?;
+    let mut config = CameraOutputConfig::new((640, 480));
+    config.frame_type = Some(ImageFrameType::NV12);
+    let encoder = pipeline.create::<VideoEncoderNode>()?;
+    encoder.set_default_profile_preset(30.0, VideoEncoderProfile::Mjpeg);
+    camera.request_output(config)?.link(&encoder.input()?)?;
+    let ring = pipeline.create_with::<RingBufferHostNode, _>(RingBufferConfig {
+        duration: ms(500),
+        output_dir: dir.clone(),
+        ..RingBufferConfig::default()
+    })?;
+    encoder.out()?.link(&ring.input()?)?;
+    let trigger = ring.trigger()?.create_input_queue(1, false)?;
+    pipeline.start()?;
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while ring.buffered_bytes() == 0 && Instant::now() < deadline {
+        std::thread::sleep(ms(20));
+    }
+    // Back to back dumps never overwrite each other, even within the same millisecond.
+    let first = ring.dump()?;
+    let second = ring.dump()?;
+    assert_ne!(first.path, second.path);
+    assert!(first.path.extension().is_some_and(|e| e == "mjpeg"));
+
+    trigger.send_buffer(&Buffer::new(1)?)?;
+    let deadline = Instant::now() + Duration::from_secs(5);
+    let triggered = loop {
+        match ring.last_dump() {
+            Some(dump) if dump.path != second.path => break Some(dump),
+            _ if Instant::now() > deadline => break None,
+            _ => std::thread::sleep(ms(20)),
+        }
+    };
+    let triggered = triggered.expect("trigger message writes a dump");
+    let written = std::fs::metadata(&triggered.path).unwrap().len();
+    assert_eq!(written, triggered.bytes as u64);
+    assert!(pipeline.take_node_errors().is_empty());
+
+    pipeline.stop()?;
+    let _ = std::fs::remove_dir_all(&dir);
+    Ok(())
+}
@@ -0,0 +1,36 @@
+#![cfg(all(feature = "ros2", not(target_os = "windows")))]
+
+use std::time::Duration;
+
+use depthai::camera::{ImageFrame, ImageFrameType};
+use depthai::ros2::{self, Time, TimeBase};
+
+#[test]
+fn ros_time_mapping() {
+    let base = TimeBase::with_anchor(Time { sec: 100, nanosec: 0 }, Duration::from_secs(10));
+    assert_eq!(base.stamp(Duration::from_millis(10_500)), Time { sec: 100, nanosec: 500_000_000 });
+    // Timestamps older than the anchor map backwards.
+    assert_eq!(base.stamp(Duration::from_millis(9_750)), Time { sec: 99, nanosec: 750_000_000 });
+    assert_eq!(Time::from_nanos(-1), Time { sec: -1, nanosec: 999_999_999 });
+}
+
+#[test]
+fn image_to_ros_without_hardware() -> depthai::Result<()> {
+    let base = TimeBase::with_anchor(Time { sec: 50, nanosec: 0 }, Duration::ZERO);
+
+    let bgr = ImageFrame::new(2, 1, ImageFrameType::BGR888i, &[1, 2, 3, 4, 5, 6])?;
+    bgr.set_timestamp(Duration::from_secs(2))?;
+    let msg = ros2::image_to_ros(&bgr, "camera", &base)?;
+    assert_eq!((msg.width, msg.height, msg.step), (2, 1, 6));
+    assert_eq!(msg.encoding, "bgr8");
+    assert_eq!(msg.data, vec![1, 2, 3, 4, 5, 6]);
+    assert_eq!(msg.header.frame_id, "camera");
+    assert_eq!(msg.header.stamp, Time { sec: 52, nanosec: 0 });
+
+    // NV12 has no ROS encoding and is converted to rgb8.
+    let nv12 = ImageFrame::new(2, 2, ImageFrameType::NV12, &[126, 126, 126, 126, 128, 128])?;
+    let msg = ros2::image_to_ros(&nv12, "camera", &base)?;
+    assert_eq!((msg.encoding.as_str(), msg.step, msg.data.len()), ("rgb8", 6, 12));
+
+    Ok(())
+}
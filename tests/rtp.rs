@@ -0,0 +1,130 @@
+#![cfg(not(target_os = "windows"))]
+
+use std::time::Duration;
+
+use depthai::rtp::{split_annexb, RtpCodec, RtpConfig, RtpPacketizer, RTP_HEADER_LEN};
+
+const SPS: [u8; 4] = [0x67, 0x42, 0x00, 0x1f];
+const PPS: [u8; 3] = [0x68, 0xce, 0x3c];
+
+fn config(mtu: usize) -> RtpConfig {
+    RtpConfig {
+        mtu,
+        ssrc: 0x1234_5678,
+        payload_type: 96,
+        aggregate: true,
+    }
+}
+
+fn annexb(nals: &[&[u8]]) -> Vec<u8> {
+    nals.iter().flat_map(|nal| [&[0, 0, 0, 1][..], nal].concat()).collect()
+}
+
+fn idr(len: usize) -> Vec<u8> {
+    let mut nal = vec![0x65];
+    nal.extend((0..len - 1).map(|i| (i % 251) as u8 + 1));
+    nal
+}
+
+#[test]
+fn split_annexb_strips_start_codes() {
+    let stream = [0, 0, 0, 1, 0x67, 1, 0, 0, 1, 0x68, 2, 0, 0];
+    assert_eq!(split_annexb(&stream), vec![&[0x67, 1][..], &[0x68, 2][..]]);
+}
+
+#[test]
+fn header_fields_and_marker() -> depthai::Result<()> {
+    let mut rtp = RtpPacketizer::new(RtpCodec::H264, config(1200))?;
+    let first_seq = rtp.sequence_number();
+    let slice = [0x41, 1, 2, 3];
+    let packets = rtp.packetize_annexb(&annexb(&[&slice]), Duration::from_secs(1));
+    assert_eq!(packets.len(), 1);
+    let p = &packets[0];
+    assert_eq!(p[0], 0x80);
+    assert_eq!(p[1], 0x80 | 96);
+    assert_eq!(u16::from_be_bytes([p[2], p[3]]), first_seq);
+    assert_eq!(u32::from_be_bytes([p[8], p[9], p[10], p[11]]), 0x1234_5678);
+    assert_eq!(&p[RTP_HEADER_LEN..], &slice);
+    assert_eq!(rtp.sequence_number(), first_seq.wrapping_add(1));
+
+    // 1/30 s later is 3000 ticks of the 90 kHz clock.
+    let ts0 = u32::from_be_bytes([p[4], p[5], p[6], p[7]]);
+    let next = rtp.packetize_annexb(&annexb(&[&slice]), Duration::from_secs(1) + Duration::from_nanos(33_333_334));
+    let q = &next[0];
+    assert_eq!(u32::from_be_bytes([q[4], q[5], q[6], q[7]]).wrapping_sub(ts0), 3000);
+    Ok(())
+}
+
+#[test]
+fn parameter_sets_are_aggregated_and_large_nals_fragmented() -> depthai::Result<()> {
+    let mut rtp = RtpPacketizer::new(RtpCodec::H264, config(100))?;
+    let idr = idr(300);
+    let packets = rtp.packetize_annexb(&annexb(&[&SPS, &PPS, &idr]), Duration::ZERO);
+
+    // STAP-A with SPS and PPS.
+    let stap = &packets[0][RTP_HEADER_LEN..];
+    assert_eq!(stap[0] & 0x1F, 24);
+    assert_eq!(&stap[1..3], &(SPS.len() as u16).to_be_bytes());
+    assert_eq!(&stap[3..7], &SPS);
+    assert_eq!(&stap[9..], &PPS);
+    assert_eq!(rtp.parameter_sets().len(), 2);
+
+    // FU-A fragments that reassemble into the IDR slice.
+    let fragments = &packets[1..];
+    assert!(fragments.iter().all(|p| p.len() <= 100));
+    let mut reassembled = vec![0x65];
+    for (i, p) in fragments.iter().enumerate() {
+        let payload = &p[RTP_HEADER_LEN..];
+        assert_eq!(payload[0], 0x60 | 28);
+        assert_eq!(payload[1] & 0x80 != 0, i == 0);
+        assert_eq!(payload[1] & 0x40 != 0, i + 1 == fragments.len());
+        assert_eq!(payload[1] & 0x1F, 5);
+        assert_eq!(p[1] & 0x80 != 0, i + 1 == fragments.len());
+        reassembled.extend_from_slice(&payload[2..]);
+    }
+    assert_eq!(reassembled, idr);
+    Ok(())
+}
+
+#[test]
+fn cached_parameter_sets_precede_later_keyframes() -> depthai::Result<()> {
+    let mut rtp = RtpPacketizer::new(RtpCodec::H264, config(1200))?;
+    let idr = idr(50);
+    rtp.packetize_annexb(&annexb(&[&SPS, &PPS, &idr]), Duration::ZERO);
+
+    let packets = rtp.packetize_annexb(&annexb(&[&idr]), Duration::from_secs(1));
+    assert_eq!(packets.len(), 1);
+    let stap = &packets[0][RTP_HEADER_LEN..];
+    assert_eq!(stap[0] & 0x1F, 24);
+    assert_eq!(&stap[3..7], &SPS);
+
+    let p_slice = [0x41, 9, 9];
+    let packets = rtp.packetize_annexb(&annexb(&[&p_slice]), Duration::from_secs(2));
+    assert_eq!(&packets[0][RTP_HEADER_LEN..], &p_slice);
+    Ok(())
+}
+
+#[test]
+fn h265_fragmentation_units() -> depthai::Result<()> {
+    let mut rtp = RtpPacketizer::new(RtpCodec::H265, config(64))?;
+    // IDR_W_RADL (type 19), layer 0, TID 1.
+    let mut nal = vec![19 << 1, 0x01];
+    nal.extend(std::iter::repeat_n(0xAB, 200));
+    let packets = rtp.packetize_annexb(&annexb(&[&nal]), Duration::ZERO);
+    assert!(packets.len() > 1);
+    let mut body = Vec::new();
+    for p in &packets {
+        let payload = &p[RTP_HEADER_LEN..];
+        assert_eq!((payload[0] >> 1) & 0x3F, 49);
+        assert_eq!(payload[1], 0x01);
+        assert_eq!(payload[2] & 0x3F, 19);
+        body.extend_from_slice(&payload[3..]);
+    }
+    assert_eq!(body, &nal[2..]);
+    Ok(())
+}
+
+#[test]
+fn tiny_mtu_is_rejected() {
+    assert!(RtpPacketizer::new(RtpCodec::H264, config(RTP_HEADER_LEN + 2)).is_err());
+}
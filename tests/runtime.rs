@@ -0,0 +1,32 @@
+#![cfg(not(target_os = "windows"))]
+
+use depthai::pipeline::Pipeline;
+use depthai::runtime;
+
+// The shutdown is process-wide, so this file holds a single test.
+#[test]
+fn shutdown_cancels_the_registrations_and_can_happen_again() -> depthai::Result<()> {
+    let pipeline = Pipeline::new_host_only()?;
+    let dropped = Pipeline::new_host_only()?;
+    let left = Pipeline::new_host_only()?;
+    let handle = runtime::register(&pipeline);
+    runtime::register(&dropped);
+    let unregistered = runtime::register(&left);
+    unregistered.unregister();
+    drop(dropped);
+
+    assert!(!handle.is_shutting_down());
+    assert!(!handle.token().is_cancelled());
+    handle.shutdown();
+    assert!(handle.is_shutting_down());
+    assert!(handle.token().is_cancelled());
+    assert!(!pipeline.is_running()?);
+    assert!(!unregistered.token().is_cancelled());
+
+    // Registering again after a shutdown gets a fresh token, cancelled by the next shutdown.
+    let again = runtime::register(&pipeline);
+    assert!(!again.is_shutting_down());
+    handle.shutdown();
+    assert!(again.token().is_cancelled());
+    Ok(())
+}
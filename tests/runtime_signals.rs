@@ -0,0 +1,34 @@
+#![cfg(all(feature = "signals", not(target_os = "windows")))]
+
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+use depthai::pipeline::Pipeline;
+use depthai::runtime;
+
+// The signal handler is process-wide, so this file holds a single test.
+#[test]
+fn sigint_shuts_the_registered_pipelines_down() -> depthai::Result<()> {
+    let pipeline = Pipeline::new_host_only()?;
+    let handle = runtime::install_shutdown_handler(pipeline.clone())?;
+    // The handler is installed once; a second pipeline only registers.
+    let other = Pipeline::new_host_only()?;
+    let second = runtime::install_shutdown_handler(other.clone())?;
+    // Another handler can no longer be installed through `ctrlc`.
+    assert!(ctrlc::set_handler(|| {}).is_err());
+
+    let status = Command::new("kill")
+        .args(["-INT", &std::process::id().to_string()])
+        .status()
+        .expect("failed to run kill");
+    assert!(status.success());
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while !(handle.token().is_cancelled() && second.token().is_cancelled()) {
+        assert!(Instant::now() < deadline, "SIGINT did not shut down");
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    assert!(!pipeline.is_running()?);
+    assert!(!other.is_running()?);
+    Ok(())
+}
@@ -0,0 +1,58 @@
+#![cfg(not(target_os = "windows"))]
+
+use depthai::pipeline::{Pipeline, SchemaDiff};
+use serde_json::json;
+
+fn link(from: i64, output: &str, to: i64, input: &str) -> serde_json::Value {
+    json!({ "node1Id": from, "node1Output": output, "node1OutputGroup": "",
+            "node2Id": to, "node2Input": input, "node2InputGroup": "" })
+}
+
+#[test]
+fn reports_node_property_and_link_changes() {
+    let before = json!({
+        "globalProperties": { "leonCssFrequencyHz": 700_000_000 },
+        "nodes": [
+            [0, { "id": 0, "name": "Camera", "alias": "", "properties": { "fps": 30 } }],
+            [1, { "id": 1, "name": "StereoDepth", "alias": "",
+                  "properties": { "initialConfig": { "median": "KERNEL_7x7", "lrCheck": true } } }],
+            [2, { "id": 2, "name": "ImageManip", "alias": "", "properties": {} }],
+        ],
+        "connections": [link(0, "raw", 1, "left"), link(0, "raw", 2, "inputImage")],
+    });
+    // The newer version inserts a node, shifting the ids of the others.
+    let properties = json!({ "initialConfig": { "median": "MEDIAN_OFF", "lrCheck": true } }).to_string();
+    let after = json!({
+        "globalProperties": { "leonCssFrequencyHz": 700_000_000 },
+        "nodes": [
+            [0, { "id": 0, "name": "Camera", "alias": "", "properties": { "fps": 30 } }],
+            [1, { "id": 1, "name": "Sync", "alias": "", "properties": {} }],
+            [2, { "id": 2, "name": "StereoDepth", "alias": "", "properties": properties.into_bytes() }],
+        ],
+        "connections": [link(0, "raw", 2, "left")],
+    });
+
+    let diff = SchemaDiff::between(&before, &after);
+    assert_eq!(diff.nodes_added.iter().map(|n| n.name.as_str()).collect::<Vec<_>>(), ["Sync"]);
+    assert_eq!(diff.nodes_removed.iter().map(|n| n.name.as_str()).collect::<Vec<_>>(), ["ImageManip"]);
+    assert_eq!(diff.property_changes.len(), 1, "{diff}");
+    let change = &diff.property_changes[0];
+    assert_eq!(change.path, "initialConfig.median");
+    assert_eq!(change.node.as_ref().map(|n| n.id), Some(2));
+    assert_eq!(change.before, Some(json!("KERNEL_7x7")));
+    assert_eq!(change.after, Some(json!("MEDIAN_OFF")));
+    // The moved StereoDepth link is the same link; only the one to the removed node is gone.
+    assert!(diff.connections_added.is_empty(), "{diff}");
+    assert_eq!(diff.connections_removed.len(), 1);
+    assert_eq!(diff.connections_removed[0].input, "inputImage");
+    assert!(diff.to_string().contains("~ StereoDepth #2: initialConfig.median"));
+}
+
+#[test]
+fn identical_pipelines_have_no_diff() -> depthai::Result<()> {
+    let a = Pipeline::new_host_only()?;
+    let b = Pipeline::new_host_only()?;
+    let diff = a.schema_diff(&b)?;
+    assert!(diff.is_empty(), "{diff}");
+    Ok(())
+}
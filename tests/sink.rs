@@ -0,0 +1,189 @@
+#![cfg(not(target_os = "windows"))]
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use depthai::camera::{ImageFrame, ImageFrameType};
+use depthai::detections::ImgDetections;
+use depthai::pipeline::{NodeError, NodeFailure};
+use depthai::sink::{create_sink_host_node, FrameSink, SinkContext, SinkHostNode, SinkInput};
+use depthai::{Buffer, Output, Pipeline, Result, SinkStats, ThreadedHostNodeContext, ThreadedHostNodeImpl};
+
+#[derive(Clone, Copy)]
+enum Step {
+    /// A `width`x2 GRAY8 frame on `image`; the sink rejects width 4.
+    Frame(u32),
+    /// A plain buffer on `image`.
+    Buffer,
+    /// Empty detections on `dets`.
+    Detections,
+}
+
+/// Sends the scripted messages 10 ms apart, then idles.
+struct Script {
+    image: Output,
+    dets: Output,
+    steps: Vec<Step>,
+}
+
+impl ThreadedHostNodeImpl for Script {
+    fn run(&mut self, ctx: &ThreadedHostNodeContext) {
+        for &step in &self.steps {
+            let sent = match step {
+                Step::Frame(width) => ImageFrame::new(width, 2, ImageFrameType::GRAY8, &vec![0; width as usize * 2])
+                    .and_then(|frame| self.image.send_frame(&frame)),
+                Step::Buffer => Buffer::new(1).and_then(|buffer| self.image.send_buffer(&buffer)),
+                Step::Detections => ImgDetections::new().and_then(|dets| self.dets.send_detections(&dets)),
+            };
+            if let Err(err) = sent {
+                ctx.report_error(&err);
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        ctx.wait_until_stopped();
+    }
+}
+
+/// (callback, input name, entity path, sequence) of a delivered message.
+type Entry = (&'static str, String, String, u64);
+type Seen = Arc<Mutex<Vec<Entry>>>;
+
+struct Recording {
+    seen: Seen,
+}
+
+impl Recording {
+    fn record(&self, callback: &'static str, ctx: &SinkContext<'_>) {
+        let entry = (
+            callback,
+            ctx.input.name.clone(),
+            ctx.input.entity_path.clone(),
+            ctx.sequence,
+        );
+        self.seen.lock().unwrap().push(entry);
+    }
+}
+
+impl FrameSink for Recording {
+    fn on_frame(&mut self, ctx: &SinkContext<'_>, frame: &ImageFrame) -> Result<()> {
+        if frame.width() == 4 {
+            // Any error will do; the node counts and reports it.
+            return ImageFrame::new(2, 2, ImageFrameType::GRAY8, &[]).map(|_| ());
+        }
+        self.record("frame", ctx);
+        Ok(())
+    }
+
+    fn on_detections(&mut self, ctx: &SinkContext<'_>, _detections: &ImgDetections) -> Result<()> {
+        self.record("detections", ctx);
+        Ok(())
+    }
+}
+
+/// Plays `steps` into a sink host node with the given inputs; returns what it delivered and the
+/// node errors reported on the way.
+fn play(inputs: Vec<SinkInput>, steps: Vec<Step>) -> Result<(Vec<Entry>, SinkStats, Vec<NodeError>)> {
+    let pipeline = Pipeline::new_host_only()?;
+    let seen = Seen::default();
+    let names: Vec<String> = inputs.iter().map(|i| i.name.clone()).collect();
+    let sink: SinkHostNode = create_sink_host_node(
+        &pipeline,
+        inputs,
+        Recording {
+            seen: Arc::clone(&seen),
+        },
+    )?;
+    let count = steps.len() as u64;
+    let script = pipeline.create_threaded_host_node(|node| {
+        Ok(Script {
+            image: node.create_output(Some("image"))?,
+            dets: node.create_output(Some("dets"))?,
+            steps,
+        })
+    })?;
+    for name in &names {
+        script.as_node().output(name)?.link(&sink.input(name)?)?;
+    }
+    pipeline.start()?;
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while sink.stats().delivered + sink.stats().dropped < count && Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(5));
+    }
+    pipeline.stop()?;
+    let seen = seen.lock().unwrap().clone();
+    Ok((seen, sink.stats(), pipeline.take_node_errors()))
+}
+
+fn entry(callback: &'static str, name: &str, path: &str, sequence: u64) -> Entry {
+    (callback, name.to_string(), path.to_string(), sequence)
+}
+
+#[test]
+fn frames_are_numbered_per_input_and_failures_are_dropped() -> Result<()> {
+    let steps = vec![
+        Step::Frame(2),
+        Step::Frame(4),
+        Step::Buffer,
+        Step::Frame(2),
+        Step::Frame(2),
+    ];
+    let (seen, stats, errors) = play(vec![SinkInput::image("image", "camera/rgb")], steps)?;
+    // The rejected frame and the buffer sent to an image input don't advance the sequence.
+    assert_eq!(
+        seen,
+        [
+            entry("frame", "image", "camera/rgb", 0),
+            entry("frame", "image", "camera/rgb", 1),
+            entry("frame", "image", "camera/rgb", 2),
+        ]
+    );
+    assert_eq!(
+        stats,
+        SinkStats {
+            delivered: 3,
+            dropped: 2
+        }
+    );
+    // Each drop is reported on the sink node; the next delivered frame ends the run of failures.
+    assert_eq!(errors.iter().map(|e| e.consecutive).collect::<Vec<_>>(), [1, 2]);
+    for error in &errors {
+        assert!(matches!(&error.failure, NodeFailure::Error(m) if m.contains("dropped a message on 'image'")));
+    }
+    Ok(())
+}
+
+#[test]
+fn several_inputs_dispatch_by_kind() -> Result<()> {
+    let inputs = vec![
+        SinkInput::image("image", "camera/rgb"),
+        SinkInput::detections("dets", "camera/rgb/dets", (2, 2)),
+    ];
+    let steps = vec![Step::Frame(2), Step::Detections, Step::Frame(2), Step::Detections];
+    let (seen, stats, errors) = play(inputs, steps)?;
+    assert_eq!(
+        seen,
+        [
+            entry("frame", "image", "camera/rgb", 0),
+            entry("detections", "dets", "camera/rgb/dets", 0),
+            entry("frame", "image", "camera/rgb", 1),
+            entry("detections", "dets", "camera/rgb/dets", 1),
+        ]
+    );
+    assert_eq!(
+        stats,
+        SinkStats {
+            delivered: 4,
+            dropped: 0
+        }
+    );
+    assert!(errors.is_empty());
+    Ok(())
+}
+
+#[test]
+fn a_sink_needs_an_input() -> Result<()> {
+    let pipeline = Pipeline::new_host_only()?;
+    let seen = Seen::default();
+    assert!(create_sink_host_node(&pipeline, Vec::new(), Recording { seen }).is_err());
+    Ok(())
+}
@@ -0,0 +1,63 @@
+#![cfg(all(feature = "serde", not(target_os = "windows")))]
+
+use std::time::Duration;
+
+use depthai::camera::{ImageFrame, ImageFrameType};
+use depthai::snapshot::{ImageFrameSnapshot, ImgDetectionsSnapshot};
+use depthai::{ImgDetection, ImgDetections};
+
+#[test]
+fn image_frame_round_trips_through_json() -> depthai::Result<()> {
+    let frame = ImageFrame::new(2, 2, ImageFrameType::GRAY8, &[1, 2, 3, 4])?;
+    frame.set_timestamp(Duration::from_millis(1500))?;
+    frame.set_sequence_num(42)?;
+
+    let json = serde_json::to_string(&frame).expect("serialize frame");
+    let snapshot: ImageFrameSnapshot = serde_json::from_str(&json).expect("parse snapshot");
+    assert_eq!((snapshot.width, snapshot.height), (2, 2));
+    assert_eq!(snapshot.frame_type, Some(ImageFrameType::GRAY8));
+    assert_eq!(snapshot.data, vec![1, 2, 3, 4]);
+
+    let copy: ImageFrame = serde_json::from_str(&json).expect("deserialize frame");
+    assert_eq!(copy.bytes(), frame.bytes());
+    assert_eq!(copy.format(), Some(ImageFrameType::GRAY8));
+    assert_eq!(copy.timestamp()?, Duration::from_millis(1500));
+    assert_eq!(copy.sequence_num()?, 42);
+    Ok(())
+}
+
+#[test]
+fn unknown_frame_type_cannot_be_rebuilt() {
+    let snapshot = ImageFrameSnapshot {
+        width: 1,
+        height: 1,
+        frame_type: None,
+        stride: 1,
+        timestamp: Duration::ZERO,
+        timestamp_device: Duration::ZERO,
+        sequence_num: 0,
+        data: vec![0],
+    };
+    assert!(snapshot.to_frame().is_err());
+}
+
+#[test]
+fn detections_round_trip_through_json() -> depthai::Result<()> {
+    let snapshot = ImgDetectionsSnapshot {
+        timestamp: Duration::from_secs(3),
+        sequence_num: 7,
+        detections: vec![ImgDetection {
+            label: 1,
+            confidence: 0.75,
+            xmin: 0.1,
+            ymin: 0.2,
+            xmax: 0.3,
+            ymax: 0.4,
+        }],
+    };
+    let detections = snapshot.to_detections()?;
+    let json = serde_json::to_string(&detections).expect("serialize detections");
+    let copy: ImgDetections = serde_json::from_str(&json).expect("deserialize detections");
+    assert_eq!(copy.snapshot()?, snapshot);
+    Ok(())
+}
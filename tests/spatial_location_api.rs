@@ -0,0 +1,42 @@
+#![cfg(feature = "hit")]
+
+use depthai::common::Rect;
+use depthai::depth::DepthAlgorithm;
+use depthai::{
+    Pipeline, Result, SpatialLocationCalculatorConfig, SpatialLocationCalculatorNode, SpatialLocationRoi, StereoDepthNode,
+};
+
+#[test]
+fn spatial_location_calculator_api_smoke() -> Result<()> {
+    let pipeline = Pipeline::new().build()?;
+
+    let calc = pipeline.create::<SpatialLocationCalculatorNode>()?;
+    calc.set_wait_for_config_input(false);
+
+    let center = SpatialLocationRoi::new(Rect::new(0.4, 0.4, 0.2, 0.2))
+        .with_thresholds(100, 10_000)
+        .with_algorithm(DepthAlgorithm::Average)
+        .with_step(2);
+
+    // Initial config is shared with the node.
+    let mut initial = calc.initial_config()?;
+    initial.set_rois(&[center]);
+    assert_eq!(calc.initial_config()?.rois()?, vec![center]);
+
+    // Standalone config message, as sent to `inputConfig` at runtime.
+    let mut cfg = SpatialLocationCalculatorConfig::new()?;
+    cfg.add_roi(&center).add_roi(&SpatialLocationRoi::new(Rect::new(0.0, 0.0, 0.1, 0.1)));
+    let rois = cfg.rois()?;
+    assert_eq!(rois.len(), 2);
+    assert_eq!(rois[1].algorithm, DepthAlgorithm::Median);
+    assert_eq!(rois[1].step, None);
+    cfg.clear_rois();
+    assert!(cfg.rois()?.is_empty());
+
+    let stereo = pipeline.create::<StereoDepthNode>()?;
+    stereo.as_node().output("depth")?.link_to(calc.as_node(), Some("inputDepth"))?;
+    let _out = calc.out()?;
+    let _input_config = calc.inputConfig()?;
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+#![cfg(not(target_os = "windows"))]
+
+use depthai::stereo_depth::{
+    aligned_output_size, StereoSizePolicy, STEREO_SAFE_WIDTH_ALIGNMENT, STEREO_WIDTH_ALIGNMENT,
+};
+
+#[test]
+fn aligned_widths_pass_through() -> depthai::Result<()> {
+    for policy in [StereoSizePolicy::Exact, StereoSizePolicy::RoundDown, StereoSizePolicy::Nearest] {
+        assert_eq!(aligned_output_size((640, 400), STEREO_WIDTH_ALIGNMENT, policy)?, (640, 400));
+    }
+    assert_eq!(STEREO_SAFE_WIDTH_ALIGNMENT % STEREO_WIDTH_ALIGNMENT, 0);
+    Ok(())
+}
+
+#[test]
+fn unaligned_widths_follow_the_policy() -> depthai::Result<()> {
+    let size = (1000, 600);
+    assert_eq!(aligned_output_size(size, 16, StereoSizePolicy::RoundDown)?, (992, 600));
+    assert_eq!(aligned_output_size(size, 16, StereoSizePolicy::RoundUp)?, (1008, 600));
+    assert_eq!(aligned_output_size(size, 16, StereoSizePolicy::Nearest)?, (1008, 600));
+    assert_eq!(aligned_output_size((1010, 600), 16, StereoSizePolicy::Nearest)?, (1008, 600));
+    assert_eq!(aligned_output_size(size, STEREO_SAFE_WIDTH_ALIGNMENT, StereoSizePolicy::Nearest)?, (1024, 600));
+
+    let err = aligned_output_size(size, 16, StereoSizePolicy::Exact).unwrap_err().to_string();
+    assert!(err.contains("976x600, 992x600, 1008x600, 1024x600"), "{err}");
+
+    assert!(aligned_output_size((10, 600), 16, StereoSizePolicy::RoundDown).is_err());
+    assert_eq!(aligned_output_size((10, 600), 16, StereoSizePolicy::RoundUp)?, (16, 600));
+    assert!(aligned_output_size((0, 600), 16, StereoSizePolicy::RoundUp).is_err());
+    Ok(())
+}
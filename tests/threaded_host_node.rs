@@ -0,0 +1,120 @@
+#![cfg(not(target_os = "windows"))]
+
+use std::sync::mpsc;
+use std::time::Duration;
+
+use depthai::camera::ImageFrame;
+use depthai::{Buffer, Input, Pipeline, Result, ThreadedHostNodeContext, ThreadedHostNodeImpl, TypedOutput};
+
+/// Sends buffers numbered from 0, each after its delay, then idles.
+struct Source {
+    out: TypedOutput<Buffer>,
+    delays: Vec<Duration>,
+}
+
+impl ThreadedHostNodeImpl for Source {
+    fn run(&mut self, ctx: &ThreadedHostNodeContext) {
+        for (seq, delay) in self.delays.iter().enumerate() {
+            std::thread::sleep(*delay);
+            let sent = Buffer::new(1).and_then(|buffer| {
+                buffer.set_sequence_num(seq as i64)?;
+                self.out.send(&buffer)
+            });
+            if let Err(err) = sent {
+                ctx.report_error(&err);
+            }
+        }
+        ctx.wait_until_stopped();
+    }
+}
+
+/// Runs `body` once on its input and reports what it returns on `done`.
+struct Consumer<F> {
+    input: Input,
+    body: Option<F>,
+    done: mpsc::Sender<Vec<String>>,
+}
+
+impl<F: FnOnce(&ThreadedHostNodeContext, &Input) -> Vec<String> + Send + 'static> ThreadedHostNodeImpl for Consumer<F> {
+    fn run(&mut self, ctx: &ThreadedHostNodeContext) {
+        if let Some(body) = self.body.take() {
+            let _ = self.done.send(body(ctx, &self.input));
+        }
+    }
+}
+
+/// A consumer running `body` on the output of a [`Source`] sending after `delays`.
+fn consume<F>(delays: Vec<Duration>, body: F) -> Result<(Pipeline, mpsc::Receiver<Vec<String>>)>
+where
+    F: FnOnce(&ThreadedHostNodeContext, &Input) -> Vec<String> + Send + 'static,
+{
+    let pipeline = Pipeline::new_host_only()?;
+    let (done, rx) = mpsc::channel();
+    let consumer = pipeline.create_threaded_host_node(|node| {
+        Ok(Consumer {
+            input: node.create_input(Some("in"))?,
+            body: Some(body),
+            done,
+        })
+    })?;
+    let source = pipeline.create_threaded_host_node(|node| {
+        Ok(Source {
+            out: node.create_typed_output("out")?,
+            delays,
+        })
+    })?;
+    source.as_node().output("out")?.link(&consumer.as_node().input("in")?)?;
+    Ok((pipeline, rx))
+}
+
+/// `Ok(Some(seq))`, `Ok(None)` or `Err` of a receive.
+fn describe(received: Result<Option<Buffer>>) -> String {
+    match received {
+        Ok(Some(buffer)) => format!("seq {}", buffer.sequence_num().unwrap()),
+        Ok(None) => "none".to_string(),
+        Err(_) => "err".to_string(),
+    }
+}
+
+#[test]
+fn receiving_waits_times_out_and_checks_the_type() -> Result<()> {
+    let delays = [300, 0, 300].map(Duration::from_millis).to_vec();
+    let (pipeline, rx) = consume(delays, |ctx, input| {
+        // Nothing is sent for 300 ms.
+        let mut seen = vec![
+            describe(ctx.recv(input, Duration::from_millis(100))),
+            describe(ctx.try_recv(input)),
+        ];
+        // 0 and 1 arrive together.
+        seen.push(describe(ctx.next(input)));
+        std::thread::sleep(Duration::from_millis(50));
+        seen.push(describe(ctx.try_recv(input)));
+        seen.push(describe(ctx.try_recv(input)));
+        // 2 is a buffer, not a frame.
+        let frame = ctx.recv::<ImageFrame, _>(input, Duration::from_secs(2));
+        seen.push(frame.map_or("err", |_| "frame").to_string());
+        seen
+    })?;
+    pipeline.start()?;
+    let results = rx.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert_eq!(results, ["none", "none", "seq 0", "seq 1", "none", "err"]);
+    pipeline.stop()?;
+    Ok(())
+}
+
+#[test]
+fn blocking_helpers_return_once_the_node_stops() -> Result<()> {
+    let (pipeline, rx) = consume(Vec::new(), |ctx, input| {
+        let next = describe(ctx.next(input));
+        let recv = describe(ctx.recv(input, Duration::from_secs(60)));
+        ctx.wait_until_stopped();
+        vec![next, recv]
+    })?;
+    pipeline.start()?;
+    std::thread::sleep(Duration::from_millis(100));
+    assert!(rx.try_recv().is_err(), "returned while running");
+    pipeline.stop()?;
+    let results = rx.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert_eq!(results, ["none", "none"]);
+    Ok(())
+}
@@ -0,0 +1,19 @@
+#![cfg(not(target_os = "windows"))]
+
+// Compile-time checks on the typed port API and the composite macro. Regenerate the `.stderr`
+// snapshots with `TRYBUILD=overwrite cargo test --test ui`.
+#[test]
+fn typed_ports() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/typed_link_mismatch.rs");
+}
+
+#[test]
+fn composite_macro() {
+    let cases = trybuild::TestCases::new();
+    cases.pass("tests/ui/composite_forward_link.rs");
+    cases.compile_fail("tests/ui/composite_link_unknown_field.rs");
+    cases.compile_fail("tests/ui/composite_link_missing_port.rs");
+    cases.compile_fail("tests/ui/composite_forward_ambiguous.rs");
+    cases.compile_fail("tests/ui/composite_unknown_argument.rs");
+}
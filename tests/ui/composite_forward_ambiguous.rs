@@ -0,0 +1,9 @@
+use depthai::depthai_composite;
+
+#[depthai_composite(default)]
+pub struct DepthBundle {
+    #[forward(output = "depth", input = "left")]
+    pub stereo: depthai::stereo_depth::StereoDepthNode,
+}
+
+fn main() {}
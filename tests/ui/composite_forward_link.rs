@@ -0,0 +1,46 @@
+use depthai::camera::ImageFrame;
+use depthai::output::{Output, TypedInput};
+use depthai::rgbd::RgbdNode;
+use depthai::stereo_depth::StereoDepthNode;
+use depthai::{depthai_composite, Pipeline, Result};
+
+// Members created by `default`, linked and re-exported.
+#[depthai_composite(default)]
+#[link(from = "stereo.depth", to = "rgbd.inDepth")]
+#[forward(output = "out", from = "rgbd", name = "rgbd_out")]
+pub struct DepthBundle {
+    #[forward(input = "left", ty = ImageFrame)]
+    pub stereo: StereoDepthNode,
+    #[forward(input = "inColor", name = "color", ty = ImageFrame)]
+    pub rgbd: RgbdNode,
+}
+
+// Members created by a private `new`, linked by `pipeline.create()`.
+#[depthai_composite]
+#[link(from = "stereo.depth", to = "rgbd.inDepth")]
+pub struct ManualBundle {
+    pub stereo: StereoDepthNode,
+    pub rgbd: RgbdNode,
+}
+
+impl ManualBundle {
+    fn new(pipeline: &Pipeline) -> Result<Self> {
+        Ok(Self {
+            stereo: pipeline.create()?,
+            rgbd: pipeline.create()?,
+        })
+    }
+}
+
+fn build(pipeline: &Pipeline) -> Result<()> {
+    let bundle = pipeline.create::<DepthBundle>()?;
+    let _: TypedInput<ImageFrame> = bundle.left()?;
+    let _: TypedInput<ImageFrame> = bundle.color()?;
+    let _: Output = bundle.rgbd_out()?;
+    let _manual = pipeline.create::<ManualBundle>()?;
+    Ok(())
+}
+
+fn main() {
+    let _ = build;
+}
@@ -0,0 +1,10 @@
+use depthai::depthai_composite;
+
+#[depthai_composite(default)]
+#[link(from = "stereo.depth", to = "rgb.inDepth")]
+pub struct DepthBundle {
+    pub stereo: depthai::stereo_depth::StereoDepthNode,
+    pub rgbd: depthai::rgbd::RgbdNode,
+}
+
+fn main() {}
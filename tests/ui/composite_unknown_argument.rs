@@ -0,0 +1,8 @@
+use depthai::depthai_composite;
+
+#[depthai_composite(defaults)]
+pub struct DepthBundle {
+    pub stereo: depthai::stereo_depth::StereoDepthNode,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use depthai::camera::ImageFrame;
+use depthai::{PointCloudData, TypedInput, TypedOutput};
+
+// A point cloud output cannot feed an image input.
+fn link(pcl: TypedOutput<PointCloudData>, frames: TypedInput<ImageFrame>) -> depthai::Result<()> {
+    pcl.link(&frames)
+}
+
+fn main() {}
@@ -0,0 +1,31 @@
+#![cfg(not(target_os = "windows"))]
+
+use depthai::version::{self, Feature};
+
+#[test]
+fn core_version_matches_build_constants() {
+    let version = version::depthai_core_version();
+    assert_eq!(version.major, 3);
+    // `VERSION` may carry build metadata (e.g. `+commit`) on top of the semver parts.
+    assert!(
+        version::depthai_core_version_string().starts_with(&version.to_string()),
+        "{version} vs {}",
+        version::depthai_core_version_string()
+    );
+    assert!(!version::device_rvc4_fw_version().is_empty());
+}
+
+#[test]
+fn feature_detection_follows_node_registry() {
+    assert!(version::has_node("dai::node::StereoDepth"));
+    assert!(!version::has_node("dai::node::DoesNotExist"));
+    assert!(!version::has_node("dai::node::Stereo\0Depth"));
+    assert_eq!(version::has_feature(Feature::NeuralDepth), version::has_node("dai::node::NeuralDepth"));
+    // Dynamic calibration and image filters are built on top of OpenCV.
+    if version::has_feature(Feature::DynamicCalibration) {
+        assert!(version::has_feature(Feature::OpenCv));
+    }
+    assert_eq!(version::has_feature(Feature::ImageFilters), version::has_feature(Feature::OpenCv));
+    // Optional nodes are registered together with their siblings.
+    assert_eq!(version::has_feature(Feature::RecordReplay), version::has_node("dai::node::ReplayVideo"));
+}
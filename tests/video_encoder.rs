@@ -0,0 +1,49 @@
+#![cfg(feature = "hit")]
+
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+use depthai::camera::{CameraNode, CameraOutputConfig};
+use depthai::common::{CameraBoardSocket, ImageFrameType};
+use depthai::{EncodedFrameProfile, Pipeline, Result, VideoEncoderNode, VideoEncoderProfile};
+
+#[test]
+fn encoded_frames_reach_the_callback_until_the_handle_drops() -> Result<()> {
+    let pipeline = Pipeline::new().build()?;
+    let camera = pipeline.create_with::<CameraNode, _>(CameraBoardSocket::CamA)?;
+    let mut config = CameraOutputConfig::new((640, 480));
+    config.frame_type = Some(ImageFrameType::NV12);
+    let encoder = pipeline.create::<VideoEncoderNode>()?;
+    encoder.set_default_profile_preset(30.0, VideoEncoderProfile::Mjpeg);
+    camera.request_output(config)?.link(&encoder.input()?)?;
+
+    let (tx, rx) = mpsc::channel();
+    let handle = encoder.on_encoded_frame(move |frame| {
+        let _ = tx.send((frame.profile(), frame.sequence_num().ok(), frame.data_len()));
+    })?;
+    pipeline.start()?;
+
+    let frames: Vec<_> = (0..5)
+        .map(|_| rx.recv_timeout(Duration::from_secs(5)).expect("encoded frame"))
+        .collect();
+    assert!(frames
+        .iter()
+        .all(|(profile, _, len)| *profile == Some(EncodedFrameProfile::Jpeg) && *len > 0));
+    // Handed over one by one, without waiting in the queue.
+    let sequence: Vec<i64> = frames.iter().filter_map(|(_, seq, _)| *seq).collect();
+    assert!(sequence.windows(2).all(|w| w[0] < w[1]), "{sequence:?}");
+
+    // Dropping the handle releases the callback, and the sender it owns, while still running.
+    drop(handle);
+    let deadline = Instant::now() + Duration::from_secs(2);
+    let released = loop {
+        match rx.recv_timeout(Duration::from_millis(100)) {
+            Err(RecvTimeoutError::Disconnected) => break true,
+            _ if Instant::now() > deadline => break false,
+            _ => {}
+        }
+    };
+    assert!(released, "callback still registered");
+    pipeline.stop()?;
+    Ok(())
+}